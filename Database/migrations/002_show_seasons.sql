@@ -0,0 +1,3 @@
+-- Season numbers only have to be unique per show, the primary key already covers that.
+alter table ShowSeason
+  drop constraint showseason_season_key;
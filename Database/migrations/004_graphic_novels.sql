@@ -0,0 +1,23 @@
+-- Volume numbers only have to be unique per graphic novel, the primary key already covers that.
+alter table GraphicNovelVolume
+  drop constraint graphicnovelvolume_volume_key;
+-- Volume translations were keyed by the graphic novel only, so existing rows can not be assigned to a volume.
+drop table GraphicNovelVolumeTranslation;
+create table GraphicNovelVolumeTranslation
+(
+  Title                varchar(150) not null,
+  Description          varchar(500),
+
+  FKGraphicNovel       int          not null references GraphicNovel (Id),
+  FKGraphicNovelVolume int          not null,
+  Language             language     not null,
+  foreign key (FKGraphicNovel, FKGraphicNovelVolume) references GraphicNovelVolume (FKGraphicNovel, Volume),
+  primary key (FKGraphicNovel, FKGraphicNovelVolume, Language)
+);
+-- The table was missing from databases created with the typo in its definition.
+create table if not exists GraphicNovelTheme
+(
+  FKGraphicNovel int not null references GraphicNovel (Id),
+  FKTheme        int not null references Theme (Id),
+  primary key (FKGraphicNovel, FkTheme)
+);
@@ -0,0 +1,17 @@
+alter table BookEdition
+  add unique (ISBN13);
+-- Edition translations referenced the book instead of the edition, so existing rows can not be assigned to an edition.
+delete
+from BookEditionTranslation;
+alter table BookEditionTranslation
+  alter column Description set not null,
+  drop constraint bookeditiontranslation_fktranslation_fkey,
+  add constraint bookeditiontranslation_fktranslation_fkey foreign key (FKTranslation) references BookEdition (Id);
+-- The table was missing from databases created with the wrong primary key in its definition.
+create table if not exists BookEditionInvolved
+(
+    FKBookEdition   int not null references BookEdition (Id),
+    FkRole          int not null references Role (Id),
+    FKPerson        int not null references Person (Id),
+    primary key (FKBookEdition, FKRole, FKPerson)
+);
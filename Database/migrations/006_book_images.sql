@@ -0,0 +1,11 @@
+-- Existing images keep the order of their ids, positions start at 0.
+alter table BookImage
+  add column Position smallint;
+update BookImage
+set Position = ordered.Position
+from (select FKBook, FKImage, row_number() over (partition by FKBook order by FKImage) - 1 as Position
+      from BookImage) as ordered
+where BookImage.FKBook = ordered.FKBook
+  and BookImage.FKImage = ordered.FKImage;
+alter table BookImage
+  alter column Position set not null;
@@ -0,0 +1,6 @@
+create table BookPublisher
+(
+  FKBook      int not null references Book (Id),
+  FKPublisher int not null references Company (Id),
+  primary key (FKBook, FKPublisher)
+);
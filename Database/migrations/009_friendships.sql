@@ -0,0 +1,27 @@
+-- Friendships created before friend requests existed are accepted, new ones start as a pending request.
+CREATE TYPE friendshipstatus AS ENUM ('Pending','Accepted','Declined');
+delete
+from Friendship
+where FKUser = FKSecondUser;
+-- only one friendship per pair of users, regardless of who sent the request
+delete
+from Friendship
+where FKUser > FKSecondUser
+  and exists (select
+              from Friendship as reverse
+              where reverse.FKUser = Friendship.FKSecondUser
+                and reverse.FKSecondUser = Friendship.FKUser);
+-- Added is recreated after Status to keep the column order of setup.sql
+alter table Friendship
+  add column Status   friendshipstatus not null DEFAULT 'Accepted',
+  add column NewAdded date             not null DEFAULT (CURRENT_DATE),
+  add CHECK (FKUser <> FKSecondUser);
+update Friendship
+set NewAdded = Added;
+alter table Friendship
+  drop column Added;
+alter table Friendship
+  rename column NewAdded to Added;
+alter table Friendship
+  alter column Status set DEFAULT 'Pending';
+create unique index FriendshipUsers on Friendship (least(FKUser, FKSecondUser), greatest(FKUser, FKSecondUser));
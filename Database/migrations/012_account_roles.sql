@@ -0,0 +1,6 @@
+CREATE TYPE accountrole AS ENUM ('Member','Editor','Admin');
+create table AccountGrant
+(
+  FKUser int         not null primary key references Account (FKUser),
+  Role   accountrole not null CHECK (Role <> 'Member')
+);
@@ -0,0 +1,11 @@
+create table RefreshToken
+(
+  Id       serial primary key,
+  FKUser   int         not null references Account (FKUser),
+  FKFamily int references RefreshToken (Id) on delete cascade,
+  FKParent int references RefreshToken (Id) on delete cascade,
+  Hash     varchar(64) not null unique,
+  Expires  timestamp   not null,
+  Used     boolean     not null default false
+);
+create index RefreshTokenUserIndex on RefreshToken (FKUser);
@@ -0,0 +1,14 @@
+-- Grants and refresh tokens reference the user like every other account table.
+alter table AccountGrant
+  drop constraint accountgrant_fkuser_fkey,
+  add constraint accountgrant_fkuser_fkey foreign key (FKUser) references "User" (Id);
+alter table RefreshToken
+  drop constraint refreshtoken_fkuser_fkey,
+  add constraint refreshtoken_fkuser_fkey foreign key (FKUser) references "User" (Id);
+create table PasswordReset
+(
+  Id      serial primary key,
+  FKUser  int         not null references "User" (Id),
+  Hash    varchar(64) not null unique,
+  Expires timestamp   not null
+);
@@ -0,0 +1,13 @@
+-- Adds email verification to a database created before it existed.
+-- Accounts registered before then are marked verified, so they keep their editor and admin permissions.
+alter table Account
+  add column Verified boolean not null default true;
+alter table Account
+  alter column Verified set default false;
+create table EmailVerification
+(
+  Id      serial primary key,
+  FKUser  int         not null references "User" (Id),
+  Hash    varchar(64) not null unique,
+  Expires timestamp   not null
+);
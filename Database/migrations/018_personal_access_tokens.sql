@@ -0,0 +1,11 @@
+create table PersonalAccessToken
+(
+  Id       serial primary key,
+  FKUser   int          not null references "User" (Id),
+  Name     varchar(100) not null,
+  Hash     varchar(64)  not null unique,
+  Scopes   varchar(255) not null,
+  Created  timestamp    not null,
+  LastUsed timestamp
+);
+create index PersonalAccessTokenUserIndex on PersonalAccessToken (FKUser);
@@ -0,0 +1,14 @@
+create table TwoFactor
+(
+  FKUser    int         not null primary key references "User" (Id),
+  Secret    varchar(64) not null,
+  Confirmed boolean     not null default false,
+  LastStep  bigint
+);
+create table RecoveryCode
+(
+  Id     serial primary key,
+  FKUser int         not null references "User" (Id),
+  Hash   varchar(64) not null
+);
+create index RecoveryCodeUserIndex on RecoveryCode (FKUser);
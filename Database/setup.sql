@@ -5,6 +5,8 @@ create database collectiondb;
 CREATE TYPE language AS ENUM ( 'EN','DE','ES','DA','NL','JA','KO');
 CREATE TYPE status AS ENUM ('NotStarted','Ongoing','Finished','Paused');
 CREATE TYPE userstatus AS ENUM ('NotStarted','Ongoing','Finished','Paused');
+CREATE TYPE friendshipstatus AS ENUM ('Pending','Accepted','Declined');
+CREATE TYPE accountrole AS ENUM ('Member','Editor','Admin');
 create table Franchise
 (
   Id   int primary key generated always as identity
@@ -226,8 +228,8 @@ create table GraphicNovelTranslation
 );
 create table GraphicNovelVolume
 (
-  Volume         smallint unique not null,
-  FKGraphicNovel int             not null references GraphicNovel (Id),
+  Volume         smallint not null,
+  FKGraphicNovel int      not null references GraphicNovel (Id),
   Pages          smallint,
   Published      date,
   Score          smallint,
@@ -235,12 +237,14 @@ create table GraphicNovelVolume
 );
 create table GraphicNovelVolumeTranslation
 (
-  Title         varchar(150) not null,
-  Description   varchar(500),
+  Title                varchar(150) not null,
+  Description          varchar(500),
 
-  FKTranslation int          not null references GraphicNovel (Id),
-  Language      language     not null,
-  primary key (FKTranslation, Language)
+  FKGraphicNovel       int          not null references GraphicNovel (Id),
+  FKGraphicNovelVolume int          not null,
+  Language             language     not null,
+  foreign key (FKGraphicNovel, FKGraphicNovelVolume) references GraphicNovelVolume (FKGraphicNovel, Volume),
+  primary key (FKGraphicNovel, FKGraphicNovelVolume, Language)
 );
 create table GraphicNovelChapter
 (
@@ -285,8 +289,7 @@ create table GraphicNovelGenre
 );
 create table GraphicNovelTheme
 (
-  FKGraphicNovel int not null references GraphicNovel (Id),rosen
-
+  FKGraphicNovel int not null references GraphicNovel (Id),
   FKTheme        int not null references Theme (Id),
   primary key (FKGraphicNovel, FkTheme)
 );
@@ -311,7 +314,7 @@ create table BookEdition(
   Pages       smallint,
   Words       int,
   Published   date,
-  ISBN13      char(13),
+  ISBN13      char(13) unique,
   Language    language,
   FKCover     int not null references Image (Id),
   FKBook      int not null references Book (Id)
@@ -319,9 +322,9 @@ create table BookEdition(
 
 create table BookEditionTranslation
 (
-  Description   varchar(500),
+  Description   varchar(500) not null,
 
-  FKTranslation int          not null references Book (Id),
+  FKTranslation int          not null references BookEdition (Id),
   Language      language     not null,
   primary key (FKTranslation, Language)
 );
@@ -330,7 +333,7 @@ create table BookEditionInvolved
     FKBookEdition   int not null references BookEdition (Id),
     FkRole          int not null references Role (Id),
     FKPerson        int not null references Person (Id),
-    primary key (FKBook, FKRole, FKPerson)
+    primary key (FKBookEdition, FKRole, FKPerson)
 );
 create table BookStatistic(
   FKBook      int not null references Book (Id) primary key,
@@ -358,11 +361,18 @@ create table BookCharacter
   primary key (FKBook, FKCharacter)
 );
 create table BookImage
- (
-   FKBook      int not null references Book (Id),
-   FKImage int not null references Image (Id),
-   primary key (FKBook, FKImage)
- );
+(
+  FKBook   int      not null references Book (Id),
+  FKImage  int      not null references Image (Id),
+  Position smallint not null,
+  primary key (FKBook, FKImage)
+);
+create table BookPublisher
+(
+  FKBook      int not null references Book (Id),
+  FKPublisher int not null references Company (Id),
+  primary key (FKBook, FKPublisher)
+);
 create table BookGenre
 (
   FKBook  int not null references Book (Id),
@@ -414,7 +424,7 @@ create table ShowTranslation
 );
 create table ShowSeason
 (
-  Season      smallint unique not null,
+  Season      smallint not null,
   FKShow      int             not null references Show (Id),
   Episodes    smallint,
   AiringStart date,
@@ -627,15 +637,75 @@ create table UserGame
 );
 create table Friendship
 (
-  FKUser       int  not null references "User" (Id),
-  FKSecondUser int  not null references "User" (Id),
-  Added        date not null DEFAULT (CURRENT_DATE),
+  FKUser       int              not null references "User" (Id),
+  FKSecondUser int              not null references "User" (Id),
+  Status       friendshipstatus not null DEFAULT 'Pending',
+  Added        date             not null DEFAULT (CURRENT_DATE),
+  CHECK (FKUser <> FKSecondUser),
   primary key (FKUser, FKSecondUser)
 );
+-- only one friendship per pair of users, regardless of who sent the request
+create unique index FriendshipUsers on Friendship (least(FKUser, FKSecondUser), greatest(FKUser, FKSecondUser));
 create table Account
 (
   FKUser   int                 not null primary key references "User" (Id),
   EMail    varchar(255) unique not null,
-  Password varchar(255)        not null
+  Password varchar(255)        not null,
+  Verified boolean             not null default false
 );
 create index AccountEmailIndex on Account using HASH (EMail);
+create table AccountGrant
+(
+  FKUser int         not null primary key references "User" (Id),
+  Role   accountrole not null CHECK (Role <> 'Member')
+);
+create table RefreshToken
+(
+  Id       serial primary key,
+  FKUser   int         not null references "User" (Id),
+  FKFamily int references RefreshToken (Id) on delete cascade,
+  FKParent int references RefreshToken (Id) on delete cascade,
+  Hash     varchar(64) not null unique,
+  Expires  timestamp   not null,
+  Used     boolean     not null default false
+);
+create index RefreshTokenUserIndex on RefreshToken (FKUser);
+create table PasswordReset
+(
+  Id      serial primary key,
+  FKUser  int         not null references "User" (Id),
+  Hash    varchar(64) not null unique,
+  Expires timestamp   not null
+);
+create table EmailVerification
+(
+  Id      serial primary key,
+  FKUser  int         not null references "User" (Id),
+  Hash    varchar(64) not null unique,
+  Expires timestamp   not null
+);
+create table PersonalAccessToken
+(
+  Id       serial primary key,
+  FKUser   int          not null references "User" (Id),
+  Name     varchar(100) not null,
+  Hash     varchar(64)  not null unique,
+  Scopes   varchar(255) not null,
+  Created  timestamp    not null,
+  LastUsed timestamp
+);
+create index PersonalAccessTokenUserIndex on PersonalAccessToken (FKUser);
+create table TwoFactor
+(
+  FKUser    int         not null primary key references "User" (Id),
+  Secret    varchar(64) not null,
+  Confirmed boolean     not null default false,
+  LastStep  bigint
+);
+create table RecoveryCode
+(
+  Id     serial primary key,
+  FKUser int         not null references "User" (Id),
+  Hash   varchar(64) not null
+);
+create index RecoveryCodeUserIndex on RecoveryCode (FKUser);
@@ -0,0 +1,127 @@
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bb8_postgres::bb8::ManageConnection;
+use bb8_postgres::PostgresConnectionManager;
+use tokio_postgres::{Client, Error, NoTls, Statement};
+
+use crate::statement_cache::{is_stale, StatementCache, DEFAULT_STATEMENT_CACHE_SIZE};
+
+/// Creates connections that cache the statements prepared by the query builders
+pub struct CachedConnectionManager {
+  manager: PostgresConnectionManager<NoTls>,
+  statement_cache_size: usize,
+}
+
+impl CachedConnectionManager {
+  pub fn new_from_stringlike(database_url: &str) -> Result<CachedConnectionManager, Error> {
+    Ok(CachedConnectionManager {
+      manager: PostgresConnectionManager::new_from_stringlike(database_url, NoTls)?,
+      statement_cache_size: DEFAULT_STATEMENT_CACHE_SIZE,
+    })
+  }
+
+  /// Maximum of prepared statements kept per connection, 0 disables the cache
+  pub const fn statement_cache_size(mut self, statement_cache_size: usize) -> Self {
+    self.statement_cache_size = statement_cache_size;
+    self
+  }
+}
+
+impl ManageConnection for CachedConnectionManager {
+  type Connection = CachedClient;
+  type Error = Error;
+
+  async fn connect(&self) -> Result<CachedClient, Error> {
+    Ok(CachedClient {
+      client: self.manager.connect().await?,
+      statements: StatementCache::new(self.statement_cache_size),
+      in_transaction: AtomicBool::new(false),
+    })
+  }
+
+  async fn is_valid(&self, connection: &mut CachedClient) -> Result<(), Error> {
+    self.manager.is_valid(&mut connection.client).await
+  }
+
+  /// A connection returned while it is still in a transaction had its transaction dropped and is not reused
+  fn has_broken(&self, connection: &mut CachedClient) -> bool {
+    *connection.in_transaction.get_mut() || self.manager.has_broken(&mut connection.client)
+  }
+}
+
+/// A connection together with the statements the query builders prepared on it
+pub struct CachedClient {
+  client: Client,
+  statements: StatementCache,
+  in_transaction: AtomicBool,
+}
+
+impl CachedClient {
+  pub async fn transaction(&mut self) -> Result<CachedTransaction<'_>, Error> {
+    self.in_transaction.store(true, Ordering::Relaxed);
+    self.client.batch_execute("BEGIN").await?;
+    Ok(CachedTransaction { client: self })
+  }
+
+  /// Runs the SQL with its cached statement. A statement the server rejects as stale is evicted,
+  /// outside of a transaction it is prepared and run once more, inside one the error already aborted the transaction.
+  pub(crate) async fn run<T, F, R>(&self, sql: &str, run: F) -> Result<T, Error>
+  where
+    F: Fn(Statement) -> R,
+    R: Future<Output = Result<T, Error>>,
+  {
+    match run(self.statements.prepare(&self.client, sql).await?).await {
+      Err(error) if is_stale(&error) => {
+        self.statements.evict(sql);
+        if self.in_transaction.load(Ordering::Relaxed) {
+          return Err(error);
+        }
+        run(self.statements.prepare(&self.client, sql).await?).await
+      }
+      result => result,
+    }
+  }
+}
+
+impl Deref for CachedClient {
+  type Target = Client;
+
+  fn deref(&self) -> &Client {
+    &self.client
+  }
+}
+
+/// A transaction on a `CachedClient`, if it is dropped before `commit` or `rollback` the pool closes the connection which rolls it back
+pub struct CachedTransaction<'a> {
+  client: &'a CachedClient,
+}
+
+impl<'a> CachedTransaction<'a> {
+  pub const fn client(&self) -> &'a CachedClient {
+    self.client
+  }
+
+  pub async fn commit(self) -> Result<(), Error> {
+    self.finish("COMMIT").await
+  }
+
+  pub async fn rollback(self) -> Result<(), Error> {
+    self.finish("ROLLBACK").await
+  }
+
+  async fn finish(self, query: &str) -> Result<(), Error> {
+    self.client.batch_execute(query).await?;
+    self.client.in_transaction.store(false, Ordering::Relaxed);
+    Ok(())
+  }
+}
+
+impl Deref for CachedTransaction<'_> {
+  type Target = Client;
+
+  fn deref(&self) -> &Client {
+    &self.client.client
+  }
+}
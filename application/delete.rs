@@ -1,10 +1,10 @@
+use crate::cached_connection::{CachedClient, CachedTransaction};
 use crate::delete::DeleteError::PostgresError;
 use crate::select::expression::Expression;
 use from_row::Table;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use tokio_postgres::types::ToSql;
-use tokio_postgres::{Client, Transaction};
 
 pub struct Delete<'a> {
   from: &'a str,
@@ -21,19 +21,22 @@ impl<'a> Delete<'a> {
 }
 
 impl<'a> Delete<'a> {
-  pub async fn execute_transaction(&self, transaction: &'a Transaction<'a>) -> Result<u64, DeleteError> {
-    transaction
-      .execute(&self.sql(), &self.values())
+  pub async fn execute_transaction(&self, transaction: &'a CachedTransaction<'a>) -> Result<u64, DeleteError> {
+    self.execute(transaction.client()).await
+  }
+  pub async fn execute(&self, client: &'a CachedClient) -> Result<u64, DeleteError> {
+    let values = &self.values();
+    client
+      .run(&self.sql(), |statement| async move {
+        client.execute(&statement, values).await
+      })
       .await
       .map_err(PostgresError)
   }
-  pub async fn execute(&self, client: &'a Client) -> Result<u64, DeleteError> {
-    client.execute(&self.sql(), &self.values()).await.map_err(PostgresError)
-  }
-  fn sql(&self) -> String {
+  pub(crate) fn sql(&self) -> String {
     format!("DELETE FROM {} WHERE {}", self.from, self.where_condition.sql(&mut 1))
   }
-  fn values(&'a self) -> Vec<&'a (dyn ToSql + Sync)> {
+  pub(crate) fn values(&'a self) -> Vec<&'a (dyn ToSql + Sync)> {
     Expression::values(&self.where_condition)
   }
 }
@@ -0,0 +1,48 @@
+use from_row::FromRow;
+use from_row::FromRowOption;
+use std::error::Error;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::private::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+
+use domain::enums::account_role::AccountRole;
+use from_row::from_row_impl;
+
+use crate::{convert, enum_from_sql};
+
+#[derive(Serialize, Deserialize, Copy, PartialEq, Eq, Clone, Debug)]
+pub enum DbAccountRole {
+  Member,
+  Editor,
+  Admin,
+}
+from_row_impl!(DbAccountRole);
+convert!(DbAccountRole, AccountRole, Member, Editor, Admin);
+enum_from_sql!(DbAccountRole, "accountrole");
+
+impl FromStr for DbAccountRole {
+  type Err = <AccountRole as FromStr>::Err;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    AccountRole::from_str(s).map(DbAccountRole::from)
+  }
+}
+impl ToSql for DbAccountRole {
+  fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+  where
+    Self: Sized,
+  {
+    out.extend_from_slice(AccountRole::from(*self).to_string().as_bytes());
+    Ok(IsNull::No)
+  }
+
+  fn accepts(ty: &Type) -> bool
+  where
+    Self: Sized,
+  {
+    <DbAccountRole as FromSql>::accepts(ty)
+  }
+  to_sql_checked!();
+}
@@ -0,0 +1,48 @@
+use from_row::FromRow;
+use from_row::FromRowOption;
+use std::error::Error;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::private::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+
+use domain::enums::friendship_status::FriendshipStatus;
+use from_row::from_row_impl;
+
+use crate::{convert, enum_from_sql};
+
+#[derive(Serialize, Deserialize, Copy, PartialEq, Eq, Clone, Debug)]
+pub enum DbFriendshipStatus {
+  Pending,
+  Accepted,
+  Declined,
+}
+from_row_impl!(DbFriendshipStatus);
+convert!(DbFriendshipStatus, FriendshipStatus, Pending, Accepted, Declined);
+enum_from_sql!(DbFriendshipStatus, "friendshipstatus");
+
+impl FromStr for DbFriendshipStatus {
+  type Err = <FriendshipStatus as FromStr>::Err;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    FriendshipStatus::from_str(s).map(DbFriendshipStatus::from)
+  }
+}
+impl ToSql for DbFriendshipStatus {
+  fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+  where
+    Self: Sized,
+  {
+    out.extend_from_slice(FriendshipStatus::from(*self).to_string().as_bytes());
+    Ok(IsNull::No)
+  }
+
+  fn accepts(ty: &Type) -> bool
+  where
+    Self: Sized,
+  {
+    <DbFriendshipStatus as FromSql>::accepts(ty)
+  }
+  to_sql_checked!();
+}
@@ -0,0 +1,49 @@
+use from_row::FromRow;
+use from_row::FromRowOption;
+use std::error::Error;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::private::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+
+use domain::enums::status::Status;
+use from_row::from_row_impl;
+
+use crate::{convert, enum_from_sql};
+
+#[derive(Serialize, Deserialize, Copy, PartialEq, Eq, Clone, Debug)]
+pub enum DbStatus {
+  NotStarted,
+  Ongoing,
+  Finished,
+  Paused,
+}
+from_row_impl!(DbStatus);
+convert!(DbStatus, Status, NotStarted, Ongoing, Finished, Paused);
+enum_from_sql!(DbStatus, "status");
+
+impl FromStr for DbStatus {
+  type Err = <Status as FromStr>::Err;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Status::from_str(s).map(DbStatus::from)
+  }
+}
+impl ToSql for DbStatus {
+  fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+  where
+    Self: Sized,
+  {
+    out.extend_from_slice(Status::from(*self).to_string().as_bytes());
+    Ok(IsNull::No)
+  }
+
+  fn accepts(ty: &Type) -> bool
+  where
+    Self: Sized,
+  {
+    <DbStatus as FromSql>::accepts(ty)
+  }
+  to_sql_checked!();
+}
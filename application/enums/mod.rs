@@ -1 +1,5 @@
+pub mod db_account_role;
+pub mod db_friendship_status;
 pub mod db_language;
+pub mod db_status;
+pub mod db_user_status;
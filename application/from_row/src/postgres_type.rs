@@ -16,6 +16,7 @@ impl PostgresType for &str {
     TypeKind::Postgres(Type::VARCHAR),
     TypeKind::Postgres(Type::TEXT),
     TypeKind::Postgres(Type::NAME),
+    TypeKind::Postgres(Type::BPCHAR),
   ];
 }
 impl PostgresType for String {
@@ -23,6 +24,7 @@ impl PostgresType for String {
     TypeKind::Postgres(Type::VARCHAR),
     TypeKind::Postgres(Type::TEXT),
     TypeKind::Postgres(Type::NAME),
+    TypeKind::Postgres(Type::BPCHAR),
   ];
 }
 impl PostgresType for bool {
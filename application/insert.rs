@@ -2,15 +2,21 @@ use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
 use tokio_postgres::types::ToSql;
-use tokio_postgres::{Client, Transaction};
 
+use crate::cached_connection::{CachedClient, CachedTransaction};
 use crate::select::to_sql_value::ToSqlValue;
-use from_row::Table;
+use from_row::{FromRow, RowColumns, Table};
 
 pub struct Insert<'a, const U: usize> {
   into: &'a str,
   columns: [&'a str; U],
   values: Vec<[&'a dyn ToSqlValue<'a>; U]>,
+  on_conflict: Option<OnConflict<'a>>,
+}
+
+enum OnConflict<'a> {
+  DoNothing(Vec<&'a str>),
+  DoUpdate(Vec<&'a str>, Vec<&'a str>),
 }
 
 impl<'a, const U: usize> Insert<'a, U> {
@@ -22,6 +28,7 @@ impl<'a, const U: usize> Insert<'a, U> {
       into,
       columns,
       values: Vec::new(),
+      on_conflict: None,
     }
   }
   pub fn values(mut self, values: [&'a dyn ToSqlValue<'a>; U]) -> Self {
@@ -33,71 +40,136 @@ impl<'a, const U: usize> Insert<'a, U> {
     self
   }
 
-  pub async fn execute(&self, connection: &'a Client) -> Result<u64, InsertError> {
+  /// Rows that conflict on the given columns are skipped, without columns any conflict is skipped
+  pub fn on_conflict_do_nothing(mut self, columns: &[&'a str]) -> Self {
+    self.on_conflict = Some(OnConflict::DoNothing(columns.to_vec()));
+    self
+  }
+  /// Rows that conflict on the given columns update the existing row with the inserted values of the `set` columns,
+  /// without `set` columns they are skipped like with `on_conflict_do_nothing`
+  pub fn on_conflict_do_update(mut self, columns: &[&'a str], set: &[&'a str]) -> Self {
+    self.on_conflict = Some(OnConflict::DoUpdate(columns.to_vec(), set.to_vec()));
+    self
+  }
+
+  pub async fn execute(&self, connection: &'a CachedClient) -> Result<u64, InsertError> {
+    let values = &self.sql_values();
     connection
-      .execute(&self.sql(), &self.sql_values())
+      .run(&self.sql(), |statement| async move {
+        connection.execute(&statement, values).await
+      })
       .await
       .map_err(InsertError::PostgresError)
   }
 
-  pub async fn execute_transaction(&self, transaction: &'a Transaction<'a>) -> Result<u64, InsertError> {
-    transaction
-      .execute(&self.sql(), &self.sql_values())
-      .await
-      .map_err(InsertError::PostgresError)
+  pub async fn execute_transaction(&self, transaction: &'a CachedTransaction<'a>) -> Result<u64, InsertError> {
+    self.execute(transaction.client()).await
   }
 
+  /// Fails with `NothingReturned` if the row was skipped by `on_conflict_do_nothing`
   pub async fn returning<T: for<'b> tokio_postgres::types::FromSql<'b>>(
     &self,
     column: &'a str,
-    connection: &'a Client,
+    connection: &'a CachedClient,
   ) -> Result<T, InsertError> {
     if self.values.len() > 1 {
       return Err(InsertError::ReturningMoreThanOne);
     }
 
+    let values = &self.sql_values();
     let result = connection
-      .query_one(&self.returning_sql(column), &self.sql_values())
+      .run(&self.returning_sql(column), |statement| async move {
+        connection.query_opt(&statement, values).await
+      })
       .await
-      .map_err(InsertError::PostgresError)?;
+      .map_err(InsertError::PostgresError)?
+      .ok_or(InsertError::NothingReturned)?;
     Ok(result.get::<'_, _, T>(0))
   }
 
+  /// Fails with `NothingReturned` if the row was skipped by `on_conflict_do_nothing`
   pub async fn returning_transaction<T: for<'b> tokio_postgres::types::FromSql<'b>>(
     &self,
     column: &'a str,
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
   ) -> Result<T, InsertError> {
-    if self.values.len() > 1 {
-      return Err(InsertError::ReturningMoreThanOne);
-    }
-    let result = transaction
-      .query_one(&self.returning_sql(column), &self.sql_values())
-      .await
-      .map_err(InsertError::PostgresError)?;
-    Ok(result.get::<'_, _, T>(0))
+    self.returning(column, transaction.client()).await
+  }
+
+  /// Rows skipped by `on_conflict_do_nothing` are not returned
+  pub async fn returning_many<T: FromRow<DbType = T> + RowColumns>(
+    &self,
+    connection: &'a CachedClient,
+  ) -> Result<Vec<T>, InsertError> {
+    let values = &self.sql_values();
+    Ok(
+      connection
+        .run(
+          &self.returning_sql(&Self::row_columns_sql::<T>()),
+          |statement| async move { connection.query(&statement, values).await },
+        )
+        .await
+        .map_err(InsertError::PostgresError)?
+        .into_iter()
+        .map(|x| T::from_row(&x, 0))
+        .collect(),
+    )
+  }
+
+  /// Rows skipped by `on_conflict_do_nothing` are not returned
+  pub async fn returning_many_transaction<T: FromRow<DbType = T> + RowColumns>(
+    &self,
+    transaction: &'a CachedTransaction<'a>,
+  ) -> Result<Vec<T>, InsertError> {
+    self.returning_many(transaction.client()).await
   }
 
   pub fn sql(&self) -> String {
     let into = self.into;
+    let on_conflict = self.on_conflict_sql();
     if U == 0 {
-      return format!("INSERT INTO {into} DEFAULT VALUES;");
+      return format!("INSERT INTO {into} DEFAULT VALUES{on_conflict};");
     }
 
     let columns = self.columns_sql();
     let values = self.values_sql();
-    format!(r"INSERT INTO {into}({columns}) values {values}")
+    format!(r"INSERT INTO {into}({columns}) values {values}{on_conflict}")
   }
 
-  pub fn returning_sql(&self, column: &'a str) -> String {
+  pub fn returning_sql(&self, column: &str) -> String {
     let into = self.into;
+    let on_conflict = self.on_conflict_sql();
     if U == 0 {
-      return format!("INSERT INTO {into} DEFAULT VALUES RETURNING {column};");
+      return format!("INSERT INTO {into} DEFAULT VALUES{on_conflict} RETURNING {column};");
     }
 
     let columns = self.columns_sql();
     let values = self.values_sql();
-    format!(r"INSERT INTO {into}({columns}) values {values} returning {column};")
+    format!(r"INSERT INTO {into}({columns}) values {values}{on_conflict} returning {column};")
+  }
+
+  fn on_conflict_sql(&self) -> String {
+    match &self.on_conflict {
+      None => String::new(),
+      Some(OnConflict::DoNothing(columns)) if columns.is_empty() => " ON CONFLICT DO NOTHING".to_string(),
+      Some(OnConflict::DoNothing(columns)) => format!(" ON CONFLICT ({}) DO NOTHING", columns.join(",")),
+      // there is nothing to update, an empty SET would not be valid sql
+      Some(OnConflict::DoUpdate(columns, set)) if set.is_empty() => {
+        format!(" ON CONFLICT ({}) DO NOTHING", columns.join(","))
+      }
+      Some(OnConflict::DoUpdate(columns, set)) => {
+        let set = set
+          .iter()
+          .map(|x| format!("{x} = EXCLUDED.{x}"))
+          .collect::<Vec<String>>()
+          .join(",");
+        format!(" ON CONFLICT ({}) DO UPDATE SET {set}", columns.join(","))
+      }
+    }
+  }
+
+  fn row_columns_sql<T: RowColumns>() -> String {
+    T::COLUMNS.iter().map(|x| x.0).collect::<Vec<&str>>().join(",")
   }
 
   fn columns_sql(&self) -> String {
@@ -125,7 +197,7 @@ impl<'a, const U: usize> Insert<'a, U> {
       .join(",")
   }
 
-  fn sql_values(&self) -> Vec<&'a (dyn ToSql + Sync)> {
+  pub(crate) fn sql_values(&self) -> Vec<&'a (dyn ToSql + Sync)> {
     let mut result = vec![];
     self.values.iter().for_each(|x| {
       x.iter().for_each(|x| {
@@ -139,6 +211,7 @@ impl<'a, const U: usize> Insert<'a, U> {
 #[derive(Debug)]
 pub enum InsertError {
   ReturningMoreThanOne,
+  NothingReturned,
   PostgresError(tokio_postgres::Error),
 }
 
@@ -146,9 +219,60 @@ impl Display for InsertError {
   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     match self {
       InsertError::ReturningMoreThanOne => write!(f, "Returning is not supported for more than 1 value at a time"),
+      InsertError::NothingReturned => write!(f, "No row was inserted because of a conflict"),
       InsertError::PostgresError(value) => std::fmt::Display::fmt(&value, f),
     }
   }
 }
 
 impl Error for InsertError {}
+
+#[cfg(test)]
+mod tests {
+  use super::Insert;
+
+  #[test]
+  fn on_conflict_do_nothing_follows_values() {
+    let insert = Insert::new_raw("bookgenre", ["fkbook", "fkgenre"])
+      .values([&1, &2])
+      .values([&1, &3])
+      .on_conflict_do_nothing(&["fkbook", "fkgenre"]);
+    assert_eq!(
+      insert.sql(),
+      "INSERT INTO bookgenre(fkbook,fkgenre) values ($1,$2),($3,$4) ON CONFLICT (fkbook,fkgenre) DO NOTHING"
+    );
+  }
+
+  #[test]
+  fn on_conflict_without_columns_skips_any_conflict() {
+    let insert = Insert::new_raw("bookgenre", ["fkbook", "fkgenre"])
+      .values([&1, &2])
+      .on_conflict_do_nothing(&[]);
+    assert_eq!(
+      insert.returning_sql("fkgenre"),
+      "INSERT INTO bookgenre(fkbook,fkgenre) values ($1,$2) ON CONFLICT DO NOTHING returning fkgenre;"
+    );
+  }
+
+  #[test]
+  fn on_conflict_do_update_sets_excluded_values() {
+    let insert = Insert::new_raw("twofactor", ["fkuser", "secret", "confirmed"])
+      .values([&1, &"secret", &false])
+      .on_conflict_do_update(&["fkuser"], &["secret", "confirmed"]);
+    assert_eq!(
+      insert.sql(),
+      "INSERT INTO twofactor(fkuser,secret,confirmed) values ($1,$2,$3) ON CONFLICT (fkuser) DO UPDATE SET secret = EXCLUDED.secret,confirmed = EXCLUDED.confirmed"
+    );
+  }
+
+  #[test]
+  fn on_conflict_do_update_without_set_does_nothing() {
+    let insert = Insert::new_raw("twofactor", ["fkuser"])
+      .values([&1])
+      .on_conflict_do_update(&["fkuser"], &[]);
+    assert_eq!(
+      insert.sql(),
+      "INSERT INTO twofactor(fkuser) values ($1) ON CONFLICT (fkuser) DO NOTHING"
+    );
+  }
+}
@@ -1,7 +1,8 @@
 use bb8_postgres::bb8::PooledConnection;
-use bb8_postgres::PostgresConnectionManager;
-use tokio_postgres::NoTls;
 
+use crate::cached_connection::CachedConnectionManager;
+
+pub mod cached_connection;
 mod convert_to_sql;
 pub mod delete;
 pub mod enums;
@@ -11,5 +12,10 @@ pub mod macros;
 pub mod repositories;
 pub mod schemas;
 pub mod select;
+#[cfg(test)]
+mod snapshot_tests;
+pub mod statement_cache;
+pub mod types;
+pub mod update;
 
-pub type Pooled<'a> = PooledConnection<'a, PostgresConnectionManager<NoTls>>;
+pub type Pooled<'a> = PooledConnection<'a, CachedConnectionManager>;
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
 use domain::entities::account::{Account, Email};
 use domain::entities::user::User;
@@ -12,20 +11,23 @@ use from_row::Table;
 use repositories::account_repository::AccountRepository;
 use repositories::user_repository::UserRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use crate::schemas::db_account::DbAccount;
+use crate::schemas::db_account_grant::DbAccountGrant;
+use crate::select::conditions::column_equal::ColumnEqual;
 use crate::select::conditions::value_equal::ValueEqual;
 use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultAccountRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   user_repository: Arc<dyn UserRepository + 'a>,
 }
 
 impl<'a> DefaultAccountRepository<'a> {
-  pub fn new(client: &'a Client, user_repository: Arc<dyn UserRepository + 'a>) -> DefaultAccountRepository<'a> {
+  pub fn new(client: &'a CachedClient, user_repository: Arc<dyn UserRepository + 'a>) -> DefaultAccountRepository<'a> {
     DefaultAccountRepository {
       client,
       user_repository,
@@ -43,11 +45,7 @@ impl AccountRepository for DefaultAccountRepository<'_> {
       .expect("Count should return one row");
     let total = total.0 as usize;
 
-    let accounts = Select::new::<DbAccount>()
-      .columns::<DbAccount>(DbAccount::TABLE_NAME)
-      .pagination(pagination)
-      .query(self.client)
-      .await?;
+    let accounts = account_select().pagination(pagination).query(self.client).await?;
 
     Ok(ItemsTotal {
       items: self.to_entities(accounts).await?,
@@ -57,8 +55,7 @@ impl AccountRepository for DefaultAccountRepository<'_> {
 
   async fn get_by_user_id(&self, id: u32) -> Result<Option<Account>, Box<dyn Error>> {
     let id = id as i32;
-    let account = Select::new::<DbAccount>()
-      .columns::<DbAccount>(DbAccount::TABLE_NAME)
+    let account = account_select()
       .where_expression(Expression::new(ValueEqual::new((DbAccount::TABLE_NAME, "fkuser"), id)))
       .get_single(self.client)
       .await?;
@@ -80,8 +77,7 @@ impl AccountRepository for DefaultAccountRepository<'_> {
   async fn get_by_user_ids(&self, ids: &[u32]) -> Result<Vec<Account>, Box<dyn Error>> {
     let ids = to_i32(ids);
 
-    let accounts = Select::new::<DbAccount>()
-      .columns::<DbAccount>(DbAccount::TABLE_NAME)
+    let accounts = account_select()
       .where_expression(Expression::new(ValueIn::new((DbAccount::TABLE_NAME, "fkuser"), &ids)))
       .query(self.client)
       .await?;
@@ -91,8 +87,7 @@ impl AccountRepository for DefaultAccountRepository<'_> {
 
   async fn get_by_email(&self, email: &Email) -> Result<Option<Account>, Box<dyn Error>> {
     let email = &email.0;
-    let account = Select::new::<DbAccount>()
-      .columns::<DbAccount>(DbAccount::TABLE_NAME)
+    let account = account_select()
       .where_expression(Expression::new(ValueEqual::new(
         (DbAccount::TABLE_NAME, "email"),
         email,
@@ -129,12 +124,28 @@ impl AccountRepository for DefaultAccountRepository<'_> {
   }
 }
 
-fn to_entity(account: (DbAccount,), user: User) -> Account {
-  account.0.to_entity(user)
+fn account_select<'a>() -> Select<'a, AccountColumns> {
+  Select::new::<DbAccount>()
+    .columns::<DbAccount>(DbAccount::TABLE_NAME)
+    .columns::<Option<DbAccountGrant>>(DbAccountGrant::TABLE_NAME)
+    .left_join::<DbAccountGrant>(
+      None,
+      Expression::new(ColumnEqual::new(
+        (DbAccountGrant::TABLE_NAME, "fkuser"),
+        (DbAccount::TABLE_NAME, "fkuser"),
+      )),
+    )
+}
+
+type AccountColumns = (DbAccount, Option<DbAccountGrant>);
+
+fn to_entity(account: AccountColumns, user: User) -> Account {
+  let role = account.1.map(|x| x.role.into()).unwrap_or_default();
+  account.0.to_entity(user, role)
 }
 
 impl<'a> DefaultAccountRepository<'a> {
-  async fn to_entities(&self, items: Vec<(DbAccount,)>) -> Result<Vec<Account>, Box<dyn Error>> {
+  async fn to_entities(&self, items: Vec<AccountColumns>) -> Result<Vec<Account>, Box<dyn Error>> {
     let user_ids: Vec<u32> = items.iter().map(|x| x.0.fk_user as u32).collect();
 
     let mut users = match user_ids.is_empty() {
@@ -151,7 +162,7 @@ impl<'a> DefaultAccountRepository<'a> {
             .expect("Associated user should exist");
 
           let user = users.swap_remove(user_index);
-          x.0.to_entity(user)
+          to_entity(x, user)
         })
         .collect(),
     )
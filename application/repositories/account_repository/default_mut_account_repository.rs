@@ -2,25 +2,38 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
+use crate::enums::db_account_role::DbAccountRole;
 use crate::insert::Insert;
 use crate::schemas::db_account::DbAccount;
+use crate::schemas::db_account_grant::DbAccountGrant;
+use crate::schemas::db_email_verification::DbEmailVerification;
+use crate::schemas::db_password_reset::DbPasswordReset;
+use crate::schemas::db_personal_access_token::DbPersonalAccessToken;
+use crate::schemas::db_recovery_code::DbRecoveryCode;
+use crate::schemas::db_refresh_token::DbRefreshToken;
+use crate::schemas::db_two_factor::DbTwoFactor;
+use crate::select::expression::Expression;
+use crate::update::Update;
 use domain::entities::account::create_partial_account::CreatePartialAccount;
-use domain::entities::account::Account;
+use domain::entities::account::{Account, Password};
+use domain::enums::account_role::AccountRole;
+use from_row::Table;
 use repositories::account_repository::mut_account_repository::MutAccountRepository;
 use repositories::account_repository::AccountRepository;
 use repositories::user_repository::UserRepository;
 
 pub struct DefaultMutAccountRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   account_repository: Arc<dyn AccountRepository + 'a>,
   user_repository: Arc<dyn UserRepository + 'a>,
 }
 
 impl<'a> DefaultMutAccountRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     account_repository: Arc<dyn AccountRepository + 'a>,
     user_repository: Arc<dyn UserRepository + 'a>,
   ) -> DefaultMutAccountRepository<'a> {
@@ -49,4 +62,68 @@ impl MutAccountRepository for DefaultMutAccountRepository<'_> {
         .expect("Account was just created, it should exist"),
     )
   }
+
+  async fn update_password(&self, user_id: u32, password: &Password) -> Result<(), Box<dyn Error>> {
+    self
+      .update(user_id, Update::new::<DbAccount>().set("password", &password.0))
+      .await
+  }
+
+  async fn set_verified(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    self
+      .update(user_id, Update::new::<DbAccount>().set("verified", true))
+      .await
+  }
+
+  async fn set_role(&self, user_id: u32, role: AccountRole) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    Delete::new::<DbAccountGrant>(Expression::column_equal(DbAccountGrant::TABLE_NAME, "fkuser", user_id))
+      .execute_transaction(self.transaction)
+      .await?;
+    if role == AccountRole::Member {
+      return Ok(());
+    }
+    let role = DbAccountRole::from(role);
+    Insert::new::<DbAccountGrant>(["fkuser", "role"])
+      .values([&user_id, &role])
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+
+  async fn delete(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    for table in [
+      DbAccountGrant::TABLE_NAME,
+      DbRefreshToken::TABLE_NAME,
+      DbPasswordReset::TABLE_NAME,
+      DbPersonalAccessToken::TABLE_NAME,
+      DbRecoveryCode::TABLE_NAME,
+      DbTwoFactor::TABLE_NAME,
+      DbEmailVerification::TABLE_NAME,
+      DbAccount::TABLE_NAME,
+    ] {
+      Delete::new_raw(table, Expression::column_equal(table, "fkuser", user_id))
+        .execute_transaction(self.transaction)
+        .await?;
+    }
+    Ok(())
+  }
+}
+
+impl DefaultMutAccountRepository<'_> {
+  async fn update(&self, user_id: u32, update: Update<'_>) -> Result<(), Box<dyn Error>> {
+    let updated = update
+      .where_expression(Expression::column_equal(
+        DbAccount::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      ))
+      .execute_transaction(self.transaction)
+      .await?;
+    if updated == 0 {
+      return Err(Box::from("Account to update does not exist"));
+    }
+    Ok(())
+  }
 }
@@ -0,0 +1,34 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::email_verification::EmailVerification;
+use from_row::Table;
+use repositories::account_repository::email_verification_repository::EmailVerificationRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::schemas::db_email_verification::DbEmailVerification;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultEmailVerificationRepository<'a> {
+  client: &'a CachedClient,
+}
+
+impl<'a> DefaultEmailVerificationRepository<'a> {
+  pub const fn new(client: &'a CachedClient) -> DefaultEmailVerificationRepository<'a> {
+    DefaultEmailVerificationRepository { client }
+  }
+}
+
+#[async_trait]
+impl EmailVerificationRepository for DefaultEmailVerificationRepository<'_> {
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<EmailVerification>, Box<dyn Error>> {
+    let verification = Select::new::<DbEmailVerification>()
+      .columns::<DbEmailVerification>(DbEmailVerification::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbEmailVerification::TABLE_NAME, "hash", hash))
+      .get_single(self.client)
+      .await?;
+    Ok(verification.map(|x| x.0.to_entity()))
+  }
+}
@@ -0,0 +1,46 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_email_verification::CreateEmailVerification;
+use from_row::Table;
+use repositories::account_repository::email_verification_repository::mut_email_verification_repository::MutEmailVerificationRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_email_verification::DbEmailVerification;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutEmailVerificationRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutEmailVerificationRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutEmailVerificationRepository<'a> {
+    DefaultMutEmailVerificationRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutEmailVerificationRepository for DefaultMutEmailVerificationRepository<'_> {
+  async fn create(&self, verification: CreateEmailVerification) -> Result<(), Box<dyn Error>> {
+    let user_id = verification.user_id as i32;
+    Insert::new::<DbEmailVerification>(["fkuser", "hash", "expires"])
+      .values([&user_id, &verification.hash, &verification.expires])
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+
+  async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    Delete::new::<DbEmailVerification>(Expression::column_equal(
+      DbEmailVerification::TABLE_NAME,
+      "fkuser",
+      user_id as i32,
+    ))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
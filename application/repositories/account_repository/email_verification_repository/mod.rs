@@ -0,0 +1,2 @@
+pub mod default_email_verification_repository;
+pub mod default_mut_email_verification_repository;
@@ -1,2 +1,7 @@
 pub mod default_account_repository;
 pub mod default_mut_account_repository;
+pub mod email_verification_repository;
+pub mod password_reset_repository;
+pub mod personal_access_token_repository;
+pub mod refresh_token_repository;
+pub mod two_factor_repository;
@@ -0,0 +1,46 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_password_reset::CreatePasswordReset;
+use from_row::Table;
+use repositories::account_repository::password_reset_repository::mut_password_reset_repository::MutPasswordResetRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_password_reset::DbPasswordReset;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutPasswordResetRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutPasswordResetRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutPasswordResetRepository<'a> {
+    DefaultMutPasswordResetRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutPasswordResetRepository for DefaultMutPasswordResetRepository<'_> {
+  async fn create(&self, reset: CreatePasswordReset) -> Result<(), Box<dyn Error>> {
+    let user_id = reset.user_id as i32;
+    Insert::new::<DbPasswordReset>(["fkuser", "hash", "expires"])
+      .values([&user_id, &reset.hash, &reset.expires])
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+
+  async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    Delete::new::<DbPasswordReset>(Expression::column_equal(
+      DbPasswordReset::TABLE_NAME,
+      "fkuser",
+      user_id as i32,
+    ))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
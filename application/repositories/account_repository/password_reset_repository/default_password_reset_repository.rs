@@ -0,0 +1,34 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::password_reset::PasswordReset;
+use from_row::Table;
+use repositories::account_repository::password_reset_repository::PasswordResetRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::schemas::db_password_reset::DbPasswordReset;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultPasswordResetRepository<'a> {
+  client: &'a CachedClient,
+}
+
+impl<'a> DefaultPasswordResetRepository<'a> {
+  pub const fn new(client: &'a CachedClient) -> DefaultPasswordResetRepository<'a> {
+    DefaultPasswordResetRepository { client }
+  }
+}
+
+#[async_trait]
+impl PasswordResetRepository for DefaultPasswordResetRepository<'_> {
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<PasswordReset>, Box<dyn Error>> {
+    let reset = Select::new::<DbPasswordReset>()
+      .columns::<DbPasswordReset>(DbPasswordReset::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbPasswordReset::TABLE_NAME, "hash", hash))
+      .get_single(self.client)
+      .await?;
+    Ok(reset.map(|x| x.0.to_entity()))
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_mut_password_reset_repository;
+pub mod default_password_reset_repository;
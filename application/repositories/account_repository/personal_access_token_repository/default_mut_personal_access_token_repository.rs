@@ -0,0 +1,78 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use domain::entities::account::create_partial_personal_access_token::CreatePartialPersonalAccessToken;
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use from_row::Table;
+use repositories::account_repository::personal_access_token_repository::mut_personal_access_token_repository::MutPersonalAccessTokenRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_personal_access_token::DbPersonalAccessToken;
+use crate::select::expression::Expression;
+use crate::update::Update;
+
+pub struct DefaultMutPersonalAccessTokenRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutPersonalAccessTokenRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutPersonalAccessTokenRepository<'a> {
+    DefaultMutPersonalAccessTokenRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutPersonalAccessTokenRepository for DefaultMutPersonalAccessTokenRepository<'_> {
+  async fn create(&self, token: CreatePartialPersonalAccessToken) -> Result<PersonalAccessToken, Box<dyn Error>> {
+    let user_id = token.user_id as i32;
+    let scopes = token
+      .scopes
+      .iter()
+      .map(ToString::to_string)
+      .collect::<Vec<String>>()
+      .join(" ");
+    let id: i32 = Insert::new::<DbPersonalAccessToken>(["fkuser", "name", "hash", "scopes", "created"])
+      .values([&user_id, &token.name, &token.hash, &scopes, &token.created])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    Ok(PersonalAccessToken {
+      id: id as u32,
+      user_id: token.user_id,
+      name: token.name,
+      scopes: token.scopes,
+      created: token.created,
+      last_used: None,
+    })
+  }
+
+  async fn delete(&self, user_id: u32, id: u32) -> Result<bool, Box<dyn Error>> {
+    let deleted = Delete::new::<DbPersonalAccessToken>(
+      Expression::column_equal(DbPersonalAccessToken::TABLE_NAME, "id", id as i32).and(Expression::column_equal(
+        DbPersonalAccessToken::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(deleted > 0)
+  }
+
+  async fn set_last_used(&self, id: u32) -> Result<(), Box<dyn Error>> {
+    Update::new::<DbPersonalAccessToken>()
+      .set("lastused", Utc::now().naive_utc())
+      .where_expression(Expression::column_equal(
+        DbPersonalAccessToken::TABLE_NAME,
+        "id",
+        id as i32,
+      ))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
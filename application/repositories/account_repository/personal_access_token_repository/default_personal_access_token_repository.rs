@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use from_row::Table;
+use repositories::account_repository::personal_access_token_repository::PersonalAccessTokenRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::schemas::db_personal_access_token::DbPersonalAccessToken;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultPersonalAccessTokenRepository<'a> {
+  client: &'a CachedClient,
+}
+
+impl<'a> DefaultPersonalAccessTokenRepository<'a> {
+  pub const fn new(client: &'a CachedClient) -> DefaultPersonalAccessTokenRepository<'a> {
+    DefaultPersonalAccessTokenRepository { client }
+  }
+}
+
+#[async_trait]
+impl PersonalAccessTokenRepository for DefaultPersonalAccessTokenRepository<'_> {
+  async fn get_by_user(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, Box<dyn Error>> {
+    let tokens = Select::new::<DbPersonalAccessToken>()
+      .columns::<DbPersonalAccessToken>(DbPersonalAccessToken::TABLE_NAME)
+      .where_expression(Expression::column_equal(
+        DbPersonalAccessToken::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      ))
+      .order_by((DbPersonalAccessToken::TABLE_NAME, "id"), Direction::Ascending, None)
+      .query(self.client)
+      .await?;
+    Ok(tokens.into_iter().map(|x| x.0.to_entity()).collect())
+  }
+
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<PersonalAccessToken>, Box<dyn Error>> {
+    let token = Select::new::<DbPersonalAccessToken>()
+      .columns::<DbPersonalAccessToken>(DbPersonalAccessToken::TABLE_NAME)
+      .where_expression(Expression::column_equal(
+        DbPersonalAccessToken::TABLE_NAME,
+        "hash",
+        hash,
+      ))
+      .get_single(self.client)
+      .await?;
+    Ok(token.map(|x| x.0.to_entity()))
+  }
+}
@@ -0,0 +1,89 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_refresh_token::CreateRefreshToken;
+use domain::entities::account::refresh_token::RefreshToken;
+use from_row::Table;
+use repositories::account_repository::refresh_token_repository::mut_refresh_token_repository::MutRefreshTokenRepository;
+use repositories::account_repository::refresh_token_repository::RefreshTokenRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_refresh_token::DbRefreshToken;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::update::Update;
+
+pub struct DefaultMutRefreshTokenRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  refresh_token_repository: Arc<dyn RefreshTokenRepository + 'a>,
+}
+
+impl<'a> DefaultMutRefreshTokenRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepository + 'a>,
+  ) -> DefaultMutRefreshTokenRepository<'a> {
+    DefaultMutRefreshTokenRepository {
+      transaction,
+      refresh_token_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutRefreshTokenRepository for DefaultMutRefreshTokenRepository<'_> {
+  async fn create(&self, token: CreateRefreshToken) -> Result<RefreshToken, Box<dyn Error>> {
+    let user_id = token.user_id as i32;
+    let family = token.family.map(|x| x as i32);
+    let parent = token.parent.map(|x| x as i32);
+    Insert::new::<DbRefreshToken>(["fkuser", "fkfamily", "fkparent", "hash", "expires"])
+      .values([&user_id, &family, &parent, &token.hash, &token.expires])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    Ok(
+      self
+        .refresh_token_repository
+        .get_by_hash(&token.hash)
+        .await?
+        .expect("Refresh token was just created, it should exist"),
+    )
+  }
+
+  async fn mark_used(&self, id: u32) -> Result<bool, Box<dyn Error>> {
+    let updated = Update::new::<DbRefreshToken>()
+      .set("used", true)
+      .where_expression(Expression::column_equal(DbRefreshToken::TABLE_NAME, "id", id as i32))
+      .where_expression(Expression::column_equal(DbRefreshToken::TABLE_NAME, "used", false))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(updated > 0)
+  }
+
+  async fn delete_families(&self, families: &[u32]) -> Result<(), Box<dyn Error>> {
+    let families = to_i32(families);
+    Delete::new::<DbRefreshToken>(Expression::new(ValueIn::new(
+      (DbRefreshToken::TABLE_NAME, "id"),
+      &families,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    Delete::new::<DbRefreshToken>(Expression::column_equal(
+      DbRefreshToken::TABLE_NAME,
+      "fkuser",
+      user_id as i32,
+    ))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
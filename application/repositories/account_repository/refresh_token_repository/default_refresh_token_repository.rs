@@ -0,0 +1,52 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::refresh_token::RefreshToken;
+use from_row::Table;
+use repositories::account_repository::refresh_token_repository::RefreshTokenRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::schemas::db_refresh_token::DbRefreshToken;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultRefreshTokenRepository<'a> {
+  client: &'a CachedClient,
+}
+
+impl<'a> DefaultRefreshTokenRepository<'a> {
+  pub const fn new(client: &'a CachedClient) -> DefaultRefreshTokenRepository<'a> {
+    DefaultRefreshTokenRepository { client }
+  }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for DefaultRefreshTokenRepository<'_> {
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<RefreshToken>, Box<dyn Error>> {
+    let token = Select::new::<DbRefreshToken>()
+      .columns::<DbRefreshToken>(DbRefreshToken::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbRefreshToken::TABLE_NAME, "hash", hash))
+      .get_single(self.client)
+      .await?;
+    Ok(token.map(|x| x.0.to_entity()))
+  }
+
+  async fn get_families(&self, user_id: u32) -> Result<Vec<RefreshToken>, Box<dyn Error>> {
+    let tokens = Select::new::<DbRefreshToken>()
+      .columns::<DbRefreshToken>(DbRefreshToken::TABLE_NAME)
+      .where_expression(Expression::column_equal(
+        DbRefreshToken::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      ))
+      .where_expression(Expression::new(ColumnNull::new((
+        DbRefreshToken::TABLE_NAME,
+        "fkfamily",
+      ))))
+      .query(self.client)
+      .await?;
+    Ok(tokens.into_iter().map(|x| x.0.to_entity()).collect())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_mut_refresh_token_repository;
+pub mod default_refresh_token_repository;
@@ -0,0 +1,105 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_two_factor::CreateTwoFactor;
+use from_row::Table;
+use repositories::account_repository::two_factor_repository::mut_two_factor_repository::MutTwoFactorRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_recovery_code::DbRecoveryCode;
+use crate::schemas::db_two_factor::DbTwoFactor;
+use crate::select::expression::Expression;
+use crate::update::Update;
+
+pub struct DefaultMutTwoFactorRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutTwoFactorRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutTwoFactorRepository<'a> {
+    DefaultMutTwoFactorRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutTwoFactorRepository for DefaultMutTwoFactorRepository<'_> {
+  async fn create(&self, two_factor: CreateTwoFactor) -> Result<(), Box<dyn Error>> {
+    let user_id = two_factor.user_id as i32;
+    Insert::new::<DbTwoFactor>(["fkuser", "secret"])
+      .values([&user_id, &two_factor.secret])
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+
+  async fn confirm(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    self
+      .update(user_id, Update::new::<DbTwoFactor>().set("confirmed", true))
+      .await
+  }
+
+  async fn set_last_step(&self, user_id: u32, step: u64) -> Result<(), Box<dyn Error>> {
+    self
+      .update(user_id, Update::new::<DbTwoFactor>().set("laststep", step as i64))
+      .await
+  }
+
+  async fn delete(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    for table in [DbRecoveryCode::TABLE_NAME, DbTwoFactor::TABLE_NAME] {
+      Delete::new_raw(table, Expression::column_equal(table, "fkuser", user_id))
+        .execute_transaction(self.transaction)
+        .await?;
+    }
+    Ok(())
+  }
+
+  async fn set_recovery_codes(&self, user_id: u32, hashes: &[String]) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    Delete::new::<DbRecoveryCode>(Expression::column_equal(DbRecoveryCode::TABLE_NAME, "fkuser", user_id))
+      .execute_transaction(self.transaction)
+      .await?;
+    if hashes.is_empty() {
+      return Ok(());
+    }
+    let mut insert = Insert::new::<DbRecoveryCode>(["fkuser", "hash"]);
+    for hash in hashes {
+      insert.values_ref([&user_id, hash]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn delete_recovery_code(&self, user_id: u32, hash: &str) -> Result<bool, Box<dyn Error>> {
+    let deleted = Delete::new::<DbRecoveryCode>(
+      Expression::column_equal(DbRecoveryCode::TABLE_NAME, "fkuser", user_id as i32).and(Expression::column_equal(
+        DbRecoveryCode::TABLE_NAME,
+        "hash",
+        hash,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(deleted > 0)
+  }
+}
+
+impl DefaultMutTwoFactorRepository<'_> {
+  async fn update(&self, user_id: u32, update: Update<'_>) -> Result<(), Box<dyn Error>> {
+    let updated = update
+      .where_expression(Expression::column_equal(
+        DbTwoFactor::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      ))
+      .execute_transaction(self.transaction)
+      .await?;
+    if updated == 0 {
+      return Err(Box::from("Second factor to update does not exist"));
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,38 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::two_factor::TwoFactor;
+use from_row::Table;
+use repositories::account_repository::two_factor_repository::TwoFactorRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::schemas::db_two_factor::DbTwoFactor;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultTwoFactorRepository<'a> {
+  client: &'a CachedClient,
+}
+
+impl<'a> DefaultTwoFactorRepository<'a> {
+  pub const fn new(client: &'a CachedClient) -> DefaultTwoFactorRepository<'a> {
+    DefaultTwoFactorRepository { client }
+  }
+}
+
+#[async_trait]
+impl TwoFactorRepository for DefaultTwoFactorRepository<'_> {
+  async fn get(&self, user_id: u32) -> Result<Option<TwoFactor>, Box<dyn Error>> {
+    let two_factor = Select::new::<DbTwoFactor>()
+      .columns::<DbTwoFactor>(DbTwoFactor::TABLE_NAME)
+      .where_expression(Expression::column_equal(
+        DbTwoFactor::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      ))
+      .get_single(self.client)
+      .await?;
+    Ok(two_factor.map(|x| x.0.to_entity()))
+  }
+}
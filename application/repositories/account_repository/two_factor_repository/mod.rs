@@ -0,0 +1,2 @@
+pub mod default_mut_two_factor_repository;
+pub mod default_two_factor_repository;
@@ -1,11 +1,11 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use from_row::Table;
 use repositories::book_repository::book_character_repository::mut_book_character_repository::MutBookCharacterRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::insert::Insert;
@@ -14,11 +14,11 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutBookCharacterRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
 }
 
 impl<'a> DefaultMutBookCharacterRepository<'a> {
-  pub const fn new(transaction: &'a Transaction<'a>) -> DefaultMutBookCharacterRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookCharacterRepository<'a> {
     DefaultMutBookCharacterRepository { transaction }
   }
 }
@@ -28,7 +28,8 @@ impl MutBookCharacterRepository for DefaultMutBookCharacterRepository<'_> {
   async fn add(&self, book_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>> {
     let book_id = book_id as i32;
     let characters = to_i32(characters);
-    let mut insert = Insert::new::<DbBookCharacter>(["fkbook", "fkcharacter"]);
+    let mut insert =
+      Insert::new::<DbBookCharacter>(["fkbook", "fkcharacter"]).on_conflict_do_nothing(&["fkbook", "fkcharacter"]);
     characters.iter().for_each(|x| {
       insert.values_ref([&book_id, x]);
     });
@@ -0,0 +1,78 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use domain::entities::involved::InvolvedId;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use from_row::Table;
+use repositories::book_repository::book_edition_involved_repository::mut_book_edition_involved_repository::MutBookEditionInvolvedRepository;
+
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_book_edition_involved::DbBookEditionInvolved;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutBookEditionInvolvedRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutBookEditionInvolvedRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookEditionInvolvedRepository<'a> {
+    DefaultMutBookEditionInvolvedRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutBookEditionInvolvedRepository for DefaultMutBookEditionInvolvedRepository<'_> {
+  async fn add(&self, edition_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let edition_id = edition_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+    let mut insert = Insert::new::<DbBookEditionInvolved>(["fkbookedition", "fkperson", "fkrole"])
+      .on_conflict_do_nothing(&["fkbookedition", "fkrole", "fkperson"]);
+    involved.iter().for_each(|(x, y)| {
+      insert.values_ref([&edition_id, x, y]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, edition_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let edition_id = edition_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+
+    Delete::new::<DbBookEditionInvolved>(
+      Expression::column_equal(DbBookEditionInvolved::TABLE_NAME, "fkbookedition", edition_id).and(Expression::new(
+        ValueIn::new(
+          (
+            (DbBookEditionInvolved::TABLE_NAME, "fkperson"),
+            (DbBookEditionInvolved::TABLE_NAME, "fkrole"),
+          ),
+          &involved,
+        ),
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, edition_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let edition_ids = to_i32(edition_ids);
+
+    Delete::new::<DbBookEditionInvolved>(Expression::new(ValueIn::new(
+      (DbBookEditionInvolved::TABLE_NAME, "fkbookedition"),
+      &edition_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_book_edition_involved_repository;
+pub mod default_mut_book_edition_involved_repository;
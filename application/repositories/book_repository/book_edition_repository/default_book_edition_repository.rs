@@ -0,0 +1,237 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::enums::language::Language;
+use domain::isbn13::Isbn13;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::{FromRow, Table};
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use repositories::image_repository::ImageRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_book_edition::DbBookEdition;
+use crate::schemas::db_book_edition_translation::DbBookEditionTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultBookEditionRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+}
+
+impl<'a> DefaultBookEditionRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+  ) -> DefaultBookEditionRepository<'a> {
+    DefaultBookEditionRepository {
+      client,
+      default_language: default_language.into(),
+      image_repository,
+    }
+  }
+
+  async fn editions_from_tuple(&self, items: Vec<BookEditionColumns>) -> Result<Vec<BookEdition>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let mut image_ids: Vec<u32> = items.iter().map(|x| x.0.fk_cover as u32).collect();
+    image_ids.sort_unstable();
+    image_ids.dedup();
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+
+    items
+      .into_iter()
+      .map(|item| {
+        let image = images.iter().find(|y| y.id == item.0.fk_cover as u32).unwrap().clone();
+        Ok(item.0.to_entity(item.1.or(item.2), image))
+      })
+      .collect()
+  }
+  async fn edition_from_tuple(&self, item: BookEditionColumns) -> Result<BookEdition, Box<dyn Error>> {
+    let image = self.image_repository.get_by_id(item.0.fk_cover as u32).await?.unwrap();
+    Ok(item.0.to_entity(item.1.or(item.2), image))
+  }
+}
+
+#[async_trait]
+impl BookEditionRepository for DefaultBookEditionRepository<'_> {
+  async fn get(
+    &self,
+    book_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEdition>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbBookEdition>()
+      .count()
+      .where_expression(Expression::column_equal(DbBookEdition::TABLE_NAME, "fkbook", book_id))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let editions = edition_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(DbBookEdition::TABLE_NAME, "fkbook", book_id))
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    let editions = self.editions_from_tuple(editions).await?;
+    Ok(ItemsTotal { items: editions, total })
+  }
+
+  async fn get_by_id(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    language: Language,
+  ) -> Result<Option<BookEdition>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let edition_id = edition_id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let select = edition_select(&db_language, &self.default_language).where_expression(
+      Expression::column_equal(DbBookEdition::TABLE_NAME, "id", edition_id).and(Expression::column_equal(
+        DbBookEdition::TABLE_NAME,
+        "fkbook",
+        book_id,
+      )),
+    );
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.edition_from_tuple(value).await?))
+  }
+
+  async fn get_by_isbn13(&self, isbn13: &Isbn13, language: Language) -> Result<Option<BookEdition>, Box<dyn Error>> {
+    let isbn13 = isbn13.to_string();
+    let db_language = DbLanguage::from(language);
+
+    let select = edition_select(&db_language, &self.default_language).where_expression(Expression::column_equal(
+      DbBookEdition::TABLE_NAME,
+      "isbn13",
+      isbn13,
+    ));
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.edition_from_tuple(value).await?))
+  }
+
+  async fn filter_existing(&self, book_id: u32, edition_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let edition_ids = to_i32(edition_ids);
+
+    let filtered = Select::new::<DbBookEdition>()
+      .column::<i32>(DbBookEdition::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbBookEdition::TABLE_NAME, "id"),
+        &edition_ids,
+      )))
+      .where_expression(Expression::column_equal(DbBookEdition::TABLE_NAME, "fkbook", book_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+
+  async fn filter_existing_isbn13(&self, isbn13: &[Isbn13]) -> Result<Vec<Isbn13>, Box<dyn Error>> {
+    let isbn13: Vec<String> = isbn13.iter().map(ToString::to_string).collect();
+
+    let filtered = Select::new::<DbBookEdition>()
+      .column::<String>(DbBookEdition::TABLE_NAME, "isbn13")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbBookEdition::TABLE_NAME, "isbn13"),
+        &isbn13,
+      )))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| Isbn13::parse(x.0))
+      .collect::<Result<Vec<Isbn13>, _>>()?;
+    Ok(filtered)
+  }
+
+  async fn get_max_progress(&self, book_id: u32) -> Result<(Option<u16>, Option<u16>), Box<dyn Error>> {
+    let book_id = book_id as i32;
+
+    let editions = Select::new::<DbBookEdition>()
+      .columns::<DbBookEdition>(DbBookEdition::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbBookEdition::TABLE_NAME, "fkbook", book_id))
+      .query(self.client)
+      .await?;
+
+    let chapters = editions.iter().filter_map(|x| x.0.chapters).max();
+    let pages = editions.iter().filter_map(|x| x.0.pages).max();
+    Ok((chapters.map(|x| x as u16), pages.map(|x| x as u16)))
+  }
+}
+
+fn edition_select<'a>(language: &'a DbLanguage, fallback_language: &'a DbLanguage) -> Select<'a, BookEditionColumns> {
+  Select::new::<DbBookEdition>()
+    .columns::<DbBookEdition>(DbBookEdition::TABLE_NAME)
+    .columns::<Option<DbBookEditionTranslation>>("book_edition_translation")
+    .columns::<Option<DbBookEditionTranslation>>("book_edition_translation_fallback")
+    .transform(|x| edition_joins(x, language, fallback_language))
+}
+
+fn edition_joins<'a, T: FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbBookEditionTranslation>(
+      Some("book_edition_translation"),
+      Expression::new(ColumnEqual::new(
+        ("book_edition_translation", "fktranslation"),
+        (DbBookEdition::TABLE_NAME, "id"),
+      ))
+      .and(Expression::new(ValueEqual::new(
+        ("book_edition_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbBookEditionTranslation>(
+      Some("book_edition_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("book_edition_translation_fallback", "fktranslation"),
+        (DbBookEdition::TABLE_NAME, "id"),
+      ))
+      .and(Expression::new(ColumnNull::new((
+        "book_edition_translation",
+        "fktranslation",
+      ))))
+      .and(Expression::new(ValueEqual::new(
+        ("book_edition_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+type BookEditionColumns = (
+  DbBookEdition,
+  Option<DbBookEditionTranslation>,
+  Option<DbBookEditionTranslation>,
+);
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::entities::book::create_book_edition::CreateBookEditionTranslation;
+use domain::entities::book::create_partial_book_edition::CreatePartialBookEdition;
+use domain::entities::book::patch_book_edition::PatchBookEdition;
+use domain::enums::language::Language;
+use from_row::Table;
+use repositories::book_repository::book_edition_involved_repository::mut_book_edition_involved_repository::MutBookEditionInvolvedRepository;
+use repositories::book_repository::book_edition_repository::mut_book_edition_repository::MutBookEditionRepository;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_book_edition::DbBookEdition;
+use crate::schemas::db_book_edition_translation::DbBookEditionTranslation;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+use crate::update::Update;
+
+pub struct DefaultMutBookEditionRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  mut_book_edition_involved_repository: Arc<dyn MutBookEditionInvolvedRepository + 'a>,
+}
+
+impl<'a> DefaultMutBookEditionRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    default_language: Language,
+    book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+    mut_book_edition_involved_repository: Arc<dyn MutBookEditionInvolvedRepository + 'a>,
+  ) -> DefaultMutBookEditionRepository<'a> {
+    DefaultMutBookEditionRepository {
+      transaction,
+      default_language,
+      book_edition_repository,
+      mut_book_edition_involved_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutBookEditionRepository for DefaultMutBookEditionRepository<'_> {
+  async fn create(&self, book_id: u32, item: CreatePartialBookEdition) -> Result<BookEdition, Box<dyn Error>> {
+    let id = self.insert_edition(&item, book_id).await? as u32;
+    self.insert_translation(&item, id).await?;
+    if !item.involved.is_empty() {
+      self
+        .mut_book_edition_involved_repository
+        .add(id, &item.involved)
+        .await?;
+    }
+
+    let edition = self
+      .book_edition_repository
+      .get_by_id(book_id, id, self.default_language)
+      .await?
+      .expect("Edition was just created");
+    Ok(edition)
+  }
+
+  async fn update(&self, book_id: u32, id: u32, item: &PatchBookEdition) -> Result<BookEdition, Box<dyn Error>> {
+    self.update_edition(id, item).await?;
+    if let Some(translations) = &item.translations {
+      self.upsert_translations(id, translations).await?;
+    }
+
+    Ok(
+      self
+        .book_edition_repository
+        .get_by_id(book_id, id, self.default_language)
+        .await?
+        .ok_or("Edition to update does not exist")?,
+    )
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.mut_book_edition_involved_repository.remove_all(ids).await?;
+    let ids = to_i32(ids);
+
+    Delete::new::<DbBookEditionTranslation>(Expression::new(ValueIn::new(
+      (DbBookEditionTranslation::TABLE_NAME, "fktranslation"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbBookEdition>(Expression::new(ValueIn::new((DbBookEdition::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, book_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let book_ids = to_i32(book_ids);
+    let edition_ids: Vec<u32> = Select::new::<DbBookEdition>()
+      .column::<i32>(DbBookEdition::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbBookEdition::TABLE_NAME, "fkbook"),
+        &book_ids,
+      )))
+      .query(self.transaction.client())
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    if edition_ids.is_empty() {
+      return Ok(());
+    }
+    self.delete(&edition_ids).await
+  }
+}
+
+impl DefaultMutBookEditionRepository<'_> {
+  async fn insert_edition(&self, item: &CreatePartialBookEdition, book_id: u32) -> Result<i32, Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let chapters = item.chapters.map(|x| x as i16);
+    let pages = item.pages.map(|x| x as i16);
+    let words = item.words.map(|x| x as i32);
+    let isbn13 = item.isbn13.as_ref().map(ToString::to_string);
+    let language = item.language.map(DbLanguage::from);
+    let cover_id = item.cover.id as i32;
+    let edition_id: i32 = Insert::new::<DbBookEdition>([
+      "chapters",
+      "pages",
+      "words",
+      "published",
+      "isbn13",
+      "language",
+      "fkcover",
+      "fkbook",
+    ])
+    .values([
+      &chapters,
+      &pages,
+      &words,
+      &item.published,
+      &isbn13,
+      &language,
+      &cover_id,
+      &book_id,
+    ])
+    .returning_transaction("id", self.transaction)
+    .await?;
+    Ok(edition_id)
+  }
+
+  async fn insert_translation(&self, item: &CreatePartialBookEdition, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.translations.is_empty() {
+      return Ok(());
+    }
+    let id = id as i32;
+    let mapped: Vec<(&String, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| (&x.1.description, DbLanguage::from(*x.0)))
+      .collect();
+    let mut insert = Insert::new::<DbBookEditionTranslation>(["description", "fktranslation", "language"]);
+    for (description, language) in &mapped {
+      insert.values_ref([*description, &id, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn update_edition(&self, id: u32, item: &PatchBookEdition) -> Result<(), Box<dyn Error>> {
+    if item.chapters.is_none()
+      && item.pages.is_none()
+      && item.words.is_none()
+      && item.published.is_none()
+      && item.isbn13.is_none()
+      && item.language.is_none()
+    {
+      return Ok(());
+    }
+    let mut update = Update::new::<DbBookEdition>();
+    if let Some(chapters) = item.chapters {
+      update = update.set("chapters", chapters as i16);
+    }
+    if let Some(pages) = item.pages {
+      update = update.set("pages", pages as i16);
+    }
+    if let Some(words) = item.words {
+      update = update.set("words", words as i32);
+    }
+    if let Some(published) = item.published {
+      update = update.set("published", published);
+    }
+    if let Some(isbn13) = &item.isbn13 {
+      update = update.set("isbn13", isbn13.to_string());
+    }
+    if let Some(language) = item.language {
+      update = update.set("language", DbLanguage::from(language));
+    }
+    update
+      .where_expression(Expression::column_equal(DbBookEdition::TABLE_NAME, "id", id as i32))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+
+  async fn upsert_translations(
+    &self,
+    id: u32,
+    translations: &HashMap<Language, CreateBookEditionTranslation>,
+  ) -> Result<(), Box<dyn Error>> {
+    if translations.is_empty() {
+      return Ok(());
+    }
+    let id = id as i32;
+    let mapped: Vec<(&String, DbLanguage)> = translations
+      .iter()
+      .map(|x| (&x.1.description, DbLanguage::from(*x.0)))
+      .collect();
+    let mut insert = Insert::new::<DbBookEditionTranslation>(["description", "fktranslation", "language"])
+      .on_conflict_do_update(&["fktranslation", "language"], &["description"]);
+    for (description, language) in &mapped {
+      insert.values_ref([*description, &id, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+}
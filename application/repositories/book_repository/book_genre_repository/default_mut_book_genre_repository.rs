@@ -1,11 +1,11 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use from_row::Table;
 use repositories::book_repository::book_genre_repository::mut_book_genre_repository::MutBookGenreRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::insert::Insert;
@@ -14,11 +14,11 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutBookGenreRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
 }
 
 impl<'a> DefaultMutBookGenreRepository<'a> {
-  pub const fn new(transaction: &'a Transaction<'a>) -> DefaultMutBookGenreRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookGenreRepository<'a> {
     DefaultMutBookGenreRepository { transaction }
   }
 }
@@ -28,7 +28,7 @@ impl MutBookGenreRepository for DefaultMutBookGenreRepository<'_> {
   async fn add(&self, book_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
     let book_id = book_id as i32;
     let genres = to_i32(genres);
-    let mut insert = Insert::new::<DbBookGenre>(["fkbook", "fkgenre"]);
+    let mut insert = Insert::new::<DbBookGenre>(["fkbook", "fkgenre"]).on_conflict_do_nothing(&["fkbook", "fkgenre"]);
     genres.iter().for_each(|x| {
       insert.values_ref([&book_id, x]);
     });
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::book::book_image::BookImage;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::book_repository::book_image_repository::BookImageRepository;
+use repositories::image_repository::ImageRepository;
+
+use crate::schemas::db_book_image::DbBookImage;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultBookImageRepository<'a> {
+  client: &'a CachedClient,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+}
+
+impl<'a> DefaultBookImageRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+  ) -> DefaultBookImageRepository<'a> {
+    DefaultBookImageRepository {
+      client,
+      image_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl BookImageRepository for DefaultBookImageRepository<'_> {
+  async fn get(&self, book_id: u32, pagination: Pagination) -> Result<ItemsTotal<BookImage>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+
+    let total = Select::new::<DbBookImage>()
+      .count()
+      .where_expression(Expression::column_equal(DbBookImage::TABLE_NAME, "fkbook", book_id))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let book_images = Select::new::<DbBookImage>()
+      .columns::<DbBookImage>(DbBookImage::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbBookImage::TABLE_NAME, "fkbook", book_id))
+      .order_by((DbBookImage::TABLE_NAME, "position"), Direction::Ascending, None)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    if book_images.is_empty() {
+      return Ok(ItemsTotal { items: vec![], total });
+    }
+
+    let image_ids: Vec<u32> = book_images.iter().map(|x| x.0.fk_image as u32).collect();
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+    let items = book_images
+      .into_iter()
+      .map(|(book_image,)| {
+        let image = images
+          .iter()
+          .find(|y| y.id == book_image.fk_image as u32)
+          .unwrap()
+          .clone();
+        book_image.to_entity(image)
+      })
+      .collect();
+
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn get_ids(&self, book_id: u32) -> Result<Vec<u32>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+
+    let ids = Select::new::<DbBookImage>()
+      .column::<i32>(DbBookImage::TABLE_NAME, "fkimage")
+      .where_expression(Expression::column_equal(DbBookImage::TABLE_NAME, "fkbook", book_id))
+      .order_by((DbBookImage::TABLE_NAME, "position"), Direction::Ascending, None)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(ids)
+  }
+
+  async fn filter_existing(&self, book_id: u32, images: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let images = to_i32(images);
+
+    let filtered = Select::new::<DbBookImage>()
+      .column::<i32>(DbBookImage::TABLE_NAME, "fkimage")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbBookImage::TABLE_NAME, "fkimage"),
+        &images,
+      )))
+      .where_expression(Expression::column_equal(DbBookImage::TABLE_NAME, "fkbook", book_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
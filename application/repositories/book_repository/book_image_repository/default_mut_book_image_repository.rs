@@ -0,0 +1,104 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use from_row::Table;
+use repositories::book_repository::book_image_repository::mut_book_image_repository::MutBookImageRepository;
+
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_book_image::DbBookImage;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultMutBookImageRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutBookImageRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookImageRepository<'a> {
+    DefaultMutBookImageRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutBookImageRepository for DefaultMutBookImageRepository<'_> {
+  async fn add(&self, book_id: u32, images: &[u32]) -> Result<(), Box<dyn Error>> {
+    let existing = self.image_ids(book_id).await?;
+    self.insert(book_id, images, existing.len()).await
+  }
+
+  async fn remove(&self, book_id: u32, images: &[u32]) -> Result<(), Box<dyn Error>> {
+    let remaining: Vec<u32> = self
+      .image_ids(book_id)
+      .await?
+      .into_iter()
+      .filter(|x| !images.contains(x))
+      .collect();
+    self.reorder(book_id, &remaining).await
+  }
+
+  async fn reorder(&self, book_id: u32, images: &[u32]) -> Result<(), Box<dyn Error>> {
+    Delete::new::<DbBookImage>(Expression::column_equal(
+      DbBookImage::TABLE_NAME,
+      "fkbook",
+      book_id as i32,
+    ))
+    .execute_transaction(self.transaction)
+    .await?;
+    self.insert(book_id, images, 0).await
+  }
+
+  async fn remove_all(&self, book_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let book_ids = to_i32(book_ids);
+
+    Delete::new::<DbBookImage>(Expression::new(ValueIn::new(
+      (DbBookImage::TABLE_NAME, "fkbook"),
+      &book_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
+
+impl DefaultMutBookImageRepository<'_> {
+  async fn image_ids(&self, book_id: u32) -> Result<Vec<u32>, Box<dyn Error>> {
+    let ids = Select::new::<DbBookImage>()
+      .column::<i32>(DbBookImage::TABLE_NAME, "fkimage")
+      .where_expression(Expression::column_equal(
+        DbBookImage::TABLE_NAME,
+        "fkbook",
+        book_id as i32,
+      ))
+      .order_by((DbBookImage::TABLE_NAME, "position"), Direction::Ascending, None)
+      .query(self.transaction.client())
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(ids)
+  }
+
+  async fn insert(&self, book_id: u32, images: &[u32], offset: usize) -> Result<(), Box<dyn Error>> {
+    if images.is_empty() {
+      return Ok(());
+    }
+    let book_id = book_id as i32;
+    let values: Vec<(i32, i16)> = images
+      .iter()
+      .enumerate()
+      .map(|(index, image_id)| (*image_id as i32, (offset + index) as i16))
+      .collect();
+    let mut insert = Insert::new::<DbBookImage>(["fkbook", "fkimage", "position"]);
+    values.iter().for_each(|(image_id, position)| {
+      insert.values_ref([&book_id, image_id, position]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_book_image_repository;
+pub mod default_mut_book_image_repository;
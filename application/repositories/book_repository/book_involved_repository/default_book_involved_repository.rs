@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
 use domain::entities::book::book_involved::BookInvolved;
 use domain::entities::involved::InvolvedId;
@@ -16,6 +15,7 @@ use repositories::book_repository::BookRepository;
 use repositories::person_repository::PersonRepository;
 use repositories::role_repository::RoleRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::enums::db_language::DbLanguage;
 use crate::schemas::db_book_involved::DbBookInvolved;
 use crate::schemas::db_role::DbRole;
@@ -29,7 +29,7 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultBookInvolvedRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   default_language: DbLanguage,
   book_repository: Arc<dyn BookRepository + 'a>,
   person_repository: Arc<dyn PersonRepository + 'a>,
@@ -38,7 +38,7 @@ pub struct DefaultBookInvolvedRepository<'a> {
 
 impl<'a> DefaultBookInvolvedRepository<'a> {
   pub fn new(
-    client: &'a Client,
+    client: &'a CachedClient,
     default_language: Language,
     book_repository: Arc<dyn BookRepository + 'a>,
     person_repository: Arc<dyn PersonRepository + 'a>,
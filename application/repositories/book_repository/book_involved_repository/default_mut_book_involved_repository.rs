@@ -2,8 +2,8 @@ use std::error::Error;
 
 use async_trait::async_trait;
 use domain::entities::involved::InvolvedId;
-use tokio_postgres::Transaction;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use from_row::Table;
 use repositories::book_repository::book_involved_repository::mut_book_involved_repository::MutBookInvolvedRepository;
@@ -15,11 +15,11 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutBookInvolvedRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
 }
 
 impl<'a> DefaultMutBookInvolvedRepository<'a> {
-  pub const fn new(transaction: &'a Transaction<'a>) -> DefaultMutBookInvolvedRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookInvolvedRepository<'a> {
     DefaultMutBookInvolvedRepository { transaction }
   }
 }
@@ -32,7 +32,8 @@ impl MutBookInvolvedRepository for DefaultMutBookInvolvedRepository<'_> {
       .iter()
       .map(|x| (x.person_id as i32, x.role_id as i32))
       .collect();
-    let mut insert = Insert::new::<DbBookInvolved>(["fkbook", "fkperson", "fkrole"]);
+    let mut insert = Insert::new::<DbBookInvolved>(["fkbook", "fkperson", "fkrole"])
+      .on_conflict_do_nothing(&["fkbook", "fkperson", "fkrole"]);
     involved.iter().for_each(|(x, y)| {
       insert.values_ref([&book_id, x, y]);
     });
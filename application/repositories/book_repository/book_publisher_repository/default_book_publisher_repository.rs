@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::book_repository::book_publisher_repository::BookPublisherRepository;
+use repositories::company_repository::CompanyRepository;
+
+use crate::schemas::db_book_publisher::DbBookPublisher;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultBookPublisherRepository<'a> {
+  client: &'a CachedClient,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultBookPublisherRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+  ) -> DefaultBookPublisherRepository<'a> {
+    DefaultBookPublisherRepository {
+      client,
+      company_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl BookPublisherRepository for DefaultBookPublisherRepository<'_> {
+  async fn get(&self, book_id: u32, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+
+    let total = Select::new::<DbBookPublisher>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbBookPublisher::TABLE_NAME, "fkbook"),
+        book_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let publisher_ids: Vec<u32> = Select::new::<DbBookPublisher>()
+      .column::<i32>(DbBookPublisher::TABLE_NAME, "fkpublisher")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbBookPublisher::TABLE_NAME, "fkbook"),
+        book_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match publisher_ids.is_empty() {
+      true => vec![],
+      false => self.company_repository.get_by_ids(&publisher_ids).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, book_id: u32, publishers: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let publishers = to_i32(publishers);
+
+    let filtered = Select::new::<DbBookPublisher>()
+      .column::<i32>(DbBookPublisher::TABLE_NAME, "fkpublisher")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbBookPublisher::TABLE_NAME, "fkpublisher"),
+        &publishers,
+      )))
+      .where_expression(Expression::column_equal(DbBookPublisher::TABLE_NAME, "fkbook", book_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::book_repository::book_publisher_repository::mut_book_publisher_repository::MutBookPublisherRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_book_publisher::DbBookPublisher;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutBookPublisherRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutBookPublisherRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookPublisherRepository<'a> {
+    DefaultMutBookPublisherRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutBookPublisherRepository for DefaultMutBookPublisherRepository<'_> {
+  async fn add(&self, book_id: u32, publishers: &[u32]) -> Result<(), Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let publishers = to_i32(publishers);
+    let mut insert =
+      Insert::new::<DbBookPublisher>(["fkbook", "fkpublisher"]).on_conflict_do_nothing(&["fkbook", "fkpublisher"]);
+    publishers.iter().for_each(|x| {
+      insert.values_ref([&book_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, book_id: u32, publishers: &[u32]) -> Result<(), Box<dyn Error>> {
+    let book_id = book_id as i32;
+    let publishers = to_i32(publishers);
+
+    Delete::new::<DbBookPublisher>(
+      Expression::column_equal(DbBookPublisher::TABLE_NAME, "fkbook", book_id).and(Expression::new(ValueIn::new(
+        (DbBookPublisher::TABLE_NAME, "fkpublisher"),
+        &publishers,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+  async fn remove_all(&self, book_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let book_ids = to_i32(book_ids);
+
+    Delete::new::<DbBookPublisher>(Expression::new(ValueIn::new(
+      (DbBookPublisher::TABLE_NAME, "fkbook"),
+      &book_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -2,8 +2,8 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use domain::entities::theme::Theme;
 use domain::enums::language::Language;
@@ -22,7 +22,7 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultBookThemeRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   default_language: DbLanguage,
   book_repository: Arc<dyn BookRepository + 'a>,
   theme_repository: Arc<dyn ThemeRepository + 'a>,
@@ -30,7 +30,7 @@ pub struct DefaultBookThemeRepository<'a> {
 
 impl<'a> DefaultBookThemeRepository<'a> {
   pub fn new(
-    client: &'a Client,
+    client: &'a CachedClient,
     default_language: Language,
     book_repository: Arc<dyn BookRepository + 'a>,
     theme_repository: Arc<dyn ThemeRepository + 'a>,
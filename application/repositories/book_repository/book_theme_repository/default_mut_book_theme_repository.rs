@@ -1,11 +1,11 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use from_row::Table;
 use repositories::book_repository::book_theme_repository::mut_book_theme_repository::MutBookThemeRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::insert::Insert;
@@ -14,11 +14,11 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutBookThemeRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
 }
 
 impl<'a> DefaultMutBookThemeRepository<'a> {
-  pub const fn new(transaction: &'a Transaction<'a>) -> DefaultMutBookThemeRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutBookThemeRepository<'a> {
     DefaultMutBookThemeRepository { transaction }
   }
 }
@@ -28,7 +28,7 @@ impl MutBookThemeRepository for DefaultMutBookThemeRepository<'_> {
   async fn add(&self, book_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
     let book_id = book_id as i32;
     let themes = to_i32(themes);
-    let mut insert = Insert::new::<DbBookTheme>(["fkbook", "fktheme"]);
+    let mut insert = Insert::new::<DbBookTheme>(["fkbook", "fktheme"]).on_conflict_do_nothing(&["fkbook", "fktheme"]);
     themes.iter().for_each(|x| {
       insert.values_ref([&book_id, x]);
     });
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
 use domain::entities::book::book_statistic::BookStatistic;
 use domain::entities::book::Book;
@@ -15,6 +14,7 @@ use repositories::book_repository::BookRepository;
 use repositories::franchise_repository::FranchiseRepository;
 use repositories::image_repository::ImageRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use crate::enums::db_language::DbLanguage;
 use crate::fallback_unwrap::{fallback_unwrap, fallback_unwrap_ref};
@@ -32,7 +32,7 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultBookRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   default_language: DbLanguage,
   image_repository: Arc<dyn ImageRepository + 'a>,
   franchise_repository: Arc<dyn FranchiseRepository + 'a>,
@@ -40,7 +40,7 @@ pub struct DefaultBookRepository<'a> {
 
 impl<'a> DefaultBookRepository<'a> {
   pub fn new(
-    client: &'a Client,
+    client: &'a CachedClient,
     default_language: Language,
     image_repository: Arc<dyn ImageRepository + 'a>,
     franchise_repository: Arc<dyn FranchiseRepository + 'a>,
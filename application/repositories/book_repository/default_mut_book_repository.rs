@@ -2,19 +2,23 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::book::create_partial_book::CreatePartialBook;
 use domain::entities::book::Book;
 use domain::enums::language::Language;
 use from_row::Table;
 use repositories::book_repository::book_character_repository::mut_book_character_repository::MutBookCharacterRepository;
+use repositories::book_repository::book_edition_repository::mut_book_edition_repository::MutBookEditionRepository;
 use repositories::book_repository::book_genre_repository::mut_book_genre_repository::MutBookGenreRepository;
+use repositories::book_repository::book_image_repository::mut_book_image_repository::MutBookImageRepository;
 use repositories::book_repository::book_involved_repository::mut_book_involved_repository::MutBookInvolvedRepository;
+use repositories::book_repository::book_publisher_repository::mut_book_publisher_repository::MutBookPublisherRepository;
 use repositories::book_repository::book_theme_repository::mut_book_theme_repository::MutBookThemeRepository;
 use repositories::book_repository::mut_book_repository::MutBookRepository;
 use repositories::book_repository::BookRepository;
+use repositories::user_repository::user_book_repository::mut_user_book_repository::MutUserBookRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::enums::db_language::DbLanguage;
@@ -28,23 +32,31 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultMutBookRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   mut_book_genre_repository: Arc<dyn MutBookGenreRepository + 'a>,
   mut_book_character_repository: Arc<dyn MutBookCharacterRepository + 'a>,
   mut_book_theme_repository: Arc<dyn MutBookThemeRepository + 'a>,
   mut_book_involved_repository: Arc<dyn MutBookInvolvedRepository + 'a>,
+  mut_book_edition_repository: Arc<dyn MutBookEditionRepository + 'a>,
+  mut_book_image_repository: Arc<dyn MutBookImageRepository + 'a>,
+  mut_book_publisher_repository: Arc<dyn MutBookPublisherRepository + 'a>,
+  mut_user_book_repository: Arc<dyn MutUserBookRepository + 'a>,
   book_repository: Arc<dyn BookRepository + 'a>,
 }
 
 impl<'a> DefaultMutBookRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     default_language: Language,
     mut_book_genre_repository: Arc<dyn MutBookGenreRepository + 'a>,
     mut_book_character_repository: Arc<dyn MutBookCharacterRepository + 'a>,
     mut_book_theme_repository: Arc<dyn MutBookThemeRepository + 'a>,
     mut_book_involved_repository: Arc<dyn MutBookInvolvedRepository + 'a>,
+    mut_book_edition_repository: Arc<dyn MutBookEditionRepository + 'a>,
+    mut_book_image_repository: Arc<dyn MutBookImageRepository + 'a>,
+    mut_book_publisher_repository: Arc<dyn MutBookPublisherRepository + 'a>,
+    mut_user_book_repository: Arc<dyn MutUserBookRepository + 'a>,
     book_repository: Arc<dyn BookRepository + 'a>,
   ) -> DefaultMutBookRepository<'a> {
     DefaultMutBookRepository {
@@ -54,6 +66,10 @@ impl<'a> DefaultMutBookRepository<'a> {
       mut_book_character_repository,
       mut_book_theme_repository,
       mut_book_involved_repository,
+      mut_book_edition_repository,
+      mut_book_image_repository,
+      mut_book_publisher_repository,
+      mut_user_book_repository,
       book_repository,
     }
   }
@@ -68,6 +84,8 @@ impl MutBookRepository for DefaultMutBookRepository<'_> {
     self.insert_themes(&item, id).await?;
     self.insert_genres(&item, id).await?;
     self.insert_involved(&item, id).await?;
+    self.insert_images(&item, id).await?;
+    self.insert_editions(item, id).await?;
 
     let book = self
       .book_repository
@@ -82,6 +100,10 @@ impl MutBookRepository for DefaultMutBookRepository<'_> {
     self.mut_book_genre_repository.remove_all(ids).await?;
     self.mut_book_involved_repository.remove_all(ids).await?;
     self.mut_book_theme_repository.remove_all(ids).await?;
+    self.mut_book_edition_repository.remove_all(ids).await?;
+    self.mut_book_image_repository.remove_all(ids).await?;
+    self.mut_book_publisher_repository.remove_all(ids).await?;
+    self.mut_user_book_repository.remove_all(ids).await?;
     let ids = to_i32(ids);
 
     Delete::new::<DbBookTranslation>(Expression::new(ValueIn::new(
@@ -99,6 +121,21 @@ impl MutBookRepository for DefaultMutBookRepository<'_> {
 }
 
 impl DefaultMutBookRepository<'_> {
+  async fn insert_editions(&self, item: CreatePartialBook, id: u32) -> Result<(), Box<dyn Error>> {
+    for edition in item.editions {
+      self.mut_book_edition_repository.create(id, edition).await?;
+    }
+    Ok(())
+  }
+
+  async fn insert_images(&self, item: &CreatePartialBook, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.images.is_empty() {
+      return Ok(());
+    }
+    let images: Vec<u32> = item.images.iter().map(|x| x.id).collect();
+    self.mut_book_image_repository.add(id, &images).await
+  }
+
   async fn insert_involved(&self, item: &CreatePartialBook, id: u32) -> Result<(), Box<dyn Error>> {
     if item.involved.is_empty() {
       return Ok(());
@@ -1,6 +1,10 @@
 pub mod book_character_repository;
+pub mod book_edition_involved_repository;
+pub mod book_edition_repository;
 pub mod book_genre_repository;
+pub mod book_image_repository;
 pub mod book_involved_repository;
+pub mod book_publisher_repository;
 pub mod book_theme_repository;
 pub mod default_book_repository;
 pub mod default_mut_book_repository;
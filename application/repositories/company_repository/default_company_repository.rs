@@ -0,0 +1,144 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::company_repository::CompanyRepository;
+use repositories::image_repository::ImageRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::schemas::db_company::DbCompany;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultCompanyRepository<'a> {
+  client: &'a CachedClient,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+}
+
+impl<'a> DefaultCompanyRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+  ) -> DefaultCompanyRepository<'a> {
+    DefaultCompanyRepository {
+      client,
+      image_repository,
+    }
+  }
+
+  async fn to_entities(&self, items: Vec<(DbCompany,)>) -> Result<Vec<Company>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+    let mut image_ids: Vec<u32> = items.iter().map(|x| x.0.fk_logo as u32).collect();
+    image_ids.sort_unstable();
+    image_ids.dedup();
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+
+    Ok(
+      items
+        .into_iter()
+        .map(|x| {
+          let logo = images
+            .iter()
+            .find(|y| y.id == x.0.fk_logo as u32)
+            .expect("Company logo should exist")
+            .clone();
+          x.0.to_entity(logo)
+        })
+        .collect(),
+    )
+  }
+}
+
+#[async_trait]
+impl CompanyRepository for DefaultCompanyRepository<'_> {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>> {
+    let total = Select::new::<DbCompany>()
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let companies = Select::new::<DbCompany>()
+      .columns::<DbCompany>(DbCompany::TABLE_NAME)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    Ok(ItemsTotal {
+      items: self.to_entities(companies).await?,
+      total,
+    })
+  }
+
+  async fn get_by_id(&self, id: u32) -> Result<Option<Company>, Box<dyn Error>> {
+    let id = id as i32;
+    let company = Select::new::<DbCompany>()
+      .columns::<DbCompany>(DbCompany::TABLE_NAME)
+      .where_expression(Expression::new(ValueEqual::new((DbCompany::TABLE_NAME, "id"), id)))
+      .get_single(self.client)
+      .await?;
+    Ok(self.to_entities(company.into_iter().collect()).await?.pop())
+  }
+
+  async fn get_by_ids(&self, ids: &[u32]) -> Result<Vec<Company>, Box<dyn Error>> {
+    let ids = to_i32(ids);
+
+    let companies = Select::new::<DbCompany>()
+      .columns::<DbCompany>(DbCompany::TABLE_NAME)
+      .where_expression(Expression::new(ValueIn::new((DbCompany::TABLE_NAME, "id"), &ids)))
+      .query(self.client)
+      .await?;
+
+    self.to_entities(companies).await
+  }
+
+  async fn get_by_name(&self, name: &str, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>> {
+    let name = format!("%{name}%");
+
+    let total = Select::new::<DbCompany>()
+      .count()
+      .where_expression(Expression::new(ValueILike::new((DbCompany::TABLE_NAME, "name"), &name)))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let companies = Select::new::<DbCompany>()
+      .columns::<DbCompany>(DbCompany::TABLE_NAME)
+      .where_expression(Expression::new(ValueILike::new((DbCompany::TABLE_NAME, "name"), &name)))
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    Ok(ItemsTotal {
+      items: self.to_entities(companies).await?,
+      total,
+    })
+  }
+
+  async fn filter_existing(&self, companies: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let companies = to_i32(companies);
+
+    let filtered = Select::new::<DbCompany>()
+      .column::<i32>(DbCompany::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new((DbCompany::TABLE_NAME, "id"), &companies)))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
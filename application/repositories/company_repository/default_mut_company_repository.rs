@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::company::create_partial_company::CreatePartialCompany;
+use domain::entities::company::Company;
+use from_row::Table;
+use repositories::company_repository::mut_company_repository::MutCompanyRepository;
+use repositories::company_repository::CompanyRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_book_publisher::DbBookPublisher;
+use crate::schemas::db_company::DbCompany;
+use crate::schemas::db_graphic_novel_publisher::DbGraphicNovelPublisher;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutCompanyRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultMutCompanyRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+  ) -> DefaultMutCompanyRepository<'a> {
+    DefaultMutCompanyRepository {
+      transaction,
+      company_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutCompanyRepository for DefaultMutCompanyRepository<'_> {
+  async fn create(&self, item: CreatePartialCompany) -> Result<Company, Box<dyn Error>> {
+    let logo_id = item.logo.id as i32;
+    let id: i32 = Insert::new::<DbCompany>(["name", "fklogo"])
+      .values([&item.name, &logo_id])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let company = self
+      .company_repository
+      .get_by_id(id as u32)
+      .await?
+      .expect("Company was just created");
+    Ok(company)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let ids = to_i32(ids);
+
+    Delete::new::<DbBookPublisher>(Expression::new(ValueIn::new(
+      (DbBookPublisher::TABLE_NAME, "fkpublisher"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelPublisher>(Expression::new(ValueIn::new(
+      (DbGraphicNovelPublisher::TABLE_NAME, "fkpublisher"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbCompany>(Expression::new(ValueIn::new((DbCompany::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
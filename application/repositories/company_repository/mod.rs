@@ -0,0 +1,2 @@
+pub mod default_company_repository;
+pub mod default_mut_company_repository;
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
 use domain::entities::character::Character;
 use domain::entities::image::Image;
@@ -13,6 +12,7 @@ use from_row::{FromRow, Table};
 use repositories::character_repository::CharacterRepository;
 use repositories::image_repository::ImageRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use crate::enums::db_language::DbLanguage;
 use crate::fallback_unwrap::fallback_unwrap;
@@ -28,14 +28,14 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultCharacterRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   default_language: DbLanguage,
   image_repository: Arc<dyn ImageRepository + 'a>,
 }
 
 impl<'a> DefaultCharacterRepository<'a> {
   pub fn new(
-    client: &'a Client,
+    client: &'a CachedClient,
     language: Language,
     image_repository: Arc<dyn ImageRepository + 'a>,
   ) -> DefaultCharacterRepository<'a> {
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::franchise::create_partial_franchise::CreatePartialFranchise;
 use domain::entities::franchise::Franchise;
@@ -11,6 +10,7 @@ use from_row::Table;
 use repositories::franchise_repository::mut_franchise_repository::MutFranchiseRepository;
 use repositories::franchise_repository::FranchiseRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::enums::db_language::DbLanguage;
@@ -21,14 +21,14 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutFranchiseRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   franchise_repository: Arc<dyn FranchiseRepository + 'a>,
 }
 
 impl<'a> DefaultMutFranchiseRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     default_language: Language,
     franchise_repository: Arc<dyn FranchiseRepository + 'a>,
   ) -> DefaultMutFranchiseRepository<'a> {
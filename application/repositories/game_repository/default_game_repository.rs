@@ -0,0 +1,288 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_statistic::GameStatistic;
+use domain::entities::game::Game;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::franchise_repository::FranchiseRepository;
+use repositories::game_repository::GameRepository;
+use repositories::image_repository::ImageRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::{fallback_unwrap, fallback_unwrap_ref};
+use crate::schemas::db_game::DbGame;
+use crate::schemas::db_game_statistic::DbGameStatistic;
+use crate::schemas::db_game_translation::DbGameTranslation;
+use crate::schemas::db_rating::DbRating;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGameRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+}
+
+impl<'a> DefaultGameRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+    franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+  ) -> DefaultGameRepository<'a> {
+    DefaultGameRepository {
+      client,
+      default_language: default_language.into(),
+      image_repository,
+      franchise_repository,
+    }
+  }
+
+  async fn games_from_tuple(&self, items: Vec<GameColumns>, language: Language) -> Result<Vec<Game>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let image_ids = image_ids(&items);
+    let franchise_ids = franchise_ids(&items);
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+    let franchises = match franchise_ids.is_empty() {
+      true => vec![],
+      false => self.franchise_repository.get_by_ids(&franchise_ids, language).await?,
+    };
+
+    items
+      .into_iter()
+      .map(|item| {
+        let game_translation = fallback_unwrap(item.1, item.2);
+        let franchise = franchises
+          .iter()
+          .find(|y| match item.0.fk_franchise {
+            None => false,
+            Some(id) => id as u32 == y.id,
+          })
+          .cloned();
+        let image = images
+          .iter()
+          .find(|y| y.id == game_translation.fk_cover as u32)
+          .unwrap()
+          .clone();
+        Ok(item.0.to_entity(game_translation, image, franchise))
+      })
+      .collect()
+  }
+  async fn game_from_tuple(&self, item: GameColumns, language: Language) -> Result<Game, Box<dyn Error>> {
+    let game_translation = fallback_unwrap(item.1, item.2);
+    let image = self
+      .image_repository
+      .get_by_id(game_translation.fk_cover as u32)
+      .await?
+      .unwrap();
+    let franchise = match item.0.fk_franchise {
+      None => None,
+      Some(value) => self.franchise_repository.get_by_id(value as u32, language).await?,
+    };
+    Ok(item.0.to_entity(game_translation, image, franchise))
+  }
+}
+
+fn image_ids(items: &[GameColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .map(|x| fallback_unwrap_ref(x.1.as_ref(), x.2.as_ref()).fk_cover as u32)
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+fn franchise_ids(items: &[GameColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .filter_map(|x| x.0.fk_franchise.map(|x| x as u32))
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+#[async_trait]
+impl GameRepository for DefaultGameRepository<'_> {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<Game>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbGame>()
+      .count()
+      .transform(|x| game_joins(x, &db_language, &self.default_language))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let games = game_select(&db_language, &self.default_language)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    let games = self.games_from_tuple(games, language).await?;
+    Ok(ItemsTotal { items: games, total })
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Game>, Box<dyn Error>> {
+    let id = id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let select = game_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueEqual::new(("game", "id"), id)));
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.game_from_tuple(value, language).await?))
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Game>, Box<dyn Error>> {
+    let title = format!("%{title}%");
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbGame>()
+      .count()
+      .transform(|x| game_joins(x, &db_language, &self.default_language))
+      .where_expression(
+        Expression::new(ValueILike::new(("game_translation", "title"), &title)).or(Expression::new(ValueILike::new(
+          ("game_translation_fallback", "title"),
+          &title,
+        ))),
+      )
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+
+    let total = total.0 as usize;
+
+    let games = game_select(&db_language, &self.default_language)
+      .where_expression(
+        Expression::new(ValueILike::new(("game_translation", "title"), &title)).or(Expression::new(ValueILike::new(
+          ("game_translation_fallback", "title"),
+          &title,
+        ))),
+      )
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+    let games = self.games_from_tuple(games, language).await?;
+    Ok(ItemsTotal { items: games, total })
+  }
+
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<Game>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+    let ids = to_i32(ids);
+
+    let games = game_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueIn::new((DbGame::TABLE_NAME, "id"), &ids)))
+      .query(self.client)
+      .await?;
+
+    let games = self.games_from_tuple(games, language).await?;
+
+    Ok(games)
+  }
+
+  async fn filter_existing(&self, game_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let games = to_i32(game_ids);
+
+    let filtered = Select::new::<DbGame>()
+      .column::<i32>(DbGame::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new((DbGame::TABLE_NAME, "id"), &games)))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+
+  async fn get_statistics(&self, game_ids: &[u32]) -> Result<Vec<GameStatistic>, Box<dyn Error>> {
+    let ids = to_i32(game_ids);
+
+    let statistics = Select::new::<DbGameStatistic>()
+      .columns::<DbGameStatistic>(DbGameStatistic::TABLE_NAME)
+      .columns::<DbRating>(DbRating::TABLE_NAME)
+      .inner_join::<DbRating>(
+        None,
+        Expression::new(ColumnEqual::new(
+          (DbRating::TABLE_NAME, "id"),
+          (DbGameStatistic::TABLE_NAME, "fkrating"),
+        )),
+      )
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGameStatistic::TABLE_NAME, "fkgame"),
+        &ids,
+      )))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|(statistic, rating)| statistic.to_entity(rating.to_entity()))
+      .collect();
+
+    Ok(statistics)
+  }
+}
+
+fn game_select<'a>(language: &'a DbLanguage, fallback_language: &'a DbLanguage) -> Select<'a, GameColumns> {
+  game_select_columns().transform(|x| game_joins(x, language, fallback_language))
+}
+
+fn game_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbGameTranslation>(
+      Some("game_translation"),
+      Expression::new(ColumnEqual::new(("game_translation", "fktranslation"), ("game", "id"))).and(Expression::new(
+        ValueEqual::new(("game_translation", "language"), language),
+      )),
+    )
+    .left_join::<DbGameTranslation>(
+      Some("game_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("game", "id"),
+        ("game_translation_fallback", "fktranslation"),
+      ))
+      .and(Expression::new(ColumnNull::new(("game_translation", "fktranslation"))))
+      .and(Expression::new(ValueEqual::new(
+        ("game_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn game_select_columns<'a>() -> Select<'a, GameColumns> {
+  Select::new::<DbGame>()
+    .columns::<DbGame>("game")
+    .columns::<Option<DbGameTranslation>>("game_translation")
+    .columns::<Option<DbGameTranslation>>("game_translation_fallback")
+}
+
+type GameColumns = (DbGame, Option<DbGameTranslation>, Option<DbGameTranslation>);
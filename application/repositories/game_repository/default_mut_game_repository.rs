@@ -0,0 +1,193 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::game::create_partial_game::CreatePartialGame;
+use domain::entities::game::Game;
+use domain::enums::language::Language;
+use from_row::Table;
+use repositories::game_repository::game_character_repository::mut_game_character_repository::MutGameCharacterRepository;
+use repositories::game_repository::game_genre_repository::mut_game_genre_repository::MutGameGenreRepository;
+use repositories::game_repository::game_involved_repository::mut_game_involved_repository::MutGameInvolvedRepository;
+use repositories::game_repository::game_platform_repository::mut_game_platform_repository::MutGamePlatformRepository;
+use repositories::game_repository::game_theme_repository::mut_game_theme_repository::MutGameThemeRepository;
+use repositories::game_repository::mut_game_repository::MutGameRepository;
+use repositories::game_repository::GameRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_game::DbGame;
+use crate::schemas::db_game_statistic::DbGameStatistic;
+use crate::schemas::db_game_translation::DbGameTranslation;
+use crate::schemas::db_rating::DbRating;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultMutGameRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_game_genre_repository: Arc<dyn MutGameGenreRepository + 'a>,
+  mut_game_character_repository: Arc<dyn MutGameCharacterRepository + 'a>,
+  mut_game_theme_repository: Arc<dyn MutGameThemeRepository + 'a>,
+  mut_game_involved_repository: Arc<dyn MutGameInvolvedRepository + 'a>,
+  mut_game_platform_repository: Arc<dyn MutGamePlatformRepository + 'a>,
+  game_repository: Arc<dyn GameRepository + 'a>,
+}
+
+impl<'a> DefaultMutGameRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    default_language: Language,
+    mut_game_genre_repository: Arc<dyn MutGameGenreRepository + 'a>,
+    mut_game_character_repository: Arc<dyn MutGameCharacterRepository + 'a>,
+    mut_game_theme_repository: Arc<dyn MutGameThemeRepository + 'a>,
+    mut_game_involved_repository: Arc<dyn MutGameInvolvedRepository + 'a>,
+    mut_game_platform_repository: Arc<dyn MutGamePlatformRepository + 'a>,
+    game_repository: Arc<dyn GameRepository + 'a>,
+  ) -> DefaultMutGameRepository<'a> {
+    DefaultMutGameRepository {
+      transaction,
+      default_language,
+      mut_game_genre_repository,
+      mut_game_character_repository,
+      mut_game_theme_repository,
+      mut_game_involved_repository,
+      mut_game_platform_repository,
+      game_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGameRepository for DefaultMutGameRepository<'_> {
+  async fn create(&self, item: CreatePartialGame) -> Result<Game, Box<dyn Error>> {
+    let id = self.insert_game(&item).await? as u32;
+    self.insert_translation(&item, id).await?;
+    self.insert_characters(&item, id).await?;
+    self.insert_themes(&item, id).await?;
+    self.insert_genres(&item, id).await?;
+    self.insert_involved(&item, id).await?;
+    self.insert_platforms(&item, id).await?;
+
+    let game = self
+      .game_repository
+      .get_by_id(id, self.default_language)
+      .await?
+      .expect("Game was just created");
+    Ok(game)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.mut_game_character_repository.remove_all(ids).await?;
+    self.mut_game_genre_repository.remove_all(ids).await?;
+    self.mut_game_involved_repository.remove_all(ids).await?;
+    self.mut_game_theme_repository.remove_all(ids).await?;
+    self.mut_game_platform_repository.remove_all(ids).await?;
+    let ids = to_i32(ids);
+
+    Delete::new::<DbGameStatistic>(Expression::new(ValueIn::new(
+      (DbGameStatistic::TABLE_NAME, "fkgame"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGameTranslation>(Expression::new(ValueIn::new(
+      (DbGameTranslation::TABLE_NAME, "fktranslation"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGame>(Expression::new(ValueIn::new((DbGame::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
+
+impl DefaultMutGameRepository<'_> {
+  async fn insert_involved(&self, item: &CreatePartialGame, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.involved.is_empty() {
+      return Ok(());
+    }
+    self.mut_game_involved_repository.add(id, &item.involved).await
+  }
+
+  async fn insert_genres(&self, item: &CreatePartialGame, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.genres.is_empty() {
+      return Ok(());
+    }
+    self.mut_game_genre_repository.add(id, &item.genres).await
+  }
+  async fn insert_themes(&self, item: &CreatePartialGame, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.themes.is_empty() {
+      return Ok(());
+    }
+    self.mut_game_theme_repository.add(id, &item.themes).await
+  }
+  async fn insert_characters(&self, item: &CreatePartialGame, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.characters.is_empty() {
+      return Ok(());
+    }
+    self.mut_game_character_repository.add(id, &item.characters).await
+  }
+  async fn insert_platforms(&self, item: &CreatePartialGame, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.platforms.is_empty() {
+      return Ok(());
+    }
+    self.mut_game_platform_repository.add(id, &item.platforms).await
+  }
+  async fn insert_game(&self, item: &CreatePartialGame) -> Result<i32, Box<dyn Error>> {
+    let franchise = &item.franchise.map(|x| x as i32);
+    let game_id: i32 = Insert::new::<DbGame>(["released", "fkfranchise"])
+      .values([&item.released, franchise])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let rating_id: i32 = Insert::new::<DbRating>([])
+      .values([])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let (game_count,) = Select::new::<DbGame>()
+      .count()
+      .get_single(self.transaction.client())
+      .await?
+      .ok_or("DbGame count returned no columns")?;
+    let game_count = game_count as i32;
+
+    Insert::new::<DbGameStatistic>(["fkgame", "fkrating", "popularity", "rank"])
+      .values([&game_id, &rating_id, &game_count, &game_count])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    Ok(game_id)
+  }
+  async fn insert_translation(&self, item: &CreatePartialGame, id: u32) -> Result<(), Box<dyn Error>> {
+    let id = id as i32;
+    let mapped: Vec<(&String, &Option<String>, i32, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| {
+        (
+          &x.1.title,
+          &x.1.description,
+          x.1.cover.id as i32,
+          DbLanguage::from(*x.0),
+        )
+      })
+      .collect();
+    let mut insert = Insert::new::<DbGameTranslation>(["title", "description", "fkcover", "fktranslation", "language"]);
+    for (title, description, cover_id, language) in &mapped {
+      insert.values_ref([*title, *description, cover_id, &id, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+}
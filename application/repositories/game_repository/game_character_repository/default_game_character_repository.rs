@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::game::game_character::GameCharacter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::character_repository::CharacterRepository;
+use repositories::game_repository::game_character_repository::GameCharacterRepository;
+use repositories::game_repository::GameRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_game_character::DbGameCharacter;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGameCharacterRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+}
+
+impl<'a> DefaultGameCharacterRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    game_repository: Arc<dyn GameRepository + 'a>,
+    character_repository: Arc<dyn CharacterRepository + 'a>,
+  ) -> DefaultGameCharacterRepository<'a> {
+    DefaultGameCharacterRepository {
+      client,
+      default_language: default_language.into(),
+      game_repository,
+      character_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GameCharacterRepository for DefaultGameCharacterRepository<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameCharacter>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+
+    let total = Select::new::<DbGameCharacter>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGameCharacter::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let character_games_ids = Select::new::<DbGameCharacter>()
+      .column::<i32>(DbGameCharacter::TABLE_NAME, "fkcharacter")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGameCharacter::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    if character_games_ids.is_empty() {
+      return Ok(ItemsTotal { items: vec![], total });
+    }
+
+    let character_ids: Vec<u32> = character_games_ids.iter().map(|x| x.0 as u32).collect();
+
+    let characters = self.character_repository.get_by_ids(&character_ids, language).await?;
+
+    let items = characters
+      .into_iter()
+      .map(|character| GameCharacter { character })
+      .collect();
+
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, game_id: u32, characters: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let characters = to_i32(characters);
+    let filtered = Select::new::<DbGameCharacter>()
+      .column::<i32>(DbGameCharacter::TABLE_NAME, "fkcharacter")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGameCharacter::TABLE_NAME, "fkcharacter"),
+        &characters,
+      )))
+      .where_expression(Expression::column_equal(DbGameCharacter::TABLE_NAME, "fkgame", game_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
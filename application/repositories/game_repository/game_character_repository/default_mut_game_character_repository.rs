@@ -0,0 +1,66 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::game_repository::game_character_repository::mut_game_character_repository::MutGameCharacterRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_game_character::DbGameCharacter;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGameCharacterRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGameCharacterRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGameCharacterRepository<'a> {
+    DefaultMutGameCharacterRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGameCharacterRepository for DefaultMutGameCharacterRepository<'_> {
+  async fn add(&self, game_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let characters = to_i32(characters);
+    let mut insert =
+      Insert::new::<DbGameCharacter>(["fkgame", "fkcharacter"]).on_conflict_do_nothing(&["fkgame", "fkcharacter"]);
+    characters.iter().for_each(|x| {
+      insert.values_ref([&game_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, game_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let characters = to_i32(characters);
+
+    Delete::new::<DbGameCharacter>(
+      Expression::column_equal(DbGameCharacter::TABLE_NAME, "fkgame", game_id).and(Expression::new(ValueIn::new(
+        (DbGameCharacter::TABLE_NAME, "fkcharacter"),
+        &characters,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_ids = to_i32(game_ids);
+
+    Delete::new::<DbGameCharacter>(Expression::new(ValueIn::new(
+      (DbGameCharacter::TABLE_NAME, "fkgame"),
+      &game_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
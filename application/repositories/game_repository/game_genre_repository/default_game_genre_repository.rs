@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::genre::Genre;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::game_repository::game_genre_repository::GameGenreRepository;
+use repositories::game_repository::GameRepository;
+use repositories::genre_repository::GenreRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_game_genre::DbGameGenre;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGameGenreRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+}
+
+impl<'a> DefaultGameGenreRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    game_repository: Arc<dyn GameRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+  ) -> DefaultGameGenreRepository<'a> {
+    DefaultGameGenreRepository {
+      client,
+      default_language: default_language.into(),
+      game_repository,
+      genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GameGenreRepository for DefaultGameGenreRepository<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Genre>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+
+    let total = Select::new::<DbGameGenre>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGameGenre::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let genre_ids: Vec<u32> = Select::new::<DbGameGenre>()
+      .column::<i32>(DbGameGenre::TABLE_NAME, "fkgenre")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGameGenre::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match genre_ids.is_empty() {
+      true => vec![],
+      false => self.genre_repository.get_by_ids(&genre_ids, language).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, game_id: u32, genres: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let genres = to_i32(genres);
+
+    let filtered = Select::new::<DbGameGenre>()
+      .column::<i32>(DbGameGenre::TABLE_NAME, "fkgenre")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGameGenre::TABLE_NAME, "fkgenre"),
+        &genres,
+      )))
+      .where_expression(Expression::column_equal(DbGameGenre::TABLE_NAME, "fkgame", game_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::game_repository::game_genre_repository::mut_game_genre_repository::MutGameGenreRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_game_genre::DbGameGenre;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGameGenreRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGameGenreRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGameGenreRepository<'a> {
+    DefaultMutGameGenreRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGameGenreRepository for DefaultMutGameGenreRepository<'_> {
+  async fn add(&self, game_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let genres = to_i32(genres);
+    let mut insert = Insert::new::<DbGameGenre>(["fkgame", "fkgenre"]).on_conflict_do_nothing(&["fkgame", "fkgenre"]);
+    genres.iter().for_each(|x| {
+      insert.values_ref([&game_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, game_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let genres = to_i32(genres);
+
+    Delete::new::<DbGameGenre>(
+      Expression::column_equal(DbGameGenre::TABLE_NAME, "fkgame", game_id).and(Expression::new(ValueIn::new(
+        (DbGameGenre::TABLE_NAME, "fkgenre"),
+        &genres,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_ids = to_i32(game_ids);
+
+    Delete::new::<DbGameGenre>(Expression::new(ValueIn::new(
+      (DbGameGenre::TABLE_NAME, "fkgame"),
+      &game_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,76 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use domain::entities::involved::InvolvedId;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use from_row::Table;
+use repositories::game_repository::game_involved_repository::mut_game_involved_repository::MutGameInvolvedRepository;
+
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_game_involved::DbGameInvolved;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGameInvolvedRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGameInvolvedRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGameInvolvedRepository<'a> {
+    DefaultMutGameInvolvedRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGameInvolvedRepository for DefaultMutGameInvolvedRepository<'_> {
+  async fn add(&self, game_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+    let mut insert = Insert::new::<DbGameInvolved>(["fkgame", "fkperson", "fkrole"])
+      .on_conflict_do_nothing(&["fkgame", "fkperson", "fkrole"]);
+    involved.iter().for_each(|(x, y)| {
+      insert.values_ref([&game_id, x, y]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, game_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+
+    Delete::new::<DbGameInvolved>(
+      Expression::column_equal(DbGameInvolved::TABLE_NAME, "fkgame", game_id).and(Expression::new(ValueIn::new(
+        (
+          (DbGameInvolved::TABLE_NAME, "fkperson"),
+          (DbGameInvolved::TABLE_NAME, "fkrole"),
+        ),
+        &involved,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_ids = to_i32(game_ids);
+
+    Delete::new::<DbGameInvolved>(Expression::new(ValueIn::new(
+      (DbGameInvolved::TABLE_NAME, "fkgame"),
+      &game_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_game_involved_repository;
+pub mod default_mut_game_involved_repository;
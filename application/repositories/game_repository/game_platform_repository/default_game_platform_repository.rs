@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::game_repository::game_platform_repository::GamePlatformRepository;
+use repositories::platform_repository::PlatformRepository;
+
+use crate::schemas::db_game_platform::DbGamePlatform;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGamePlatformRepository<'a> {
+  client: &'a CachedClient,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+}
+
+impl<'a> DefaultGamePlatformRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    platform_repository: Arc<dyn PlatformRepository + 'a>,
+  ) -> DefaultGamePlatformRepository<'a> {
+    DefaultGamePlatformRepository {
+      client,
+      platform_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GamePlatformRepository for DefaultGamePlatformRepository<'_> {
+  async fn get(&self, game_id: u32, pagination: Pagination) -> Result<ItemsTotal<Platform>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+
+    let total = Select::new::<DbGamePlatform>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGamePlatform::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let platform_ids: Vec<u32> = Select::new::<DbGamePlatform>()
+      .column::<i32>(DbGamePlatform::TABLE_NAME, "fkplatform")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGamePlatform::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match platform_ids.is_empty() {
+      true => vec![],
+      false => self.platform_repository.get_by_ids(&platform_ids).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, game_id: u32, platforms: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let platforms = to_i32(platforms);
+
+    let filtered = Select::new::<DbGamePlatform>()
+      .column::<i32>(DbGamePlatform::TABLE_NAME, "fkplatform")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGamePlatform::TABLE_NAME, "fkplatform"),
+        &platforms,
+      )))
+      .where_expression(Expression::column_equal(DbGamePlatform::TABLE_NAME, "fkgame", game_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
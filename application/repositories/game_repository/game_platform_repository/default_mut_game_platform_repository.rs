@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::game_repository::game_platform_repository::mut_game_platform_repository::MutGamePlatformRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_game_platform::DbGamePlatform;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGamePlatformRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGamePlatformRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGamePlatformRepository<'a> {
+    DefaultMutGamePlatformRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGamePlatformRepository for DefaultMutGamePlatformRepository<'_> {
+  async fn add(&self, game_id: u32, platforms: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let platforms = to_i32(platforms);
+    let mut insert =
+      Insert::new::<DbGamePlatform>(["fkgame", "fkplatform"]).on_conflict_do_nothing(&["fkgame", "fkplatform"]);
+    platforms.iter().for_each(|x| {
+      insert.values_ref([&game_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, game_id: u32, platforms: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let platforms = to_i32(platforms);
+
+    Delete::new::<DbGamePlatform>(
+      Expression::column_equal(DbGamePlatform::TABLE_NAME, "fkgame", game_id).and(Expression::new(ValueIn::new(
+        (DbGamePlatform::TABLE_NAME, "fkplatform"),
+        &platforms,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_ids = to_i32(game_ids);
+
+    Delete::new::<DbGamePlatform>(Expression::new(ValueIn::new(
+      (DbGamePlatform::TABLE_NAME, "fkgame"),
+      &game_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::theme::Theme;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::game_repository::game_theme_repository::GameThemeRepository;
+use repositories::game_repository::GameRepository;
+use repositories::theme_repository::ThemeRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_game_theme::DbGameTheme;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGameThemeRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+}
+
+impl<'a> DefaultGameThemeRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    game_repository: Arc<dyn GameRepository + 'a>,
+    theme_repository: Arc<dyn ThemeRepository + 'a>,
+  ) -> DefaultGameThemeRepository<'a> {
+    DefaultGameThemeRepository {
+      client,
+      default_language: default_language.into(),
+      game_repository,
+      theme_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GameThemeRepository for DefaultGameThemeRepository<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Theme>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+
+    let total = Select::new::<DbGameTheme>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGameTheme::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let theme_ids: Vec<u32> = Select::new::<DbGameTheme>()
+      .column::<i32>(DbGameTheme::TABLE_NAME, "fktheme")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGameTheme::TABLE_NAME, "fkgame"),
+        game_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match theme_ids.is_empty() {
+      true => vec![],
+      false => self.theme_repository.get_by_ids(&theme_ids, language).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, game_id: u32, themes: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let themes = to_i32(themes);
+
+    let filtered = Select::new::<DbGameTheme>()
+      .column::<i32>(DbGameTheme::TABLE_NAME, "fktheme")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGameTheme::TABLE_NAME, "fktheme"),
+        &themes,
+      )))
+      .where_expression(Expression::column_equal(DbGameTheme::TABLE_NAME, "fkgame", game_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
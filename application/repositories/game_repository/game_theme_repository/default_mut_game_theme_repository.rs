@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::game_repository::game_theme_repository::mut_game_theme_repository::MutGameThemeRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_game_theme::DbGameTheme;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGameThemeRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGameThemeRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGameThemeRepository<'a> {
+    DefaultMutGameThemeRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGameThemeRepository for DefaultMutGameThemeRepository<'_> {
+  async fn add(&self, game_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let themes = to_i32(themes);
+    let mut insert = Insert::new::<DbGameTheme>(["fkgame", "fktheme"]).on_conflict_do_nothing(&["fkgame", "fktheme"]);
+    themes.iter().for_each(|x| {
+      insert.values_ref([&game_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, game_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_id = game_id as i32;
+    let themes = to_i32(themes);
+
+    Delete::new::<DbGameTheme>(
+      Expression::column_equal(DbGameTheme::TABLE_NAME, "fkgame", game_id).and(Expression::new(ValueIn::new(
+        (DbGameTheme::TABLE_NAME, "fktheme"),
+        &themes,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let game_ids = to_i32(game_ids);
+
+    Delete::new::<DbGameTheme>(Expression::new(ValueIn::new(
+      (DbGameTheme::TABLE_NAME, "fkgame"),
+      &game_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
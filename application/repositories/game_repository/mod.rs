@@ -0,0 +1,7 @@
+pub mod default_game_repository;
+pub mod default_mut_game_repository;
+pub mod game_character_repository;
+pub mod game_genre_repository;
+pub mod game_involved_repository;
+pub mod game_platform_repository;
+pub mod game_theme_repository;
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::genre::create_partial_genre::CreatePartialGenre;
 use domain::entities::genre::Genre;
@@ -11,6 +10,7 @@ use from_row::Table;
 use repositories::genre_repository::mut_genre_repository::MutGenreRepository;
 use repositories::genre_repository::GenreRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::enums::db_language::DbLanguage;
@@ -21,14 +21,14 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutGenreRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   genre_repository: Arc<dyn GenreRepository + 'a>,
 }
 
 impl<'a> DefaultMutGenreRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     default_language: Language,
     genre_repository: Arc<dyn GenreRepository + 'a>,
   ) -> DefaultMutGenreRepository<'a> {
@@ -0,0 +1,254 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use repositories::image_repository::ImageRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::{fallback_unwrap, fallback_unwrap_ref};
+use crate::schemas::db_graphic_novel::DbGraphicNovel;
+use crate::schemas::db_graphic_novel_translation::DbGraphicNovelTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+  ) -> DefaultGraphicNovelRepository<'a> {
+    DefaultGraphicNovelRepository {
+      client,
+      default_language: default_language.into(),
+      image_repository,
+    }
+  }
+
+  async fn graphic_novels_from_tuple(
+    &self,
+    items: Vec<GraphicNovelColumns>,
+  ) -> Result<Vec<GraphicNovel>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let image_ids = image_ids(&items);
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+
+    items
+      .into_iter()
+      .map(|item| {
+        let graphic_novel_translation = fallback_unwrap(item.1, item.2);
+        let image = images
+          .iter()
+          .find(|y| y.id == graphic_novel_translation.fk_cover as u32)
+          .unwrap()
+          .clone();
+        Ok(item.0.to_entity(graphic_novel_translation, image))
+      })
+      .collect()
+  }
+  async fn graphic_novel_from_tuple(&self, item: GraphicNovelColumns) -> Result<GraphicNovel, Box<dyn Error>> {
+    let graphic_novel_translation = fallback_unwrap(item.1, item.2);
+    let image = self
+      .image_repository
+      .get_by_id(graphic_novel_translation.fk_cover as u32)
+      .await?
+      .unwrap();
+    Ok(item.0.to_entity(graphic_novel_translation, image))
+  }
+}
+
+fn image_ids(items: &[GraphicNovelColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .map(|x| fallback_unwrap_ref(x.1.as_ref(), x.2.as_ref()).fk_cover as u32)
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+#[async_trait]
+impl GraphicNovelRepository for DefaultGraphicNovelRepository<'_> {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<GraphicNovel>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbGraphicNovel>()
+      .count()
+      .transform(|x| graphic_novel_joins(x, &db_language, &self.default_language))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let graphic_novels = graphic_novel_select(&db_language, &self.default_language)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    let graphic_novels = self.graphic_novels_from_tuple(graphic_novels).await?;
+    Ok(ItemsTotal {
+      items: graphic_novels,
+      total,
+    })
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<GraphicNovel>, Box<dyn Error>> {
+    let id = id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let select = graphic_novel_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueEqual::new(("graphicnovel", "id"), id)));
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.graphic_novel_from_tuple(value).await?))
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovel>, Box<dyn Error>> {
+    let title = format!("%{title}%");
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbGraphicNovel>()
+      .count()
+      .transform(|x| graphic_novel_joins(x, &db_language, &self.default_language))
+      .where_expression(
+        Expression::new(ValueILike::new(("graphic_novel_translation", "title"), &title)).or(Expression::new(
+          ValueILike::new(("graphic_novel_translation_fallback", "title"), &title),
+        )),
+      )
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+
+    let total = total.0 as usize;
+
+    let graphic_novels = graphic_novel_select(&db_language, &self.default_language)
+      .where_expression(
+        Expression::new(ValueILike::new(("graphic_novel_translation", "title"), &title)).or(Expression::new(
+          ValueILike::new(("graphic_novel_translation_fallback", "title"), &title),
+        )),
+      )
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+    let graphic_novels = self.graphic_novels_from_tuple(graphic_novels).await?;
+    Ok(ItemsTotal {
+      items: graphic_novels,
+      total,
+    })
+  }
+
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<GraphicNovel>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+    let ids = to_i32(ids);
+
+    let graphic_novels = graphic_novel_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueIn::new((DbGraphicNovel::TABLE_NAME, "id"), &ids)))
+      .query(self.client)
+      .await?;
+
+    let graphic_novels = self.graphic_novels_from_tuple(graphic_novels).await?;
+
+    Ok(graphic_novels)
+  }
+
+  async fn filter_existing(&self, graphic_novel_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let graphic_novels = to_i32(graphic_novel_ids);
+
+    let filtered = Select::new::<DbGraphicNovel>()
+      .column::<i32>(DbGraphicNovel::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGraphicNovel::TABLE_NAME, "id"),
+        &graphic_novels,
+      )))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
+
+fn graphic_novel_select<'a>(
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, GraphicNovelColumns> {
+  graphic_novel_select_columns().transform(|x| graphic_novel_joins(x, language, fallback_language))
+}
+
+fn graphic_novel_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbGraphicNovelTranslation>(
+      Some("graphic_novel_translation"),
+      Expression::new(ColumnEqual::new(
+        ("graphic_novel_translation", "fktranslation"),
+        ("graphicnovel", "id"),
+      ))
+      .and(Expression::new(ValueEqual::new(
+        ("graphic_novel_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbGraphicNovelTranslation>(
+      Some("graphic_novel_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("graphicnovel", "id"),
+        ("graphic_novel_translation_fallback", "fktranslation"),
+      ))
+      .and(Expression::new(ColumnNull::new((
+        "graphic_novel_translation",
+        "fktranslation",
+      ))))
+      .and(Expression::new(ValueEqual::new(
+        ("graphic_novel_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn graphic_novel_select_columns<'a>() -> Select<'a, GraphicNovelColumns> {
+  Select::new::<DbGraphicNovel>()
+    .columns::<DbGraphicNovel>("graphicnovel")
+    .columns::<Option<DbGraphicNovelTranslation>>("graphic_novel_translation")
+    .columns::<Option<DbGraphicNovelTranslation>>("graphic_novel_translation_fallback")
+}
+
+type GraphicNovelColumns = (
+  DbGraphicNovel,
+  Option<DbGraphicNovelTranslation>,
+  Option<DbGraphicNovelTranslation>,
+);
@@ -0,0 +1,207 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_partial_graphic_novel::CreatePartialGraphicNovel;
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::enums::language::Language;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::mut_graphic_novel_character_repository::MutGraphicNovelCharacterRepository;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::mut_graphic_novel_genre_repository::MutGraphicNovelGenreRepository;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::mut_graphic_novel_involved_repository::MutGraphicNovelInvolvedRepository;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::mut_graphic_novel_publisher_repository::MutGraphicNovelPublisherRepository;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::mut_graphic_novel_theme_repository::MutGraphicNovelThemeRepository;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::mut_graphic_novel_volume_repository::MutGraphicNovelVolumeRepository;
+use repositories::graphic_novel_repository::mut_graphic_novel_repository::MutGraphicNovelRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use repositories::user_repository::mut_user_average_repository::MutUserAverageRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::enums::db_status::DbStatus;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel::DbGraphicNovel;
+use crate::schemas::db_graphic_novel_statistic::DbGraphicNovelStatistic;
+use crate::schemas::db_graphic_novel_translation::DbGraphicNovelTranslation;
+use crate::schemas::db_rating::DbRating;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+const USER_GRAPHIC_NOVEL_TABLE: &str = "usergraphicnovel";
+
+pub struct DefaultMutGraphicNovelRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_graphic_novel_genre_repository: Arc<dyn MutGraphicNovelGenreRepository + 'a>,
+  mut_graphic_novel_character_repository: Arc<dyn MutGraphicNovelCharacterRepository + 'a>,
+  mut_graphic_novel_theme_repository: Arc<dyn MutGraphicNovelThemeRepository + 'a>,
+  mut_graphic_novel_involved_repository: Arc<dyn MutGraphicNovelInvolvedRepository + 'a>,
+  mut_graphic_novel_publisher_repository: Arc<dyn MutGraphicNovelPublisherRepository + 'a>,
+  mut_graphic_novel_volume_repository: Arc<dyn MutGraphicNovelVolumeRepository + 'a>,
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    default_language: Language,
+    mut_graphic_novel_genre_repository: Arc<dyn MutGraphicNovelGenreRepository + 'a>,
+    mut_graphic_novel_character_repository: Arc<dyn MutGraphicNovelCharacterRepository + 'a>,
+    mut_graphic_novel_theme_repository: Arc<dyn MutGraphicNovelThemeRepository + 'a>,
+    mut_graphic_novel_involved_repository: Arc<dyn MutGraphicNovelInvolvedRepository + 'a>,
+    mut_graphic_novel_publisher_repository: Arc<dyn MutGraphicNovelPublisherRepository + 'a>,
+    mut_graphic_novel_volume_repository: Arc<dyn MutGraphicNovelVolumeRepository + 'a>,
+    mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  ) -> DefaultMutGraphicNovelRepository<'a> {
+    DefaultMutGraphicNovelRepository {
+      transaction,
+      default_language,
+      mut_graphic_novel_genre_repository,
+      mut_graphic_novel_character_repository,
+      mut_graphic_novel_theme_repository,
+      mut_graphic_novel_involved_repository,
+      mut_graphic_novel_publisher_repository,
+      mut_graphic_novel_volume_repository,
+      mut_user_average_repository,
+      graphic_novel_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelRepository for DefaultMutGraphicNovelRepository<'_> {
+  async fn create(&self, item: CreatePartialGraphicNovel) -> Result<GraphicNovel, Box<dyn Error>> {
+    let id = self.insert_graphic_novel(&item).await? as u32;
+    self.insert_translation(&item, id).await?;
+
+    let graphic_novel = self
+      .graphic_novel_repository
+      .get_by_id(id, self.default_language)
+      .await?
+      .expect("Graphic novel was just created");
+    Ok(graphic_novel)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.mut_graphic_novel_character_repository.remove_all(ids).await?;
+    self.mut_graphic_novel_genre_repository.remove_all(ids).await?;
+    self.mut_graphic_novel_involved_repository.remove_all(ids).await?;
+    self.mut_graphic_novel_theme_repository.remove_all(ids).await?;
+    self.mut_graphic_novel_publisher_repository.remove_all(ids).await?;
+    self.mut_graphic_novel_volume_repository.delete_all(ids).await?;
+    let ids = to_i32(ids);
+    self.delete_user_graphic_novels(&ids).await?;
+
+    Delete::new::<DbGraphicNovelStatistic>(Expression::new(ValueIn::new(
+      (DbGraphicNovelStatistic::TABLE_NAME, "fkgraphicnovel"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelTranslation>(Expression::new(ValueIn::new(
+      (DbGraphicNovelTranslation::TABLE_NAME, "fktranslation"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovel>(Expression::new(ValueIn::new((DbGraphicNovel::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
+
+impl DefaultMutGraphicNovelRepository<'_> {
+  async fn delete_user_graphic_novels(&self, graphic_novel_ids: &[i32]) -> Result<(), Box<dyn Error>> {
+    let mut user_ids: Vec<u32> = Select::new_raw(USER_GRAPHIC_NOVEL_TABLE)
+      .column::<i32>(USER_GRAPHIC_NOVEL_TABLE, "fkuser")
+      .where_expression(Expression::new(ValueIn::new(
+        (USER_GRAPHIC_NOVEL_TABLE, "fkgraphicnovel"),
+        graphic_novel_ids,
+      )))
+      .query(self.transaction.client())
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    Delete::new_raw(
+      USER_GRAPHIC_NOVEL_TABLE,
+      Expression::new(ValueIn::new(
+        (USER_GRAPHIC_NOVEL_TABLE, "fkgraphicnovel"),
+        graphic_novel_ids,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    self.mut_user_average_repository.recompute(&user_ids).await
+  }
+
+  async fn insert_graphic_novel(&self, item: &CreatePartialGraphicNovel) -> Result<i32, Box<dyn Error>> {
+    let status = DbStatus::from(item.status);
+    let volumes = item.volumes.map(|x| x as i16);
+    let chapters = item.chapters.map(|x| x as i16);
+    let graphic_novel_id: i32 =
+      Insert::new::<DbGraphicNovel>(["publishstart", "publishend", "volumes", "chapters", "status"])
+        .values([&item.publish_start, &item.publish_end, &volumes, &chapters, &status])
+        .returning_transaction("id", self.transaction)
+        .await?;
+
+    let rating_id: i32 = Insert::new::<DbRating>([])
+      .values([])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let (graphic_novel_count,) = Select::new::<DbGraphicNovel>()
+      .count()
+      .get_single(self.transaction.client())
+      .await?
+      .ok_or("DbGraphicNovel count returned no columns")?;
+    let graphic_novel_count = graphic_novel_count as i32;
+
+    Insert::new::<DbGraphicNovelStatistic>(["fkgraphicnovel", "fkrating", "popularity", "rank"])
+      .values([
+        &graphic_novel_id,
+        &rating_id,
+        &graphic_novel_count,
+        &graphic_novel_count,
+      ])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    Ok(graphic_novel_id)
+  }
+
+  async fn insert_translation(&self, item: &CreatePartialGraphicNovel, id: u32) -> Result<(), Box<dyn Error>> {
+    let id = id as i32;
+    let mapped: Vec<(&String, &Option<String>, i32, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| {
+        (
+          &x.1.title,
+          &x.1.description,
+          x.1.cover.id as i32,
+          DbLanguage::from(*x.0),
+        )
+      })
+      .collect();
+    let mut insert =
+      Insert::new::<DbGraphicNovelTranslation>(["title", "description", "fkcover", "fktranslation", "language"]);
+    for (title, description, cover_id, language) in &mapped {
+      insert.values_ref([*title, *description, cover_id, &id, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+}
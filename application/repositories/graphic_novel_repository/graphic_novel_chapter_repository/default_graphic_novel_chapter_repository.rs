@@ -0,0 +1,210 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::GraphicNovelChapterRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::fallback_unwrap;
+use crate::schemas::db_graphic_novel_chapter::DbGraphicNovelChapter;
+use crate::schemas::db_graphic_novel_chapter_translation::DbGraphicNovelChapterTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelChapterRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+}
+
+impl<'a> DefaultGraphicNovelChapterRepository<'a> {
+  pub fn new(client: &'a CachedClient, default_language: Language) -> DefaultGraphicNovelChapterRepository<'a> {
+    DefaultGraphicNovelChapterRepository {
+      client,
+      default_language: default_language.into(),
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelChapterRepository for DefaultGraphicNovelChapterRepository<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelChapter>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = volume as i32;
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbGraphicNovelChapter>()
+      .count()
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovelvolume",
+        volume,
+      ))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let items = graphic_novel_chapter_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovelvolume",
+        volume,
+      ))
+      .order_by(
+        (DbGraphicNovelChapter::TABLE_NAME, "chapter"),
+        Direction::Ascending,
+        None,
+      )
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|item| item.0.to_entity(fallback_unwrap(item.1, item.2)))
+      .collect();
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn get_by_chapter(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    chapter: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelChapter>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = volume as i32;
+    let chapter = chapter as i16;
+    let db_language = DbLanguage::from(language);
+
+    let item = graphic_novel_chapter_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovelvolume",
+        volume,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "chapter",
+        chapter,
+      ))
+      .get_single(self.client)
+      .await?
+      .map(|item| item.0.to_entity(fallback_unwrap(item.1, item.2)));
+    Ok(item)
+  }
+
+  async fn exists(&self, graphic_novel_id: u32, chapter: u16) -> Result<bool, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let chapter = chapter as i16;
+
+    let item = Select::new::<DbGraphicNovelChapter>()
+      .column::<i16>(DbGraphicNovelChapter::TABLE_NAME, "chapter")
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "chapter",
+        chapter,
+      ))
+      .get_single(self.client)
+      .await?;
+    Ok(item.is_some())
+  }
+}
+
+fn graphic_novel_chapter_select<'a>(
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, GraphicNovelChapterColumns> {
+  graphic_novel_chapter_select_columns().transform(|x| graphic_novel_chapter_joins(x, language, fallback_language))
+}
+
+fn graphic_novel_chapter_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbGraphicNovelChapterTranslation>(
+      Some("graphic_novel_chapter_translation"),
+      Expression::new(ColumnEqual::new(
+        ("graphic_novel_chapter_translation", "fkgraphicnovel"),
+        (DbGraphicNovelChapter::TABLE_NAME, "fkgraphicnovel"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("graphic_novel_chapter_translation", "fkgraphicnovelchapter"),
+        (DbGraphicNovelChapter::TABLE_NAME, "chapter"),
+      )))
+      .and(Expression::new(ValueEqual::new(
+        ("graphic_novel_chapter_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbGraphicNovelChapterTranslation>(
+      Some("graphic_novel_chapter_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("graphic_novel_chapter_translation_fallback", "fkgraphicnovel"),
+        (DbGraphicNovelChapter::TABLE_NAME, "fkgraphicnovel"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("graphic_novel_chapter_translation_fallback", "fkgraphicnovelchapter"),
+        (DbGraphicNovelChapter::TABLE_NAME, "chapter"),
+      )))
+      .and(Expression::new(ColumnNull::new((
+        "graphic_novel_chapter_translation",
+        "fkgraphicnovel",
+      ))))
+      .and(Expression::new(ValueEqual::new(
+        ("graphic_novel_chapter_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn graphic_novel_chapter_select_columns<'a>() -> Select<'a, GraphicNovelChapterColumns> {
+  Select::new::<DbGraphicNovelChapter>()
+    .columns::<DbGraphicNovelChapter>(DbGraphicNovelChapter::TABLE_NAME)
+    .columns::<Option<DbGraphicNovelChapterTranslation>>("graphic_novel_chapter_translation")
+    .columns::<Option<DbGraphicNovelChapterTranslation>>("graphic_novel_chapter_translation_fallback")
+}
+
+type GraphicNovelChapterColumns = (
+  DbGraphicNovelChapter,
+  Option<DbGraphicNovelChapterTranslation>,
+  Option<DbGraphicNovelChapterTranslation>,
+);
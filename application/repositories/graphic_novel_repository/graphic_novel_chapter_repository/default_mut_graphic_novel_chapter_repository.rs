@@ -0,0 +1,160 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapter;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::mut_graphic_novel_chapter_repository::MutGraphicNovelChapterRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_chapter::DbGraphicNovelChapter;
+use crate::schemas::db_graphic_novel_chapter_translation::DbGraphicNovelChapterTranslation;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::conditions::value_in_select::ValueInSelect;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultMutGraphicNovelChapterRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelChapterRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGraphicNovelChapterRepository<'a> {
+    DefaultMutGraphicNovelChapterRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelChapterRepository for DefaultMutGraphicNovelChapterRepository<'_> {
+  async fn create(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    item: CreateGraphicNovelChapter,
+  ) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = volume as i32;
+    let chapter = item.chapter as i16;
+    let pages = item.pages as i16;
+    Insert::new::<DbGraphicNovelChapter>([
+      "chapter",
+      "fkgraphicnovel",
+      "fkgraphicnovelvolume",
+      "pages",
+      "published",
+    ])
+    .values([&chapter, &graphic_novel_id, &volume, &pages, &item.published])
+    .execute_transaction(self.transaction)
+    .await?;
+
+    let chapter = chapter as i32;
+    let mapped: Vec<(&String, &Option<String>, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| (&x.1.title, &x.1.description, DbLanguage::from(*x.0)))
+      .collect();
+    let mut insert = Insert::new::<DbGraphicNovelChapterTranslation>([
+      "title",
+      "description",
+      "fkgraphicnovel",
+      "fkgraphicnovelchapter",
+      "language",
+    ]);
+    for (title, description, language) in &mapped {
+      insert.values_ref([*title, *description, &graphic_novel_id, &chapter, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn delete(&self, graphic_novel_id: u32, chapter: u16) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+
+    Delete::new::<DbGraphicNovelChapterTranslation>(
+      Expression::column_equal(
+        DbGraphicNovelChapterTranslation::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      )
+      .and(Expression::column_equal(
+        DbGraphicNovelChapterTranslation::TABLE_NAME,
+        "fkgraphicnovelchapter",
+        chapter as i32,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelChapter>(
+      Expression::column_equal(DbGraphicNovelChapter::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::column_equal(DbGraphicNovelChapter::TABLE_NAME, "chapter", chapter as i16),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_by_volume(&self, graphic_novel_id: u32, volume: u16) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = volume as i32;
+
+    let chapters = Select::new::<DbGraphicNovelChapter>()
+      .column::<i16>(DbGraphicNovelChapter::TABLE_NAME, "chapter")
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelChapter::TABLE_NAME,
+        "fkgraphicnovelvolume",
+        volume,
+      ));
+    Delete::new::<DbGraphicNovelChapterTranslation>(
+      Expression::column_equal(
+        DbGraphicNovelChapterTranslation::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      )
+      .and(Expression::new(ValueInSelect::new(
+        (DbGraphicNovelChapterTranslation::TABLE_NAME, "fkgraphicnovelchapter"),
+        chapters,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelChapter>(
+      Expression::column_equal(DbGraphicNovelChapter::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::column_equal(DbGraphicNovelChapter::TABLE_NAME, "fkgraphicnovelvolume", volume),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelChapterTranslation>(Expression::new(ValueIn::new(
+      (DbGraphicNovelChapterTranslation::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelChapter>(Expression::new(ValueIn::new(
+      (DbGraphicNovelChapter::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
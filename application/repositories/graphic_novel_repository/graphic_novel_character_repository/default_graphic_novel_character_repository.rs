@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::graphic_novel::graphic_novel_character::GraphicNovelCharacter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::character_repository::CharacterRepository;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::GraphicNovelCharacterRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_graphic_novel_character::DbGraphicNovelCharacter;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelCharacterRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelCharacterRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    character_repository: Arc<dyn CharacterRepository + 'a>,
+  ) -> DefaultGraphicNovelCharacterRepository<'a> {
+    DefaultGraphicNovelCharacterRepository {
+      client,
+      default_language: default_language.into(),
+      graphic_novel_repository,
+      character_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelCharacterRepository for DefaultGraphicNovelCharacterRepository<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelCharacter>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+
+    let total = Select::new::<DbGraphicNovelCharacter>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelCharacter::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let character_graphic_novels_ids = Select::new::<DbGraphicNovelCharacter>()
+      .column::<i32>(DbGraphicNovelCharacter::TABLE_NAME, "fkcharacter")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelCharacter::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    if character_graphic_novels_ids.is_empty() {
+      return Ok(ItemsTotal { items: vec![], total });
+    }
+
+    let character_ids: Vec<u32> = character_graphic_novels_ids.iter().map(|x| x.0 as u32).collect();
+
+    let characters = self.character_repository.get_by_ids(&character_ids, language).await?;
+
+    let items = characters
+      .into_iter()
+      .map(|character| GraphicNovelCharacter { character })
+      .collect();
+
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, graphic_novel_id: u32, characters: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let characters = to_i32(characters);
+    let filtered = Select::new::<DbGraphicNovelCharacter>()
+      .column::<i32>(DbGraphicNovelCharacter::TABLE_NAME, "fkcharacter")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGraphicNovelCharacter::TABLE_NAME, "fkcharacter"),
+        &characters,
+      )))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelCharacter::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
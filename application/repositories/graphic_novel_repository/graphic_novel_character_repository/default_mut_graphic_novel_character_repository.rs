@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::mut_graphic_novel_character_repository::MutGraphicNovelCharacterRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_character::DbGraphicNovelCharacter;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGraphicNovelCharacterRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelCharacterRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGraphicNovelCharacterRepository<'a> {
+    DefaultMutGraphicNovelCharacterRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelCharacterRepository for DefaultMutGraphicNovelCharacterRepository<'_> {
+  async fn add(&self, graphic_novel_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let characters = to_i32(characters);
+    let mut insert = Insert::new::<DbGraphicNovelCharacter>(["fkgraphicnovel", "fkcharacter"])
+      .on_conflict_do_nothing(&["fkgraphicnovel", "fkcharacter"]);
+    characters.iter().for_each(|x| {
+      insert.values_ref([&graphic_novel_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, graphic_novel_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let characters = to_i32(characters);
+
+    Delete::new::<DbGraphicNovelCharacter>(
+      Expression::column_equal(DbGraphicNovelCharacter::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::new(ValueIn::new(
+          (DbGraphicNovelCharacter::TABLE_NAME, "fkcharacter"),
+          &characters,
+        )),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelCharacter>(Expression::new(ValueIn::new(
+      (DbGraphicNovelCharacter::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,110 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::genre::Genre;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::genre_repository::GenreRepository;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::GraphicNovelGenreRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_graphic_novel_genre::DbGraphicNovelGenre;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelGenreRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelGenreRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+  ) -> DefaultGraphicNovelGenreRepository<'a> {
+    DefaultGraphicNovelGenreRepository {
+      client,
+      default_language: default_language.into(),
+      graphic_novel_repository,
+      genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelGenreRepository for DefaultGraphicNovelGenreRepository<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Genre>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+
+    let total = Select::new::<DbGraphicNovelGenre>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelGenre::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let genre_ids: Vec<u32> = Select::new::<DbGraphicNovelGenre>()
+      .column::<i32>(DbGraphicNovelGenre::TABLE_NAME, "fkgenre")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelGenre::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match genre_ids.is_empty() {
+      true => vec![],
+      false => self.genre_repository.get_by_ids(&genre_ids, language).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, graphic_novel_id: u32, genres: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let genres = to_i32(genres);
+
+    let filtered = Select::new::<DbGraphicNovelGenre>()
+      .column::<i32>(DbGraphicNovelGenre::TABLE_NAME, "fkgenre")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGraphicNovelGenre::TABLE_NAME, "fkgenre"),
+        &genres,
+      )))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelGenre::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::mut_graphic_novel_genre_repository::MutGraphicNovelGenreRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_genre::DbGraphicNovelGenre;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGraphicNovelGenreRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelGenreRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGraphicNovelGenreRepository<'a> {
+    DefaultMutGraphicNovelGenreRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelGenreRepository for DefaultMutGraphicNovelGenreRepository<'_> {
+  async fn add(&self, graphic_novel_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let genres = to_i32(genres);
+    let mut insert = Insert::new::<DbGraphicNovelGenre>(["fkgraphicnovel", "fkgenre"])
+      .on_conflict_do_nothing(&["fkgraphicnovel", "fkgenre"]);
+    genres.iter().for_each(|x| {
+      insert.values_ref([&graphic_novel_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, graphic_novel_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let genres = to_i32(genres);
+
+    Delete::new::<DbGraphicNovelGenre>(
+      Expression::column_equal(DbGraphicNovelGenre::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::new(ValueIn::new((DbGraphicNovelGenre::TABLE_NAME, "fkgenre"), &genres)),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+  async fn remove_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelGenre>(Expression::new(ValueIn::new(
+      (DbGraphicNovelGenre::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,78 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use domain::entities::involved::InvolvedId;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::mut_graphic_novel_involved_repository::MutGraphicNovelInvolvedRepository;
+
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_involved::DbGraphicNovelInvolved;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGraphicNovelInvolvedRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelInvolvedRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGraphicNovelInvolvedRepository<'a> {
+    DefaultMutGraphicNovelInvolvedRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelInvolvedRepository for DefaultMutGraphicNovelInvolvedRepository<'_> {
+  async fn add(&self, graphic_novel_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+    let mut insert = Insert::new::<DbGraphicNovelInvolved>(["fkgraphicnovel", "fkperson", "fkrole"])
+      .on_conflict_do_nothing(&["fkgraphicnovel", "fkperson", "fkrole"]);
+    involved.iter().for_each(|(x, y)| {
+      insert.values_ref([&graphic_novel_id, x, y]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, graphic_novel_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+
+    Delete::new::<DbGraphicNovelInvolved>(
+      Expression::column_equal(DbGraphicNovelInvolved::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::new(ValueIn::new(
+          (
+            (DbGraphicNovelInvolved::TABLE_NAME, "fkperson"),
+            (DbGraphicNovelInvolved::TABLE_NAME, "fkrole"),
+          ),
+          &involved,
+        )),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelInvolved>(Expression::new(ValueIn::new(
+      (DbGraphicNovelInvolved::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,96 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::company_repository::CompanyRepository;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::GraphicNovelPublisherRepository;
+
+use crate::schemas::db_graphic_novel_publisher::DbGraphicNovelPublisher;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelPublisherRepository<'a> {
+  client: &'a CachedClient,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelPublisherRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+  ) -> DefaultGraphicNovelPublisherRepository<'a> {
+    DefaultGraphicNovelPublisherRepository {
+      client,
+      company_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelPublisherRepository for DefaultGraphicNovelPublisherRepository<'_> {
+  async fn get(&self, graphic_novel_id: u32, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+
+    let total = Select::new::<DbGraphicNovelPublisher>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelPublisher::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let publisher_ids: Vec<u32> = Select::new::<DbGraphicNovelPublisher>()
+      .column::<i32>(DbGraphicNovelPublisher::TABLE_NAME, "fkpublisher")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelPublisher::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match publisher_ids.is_empty() {
+      true => vec![],
+      false => self.company_repository.get_by_ids(&publisher_ids).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, graphic_novel_id: u32, publishers: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let publishers = to_i32(publishers);
+
+    let filtered = Select::new::<DbGraphicNovelPublisher>()
+      .column::<i32>(DbGraphicNovelPublisher::TABLE_NAME, "fkpublisher")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGraphicNovelPublisher::TABLE_NAME, "fkpublisher"),
+        &publishers,
+      )))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelPublisher::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
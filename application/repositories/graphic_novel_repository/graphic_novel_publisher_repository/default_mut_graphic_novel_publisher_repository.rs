@@ -0,0 +1,67 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::mut_graphic_novel_publisher_repository::MutGraphicNovelPublisherRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_publisher::DbGraphicNovelPublisher;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGraphicNovelPublisherRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelPublisherRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGraphicNovelPublisherRepository<'a> {
+    DefaultMutGraphicNovelPublisherRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelPublisherRepository for DefaultMutGraphicNovelPublisherRepository<'_> {
+  async fn add(&self, graphic_novel_id: u32, publishers: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let publishers = to_i32(publishers);
+    let mut insert = Insert::new::<DbGraphicNovelPublisher>(["fkgraphicnovel", "fkpublisher"])
+      .on_conflict_do_nothing(&["fkgraphicnovel", "fkpublisher"]);
+    publishers.iter().for_each(|x| {
+      insert.values_ref([&graphic_novel_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, graphic_novel_id: u32, publishers: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let publishers = to_i32(publishers);
+
+    Delete::new::<DbGraphicNovelPublisher>(
+      Expression::column_equal(DbGraphicNovelPublisher::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::new(ValueIn::new(
+          (DbGraphicNovelPublisher::TABLE_NAME, "fkpublisher"),
+          &publishers,
+        )),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+  async fn remove_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelPublisher>(Expression::new(ValueIn::new(
+      (DbGraphicNovelPublisher::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,110 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::theme::Theme;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::GraphicNovelThemeRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use repositories::theme_repository::ThemeRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_graphic_novel_theme::DbGraphicNovelTheme;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelThemeRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelThemeRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    theme_repository: Arc<dyn ThemeRepository + 'a>,
+  ) -> DefaultGraphicNovelThemeRepository<'a> {
+    DefaultGraphicNovelThemeRepository {
+      client,
+      default_language: default_language.into(),
+      graphic_novel_repository,
+      theme_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelThemeRepository for DefaultGraphicNovelThemeRepository<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Theme>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+
+    let total = Select::new::<DbGraphicNovelTheme>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelTheme::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let theme_ids: Vec<u32> = Select::new::<DbGraphicNovelTheme>()
+      .column::<i32>(DbGraphicNovelTheme::TABLE_NAME, "fktheme")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbGraphicNovelTheme::TABLE_NAME, "fkgraphicnovel"),
+        graphic_novel_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match theme_ids.is_empty() {
+      true => vec![],
+      false => self.theme_repository.get_by_ids(&theme_ids, language).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, graphic_novel_id: u32, themes: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let themes = to_i32(themes);
+
+    let filtered = Select::new::<DbGraphicNovelTheme>()
+      .column::<i32>(DbGraphicNovelTheme::TABLE_NAME, "fktheme")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbGraphicNovelTheme::TABLE_NAME, "fktheme"),
+        &themes,
+      )))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelTheme::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
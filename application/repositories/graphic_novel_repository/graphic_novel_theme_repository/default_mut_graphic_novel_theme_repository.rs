@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::mut_graphic_novel_theme_repository::MutGraphicNovelThemeRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_theme::DbGraphicNovelTheme;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGraphicNovelThemeRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelThemeRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutGraphicNovelThemeRepository<'a> {
+    DefaultMutGraphicNovelThemeRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelThemeRepository for DefaultMutGraphicNovelThemeRepository<'_> {
+  async fn add(&self, graphic_novel_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let themes = to_i32(themes);
+    let mut insert = Insert::new::<DbGraphicNovelTheme>(["fkgraphicnovel", "fktheme"])
+      .on_conflict_do_nothing(&["fkgraphicnovel", "fktheme"]);
+    themes.iter().for_each(|x| {
+      insert.values_ref([&graphic_novel_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, graphic_novel_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let themes = to_i32(themes);
+
+    Delete::new::<DbGraphicNovelTheme>(
+      Expression::column_equal(DbGraphicNovelTheme::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::new(ValueIn::new((DbGraphicNovelTheme::TABLE_NAME, "fktheme"), &themes)),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelTheme>(Expression::new(ValueIn::new(
+      (DbGraphicNovelTheme::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
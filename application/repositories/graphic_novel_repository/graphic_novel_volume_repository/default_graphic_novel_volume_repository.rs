@@ -0,0 +1,187 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::GraphicNovelVolumeRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::fallback_unwrap;
+use crate::schemas::db_graphic_novel_volume::DbGraphicNovelVolume;
+use crate::schemas::db_graphic_novel_volume_translation::DbGraphicNovelVolumeTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultGraphicNovelVolumeRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+}
+
+impl<'a> DefaultGraphicNovelVolumeRepository<'a> {
+  pub fn new(client: &'a CachedClient, default_language: Language) -> DefaultGraphicNovelVolumeRepository<'a> {
+    DefaultGraphicNovelVolumeRepository {
+      client,
+      default_language: default_language.into(),
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelVolumeRepository for DefaultGraphicNovelVolumeRepository<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelVolume>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbGraphicNovelVolume>()
+      .count()
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelVolume::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let items = graphic_novel_volume_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelVolume::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .order_by((DbGraphicNovelVolume::TABLE_NAME, "volume"), Direction::Ascending, None)
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|item| item.0.to_entity(fallback_unwrap(item.1, item.2)))
+      .collect();
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn get_by_volume(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelVolume>, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = volume as i16;
+    let db_language = DbLanguage::from(language);
+
+    let item = graphic_novel_volume_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelVolume::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelVolume::TABLE_NAME,
+        "volume",
+        volume,
+      ))
+      .get_single(self.client)
+      .await?
+      .map(|item| item.0.to_entity(fallback_unwrap(item.1, item.2)));
+    Ok(item)
+  }
+
+  async fn exists(&self, graphic_novel_id: u32, volume: u16) -> Result<bool, Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = volume as i16;
+
+    let item = Select::new::<DbGraphicNovelVolume>()
+      .column::<i16>(DbGraphicNovelVolume::TABLE_NAME, "volume")
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelVolume::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      ))
+      .where_expression(Expression::column_equal(
+        DbGraphicNovelVolume::TABLE_NAME,
+        "volume",
+        volume,
+      ))
+      .get_single(self.client)
+      .await?;
+    Ok(item.is_some())
+  }
+}
+
+fn graphic_novel_volume_select<'a>(
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, GraphicNovelVolumeColumns> {
+  graphic_novel_volume_select_columns().transform(|x| graphic_novel_volume_joins(x, language, fallback_language))
+}
+
+fn graphic_novel_volume_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbGraphicNovelVolumeTranslation>(
+      Some("graphic_novel_volume_translation"),
+      Expression::new(ColumnEqual::new(
+        ("graphic_novel_volume_translation", "fkgraphicnovel"),
+        (DbGraphicNovelVolume::TABLE_NAME, "fkgraphicnovel"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("graphic_novel_volume_translation", "fkgraphicnovelvolume"),
+        (DbGraphicNovelVolume::TABLE_NAME, "volume"),
+      )))
+      .and(Expression::new(ValueEqual::new(
+        ("graphic_novel_volume_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbGraphicNovelVolumeTranslation>(
+      Some("graphic_novel_volume_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("graphic_novel_volume_translation_fallback", "fkgraphicnovel"),
+        (DbGraphicNovelVolume::TABLE_NAME, "fkgraphicnovel"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("graphic_novel_volume_translation_fallback", "fkgraphicnovelvolume"),
+        (DbGraphicNovelVolume::TABLE_NAME, "volume"),
+      )))
+      .and(Expression::new(ColumnNull::new((
+        "graphic_novel_volume_translation",
+        "fkgraphicnovel",
+      ))))
+      .and(Expression::new(ValueEqual::new(
+        ("graphic_novel_volume_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn graphic_novel_volume_select_columns<'a>() -> Select<'a, GraphicNovelVolumeColumns> {
+  Select::new::<DbGraphicNovelVolume>()
+    .columns::<DbGraphicNovelVolume>(DbGraphicNovelVolume::TABLE_NAME)
+    .columns::<Option<DbGraphicNovelVolumeTranslation>>("graphic_novel_volume_translation")
+    .columns::<Option<DbGraphicNovelVolumeTranslation>>("graphic_novel_volume_translation_fallback")
+}
+
+type GraphicNovelVolumeColumns = (
+  DbGraphicNovelVolume,
+  Option<DbGraphicNovelVolumeTranslation>,
+  Option<DbGraphicNovelVolumeTranslation>,
+);
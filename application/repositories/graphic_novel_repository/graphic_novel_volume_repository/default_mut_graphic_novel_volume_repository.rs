@@ -0,0 +1,123 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolume;
+use from_row::Table;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::mut_graphic_novel_chapter_repository::MutGraphicNovelChapterRepository;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::mut_graphic_novel_volume_repository::MutGraphicNovelVolumeRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_graphic_novel_volume::DbGraphicNovelVolume;
+use crate::schemas::db_graphic_novel_volume_translation::DbGraphicNovelVolumeTranslation;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutGraphicNovelVolumeRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  mut_graphic_novel_chapter_repository: Arc<dyn MutGraphicNovelChapterRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelVolumeRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    mut_graphic_novel_chapter_repository: Arc<dyn MutGraphicNovelChapterRepository + 'a>,
+  ) -> DefaultMutGraphicNovelVolumeRepository<'a> {
+    DefaultMutGraphicNovelVolumeRepository {
+      transaction,
+      mut_graphic_novel_chapter_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelVolumeRepository for DefaultMutGraphicNovelVolumeRepository<'_> {
+  async fn create(&self, graphic_novel_id: u32, item: CreateGraphicNovelVolume) -> Result<(), Box<dyn Error>> {
+    let graphic_novel_id = graphic_novel_id as i32;
+    let volume = item.volume as i16;
+    let pages = item.pages.map(|x| x as i16);
+    Insert::new::<DbGraphicNovelVolume>(["volume", "fkgraphicnovel", "pages", "published"])
+      .values([&volume, &graphic_novel_id, &pages, &item.published])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    let volume = volume as i32;
+    let mapped: Vec<(&String, &Option<String>, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| (&x.1.title, &x.1.description, DbLanguage::from(*x.0)))
+      .collect();
+    let mut insert = Insert::new::<DbGraphicNovelVolumeTranslation>([
+      "title",
+      "description",
+      "fkgraphicnovel",
+      "fkgraphicnovelvolume",
+      "language",
+    ]);
+    for (title, description, language) in &mapped {
+      insert.values_ref([*title, *description, &graphic_novel_id, &volume, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn delete(&self, graphic_novel_id: u32, volume: u16) -> Result<(), Box<dyn Error>> {
+    self
+      .mut_graphic_novel_chapter_repository
+      .delete_by_volume(graphic_novel_id, volume)
+      .await?;
+    let graphic_novel_id = graphic_novel_id as i32;
+
+    Delete::new::<DbGraphicNovelVolumeTranslation>(
+      Expression::column_equal(
+        DbGraphicNovelVolumeTranslation::TABLE_NAME,
+        "fkgraphicnovel",
+        graphic_novel_id,
+      )
+      .and(Expression::column_equal(
+        DbGraphicNovelVolumeTranslation::TABLE_NAME,
+        "fkgraphicnovelvolume",
+        volume as i32,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelVolume>(
+      Expression::column_equal(DbGraphicNovelVolume::TABLE_NAME, "fkgraphicnovel", graphic_novel_id).and(
+        Expression::column_equal(DbGraphicNovelVolume::TABLE_NAME, "volume", volume as i16),
+      ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self
+      .mut_graphic_novel_chapter_repository
+      .delete_all(graphic_novel_ids)
+      .await?;
+    let graphic_novel_ids = to_i32(graphic_novel_ids);
+
+    Delete::new::<DbGraphicNovelVolumeTranslation>(Expression::new(ValueIn::new(
+      (DbGraphicNovelVolumeTranslation::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbGraphicNovelVolume>(Expression::new(ValueIn::new(
+      (DbGraphicNovelVolume::TABLE_NAME, "fkgraphicnovel"),
+      &graphic_novel_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_graphic_novel_volume_repository;
+pub mod default_mut_graphic_novel_volume_repository;
@@ -0,0 +1,9 @@
+pub mod default_graphic_novel_repository;
+pub mod default_mut_graphic_novel_repository;
+pub mod graphic_novel_chapter_repository;
+pub mod graphic_novel_character_repository;
+pub mod graphic_novel_genre_repository;
+pub mod graphic_novel_involved_repository;
+pub mod graphic_novel_publisher_repository;
+pub mod graphic_novel_theme_repository;
+pub mod graphic_novel_volume_repository;
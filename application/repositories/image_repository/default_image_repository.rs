@@ -1,7 +1,6 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
 use domain::entities::image::image_data::ImageData;
 use domain::entities::image::Image;
@@ -9,6 +8,7 @@ use domain::items_total::ItemsTotal;
 use domain::pagination::Pagination;
 use repositories::image_repository::ImageRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use crate::schemas::db_image::DbImage;
 use crate::schemas::db_image_data::DbImageData;
@@ -18,11 +18,11 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultImageRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
 }
 
 impl<'a> DefaultImageRepository<'a> {
-  pub const fn new(client: &'a Client) -> DefaultImageRepository<'a> {
+  pub const fn new(client: &'a CachedClient) -> DefaultImageRepository<'a> {
     DefaultImageRepository { client }
   }
 }
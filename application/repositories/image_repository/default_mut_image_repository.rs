@@ -5,7 +5,6 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use image::imageops::FilterType;
 use image::{DynamicImage, ImageFormat};
-use tokio_postgres::Transaction;
 
 use domain::entities::image::create_partial_image::CreatePartialImage;
 use domain::entities::image::Image;
@@ -14,12 +13,17 @@ use repositories::file_repository::FileRepository;
 use repositories::image_repository::mut_image_repository::MutImageRepository;
 use repositories::image_repository::ImageRepository;
 
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
 use crate::insert::Insert;
 use crate::schemas::db_image::DbImage;
 use crate::schemas::db_image_data::DbImageData;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use from_row::Table;
 
 pub struct DefaultMutImageRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   image_repository: Arc<dyn ImageRepository + 'a>,
   mut_file_repository: Arc<dyn MutFileRepository + 'a>,
   file_repository: Arc<dyn FileRepository + 'a>,
@@ -27,7 +31,7 @@ pub struct DefaultMutImageRepository<'a> {
 
 impl<'a> DefaultMutImageRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     image_repository: Arc<dyn ImageRepository + 'a>,
     mut_file_repository: Arc<dyn MutFileRepository + 'a>,
     file_repository: Arc<dyn FileRepository + 'a>,
@@ -73,6 +77,20 @@ impl MutImageRepository for DefaultMutImageRepository<'_> {
         .expect("image was just created, it should exist"),
     )
   }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let ids: Vec<i32> = ids.iter().map(|x| *x as i32).collect();
+    Delete::new::<DbImageData>(Expression::new(ValueIn::new(
+      (DbImageData::TABLE_NAME, "fkimage"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Delete::new::<DbImage>(Expression::new(ValueIn::new((DbImage::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
 }
 
 fn combined(mut value: String, b: &str) -> String {
@@ -0,0 +1,41 @@
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use domain::entities::mail::Mail;
+use repositories::mail_sender::MailSender;
+
+/// Writes every mail into a file of the given directory instead of sending it, for development and tests.
+pub struct FileMailSender {
+  directory: PathBuf,
+}
+
+impl FileMailSender {
+  pub fn new(directory: impl Into<PathBuf>) -> FileMailSender {
+    FileMailSender {
+      directory: directory.into(),
+    }
+  }
+}
+
+#[async_trait]
+impl MailSender for FileMailSender {
+  async fn send(&self, mail: &Mail) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(&self.directory)?;
+    let file_name = format!(
+      "{}-{}.txt",
+      Utc::now().format("%Y%m%d%H%M%S%f"),
+      mail.to.0.replace(|x: char| !x.is_ascii_alphanumeric(), "_")
+    );
+    let path = self.directory.join(file_name);
+    fs::write(
+      &path,
+      format!("To: {}\nSubject: {}\n\n{}\n", mail.to.0, mail.subject, mail.body),
+    )?;
+    println!("Mail to {} written to {}", mail.to.0, path.display());
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod file_mail_sender;
+pub mod smtp_mail_sender;
@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use domain::entities::mail::Mail;
+use repositories::mail_sender::MailSender;
+
+pub struct SmtpMailSender {
+  transport: AsyncSmtpTransport<Tokio1Executor>,
+  from: Mailbox,
+}
+
+impl SmtpMailSender {
+  /// Connects to the relay with implicit TLS on port 465.
+  pub fn new(host: &str, username: String, password: String, from: &str) -> Result<SmtpMailSender, Box<dyn Error>> {
+    let transport = AsyncSmtpTransport::<Tokio1Executor>::relay(host)?
+      .credentials(Credentials::new(username, password))
+      .build();
+    Ok(SmtpMailSender {
+      transport,
+      from: from.parse()?,
+    })
+  }
+}
+
+#[async_trait]
+impl MailSender for SmtpMailSender {
+  async fn send(&self, mail: &Mail) -> Result<(), Box<dyn Error>> {
+    let message = Message::builder()
+      .from(self.from.clone())
+      .to(mail.to.0.parse()?)
+      .subject(mail.subject.clone())
+      .body(mail.body.clone())?;
+    self.transport.send(message).await?;
+    Ok(())
+  }
+}
@@ -1,11 +1,18 @@
 pub mod account_repository;
 pub mod book_repository;
+pub mod company_repository;
 pub mod default_character_repository;
 pub mod file_repository;
 pub mod franchise_repository;
+pub mod game_repository;
 pub mod genre_repository;
+pub mod graphic_novel_repository;
 pub mod image_repository;
+pub mod mail_sender;
+pub mod movie_repository;
 pub mod person_repository;
+pub mod platform_repository;
 pub mod role_repository;
+pub mod show_repository;
 pub mod theme_repository;
 pub mod user_repository;
@@ -0,0 +1,263 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::movie::movie_statistic::MovieStatistic;
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::image_repository::ImageRepository;
+use repositories::movie_repository::MovieRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::{fallback_unwrap, fallback_unwrap_ref};
+use crate::schemas::db_movie::DbMovie;
+use crate::schemas::db_movie_statistic::DbMovieStatistic;
+use crate::schemas::db_movie_translation::DbMovieTranslation;
+use crate::schemas::db_rating::DbRating;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultMovieRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+}
+
+impl<'a> DefaultMovieRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+  ) -> DefaultMovieRepository<'a> {
+    DefaultMovieRepository {
+      client,
+      default_language: default_language.into(),
+      image_repository,
+    }
+  }
+
+  async fn movies_from_tuple(&self, items: Vec<MovieColumns>) -> Result<Vec<Movie>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let image_ids = image_ids(&items);
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+
+    items
+      .into_iter()
+      .map(|item| {
+        let movie_translation = fallback_unwrap(item.1, item.2);
+        let image = images
+          .iter()
+          .find(|y| y.id == movie_translation.fk_cover as u32)
+          .unwrap()
+          .clone();
+        Ok(item.0.to_entity(movie_translation, image))
+      })
+      .collect()
+  }
+  async fn movie_from_tuple(&self, item: MovieColumns) -> Result<Movie, Box<dyn Error>> {
+    let movie_translation = fallback_unwrap(item.1, item.2);
+    let image = self
+      .image_repository
+      .get_by_id(movie_translation.fk_cover as u32)
+      .await?
+      .unwrap();
+    Ok(item.0.to_entity(movie_translation, image))
+  }
+}
+
+fn image_ids(items: &[MovieColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .map(|x| fallback_unwrap_ref(x.1.as_ref(), x.2.as_ref()).fk_cover as u32)
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+#[async_trait]
+impl MovieRepository for DefaultMovieRepository<'_> {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<Movie>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbMovie>()
+      .count()
+      .transform(|x| movie_joins(x, &db_language, &self.default_language))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let movies = movie_select(&db_language, &self.default_language)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    let movies = self.movies_from_tuple(movies).await?;
+    Ok(ItemsTotal { items: movies, total })
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Movie>, Box<dyn Error>> {
+    let id = id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let select = movie_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueEqual::new(("movie", "id"), id)));
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.movie_from_tuple(value).await?))
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Movie>, Box<dyn Error>> {
+    let title = format!("%{title}%");
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbMovie>()
+      .count()
+      .transform(|x| movie_joins(x, &db_language, &self.default_language))
+      .where_expression(
+        Expression::new(ValueILike::new(("movie_translation", "title"), &title)).or(Expression::new(ValueILike::new(
+          ("movie_translation_fallback", "title"),
+          &title,
+        ))),
+      )
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+
+    let total = total.0 as usize;
+
+    let movies = movie_select(&db_language, &self.default_language)
+      .where_expression(
+        Expression::new(ValueILike::new(("movie_translation", "title"), &title)).or(Expression::new(ValueILike::new(
+          ("movie_translation_fallback", "title"),
+          &title,
+        ))),
+      )
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+    let movies = self.movies_from_tuple(movies).await?;
+    Ok(ItemsTotal { items: movies, total })
+  }
+
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<Movie>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+    let ids = to_i32(ids);
+
+    let movies = movie_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueIn::new((DbMovie::TABLE_NAME, "id"), &ids)))
+      .query(self.client)
+      .await?;
+
+    let movies = self.movies_from_tuple(movies).await?;
+
+    Ok(movies)
+  }
+
+  async fn filter_existing(&self, movie_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let movies = to_i32(movie_ids);
+
+    let filtered = Select::new::<DbMovie>()
+      .column::<i32>(DbMovie::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new((DbMovie::TABLE_NAME, "id"), &movies)))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+
+  async fn get_statistics(&self, movie_ids: &[u32]) -> Result<Vec<MovieStatistic>, Box<dyn Error>> {
+    let ids = to_i32(movie_ids);
+
+    let statistics = Select::new::<DbMovieStatistic>()
+      .columns::<DbMovieStatistic>(DbMovieStatistic::TABLE_NAME)
+      .columns::<DbRating>(DbRating::TABLE_NAME)
+      .inner_join::<DbRating>(
+        None,
+        Expression::new(ColumnEqual::new(
+          (DbRating::TABLE_NAME, "id"),
+          (DbMovieStatistic::TABLE_NAME, "fkrating"),
+        )),
+      )
+      .where_expression(Expression::new(ValueIn::new(
+        (DbMovieStatistic::TABLE_NAME, "fkmovie"),
+        &ids,
+      )))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|(statistic, rating)| statistic.to_entity(rating.to_entity()))
+      .collect();
+
+    Ok(statistics)
+  }
+}
+
+fn movie_select<'a>(language: &'a DbLanguage, fallback_language: &'a DbLanguage) -> Select<'a, MovieColumns> {
+  movie_select_columns().transform(|x| movie_joins(x, language, fallback_language))
+}
+
+fn movie_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbMovieTranslation>(
+      Some("movie_translation"),
+      Expression::new(ColumnEqual::new(
+        ("movie_translation", "fktranslation"),
+        ("movie", "id"),
+      ))
+      .and(Expression::new(ValueEqual::new(
+        ("movie_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbMovieTranslation>(
+      Some("movie_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("movie", "id"),
+        ("movie_translation_fallback", "fktranslation"),
+      ))
+      .and(Expression::new(ColumnNull::new(("movie_translation", "fktranslation"))))
+      .and(Expression::new(ValueEqual::new(
+        ("movie_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn movie_select_columns<'a>() -> Select<'a, MovieColumns> {
+  Select::new::<DbMovie>()
+    .columns::<DbMovie>("movie")
+    .columns::<Option<DbMovieTranslation>>("movie_translation")
+    .columns::<Option<DbMovieTranslation>>("movie_translation_fallback")
+}
+
+type MovieColumns = (DbMovie, Option<DbMovieTranslation>, Option<DbMovieTranslation>);
@@ -0,0 +1,171 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::movie::create_partial_movie::CreatePartialMovie;
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use from_row::Table;
+use repositories::movie_repository::movie_genre_repository::mut_movie_genre_repository::MutMovieGenreRepository;
+use repositories::movie_repository::movie_involved_repository::mut_movie_involved_repository::MutMovieInvolvedRepository;
+use repositories::movie_repository::movie_theme_repository::mut_movie_theme_repository::MutMovieThemeRepository;
+use repositories::movie_repository::mut_movie_repository::MutMovieRepository;
+use repositories::movie_repository::MovieRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_movie::DbMovie;
+use crate::schemas::db_movie_statistic::DbMovieStatistic;
+use crate::schemas::db_movie_translation::DbMovieTranslation;
+use crate::schemas::db_rating::DbRating;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+use crate::types::db_interval::DbInterval;
+
+pub struct DefaultMutMovieRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_movie_genre_repository: Arc<dyn MutMovieGenreRepository + 'a>,
+  mut_movie_theme_repository: Arc<dyn MutMovieThemeRepository + 'a>,
+  mut_movie_involved_repository: Arc<dyn MutMovieInvolvedRepository + 'a>,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+}
+
+impl<'a> DefaultMutMovieRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    default_language: Language,
+    mut_movie_genre_repository: Arc<dyn MutMovieGenreRepository + 'a>,
+    mut_movie_theme_repository: Arc<dyn MutMovieThemeRepository + 'a>,
+    mut_movie_involved_repository: Arc<dyn MutMovieInvolvedRepository + 'a>,
+    movie_repository: Arc<dyn MovieRepository + 'a>,
+  ) -> DefaultMutMovieRepository<'a> {
+    DefaultMutMovieRepository {
+      transaction,
+      default_language,
+      mut_movie_genre_repository,
+      mut_movie_theme_repository,
+      mut_movie_involved_repository,
+      movie_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutMovieRepository for DefaultMutMovieRepository<'_> {
+  async fn create(&self, item: CreatePartialMovie) -> Result<Movie, Box<dyn Error>> {
+    let id = self.insert_movie(&item).await? as u32;
+    self.insert_translation(&item, id).await?;
+    self.insert_themes(&item, id).await?;
+    self.insert_genres(&item, id).await?;
+    self.insert_involved(&item, id).await?;
+
+    let movie = self
+      .movie_repository
+      .get_by_id(id, self.default_language)
+      .await?
+      .expect("Movie was just created");
+    Ok(movie)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.mut_movie_genre_repository.remove_all(ids).await?;
+    self.mut_movie_involved_repository.remove_all(ids).await?;
+    self.mut_movie_theme_repository.remove_all(ids).await?;
+    let ids = to_i32(ids);
+
+    Delete::new::<DbMovieStatistic>(Expression::new(ValueIn::new(
+      (DbMovieStatistic::TABLE_NAME, "fkmovie"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbMovieTranslation>(Expression::new(ValueIn::new(
+      (DbMovieTranslation::TABLE_NAME, "fktranslation"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbMovie>(Expression::new(ValueIn::new((DbMovie::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
+
+impl DefaultMutMovieRepository<'_> {
+  async fn insert_involved(&self, item: &CreatePartialMovie, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.involved.is_empty() {
+      return Ok(());
+    }
+    self.mut_movie_involved_repository.add(id, &item.involved).await
+  }
+
+  async fn insert_genres(&self, item: &CreatePartialMovie, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.genres.is_empty() {
+      return Ok(());
+    }
+    self.mut_movie_genre_repository.add(id, &item.genres).await
+  }
+  async fn insert_themes(&self, item: &CreatePartialMovie, id: u32) -> Result<(), Box<dyn Error>> {
+    if item.themes.is_empty() {
+      return Ok(());
+    }
+    self.mut_movie_theme_repository.add(id, &item.themes).await
+  }
+  async fn insert_movie(&self, item: &CreatePartialMovie) -> Result<i32, Box<dyn Error>> {
+    let length = item.length.map(DbInterval::from_minutes);
+    let movie_id: i32 = Insert::new::<DbMovie>(["airing", "length"])
+      .values([&item.airing, &length])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let rating_id: i32 = Insert::new::<DbRating>([])
+      .values([])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let (movie_count,) = Select::new::<DbMovie>()
+      .count()
+      .get_single(self.transaction.client())
+      .await?
+      .ok_or("DbMovie count returned no columns")?;
+    let movie_count = movie_count as i32;
+
+    Insert::new::<DbMovieStatistic>(["fkmovie", "fkrating", "popularity", "rank"])
+      .values([&movie_id, &rating_id, &movie_count, &movie_count])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    Ok(movie_id)
+  }
+  async fn insert_translation(&self, item: &CreatePartialMovie, id: u32) -> Result<(), Box<dyn Error>> {
+    let id = id as i32;
+    let mapped: Vec<(&String, &Option<String>, i32, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| {
+        (
+          &x.1.title,
+          &x.1.description,
+          x.1.cover.id as i32,
+          DbLanguage::from(*x.0),
+        )
+      })
+      .collect();
+    let mut insert =
+      Insert::new::<DbMovieTranslation>(["title", "description", "fkcover", "fktranslation", "language"]);
+    for (title, description, cover_id, language) in &mapped {
+      insert.values_ref([*title, *description, cover_id, &id, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,5 @@
+pub mod default_movie_repository;
+pub mod default_mut_movie_repository;
+pub mod movie_genre_repository;
+pub mod movie_involved_repository;
+pub mod movie_theme_repository;
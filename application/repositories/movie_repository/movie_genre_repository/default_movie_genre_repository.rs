@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use domain::entities::genre::Genre;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::genre_repository::GenreRepository;
+use repositories::movie_repository::movie_genre_repository::MovieGenreRepository;
+use repositories::movie_repository::MovieRepository;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_movie_genre::DbMovieGenre;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultMovieGenreRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+}
+
+impl<'a> DefaultMovieGenreRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    movie_repository: Arc<dyn MovieRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+  ) -> DefaultMovieGenreRepository<'a> {
+    DefaultMovieGenreRepository {
+      client,
+      default_language: default_language.into(),
+      movie_repository,
+      genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MovieGenreRepository for DefaultMovieGenreRepository<'_> {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Genre>, Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+
+    let total = Select::new::<DbMovieGenre>()
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbMovieGenre::TABLE_NAME, "fkmovie"),
+        movie_id,
+      )))
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let genre_ids: Vec<u32> = Select::new::<DbMovieGenre>()
+      .column::<i32>(DbMovieGenre::TABLE_NAME, "fkgenre")
+      .where_expression(Expression::new(ValueEqual::new(
+        (DbMovieGenre::TABLE_NAME, "fkmovie"),
+        movie_id,
+      )))
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+
+    let items = match genre_ids.is_empty() {
+      true => vec![],
+      false => self.genre_repository.get_by_ids(&genre_ids, language).await?,
+    };
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn filter_existing(&self, movie_id: u32, genres: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let genres = to_i32(genres);
+
+    let filtered = Select::new::<DbMovieGenre>()
+      .column::<i32>(DbMovieGenre::TABLE_NAME, "fkgenre")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbMovieGenre::TABLE_NAME, "fkgenre"),
+        &genres,
+      )))
+      .where_expression(Expression::column_equal(DbMovieGenre::TABLE_NAME, "fkmovie", movie_id))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::movie_repository::movie_genre_repository::mut_movie_genre_repository::MutMovieGenreRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_movie_genre::DbMovieGenre;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutMovieGenreRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutMovieGenreRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutMovieGenreRepository<'a> {
+    DefaultMutMovieGenreRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutMovieGenreRepository for DefaultMutMovieGenreRepository<'_> {
+  async fn add(&self, movie_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let genres = to_i32(genres);
+    let mut insert =
+      Insert::new::<DbMovieGenre>(["fkmovie", "fkgenre"]).on_conflict_do_nothing(&["fkmovie", "fkgenre"]);
+    genres.iter().for_each(|x| {
+      insert.values_ref([&movie_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, movie_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let genres = to_i32(genres);
+
+    Delete::new::<DbMovieGenre>(
+      Expression::column_equal(DbMovieGenre::TABLE_NAME, "fkmovie", movie_id).and(Expression::new(ValueIn::new(
+        (DbMovieGenre::TABLE_NAME, "fkgenre"),
+        &genres,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+  async fn remove_all(&self, movie_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_ids = to_i32(movie_ids);
+
+    Delete::new::<DbMovieGenre>(Expression::new(ValueIn::new(
+      (DbMovieGenre::TABLE_NAME, "fkmovie"),
+      &movie_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,76 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use domain::entities::involved::InvolvedId;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use from_row::Table;
+use repositories::movie_repository::movie_involved_repository::mut_movie_involved_repository::MutMovieInvolvedRepository;
+
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_movie_involved::DbMovieInvolved;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutMovieInvolvedRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutMovieInvolvedRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutMovieInvolvedRepository<'a> {
+    DefaultMutMovieInvolvedRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutMovieInvolvedRepository for DefaultMutMovieInvolvedRepository<'_> {
+  async fn add(&self, movie_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+    let mut insert = Insert::new::<DbMovieInvolved>(["fkmovie", "fkperson", "fkrole"])
+      .on_conflict_do_nothing(&["fkmovie", "fkperson", "fkrole"]);
+    involved.iter().for_each(|(x, y)| {
+      insert.values_ref([&movie_id, x, y]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, movie_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let involved: Vec<(i32, i32)> = involved
+      .iter()
+      .map(|x| (x.person_id as i32, x.role_id as i32))
+      .collect();
+
+    Delete::new::<DbMovieInvolved>(
+      Expression::column_equal(DbMovieInvolved::TABLE_NAME, "fkmovie", movie_id).and(Expression::new(ValueIn::new(
+        (
+          (DbMovieInvolved::TABLE_NAME, "fkperson"),
+          (DbMovieInvolved::TABLE_NAME, "fkrole"),
+        ),
+        &involved,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, movie_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_ids = to_i32(movie_ids);
+
+    Delete::new::<DbMovieInvolved>(Expression::new(ValueIn::new(
+      (DbMovieInvolved::TABLE_NAME, "fkmovie"),
+      &movie_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
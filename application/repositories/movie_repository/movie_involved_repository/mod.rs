@@ -0,0 +1,2 @@
+pub mod default_movie_involved_repository;
+pub mod default_mut_movie_involved_repository;
@@ -0,0 +1,66 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::movie_repository::movie_theme_repository::mut_movie_theme_repository::MutMovieThemeRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_movie_theme::DbMovieTheme;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutMovieThemeRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutMovieThemeRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutMovieThemeRepository<'a> {
+    DefaultMutMovieThemeRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutMovieThemeRepository for DefaultMutMovieThemeRepository<'_> {
+  async fn add(&self, movie_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let themes = to_i32(themes);
+    let mut insert =
+      Insert::new::<DbMovieTheme>(["fkmovie", "fktheme"]).on_conflict_do_nothing(&["fkmovie", "fktheme"]);
+    themes.iter().for_each(|x| {
+      insert.values_ref([&movie_id, x]);
+    });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn remove(&self, movie_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_id = movie_id as i32;
+    let themes = to_i32(themes);
+
+    Delete::new::<DbMovieTheme>(
+      Expression::column_equal(DbMovieTheme::TABLE_NAME, "fkmovie", movie_id).and(Expression::new(ValueIn::new(
+        (DbMovieTheme::TABLE_NAME, "fktheme"),
+        &themes,
+      ))),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn remove_all(&self, movie_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let movie_ids = to_i32(movie_ids);
+
+    Delete::new::<DbMovieTheme>(Expression::new(ValueIn::new(
+      (DbMovieTheme::TABLE_NAME, "fkmovie"),
+      &movie_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::person::create_partial_person::CreatePartialPerson;
 use domain::entities::person::Person;
@@ -11,6 +10,7 @@ use from_row::Table;
 use repositories::person_repository::mut_person_repository::MutPersonRepository;
 use repositories::person_repository::PersonRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::enums::db_language::DbLanguage;
@@ -21,14 +21,14 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutPersonRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   person_repository: Arc<dyn PersonRepository + 'a>,
 }
 
 impl<'a> DefaultMutPersonRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     default_language: Language,
     person_repository: Arc<dyn PersonRepository + 'a>,
   ) -> DefaultMutPersonRepository<'a> {
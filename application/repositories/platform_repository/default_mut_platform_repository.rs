@@ -0,0 +1,90 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::create_partial_platform::CreatePartialPlatform;
+use domain::entities::platform::Platform;
+use from_row::Table;
+use repositories::platform_repository::mut_platform_repository::MutPlatformRepository;
+use repositories::platform_repository::PlatformRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_game_platform::DbGamePlatform;
+use crate::schemas::db_platform::DbPlatform;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::update::Update;
+
+pub struct DefaultMutPlatformRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+}
+
+impl<'a> DefaultMutPlatformRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    platform_repository: Arc<dyn PlatformRepository + 'a>,
+  ) -> DefaultMutPlatformRepository<'a> {
+    DefaultMutPlatformRepository {
+      transaction,
+      platform_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutPlatformRepository for DefaultMutPlatformRepository<'_> {
+  async fn create(&self, item: CreatePartialPlatform) -> Result<Platform, Box<dyn Error>> {
+    let company_id = item.company as i32;
+    let logo_id = item.logo.id as i32;
+    let id: i32 = Insert::new::<DbPlatform>(["name", "shortname", "fkcompany", "fklogo"])
+      .values([&item.name, &item.short_name, &company_id, &logo_id])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let platform = self
+      .platform_repository
+      .get_by_id(id as u32)
+      .await?
+      .expect("Platform was just created");
+    Ok(platform)
+  }
+
+  async fn update(&self, id: u32, item: CreatePartialPlatform) -> Result<Platform, Box<dyn Error>> {
+    Update::new::<DbPlatform>()
+      .set("name", item.name)
+      .set("shortname", item.short_name)
+      .set("fkcompany", item.company as i32)
+      .set("fklogo", item.logo.id as i32)
+      .where_expression(Expression::column_equal(DbPlatform::TABLE_NAME, "id", id as i32))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(
+      self
+        .platform_repository
+        .get_by_id(id)
+        .await?
+        .ok_or("Platform to update does not exist")?,
+    )
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let ids = to_i32(ids);
+
+    Delete::new::<DbGamePlatform>(Expression::new(ValueIn::new(
+      (DbGamePlatform::TABLE_NAME, "fkplatform"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbPlatform>(Expression::new(ValueIn::new((DbPlatform::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
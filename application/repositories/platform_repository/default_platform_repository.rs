@@ -0,0 +1,190 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::company_repository::CompanyRepository;
+use repositories::image_repository::ImageRepository;
+use repositories::platform_repository::PlatformRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::schemas::db_platform::DbPlatform;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultPlatformRepository<'a> {
+  client: &'a CachedClient,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultPlatformRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+  ) -> DefaultPlatformRepository<'a> {
+    DefaultPlatformRepository {
+      client,
+      image_repository,
+      company_repository,
+    }
+  }
+
+  async fn to_entities(&self, items: Vec<(DbPlatform,)>) -> Result<Vec<Platform>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+    let mut image_ids: Vec<u32> = items.iter().map(|x| x.0.fk_logo as u32).collect();
+    image_ids.sort_unstable();
+    image_ids.dedup();
+    let mut company_ids: Vec<u32> = items.iter().map(|x| x.0.fk_company as u32).collect();
+    company_ids.sort_unstable();
+    company_ids.dedup();
+
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+    let companies = self.company_repository.get_by_ids(&company_ids).await?;
+
+    Ok(
+      items
+        .into_iter()
+        .map(|x| {
+          let logo = images
+            .iter()
+            .find(|y| y.id == x.0.fk_logo as u32)
+            .expect("Platform logo should exist")
+            .clone();
+          let company = companies
+            .iter()
+            .find(|y| y.id == x.0.fk_company as u32)
+            .expect("Platform company should exist")
+            .clone();
+          x.0.to_entity(company, logo)
+        })
+        .collect(),
+    )
+  }
+}
+
+#[async_trait]
+impl PlatformRepository for DefaultPlatformRepository<'_> {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Platform>, Box<dyn Error>> {
+    let total = Select::new::<DbPlatform>()
+      .count()
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let platforms = Select::new::<DbPlatform>()
+      .columns::<DbPlatform>(DbPlatform::TABLE_NAME)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    Ok(ItemsTotal {
+      items: self.to_entities(platforms).await?,
+      total,
+    })
+  }
+
+  async fn get_by_id(&self, id: u32) -> Result<Option<Platform>, Box<dyn Error>> {
+    let id = id as i32;
+    let platform = Select::new::<DbPlatform>()
+      .columns::<DbPlatform>(DbPlatform::TABLE_NAME)
+      .where_expression(Expression::new(ValueEqual::new((DbPlatform::TABLE_NAME, "id"), id)))
+      .get_single(self.client)
+      .await?;
+    Ok(self.to_entities(platform.into_iter().collect()).await?.pop())
+  }
+
+  async fn get_by_ids(&self, ids: &[u32]) -> Result<Vec<Platform>, Box<dyn Error>> {
+    let ids = to_i32(ids);
+
+    let platforms = Select::new::<DbPlatform>()
+      .columns::<DbPlatform>(DbPlatform::TABLE_NAME)
+      .where_expression(Expression::new(ValueIn::new((DbPlatform::TABLE_NAME, "id"), &ids)))
+      .query(self.client)
+      .await?;
+
+    self.to_entities(platforms).await
+  }
+
+  async fn get_by_name(&self, name: &str, pagination: Pagination) -> Result<ItemsTotal<Platform>, Box<dyn Error>> {
+    let name = format!("%{name}%");
+
+    let total = Select::new::<DbPlatform>()
+      .count()
+      .where_expression(
+        Expression::new(ValueILike::new((DbPlatform::TABLE_NAME, "name"), &name)).or(Expression::new(ValueILike::new(
+          (DbPlatform::TABLE_NAME, "shortname"),
+          &name,
+        ))),
+      )
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let platforms = Select::new::<DbPlatform>()
+      .columns::<DbPlatform>(DbPlatform::TABLE_NAME)
+      .where_expression(
+        Expression::new(ValueILike::new((DbPlatform::TABLE_NAME, "name"), &name)).or(Expression::new(ValueILike::new(
+          (DbPlatform::TABLE_NAME, "shortname"),
+          &name,
+        ))),
+      )
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    Ok(ItemsTotal {
+      items: self.to_entities(platforms).await?,
+      total,
+    })
+  }
+
+  async fn filter_existing(&self, platforms: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let platforms = to_i32(platforms);
+
+    let filtered = Select::new::<DbPlatform>()
+      .column::<i32>(DbPlatform::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbPlatform::TABLE_NAME, "id"),
+        &platforms,
+      )))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+
+  async fn filter_companies_with_platforms(&self, companies: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let companies = to_i32(companies);
+
+    let mut filtered: Vec<u32> = Select::new::<DbPlatform>()
+      .column::<i32>(DbPlatform::TABLE_NAME, "fkcompany")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbPlatform::TABLE_NAME, "fkcompany"),
+        &companies,
+      )))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    filtered.sort_unstable();
+    filtered.dedup();
+    Ok(filtered)
+  }
+}
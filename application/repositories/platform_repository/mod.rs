@@ -0,0 +1,2 @@
+pub mod default_mut_platform_repository;
+pub mod default_platform_repository;
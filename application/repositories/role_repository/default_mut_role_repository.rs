@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::role::create_partial_role::CreatePartialRole;
 use domain::entities::role::Role;
@@ -11,6 +10,7 @@ use from_row::Table;
 use repositories::role_repository::mut_role_repository::MutRoleRepository;
 use repositories::role_repository::RoleRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::enums::db_language::DbLanguage;
@@ -21,14 +21,14 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutRoleRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   role_repository: Arc<dyn RoleRepository + 'a>,
 }
 
 impl<'a> DefaultMutRoleRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     default_language: Language,
     role_repository: Arc<dyn RoleRepository + 'a>,
   ) -> DefaultMutRoleRepository<'a> {
@@ -1,7 +1,6 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
 use domain::entities::role::Role;
 use domain::enums::language::Language;
@@ -10,6 +9,7 @@ use domain::pagination::Pagination;
 use from_row::{FromRow, Table};
 use repositories::role_repository::RoleRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use crate::enums::db_language::DbLanguage;
 use crate::fallback_unwrap::fallback_unwrap;
@@ -25,12 +25,12 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultRoleRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   default_language: DbLanguage,
 }
 
 impl<'a> DefaultRoleRepository<'a> {
-  pub fn new(client: &'a Client, language: Language) -> DefaultRoleRepository<'a> {
+  pub fn new(client: &'a CachedClient, language: Language) -> DefaultRoleRepository<'a> {
     DefaultRoleRepository {
       client,
       default_language: language.into(),
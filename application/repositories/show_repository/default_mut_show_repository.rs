@@ -0,0 +1,176 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_partial_show::CreatePartialShow;
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use from_row::Table;
+use repositories::show_repository::mut_show_repository::MutShowRepository;
+use repositories::show_repository::show_season_repository::mut_show_season_repository::MutShowSeasonRepository;
+use repositories::show_repository::ShowRepository;
+use repositories::user_repository::mut_user_average_repository::MutUserAverageRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::enums::db_status::DbStatus;
+use crate::insert::Insert;
+use crate::schemas::db_rating::DbRating;
+use crate::schemas::db_show::DbShow;
+use crate::schemas::db_show_statistic::DbShowStatistic;
+use crate::schemas::db_show_translation::DbShowTranslation;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+/// Genres, themes, involved and characters of shows can not be added yet, their rows are still removed on delete
+const SHOW_RELATION_TABLES: [&str; 4] = ["showgenre", "showtheme", "showinvolved", "showcharacter"];
+const USER_SHOW_TABLE: &str = "usershow";
+
+pub struct DefaultMutShowRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_show_season_repository: Arc<dyn MutShowSeasonRepository + 'a>,
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+  show_repository: Arc<dyn ShowRepository + 'a>,
+}
+
+impl<'a> DefaultMutShowRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    default_language: Language,
+    mut_show_season_repository: Arc<dyn MutShowSeasonRepository + 'a>,
+    mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+    show_repository: Arc<dyn ShowRepository + 'a>,
+  ) -> DefaultMutShowRepository<'a> {
+    DefaultMutShowRepository {
+      transaction,
+      default_language,
+      mut_show_season_repository,
+      mut_user_average_repository,
+      show_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutShowRepository for DefaultMutShowRepository<'_> {
+  async fn create(&self, item: CreatePartialShow) -> Result<Show, Box<dyn Error>> {
+    let id = self.insert_show(&item).await? as u32;
+    self.insert_translation(&item, id).await?;
+
+    let show = self
+      .show_repository
+      .get_by_id(id, self.default_language)
+      .await?
+      .expect("Show was just created");
+    Ok(show)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.mut_show_season_repository.delete_all(ids).await?;
+    let ids = to_i32(ids);
+
+    for table in SHOW_RELATION_TABLES {
+      Delete::new_raw(table, Expression::new(ValueIn::new((table, "fkshow"), &ids)))
+        .execute_transaction(self.transaction)
+        .await?;
+    }
+    self.delete_user_shows(&ids).await?;
+
+    Delete::new::<DbShowStatistic>(Expression::new(ValueIn::new(
+      (DbShowStatistic::TABLE_NAME, "fkshow"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShowTranslation>(Expression::new(ValueIn::new(
+      (DbShowTranslation::TABLE_NAME, "fktranslation"),
+      &ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShow>(Expression::new(ValueIn::new((DbShow::TABLE_NAME, "id"), &ids)))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
+
+impl DefaultMutShowRepository<'_> {
+  async fn delete_user_shows(&self, show_ids: &[i32]) -> Result<(), Box<dyn Error>> {
+    let mut user_ids: Vec<u32> = Select::new_raw(USER_SHOW_TABLE)
+      .column::<i32>(USER_SHOW_TABLE, "fkuser")
+      .where_expression(Expression::new(ValueIn::new((USER_SHOW_TABLE, "fkshow"), show_ids)))
+      .query(self.transaction.client())
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    Delete::new_raw(
+      USER_SHOW_TABLE,
+      Expression::new(ValueIn::new((USER_SHOW_TABLE, "fkshow"), show_ids)),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    self.mut_user_average_repository.recompute(&user_ids).await
+  }
+
+  async fn insert_show(&self, item: &CreatePartialShow) -> Result<i32, Box<dyn Error>> {
+    let status = DbStatus::from(item.status);
+    let franchise = item.franchise.map(|x| x as i32);
+    let show_id: i32 = Insert::new::<DbShow>(["airingstart", "airingend", "status", "fkfranchise"])
+      .values([&item.airing_start, &item.airing_end, &status, &franchise])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let rating_id: i32 = Insert::new::<DbRating>([])
+      .values([])
+      .returning_transaction("id", self.transaction)
+      .await?;
+
+    let (show_count,) = Select::new::<DbShow>()
+      .count()
+      .get_single(self.transaction.client())
+      .await?
+      .ok_or("DbShow count returned no columns")?;
+    let show_count = show_count as i32;
+
+    Insert::new::<DbShowStatistic>(["fkshow", "fkrating", "popularity", "rank"])
+      .values([&show_id, &rating_id, &show_count, &show_count])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    Ok(show_id)
+  }
+
+  async fn insert_translation(&self, item: &CreatePartialShow, id: u32) -> Result<(), Box<dyn Error>> {
+    let id = id as i32;
+    let mapped: Vec<(&String, &Option<String>, i32, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| {
+        (
+          &x.1.title,
+          &x.1.description,
+          x.1.cover.id as i32,
+          DbLanguage::from(*x.0),
+        )
+      })
+      .collect();
+    let mut insert = Insert::new::<DbShowTranslation>(["title", "description", "fkcover", "fktranslation", "language"]);
+    for (title, description, cover_id, language) in &mapped {
+      insert.values_ref([*title, *description, cover_id, &id, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,259 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::franchise_repository::FranchiseRepository;
+use repositories::image_repository::ImageRepository;
+use repositories::show_repository::ShowRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::convert_to_sql::to_i32;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::{fallback_unwrap, fallback_unwrap_ref};
+use crate::schemas::db_show::DbShow;
+use crate::schemas::db_show_translation::DbShowTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultShowRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+}
+
+impl<'a> DefaultShowRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+    franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+  ) -> DefaultShowRepository<'a> {
+    DefaultShowRepository {
+      client,
+      default_language: default_language.into(),
+      image_repository,
+      franchise_repository,
+    }
+  }
+
+  async fn shows_from_tuple(&self, items: Vec<ShowColumns>, language: Language) -> Result<Vec<Show>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let image_ids = image_ids(&items);
+    let franchise_ids = franchise_ids(&items);
+    let images = self.image_repository.get_by_ids(&image_ids).await?;
+    let franchises = match franchise_ids.is_empty() {
+      true => vec![],
+      false => self.franchise_repository.get_by_ids(&franchise_ids, language).await?,
+    };
+
+    items
+      .into_iter()
+      .map(|item| {
+        let show_translation = fallback_unwrap(item.1, item.2);
+        let franchise = franchises
+          .iter()
+          .find(|y| match item.0.fk_franchise {
+            None => false,
+            Some(id) => id as u32 == y.id,
+          })
+          .cloned();
+        let image = images
+          .iter()
+          .find(|y| y.id == show_translation.fk_cover as u32)
+          .unwrap()
+          .clone();
+        Ok(item.0.to_entity(show_translation, image, franchise))
+      })
+      .collect()
+  }
+  async fn show_from_tuple(&self, item: ShowColumns, language: Language) -> Result<Show, Box<dyn Error>> {
+    let show_translation = fallback_unwrap(item.1, item.2);
+    let image = self
+      .image_repository
+      .get_by_id(show_translation.fk_cover as u32)
+      .await?
+      .unwrap();
+    let franchise = match item.0.fk_franchise {
+      None => None,
+      Some(value) => self.franchise_repository.get_by_id(value as u32, language).await?,
+    };
+    Ok(item.0.to_entity(show_translation, image, franchise))
+  }
+}
+
+fn image_ids(items: &[ShowColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .map(|x| fallback_unwrap_ref(x.1.as_ref(), x.2.as_ref()).fk_cover as u32)
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+fn franchise_ids(items: &[ShowColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .filter_map(|x| x.0.fk_franchise.map(|x| x as u32))
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+#[async_trait]
+impl ShowRepository for DefaultShowRepository<'_> {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<Show>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbShow>()
+      .count()
+      .transform(|x| show_joins(x, &db_language, &self.default_language))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let shows = show_select(&db_language, &self.default_language)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    let shows = self.shows_from_tuple(shows, language).await?;
+    Ok(ItemsTotal { items: shows, total })
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Show>, Box<dyn Error>> {
+    let id = id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let select = show_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueEqual::new(("show", "id"), id)));
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.show_from_tuple(value, language).await?))
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Show>, Box<dyn Error>> {
+    let title = format!("%{title}%");
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbShow>()
+      .count()
+      .transform(|x| show_joins(x, &db_language, &self.default_language))
+      .where_expression(
+        Expression::new(ValueILike::new(("show_translation", "title"), &title)).or(Expression::new(ValueILike::new(
+          ("show_translation_fallback", "title"),
+          &title,
+        ))),
+      )
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+
+    let total = total.0 as usize;
+
+    let shows = show_select(&db_language, &self.default_language)
+      .where_expression(
+        Expression::new(ValueILike::new(("show_translation", "title"), &title)).or(Expression::new(ValueILike::new(
+          ("show_translation_fallback", "title"),
+          &title,
+        ))),
+      )
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+    let shows = self.shows_from_tuple(shows, language).await?;
+    Ok(ItemsTotal { items: shows, total })
+  }
+
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<Show>, Box<dyn Error>> {
+    let db_language = DbLanguage::from(language);
+    let ids = to_i32(ids);
+
+    let shows = show_select(&db_language, &self.default_language)
+      .where_expression(Expression::new(ValueIn::new((DbShow::TABLE_NAME, "id"), &ids)))
+      .query(self.client)
+      .await?;
+
+    let shows = self.shows_from_tuple(shows, language).await?;
+
+    Ok(shows)
+  }
+
+  async fn filter_existing(&self, show_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+    let shows = to_i32(show_ids);
+
+    let filtered = Select::new::<DbShow>()
+      .column::<i32>(DbShow::TABLE_NAME, "id")
+      .where_expression(Expression::new(ValueIn::new((DbShow::TABLE_NAME, "id"), &shows)))
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    Ok(filtered)
+  }
+}
+
+fn show_select<'a>(language: &'a DbLanguage, fallback_language: &'a DbLanguage) -> Select<'a, ShowColumns> {
+  show_select_columns().transform(|x| show_joins(x, language, fallback_language))
+}
+
+fn show_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbShowTranslation>(
+      Some("show_translation"),
+      Expression::new(ColumnEqual::new(("show_translation", "fktranslation"), ("show", "id"))).and(Expression::new(
+        ValueEqual::new(("show_translation", "language"), language),
+      )),
+    )
+    .left_join::<DbShowTranslation>(
+      Some("show_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("show", "id"),
+        ("show_translation_fallback", "fktranslation"),
+      ))
+      .and(Expression::new(ColumnNull::new(("show_translation", "fktranslation"))))
+      .and(Expression::new(ValueEqual::new(
+        ("show_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn show_select_columns<'a>() -> Select<'a, ShowColumns> {
+  Select::new::<DbShow>()
+    .columns::<DbShow>("show")
+    .columns::<Option<DbShowTranslation>>("show_translation")
+    .columns::<Option<DbShowTranslation>>("show_translation_fallback")
+}
+
+type ShowColumns = (DbShow, Option<DbShowTranslation>, Option<DbShowTranslation>);
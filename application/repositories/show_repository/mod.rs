@@ -0,0 +1,4 @@
+pub mod default_mut_show_repository;
+pub mod default_show_repository;
+pub mod show_episode_repository;
+pub mod show_season_repository;
@@ -0,0 +1,129 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_episode::CreateShowEpisode;
+use from_row::Table;
+use repositories::show_repository::show_episode_repository::mut_show_episode_repository::MutShowEpisodeRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_show_episode::DbShowEpisode;
+use crate::schemas::db_show_episode_translation::DbShowEpisodeTranslation;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::conditions::value_in_select::ValueInSelect;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+pub struct DefaultMutShowEpisodeRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutShowEpisodeRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutShowEpisodeRepository<'a> {
+    DefaultMutShowEpisodeRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutShowEpisodeRepository for DefaultMutShowEpisodeRepository<'_> {
+  async fn create(&self, show_id: u32, season: u16, item: CreateShowEpisode) -> Result<(), Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = season as i32;
+    let episode = item.episode as i16;
+    let length = item.length.map(|x| x as i16);
+    Insert::new::<DbShowEpisode>(["episode", "fkshow", "fkseason", "length", "airing"])
+      .values([&episode, &show_id, &season, &length, &item.airing])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    let episode = episode as i32;
+    let mapped: Vec<(&String, &Option<String>, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| (&x.1.title, &x.1.description, DbLanguage::from(*x.0)))
+      .collect();
+    let mut insert =
+      Insert::new::<DbShowEpisodeTranslation>(["title", "description", "fkshow", "fkshowepisode", "language"]);
+    for (title, description, language) in &mapped {
+      insert.values_ref([*title, *description, &show_id, &episode, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn delete(&self, show_id: u32, episode: u16) -> Result<(), Box<dyn Error>> {
+    let show_id = show_id as i32;
+
+    Delete::new::<DbShowEpisodeTranslation>(
+      Expression::column_equal(DbShowEpisodeTranslation::TABLE_NAME, "fkshow", show_id).and(Expression::column_equal(
+        DbShowEpisodeTranslation::TABLE_NAME,
+        "fkshowepisode",
+        episode as i32,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShowEpisode>(
+      Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id).and(Expression::column_equal(
+        DbShowEpisode::TABLE_NAME,
+        "episode",
+        episode as i16,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_by_season(&self, show_id: u32, season: u16) -> Result<(), Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = season as i32;
+
+    let episodes = Select::new::<DbShowEpisode>()
+      .column::<i16>(DbShowEpisode::TABLE_NAME, "episode")
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkseason", season));
+    Delete::new::<DbShowEpisodeTranslation>(
+      Expression::column_equal(DbShowEpisodeTranslation::TABLE_NAME, "fkshow", show_id).and(Expression::new(
+        ValueInSelect::new((DbShowEpisodeTranslation::TABLE_NAME, "fkshowepisode"), episodes),
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShowEpisode>(
+      Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id).and(Expression::column_equal(
+        DbShowEpisode::TABLE_NAME,
+        "fkseason",
+        season,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_all(&self, show_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let show_ids = to_i32(show_ids);
+
+    Delete::new::<DbShowEpisodeTranslation>(Expression::new(ValueIn::new(
+      (DbShowEpisodeTranslation::TABLE_NAME, "fkshow"),
+      &show_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShowEpisode>(Expression::new(ValueIn::new(
+      (DbShowEpisode::TABLE_NAME, "fkshow"),
+      &show_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,220 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::image_repository::ImageRepository;
+use repositories::show_repository::show_episode_repository::ShowEpisodeRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::{fallback_unwrap, fallback_unwrap_ref};
+use crate::schemas::db_show_episode::DbShowEpisode;
+use crate::schemas::db_show_episode_translation::DbShowEpisodeTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultShowEpisodeRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+}
+
+impl<'a> DefaultShowEpisodeRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    default_language: Language,
+    image_repository: Arc<dyn ImageRepository + 'a>,
+  ) -> DefaultShowEpisodeRepository<'a> {
+    DefaultShowEpisodeRepository {
+      client,
+      default_language: default_language.into(),
+      image_repository,
+    }
+  }
+
+  async fn episodes_from_tuple(&self, items: Vec<ShowEpisodeColumns>) -> Result<Vec<ShowEpisode>, Box<dyn Error>> {
+    if items.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let image_ids = image_ids(&items);
+    let images = match image_ids.is_empty() {
+      true => vec![],
+      false => self.image_repository.get_by_ids(&image_ids).await?,
+    };
+
+    items
+      .into_iter()
+      .map(|item| {
+        let episode_translation = fallback_unwrap(item.1, item.2);
+        let image = episode_translation
+          .fk_cover
+          .and_then(|id| images.iter().find(|y| y.id == id as u32).cloned());
+        Ok(item.0.to_entity(episode_translation, image))
+      })
+      .collect()
+  }
+
+  async fn episode_from_tuple(&self, item: ShowEpisodeColumns) -> Result<ShowEpisode, Box<dyn Error>> {
+    let episode_translation = fallback_unwrap(item.1, item.2);
+    let image = match episode_translation.fk_cover {
+      None => None,
+      Some(id) => self.image_repository.get_by_id(id as u32).await?,
+    };
+    Ok(item.0.to_entity(episode_translation, image))
+  }
+}
+
+fn image_ids(items: &[ShowEpisodeColumns]) -> Vec<u32> {
+  let mut result = items
+    .iter()
+    .filter_map(|x| {
+      fallback_unwrap_ref(x.1.as_ref(), x.2.as_ref())
+        .fk_cover
+        .map(|x| x as u32)
+    })
+    .collect::<Vec<u32>>();
+  result.sort_unstable();
+  result.dedup();
+  result
+}
+
+#[async_trait]
+impl ShowEpisodeRepository for DefaultShowEpisodeRepository<'_> {
+  async fn get(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowEpisode>, Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = season as i32;
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbShowEpisode>()
+      .count()
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkseason", season))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let episodes = show_episode_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkseason", season))
+      .order_by((DbShowEpisode::TABLE_NAME, "episode"), Direction::Ascending, None)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+    let items = self.episodes_from_tuple(episodes).await?;
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn get_by_episode(
+    &self,
+    show_id: u32,
+    season: u16,
+    episode: u16,
+    language: Language,
+  ) -> Result<Option<ShowEpisode>, Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = season as i32;
+    let episode = episode as i16;
+    let db_language = DbLanguage::from(language);
+
+    let select = show_episode_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkseason", season))
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "episode", episode));
+
+    let Some(value) = select.get_single(self.client).await? else {
+      return Ok(None);
+    };
+    Ok(Some(self.episode_from_tuple(value).await?))
+  }
+
+  async fn exists(&self, show_id: u32, episode: u16) -> Result<bool, Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let episode = episode as i16;
+
+    let item = Select::new::<DbShowEpisode>()
+      .column::<i16>(DbShowEpisode::TABLE_NAME, "episode")
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowEpisode::TABLE_NAME, "episode", episode))
+      .get_single(self.client)
+      .await?;
+    Ok(item.is_some())
+  }
+}
+
+fn show_episode_select<'a>(
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, ShowEpisodeColumns> {
+  show_episode_select_columns().transform(|x| show_episode_joins(x, language, fallback_language))
+}
+
+fn show_episode_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbShowEpisodeTranslation>(
+      Some("show_episode_translation"),
+      Expression::new(ColumnEqual::new(
+        ("show_episode_translation", "fkshow"),
+        (DbShowEpisode::TABLE_NAME, "fkshow"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("show_episode_translation", "fkshowepisode"),
+        (DbShowEpisode::TABLE_NAME, "episode"),
+      )))
+      .and(Expression::new(ValueEqual::new(
+        ("show_episode_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbShowEpisodeTranslation>(
+      Some("show_episode_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("show_episode_translation_fallback", "fkshow"),
+        (DbShowEpisode::TABLE_NAME, "fkshow"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("show_episode_translation_fallback", "fkshowepisode"),
+        (DbShowEpisode::TABLE_NAME, "episode"),
+      )))
+      .and(Expression::new(ColumnNull::new(("show_episode_translation", "fkshow"))))
+      .and(Expression::new(ValueEqual::new(
+        ("show_episode_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn show_episode_select_columns<'a>() -> Select<'a, ShowEpisodeColumns> {
+  Select::new::<DbShowEpisode>()
+    .columns::<DbShowEpisode>(DbShowEpisode::TABLE_NAME)
+    .columns::<Option<DbShowEpisodeTranslation>>("show_episode_translation")
+    .columns::<Option<DbShowEpisodeTranslation>>("show_episode_translation_fallback")
+}
+
+type ShowEpisodeColumns = (
+  DbShowEpisode,
+  Option<DbShowEpisodeTranslation>,
+  Option<DbShowEpisodeTranslation>,
+);
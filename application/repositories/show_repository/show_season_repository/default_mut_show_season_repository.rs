@@ -0,0 +1,112 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_season::CreateShowSeason;
+use from_row::Table;
+use repositories::show_repository::show_episode_repository::mut_show_episode_repository::MutShowEpisodeRepository;
+use repositories::show_repository::show_season_repository::mut_show_season_repository::MutShowSeasonRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_language::DbLanguage;
+use crate::insert::Insert;
+use crate::schemas::db_show_season::DbShowSeason;
+use crate::schemas::db_show_season_translation::DbShowSeasonTranslation;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutShowSeasonRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  mut_show_episode_repository: Arc<dyn MutShowEpisodeRepository + 'a>,
+}
+
+impl<'a> DefaultMutShowSeasonRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    mut_show_episode_repository: Arc<dyn MutShowEpisodeRepository + 'a>,
+  ) -> DefaultMutShowSeasonRepository<'a> {
+    DefaultMutShowSeasonRepository {
+      transaction,
+      mut_show_episode_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutShowSeasonRepository for DefaultMutShowSeasonRepository<'_> {
+  async fn create(&self, show_id: u32, item: CreateShowSeason) -> Result<(), Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = item.season as i16;
+    let episodes = item.episodes.map(|x| x as i16);
+    Insert::new::<DbShowSeason>(["season", "fkshow", "episodes", "airingstart", "airingend"])
+      .values([&season, &show_id, &episodes, &item.airing_start, &item.airing_end])
+      .execute_transaction(self.transaction)
+      .await?;
+
+    let season = season as i32;
+    let mapped: Vec<(&String, &Option<String>, DbLanguage)> = item
+      .translations
+      .iter()
+      .map(|x| (&x.1.title, &x.1.description, DbLanguage::from(*x.0)))
+      .collect();
+    let mut insert =
+      Insert::new::<DbShowSeasonTranslation>(["title", "description", "fkshow", "fkshowseason", "language"]);
+    for (title, description, language) in &mapped {
+      insert.values_ref([*title, *description, &show_id, &season, language]);
+    }
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  async fn delete(&self, show_id: u32, season: u16) -> Result<(), Box<dyn Error>> {
+    self
+      .mut_show_episode_repository
+      .delete_by_season(show_id, season)
+      .await?;
+    let show_id = show_id as i32;
+
+    Delete::new::<DbShowSeasonTranslation>(
+      Expression::column_equal(DbShowSeasonTranslation::TABLE_NAME, "fkshow", show_id).and(Expression::column_equal(
+        DbShowSeasonTranslation::TABLE_NAME,
+        "fkshowseason",
+        season as i32,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShowSeason>(
+      Expression::column_equal(DbShowSeason::TABLE_NAME, "fkshow", show_id).and(Expression::column_equal(
+        DbShowSeason::TABLE_NAME,
+        "season",
+        season as i16,
+      )),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn delete_all(&self, show_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.mut_show_episode_repository.delete_all(show_ids).await?;
+    let show_ids = to_i32(show_ids);
+
+    Delete::new::<DbShowSeasonTranslation>(Expression::new(ValueIn::new(
+      (DbShowSeasonTranslation::TABLE_NAME, "fkshow"),
+      &show_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    Delete::new::<DbShowSeason>(Expression::new(ValueIn::new(
+      (DbShowSeason::TABLE_NAME, "fkshow"),
+      &show_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,160 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_season::ShowSeason;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::show_repository::show_season_repository::ShowSeasonRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::enums::db_language::DbLanguage;
+use crate::fallback_unwrap::fallback_unwrap;
+use crate::schemas::db_show_season::DbShowSeason;
+use crate::schemas::db_show_season_translation::DbShowSeasonTranslation;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::value_equal::ValueEqual;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultShowSeasonRepository<'a> {
+  client: &'a CachedClient,
+  default_language: DbLanguage,
+}
+
+impl<'a> DefaultShowSeasonRepository<'a> {
+  pub fn new(client: &'a CachedClient, default_language: Language) -> DefaultShowSeasonRepository<'a> {
+    DefaultShowSeasonRepository {
+      client,
+      default_language: default_language.into(),
+    }
+  }
+}
+
+#[async_trait]
+impl ShowSeasonRepository for DefaultShowSeasonRepository<'_> {
+  async fn get(
+    &self,
+    show_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowSeason>, Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let db_language = DbLanguage::from(language);
+
+    let total = Select::new::<DbShowSeason>()
+      .count()
+      .where_expression(Expression::column_equal(DbShowSeason::TABLE_NAME, "fkshow", show_id))
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let items = show_season_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(DbShowSeason::TABLE_NAME, "fkshow", show_id))
+      .order_by((DbShowSeason::TABLE_NAME, "season"), Direction::Ascending, None)
+      .pagination(pagination)
+      .query(self.client)
+      .await?
+      .into_iter()
+      .map(|item| item.0.to_entity(fallback_unwrap(item.1, item.2)))
+      .collect();
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn get_by_season(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+  ) -> Result<Option<ShowSeason>, Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = season as i16;
+    let db_language = DbLanguage::from(language);
+
+    let item = show_season_select(&db_language, &self.default_language)
+      .where_expression(Expression::column_equal(DbShowSeason::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowSeason::TABLE_NAME, "season", season))
+      .get_single(self.client)
+      .await?
+      .map(|item| item.0.to_entity(fallback_unwrap(item.1, item.2)));
+    Ok(item)
+  }
+
+  async fn exists(&self, show_id: u32, season: u16) -> Result<bool, Box<dyn Error>> {
+    let show_id = show_id as i32;
+    let season = season as i16;
+
+    let item = Select::new::<DbShowSeason>()
+      .column::<i16>(DbShowSeason::TABLE_NAME, "season")
+      .where_expression(Expression::column_equal(DbShowSeason::TABLE_NAME, "fkshow", show_id))
+      .where_expression(Expression::column_equal(DbShowSeason::TABLE_NAME, "season", season))
+      .get_single(self.client)
+      .await?;
+    Ok(item.is_some())
+  }
+}
+
+fn show_season_select<'a>(
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, ShowSeasonColumns> {
+  show_season_select_columns().transform(|x| show_season_joins(x, language, fallback_language))
+}
+
+fn show_season_joins<'a, T: from_row::FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  language: &'a DbLanguage,
+  fallback_language: &'a DbLanguage,
+) -> Select<'a, T> {
+  select
+    .left_join::<DbShowSeasonTranslation>(
+      Some("show_season_translation"),
+      Expression::new(ColumnEqual::new(
+        ("show_season_translation", "fkshow"),
+        (DbShowSeason::TABLE_NAME, "fkshow"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("show_season_translation", "fkshowseason"),
+        (DbShowSeason::TABLE_NAME, "season"),
+      )))
+      .and(Expression::new(ValueEqual::new(
+        ("show_season_translation", "language"),
+        language,
+      ))),
+    )
+    .left_join::<DbShowSeasonTranslation>(
+      Some("show_season_translation_fallback"),
+      Expression::new(ColumnEqual::new(
+        ("show_season_translation_fallback", "fkshow"),
+        (DbShowSeason::TABLE_NAME, "fkshow"),
+      ))
+      .and(Expression::new(ColumnEqual::new(
+        ("show_season_translation_fallback", "fkshowseason"),
+        (DbShowSeason::TABLE_NAME, "season"),
+      )))
+      .and(Expression::new(ColumnNull::new(("show_season_translation", "fkshow"))))
+      .and(Expression::new(ValueEqual::new(
+        ("show_season_translation_fallback", "language"),
+        fallback_language,
+      ))),
+    )
+}
+
+fn show_season_select_columns<'a>() -> Select<'a, ShowSeasonColumns> {
+  Select::new::<DbShowSeason>()
+    .columns::<DbShowSeason>(DbShowSeason::TABLE_NAME)
+    .columns::<Option<DbShowSeasonTranslation>>("show_season_translation")
+    .columns::<Option<DbShowSeasonTranslation>>("show_season_translation_fallback")
+}
+
+type ShowSeasonColumns = (
+  DbShowSeason,
+  Option<DbShowSeasonTranslation>,
+  Option<DbShowSeasonTranslation>,
+);
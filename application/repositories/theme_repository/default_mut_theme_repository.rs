@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::theme::create_partial_theme::CreatePartialTheme;
 use domain::entities::theme::Theme;
@@ -11,6 +10,7 @@ use from_row::Table;
 use repositories::theme_repository::mut_theme_repository::MutThemeRepository;
 use repositories::theme_repository::ThemeRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::convert_to_sql::to_i32;
 use crate::delete::Delete;
 use crate::enums::db_language::DbLanguage;
@@ -21,14 +21,14 @@ use crate::select::conditions::value_in::ValueIn;
 use crate::select::expression::Expression;
 
 pub struct DefaultMutThemeRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   theme_repository: Arc<dyn ThemeRepository + 'a>,
 }
 
 impl<'a> DefaultMutThemeRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     default_language: Language,
     theme_repository: Arc<dyn ThemeRepository + 'a>,
   ) -> DefaultMutThemeRepository<'a> {
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use from_row::Table;
+use repositories::user_repository::mut_user_average_repository::MutUserAverageRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::schemas::db_user::DbUser;
+use crate::schemas::db_user_average::DbUserAverage;
+use crate::schemas::db_user_book::DbUserBook;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+
+/// Keeps the amount of parameters per statement well below the postgres limit
+const CHUNK_SIZE: usize = 1000;
+
+pub struct DefaultMutUserAverageRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutUserAverageRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutUserAverageRepository<'a> {
+    DefaultMutUserAverageRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutUserAverageRepository for DefaultMutUserAverageRepository<'_> {
+  async fn recompute(&self, user_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    for chunk in to_i32(user_ids).chunks(CHUNK_SIZE) {
+      self.recompute_chunk(chunk).await?;
+    }
+    Ok(())
+  }
+
+  async fn recompute_all(&self) -> Result<usize, Box<dyn Error>> {
+    let user_ids: Vec<i32> = Select::new::<DbUser>()
+      .column::<i32>(DbUser::TABLE_NAME, "id")
+      .query(self.transaction.client())
+      .await?
+      .into_iter()
+      .map(|x| x.0)
+      .collect();
+    for chunk in user_ids.chunks(CHUNK_SIZE) {
+      self.recompute_chunk(chunk).await?;
+    }
+    Ok(user_ids.len())
+  }
+}
+
+impl DefaultMutUserAverageRepository<'_> {
+  async fn recompute_chunk(&self, user_ids: &[i32]) -> Result<(), Box<dyn Error>> {
+    if user_ids.is_empty() {
+      return Ok(());
+    }
+    let graphic_novel = self.averages("usergraphicnovel", user_ids).await?;
+    let show = self.averages("usershow", user_ids).await?;
+    let movie = self.averages("usermovie", user_ids).await?;
+    let book = self.averages(DbUserBook::TABLE_NAME, user_ids).await?;
+    let game = self.averages("usergame", user_ids).await?;
+
+    Delete::new::<DbUserAverage>(Expression::new(ValueIn::new(
+      (DbUserAverage::TABLE_NAME, "fkuser"),
+      user_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+
+    let values: Vec<(i32, [Option<f32>; 5])> = user_ids
+      .iter()
+      .map(|id| {
+        let averages = [
+          graphic_novel.get(id).copied(),
+          show.get(id).copied(),
+          movie.get(id).copied(),
+          book.get(id).copied(),
+          game.get(id).copied(),
+        ];
+        (*id, averages)
+      })
+      .filter(|(_, averages)| averages.iter().any(Option::is_some))
+      .collect();
+    if values.is_empty() {
+      return Ok(());
+    }
+
+    let mut insert = Insert::new::<DbUserAverage>([
+      "fkuser",
+      "graphicnovelaverage",
+      "showaverage",
+      "movieaverage",
+      "bookaverage",
+      "gameaverage",
+    ]);
+    values
+      .iter()
+      .for_each(|(id, [graphic_novel, show, movie, book, game])| {
+        insert.values_ref([id, graphic_novel, show, movie, book, game]);
+      });
+    insert.execute_transaction(self.transaction).await?;
+    Ok(())
+  }
+
+  /// Average score per user of one of the user media tables, users without any score are left out
+  async fn averages(&self, table: &str, user_ids: &[i32]) -> Result<HashMap<i32, f32>, Box<dyn Error>> {
+    let averages = Select::new_raw(table)
+      .column::<i32>(table, "fkuser")
+      .average(table, "score")
+      .where_expression(Expression::new(ValueIn::new((table, "fkuser"), user_ids)))
+      .group_by(table, "fkuser")
+      .query(self.transaction.client())
+      .await?;
+
+    Ok(
+      averages
+        .into_iter()
+        .filter_map(|(user_id, average)| average.map(|average| (user_id, average)))
+        .collect(),
+    )
+  }
+}
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Transaction;
 
 use domain::entities::user::create_partial_user::CreatePartialUser;
 use domain::entities::user::User;
@@ -10,18 +9,22 @@ use repositories::image_repository::ImageRepository;
 use repositories::user_repository::mut_user_repository::MutUserRepository;
 use repositories::user_repository::UserRepository;
 
+use crate::cached_connection::CachedTransaction;
 use crate::insert::Insert;
 use crate::schemas::db_user::DbUser;
+use crate::select::expression::Expression;
+use crate::update::Update;
+use from_row::Table;
 
 pub struct DefaultMutUserRepository<'a> {
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   user_repository: Arc<dyn UserRepository + 'a>,
   image_repository: Arc<dyn ImageRepository + 'a>,
 }
 
 impl<'a> DefaultMutUserRepository<'a> {
   pub fn new(
-    transaction: &'a Transaction<'a>,
+    transaction: &'a CachedTransaction<'a>,
     user_repository: Arc<dyn UserRepository + 'a>,
     image_repository: Arc<dyn ImageRepository + 'a>,
   ) -> DefaultMutUserRepository<'a> {
@@ -52,4 +55,35 @@ impl MutUserRepository for DefaultMutUserRepository<'_> {
         .expect("User was just created, they should exist"),
     )
   }
+
+  async fn update(&self, user_id: u32, user: CreatePartialUser) -> Result<User, Box<dyn Error>> {
+    Update::new::<DbUser>()
+      .set("name", user.name)
+      .set("description", user.description)
+      .set("fkprofilepicture", user.profile_picture.map(|x| x.id as i32))
+      .where_expression(Expression::column_equal(DbUser::TABLE_NAME, "id", user_id as i32))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(
+      self
+        .user_repository
+        .get_by_id(user_id)
+        .await?
+        .ok_or("User to update does not exist")?,
+    )
+  }
+
+  async fn anonymize(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let name = format!("deleted-user-{user_id}");
+    Update::new::<DbUser>()
+      .set("name", name)
+      .set("description", None::<String>)
+      .set("fkprofilepicture", None::<i32>)
+      .set("deleted", true)
+      .where_expression(Expression::column_equal(DbUser::TABLE_NAME, "id", user_id))
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
 }
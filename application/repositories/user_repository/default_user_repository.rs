@@ -2,9 +2,7 @@ use std::error::Error;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio_postgres::Client;
 
-use domain::entities::image::Image;
 use domain::entities::user::User;
 use domain::items_total::ItemsTotal;
 use domain::pagination::Pagination;
@@ -12,8 +10,11 @@ use from_row::Table;
 use repositories::image_repository::ImageRepository;
 use repositories::user_repository::UserRepository;
 
+use crate::cached_connection::CachedClient;
 use crate::convert_to_sql::to_i32;
 use crate::schemas::db_user::DbUser;
+use crate::schemas::db_user_average::DbUserAverage;
+use crate::select::conditions::column_equal::ColumnEqual;
 use crate::select::conditions::value_equal::ValueEqual;
 use crate::select::conditions::value_ilike::ValueILike;
 use crate::select::conditions::value_in::ValueIn;
@@ -21,12 +22,12 @@ use crate::select::expression::Expression;
 use crate::select::Select;
 
 pub struct DefaultUserRepository<'a> {
-  client: &'a Client,
+  client: &'a CachedClient,
   image_repository: Arc<dyn ImageRepository + 'a>,
 }
 
 impl<'a> DefaultUserRepository<'a> {
-  pub fn new(client: &'a Client, image_repository: Arc<dyn ImageRepository + 'a>) -> DefaultUserRepository<'a> {
+  pub fn new(client: &'a CachedClient, image_repository: Arc<dyn ImageRepository + 'a>) -> DefaultUserRepository<'a> {
     DefaultUserRepository {
       client,
       image_repository,
@@ -44,11 +45,7 @@ impl UserRepository for DefaultUserRepository<'_> {
       .expect("Count should return one row");
     let total = total.0 as usize;
 
-    let users = Select::new::<DbUser>()
-      .columns::<DbUser>(DbUser::TABLE_NAME)
-      .pagination(pagination)
-      .query(self.client)
-      .await?;
+    let users = user_select().pagination(pagination).query(self.client).await?;
 
     Ok(ItemsTotal {
       items: self.to_entities(users).await?,
@@ -58,8 +55,7 @@ impl UserRepository for DefaultUserRepository<'_> {
 
   async fn get_by_id(&self, id: u32) -> Result<Option<User>, Box<dyn Error>> {
     let id = id as i32;
-    let user = Select::new::<DbUser>()
-      .columns::<DbUser>(DbUser::TABLE_NAME)
+    let user = user_select()
       .where_expression(Expression::new(ValueEqual::new((DbUser::TABLE_NAME, "id"), id)))
       .get_single(self.client)
       .await?;
@@ -68,14 +64,13 @@ impl UserRepository for DefaultUserRepository<'_> {
       None => None,
       Some(id) => self.image_repository.get_by_id(id as u32).await?,
     };
-    Ok(user.map(|x| to_entity(x, image)))
+    Ok(user.map(|x| x.0.to_entity(image, x.1)))
   }
 
   async fn get_by_ids(&self, ids: &[u32]) -> Result<Vec<User>, Box<dyn Error>> {
     let ids = to_i32(ids);
 
-    let users = Select::new::<DbUser>()
-      .columns::<DbUser>(DbUser::TABLE_NAME)
+    let users = user_select()
       .where_expression(Expression::new(ValueIn::new((DbUser::TABLE_NAME, "id"), &ids)))
       .query(self.client)
       .await?;
@@ -94,8 +89,7 @@ impl UserRepository for DefaultUserRepository<'_> {
       .expect("Count should return one row");
     let total = total.0 as usize;
 
-    let users = Select::new::<DbUser>()
-      .columns::<DbUser>(DbUser::TABLE_NAME)
+    let users = user_select()
       .where_expression(Expression::new(ValueILike::new((DbUser::TABLE_NAME, "name"), &name)))
       .pagination(pagination)
       .query(self.client)
@@ -107,6 +101,15 @@ impl UserRepository for DefaultUserRepository<'_> {
     })
   }
 
+  async fn get_id_by_exact_name(&self, name: &str) -> Result<Option<u32>, Box<dyn Error>> {
+    let id = Select::new::<DbUser>()
+      .column::<i32>(DbUser::TABLE_NAME, "id")
+      .where_expression(Expression::column_equal(DbUser::TABLE_NAME, "name", name))
+      .get_single(self.client)
+      .await?;
+    Ok(id.map(|x| x.0 as u32))
+  }
+
   async fn filter_existing(&self, users: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
     let users = to_i32(users);
 
@@ -122,12 +125,23 @@ impl UserRepository for DefaultUserRepository<'_> {
   }
 }
 
-fn to_entity(user: (DbUser,), image: Option<Image>) -> User {
-  user.0.to_entity(image)
+fn user_select<'a>() -> Select<'a, UserColumns> {
+  Select::new::<DbUser>()
+    .columns::<DbUser>(DbUser::TABLE_NAME)
+    .columns::<Option<DbUserAverage>>(DbUserAverage::TABLE_NAME)
+    .left_join::<DbUserAverage>(
+      None,
+      Expression::new(ColumnEqual::new(
+        (DbUserAverage::TABLE_NAME, "fkuser"),
+        (DbUser::TABLE_NAME, "id"),
+      )),
+    )
 }
 
+type UserColumns = (DbUser, Option<DbUserAverage>);
+
 impl<'a> DefaultUserRepository<'a> {
-  async fn to_entities(&self, items: Vec<(DbUser,)>) -> Result<Vec<User>, Box<dyn Error>> {
+  async fn to_entities(&self, items: Vec<UserColumns>) -> Result<Vec<User>, Box<dyn Error>> {
     let image_ids: Vec<u32> = items
       .iter()
       .filter_map(|x| x.0.fk_profile_picture.map(|x| x as u32))
@@ -146,7 +160,7 @@ impl<'a> DefaultUserRepository<'a> {
             .fk_profile_picture
             .and_then(|x| images.iter().position(|y| y.id == x as u32));
           let image = image_index.map(|x| images.swap_remove(x));
-          x.0.to_entity(image)
+          x.0.to_entity(image, x.1)
         })
         .collect(),
     )
@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::user::friendship::Friendship;
+use domain::enums::friendship_status::FriendshipStatus;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::{FromRow, Table};
+use repositories::user_repository::friendship_repository::FriendshipRepository;
+use repositories::user_repository::UserRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::enums::db_friendship_status::DbFriendshipStatus;
+use crate::schemas::db_friendship::DbFriendship;
+use crate::schemas::db_user::DbUser;
+use crate::select::combined_tuple::CombinedType;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultFriendshipRepository<'a> {
+  client: &'a CachedClient,
+  user_repository: Arc<dyn UserRepository + 'a>,
+}
+
+impl<'a> DefaultFriendshipRepository<'a> {
+  pub fn new(
+    client: &'a CachedClient,
+    user_repository: Arc<dyn UserRepository + 'a>,
+  ) -> DefaultFriendshipRepository<'a> {
+    DefaultFriendshipRepository {
+      client,
+      user_repository,
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+  Incoming,
+  Outgoing,
+  Both,
+}
+
+#[async_trait]
+impl FriendshipRepository for DefaultFriendshipRepository<'_> {
+  async fn get_friends(&self, user_id: u32, pagination: Pagination) -> Result<ItemsTotal<Friendship>, Box<dyn Error>> {
+    self
+      .get_friendships(user_id, FriendshipStatus::Accepted, Side::Both, pagination)
+      .await
+  }
+
+  async fn get_incoming(&self, user_id: u32, pagination: Pagination) -> Result<ItemsTotal<Friendship>, Box<dyn Error>> {
+    self
+      .get_friendships(user_id, FriendshipStatus::Pending, Side::Incoming, pagination)
+      .await
+  }
+
+  async fn get_outgoing(&self, user_id: u32, pagination: Pagination) -> Result<ItemsTotal<Friendship>, Box<dyn Error>> {
+    self
+      .get_friendships(user_id, FriendshipStatus::Pending, Side::Outgoing, pagination)
+      .await
+  }
+
+  async fn get_by_users(&self, user_id: u32, other_user_id: u32) -> Result<Option<Friendship>, Box<dyn Error>> {
+    let friendship = Select::new::<DbFriendship>()
+      .columns::<DbFriendship>(DbFriendship::TABLE_NAME)
+      .where_expression(between(user_id as i32, other_user_id as i32))
+      .get_single(self.client)
+      .await?;
+    let Some((friendship,)) = friendship else {
+      return Ok(None);
+    };
+
+    let user = self
+      .user_repository
+      .get_by_id(other_user_id)
+      .await?
+      .expect("Users of a friendship should exist");
+    Ok(Some(friendship.to_entity(user_id, user)))
+  }
+}
+
+impl DefaultFriendshipRepository<'_> {
+  async fn get_friendships(
+    &self,
+    user_id: u32,
+    status: FriendshipStatus,
+    side: Side,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Friendship>, Box<dyn Error>> {
+    let id = user_id as i32;
+    let status = DbFriendshipStatus::from(status);
+
+    let total = friendship_filter(Select::new::<DbFriendship>().count(), id, status, side)
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let friendships = friendship_filter(
+      Select::new::<DbFriendship>().columns::<DbFriendship>(DbFriendship::TABLE_NAME),
+      id,
+      status,
+      side,
+    )
+    .order_by((DbFriendship::TABLE_NAME, "added"), Direction::Descending, None)
+    .pagination(pagination)
+    .query(self.client)
+    .await?;
+
+    if friendships.is_empty() {
+      return Ok(ItemsTotal { items: vec![], total });
+    }
+
+    let user_ids: Vec<u32> = friendships.iter().map(|x| x.0.other_user(user_id)).collect();
+    let users = self.user_repository.get_by_ids(&user_ids).await?;
+    let items = friendships
+      .into_iter()
+      .filter_map(|(friendship,)| {
+        let other_user_id = friendship.other_user(user_id);
+        let user = users.iter().find(|y| y.id == other_user_id)?.clone();
+        Some(friendship.to_entity(user_id, user))
+      })
+      .collect();
+
+    Ok(ItemsTotal { items, total })
+  }
+}
+
+/// Joins the other user of the friendship and skips deleted ones.
+fn friendship_filter<'a, T: FromRow<DbType = T> + CombinedType>(
+  select: Select<'a, T>,
+  user_id: i32,
+  status: DbFriendshipStatus,
+  side: Side,
+) -> Select<'a, T> {
+  let incoming = Expression::new(ColumnEqual::new(
+    (DbUser::TABLE_NAME, "id"),
+    (DbFriendship::TABLE_NAME, "fkuser"),
+  ))
+  .and(Expression::column_equal(
+    DbFriendship::TABLE_NAME,
+    "fkseconduser",
+    user_id,
+  ));
+  let outgoing = Expression::new(ColumnEqual::new(
+    (DbUser::TABLE_NAME, "id"),
+    (DbFriendship::TABLE_NAME, "fkseconduser"),
+  ))
+  .and(Expression::column_equal(DbFriendship::TABLE_NAME, "fkuser", user_id));
+  let join = match side {
+    Side::Incoming => incoming,
+    Side::Outgoing => outgoing,
+    Side::Both => incoming.or(outgoing),
+  };
+
+  select
+    .inner_join::<DbUser>(None, join)
+    .where_expression(Expression::column_equal(DbFriendship::TABLE_NAME, "status", status))
+    .where_expression(Expression::column_equal(DbUser::TABLE_NAME, "deleted", false))
+}
+
+fn between<'a>(user_id: i32, other_user_id: i32) -> Expression<'a> {
+  Expression::column_equal(DbFriendship::TABLE_NAME, "fkuser", user_id)
+    .and(Expression::column_equal(
+      DbFriendship::TABLE_NAME,
+      "fkseconduser",
+      other_user_id,
+    ))
+    .or(
+      Expression::column_equal(DbFriendship::TABLE_NAME, "fkuser", other_user_id).and(Expression::column_equal(
+        DbFriendship::TABLE_NAME,
+        "fkseconduser",
+        user_id,
+      )),
+    )
+}
@@ -0,0 +1,83 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::enums::friendship_status::FriendshipStatus;
+use from_row::Table;
+use repositories::user_repository::friendship_repository::mut_friendship_repository::MutFriendshipRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::delete::Delete;
+use crate::enums::db_friendship_status::DbFriendshipStatus;
+use crate::insert::Insert;
+use crate::schemas::db_friendship::DbFriendship;
+use crate::select::expression::Expression;
+
+pub struct DefaultMutFriendshipRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+}
+
+impl<'a> DefaultMutFriendshipRepository<'a> {
+  pub const fn new(transaction: &'a CachedTransaction<'a>) -> DefaultMutFriendshipRepository<'a> {
+    DefaultMutFriendshipRepository { transaction }
+  }
+}
+
+#[async_trait]
+impl MutFriendshipRepository for DefaultMutFriendshipRepository<'_> {
+  async fn create(&self, user_id: u32, other_user_id: u32) -> Result<bool, Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let other_user_id = other_user_id as i32;
+    let status = DbFriendshipStatus::from(FriendshipStatus::Pending);
+
+    let inserted = Insert::new::<DbFriendship>(["fkuser", "fkseconduser", "status"])
+      .values([&user_id, &other_user_id, &status])
+      .on_conflict_do_nothing(&[])
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(inserted > 0)
+  }
+
+  async fn set_status(&self, user_id: u32, other_user_id: u32, status: FriendshipStatus) -> Result<(), Box<dyn Error>> {
+    self.delete(user_id, other_user_id).await?;
+    self.insert(user_id, other_user_id, status).await
+  }
+
+  async fn delete(&self, user_id: u32, other_user_id: u32) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let other_user_id = other_user_id as i32;
+
+    Delete::new::<DbFriendship>(
+      Expression::column_equal(DbFriendship::TABLE_NAME, "fkuser", user_id)
+        .and(Expression::column_equal(
+          DbFriendship::TABLE_NAME,
+          "fkseconduser",
+          other_user_id,
+        ))
+        .or(
+          Expression::column_equal(DbFriendship::TABLE_NAME, "fkuser", other_user_id).and(Expression::column_equal(
+            DbFriendship::TABLE_NAME,
+            "fkseconduser",
+            user_id,
+          )),
+        ),
+    )
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
+
+impl DefaultMutFriendshipRepository<'_> {
+  async fn insert(&self, user_id: u32, other_user_id: u32, status: FriendshipStatus) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let other_user_id = other_user_id as i32;
+    let status = DbFriendshipStatus::from(status);
+
+    Insert::new::<DbFriendship>(["fkuser", "fkseconduser", "status"])
+      .values([&user_id, &other_user_id, &status])
+      .execute_transaction(self.transaction)
+      .await?;
+    Ok(())
+  }
+}
@@ -1,2 +1,5 @@
+pub mod default_mut_user_average_repository;
 pub mod default_mut_user_repository;
 pub mod default_user_repository;
+pub mod friendship_repository;
+pub mod user_book_repository;
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use domain::entities::user::create_user_book::CreateUserBook;
+use from_row::Table;
+use repositories::user_repository::mut_user_average_repository::MutUserAverageRepository;
+use repositories::user_repository::user_book_repository::mut_user_book_repository::MutUserBookRepository;
+
+use crate::cached_connection::CachedTransaction;
+use crate::convert_to_sql::to_i32;
+use crate::delete::Delete;
+use crate::enums::db_user_status::DbUserStatus;
+use crate::insert::Insert;
+use crate::schemas::db_user_book::DbUserBook;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::expression::Expression;
+use crate::select::Select;
+use crate::update::Update;
+
+pub struct DefaultMutUserBookRepository<'a> {
+  transaction: &'a CachedTransaction<'a>,
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+}
+
+impl<'a> DefaultMutUserBookRepository<'a> {
+  pub fn new(
+    transaction: &'a CachedTransaction<'a>,
+    mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+  ) -> DefaultMutUserBookRepository<'a> {
+    DefaultMutUserBookRepository {
+      transaction,
+      mut_user_average_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutUserBookRepository for DefaultMutUserBookRepository<'_> {
+  async fn create(&self, user_id: u32, book_id: u32, item: &CreateUserBook) -> Result<(), Box<dyn Error>> {
+    self.insert(user_id, book_id, item).await?;
+    self.mut_user_average_repository.recompute(&[user_id]).await
+  }
+
+  async fn update(&self, user_id: u32, book_id: u32, item: &CreateUserBook) -> Result<(), Box<dyn Error>> {
+    Update::new::<DbUserBook>()
+      .set("userstatus", DbUserStatus::from(item.status))
+      .set("favorite", item.favorite)
+      .set("score", item.score.map(i16::from))
+      .set("review", item.review.clone())
+      .set("start", item.start)
+      .set("finished", item.finished)
+      .set("chapters", item.chapters.map(|x| x as i16))
+      .set("pages", item.pages.map(|x| x as i16))
+      .where_expression(Expression::column_equal(
+        DbUserBook::TABLE_NAME,
+        "fkuser",
+        user_id as i32,
+      ))
+      .where_expression(Expression::column_equal(
+        DbUserBook::TABLE_NAME,
+        "fkbook",
+        book_id as i32,
+      ))
+      .execute_transaction(self.transaction)
+      .await?;
+    self.mut_user_average_repository.recompute(&[user_id]).await
+  }
+
+  async fn delete(&self, user_id: u32, book_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    self.delete_entries(user_id, book_ids).await?;
+    self.mut_user_average_repository.recompute(&[user_id]).await
+  }
+
+  async fn remove_all(&self, book_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let book_ids = to_i32(book_ids);
+
+    let mut user_ids: Vec<u32> = Select::new::<DbUserBook>()
+      .column::<i32>(DbUserBook::TABLE_NAME, "fkuser")
+      .where_expression(Expression::new(ValueIn::new(
+        (DbUserBook::TABLE_NAME, "fkbook"),
+        &book_ids,
+      )))
+      .query(self.transaction.client())
+      .await?
+      .into_iter()
+      .map(|x| x.0 as u32)
+      .collect();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    Delete::new::<DbUserBook>(Expression::new(ValueIn::new(
+      (DbUserBook::TABLE_NAME, "fkbook"),
+      &book_ids,
+    )))
+    .execute_transaction(self.transaction)
+    .await?;
+    self.mut_user_average_repository.recompute(&user_ids).await
+  }
+}
+
+impl DefaultMutUserBookRepository<'_> {
+  async fn delete_entries(&self, user_id: u32, book_ids: &[u32]) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let book_ids = to_i32(book_ids);
+
+    Delete::new::<DbUserBook>(Expression::column_equal(DbUserBook::TABLE_NAME, "fkuser", user_id).and(
+      Expression::new(ValueIn::new((DbUserBook::TABLE_NAME, "fkbook"), &book_ids)),
+    ))
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+
+  async fn insert(&self, user_id: u32, book_id: u32, item: &CreateUserBook) -> Result<(), Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let book_id = book_id as i32;
+    let status = DbUserStatus::from(item.status);
+    let score = item.score.map(i16::from);
+    let chapters = item.chapters.map(|x| x as i16);
+    let pages = item.pages.map(|x| x as i16);
+    let added = Utc::now().date_naive();
+
+    Insert::new::<DbUserBook>([
+      "fkuser",
+      "fkbook",
+      "userstatus",
+      "favorite",
+      "score",
+      "review",
+      "start",
+      "finished",
+      "chapters",
+      "pages",
+      "added",
+    ])
+    .values([
+      &user_id,
+      &book_id,
+      &status,
+      &item.favorite,
+      &score,
+      &item.review,
+      &item.start,
+      &item.finished,
+      &chapters,
+      &pages,
+      &added,
+    ])
+    .execute_transaction(self.transaction)
+    .await?;
+    Ok(())
+  }
+}
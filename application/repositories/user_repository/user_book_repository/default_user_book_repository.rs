@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::user::user_book::UserBook;
+use domain::enums::language::Language;
+use domain::enums::user_status::UserStatus;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use from_row::Table;
+use repositories::book_repository::BookRepository;
+use repositories::user_repository::user_book_repository::UserBookRepository;
+
+use crate::cached_connection::CachedClient;
+use crate::enums::db_user_status::DbUserStatus;
+use crate::schemas::db_user_book::DbUserBook;
+use crate::select::expression::Expression;
+use crate::select::order_by::Direction;
+use crate::select::Select;
+
+pub struct DefaultUserBookRepository<'a> {
+  client: &'a CachedClient,
+  book_repository: Arc<dyn BookRepository + 'a>,
+}
+
+impl<'a> DefaultUserBookRepository<'a> {
+  pub fn new(client: &'a CachedClient, book_repository: Arc<dyn BookRepository + 'a>) -> DefaultUserBookRepository<'a> {
+    DefaultUserBookRepository {
+      client,
+      book_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl UserBookRepository for DefaultUserBookRepository<'_> {
+  async fn get(
+    &self,
+    user_id: u32,
+    status: Option<UserStatus>,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<UserBook>, Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let status = status.map(DbUserStatus::from);
+
+    let mut total = Select::new::<DbUserBook>()
+      .count()
+      .where_expression(Expression::column_equal(DbUserBook::TABLE_NAME, "fkuser", user_id));
+    let mut user_books = Select::new::<DbUserBook>()
+      .columns::<DbUserBook>(DbUserBook::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbUserBook::TABLE_NAME, "fkuser", user_id));
+    if let Some(status) = status {
+      total = total.where_expression(Expression::column_equal(DbUserBook::TABLE_NAME, "userstatus", status));
+      user_books = user_books.where_expression(Expression::column_equal(DbUserBook::TABLE_NAME, "userstatus", status));
+    }
+
+    let total = total
+      .get_single(self.client)
+      .await?
+      .expect("Count should return one row");
+    let total = total.0 as usize;
+
+    let user_books = user_books
+      .order_by((DbUserBook::TABLE_NAME, "added"), Direction::Descending, None)
+      .order_by((DbUserBook::TABLE_NAME, "fkbook"), Direction::Ascending, None)
+      .pagination(pagination)
+      .query(self.client)
+      .await?;
+
+    if user_books.is_empty() {
+      return Ok(ItemsTotal { items: vec![], total });
+    }
+
+    let book_ids: Vec<u32> = user_books.iter().map(|x| x.0.fk_book as u32).collect();
+    let books = self.book_repository.get_by_ids(&book_ids, language).await?;
+    let items = user_books
+      .into_iter()
+      .filter_map(|(user_book,)| {
+        let book = books.iter().find(|y| y.id == user_book.fk_book as u32)?.clone();
+        Some(user_book.to_entity(book))
+      })
+      .collect();
+
+    Ok(ItemsTotal { items, total })
+  }
+
+  async fn get_by_id(
+    &self,
+    user_id: u32,
+    book_id: u32,
+    language: Language,
+  ) -> Result<Option<UserBook>, Box<dyn Error>> {
+    let user_id = user_id as i32;
+    let book_id = book_id as i32;
+
+    let user_book = Select::new::<DbUserBook>()
+      .columns::<DbUserBook>(DbUserBook::TABLE_NAME)
+      .where_expression(Expression::column_equal(DbUserBook::TABLE_NAME, "fkuser", user_id))
+      .where_expression(Expression::column_equal(DbUserBook::TABLE_NAME, "fkbook", book_id))
+      .get_single(self.client)
+      .await?;
+    let Some((user_book,)) = user_book else {
+      return Ok(None);
+    };
+
+    let book = self.book_repository.get_by_id(book_id as u32, language).await?;
+    Ok(book.map(|x| user_book.to_entity(x)))
+  }
+}
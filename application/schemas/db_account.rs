@@ -1,5 +1,6 @@
 use domain::entities::account::{Account, Email, Password};
 use domain::entities::user::User;
+use domain::enums::account_role::AccountRole;
 use from_row::FromRow;
 use tokio_postgres::Row;
 
@@ -10,14 +11,17 @@ pub struct DbAccount {
   pub fk_user: i32,
   pub email: String,
   pub password: String,
+  pub verified: bool,
 }
 
 impl DbAccount {
-  pub fn to_entity(self, user: User) -> Account {
+  pub fn to_entity(self, user: User, role: AccountRole) -> Account {
     Account {
       user,
       email: Email(self.email),
       password: Password(self.password),
+      role,
+      verified: self.verified,
     }
   }
 }
@@ -0,0 +1,13 @@
+use tokio_postgres::Row;
+
+use from_row::FromRow;
+
+use crate::enums::db_account_role::DbAccountRole;
+
+#[derive(FromRow, Debug)]
+#[rename = "accountgrant"]
+pub struct DbAccountGrant {
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  pub role: DbAccountRole,
+}
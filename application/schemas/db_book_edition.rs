@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::entities::image::Image;
+use domain::isbn13::Isbn13;
+use from_row::FromRow;
+
+use crate::enums::db_language::DbLanguage;
+use crate::schemas::db_book_edition_translation::DbBookEditionTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "bookedition"]
+pub struct DbBookEdition {
+  pub id: i32,
+  pub chapters: Option<i16>,
+  pub pages: Option<i16>,
+  pub words: Option<i32>,
+  pub published: Option<NaiveDate>,
+  pub isbn13: Option<String>,
+  pub language: Option<DbLanguage>,
+  #[rename = "fkcover"]
+  pub fk_cover: i32,
+  #[rename = "fkbook"]
+  pub fk_book: i32,
+}
+
+impl DbBookEdition {
+  /// # Panics
+  ///
+  /// Will panic if the ISBN-13 is not valid. This could only happen if the value was not validated when inserted.
+  pub fn to_entity(self, edition_translation: Option<DbBookEditionTranslation>, cover: Image) -> BookEdition {
+    BookEdition {
+      id: self.id as u32,
+      book_id: self.fk_book as u32,
+      description: edition_translation.map(|x| x.description),
+      chapters: self.chapters.map(|x| x as u16),
+      pages: self.pages.map(|x| x as u16),
+      words: self.words.map(|x| x as u32),
+      published: self.published,
+      isbn13: self.isbn13.map(|x| Isbn13::parse(x).unwrap()),
+      language: self.language.map(Into::into),
+      cover,
+    }
+  }
+}
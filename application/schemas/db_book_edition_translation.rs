@@ -0,0 +1,12 @@
+use crate::enums::db_language::DbLanguage;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "bookeditiontranslation"]
+pub struct DbBookEditionTranslation {
+  pub description: String,
+  #[rename = "fktranslation"]
+  pub fk_translation: i32,
+  pub language: DbLanguage,
+}
@@ -0,0 +1,23 @@
+use domain::entities::book::book_image::BookImage;
+use domain::entities::image::Image;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "bookimage"]
+pub struct DbBookImage {
+  #[rename = "fkbook"]
+  pub fk_book: i32,
+  #[rename = "fkimage"]
+  pub fk_image: i32,
+  pub position: i16,
+}
+
+impl DbBookImage {
+  pub const fn to_entity(self, image: Image) -> BookImage {
+    BookImage {
+      image,
+      position: self.position as u16,
+    }
+  }
+}
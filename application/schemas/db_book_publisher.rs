@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "bookpublisher"]
+pub struct DbBookPublisher {
+  #[rename = "fkbook"]
+  pub fk_book: i32,
+  #[rename = "fkpublisher"]
+  pub fk_publisher: i32,
+}
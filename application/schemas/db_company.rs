@@ -0,0 +1,23 @@
+use domain::entities::company::Company;
+use domain::entities::image::Image;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "company"]
+pub struct DbCompany {
+  pub id: i32,
+  pub name: String,
+  #[rename = "fklogo"]
+  pub fk_logo: i32,
+}
+
+impl DbCompany {
+  pub fn to_entity(self, logo: Image) -> Company {
+    Company {
+      id: self.id as u32,
+      name: self.name,
+      logo,
+    }
+  }
+}
@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use tokio_postgres::Row;
+
+use domain::entities::account::email_verification::EmailVerification;
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "emailverification"]
+pub struct DbEmailVerification {
+  pub id: i32,
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
+
+impl DbEmailVerification {
+  pub fn to_entity(self) -> EmailVerification {
+    EmailVerification {
+      id: self.id as u32,
+      user_id: self.fk_user as u32,
+      hash: self.hash,
+      expires: self.expires,
+    }
+  }
+}
@@ -0,0 +1,37 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::user::friendship::Friendship;
+use domain::entities::user::User;
+use from_row::FromRow;
+
+use crate::enums::db_friendship_status::DbFriendshipStatus;
+
+#[derive(FromRow, Debug)]
+#[rename = "friendship"]
+pub struct DbFriendship {
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  #[rename = "fkseconduser"]
+  pub fk_second_user: i32,
+  pub status: DbFriendshipStatus,
+  pub added: NaiveDate,
+}
+
+impl DbFriendship {
+  pub fn to_entity(self, user_id: u32, user: User) -> Friendship {
+    Friendship {
+      user,
+      status: self.status.into(),
+      outgoing: self.fk_user as u32 == user_id,
+      added: self.added,
+    }
+  }
+
+  pub const fn other_user(&self, user_id: u32) -> u32 {
+    match self.fk_user as u32 == user_id {
+      true => self.fk_second_user as u32,
+      false => self.fk_user as u32,
+    }
+  }
+}
@@ -0,0 +1,32 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::franchise::Franchise;
+use domain::entities::game::Game;
+use domain::entities::image::Image;
+use from_row::FromRow;
+
+use crate::schemas::db_game_translation::DbGameTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "game"]
+pub struct DbGame {
+  pub id: i32,
+  pub released: Option<NaiveDate>,
+  #[rename = "fkfranchise"]
+  pub fk_franchise: Option<i32>,
+}
+
+impl DbGame {
+  pub fn to_entity(self, game_translation: DbGameTranslation, cover: Image, franchise: Option<Franchise>) -> Game {
+    Game {
+      id: self.id as u32,
+      title: game_translation.title,
+      description: game_translation.description,
+      released: self.released,
+      cover,
+      franchise,
+      language: game_translation.language.into(),
+    }
+  }
+}
@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "gamecharacter"]
+pub struct DbGameCharacter {
+  #[rename = "fkgame"]
+  pub fk_game: i32,
+  #[rename = "fkcharacter"]
+  pub fk_character: i32,
+}
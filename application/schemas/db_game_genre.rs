@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "gamegenre"]
+pub struct DbGameGenre {
+  #[rename = "fkgame"]
+  pub fk_game: i32,
+  #[rename = "fkgenre"]
+  pub fk_genre: i32,
+}
@@ -0,0 +1,13 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "gameinvolved"]
+pub struct DbGameInvolved {
+  #[rename = "fkgame"]
+  pub fk_game: i32,
+  #[rename = "fkrole"]
+  pub fk_role: i32,
+  #[rename = "fkperson"]
+  pub fk_person: i32,
+}
@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "gameplatform"]
+pub struct DbGamePlatform {
+  #[rename = "fkgame"]
+  pub fk_game: i32,
+  #[rename = "fkplatform"]
+  pub fk_platform: i32,
+}
@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "gametheme"]
+pub struct DbGameTheme {
+  #[rename = "fkgame"]
+  pub fk_game: i32,
+  #[rename = "fktheme"]
+  pub fk_theme: i32,
+}
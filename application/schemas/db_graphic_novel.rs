@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::entities::image::Image;
+use from_row::FromRow;
+
+use crate::enums::db_status::DbStatus;
+use crate::schemas::db_graphic_novel_translation::DbGraphicNovelTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovel"]
+pub struct DbGraphicNovel {
+  pub id: i32,
+  #[rename = "publishstart"]
+  pub publish_start: Option<NaiveDate>,
+  #[rename = "publishend"]
+  pub publish_end: Option<NaiveDate>,
+  pub volumes: Option<i16>,
+  pub chapters: Option<i16>,
+  pub status: DbStatus,
+}
+
+impl DbGraphicNovel {
+  pub fn to_entity(self, graphic_novel_translation: DbGraphicNovelTranslation, cover: Image) -> GraphicNovel {
+    GraphicNovel {
+      id: self.id as u32,
+      title: graphic_novel_translation.title,
+      description: graphic_novel_translation.description,
+      publish_start: self.publish_start,
+      publish_end: self.publish_end,
+      volumes: self.volumes.map(|x| x as u16),
+      chapters: self.chapters.map(|x| x as u16),
+      status: self.status.into(),
+      cover,
+      language: graphic_novel_translation.language.into(),
+    }
+  }
+}
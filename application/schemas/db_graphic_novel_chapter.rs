@@ -0,0 +1,36 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use from_row::FromRow;
+
+use crate::schemas::db_graphic_novel_chapter_translation::DbGraphicNovelChapterTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelchapter"]
+pub struct DbGraphicNovelChapter {
+  pub chapter: i16,
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  #[rename = "fkgraphicnovelvolume"]
+  pub fk_graphic_novel_volume: Option<i32>,
+  pub pages: i16,
+  pub published: Option<NaiveDate>,
+  pub score: Option<i16>,
+}
+
+impl DbGraphicNovelChapter {
+  pub fn to_entity(self, chapter_translation: DbGraphicNovelChapterTranslation) -> GraphicNovelChapter {
+    GraphicNovelChapter {
+      graphic_novel_id: self.fk_graphic_novel as u32,
+      chapter: self.chapter as u16,
+      volume: self.fk_graphic_novel_volume.map(|x| x as u16),
+      title: chapter_translation.title,
+      description: chapter_translation.description,
+      pages: self.pages as u16,
+      published: self.published,
+      score: self.score.map(|x| x as u16),
+      language: chapter_translation.language.into(),
+    }
+  }
+}
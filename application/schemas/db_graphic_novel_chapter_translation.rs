@@ -0,0 +1,15 @@
+use crate::enums::db_language::DbLanguage;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelchaptertranslation"]
+pub struct DbGraphicNovelChapterTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  #[rename = "fkgraphicnovelchapter"]
+  pub fk_graphic_novel_chapter: i32,
+  pub language: DbLanguage,
+}
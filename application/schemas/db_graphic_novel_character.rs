@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelcharacter"]
+pub struct DbGraphicNovelCharacter {
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  #[rename = "fkcharacter"]
+  pub fk_character: i32,
+}
@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelgenre"]
+pub struct DbGraphicNovelGenre {
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  #[rename = "fkgenre"]
+  pub fk_genre: i32,
+}
@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelpublisher"]
+pub struct DbGraphicNovelPublisher {
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  #[rename = "fkpublisher"]
+  pub fk_publisher: i32,
+}
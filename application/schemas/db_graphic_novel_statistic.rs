@@ -0,0 +1,17 @@
+use chrono::NaiveDate;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelstatistic"]
+pub struct DbGraphicNovelStatistic {
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  #[rename = "fkrating"]
+  pub fk_rating: i32,
+  pub added: NaiveDate,
+  pub rank: i32,
+  pub popularity: i32,
+  pub favorites: i32,
+  pub members: i32,
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use from_row::FromRow;
+
+use crate::schemas::db_graphic_novel_volume_translation::DbGraphicNovelVolumeTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "graphicnovelvolume"]
+pub struct DbGraphicNovelVolume {
+  pub volume: i16,
+  #[rename = "fkgraphicnovel"]
+  pub fk_graphic_novel: i32,
+  pub pages: Option<i16>,
+  pub published: Option<NaiveDate>,
+  pub score: Option<i16>,
+}
+
+impl DbGraphicNovelVolume {
+  pub fn to_entity(self, volume_translation: DbGraphicNovelVolumeTranslation) -> GraphicNovelVolume {
+    GraphicNovelVolume {
+      graphic_novel_id: self.fk_graphic_novel as u32,
+      volume: self.volume as u16,
+      title: volume_translation.title,
+      description: volume_translation.description,
+      pages: self.pages.map(|x| x as u16),
+      published: self.published,
+      score: self.score.map(|x| x as u16),
+      language: volume_translation.language.into(),
+    }
+  }
+}
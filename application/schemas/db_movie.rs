@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::image::Image;
+use domain::entities::movie::Movie;
+use from_row::FromRow;
+
+use crate::schemas::db_movie_translation::DbMovieTranslation;
+use crate::types::db_interval::DbInterval;
+
+#[derive(FromRow, Debug)]
+#[rename = "movie"]
+pub struct DbMovie {
+  pub id: i32,
+  pub airing: Option<NaiveDate>,
+  pub length: Option<DbInterval>,
+}
+
+impl DbMovie {
+  pub fn to_entity(self, movie_translation: DbMovieTranslation, cover: Image) -> Movie {
+    Movie {
+      id: self.id as u32,
+      title: movie_translation.title,
+      description: movie_translation.description,
+      airing: self.airing,
+      length: self.length.map(DbInterval::minutes),
+      cover,
+      language: movie_translation.language.into(),
+    }
+  }
+}
@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "moviegenre"]
+pub struct DbMovieGenre {
+  #[rename = "fkmovie"]
+  pub fk_movie: i32,
+  #[rename = "fkgenre"]
+  pub fk_genre: i32,
+}
@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use domain::entities::movie::movie_statistic::MovieStatistic;
+use domain::entities::rating::Rating;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "moviestatistic"]
+pub struct DbMovieStatistic {
+  #[rename = "fkmovie"]
+  pub fk_movie: i32,
+  #[rename = "fkrating"]
+  pub fk_rating: i32,
+  pub added: NaiveDate,
+  pub rank: i32,
+  pub popularity: i32,
+  pub favorites: i32,
+  pub members: i32,
+}
+impl DbMovieStatistic {
+  pub const fn to_entity(self, rating: Rating) -> MovieStatistic {
+    MovieStatistic {
+      rating,
+      rank: self.rank as u32,
+      popularity: self.popularity as u32,
+      favorites: self.favorites as u32,
+      members: self.members as u32,
+      added: self.added,
+    }
+  }
+}
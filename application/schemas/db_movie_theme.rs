@@ -0,0 +1,11 @@
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "movietheme"]
+pub struct DbMovieTheme {
+  #[rename = "fkmovie"]
+  pub fk_movie: i32,
+  #[rename = "fktheme"]
+  pub fk_theme: i32,
+}
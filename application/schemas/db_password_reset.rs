@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use tokio_postgres::Row;
+
+use domain::entities::account::password_reset::PasswordReset;
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "passwordreset"]
+pub struct DbPasswordReset {
+  pub id: i32,
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
+
+impl DbPasswordReset {
+  pub fn to_entity(self) -> PasswordReset {
+    PasswordReset {
+      id: self.id as u32,
+      user_id: self.fk_user as u32,
+      hash: self.hash,
+      expires: self.expires,
+    }
+  }
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime;
+use tokio_postgres::Row;
+
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "personalaccesstoken"]
+pub struct DbPersonalAccessToken {
+  pub id: i32,
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  pub name: String,
+  pub hash: String,
+  /// Separated by spaces
+  pub scopes: String,
+  pub created: NaiveDateTime,
+  #[rename = "lastused"]
+  pub last_used: Option<NaiveDateTime>,
+}
+
+impl DbPersonalAccessToken {
+  pub fn to_entity(self) -> PersonalAccessToken {
+    PersonalAccessToken {
+      id: self.id as u32,
+      user_id: self.fk_user as u32,
+      name: self.name,
+      scopes: self.scopes.split_whitespace().filter_map(|x| x.parse().ok()).collect(),
+      created: self.created,
+      last_used: self.last_used,
+    }
+  }
+}
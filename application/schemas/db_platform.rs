@@ -0,0 +1,30 @@
+use domain::entities::company::Company;
+use domain::entities::image::Image;
+use domain::entities::platform::Platform;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "platform"]
+pub struct DbPlatform {
+  pub id: i32,
+  pub name: String,
+  #[rename = "shortname"]
+  pub short_name: Option<String>,
+  #[rename = "fkcompany"]
+  pub fk_company: i32,
+  #[rename = "fklogo"]
+  pub fk_logo: i32,
+}
+
+impl DbPlatform {
+  pub fn to_entity(self, company: Company, logo: Image) -> Platform {
+    Platform {
+      id: self.id as u32,
+      name: self.name,
+      short_name: self.short_name,
+      company,
+      logo,
+    }
+  }
+}
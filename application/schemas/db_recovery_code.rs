@@ -0,0 +1,12 @@
+use tokio_postgres::Row;
+
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "recoverycode"]
+pub struct DbRecoveryCode {
+  pub id: i32,
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  pub hash: String,
+}
@@ -0,0 +1,32 @@
+use chrono::NaiveDateTime;
+use tokio_postgres::Row;
+
+use domain::entities::account::refresh_token::RefreshToken;
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "refreshtoken"]
+pub struct DbRefreshToken {
+  pub id: i32,
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  #[rename = "fkfamily"]
+  pub fk_family: Option<i32>,
+  #[rename = "fkparent"]
+  pub fk_parent: Option<i32>,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
+
+impl DbRefreshToken {
+  pub fn to_entity(self) -> RefreshToken {
+    RefreshToken {
+      id: self.id as u32,
+      user_id: self.fk_user as u32,
+      family: self.fk_family.map(|x| x as u32),
+      parent: self.fk_parent.map(|x| x as u32),
+      hash: self.hash,
+      expires: self.expires,
+    }
+  }
+}
@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::franchise::Franchise;
+use domain::entities::image::Image;
+use domain::entities::show::Show;
+use from_row::FromRow;
+
+use crate::enums::db_status::DbStatus;
+use crate::schemas::db_show_translation::DbShowTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "show"]
+pub struct DbShow {
+  pub id: i32,
+  #[rename = "airingstart"]
+  pub airing_start: Option<NaiveDate>,
+  #[rename = "airingend"]
+  pub airing_end: Option<NaiveDate>,
+  pub score: Option<f32>,
+  pub seasons: Option<i16>,
+  pub status: DbStatus,
+  #[rename = "fkfranchise"]
+  pub fk_franchise: Option<i32>,
+}
+
+impl DbShow {
+  pub fn to_entity(self, show_translation: DbShowTranslation, cover: Image, franchise: Option<Franchise>) -> Show {
+    Show {
+      id: self.id as u32,
+      title: show_translation.title,
+      description: show_translation.description,
+      airing_start: self.airing_start,
+      airing_end: self.airing_end,
+      score: self.score,
+      seasons: self.seasons.map(|x| x as u16),
+      status: self.status.into(),
+      cover,
+      franchise,
+      language: show_translation.language.into(),
+    }
+  }
+}
@@ -0,0 +1,38 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::image::Image;
+use domain::entities::show::show_episode::ShowEpisode;
+use from_row::FromRow;
+
+use crate::schemas::db_show_episode_translation::DbShowEpisodeTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "showepisode"]
+pub struct DbShowEpisode {
+  pub episode: i16,
+  #[rename = "fkshow"]
+  pub fk_show: i32,
+  #[rename = "fkseason"]
+  pub fk_season: Option<i32>,
+  pub length: Option<i16>,
+  pub airing: Option<NaiveDate>,
+  pub score: Option<i16>,
+}
+
+impl DbShowEpisode {
+  pub fn to_entity(self, episode_translation: DbShowEpisodeTranslation, cover: Option<Image>) -> ShowEpisode {
+    ShowEpisode {
+      show_id: self.fk_show as u32,
+      episode: self.episode as u16,
+      season: self.fk_season.map(|x| x as u16),
+      title: episode_translation.title,
+      description: episode_translation.description,
+      length: self.length.map(|x| x as u16),
+      airing: self.airing,
+      score: self.score.map(|x| x as u16),
+      cover,
+      language: episode_translation.language.into(),
+    }
+  }
+}
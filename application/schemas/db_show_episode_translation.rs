@@ -0,0 +1,17 @@
+use crate::enums::db_language::DbLanguage;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "showepisodetranslation"]
+pub struct DbShowEpisodeTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  #[rename = "fkcover"]
+  pub fk_cover: Option<i32>,
+  #[rename = "fkshow"]
+  pub fk_show: i32,
+  #[rename = "fkshowepisode"]
+  pub fk_show_episode: i32,
+  pub language: DbLanguage,
+}
@@ -0,0 +1,37 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::show::show_season::ShowSeason;
+use from_row::FromRow;
+
+use crate::schemas::db_show_season_translation::DbShowSeasonTranslation;
+
+#[derive(FromRow, Debug)]
+#[rename = "showseason"]
+pub struct DbShowSeason {
+  pub season: i16,
+  #[rename = "fkshow"]
+  pub fk_show: i32,
+  pub episodes: Option<i16>,
+  #[rename = "airingstart"]
+  pub airing_start: Option<NaiveDate>,
+  #[rename = "airingend"]
+  pub airing_end: Option<NaiveDate>,
+  pub score: Option<i16>,
+}
+
+impl DbShowSeason {
+  pub fn to_entity(self, season_translation: DbShowSeasonTranslation) -> ShowSeason {
+    ShowSeason {
+      show_id: self.fk_show as u32,
+      season: self.season as u16,
+      title: season_translation.title,
+      description: season_translation.description,
+      episodes: self.episodes.map(|x| x as u16),
+      airing_start: self.airing_start,
+      airing_end: self.airing_end,
+      score: self.score.map(|x| x as u16),
+      language: season_translation.language.into(),
+    }
+  }
+}
@@ -0,0 +1,15 @@
+use crate::enums::db_language::DbLanguage;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "showseasontranslation"]
+pub struct DbShowSeasonTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  #[rename = "fkshow"]
+  pub fk_show: i32,
+  #[rename = "fkshowseason"]
+  pub fk_show_season: i32,
+  pub language: DbLanguage,
+}
@@ -0,0 +1,15 @@
+use crate::enums::db_language::DbLanguage;
+use from_row::FromRow;
+use tokio_postgres::Row;
+
+#[derive(FromRow, Debug)]
+#[rename = "showtranslation"]
+pub struct DbShowTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  #[rename = "fkcover"]
+  pub fk_cover: i32,
+  #[rename = "fktranslation"]
+  pub fk_translation: i32,
+  pub language: DbLanguage,
+}
@@ -0,0 +1,26 @@
+use tokio_postgres::Row;
+
+use domain::entities::account::two_factor::TwoFactor;
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "twofactor"]
+pub struct DbTwoFactor {
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  pub secret: String,
+  pub confirmed: bool,
+  #[rename = "laststep"]
+  pub last_step: Option<i64>,
+}
+
+impl DbTwoFactor {
+  pub fn to_entity(self) -> TwoFactor {
+    TwoFactor {
+      user_id: self.fk_user as u32,
+      secret: self.secret,
+      confirmed: self.confirmed,
+      last_step: self.last_step.map(|x| x as u64),
+    }
+  }
+}
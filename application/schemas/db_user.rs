@@ -5,6 +5,8 @@ use tokio_postgres::Row;
 use domain::entities::user::User;
 use from_row::FromRow;
 
+use crate::schemas::db_user_average::DbUserAverage;
+
 #[derive(FromRow, Debug)]
 #[rename = "\"User\""]
 pub struct DbUser {
@@ -18,7 +20,7 @@ pub struct DbUser {
 }
 
 impl DbUser {
-  pub fn to_entity(self, profile_picture: Option<Image>) -> User {
+  pub fn to_entity(self, profile_picture: Option<Image>, averages: Option<DbUserAverage>) -> User {
     User {
       id: self.id as u32,
       name: self.name,
@@ -26,6 +28,7 @@ impl DbUser {
       description: self.description,
       deleted: self.deleted,
       profile_picture,
+      averages: averages.map(DbUserAverage::to_entity).unwrap_or_default(),
     }
   }
 }
@@ -0,0 +1,33 @@
+use tokio_postgres::Row;
+
+use domain::entities::user::user_average::UserAverage;
+use from_row::FromRow;
+
+#[derive(FromRow, Debug)]
+#[rename = "useraverage"]
+pub struct DbUserAverage {
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  #[rename = "graphicnovelaverage"]
+  pub graphic_novel_average: Option<f32>,
+  #[rename = "showaverage"]
+  pub show_average: Option<f32>,
+  #[rename = "movieaverage"]
+  pub movie_average: Option<f32>,
+  #[rename = "bookaverage"]
+  pub book_average: Option<f32>,
+  #[rename = "gameaverage"]
+  pub game_average: Option<f32>,
+}
+
+impl DbUserAverage {
+  pub const fn to_entity(self) -> UserAverage {
+    UserAverage {
+      graphic_novel: self.graphic_novel_average,
+      show: self.show_average,
+      movie: self.movie_average,
+      book: self.book_average,
+      game: self.game_average,
+    }
+  }
+}
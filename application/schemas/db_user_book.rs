@@ -0,0 +1,45 @@
+use chrono::NaiveDate;
+use tokio_postgres::Row;
+
+use domain::entities::book::Book;
+use domain::entities::user::user_book::UserBook;
+use from_row::FromRow;
+
+use crate::enums::db_user_status::DbUserStatus;
+
+#[derive(FromRow, Debug)]
+#[rename = "userbook"]
+pub struct DbUserBook {
+  #[rename = "fkuser"]
+  pub fk_user: i32,
+  #[rename = "fkbook"]
+  pub fk_book: i32,
+  #[rename = "userstatus"]
+  pub user_status: DbUserStatus,
+  pub favorite: bool,
+  pub score: Option<i16>,
+  pub review: Option<String>,
+  pub start: Option<NaiveDate>,
+  pub finished: Option<NaiveDate>,
+  pub chapters: Option<i16>,
+  pub pages: Option<i16>,
+  pub added: NaiveDate,
+}
+
+impl DbUserBook {
+  pub fn to_entity(self, book: Book) -> UserBook {
+    UserBook {
+      user_id: self.fk_user as u32,
+      book,
+      status: self.user_status.into(),
+      favorite: self.favorite,
+      score: self.score.map(|x| x as u8),
+      review: self.review,
+      start: self.start,
+      finished: self.finished,
+      chapters: self.chapters.map(|x| x as u16),
+      pages: self.pages.map(|x| x as u16),
+      added: self.added,
+    }
+  }
+}
@@ -1,24 +1,74 @@
 pub mod db_account;
+pub mod db_account_grant;
 pub mod db_book;
 pub mod db_book_character;
+pub mod db_book_edition;
+pub mod db_book_edition_involved;
+pub mod db_book_edition_translation;
 pub mod db_book_genre;
+pub mod db_book_image;
 pub mod db_book_involved;
+pub mod db_book_publisher;
 pub mod db_book_statistic;
 pub mod db_book_theme;
 pub mod db_book_translation;
 pub mod db_character;
 pub mod db_character_translation;
+pub mod db_company;
+pub mod db_email_verification;
 pub mod db_franchise;
 pub mod db_franchise_translation;
+pub mod db_friendship;
+pub mod db_game;
+pub mod db_game_character;
+pub mod db_game_genre;
+pub mod db_game_involved;
+pub mod db_game_platform;
+pub mod db_game_statistic;
+pub mod db_game_theme;
+pub mod db_game_translation;
 pub mod db_genre;
 pub mod db_genre_translation;
+pub mod db_graphic_novel;
+pub mod db_graphic_novel_chapter;
+pub mod db_graphic_novel_chapter_translation;
+pub mod db_graphic_novel_character;
+pub mod db_graphic_novel_genre;
+pub mod db_graphic_novel_involved;
+pub mod db_graphic_novel_publisher;
+pub mod db_graphic_novel_statistic;
+pub mod db_graphic_novel_theme;
+pub mod db_graphic_novel_translation;
+pub mod db_graphic_novel_volume;
+pub mod db_graphic_novel_volume_translation;
 pub mod db_image;
 pub mod db_image_data;
+pub mod db_movie;
+pub mod db_movie_genre;
+pub mod db_movie_involved;
+pub mod db_movie_statistic;
+pub mod db_movie_theme;
+pub mod db_movie_translation;
+pub mod db_password_reset;
 pub mod db_person;
 pub mod db_person_translation;
+pub mod db_personal_access_token;
+pub mod db_platform;
 pub mod db_rating;
+pub mod db_recovery_code;
+pub mod db_refresh_token;
 pub mod db_role;
 pub mod db_role_translation;
+pub mod db_show;
+pub mod db_show_episode;
+pub mod db_show_episode_translation;
+pub mod db_show_season;
+pub mod db_show_season_translation;
+pub mod db_show_statistic;
+pub mod db_show_translation;
 pub mod db_theme;
 pub mod db_theme_translation;
+pub mod db_two_factor;
 pub mod db_user;
+pub mod db_user_average;
+pub mod db_user_book;
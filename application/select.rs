@@ -1,19 +1,19 @@
-use std::collections::HashMap;
 use std::error::Error;
 use std::marker::PhantomData;
 
 use tokio_postgres::types::ToSql;
-use tokio_postgres::Client;
 
 use domain::pagination::Pagination;
 use from_row::{FromRow, Table};
 
+use crate::cached_connection::CachedClient;
 use crate::select::column_table::{ColumnTable, SelectElement};
 use crate::select::combined_tuple::CombinedType;
-use crate::select::expression::Expression;
+use crate::select::expression::{next, Expression};
 use crate::select::join::{Join, JoinType};
 use crate::select::order_by::{Direction, NullsOrder, OrderBy};
 use crate::select::selector::Selector;
+use crate::select::sub_select::SubSelect;
 
 mod column_table;
 pub mod combined_tuple;
@@ -24,22 +24,23 @@ pub mod expression;
 pub mod join;
 pub mod order_by;
 pub mod selector;
+pub mod sub_select;
 pub mod to_sql_value;
 
-//TODO: Prepared version
 pub struct Select<'a, T: FromRow<DbType = T> + CombinedType> {
   marker: PhantomData<T>,
-  offset: Option<usize>,
-  limit: Option<usize>,
+  offset: Option<i64>,
+  limit: Option<i64>,
   from: &'a str,
   alias: Option<&'a str>,
   columns: Vec<SelectElement<'a>>,
   joins: Vec<Join<'a>>,
   wheres: Vec<Expression<'a>>,
-  distinct: HashMap<&'a str, Vec<&'a str>>,
-  group_by: HashMap<&'a str, Vec<&'a str>>,
+  distinct: Vec<(&'a str, &'a str)>,
+  group_by: Vec<(&'a str, &'a str)>,
   order_by: Vec<OrderBy<'a>>,
   having: Vec<Expression<'a>>,
+  with: Vec<(&'a str, Box<dyn SubSelect + 'a>)>,
 }
 
 impl<'a> Select<'a, ()> {
@@ -56,10 +57,11 @@ impl<'a> Select<'a, ()> {
       offset: None,
       limit: None,
       wheres: vec![],
-      distinct: HashMap::new(),
-      group_by: HashMap::new(),
+      distinct: vec![],
+      group_by: vec![],
       order_by: vec![],
       having: vec![],
+      with: vec![],
     }
   }
 }
@@ -100,6 +102,7 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
       group_by: self.group_by,
       order_by: self.order_by,
       having: self.having,
+      with: self.with,
     }
   }
   pub fn columns<C: from_row::RowColumns + FromRow<DbType = C>>(
@@ -116,43 +119,34 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
     self.create_new_select::<C>()
   }
   pub fn distinct_on(mut self, from: &'a str, column: &'a str) -> Self {
-    match self.distinct.get_mut(from) {
-      None => {
-        self.distinct.insert(from, vec![column]);
-      }
-      Some(value) => {
-        value.push(column);
-      }
-    };
+    self.distinct.push((from, column));
     self
   }
   pub fn group_by(mut self, from: &'a str, column: &'a str) -> Self {
-    match self.group_by.get_mut(from) {
-      None => {
-        self.group_by.insert(from, vec![column]);
-      }
-      Some(value) => {
-        value.push(column);
-      }
-    };
+    self.group_by.push((from, column));
     self
   }
   pub fn having(mut self, expression: Expression<'a>) -> Self {
     self.having.push(expression);
     self
   }
+  /// Adds a common table expression, it can be selected from by its name
+  pub fn with(mut self, name: &'a str, select: impl SubSelect + 'a) -> Self {
+    self.with.push((name, Box::new(select)));
+    self
+  }
   pub fn where_expression(mut self, expression: Expression<'a>) -> Self {
     self.wheres.push(expression);
     self
   }
 
   pub const fn offset(mut self, offset: usize) -> Self {
-    self.offset = Some(offset);
+    self.offset = Some(offset as i64);
     self
   }
 
   pub const fn limit(mut self, limit: usize) -> Self {
-    self.limit = Some(limit);
+    self.limit = Some(limit as i64);
     self
   }
 
@@ -205,6 +199,15 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
     self.create_new_select::<i64>()
   }
 
+  /// Rows where the column is null are ignored, a group without any value averages to null
+  pub fn average(mut self, from: &'a str, column: &'a str) -> Select<'a, <T as CombinedType>::Combined<Option<f32>>>
+  where
+    <T as CombinedType>::Combined<Option<f32>>: FromRow<DbType = <T as CombinedType>::Combined<Option<f32>>>,
+  {
+    self.columns.push(SelectElement::Average(from, column));
+    self.create_new_select::<Option<f32>>()
+  }
+
   pub fn order_by(
     mut self,
     selector: impl Selector + 'a,
@@ -231,6 +234,7 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
           .collect::<Vec<String>>()
           .join(","),
         SelectElement::Raw(raw) => (*raw).to_string(),
+        SelectElement::Average(from, column) => format!("AVG({from}.{column})::real"),
       })
       .collect::<Vec<String>>()
       .join(",")
@@ -245,11 +249,7 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
       self
         .distinct
         .iter()
-        .map(|(from, columns)| columns
-          .iter()
-          .map(|x| format!("{from}.{x}"))
-          .collect::<Vec<String>>()
-          .join(","))
+        .map(|(from, column)| format!("{from}.{column}"))
         .collect::<Vec<String>>()
         .join(",")
     ))
@@ -268,15 +268,16 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
     Some(format!("ORDER BY {}", order_bys))
   }
 
-  fn limit_sql(&self) -> Option<String> {
-    self.limit.map(|x| format!("LIMIT {x}"))
+  /// Limit and offset are parameters, so every page of a query shares one prepared statement
+  fn limit_sql(&self, count: &mut usize) -> Option<String> {
+    self.limit.map(|_| format!("LIMIT ${}", next(count)))
   }
 
-  fn offset_sql(&self) -> Option<String> {
-    self.offset.map(|x| format!("OFFSET {x}"))
+  fn offset_sql(&self, count: &mut usize) -> Option<String> {
+    self.offset.map(|_| format!("OFFSET ${}", next(count)))
   }
 
-  fn where_sql(&self, count: &'a mut usize) -> Option<String> {
+  fn where_sql(&self, count: &mut usize) -> Option<String> {
     if self.wheres.is_empty() {
       return None;
     }
@@ -284,7 +285,10 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
     let wheres = self
       .wheres
       .iter()
-      .map(|expression| expression.sql(count))
+      .map(|expression| match self.wheres.len() {
+        1 => expression.sql(count),
+        _ => format!("({})", expression.sql(count)),
+      })
       .collect::<Vec<String>>()
       .join(" AND ");
     Some(format!("WHERE {}", wheres))
@@ -299,17 +303,13 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
       self
         .group_by
         .iter()
-        .map(|(from, columns)| columns
-          .iter()
-          .map(|x| format!("{from}.{x}"))
-          .collect::<Vec<String>>()
-          .join(","))
+        .map(|(from, column)| format!("{from}.{column}"))
         .collect::<Vec<String>>()
         .join(",")
     ))
   }
 
-  fn having_sql(&self, count: &'a mut usize) -> Option<String> {
+  fn having_sql(&self, count: &mut usize) -> Option<String> {
     if self.having.is_empty() {
       return None;
     }
@@ -317,36 +317,78 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
     let having = self
       .having
       .iter()
-      .map(|expression| expression.sql(count))
-      .collect::<String>();
+      .map(|expression| match self.having.len() {
+        1 => expression.sql(count),
+        _ => format!("({})", expression.sql(count)),
+      })
+      .collect::<Vec<String>>()
+      .join(" AND ");
     Some(format!("HAVING {}", having))
   }
 
-  fn join_sql(&self, count: &'a mut usize) -> String {
-    self.joins.iter().map(|join| join.fmt(count)).collect::<String>()
+  fn join_sql(&self, count: &mut usize) -> Option<String> {
+    if self.joins.is_empty() {
+      return None;
+    }
+    Some(
+      self
+        .joins
+        .iter()
+        .map(|join| join.fmt(count))
+        .collect::<Vec<String>>()
+        .join(" "),
+    )
   }
 
-  fn query_sql(&self) -> String {
-    let columns = self.columns_sql();
-    let mut count = 1;
-    let joins = self.join_sql(&mut count);
-    let where_sql = self.where_sql(&mut count).unwrap_or_default();
-    let group_by_sql = self.group_by_sql().unwrap_or_default();
-    let having_sql = self.having_sql(&mut count).unwrap_or_default();
-    let limit_sql = self.limit_sql().unwrap_or_default();
-    let offset_sql = self.offset_sql().unwrap_or_default();
-    let alias_sql = self.alias.unwrap_or_default();
-    let distinct_sql = self.distinct_sql().unwrap_or_default();
-    let order_by_sql = self.order_by_sql().unwrap_or_default();
-    let from = self.from;
-
-    format!("SELECT {distinct_sql} {columns} FROM {from} {alias_sql} {joins} {where_sql}{group_by_sql} {having_sql} {order_by_sql} {limit_sql} {offset_sql}")
-  }
+  fn with_sql(&self, count: &mut usize) -> Option<String> {
+    if self.with.is_empty() {
+      return None;
+    }
 
-  pub async fn query(self, connection: &'a Client) -> Result<Vec<T>, Box<dyn Error>> {
+    let with = self
+      .with
+      .iter()
+      .map(|(name, select)| format!("{name} AS ({})", select.sql(count)))
+      .collect::<Vec<String>>()
+      .join(",");
+    Some(format!("WITH {with}"))
+  }
+
+  pub(crate) fn query_sql(&self) -> String {
+    self.sql(&mut 1)
+  }
+
+  /// Placeholders are numbered starting at `count`, which allows the select to be embedded in another statement
+  fn sql(&self, count: &mut usize) -> String {
+    // the parts are built in order, so the placeholders are numbered as they appear
+    [
+      self.with_sql(count),
+      Some("SELECT".to_string()),
+      self.distinct_sql(),
+      Some(self.columns_sql()),
+      Some(format!("FROM {}", self.from)),
+      self.alias.map(str::to_string),
+      self.join_sql(count),
+      self.where_sql(count),
+      self.group_by_sql(),
+      self.having_sql(count),
+      self.order_by_sql(),
+      self.limit_sql(count),
+      self.offset_sql(count),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>()
+    .join(" ")
+  }
+
+  pub async fn query(self, connection: &'a CachedClient) -> Result<Vec<T>, Box<dyn Error>> {
+    let values = &self.values();
     Ok(
       connection
-        .query(&self.query_sql(), &self.values())
+        .run(&self.query_sql(), |statement| async move {
+          connection.query(&statement, values).await
+        })
         .await?
         .into_iter()
         .map(|x| T::from_row(&x, 0))
@@ -354,17 +396,31 @@ impl<'a, T: from_row::FromRow<DbType = T> + CombinedType> Select<'a, T> {
     )
   }
 
-  fn values(&'a self) -> Vec<&'a (dyn ToSql + Sync)> {
-    let mut total: Vec<&'a (dyn ToSql + Sync)> = vec![];
+  pub(crate) fn values(&self) -> Vec<&(dyn ToSql + Sync)> {
+    let mut total: Vec<&(dyn ToSql + Sync)> = vec![];
+    self
+      .with
+      .iter()
+      .for_each(|(_, select)| total.append(&mut select.values()));
     self.joins.iter().for_each(|x| total.append(&mut x.expression.values()));
     self.wheres.iter().for_each(|x| total.append(&mut x.values()));
+    self.having.iter().for_each(|x| total.append(&mut x.values()));
+    if let Some(limit) = &self.limit {
+      total.push(limit);
+    }
+    if let Some(offset) = &self.offset {
+      total.push(offset);
+    }
     total
   }
 
-  pub async fn get_single(self, connection: &'a Client) -> Result<Option<T>, Box<dyn Error>> {
+  pub async fn get_single(self, connection: &'a CachedClient) -> Result<Option<T>, Box<dyn Error>> {
+    let values = &self.values();
     Ok(
       connection
-        .query_opt(&self.query_sql(), &self.values())
+        .run(&self.query_sql(), |statement| async move {
+          connection.query_opt(&statement, values).await
+        })
         .await?
         .map(|x| T::from_row(&x, 0)),
     )
@@ -8,4 +8,5 @@ pub struct ColumnTable<'a> {
 pub enum SelectElement<'a> {
   Column(ColumnTable<'a>),
   Raw(&'a str),
+  Average(&'a str, &'a str),
 }
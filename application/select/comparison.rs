@@ -1,15 +1,28 @@
-use tokio_postgres::types::ToSql;
+use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Clone)]
-pub enum Comparison<'a> {
-  Equal(&'a (dyn ToSql + Sync)),
-  NotEqual(&'a (dyn ToSql + Sync)),
-  IsNull,
-  IsNotNull,
-  ILike(&'a String),
-  In(&'a [&'a (dyn ToSql + Sync)]),
-  Bigger(&'a (dyn ToSql + Sync)),
-  BiggerEqual(&'a (dyn ToSql + Sync)),
-  Less(&'a (dyn ToSql + Sync)),
-  LessEqual(&'a (dyn ToSql + Sync)),
+#[derive(Debug, Clone, Copy)]
+pub enum Comparison {
+  Equal,
+  NotEqual,
+  Bigger,
+  BiggerEqual,
+  Less,
+  LessEqual,
+}
+
+impl Display for Comparison {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Comparison::Equal => "=",
+        Comparison::NotEqual => "<>",
+        Comparison::Bigger => ">",
+        Comparison::BiggerEqual => ">=",
+        Comparison::Less => "<",
+        Comparison::LessEqual => "<=",
+      }
+    )
+  }
 }
@@ -0,0 +1,25 @@
+use crate::select::condition::Condition;
+use crate::select::expression::IntoSql;
+use crate::select::sub_select::SubSelect;
+
+pub struct Exists<'a> {
+  select: Box<dyn SubSelect + 'a>,
+}
+
+impl<'a> Exists<'a> {
+  pub fn new(select: impl SubSelect + 'a) -> Exists<'a> {
+    Exists {
+      select: Box::new(select),
+    }
+  }
+}
+
+impl Condition for Exists<'_> {
+  fn sql(&self, value_index: &mut usize) -> String {
+    format!("EXISTS ({})", self.select.sql(value_index))
+  }
+
+  fn values(&self) -> Vec<&IntoSql> {
+    self.select.values()
+  }
+}
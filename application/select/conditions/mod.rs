@@ -1,6 +1,11 @@
 pub mod column_equal;
 pub mod column_not_null;
 pub mod column_null;
+pub mod exists;
+pub mod not;
+pub mod value_between;
+pub mod value_comparison;
 pub mod value_equal;
 pub mod value_ilike;
 pub mod value_in;
+pub mod value_in_select;
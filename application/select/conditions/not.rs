@@ -0,0 +1,22 @@
+use crate::select::condition::Condition;
+use crate::select::expression::{Expression, IntoSql};
+
+pub struct Not<'a> {
+  expression: Expression<'a>,
+}
+
+impl<'a> Not<'a> {
+  pub const fn new(expression: Expression<'a>) -> Not<'a> {
+    Not { expression }
+  }
+}
+
+impl Condition for Not<'_> {
+  fn sql(&self, value_index: &mut usize) -> String {
+    format!("NOT ({})", self.expression.sql(value_index))
+  }
+
+  fn values(&self) -> Vec<&IntoSql> {
+    self.expression.values()
+  }
+}
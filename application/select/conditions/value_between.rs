@@ -0,0 +1,35 @@
+use crate::select::condition::Condition;
+use crate::select::expression::IntoSql;
+use crate::select::selector::Selector;
+use crate::select::to_sql_value::ToSqlValue;
+
+/// Both bounds are inclusive
+pub struct ValueBetween<'a> {
+  selector: Box<dyn Selector + 'a>,
+  from: Box<dyn ToSqlValue<'a> + 'a>,
+  to: Box<dyn ToSqlValue<'a> + 'a>,
+}
+
+impl Condition for ValueBetween<'_> {
+  fn sql(&self, value_index: &mut usize) -> String {
+    let selector = self.selector.sql();
+    let from = self.from.sql(value_index);
+    let to = self.to.sql(value_index);
+    format!("{selector} BETWEEN {from} AND {to}")
+  }
+  fn values(&self) -> Vec<&IntoSql> {
+    let mut result = self.from.values();
+    result.append(&mut self.to.values());
+    result
+  }
+}
+
+impl<'a> ValueBetween<'a> {
+  pub fn new(selector: impl Selector + 'a, from: impl ToSqlValue<'a> + 'a, to: impl ToSqlValue<'a> + 'a) -> Self {
+    Self {
+      selector: Box::new(selector),
+      from: Box::new(from),
+      to: Box::new(to),
+    }
+  }
+}
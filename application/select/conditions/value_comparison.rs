@@ -0,0 +1,31 @@
+use crate::select::comparison::Comparison;
+use crate::select::condition::Condition;
+use crate::select::expression::IntoSql;
+use crate::select::selector::Selector;
+use crate::select::to_sql_value::ToSqlValue;
+
+pub struct ValueComparison<'a> {
+  selector: Box<dyn Selector + 'a>,
+  comparison: Comparison,
+  value: Box<dyn ToSqlValue<'a> + 'a>,
+}
+
+impl Condition for ValueComparison<'_> {
+  fn sql(&self, value_index: &mut usize) -> String {
+    let selector = self.selector.sql();
+    format!("{} {} {}", selector, self.comparison, self.value.sql(value_index))
+  }
+  fn values(&self) -> Vec<&IntoSql> {
+    self.value.values()
+  }
+}
+
+impl<'a> ValueComparison<'a> {
+  pub fn new(selector: impl Selector + 'a, comparison: Comparison, value: impl ToSqlValue<'a> + 'a) -> Self {
+    Self {
+      selector: Box::new(selector),
+      comparison,
+      value: Box::new(value),
+    }
+  }
+}
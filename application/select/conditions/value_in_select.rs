@@ -0,0 +1,30 @@
+use crate::select::condition::Condition;
+use crate::select::expression::IntoSql;
+use crate::select::selector::Selector;
+use crate::select::sub_select::SubSelect;
+
+pub struct ValueInSelect<'a> {
+  selector: Box<dyn Selector + 'a>,
+  select: Box<dyn SubSelect + 'a>,
+}
+
+impl<'a> ValueInSelect<'a> {
+  pub fn new(selector: impl Selector + 'a, select: impl SubSelect + 'a) -> ValueInSelect<'a> {
+    ValueInSelect {
+      selector: Box::new(selector),
+      select: Box::new(select),
+    }
+  }
+}
+
+impl Condition for ValueInSelect<'_> {
+  fn sql(&self, value_index: &mut usize) -> String {
+    let selector = self.selector.sql();
+    let select = self.select.sql(value_index);
+    format!("{} IN ({})", selector, select)
+  }
+
+  fn values(&self) -> Vec<&IntoSql> {
+    self.select.values()
+  }
+}
@@ -35,20 +35,22 @@ impl<'a> Expression<'a> {
   }
 
   pub fn sql(&self, value_index: &mut usize) -> String {
-    let condition = self.condition.sql(value_index);
-    let ands = self
-      .ands
-      .iter()
-      .map(|x| format!("AND ({})", x.sql(value_index)))
-      .collect::<Vec<String>>()
-      .join(" ");
-    let ors = self
-      .ors
-      .iter()
-      .map(|x| format!("OR ({})", x.sql(value_index)))
-      .collect::<Vec<String>>()
-      .join(" ");
-    format!("{} {} {}", condition, ands, ors)
+    let mut parts = vec![self.condition.sql(value_index)];
+    for and in &self.ands {
+      parts.push(format!("AND ({})", and.sql(value_index)));
+    }
+    if self.ors.is_empty() {
+      return parts.join(" ");
+    }
+    let mut parts = if self.ands.is_empty() {
+      parts
+    } else {
+      vec![format!("({})", parts.join(" "))]
+    };
+    for or in &self.ors {
+      parts.push(format!("OR ({})", or.sql(value_index)));
+    }
+    parts.join(" ")
   }
   pub fn and(mut self, expression: Expression<'a>) -> Expression<'a> {
     self.ands.push(expression);
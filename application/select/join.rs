@@ -26,11 +26,12 @@ impl<'a> Join<'a> {
       JoinType::Inner => "INNER",
       JoinType::Left => "LEFT",
     };
+    let alias = self.alias.map(|x| format!(" {x}")).unwrap_or_default();
     format!(
-      "{} JOIN {} {} ON {}",
+      "{} JOIN {}{} ON {}",
       join_type,
       self.table,
-      self.alias.unwrap_or(""),
+      alias,
       &self.expression.sql(count)
     )
   }
@@ -9,17 +9,11 @@ pub struct OrderBy<'a> {
 
 impl Display for OrderBy<'_> {
   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-    write!(
-      f,
-      "{} {} {}",
-      self.selector.sql(),
-      self.direction,
-      self
-        .nulls_order
-        .as_ref()
-        .map(|x| format!("NULLS {x}"))
-        .unwrap_or_default()
-    )
+    write!(f, "{} {}", self.selector.sql(), self.direction)?;
+    match &self.nulls_order {
+      Some(nulls_order) => write!(f, " NULLS {nulls_order}"),
+      None => Ok(()),
+    }
   }
 }
 
@@ -0,0 +1,93 @@
+use from_row::FromRow;
+
+use crate::select::combined_tuple::CombinedType;
+use crate::select::expression::IntoSql;
+use crate::select::Select;
+
+/// A select that is embedded in another statement, its placeholders continue the numbering of the outer statement
+pub trait SubSelect: Send + Sync {
+  fn sql(&self, value_index: &mut usize) -> String;
+  fn values(&self) -> Vec<&IntoSql>;
+}
+
+impl<T: FromRow<DbType = T> + CombinedType + Send + Sync> SubSelect for Select<'_, T> {
+  fn sql(&self, value_index: &mut usize) -> String {
+    Select::sql(self, value_index)
+  }
+  fn values(&self) -> Vec<&IntoSql> {
+    Select::values(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::select::comparison::Comparison;
+  use crate::select::conditions::exists::Exists;
+  use crate::select::conditions::not::Not;
+  use crate::select::conditions::value_between::ValueBetween;
+  use crate::select::conditions::value_comparison::ValueComparison;
+  use crate::select::conditions::value_in_select::ValueInSelect;
+  use crate::select::expression::Expression;
+  use crate::select::Select;
+
+  fn normalize(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<&str>>().join(" ")
+  }
+
+  #[test]
+  fn value_in_select_continues_numbering() {
+    let genres = Select::new_raw("bookgenre")
+      .column::<i32>("bookgenre", "fkbook")
+      .where_expression(Expression::column_equal("bookgenre", "fkgenre", 2));
+    let select = Select::new_raw("book")
+      .column::<i32>("book", "id")
+      .where_expression(Expression::column_equal("book", "id", 1))
+      .where_expression(Expression::new(ValueInSelect::new(("book", "id"), genres)))
+      .where_expression(Expression::column_equal("book", "id", 3));
+    assert_eq!(
+      normalize(&select.query_sql()),
+      "SELECT book.id FROM book WHERE (book.id = $1) AND (book.id IN (SELECT bookgenre.fkbook FROM bookgenre WHERE bookgenre.fkgenre = $2)) AND (book.id = $3)"
+    );
+    assert_eq!(format!("{:?}", select.values()), "[1, 2, 3]");
+  }
+
+  #[test]
+  fn exists_and_not_wrap_their_expression() {
+    let themes = Select::new_raw("booktheme")
+      .column::<i32>("booktheme", "fktheme")
+      .where_expression(Expression::column_equal("booktheme", "fktheme", 5));
+    let select = Select::new_raw("book")
+      .column::<i32>("book", "id")
+      .where_expression(Expression::new(Not::new(Expression::new(Exists::new(themes)))))
+      .where_expression(Expression::new(ValueBetween::new(("book", "id"), 1, 10)));
+    assert_eq!(
+      normalize(&select.query_sql()),
+      "SELECT book.id FROM book WHERE (NOT (EXISTS (SELECT booktheme.fktheme FROM booktheme WHERE booktheme.fktheme = $1))) AND (book.id BETWEEN $2 AND $3)"
+    );
+    assert_eq!(format!("{:?}", select.values()), "[5, 1, 10]");
+  }
+
+  #[test]
+  fn with_values_come_first() {
+    let ranks = Select::new_raw("bookstatistic")
+      .column::<i32>("bookstatistic", "fkbook")
+      .where_expression(Expression::new(ValueComparison::new(
+        ("bookstatistic", "rank"),
+        Comparison::LessEqual,
+        100,
+      )));
+    let select = Select::new_raw("ranks")
+      .with("ranks", ranks)
+      .column::<i32>("ranks", "fkbook")
+      .where_expression(Expression::new(ValueComparison::new(
+        ("ranks", "fkbook"),
+        Comparison::Bigger,
+        7,
+      )));
+    assert_eq!(
+      normalize(&select.query_sql()),
+      "WITH ranks AS (SELECT bookstatistic.fkbook FROM bookstatistic WHERE bookstatistic.rank <= $1) SELECT ranks.fkbook FROM ranks WHERE ranks.fkbook > $2"
+    );
+    assert_eq!(format!("{:?}", select.values()), "[100, 7]");
+  }
+}
@@ -1,6 +1,11 @@
+use crate::enums::db_account_role::DbAccountRole;
+use crate::enums::db_friendship_status::DbFriendshipStatus;
 use crate::enums::db_language::DbLanguage;
+use crate::enums::db_status::DbStatus;
+use crate::enums::db_user_status::DbUserStatus;
 use crate::select::expression::{next, IntoSql};
-use chrono::{NaiveDate, NaiveTime};
+use crate::types::db_interval::DbInterval;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use tokio_postgres::types::ToSql;
 
 pub trait ToSqlValue<'a>: Send + Sync {
@@ -76,8 +81,16 @@ to_value!(i8);
 to_value!(i16);
 to_value!(i32);
 to_value!(i64);
+to_value!(f32);
+to_value!(bool);
+to_value!(DbAccountRole);
+to_value!(DbFriendshipStatus);
 to_value!(DbLanguage);
+to_value!(DbUserStatus);
+to_value!(DbStatus);
 to_value!(NaiveDate);
+to_value!(NaiveDateTime);
 to_value!(NaiveTime);
+to_value!(DbInterval);
 to_value!(String);
 to_value!(&'a str);
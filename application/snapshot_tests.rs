@@ -0,0 +1,230 @@
+//! Compares the generated SQL and parameter order of the query builders with the files in `snapshots`.
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to write the snapshots again after an intended change.
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use tokio_postgres::types::ToSql;
+
+use crate::delete::Delete;
+use crate::insert::Insert;
+use crate::select::comparison::Comparison;
+use crate::select::conditions::column_equal::ColumnEqual;
+use crate::select::conditions::column_null::ColumnNull;
+use crate::select::conditions::not::Not;
+use crate::select::conditions::value_comparison::ValueComparison;
+use crate::select::conditions::value_ilike::ValueILike;
+use crate::select::conditions::value_in::ValueIn;
+use crate::select::conditions::value_in_select::ValueInSelect;
+use crate::select::expression::Expression;
+use crate::select::order_by::{Direction, NullsOrder};
+use crate::select::Select;
+use crate::update::Update;
+use domain::pagination::Pagination;
+
+fn assert_snapshot(name: &str, sql: &str, values: &[&(dyn ToSql + Sync)]) {
+  let actual = format!("{sql}\n{values:?}\n");
+  let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("snapshots")
+    .join(format!("{name}.snap"));
+  if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, actual).unwrap();
+    return;
+  }
+  let expected = fs::read_to_string(&path)
+    .unwrap_or_else(|_| panic!("Snapshot {name} is missing, run the tests with UPDATE_SNAPSHOTS=1 to create it"));
+  assert_eq!(
+    actual, expected,
+    "Snapshot {name} changed, run the tests with UPDATE_SNAPSHOTS=1 if this is intended"
+  );
+}
+
+#[test]
+fn expression_and_or_nesting() {
+  let ids = vec![2, 3];
+  let expression = Expression::column_equal("book", "id", 1)
+    .and(Expression::column_equal("book", "slug", "slug").or(Expression::new(ColumnNull::new(("book", "slug")))))
+    .or(Expression::new(ValueIn::new(("book", "id"), &ids)));
+  assert_snapshot(
+    "expression_and_or_nesting",
+    &expression.sql(&mut 1),
+    &expression.values(),
+  );
+}
+
+#[test]
+fn expression_starts_at_given_index() {
+  let title = "%title%".to_string();
+  let expression = Expression::new(ValueILike::new(("booktranslation", "title"), &title))
+    .and(Expression::new(Not::new(Expression::column_equal("book", "id", 4))));
+  assert_snapshot(
+    "expression_starts_at_given_index",
+    &expression.sql(&mut 5),
+    &expression.values(),
+  );
+}
+
+#[test]
+fn select_columns_and_wheres() {
+  let select = Select::new_raw("book")
+    .column::<i32>("book", "id")
+    .column::<String>("book", "slug")
+    .where_expression(Expression::column_equal("book", "id", 1))
+    .where_expression(Expression::column_equal("book", "slug", "slug").or(Expression::column_equal("book", "id", 2)));
+  assert_snapshot("select_columns_and_wheres", &select.query_sql(), &select.values());
+}
+
+#[test]
+fn select_join_values_come_before_wheres() {
+  let select = Select::new_raw("book")
+    .alias("b")
+    .column::<i32>("b", "id")
+    .inner_join_raw(
+      "booktranslation",
+      Some("t"),
+      Expression::new(ColumnEqual::new(("t", "fktranslation"), ("b", "id")))
+        .and(Expression::column_equal("t", "language", "EN")),
+    )
+    .left_join_raw(
+      "franchise",
+      None,
+      Expression::new(ColumnEqual::new(("franchise", "id"), ("b", "fkfranchise"))),
+    )
+    .where_expression(Expression::column_equal("b", "id", 3));
+  assert_snapshot(
+    "select_join_values_come_before_wheres",
+    &select.query_sql(),
+    &select.values(),
+  );
+}
+
+#[test]
+fn select_group_by_having() {
+  let select = Select::new_raw("userbook")
+    .column::<i32>("userbook", "fkbook")
+    .count()
+    .where_expression(Expression::column_equal("userbook", "fkuser", 1))
+    .group_by("userbook", "fkbook")
+    .group_by("userbook", "status")
+    .having(Expression::new(ValueComparison::new(
+      ("userbook", "score"),
+      Comparison::BiggerEqual,
+      5,
+    )))
+    .having(Expression::new(ValueComparison::new(
+      ("userbook", "score"),
+      Comparison::Less,
+      9,
+    )))
+    .limit(10);
+  assert_snapshot("select_group_by_having", &select.query_sql(), &select.values());
+}
+
+#[test]
+fn select_average_group_by() {
+  let ids = vec![1, 2];
+  let select = Select::new_raw("usermovie")
+    .column::<i32>("usermovie", "fkuser")
+    .average("usermovie", "score")
+    .where_expression(Expression::new(ValueIn::new(("usermovie", "fkuser"), &ids)))
+    .group_by("usermovie", "fkuser");
+  assert_snapshot("select_average_group_by", &select.query_sql(), &select.values());
+}
+
+#[test]
+fn select_distinct_order_and_pagination() {
+  let select = Select::new_raw("bookstatistic")
+    .column::<i32>("bookstatistic", "fkbook")
+    .distinct_on("bookstatistic", "rank")
+    .distinct_on("bookstatistic", "fkbook")
+    .order_by(("bookstatistic", "rank"), Direction::Ascending, None)
+    .order_by(
+      ("bookstatistic", "fkbook"),
+      Direction::Descending,
+      Some(NullsOrder::Last),
+    )
+    .pagination(Pagination { page: 2, count: 25 });
+  assert_snapshot(
+    "select_distinct_order_and_pagination",
+    &select.query_sql(),
+    &select.values(),
+  );
+}
+
+#[test]
+fn select_subquery_and_with() {
+  let ranked = Select::new_raw("bookstatistic")
+    .column::<i32>("bookstatistic", "fkbook")
+    .where_expression(Expression::new(ValueComparison::new(
+      ("bookstatistic", "rank"),
+      Comparison::LessEqual,
+      100,
+    )));
+  let genres = Select::new_raw("bookgenre")
+    .column::<i32>("bookgenre", "fkbook")
+    .where_expression(Expression::column_equal("bookgenre", "fkgenre", 7));
+  let select = Select::new_raw("ranked")
+    .with("ranked", ranked)
+    .column::<i32>("ranked", "fkbook")
+    .where_expression(Expression::new(ValueInSelect::new(("ranked", "fkbook"), genres)))
+    .limit(5);
+  assert_snapshot("select_subquery_and_with", &select.query_sql(), &select.values());
+}
+
+#[test]
+fn insert_multiple_rows() {
+  let insert = Insert::new_raw("bookgenre", ["fkbook", "fkgenre"])
+    .values([&1, &2])
+    .values([&1, &3])
+    .values([&4, &5]);
+  assert_snapshot("insert_multiple_rows", &insert.sql(), &insert.sql_values());
+}
+
+#[test]
+fn insert_default_values() {
+  let insert = Insert::new_raw("franchise", []);
+  assert_snapshot(
+    "insert_default_values",
+    &insert.returning_sql("id"),
+    &insert.sql_values(),
+  );
+}
+
+#[test]
+fn insert_on_conflict_returning() {
+  let description: Option<String> = None;
+  let insert = Insert::new_raw("user", ["name", "description"])
+    .values([&"name", &description])
+    .on_conflict_do_update(&["name"], &["description"]);
+  assert_snapshot(
+    "insert_on_conflict_returning",
+    &insert.returning_sql("id"),
+    &insert.sql_values(),
+  );
+}
+
+#[test]
+fn delete_with_nested_expression() {
+  let ids = vec![1, 2, 3];
+  let delete = Delete::new_raw(
+    "bookgenre",
+    Expression::column_equal("bookgenre", "fkbook", 9).and(
+      Expression::new(ValueIn::new(("bookgenre", "fkgenre"), &ids))
+        .or(Expression::new(ColumnNull::new(("bookgenre", "fkgenre")))),
+    ),
+  );
+  assert_snapshot("delete_with_nested_expression", &delete.sql(), &delete.values());
+}
+
+#[test]
+fn update_set_and_where() {
+  let update = Update::new_raw("account")
+    .set("password", "hash")
+    .set("verified", true)
+    .where_expression(Expression::column_equal("account", "fkuser", 1))
+    .where_expression(Expression::new(Not::new(Expression::column_equal(
+      "account", "verified", true,
+    ))));
+  assert_snapshot("update_set_and_where", &update.sql().unwrap(), &update.values());
+}
@@ -0,0 +1,145 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use tokio_postgres::error::SqlState;
+use tokio_postgres::{Client, Error, Statement};
+
+pub const DEFAULT_STATEMENT_CACHE_SIZE: usize = 256;
+
+/// Prepared statements of one connection keyed by their SQL, the least recently used one is dropped once it is full.
+pub(crate) struct StatementCache {
+  entries: Mutex<Entries<Statement>>,
+}
+
+impl StatementCache {
+  pub(crate) fn new(capacity: usize) -> StatementCache {
+    StatementCache {
+      entries: Mutex::new(Entries::new(capacity)),
+    }
+  }
+
+  /// Returns the cached statement for the SQL or prepares and caches it
+  pub(crate) async fn prepare(&self, client: &Client, sql: &str) -> Result<Statement, Error> {
+    if let Some(statement) = self.entries.lock().ok().and_then(|mut entries| entries.get(sql)) {
+      return Ok(statement);
+    }
+    let statement = client.prepare(sql).await?;
+    if let Ok(mut entries) = self.entries.lock() {
+      entries.insert(sql, statement.clone());
+    }
+    Ok(statement)
+  }
+
+  pub(crate) fn evict(&self, sql: &str) {
+    if let Ok(mut entries) = self.entries.lock() {
+      entries.remove(sql);
+    }
+  }
+}
+
+/// Whether the error means the cached statement of the SQL can not be used anymore,
+/// e.g. because a table it selects from was altered or the server no longer knows it
+pub(crate) fn is_stale(error: &Error) -> bool {
+  error
+    .code()
+    .is_some_and(|code| *code == SqlState::FEATURE_NOT_SUPPORTED || *code == SqlState::INVALID_SQL_STATEMENT_NAME)
+}
+
+/// Values keyed by their SQL and by their last use, so the least recently used one is found without a scan
+struct Entries<T> {
+  capacity: usize,
+  uses: u64,
+  values: HashMap<String, (T, u64)>,
+  by_use: BTreeMap<u64, String>,
+}
+
+impl<T: Clone> Entries<T> {
+  fn new(capacity: usize) -> Entries<T> {
+    Entries {
+      capacity,
+      uses: 0,
+      values: HashMap::new(),
+      by_use: BTreeMap::new(),
+    }
+  }
+
+  fn get(&mut self, sql: &str) -> Option<T> {
+    self.uses += 1;
+    let (value, last_use) = self.values.get_mut(sql)?;
+    let sql = self.by_use.remove(last_use)?;
+    *last_use = self.uses;
+    let value = value.clone();
+    self.by_use.insert(self.uses, sql);
+    Some(value)
+  }
+
+  fn insert(&mut self, sql: &str, value: T) {
+    if self.capacity == 0 {
+      return;
+    }
+    self.remove(sql);
+    if self.values.len() >= self.capacity {
+      if let Some((_, least_recent)) = self.by_use.pop_first() {
+        self.values.remove(&least_recent);
+      }
+    }
+    self.uses += 1;
+    self.values.insert(sql.to_string(), (value, self.uses));
+    self.by_use.insert(self.uses, sql.to_string());
+  }
+
+  fn remove(&mut self, sql: &str) {
+    if let Some((_, last_use)) = self.values.remove(sql) {
+      self.by_use.remove(&last_use);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Entries;
+
+  #[test]
+  fn evicts_least_recently_used() {
+    let mut entries = Entries::new(2);
+    entries.insert("a", 1);
+    entries.insert("b", 2);
+    assert_eq!(entries.get("a"), Some(1));
+
+    entries.insert("c", 3);
+
+    assert_eq!(entries.get("b"), None);
+    assert_eq!(entries.get("a"), Some(1));
+    assert_eq!(entries.get("c"), Some(3));
+  }
+
+  #[test]
+  fn reinserting_replaces_the_value() {
+    let mut entries = Entries::new(2);
+    entries.insert("a", 1);
+    entries.insert("b", 2);
+    entries.insert("a", 3);
+
+    assert_eq!(entries.get("a"), Some(3));
+    assert_eq!(entries.get("b"), Some(2));
+    assert_eq!(entries.values.len(), entries.by_use.len());
+  }
+
+  #[test]
+  fn removed_entries_are_prepared_again() {
+    let mut entries = Entries::new(2);
+    entries.insert("a", 1);
+    entries.remove("a");
+
+    assert_eq!(entries.get("a"), None);
+    assert!(entries.by_use.is_empty());
+  }
+
+  #[test]
+  fn zero_capacity_caches_nothing() {
+    let mut entries = Entries::new(0);
+    entries.insert("a", 1);
+
+    assert_eq!(entries.get("a"), None);
+  }
+}
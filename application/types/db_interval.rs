@@ -0,0 +1,80 @@
+use std::error::Error;
+
+use from_row::postgres_type::{PostgresType, TypeKind};
+use from_row::{from_row_impl, FromRow, FromRowOption};
+use tokio_postgres::types::private::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+const MICROSECONDS_PER_MINUTE: i64 = 60_000_000;
+const MINUTES_PER_DAY: i64 = 24 * 60;
+const DAYS_PER_MONTH: i64 = 30;
+
+/// Postgres `interval` in its binary representation.
+///
+/// tokio-postgres does not provide a mapping for intervals, so the wire format
+/// (microseconds, days and months) is read and written directly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DbInterval {
+  pub microseconds: i64,
+  pub days: i32,
+  pub months: i32,
+}
+from_row_impl!(DbInterval);
+
+impl DbInterval {
+  pub const fn from_minutes(minutes: u32) -> DbInterval {
+    DbInterval {
+      microseconds: minutes as i64 * MICROSECONDS_PER_MINUTE,
+      days: 0,
+      months: 0,
+    }
+  }
+
+  /// Total length in minutes. A month is counted as 30 days, same as Postgres does when justifying intervals.
+  pub const fn minutes(self) -> u32 {
+    let days = self.months as i64 * DAYS_PER_MONTH + self.days as i64;
+    let minutes = days * MINUTES_PER_DAY + self.microseconds / MICROSECONDS_PER_MINUTE;
+    minutes as u32
+  }
+}
+
+impl<'a> FromSql<'a> for DbInterval {
+  fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+    if raw.len() != 16 {
+      return Err("Invalid interval length".into());
+    }
+    Ok(DbInterval {
+      microseconds: i64::from_be_bytes(raw[0..8].try_into()?),
+      days: i32::from_be_bytes(raw[8..12].try_into()?),
+      months: i32::from_be_bytes(raw[12..16].try_into()?),
+    })
+  }
+
+  fn accepts(ty: &Type) -> bool {
+    *ty == Type::INTERVAL
+  }
+}
+
+impl ToSql for DbInterval {
+  fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+  where
+    Self: Sized,
+  {
+    out.extend_from_slice(&self.microseconds.to_be_bytes());
+    out.extend_from_slice(&self.days.to_be_bytes());
+    out.extend_from_slice(&self.months.to_be_bytes());
+    Ok(IsNull::No)
+  }
+
+  fn accepts(ty: &Type) -> bool
+  where
+    Self: Sized,
+  {
+    <DbInterval as FromSql>::accepts(ty)
+  }
+  to_sql_checked!();
+}
+
+impl PostgresType for DbInterval {
+  const POSTGRES_TYPES: &'static [TypeKind] = &[TypeKind::Postgres(Type::INTERVAL)];
+}
@@ -0,0 +1,247 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use tokio_postgres::types::ToSql;
+
+use crate::cached_connection::{CachedClient, CachedTransaction};
+use crate::select::expression::Expression;
+use crate::select::to_sql_value::ToSqlValue;
+use from_row::{FromRow, RowColumns, Table};
+
+pub struct Update<'a> {
+  table: &'a str,
+  sets: Vec<(&'a str, Box<dyn ToSqlValue<'a> + 'a>)>,
+  wheres: Vec<Expression<'a>>,
+}
+
+impl<'a> Update<'a> {
+  pub fn new<T: Table>() -> Update<'a> {
+    Self::new_raw(T::TABLE_NAME)
+  }
+  pub fn new_raw(table: &'a str) -> Update<'a> {
+    Update {
+      table,
+      sets: vec![],
+      wheres: vec![],
+    }
+  }
+  pub fn set(mut self, column: &'a str, value: impl ToSqlValue<'a> + 'a) -> Self {
+    self.sets.push((column, Box::new(value)));
+    self
+  }
+  pub fn where_expression(mut self, expression: Expression<'a>) -> Self {
+    self.wheres.push(expression);
+    self
+  }
+}
+
+impl<'a> Update<'a> {
+  pub async fn execute(&self, client: &CachedClient) -> Result<u64, UpdateError> {
+    let values = &self.values();
+    client
+      .run(&self.sql()?, |statement| async move {
+        client.execute(&statement, values).await
+      })
+      .await
+      .map_err(UpdateError::PostgresError)
+  }
+
+  pub async fn execute_transaction(&self, transaction: &CachedTransaction<'_>) -> Result<u64, UpdateError> {
+    self.execute(transaction.client()).await
+  }
+
+  /// Returns an error if more than one row was updated, the rows are changed nonetheless.
+  /// Only use it with a condition on a unique column or roll back the transaction on the error
+  pub async fn returning<T: FromRow<DbType = T> + RowColumns>(
+    &self,
+    client: &CachedClient,
+  ) -> Result<Option<T>, UpdateError> {
+    let values = &self.values();
+    Ok(
+      client
+        .run(&self.returning_sql::<T>()?, |statement| async move {
+          client.query_opt(&statement, values).await
+        })
+        .await
+        .map_err(UpdateError::PostgresError)?
+        .map(|x| T::from_row(&x, 0)),
+    )
+  }
+
+  /// Returns an error if more than one row was updated, the rows stay changed until the transaction is rolled back
+  pub async fn returning_transaction<T: FromRow<DbType = T> + RowColumns>(
+    &self,
+    transaction: &CachedTransaction<'_>,
+  ) -> Result<Option<T>, UpdateError> {
+    self.returning(transaction.client()).await
+  }
+
+  pub async fn returning_many<T: FromRow<DbType = T> + RowColumns>(
+    &self,
+    client: &CachedClient,
+  ) -> Result<Vec<T>, UpdateError> {
+    let values = &self.values();
+    Ok(
+      client
+        .run(&self.returning_sql::<T>()?, |statement| async move {
+          client.query(&statement, values).await
+        })
+        .await
+        .map_err(UpdateError::PostgresError)?
+        .into_iter()
+        .map(|x| T::from_row(&x, 0))
+        .collect(),
+    )
+  }
+
+  pub async fn returning_many_transaction<T: FromRow<DbType = T> + RowColumns>(
+    &self,
+    transaction: &CachedTransaction<'_>,
+  ) -> Result<Vec<T>, UpdateError> {
+    self.returning_many(transaction.client()).await
+  }
+
+  pub fn sql(&self) -> Result<String, UpdateError> {
+    if self.sets.is_empty() {
+      return Err(UpdateError::NoColumns);
+    }
+    let mut count = 1;
+    let table = self.table;
+    let sets = self.sets_sql(&mut count);
+    let where_sql = self.where_sql(&mut count).unwrap_or_default();
+    Ok(format!("UPDATE {table} SET {sets}{where_sql}"))
+  }
+
+  pub fn returning_sql<T: RowColumns>(&self) -> Result<String, UpdateError> {
+    let columns = T::COLUMNS.iter().map(|x| x.0).collect::<Vec<&str>>().join(",");
+    Ok(format!("{} RETURNING {columns}", self.sql()?))
+  }
+
+  fn sets_sql(&self, count: &mut usize) -> String {
+    self
+      .sets
+      .iter()
+      .map(|(column, value)| format!("{column} = {}", value.sql(count)))
+      .collect::<Vec<String>>()
+      .join(",")
+  }
+
+  fn where_sql(&self, count: &mut usize) -> Option<String> {
+    if self.wheres.is_empty() {
+      return None;
+    }
+
+    let wheres = self
+      .wheres
+      .iter()
+      .map(|expression| match self.wheres.len() {
+        1 => expression.sql(count),
+        _ => format!("({})", expression.sql(count)),
+      })
+      .collect::<Vec<String>>()
+      .join(" AND ");
+    Some(format!(" WHERE {}", wheres))
+  }
+
+  pub(crate) fn values(&self) -> Vec<&(dyn ToSql + Sync)> {
+    let mut total: Vec<&(dyn ToSql + Sync)> = vec![];
+    self
+      .sets
+      .iter()
+      .for_each(|(_, value)| total.append(&mut value.values()));
+    self.wheres.iter().for_each(|x| total.append(&mut x.values()));
+    total
+  }
+}
+
+#[derive(Debug)]
+pub enum UpdateError {
+  NoColumns,
+  PostgresError(tokio_postgres::Error),
+}
+
+impl Display for UpdateError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      UpdateError::NoColumns => write!(f, "An update needs at least one column to set"),
+      UpdateError::PostgresError(value) => std::fmt::Display::fmt(&value, f),
+    }
+  }
+}
+
+impl Error for UpdateError {}
+
+#[cfg(test)]
+mod tests {
+  use crate::select::conditions::value_in::ValueIn;
+  use crate::select::expression::Expression;
+
+  use super::{Update, UpdateError};
+
+  #[test]
+  fn numbers_set_values_in_order() {
+    let update = Update::new_raw("users").set("name", "name").set("deleted", true);
+    assert_eq!(update.sql().unwrap(), "UPDATE users SET name = $1,deleted = $2");
+    assert_eq!(update.values().len(), 2);
+  }
+
+  #[test]
+  fn where_continues_after_set_values() {
+    let update = Update::new_raw("users")
+      .set("name", "name")
+      .set("description", "description")
+      .where_expression(Expression::column_equal("users", "id", 3));
+    assert_eq!(
+      update.sql().unwrap(),
+      "UPDATE users SET name = $1,description = $2 WHERE users.id = $3"
+    );
+    assert_eq!(update.values().len(), 3);
+  }
+
+  #[test]
+  fn multiple_wheres_are_combined() {
+    let ids = vec![1, 2];
+    let update = Update::new_raw("tokens")
+      .set("lastused", 5)
+      .where_expression(
+        Expression::column_equal("tokens", "fkuser", 1).and(Expression::column_equal("tokens", "id", 2)),
+      )
+      .where_expression(Expression::new(ValueIn::new(("tokens", "id"), &ids)));
+    assert_eq!(
+      update.sql().unwrap(),
+      "UPDATE tokens SET lastused = $1 WHERE (tokens.fkuser = $2 AND (tokens.id = $3)) AND (tokens.id IN ($4,$5))"
+    );
+    assert_eq!(update.values().len(), 5);
+  }
+
+  #[test]
+  fn null_values_take_a_placeholder() {
+    let update = Update::new_raw("users")
+      .set("description", None::<String>)
+      .set("fkprofilepicture", Some(4))
+      .where_expression(Expression::column_equal("users", "id", 1));
+    assert_eq!(
+      update.sql().unwrap(),
+      "UPDATE users SET description = $1,fkprofilepicture = $2 WHERE users.id = $3"
+    );
+    assert_eq!(update.values().len(), 3);
+  }
+
+  #[test]
+  fn returning_lists_row_columns() {
+    let update = Update::new_raw("twofactor")
+      .set("confirmed", true)
+      .where_expression(Expression::column_equal("twofactor", "fkuser", 1));
+    assert_eq!(
+      update
+        .returning_sql::<crate::schemas::db_two_factor::DbTwoFactor>()
+        .unwrap(),
+      "UPDATE twofactor SET confirmed = $1 WHERE twofactor.fkuser = $2 RETURNING fkuser,secret,confirmed,laststep"
+    );
+  }
+
+  #[test]
+  fn update_without_columns_fails() {
+    assert!(matches!(Update::new_raw("users").sql(), Err(UpdateError::NoColumns)));
+  }
+}
@@ -1,7 +1,21 @@
+pub mod account_export;
 pub mod create_account;
+pub mod create_email_verification;
 pub mod create_partial_account;
+pub mod create_partial_personal_access_token;
+pub mod create_password_reset;
+pub mod create_personal_access_token;
+pub mod create_refresh_token;
+pub mod create_two_factor;
+pub mod email_verification;
+pub mod password_reset;
+pub mod personal_access_token;
+pub mod refresh_token;
+pub mod two_factor;
+pub mod two_factor_enrollment;
 
 use crate::entities::user::User;
+use crate::enums::account_role::AccountRole;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10,6 +24,8 @@ pub struct Account {
   pub user: User,
   pub email: Email,
   pub password: Password,
+  pub role: AccountRole,
+  pub verified: bool,
 }
 
 #[derive(Debug, Clone)]
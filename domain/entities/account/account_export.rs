@@ -0,0 +1,20 @@
+use crate::entities::account::Email;
+use crate::entities::user::friendship::Friendship;
+use crate::entities::user::user_book::UserBook;
+use crate::entities::user::User;
+use crate::enums::account_role::AccountRole;
+
+/// Everything stored about an account and its user, the password hash and tokens are left out.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct AccountExport {
+  pub email: Email,
+  pub role: AccountRole,
+  pub verified: bool,
+  pub user: User,
+  pub books: Vec<UserBook>,
+  pub friends: Vec<Friendship>,
+  pub incoming_requests: Vec<Friendship>,
+  pub outgoing_requests: Vec<Friendship>,
+}
@@ -0,0 +1,8 @@
+use chrono::NaiveDateTime;
+
+#[derive(Debug, Clone)]
+pub struct CreateEmailVerification {
+  pub user_id: u32,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
@@ -0,0 +1,12 @@
+use chrono::NaiveDateTime;
+
+use crate::enums::token_scope::TokenScope;
+
+#[derive(Debug, Clone)]
+pub struct CreatePartialPersonalAccessToken {
+  pub user_id: u32,
+  pub name: String,
+  pub scopes: Vec<TokenScope>,
+  pub hash: String,
+  pub created: NaiveDateTime,
+}
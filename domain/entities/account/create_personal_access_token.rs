@@ -0,0 +1,9 @@
+use crate::enums::token_scope::TokenScope;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePersonalAccessToken {
+  pub name: String,
+  pub scopes: Vec<TokenScope>,
+}
@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+
+#[derive(Debug, Clone)]
+pub struct CreateRefreshToken {
+  pub user_id: u32,
+  pub family: Option<u32>,
+  pub parent: Option<u32>,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
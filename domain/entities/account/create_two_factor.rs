@@ -0,0 +1,5 @@
+#[derive(Debug, Clone)]
+pub struct CreateTwoFactor {
+  pub user_id: u32,
+  pub secret: String,
+}
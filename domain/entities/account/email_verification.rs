@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+
+/// A hashed, single use token that proves the owner of an account can receive mails at its email.
+#[derive(Debug, Clone)]
+pub struct EmailVerification {
+  pub id: u32,
+  pub user_id: u32,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
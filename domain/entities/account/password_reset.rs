@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+
+/// A hashed, single use token that allows setting a new password without knowing the old one.
+#[derive(Debug, Clone)]
+pub struct PasswordReset {
+  pub id: u32,
+  pub user_id: u32,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+
+use crate::enums::token_scope::TokenScope;
+
+/// A long-lived token for scripts, only its hash is stored.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PersonalAccessToken {
+  pub id: u32,
+  pub user_id: u32,
+  pub name: String,
+  pub scopes: Vec<TokenScope>,
+  pub created: NaiveDateTime,
+  pub last_used: Option<NaiveDateTime>,
+}
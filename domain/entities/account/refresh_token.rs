@@ -0,0 +1,20 @@
+use chrono::NaiveDateTime;
+
+/// A hashed refresh token, every rotation creates a new token in the family of the token that was used.
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+  pub id: u32,
+  pub user_id: u32,
+  /// Id of the token that started the family, `None` if this token started it
+  pub family: Option<u32>,
+  /// Id of the token that was used to create this one
+  pub parent: Option<u32>,
+  pub hash: String,
+  pub expires: NaiveDateTime,
+}
+
+impl RefreshToken {
+  pub fn family_id(&self) -> u32 {
+    self.family.unwrap_or(self.id)
+  }
+}
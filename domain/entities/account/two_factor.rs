@@ -0,0 +1,10 @@
+/// A TOTP second factor, it is only used for logins once it is confirmed.
+#[derive(Debug, Clone)]
+pub struct TwoFactor {
+  pub user_id: u32,
+  /// Base32 encoded, as shown to the user
+  pub secret: String,
+  pub confirmed: bool,
+  /// The time step of the last accepted code, codes can not be used twice
+  pub last_step: Option<u64>,
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct TwoFactorEnrollment {
+  /// Base32 encoded, for entering it manually
+  pub secret: String,
+  /// otpauth URI, usually shown as a QR code
+  pub uri: String,
+}
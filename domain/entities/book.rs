@@ -6,10 +6,17 @@ use crate::enums::language::Language;
 use crate::slug::Slug;
 
 pub mod book_character;
+pub mod book_edition;
+pub mod book_edition_involved;
+pub mod book_image;
 pub mod book_involved;
 pub mod book_statistic;
 pub mod create_book;
+pub mod create_book_edition;
+pub mod create_book_image;
 pub mod create_partial_book;
+pub mod create_partial_book_edition;
+pub mod patch_book_edition;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,21 @@
+use chrono::NaiveDate;
+
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+use crate::isbn13::Isbn13;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct BookEdition {
+  pub id: u32,
+  pub book_id: u32,
+  pub description: Option<String>,
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+  pub words: Option<u32>,
+  pub published: Option<NaiveDate>,
+  pub isbn13: Option<Isbn13>,
+  pub language: Option<Language>,
+  pub cover: Image,
+}
@@ -0,0 +1,9 @@
+use crate::entities::image::Image;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct BookImage {
+  pub image: Image,
+  pub position: u16,
+}
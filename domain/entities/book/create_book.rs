@@ -1,4 +1,4 @@
-// use crate::entities::book::create_book_edition::CreateBookEditionData;
+use crate::entities::book::create_book_edition::CreateBookEditionData;
 use crate::entities::image::create_image::CreateImage;
 use crate::entities::involved::InvolvedId;
 use crate::enums::language::Language;
@@ -12,9 +12,9 @@ use std::collections::HashMap;
 pub struct CreateBook {
   pub book: CreateBookData,
   pub covers: Vec<CreateImage>,
-  // pub images: Option<Vec<CreateImage>>,
-  // pub editions: Vec<CreateBookEditionData>,
-  // pub edition_covers: Vec<CreateImage>,
+  pub images: Option<Vec<CreateImage>>,
+  pub editions: Vec<CreateBookEditionData>,
+  pub edition_covers: Vec<CreateImage>,
 }
 
 #[derive(Debug, Clone)]
@@ -51,7 +51,7 @@ pub enum CreateCover {
 #[cfg(feature = "axum-multipart")]
 pub mod create_book_part {
   use crate::entities::book::create_book::{CreateBook, CreateBookData};
-  // use crate::entities::book::create_book_edition::CreateBookEditionData;
+  use crate::entities::book::create_book_edition::CreateBookEditionData;
   use crate::entities::image::create_image::CreateImage;
   use crate::vec_single::{Single, SingleVecError};
   use multipart::axum::extract::multipart::MultipartError;
@@ -113,34 +113,28 @@ pub mod create_book_part {
         })?;
 
       let book: CreateBookData = from_slice(&book_bytes)?;
-      // let editions = parts.remove(&Some("editions".to_string())).unwrap_or_else(Vec::new);
-      // let editions: Result<Vec<CreateBookEditionData>, serde_json::Error> = editions
-      //   .into_iter()
-      //   .map(|x| {
-      //     let edition: Result<CreateBookEditionData, serde_json::Error> = from_slice(&x);
-      //     edition
-      //   })
-      //   .collect::<Vec<Result<CreateBookEditionData, serde_json::Error>>>()
-      //   .into_iter()
-      //   .collect();
-      // let editions = editions?;
+      let editions = parts.remove(&Some("editions".to_string())).unwrap_or_else(Vec::new);
+      let editions: Vec<CreateBookEditionData> = editions
+        .into_iter()
+        .map(|x| from_slice(&x))
+        .collect::<Result<Vec<CreateBookEditionData>, serde_json::Error>>()?;
 
       let covers = parts.remove(&Some("covers".to_string())).unwrap_or_else(Vec::new);
       let covers: Vec<CreateImage> = covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
 
-      // let images = parts.remove(&Some("images".to_string())).unwrap_or_else(Vec::new);
-      // let images: Option<Vec<CreateImage>> = Some(images.into_iter().map(|x| CreateImage(x.to_vec())).collect());
+      let images = parts.remove(&Some("images".to_string())).unwrap_or_else(Vec::new);
+      let images: Option<Vec<CreateImage>> = Some(images.into_iter().map(|x| CreateImage(x.to_vec())).collect());
 
-      // let edition_covers = parts
-      // .remove(&Some("edition_covers".to_string()))
-      // .unwrap_or_else(Vec::new);
-      // let edition_covers: Vec<CreateImage> = edition_covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+      let edition_covers = parts
+        .remove(&Some("edition_covers".to_string()))
+        .unwrap_or_else(Vec::new);
+      let edition_covers: Vec<CreateImage> = edition_covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
       Ok(CreateBook {
         book,
         covers,
-        // images,
-        // editions,
-        // edition_covers,
+        images,
+        editions,
+        edition_covers,
       })
     }
   }
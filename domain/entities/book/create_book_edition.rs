@@ -0,0 +1,108 @@
+use crate::entities::image::create_image::CreateImage;
+use crate::entities::involved::InvolvedId;
+use crate::enums::language::Language;
+use crate::isbn13::Isbn13;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateBookEdition {
+  pub edition: CreateBookEditionData,
+  pub covers: Vec<CreateImage>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateBookEditionData {
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+  pub words: Option<u32>,
+  pub published: Option<NaiveDate>,
+  pub isbn13: Option<Isbn13>,
+  pub language: Option<Language>,
+  pub translations: HashMap<Language, CreateBookEditionTranslation>,
+  pub involved: Option<Vec<InvolvedId>>,
+  pub cover: usize,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateBookEditionTranslation {
+  pub description: String,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_book_edition_part {
+  use crate::entities::book::create_book_edition::{CreateBookEdition, CreateBookEditionData};
+  use crate::entities::image::create_image::CreateImage;
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::axum::extract::Multipart;
+  use multipart::{serialize_parts, FromMultiPart};
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreateBookEditionPartError {
+    EditionMissing,
+    MoreThanOneEdition,
+    OtherError(Box<dyn Error + Send>),
+  }
+  impl From<serde_json::Error> for CreateBookEditionPartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreateBookEditionPartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreateBookEditionPartError {
+    fn from(value: MultipartError) -> Self {
+      CreateBookEditionPartError::OtherError(Box::new(value))
+    }
+  }
+
+  impl Display for CreateBookEditionPartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreateBookEditionPartError::EditionMissing => "Edition missing".to_string(),
+          CreateBookEditionPartError::OtherError(value) => value.to_string(),
+          CreateBookEditionPartError::MoreThanOneEdition => "There is more than 1 edition".to_string(),
+        }
+      )
+    }
+  }
+
+  impl Error for CreateBookEditionPartError {}
+
+  #[async_trait::async_trait]
+  impl FromMultiPart for CreateBookEdition {
+    type Error = CreateBookEditionPartError;
+
+    async fn from_multi_part(multipart: Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let edition_bytes = parts
+        .remove(&Some("edition".to_string()))
+        .ok_or_else(|| CreateBookEditionPartError::EditionMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateBookEditionPartError::EditionMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateBookEditionPartError::MoreThanOneEdition,
+        })?;
+
+      let edition: CreateBookEditionData = from_slice(&edition_bytes)?;
+
+      let covers = parts.remove(&Some("covers".to_string())).unwrap_or_else(Vec::new);
+      let covers: Vec<CreateImage> = covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+      Ok(CreateBookEdition { edition, covers })
+    }
+  }
+}
@@ -0,0 +1,32 @@
+use crate::entities::image::create_image::CreateImage;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateBookImages {
+  pub images: Vec<CreateImage>,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_book_images_part {
+  use crate::entities::book::create_book_image::CreateBookImages;
+  use crate::entities::image::create_image::CreateImage;
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::axum::extract::Multipart;
+  use multipart::{serialize_parts, FromMultiPart};
+
+  #[async_trait::async_trait]
+  impl FromMultiPart for CreateBookImages {
+    type Error = MultipartError;
+
+    async fn from_multi_part(multipart: Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let images = parts.remove(&Some("images".to_string())).unwrap_or_else(Vec::new);
+      let images: Vec<CreateImage> = images.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+      Ok(CreateBookImages { images })
+    }
+  }
+}
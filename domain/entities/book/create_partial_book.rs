@@ -1,4 +1,4 @@
-// use crate::entities::book::book_edition::CreateBookEdition;
+use crate::entities::book::create_partial_book_edition::CreatePartialBookEdition;
 use crate::entities::image::Image;
 use crate::entities::involved::InvolvedId;
 use crate::enums::language::Language;
@@ -18,8 +18,8 @@ pub struct CreatePartialBook {
   pub themes: Vec<u32>,
   pub characters: Vec<u32>,
   pub involved: Vec<InvolvedId>,
-  // pub editions: Vec<CreateBookEdition>,
-  // pub images: Vec<Image>,
+  pub editions: Vec<CreatePartialBookEdition>,
+  pub images: Vec<Image>,
 }
 
 #[derive(Debug, Clone)]
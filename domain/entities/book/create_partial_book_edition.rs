@@ -0,0 +1,28 @@
+use crate::entities::image::Image;
+use crate::entities::involved::InvolvedId;
+use crate::enums::language::Language;
+use crate::isbn13::Isbn13;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialBookEdition {
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+  pub words: Option<u32>,
+  pub published: Option<NaiveDate>,
+  pub isbn13: Option<Isbn13>,
+  pub language: Option<Language>,
+  pub translations: HashMap<Language, CreatePartialBookEditionTranslation>,
+  pub involved: Vec<InvolvedId>,
+  pub cover: Image,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialBookEditionTranslation {
+  pub description: String,
+}
@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use crate::entities::book::create_book_edition::CreateBookEditionTranslation;
+use crate::enums::language::Language;
+use crate::isbn13::Isbn13;
+
+/// Fields which are not set keep their current value.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PatchBookEdition {
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+  pub words: Option<u32>,
+  pub published: Option<NaiveDate>,
+  pub isbn13: Option<Isbn13>,
+  pub language: Option<Language>,
+  /// Translations which are not listed keep their current description
+  pub translations: Option<HashMap<Language, CreateBookEditionTranslation>>,
+}
@@ -0,0 +1,13 @@
+pub mod create_company;
+pub mod create_partial_company;
+
+use crate::entities::image::Image;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Company {
+  pub id: u32,
+  pub name: String,
+  pub logo: Image,
+}
@@ -0,0 +1,100 @@
+use crate::entities::image::create_image::CreateImage;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateCompany {
+  pub company: CreateCompanyData,
+  pub logo: CreateImage,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateCompanyData {
+  pub name: String,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_company_part {
+  use crate::entities::company::create_company::{CreateCompany, CreateCompanyData};
+  use crate::entities::image::create_image::CreateImage;
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::serialize_parts;
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreateCompanyPartError {
+    CompanyMissing,
+    MoreThanOneCompany,
+    LogoMissing,
+    MoreThanOneLogo,
+    OtherError(Box<dyn Error + Send>),
+  }
+
+  impl Display for CreateCompanyPartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreateCompanyPartError::CompanyMissing => "Company missing".to_string(),
+          CreateCompanyPartError::MoreThanOneCompany => "There is more than 1 company".to_string(),
+          CreateCompanyPartError::LogoMissing => "Logo missing".to_string(),
+          CreateCompanyPartError::MoreThanOneLogo => "There is more than 1 logo".to_string(),
+          CreateCompanyPartError::OtherError(value) => value.to_string(),
+        }
+      )
+    }
+  }
+  impl Error for CreateCompanyPartError {}
+  impl From<serde_json::Error> for CreateCompanyPartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreateCompanyPartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreateCompanyPartError {
+    fn from(value: MultipartError) -> Self {
+      CreateCompanyPartError::OtherError(Box::new(value))
+    }
+  }
+
+  #[async_trait::async_trait]
+  impl multipart::FromMultiPart for CreateCompany {
+    type Error = CreateCompanyPartError;
+
+    async fn from_multi_part(multipart: multipart::axum::extract::Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let company_bytes = parts
+        .remove(&Some("company".to_string()))
+        .ok_or_else(|| CreateCompanyPartError::CompanyMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateCompanyPartError::CompanyMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateCompanyPartError::MoreThanOneCompany,
+        })?;
+
+      let company: CreateCompanyData = from_slice(&company_bytes)?;
+
+      let logo = parts
+        .remove(&Some("logo".to_string()))
+        .ok_or_else(|| CreateCompanyPartError::LogoMissing)?
+        .into_iter()
+        .map(|x| CreateImage(x.to_vec()))
+        .collect::<Vec<CreateImage>>()
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateCompanyPartError::LogoMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateCompanyPartError::MoreThanOneLogo,
+        })?;
+
+      Ok(CreateCompany { company, logo })
+    }
+  }
+}
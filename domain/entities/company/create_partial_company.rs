@@ -0,0 +1,9 @@
+use crate::entities::image::Image;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialCompany {
+  pub name: String,
+  pub logo: Image,
+}
@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+
+use crate::entities::franchise::Franchise;
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+
+pub mod create_game;
+pub mod create_partial_game;
+pub mod game_character;
+pub mod game_involved;
+pub mod game_statistic;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Game {
+  pub id: u32,
+  pub title: String,
+  pub description: Option<String>,
+  pub released: Option<NaiveDate>,
+  pub cover: Image,
+  pub franchise: Option<Franchise>,
+  pub language: Language,
+}
@@ -0,0 +1,110 @@
+use crate::entities::book::create_book::CreateCover;
+use crate::entities::image::create_image::CreateImage;
+use crate::entities::involved::InvolvedId;
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGame {
+  pub game: CreateGameData,
+  pub covers: Vec<CreateImage>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGameData {
+  pub released: Option<NaiveDate>,
+  pub franchise: Option<u32>,
+  pub translations: HashMap<Language, CreateGameTranslation>,
+  pub genres: Option<Vec<u32>>,
+  pub themes: Option<Vec<u32>>,
+  pub characters: Option<Vec<u32>>,
+  pub platforms: Option<Vec<u32>>,
+  pub involved: Option<Vec<InvolvedId>>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGameTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: CreateCover,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_game_part {
+  use crate::entities::game::create_game::{CreateGame, CreateGameData};
+  use crate::entities::image::create_image::CreateImage;
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::axum::extract::Multipart;
+  use multipart::{serialize_parts, FromMultiPart};
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreateGamePartError {
+    GameMissing,
+    MoreThanOneGame,
+    OtherError(Box<dyn Error + Send>),
+  }
+  impl From<serde_json::Error> for CreateGamePartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreateGamePartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreateGamePartError {
+    fn from(value: MultipartError) -> Self {
+      CreateGamePartError::OtherError(Box::new(value))
+    }
+  }
+
+  impl Display for CreateGamePartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreateGamePartError::GameMissing => "Game missing".to_string(),
+          CreateGamePartError::OtherError(value) => value.to_string(),
+          CreateGamePartError::MoreThanOneGame => "There is more than 1 game".to_string(),
+        }
+      )
+    }
+  }
+
+  impl Error for CreateGamePartError {}
+
+  #[async_trait::async_trait]
+  impl FromMultiPart for CreateGame {
+    type Error = CreateGamePartError;
+
+    async fn from_multi_part(multipart: Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let game_bytes = parts
+        .remove(&Some("game".to_string()))
+        .ok_or_else(|| CreateGamePartError::GameMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateGamePartError::GameMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateGamePartError::MoreThanOneGame,
+        })?;
+
+      let game: CreateGameData = from_slice(&game_bytes)?;
+
+      let covers = parts.remove(&Some("covers".to_string())).unwrap_or_else(Vec::new);
+      let covers: Vec<CreateImage> = covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+
+      Ok(CreateGame { game, covers })
+    }
+  }
+}
@@ -0,0 +1,28 @@
+use crate::entities::image::Image;
+use crate::entities::involved::InvolvedId;
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialGame {
+  pub released: Option<NaiveDate>,
+  pub franchise: Option<u32>,
+  pub translations: HashMap<Language, CreatePartialGameTranslation>,
+  pub genres: Vec<u32>,
+  pub themes: Vec<u32>,
+  pub characters: Vec<u32>,
+  pub platforms: Vec<u32>,
+  pub involved: Vec<InvolvedId>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialGameTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: Image,
+}
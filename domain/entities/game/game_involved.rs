@@ -0,0 +1,10 @@
+use crate::entities::involved::Involved;
+
+pub type GameInvolved = Involved;
+
+/* TODO:
+pub struct GameInvolved {
+  pub person: Person,
+  pub roles: Vec<PersonRole>,
+}
+*/
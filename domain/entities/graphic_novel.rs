@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+use crate::enums::status::Status;
+
+pub mod create_graphic_novel;
+pub mod create_graphic_novel_chapter;
+pub mod create_graphic_novel_volume;
+pub mod create_partial_graphic_novel;
+pub mod graphic_novel_chapter;
+pub mod graphic_novel_character;
+pub mod graphic_novel_involved;
+pub mod graphic_novel_volume;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GraphicNovel {
+  pub id: u32,
+  pub title: String,
+  pub description: Option<String>,
+  pub publish_start: Option<NaiveDate>,
+  pub publish_end: Option<NaiveDate>,
+  pub volumes: Option<u16>,
+  pub chapters: Option<u16>,
+  pub status: Status,
+  pub cover: Image,
+  pub language: Language,
+}
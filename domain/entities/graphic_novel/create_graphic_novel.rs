@@ -0,0 +1,108 @@
+use crate::entities::book::create_book::CreateCover;
+use crate::entities::image::create_image::CreateImage;
+use crate::enums::language::Language;
+use crate::enums::status::Status;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovel {
+  pub graphic_novel: CreateGraphicNovelData,
+  pub covers: Vec<CreateImage>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovelData {
+  pub publish_start: Option<NaiveDate>,
+  pub publish_end: Option<NaiveDate>,
+  pub volumes: Option<u16>,
+  pub chapters: Option<u16>,
+  pub status: Status,
+  pub translations: HashMap<Language, CreateGraphicNovelTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovelTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: CreateCover,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_graphic_novel_part {
+  use crate::entities::graphic_novel::create_graphic_novel::{CreateGraphicNovel, CreateGraphicNovelData};
+  use crate::entities::image::create_image::CreateImage;
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::axum::extract::Multipart;
+  use multipart::{serialize_parts, FromMultiPart};
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreateGraphicNovelPartError {
+    GraphicNovelMissing,
+    MoreThanOneGraphicNovel,
+    OtherError(Box<dyn Error + Send>),
+  }
+  impl From<serde_json::Error> for CreateGraphicNovelPartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreateGraphicNovelPartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreateGraphicNovelPartError {
+    fn from(value: MultipartError) -> Self {
+      CreateGraphicNovelPartError::OtherError(Box::new(value))
+    }
+  }
+
+  impl Display for CreateGraphicNovelPartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreateGraphicNovelPartError::GraphicNovelMissing => "Graphic novel missing".to_string(),
+          CreateGraphicNovelPartError::OtherError(value) => value.to_string(),
+          CreateGraphicNovelPartError::MoreThanOneGraphicNovel => "There is more than 1 graphic novel".to_string(),
+        }
+      )
+    }
+  }
+
+  impl Error for CreateGraphicNovelPartError {}
+
+  #[async_trait::async_trait]
+  impl FromMultiPart for CreateGraphicNovel {
+    type Error = CreateGraphicNovelPartError;
+
+    async fn from_multi_part(multipart: Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let graphic_novel_bytes = parts
+        .remove(&Some("graphic_novel".to_string()))
+        .ok_or_else(|| CreateGraphicNovelPartError::GraphicNovelMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateGraphicNovelPartError::GraphicNovelMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateGraphicNovelPartError::MoreThanOneGraphicNovel,
+        })?;
+
+      let graphic_novel: CreateGraphicNovelData = from_slice(&graphic_novel_bytes)?;
+
+      let covers = parts.remove(&Some("covers".to_string())).unwrap_or_else(Vec::new);
+      let covers: Vec<CreateImage> = covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+
+      Ok(CreateGraphicNovel { graphic_novel, covers })
+    }
+  }
+}
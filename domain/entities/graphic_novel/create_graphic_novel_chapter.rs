@@ -0,0 +1,22 @@
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovelChapter {
+  /// Chapters are numbered across the whole graphic novel
+  pub chapter: u16,
+  pub pages: u16,
+  pub published: Option<NaiveDate>,
+  pub translations: HashMap<Language, CreateGraphicNovelChapterTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovelChapterTranslation {
+  pub title: String,
+  pub description: Option<String>,
+}
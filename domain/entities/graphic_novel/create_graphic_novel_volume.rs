@@ -0,0 +1,21 @@
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovelVolume {
+  pub volume: u16,
+  pub pages: Option<u16>,
+  pub published: Option<NaiveDate>,
+  pub translations: HashMap<Language, CreateGraphicNovelVolumeTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateGraphicNovelVolumeTranslation {
+  pub title: String,
+  pub description: Option<String>,
+}
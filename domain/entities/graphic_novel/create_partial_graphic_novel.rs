@@ -0,0 +1,26 @@
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+use crate::enums::status::Status;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialGraphicNovel {
+  pub publish_start: Option<NaiveDate>,
+  pub publish_end: Option<NaiveDate>,
+  pub volumes: Option<u16>,
+  pub chapters: Option<u16>,
+  pub status: Status,
+  pub translations: HashMap<Language, CreatePartialGraphicNovelTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialGraphicNovelTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: Image,
+}
@@ -0,0 +1,18 @@
+use chrono::NaiveDate;
+
+use crate::enums::language::Language;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GraphicNovelChapter {
+  pub graphic_novel_id: u32,
+  pub chapter: u16,
+  pub volume: Option<u16>,
+  pub title: String,
+  pub description: Option<String>,
+  pub pages: u16,
+  pub published: Option<NaiveDate>,
+  pub score: Option<u16>,
+  pub language: Language,
+}
@@ -0,0 +1,8 @@
+use crate::entities::character::Character;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GraphicNovelCharacter {
+  pub character: Character,
+}
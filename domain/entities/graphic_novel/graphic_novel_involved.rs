@@ -0,0 +1,10 @@
+use crate::entities::involved::Involved;
+
+pub type GraphicNovelInvolved = Involved;
+
+/* TODO:
+pub struct GraphicNovelInvolved {
+  pub person: Person,
+  pub roles: Vec<PersonRole>,
+}
+*/
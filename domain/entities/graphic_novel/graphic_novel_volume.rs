@@ -0,0 +1,17 @@
+use chrono::NaiveDate;
+
+use crate::enums::language::Language;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct GraphicNovelVolume {
+  pub graphic_novel_id: u32,
+  pub volume: u16,
+  pub title: String,
+  pub description: Option<String>,
+  pub pages: Option<u16>,
+  pub published: Option<NaiveDate>,
+  pub score: Option<u16>,
+  pub language: Language,
+}
@@ -0,0 +1,8 @@
+use crate::entities::account::Email;
+
+#[derive(Debug, Clone)]
+pub struct Mail {
+  pub to: Email,
+  pub subject: String,
+  pub body: String,
+}
@@ -1,12 +1,19 @@
 pub mod account;
 pub mod book;
 pub mod character;
+pub mod company;
 pub mod franchise;
+pub mod game;
 pub mod genre;
+pub mod graphic_novel;
 pub mod image;
 pub mod involved;
+pub mod mail;
+pub mod movie;
 pub mod person;
+pub mod platform;
 pub mod rating;
 pub mod role;
+pub mod show;
 pub mod theme;
 pub mod user;
@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+
+pub mod create_movie;
+pub mod create_partial_movie;
+pub mod movie_involved;
+pub mod movie_statistic;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Movie {
+  pub id: u32,
+  pub title: String,
+  pub description: Option<String>,
+  pub airing: Option<NaiveDate>,
+  /// Length of the movie in minutes
+  pub length: Option<u32>,
+  pub cover: Image,
+  pub language: Language,
+}
@@ -0,0 +1,108 @@
+use crate::entities::book::create_book::CreateCover;
+use crate::entities::image::create_image::CreateImage;
+use crate::entities::involved::InvolvedId;
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateMovie {
+  pub movie: CreateMovieData,
+  pub covers: Vec<CreateImage>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateMovieData {
+  pub airing: Option<NaiveDate>,
+  pub length: Option<u32>,
+  pub translations: HashMap<Language, CreateMovieTranslation>,
+  pub genres: Option<Vec<u32>>,
+  pub themes: Option<Vec<u32>>,
+  pub involved: Option<Vec<InvolvedId>>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateMovieTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: CreateCover,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_movie_part {
+  use crate::entities::image::create_image::CreateImage;
+  use crate::entities::movie::create_movie::{CreateMovie, CreateMovieData};
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::axum::extract::Multipart;
+  use multipart::{serialize_parts, FromMultiPart};
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreateMoviePartError {
+    MovieMissing,
+    MoreThanOneMovie,
+    OtherError(Box<dyn Error + Send>),
+  }
+  impl From<serde_json::Error> for CreateMoviePartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreateMoviePartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreateMoviePartError {
+    fn from(value: MultipartError) -> Self {
+      CreateMoviePartError::OtherError(Box::new(value))
+    }
+  }
+
+  impl Display for CreateMoviePartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreateMoviePartError::MovieMissing => "Movie missing".to_string(),
+          CreateMoviePartError::OtherError(value) => value.to_string(),
+          CreateMoviePartError::MoreThanOneMovie => "There is more than 1 movie".to_string(),
+        }
+      )
+    }
+  }
+
+  impl Error for CreateMoviePartError {}
+
+  #[async_trait::async_trait]
+  impl FromMultiPart for CreateMovie {
+    type Error = CreateMoviePartError;
+
+    async fn from_multi_part(multipart: Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let movie_bytes = parts
+        .remove(&Some("movie".to_string()))
+        .ok_or_else(|| CreateMoviePartError::MovieMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateMoviePartError::MovieMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateMoviePartError::MoreThanOneMovie,
+        })?;
+
+      let movie: CreateMovieData = from_slice(&movie_bytes)?;
+
+      let covers = parts.remove(&Some("covers".to_string())).unwrap_or_else(Vec::new);
+      let covers: Vec<CreateImage> = covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+
+      Ok(CreateMovie { movie, covers })
+    }
+  }
+}
@@ -0,0 +1,26 @@
+use crate::entities::image::Image;
+use crate::entities::involved::InvolvedId;
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialMovie {
+  pub airing: Option<NaiveDate>,
+  pub length: Option<u32>,
+  pub translations: HashMap<Language, CreatePartialMovieTranslation>,
+  pub genres: Vec<u32>,
+  pub themes: Vec<u32>,
+  pub involved: Vec<InvolvedId>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialMovieTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: Image,
+}
@@ -0,0 +1,3 @@
+use crate::entities::involved::Involved;
+
+pub type MovieInvolved = Involved;
@@ -0,0 +1,17 @@
+pub mod create_partial_platform;
+pub mod create_platform;
+pub mod patch_platform;
+
+use crate::entities::company::Company;
+use crate::entities::image::Image;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Platform {
+  pub id: u32,
+  pub name: String,
+  pub short_name: Option<String>,
+  pub company: Company,
+  pub logo: Image,
+}
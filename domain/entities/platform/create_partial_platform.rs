@@ -0,0 +1,11 @@
+use crate::entities::image::Image;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialPlatform {
+  pub name: String,
+  pub short_name: Option<String>,
+  pub company: u32,
+  pub logo: Image,
+}
@@ -0,0 +1,102 @@
+use crate::entities::image::create_image::CreateImage;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePlatform {
+  pub platform: CreatePlatformData,
+  pub logo: CreateImage,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePlatformData {
+  pub name: String,
+  pub short_name: Option<String>,
+  pub company: u32,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_platform_part {
+  use crate::entities::image::create_image::CreateImage;
+  use crate::entities::platform::create_platform::{CreatePlatform, CreatePlatformData};
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::serialize_parts;
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreatePlatformPartError {
+    PlatformMissing,
+    MoreThanOnePlatform,
+    LogoMissing,
+    MoreThanOneLogo,
+    OtherError(Box<dyn Error + Send>),
+  }
+
+  impl Display for CreatePlatformPartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreatePlatformPartError::PlatformMissing => "Platform missing".to_string(),
+          CreatePlatformPartError::MoreThanOnePlatform => "There is more than 1 platform".to_string(),
+          CreatePlatformPartError::LogoMissing => "Logo missing".to_string(),
+          CreatePlatformPartError::MoreThanOneLogo => "There is more than 1 logo".to_string(),
+          CreatePlatformPartError::OtherError(value) => value.to_string(),
+        }
+      )
+    }
+  }
+  impl Error for CreatePlatformPartError {}
+  impl From<serde_json::Error> for CreatePlatformPartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreatePlatformPartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreatePlatformPartError {
+    fn from(value: MultipartError) -> Self {
+      CreatePlatformPartError::OtherError(Box::new(value))
+    }
+  }
+
+  #[async_trait::async_trait]
+  impl multipart::FromMultiPart for CreatePlatform {
+    type Error = CreatePlatformPartError;
+
+    async fn from_multi_part(multipart: multipart::axum::extract::Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let platform_bytes = parts
+        .remove(&Some("platform".to_string()))
+        .ok_or_else(|| CreatePlatformPartError::PlatformMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreatePlatformPartError::PlatformMissing,
+          SingleVecError::MoreThanOneItem(_) => CreatePlatformPartError::MoreThanOnePlatform,
+        })?;
+
+      let platform: CreatePlatformData = from_slice(&platform_bytes)?;
+
+      let logo = parts
+        .remove(&Some("logo".to_string()))
+        .ok_or_else(|| CreatePlatformPartError::LogoMissing)?
+        .into_iter()
+        .map(|x| CreateImage(x.to_vec()))
+        .collect::<Vec<CreateImage>>()
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreatePlatformPartError::LogoMissing,
+          SingleVecError::MoreThanOneItem(_) => CreatePlatformPartError::MoreThanOneLogo,
+        })?;
+
+      Ok(CreatePlatform { platform, logo })
+    }
+  }
+}
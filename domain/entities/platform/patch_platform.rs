@@ -0,0 +1,10 @@
+/// Fields which are not set keep their current value
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PatchPlatform {
+  pub name: Option<String>,
+  /// An empty short name removes the current one
+  pub short_name: Option<String>,
+  pub company: Option<u32>,
+}
@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+
+use crate::entities::franchise::Franchise;
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+use crate::enums::status::Status;
+
+pub mod create_partial_show;
+pub mod create_show;
+pub mod create_show_episode;
+pub mod create_show_season;
+pub mod show_episode;
+pub mod show_season;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Show {
+  pub id: u32,
+  pub title: String,
+  pub description: Option<String>,
+  pub airing_start: Option<NaiveDate>,
+  pub airing_end: Option<NaiveDate>,
+  pub score: Option<f32>,
+  pub seasons: Option<u16>,
+  pub status: Status,
+  pub cover: Image,
+  pub franchise: Option<Franchise>,
+  pub language: Language,
+}
@@ -0,0 +1,25 @@
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+use crate::enums::status::Status;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialShow {
+  pub airing_start: Option<NaiveDate>,
+  pub airing_end: Option<NaiveDate>,
+  pub status: Status,
+  pub franchise: Option<u32>,
+  pub translations: HashMap<Language, CreatePartialShowTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreatePartialShowTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: Image,
+}
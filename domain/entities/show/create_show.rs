@@ -0,0 +1,107 @@
+use crate::entities::book::create_book::CreateCover;
+use crate::entities::image::create_image::CreateImage;
+use crate::enums::language::Language;
+use crate::enums::status::Status;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShow {
+  pub show: CreateShowData,
+  pub covers: Vec<CreateImage>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShowData {
+  pub airing_start: Option<NaiveDate>,
+  pub airing_end: Option<NaiveDate>,
+  pub status: Status,
+  pub franchise: Option<u32>,
+  pub translations: HashMap<Language, CreateShowTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShowTranslation {
+  pub title: String,
+  pub description: Option<String>,
+  pub cover: CreateCover,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod create_show_part {
+  use crate::entities::image::create_image::CreateImage;
+  use crate::entities::show::create_show::{CreateShow, CreateShowData};
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::axum::extract::Multipart;
+  use multipart::{serialize_parts, FromMultiPart};
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum CreateShowPartError {
+    ShowMissing,
+    MoreThanOneShow,
+    OtherError(Box<dyn Error + Send>),
+  }
+  impl From<serde_json::Error> for CreateShowPartError {
+    fn from(value: serde_json::Error) -> Self {
+      CreateShowPartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for CreateShowPartError {
+    fn from(value: MultipartError) -> Self {
+      CreateShowPartError::OtherError(Box::new(value))
+    }
+  }
+
+  impl Display for CreateShowPartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          CreateShowPartError::ShowMissing => "Show missing".to_string(),
+          CreateShowPartError::OtherError(value) => value.to_string(),
+          CreateShowPartError::MoreThanOneShow => "There is more than 1 show".to_string(),
+        }
+      )
+    }
+  }
+
+  impl Error for CreateShowPartError {}
+
+  #[async_trait::async_trait]
+  impl FromMultiPart for CreateShow {
+    type Error = CreateShowPartError;
+
+    async fn from_multi_part(multipart: Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let show_bytes = parts
+        .remove(&Some("show".to_string()))
+        .ok_or_else(|| CreateShowPartError::ShowMissing)?
+        .single()
+        .map_err(|x| match x {
+          SingleVecError::NoItems => CreateShowPartError::ShowMissing,
+          SingleVecError::MoreThanOneItem(_) => CreateShowPartError::MoreThanOneShow,
+        })?;
+
+      let show: CreateShowData = from_slice(&show_bytes)?;
+
+      let covers = parts.remove(&Some("covers".to_string())).unwrap_or_else(Vec::new);
+      let covers: Vec<CreateImage> = covers.into_iter().map(|x| CreateImage(x.to_vec())).collect();
+
+      Ok(CreateShow { show, covers })
+    }
+  }
+}
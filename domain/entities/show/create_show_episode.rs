@@ -0,0 +1,23 @@
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShowEpisode {
+  /// Episodes are numbered across the whole show
+  pub episode: u16,
+  /// Length of the episode in minutes
+  pub length: Option<u16>,
+  pub airing: Option<NaiveDate>,
+  pub translations: HashMap<Language, CreateShowEpisodeTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShowEpisodeTranslation {
+  pub title: String,
+  pub description: Option<String>,
+}
@@ -0,0 +1,22 @@
+use crate::enums::language::Language;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShowSeason {
+  pub season: u16,
+  pub episodes: Option<u16>,
+  pub airing_start: Option<NaiveDate>,
+  pub airing_end: Option<NaiveDate>,
+  pub translations: HashMap<Language, CreateShowSeasonTranslation>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateShowSeasonTranslation {
+  pub title: String,
+  pub description: Option<String>,
+}
@@ -0,0 +1,21 @@
+use chrono::NaiveDate;
+
+use crate::entities::image::Image;
+use crate::enums::language::Language;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ShowEpisode {
+  pub show_id: u32,
+  pub episode: u16,
+  pub season: Option<u16>,
+  pub title: String,
+  pub description: Option<String>,
+  /// Length of the episode in minutes
+  pub length: Option<u16>,
+  pub airing: Option<NaiveDate>,
+  pub score: Option<u16>,
+  pub cover: Option<Image>,
+  pub language: Language,
+}
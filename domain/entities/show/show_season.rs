@@ -0,0 +1,18 @@
+use chrono::NaiveDate;
+
+use crate::enums::language::Language;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ShowSeason {
+  pub show_id: u32,
+  pub season: u16,
+  pub title: String,
+  pub description: Option<String>,
+  pub episodes: Option<u16>,
+  pub airing_start: Option<NaiveDate>,
+  pub airing_end: Option<NaiveDate>,
+  pub score: Option<u16>,
+  pub language: Language,
+}
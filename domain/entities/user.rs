@@ -1,7 +1,14 @@
 pub mod create_partial_user;
 pub mod create_user;
+pub mod create_user_book;
+pub mod friendship;
+pub mod patch_user;
+pub mod patch_user_book;
+pub mod user_average;
+pub mod user_book;
 
 use crate::entities::image::Image;
+use crate::entities::user::user_average::UserAverage;
 use chrono::NaiveDate;
 
 #[derive(Debug, Clone)]
@@ -14,4 +21,5 @@ pub struct User {
   pub description: Option<String>,
   pub deleted: bool,
   pub profile_picture: Option<Image>,
+  pub averages: UserAverage,
 }
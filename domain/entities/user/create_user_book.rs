@@ -0,0 +1,17 @@
+use chrono::NaiveDate;
+
+use crate::enums::user_status::UserStatus;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateUserBook {
+  pub status: UserStatus,
+  pub favorite: bool,
+  pub score: Option<u8>,
+  pub review: Option<String>,
+  pub start: Option<NaiveDate>,
+  pub finished: Option<NaiveDate>,
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+}
@@ -0,0 +1,16 @@
+use chrono::NaiveDate;
+
+use crate::entities::user::User;
+use crate::enums::friendship_status::FriendshipStatus;
+
+/// A friendship from the perspective of one user, `user` is the other side of it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Friendship {
+  pub user: User,
+  pub status: FriendshipStatus,
+  /// Whether the request was sent by the user whose friendships were requested
+  pub outgoing: bool,
+  pub added: NaiveDate,
+}
@@ -0,0 +1,103 @@
+use crate::entities::image::create_image::CreateImage;
+
+/// Fields which are not set keep their current value, a profile picture replaces the current one.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PatchUser {
+  pub user: PatchUserData,
+  pub profile_picture: Option<CreateImage>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PatchUserData {
+  pub name: Option<String>,
+  /// An empty description removes the current one
+  pub description: Option<String>,
+}
+
+#[cfg(feature = "axum-multipart")]
+pub mod patch_user_part {
+  use crate::entities::image::create_image::CreateImage;
+  use crate::entities::user::patch_user::{PatchUser, PatchUserData};
+  use crate::vec_single::{Single, SingleVecError};
+  use multipart::axum::extract::multipart::MultipartError;
+  use multipart::serialize_parts;
+  use serde_json::from_slice;
+  use std::error::Error;
+  use std::fmt::{Display, Formatter};
+
+  #[derive(Debug)]
+  pub enum PatchUserPartError {
+    MoreThanOneUser,
+    MoreThanOneProfilePicture,
+    OtherError(Box<dyn Error + Send>),
+  }
+
+  impl Display for PatchUserPartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(
+        f,
+        "{}",
+        match self {
+          PatchUserPartError::MoreThanOneUser => "There is more than 1 user".to_string(),
+          PatchUserPartError::MoreThanOneProfilePicture => "There is more than 1 profile picture".to_string(),
+          PatchUserPartError::OtherError(value) => value.to_string(),
+        }
+      )
+    }
+  }
+
+  impl Error for PatchUserPartError {}
+
+  impl From<serde_json::Error> for PatchUserPartError {
+    fn from(value: serde_json::Error) -> Self {
+      PatchUserPartError::OtherError(Box::new(value))
+    }
+  }
+  impl From<MultipartError> for PatchUserPartError {
+    fn from(value: MultipartError) -> Self {
+      PatchUserPartError::OtherError(Box::new(value))
+    }
+  }
+  #[async_trait::async_trait]
+  impl multipart::FromMultiPart for PatchUser {
+    type Error = PatchUserPartError;
+
+    async fn from_multi_part(multipart: multipart::axum::extract::Multipart) -> Result<Self, Self::Error>
+    where
+      Self: Sized,
+    {
+      let mut parts = serialize_parts(multipart).await?;
+      let user = parts
+        .remove(&Some("user".to_string()))
+        .unwrap_or_else(Vec::new)
+        .single();
+      let user: PatchUserData = match user {
+        Ok(bytes) => from_slice(&bytes)?,
+        Err(SingleVecError::NoItems) => PatchUserData::default(),
+        Err(SingleVecError::MoreThanOneItem(_)) => Err(PatchUserPartError::MoreThanOneUser)?,
+      };
+
+      let profile_picture = parts
+        .remove(&Some("profile_picture".to_string()))
+        .unwrap_or_else(Vec::new);
+      let profile_picture = profile_picture
+        .into_iter()
+        .map(|x| CreateImage(x.to_vec()))
+        .collect::<Vec<CreateImage>>()
+        .single();
+      let profile_picture = match profile_picture {
+        Ok(pic) => Ok(Some(pic)),
+        Err(err) => match err {
+          SingleVecError::NoItems => Ok(None),
+          SingleVecError::MoreThanOneItem(_) => Err(PatchUserPartError::MoreThanOneProfilePicture),
+        },
+      }?;
+
+      Ok(PatchUser { user, profile_picture })
+    }
+  }
+}
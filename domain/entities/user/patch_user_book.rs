@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+
+use crate::entities::user::create_user_book::CreateUserBook;
+use crate::entities::user::user_book::UserBook;
+use crate::enums::user_status::UserStatus;
+
+/// Fields which are not set keep their current value.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PatchUserBook {
+  pub status: Option<UserStatus>,
+  pub favorite: Option<bool>,
+  pub score: Option<u8>,
+  pub review: Option<String>,
+  pub start: Option<NaiveDate>,
+  pub finished: Option<NaiveDate>,
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+}
+
+impl PatchUserBook {
+  pub fn apply(self, current: UserBook) -> CreateUserBook {
+    CreateUserBook {
+      status: self.status.unwrap_or(current.status),
+      favorite: self.favorite.unwrap_or(current.favorite),
+      score: self.score.or(current.score),
+      review: self.review.or(current.review),
+      start: self.start.or(current.start),
+      finished: self.finished.or(current.finished),
+      chapters: self.chapters.or(current.chapters),
+      pages: self.pages.or(current.pages),
+    }
+  }
+}
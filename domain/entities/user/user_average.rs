@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct UserAverage {
+  pub graphic_novel: Option<f32>,
+  pub show: Option<f32>,
+  pub movie: Option<f32>,
+  pub book: Option<f32>,
+  pub game: Option<f32>,
+}
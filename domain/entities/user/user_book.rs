@@ -0,0 +1,21 @@
+use chrono::NaiveDate;
+
+use crate::entities::book::Book;
+use crate::enums::user_status::UserStatus;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct UserBook {
+  pub user_id: u32,
+  pub book: Book,
+  pub status: UserStatus,
+  pub favorite: bool,
+  pub score: Option<u8>,
+  pub review: Option<String>,
+  pub start: Option<NaiveDate>,
+  pub finished: Option<NaiveDate>,
+  pub chapters: Option<u16>,
+  pub pages: Option<u16>,
+  pub added: NaiveDate,
+}
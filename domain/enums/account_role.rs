@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::enums::account_role::AccountRole::{Admin, Editor, Member};
+
+/// Roles are ordered by their permissions, every role has all permissions of the roles below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum AccountRole {
+  #[default]
+  Member,
+  Editor,
+  Admin,
+}
+
+impl Display for AccountRole {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Member => "Member",
+        Editor => "Editor",
+        Admin => "Admin",
+      }
+    )
+  }
+}
+
+#[derive(Debug)]
+pub enum AccountRoleError {
+  UnknownRole(String),
+}
+
+impl Display for AccountRoleError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        AccountRoleError::UnknownRole(value) => format!("Unknown account role, {value}"),
+      }
+    )
+  }
+}
+
+impl Error for AccountRoleError {}
+impl FromStr for AccountRole {
+  type Err = AccountRoleError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let result = match value {
+      "Member" => Member,
+      "Editor" => Editor,
+      "Admin" => Admin,
+      _ => Err(AccountRoleError::UnknownRole(value.to_string()))?,
+    };
+    Ok(result)
+  }
+}
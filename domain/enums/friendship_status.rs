@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::enums::friendship_status::FriendshipStatus::{Accepted, Declined, Pending};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum FriendshipStatus {
+  Pending,
+  Accepted,
+  Declined,
+}
+
+impl Display for FriendshipStatus {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Pending => "Pending",
+        Accepted => "Accepted",
+        Declined => "Declined",
+      }
+    )
+  }
+}
+
+#[derive(Debug)]
+pub enum FriendshipStatusError {
+  UnknownStatus(String),
+}
+
+impl Display for FriendshipStatusError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        FriendshipStatusError::UnknownStatus(value) => format!("Unknown friendship status, {value}"),
+      }
+    )
+  }
+}
+
+impl Error for FriendshipStatusError {}
+impl FromStr for FriendshipStatus {
+  type Err = FriendshipStatusError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let result = match value {
+      "Pending" => Pending,
+      "Accepted" => Accepted,
+      "Declined" => Declined,
+      _ => Err(FriendshipStatusError::UnknownStatus(value.to_string()))?,
+    };
+    Ok(result)
+  }
+}
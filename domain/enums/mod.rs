@@ -1 +1,6 @@
+pub mod account_role;
+pub mod friendship_status;
 pub mod language;
+pub mod status;
+pub mod token_scope;
+pub mod user_status;
@@ -0,0 +1,63 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::enums::status::Status::{Finished, NotStarted, Ongoing, Paused};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum Status {
+  NotStarted,
+  Ongoing,
+  Finished,
+  Paused,
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        NotStarted => "NotStarted",
+        Ongoing => "Ongoing",
+        Finished => "Finished",
+        Paused => "Paused",
+      }
+    )
+  }
+}
+
+#[derive(Debug)]
+pub enum StatusError {
+  UnknownStatus(String),
+}
+
+impl Display for StatusError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        StatusError::UnknownStatus(value) => format!("Unknown status, {value}"),
+      }
+    )
+  }
+}
+
+impl Error for StatusError {}
+impl FromStr for Status {
+  type Err = StatusError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let result = match value {
+      "NotStarted" => NotStarted,
+      "Ongoing" => Ongoing,
+      "Finished" => Finished,
+      "Paused" => Paused,
+      _ => Err(StatusError::UnknownStatus(value.to_string()))?,
+    };
+    Ok(result)
+  }
+}
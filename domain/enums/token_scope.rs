@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::enums::token_scope::TokenScope::{
+  AccountRead, Admin, CatalogueWrite, FriendsRead, FriendsWrite, LibraryWrite,
+};
+
+/// What a personal access token may be used for, sessions are not limited by scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum TokenScope {
+  /// Creating and modifying catalogue entries, requires the editor role
+  #[cfg_attr(feature = "serde", serde(rename = "catalogue:write"))]
+  CatalogueWrite,
+  /// Everything that requires the admin role
+  #[cfg_attr(feature = "serde", serde(rename = "admin"))]
+  Admin,
+  #[cfg_attr(feature = "serde", serde(rename = "library:write"))]
+  LibraryWrite,
+  #[cfg_attr(feature = "serde", serde(rename = "friends:read"))]
+  FriendsRead,
+  #[cfg_attr(feature = "serde", serde(rename = "friends:write"))]
+  FriendsWrite,
+  /// Exporting the data of the account
+  #[cfg_attr(feature = "serde", serde(rename = "account:read"))]
+  AccountRead,
+}
+
+impl Display for TokenScope {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        CatalogueWrite => "catalogue:write",
+        Admin => "admin",
+        LibraryWrite => "library:write",
+        FriendsRead => "friends:read",
+        FriendsWrite => "friends:write",
+        AccountRead => "account:read",
+      }
+    )
+  }
+}
+
+#[derive(Debug)]
+pub enum TokenScopeError {
+  UnknownScope(String),
+}
+
+impl Display for TokenScopeError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        TokenScopeError::UnknownScope(value) => format!("Unknown token scope, {value}"),
+      }
+    )
+  }
+}
+
+impl Error for TokenScopeError {}
+impl FromStr for TokenScope {
+  type Err = TokenScopeError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let result = match value {
+      "catalogue:write" => CatalogueWrite,
+      "admin" => Admin,
+      "library:write" => LibraryWrite,
+      "friends:read" => FriendsRead,
+      "friends:write" => FriendsWrite,
+      "account:read" => AccountRead,
+      _ => Err(TokenScopeError::UnknownScope(value.to_string()))?,
+    };
+    Ok(result)
+  }
+}
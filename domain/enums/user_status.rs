@@ -0,0 +1,63 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::enums::user_status::UserStatus::{Finished, NotStarted, Ongoing, Paused};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum UserStatus {
+  NotStarted,
+  Ongoing,
+  Finished,
+  Paused,
+}
+
+impl Display for UserStatus {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        NotStarted => "NotStarted",
+        Ongoing => "Ongoing",
+        Finished => "Finished",
+        Paused => "Paused",
+      }
+    )
+  }
+}
+
+#[derive(Debug)]
+pub enum UserStatusError {
+  UnknownStatus(String),
+}
+
+impl Display for UserStatusError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        UserStatusError::UnknownStatus(value) => format!("Unknown user status, {value}"),
+      }
+    )
+  }
+}
+
+impl Error for UserStatusError {}
+impl FromStr for UserStatus {
+  type Err = UserStatusError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let result = match value {
+      "NotStarted" => NotStarted,
+      "Ongoing" => Ongoing,
+      "Finished" => Finished,
+      "Paused" => Paused,
+      _ => Err(UserStatusError::UnknownStatus(value.to_string()))?,
+    };
+    Ok(result)
+  }
+}
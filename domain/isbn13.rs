@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Isbn13(String);
+
+#[cfg(feature = "serde")]
+pub mod serde {
+  use crate::isbn13::Isbn13;
+  use serde::de::Error;
+  use serde::{Deserializer, Serializer};
+
+  impl serde::Serialize for Isbn13 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: Serializer,
+    {
+      serializer.serialize_str(&self.0)
+    }
+  }
+  impl<'de> serde::Deserialize<'de> for Isbn13 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+      D: Deserializer<'de>,
+    {
+      let value = String::deserialize(deserializer)?;
+      let isbn = Isbn13::parse(value).map_err(D::Error::custom)?;
+      Ok(isbn)
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum Isbn13Error {
+  InvalidFormat(String),
+  InvalidChecksum(String),
+}
+impl Display for Isbn13Error {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Isbn13Error::InvalidFormat(value) => format!("value '{value}' is not made up of 13 digits"),
+        Isbn13Error::InvalidChecksum(value) => format!("value '{value}' has an invalid ISBN-13 check digit"),
+      }
+    )
+  }
+}
+impl Error for Isbn13Error {}
+
+impl Isbn13 {
+  /// Parses an ISBN-13, ignoring hyphens and spaces between the digit groups.
+  pub fn parse(value: String) -> Result<Isbn13, Isbn13Error> {
+    let digits: String = value.chars().filter(|x| *x != '-' && *x != ' ').collect();
+    if digits.len() != 13 || !digits.chars().all(|x| x.is_ascii_digit()) {
+      return Err(Isbn13Error::InvalidFormat(value));
+    }
+    let sum: u32 = digits
+      .chars()
+      .filter_map(|x| x.to_digit(10))
+      .enumerate()
+      .map(|(index, digit)| if index % 2 == 0 { digit } else { digit * 3 })
+      .sum();
+    if sum % 10 == 0 {
+      Ok(Isbn13(digits))
+    } else {
+      Err(Isbn13Error::InvalidChecksum(value))
+    }
+  }
+}
+impl Display for Isbn13 {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    String::fmt(&self.0, f)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Isbn13, Isbn13Error};
+
+  #[test]
+  fn parses_valid_isbn() {
+    let isbn = Isbn13::parse("9780306406157".to_string()).unwrap();
+    assert_eq!(isbn.to_string(), "9780306406157");
+  }
+
+  #[test]
+  fn ignores_hyphens_and_spaces() {
+    let isbn = Isbn13::parse("978-0-306 40615-7".to_string()).unwrap();
+    assert_eq!(isbn.to_string(), "9780306406157");
+  }
+
+  #[test]
+  fn rejects_bad_check_digit() {
+    let result = Isbn13::parse("9780306406158".to_string());
+    assert!(matches!(result, Err(Isbn13Error::InvalidChecksum(_))));
+  }
+
+  #[test]
+  fn rejects_wrong_length() {
+    assert!(matches!(
+      Isbn13::parse("978030640615".to_string()),
+      Err(Isbn13Error::InvalidFormat(_))
+    ));
+    assert!(matches!(
+      Isbn13::parse("97803064061570".to_string()),
+      Err(Isbn13Error::InvalidFormat(_))
+    ));
+  }
+
+  #[test]
+  fn rejects_non_digits() {
+    let result = Isbn13::parse("978030640615X".to_string());
+    assert!(matches!(result, Err(Isbn13Error::InvalidFormat(_))));
+  }
+}
@@ -1,12 +1,28 @@
 use crate::entities::book::book_character::BookCharacter;
+use crate::entities::book::book_edition::BookEdition;
+use crate::entities::book::book_image::BookImage;
 use crate::entities::book::Book;
 use crate::entities::character::Character;
+use crate::entities::company::Company;
 use crate::entities::franchise::Franchise;
+use crate::entities::game::game_character::GameCharacter;
+use crate::entities::game::Game;
 use crate::entities::genre::Genre;
+use crate::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use crate::entities::graphic_novel::graphic_novel_character::GraphicNovelCharacter;
+use crate::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use crate::entities::graphic_novel::GraphicNovel;
 use crate::entities::involved::Involved;
+use crate::entities::movie::Movie;
 use crate::entities::person::Person;
+use crate::entities::platform::Platform;
 use crate::entities::role::Role;
+use crate::entities::show::show_episode::ShowEpisode;
+use crate::entities::show::show_season::ShowSeason;
+use crate::entities::show::Show;
 use crate::entities::theme::Theme;
+use crate::entities::user::friendship::Friendship;
+use crate::entities::user::user_book::UserBook;
 use crate::entities::user::User;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20,8 +36,28 @@ use crate::entities::user::User;
   RolesTotal = ItemsTotal < Role >,
   BookInvolvedTotal = ItemsTotal < Involved >,
   BookCharactersTotal = ItemsTotal < BookCharacter >,
+  BookEditionsTotal = ItemsTotal < BookEdition >,
+  BookEditionInvolvedTotal = ItemsTotal < Involved >,
+  BookImagesTotal = ItemsTotal < BookImage >,
   FranchisesTotal = ItemsTotal < Franchise >,
-  UsersTotal = ItemsTotal < User >
+  UsersTotal = ItemsTotal < User >,
+  UserBooksTotal = ItemsTotal < UserBook >,
+  FriendshipsTotal = ItemsTotal < Friendship >,
+  MoviesTotal = ItemsTotal < Movie >,
+  MovieInvolvedTotal = ItemsTotal < Involved >,
+  ShowsTotal = ItemsTotal < Show >,
+  ShowSeasonsTotal = ItemsTotal < ShowSeason >,
+  ShowEpisodesTotal = ItemsTotal < ShowEpisode >,
+  GamesTotal = ItemsTotal < Game >,
+  GameInvolvedTotal = ItemsTotal < Involved >,
+  GameCharactersTotal = ItemsTotal < GameCharacter >,
+  PlatformsTotal = ItemsTotal < Platform >,
+  GraphicNovelsTotal = ItemsTotal < GraphicNovel >,
+  GraphicNovelVolumesTotal = ItemsTotal < GraphicNovelVolume >,
+  GraphicNovelChaptersTotal = ItemsTotal < GraphicNovelChapter >,
+  GraphicNovelInvolvedTotal = ItemsTotal < Involved >,
+  GraphicNovelCharactersTotal = ItemsTotal < GraphicNovelCharacter >,
+  CompaniesTotal = ItemsTotal < Company >
 ))]
 pub struct ItemsTotal<T> {
   pub items: Vec<T>,
@@ -2,6 +2,7 @@ pub mod entities;
 pub mod enums;
 pub mod file_name;
 pub mod io_transaction;
+pub mod isbn13;
 pub mod items_total;
 pub mod pagination;
 pub mod slug;
@@ -0,0 +1,38 @@
+use chrono::{NaiveDateTime, Utc};
+
+/// Source of the current time, so time dependent services can be tested without waiting.
+pub trait Clock: Send + Sync {
+  fn now(&self) -> NaiveDateTime;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> NaiveDateTime {
+    Utc::now().naive_utc()
+  }
+}
+
+/// Stands still until it is advanced.
+#[cfg(test)]
+pub(crate) struct FakeClock(std::sync::Mutex<NaiveDateTime>);
+
+#[cfg(test)]
+impl FakeClock {
+  /// Starts at the unix timestamp in seconds
+  pub(crate) fn at(timestamp: i64) -> FakeClock {
+    let now = chrono::DateTime::from_timestamp(timestamp, 0).expect("Timestamp should be in range");
+    FakeClock(std::sync::Mutex::new(now.naive_utc()))
+  }
+
+  pub(crate) fn advance(&self, duration: chrono::Duration) {
+    *self.0.lock().unwrap() += duration;
+  }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+  fn now(&self) -> NaiveDateTime {
+    *self.0.lock().unwrap()
+  }
+}
@@ -0,0 +1,85 @@
+use std::error::Error;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::account::account_export::AccountExport;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::account_repository::AccountRepository;
+use repositories::user_repository::friendship_repository::FriendshipRepository;
+use repositories::user_repository::user_book_repository::UserBookRepository;
+use services::account_service::account_export_service::{AccountExportService, AccountExportServiceError};
+use services::traits::service_error::ServiceError;
+
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+pub struct DefaultAccountExportService<'a> {
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  user_book_repository: Arc<dyn UserBookRepository + 'a>,
+  friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+}
+
+impl<'a> DefaultAccountExportService<'a> {
+  pub fn new(
+    account_repository: Arc<dyn AccountRepository + 'a>,
+    user_book_repository: Arc<dyn UserBookRepository + 'a>,
+    friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+  ) -> DefaultAccountExportService<'a> {
+    DefaultAccountExportService {
+      account_repository,
+      user_book_repository,
+      friendship_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl AccountExportService for DefaultAccountExportService<'_> {
+  async fn export(
+    &self,
+    user_id: u32,
+    language: Language,
+  ) -> Result<Option<AccountExport>, ServiceError<AccountExportServiceError>> {
+    let Some(account) = self.account_repository.get_by_user_id(user_id).await? else {
+      return Ok(None);
+    };
+    let books = all_pages(|x| self.user_book_repository.get(user_id, None, language, x)).await?;
+    let friends = all_pages(|x| self.friendship_repository.get_friends(user_id, x)).await?;
+    let incoming_requests = all_pages(|x| self.friendship_repository.get_incoming(user_id, x)).await?;
+    let outgoing_requests = all_pages(|x| self.friendship_repository.get_outgoing(user_id, x)).await?;
+    Ok(Some(AccountExport {
+      email: account.email,
+      role: account.role,
+      verified: account.verified,
+      user: account.user,
+      books,
+      friends,
+      incoming_requests,
+      outgoing_requests,
+    }))
+  }
+}
+
+async fn all_pages<T, F, R>(get_page: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+  F: Fn(Pagination) -> R,
+  R: Future<Output = Result<ItemsTotal<T>, Box<dyn Error>>>,
+{
+  let mut items = Vec::new();
+  for page in 0.. {
+    let result = get_page(Pagination {
+      page,
+      count: EXPORT_PAGE_SIZE,
+    })
+    .await?;
+    let empty = result.items.is_empty();
+    items.extend(result.items);
+    if empty || items.len() >= result.total {
+      break;
+    }
+  }
+  Ok(items)
+}
@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use std::net::IpAddr;
+use std::sync::{Arc, OnceLock};
 
 use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use async_trait::async_trait;
@@ -7,18 +8,28 @@ use domain::entities::account::{Account, Email, Password};
 use domain::items_total::ItemsTotal;
 use domain::pagination::Pagination;
 use repositories::account_repository::AccountRepository;
+use services::account_service::login_throttle_service::{LoginKey, LoginThrottleService, LoginThrottleServiceError};
 use services::account_service::{AccountService, AccountServiceError};
 use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
 
+use crate::services::account_service::hash_password;
 use crate::services::map_server_error;
 
 pub struct DefaultAccountService<'a> {
   account_repository: Arc<dyn AccountRepository + 'a>,
+  login_throttle_service: Arc<dyn LoginThrottleService + 'a>,
 }
 
 impl<'a> DefaultAccountService<'a> {
-  pub fn new(account_repository: Arc<dyn AccountRepository + 'a>) -> DefaultAccountService<'a> {
-    DefaultAccountService { account_repository }
+  pub fn new(
+    account_repository: Arc<dyn AccountRepository + 'a>,
+    login_throttle_service: Arc<dyn LoginThrottleService + 'a>,
+  ) -> DefaultAccountService<'a> {
+    DefaultAccountService {
+      account_repository,
+      login_throttle_service,
+    }
   }
 }
 
@@ -36,25 +47,67 @@ impl AccountService for DefaultAccountService<'_> {
     Ok(self.account_repository.get_by_email(email).await?)
   }
 
-  async fn login(&self, email: &Email, password: &Password) -> Result<Account, ServiceError<AccountServiceError>> {
-    let account = self.get_by_email(email).await?.ok_or_else(unknown_email)?;
+  async fn login(
+    &self,
+    email: &Email,
+    password: &Password,
+    ip: Option<IpAddr>,
+  ) -> Result<Account, ServiceError<AccountServiceError>> {
+    let email_key = LoginKey::Email(email.0.to_lowercase());
+    let keys: Vec<LoginKey> = [Some(email_key.clone()), ip.map(LoginKey::Ip)]
+      .into_iter()
+      .flatten()
+      .collect();
+    self
+      .login_throttle_service
+      .check(&keys)
+      .await
+      .map_err(map_throttle_error)?;
 
-    let hash = password_hash(&account.password.0)?;
-    if Argon2::default().verify_password(password.0.as_bytes(), &hash).is_ok() {
-      return Ok(account);
+    let account = self.get_by_email(email).await?;
+    // Unknown emails are checked against a dummy hash, so they take as long as a wrong password
+    let hash = match &account {
+      Some(account) => password_hash(&account.password.0)?,
+      None => password_hash(dummy_hash()?)?,
+    };
+    let valid = Argon2::default().verify_password(password.0.as_bytes(), &hash).is_ok();
+    match account {
+      Some(account) if valid => {
+        self
+          .login_throttle_service
+          .succeeded(&[email_key])
+          .await
+          .map_err(map_throttle_error)?;
+        Ok(account)
+      }
+      _ => {
+        self
+          .login_throttle_service
+          .failed(&keys)
+          .await
+          .map_err(map_throttle_error)?;
+        Err(ClientError(AccountServiceError::InvalidCredentials))
+      }
     }
-    Err(wrong_password())
   }
 }
 
-fn password_hash(argon_password: &str) -> Result<PasswordHash, ServiceError<AccountServiceError>> {
+fn password_hash(argon_password: &str) -> Result<PasswordHash<'_>, ServiceError<AccountServiceError>> {
   PasswordHash::new(argon_password).map_err(|y| map_server_error(Box::new(y)))
 }
 
-const fn unknown_email() -> ServiceError<AccountServiceError> {
-  ServiceError::ClientError(AccountServiceError::UnknownEmail)
+fn dummy_hash() -> Result<&'static str, ServiceError<AccountServiceError>> {
+  static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+  if let Some(hash) = DUMMY_HASH.get() {
+    return Ok(hash);
+  }
+  let hash = hash_password("dummy password")?.0;
+  Ok(DUMMY_HASH.get_or_init(|| hash))
 }
 
-const fn wrong_password() -> ServiceError<AccountServiceError> {
-  ServiceError::ClientError(AccountServiceError::WrongPassword)
+fn map_throttle_error(error: ServiceError<LoginThrottleServiceError>) -> ServiceError<AccountServiceError> {
+  match error {
+    ClientError(LoginThrottleServiceError::Locked(x)) => ClientError(AccountServiceError::TooManyAttempts(x)),
+    ServerError(x) => ServerError(x),
+  }
 }
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+
+use services::account_service::login_throttle_service::{LoginKey, LoginThrottleService, LoginThrottleServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+use crate::clock::Clock;
+use crate::services::map_server_error;
+
+/// How failed logins of one kind of key are throttled.
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottlePolicy {
+  /// Failed attempts that are allowed without any delay
+  pub free_attempts: u32,
+  /// Delay after the first failed attempt past the free ones, it doubles with every further one
+  pub base_delay: Duration,
+  /// Failed attempts after which the key is locked out
+  pub lockout_attempts: u32,
+  /// How long a lockout lasts, it caps the delays and is how long failed attempts are remembered
+  pub lockout: Duration,
+}
+
+impl ThrottlePolicy {
+  fn delay(&self, failures: u32) -> Duration {
+    if failures >= self.lockout_attempts {
+      return self.lockout;
+    }
+    if failures <= self.free_attempts {
+      return Duration::zero();
+    }
+    let factor = 2_i32.checked_pow(failures - self.free_attempts - 1).unwrap_or(i32::MAX);
+    self
+      .base_delay
+      .checked_mul(factor)
+      .map_or(self.lockout, |x| x.min(self.lockout))
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoginThrottleConfig {
+  pub email: ThrottlePolicy,
+  /// Should be more lenient than the email policy, many users can share an address
+  pub ip: ThrottlePolicy,
+}
+
+impl Default for LoginThrottleConfig {
+  fn default() -> Self {
+    LoginThrottleConfig {
+      email: ThrottlePolicy {
+        free_attempts: 3,
+        base_delay: Duration::seconds(1),
+        lockout_attempts: 10,
+        lockout: Duration::minutes(15),
+      },
+      ip: ThrottlePolicy {
+        free_attempts: 10,
+        base_delay: Duration::seconds(1),
+        lockout_attempts: 50,
+        lockout: Duration::minutes(15),
+      },
+    }
+  }
+}
+
+struct FailedAttempts {
+  count: u32,
+  last: NaiveDateTime,
+}
+
+/// Keeps the failed attempts in memory, they are not shared between multiple instances of the server.
+pub struct DefaultLoginThrottleService {
+  config: LoginThrottleConfig,
+  clock: Arc<dyn Clock>,
+  attempts: Mutex<HashMap<LoginKey, FailedAttempts>>,
+}
+
+impl DefaultLoginThrottleService {
+  pub fn new(config: LoginThrottleConfig, clock: Arc<dyn Clock>) -> DefaultLoginThrottleService {
+    DefaultLoginThrottleService {
+      config,
+      clock,
+      attempts: Mutex::new(HashMap::new()),
+    }
+  }
+}
+
+#[async_trait]
+impl LoginThrottleService for DefaultLoginThrottleService {
+  async fn check(&self, keys: &[LoginKey]) -> Result<(), ServiceError<LoginThrottleServiceError>> {
+    let now = self.clock.now();
+    let attempts = self.attempts()?;
+    let wait = keys
+      .iter()
+      .filter_map(|key| {
+        let failed = attempts.get(key)?;
+        Some(failed.last + self.policy(key).delay(failed.count) - now)
+      })
+      .max()
+      .unwrap_or_else(Duration::zero);
+    if wait <= Duration::zero() {
+      return Ok(());
+    }
+    let seconds = (wait + Duration::seconds(1) - Duration::nanoseconds(1)).num_seconds();
+    Err(ClientError(LoginThrottleServiceError::Locked(seconds as u64)))
+  }
+
+  async fn failed(&self, keys: &[LoginKey]) -> Result<(), ServiceError<LoginThrottleServiceError>> {
+    let now = self.clock.now();
+    let mut attempts = self.attempts()?;
+    attempts.retain(|key, failed| failed.last + self.policy(key).lockout > now);
+    for key in keys {
+      let failed = attempts
+        .entry(key.clone())
+        .or_insert(FailedAttempts { count: 0, last: now });
+      failed.count += 1;
+      failed.last = now;
+    }
+    Ok(())
+  }
+
+  async fn succeeded(&self, keys: &[LoginKey]) -> Result<(), ServiceError<LoginThrottleServiceError>> {
+    let mut attempts = self.attempts()?;
+    for key in keys {
+      attempts.remove(key);
+    }
+    Ok(())
+  }
+}
+
+impl DefaultLoginThrottleService {
+  const fn policy(&self, key: &LoginKey) -> &ThrottlePolicy {
+    match key {
+      LoginKey::Email(_) | LoginKey::SecondFactor(_) => &self.config.email,
+      LoginKey::Ip(_) => &self.config.ip,
+    }
+  }
+
+  fn attempts(
+    &self,
+  ) -> Result<MutexGuard<'_, HashMap<LoginKey, FailedAttempts>>, ServiceError<LoginThrottleServiceError>> {
+    self
+      .attempts
+      .lock()
+      .map_err(|_| map_server_error(Box::from("Failed login attempts are poisoned")))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::net::{IpAddr, Ipv4Addr};
+  use std::sync::Arc;
+
+  use chrono::Duration;
+
+  use services::account_service::login_throttle_service::{LoginKey, LoginThrottleService, LoginThrottleServiceError};
+  use services::traits::service_error::ServiceError;
+
+  use crate::clock::FakeClock;
+
+  use super::{DefaultLoginThrottleService, LoginThrottleConfig, ThrottlePolicy};
+
+  fn policy(free_attempts: u32, lockout_attempts: u32) -> ThrottlePolicy {
+    ThrottlePolicy {
+      free_attempts,
+      base_delay: Duration::seconds(1),
+      lockout_attempts,
+      lockout: Duration::minutes(15),
+    }
+  }
+
+  fn service() -> (DefaultLoginThrottleService, Arc<FakeClock>) {
+    let clock = Arc::new(FakeClock::at(0));
+    let config = LoginThrottleConfig {
+      email: policy(3, 10),
+      ip: policy(5, 20),
+    };
+    (DefaultLoginThrottleService::new(config, clock.clone()), clock)
+  }
+
+  fn email() -> LoginKey {
+    LoginKey::Email("user@example.com".to_string())
+  }
+
+  fn ip() -> LoginKey {
+    LoginKey::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST))
+  }
+
+  async fn fail(service: &DefaultLoginThrottleService, keys: &[LoginKey], times: u32) {
+    for _ in 0..times {
+      assert!(service.failed(keys).await.is_ok());
+    }
+  }
+
+  async fn locked_for(service: &DefaultLoginThrottleService, keys: &[LoginKey]) -> Option<u64> {
+    match service.check(keys).await {
+      Ok(()) => None,
+      Err(ServiceError::ClientError(LoginThrottleServiceError::Locked(seconds))) => Some(seconds),
+      Err(ServiceError::ServerError(error)) => panic!("Unexpected server error: {error}"),
+    }
+  }
+
+  #[tokio::test]
+  async fn free_attempts_are_not_delayed() {
+    let (service, _) = service();
+    fail(&service, &[email()], 3).await;
+    assert_eq!(locked_for(&service, &[email()]).await, None);
+  }
+
+  #[tokio::test]
+  async fn delay_doubles_after_free_attempts() {
+    let (service, clock) = service();
+    fail(&service, &[email()], 4).await;
+    assert_eq!(locked_for(&service, &[email()]).await, Some(1));
+    clock.advance(Duration::seconds(1));
+    assert_eq!(locked_for(&service, &[email()]).await, None);
+
+    fail(&service, &[email()], 1).await;
+    assert_eq!(locked_for(&service, &[email()]).await, Some(2));
+    fail(&service, &[email()], 1).await;
+    assert_eq!(locked_for(&service, &[email()]).await, Some(4));
+    clock.advance(Duration::milliseconds(3500));
+    assert_eq!(locked_for(&service, &[email()]).await, Some(1));
+  }
+
+  #[tokio::test]
+  async fn locks_out_until_lockout_passed() {
+    let (service, clock) = service();
+    fail(&service, &[email()], 10).await;
+    assert_eq!(locked_for(&service, &[email()]).await, Some(15 * 60));
+    clock.advance(Duration::minutes(14));
+    assert_eq!(locked_for(&service, &[email()]).await, Some(60));
+    clock.advance(Duration::minutes(1));
+    assert_eq!(locked_for(&service, &[email()]).await, None);
+  }
+
+  #[tokio::test]
+  async fn failures_are_forgotten_after_lockout() {
+    let (service, clock) = service();
+    fail(&service, &[email()], 3).await;
+    clock.advance(Duration::minutes(15));
+    fail(&service, &[email()], 1).await;
+    assert_eq!(locked_for(&service, &[email()]).await, None);
+  }
+
+  #[tokio::test]
+  async fn success_resets_only_given_keys() {
+    let (service, _) = service();
+    fail(&service, &[email(), ip()], 6).await;
+    assert!(service.succeeded(&[email()]).await.is_ok());
+    assert_eq!(locked_for(&service, &[email()]).await, None);
+    assert_eq!(locked_for(&service, &[ip()]).await, Some(1));
+  }
+
+  #[tokio::test]
+  async fn keys_use_their_own_policy() {
+    let (service, _) = service();
+    let other_email = LoginKey::Email("other@example.com".to_string());
+    fail(&service, &[email(), ip()], 4).await;
+    fail(&service, &[other_email.clone(), ip()], 1).await;
+    assert_eq!(locked_for(&service, &[email()]).await, Some(1));
+    assert_eq!(locked_for(&service, std::slice::from_ref(&other_email)).await, None);
+    assert_eq!(locked_for(&service, &[other_email, ip()]).await, None);
+    fail(&service, &[ip()], 2).await;
+    assert_eq!(locked_for(&service, &[ip()]).await, Some(2));
+  }
+}
@@ -1,28 +1,28 @@
 use std::sync::Arc;
 
-use argon2::password_hash::rand_core::OsRng;
-use argon2::password_hash::SaltString;
-use argon2::{Argon2, PasswordHasher};
 use async_trait::async_trait;
 
 use domain::entities::account::create_account::CreateAccount;
 use domain::entities::account::create_partial_account::CreatePartialAccount;
 use domain::entities::account::{Account, Password};
 use domain::entities::user::create_user::CreateUser;
+use domain::enums::account_role::AccountRole;
 use repositories::account_repository::mut_account_repository::MutAccountRepository;
 use services::account_service::mut_account_service::MutAccountServiceError::OtherError;
 use services::account_service::mut_account_service::{MutAccountService, MutAccountServiceError};
-use services::account_service::AccountService;
+use services::account_service::mut_verification_service::MutVerificationService;
+use services::account_service::{AccountService, AccountServiceError};
 use services::traits::service_error::ServiceError;
 use services::traits::service_error::ServiceError::{ClientError, ServerError};
 use services::user_service::mut_user_service::MutUserService;
 
-use crate::services::map_server_error;
+use crate::services::account_service::hash_password;
 
 pub struct DefaultMutAccountService<'a> {
   mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
   account_service: Arc<dyn AccountService + 'a>,
   mut_user_service: Arc<dyn MutUserService + 'a>,
+  mut_verification_service: Arc<dyn MutVerificationService + 'a>,
 }
 
 impl<'a> DefaultMutAccountService<'a> {
@@ -30,11 +30,13 @@ impl<'a> DefaultMutAccountService<'a> {
     mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
     account_repository: Arc<dyn AccountService + 'a>,
     mut_user_service: Arc<dyn MutUserService + 'a>,
+    mut_verification_service: Arc<dyn MutVerificationService + 'a>,
   ) -> DefaultMutAccountService<'a> {
     DefaultMutAccountService {
       mut_account_repository,
       account_service: account_repository,
       mut_user_service,
+      mut_verification_service,
     }
   }
 }
@@ -57,7 +59,79 @@ impl MutAccountService for DefaultMutAccountService<'_> {
       email: data.email,
       password: hash_password(&data.password.0)?,
     };
-    Ok(self.mut_account_repository.create(account).await?)
+    let account = self.mut_account_repository.create(account).await?;
+    self
+      .mut_verification_service
+      .send(account.user.id)
+      .await
+      .map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?;
+    Ok(account)
+  }
+
+  async fn set_role(&self, user_id: u32, role: AccountRole) -> Result<Account, ServiceError<MutAccountServiceError>> {
+    let account = self
+      .account_service
+      .get_by_user_id(user_id)
+      .await
+      .map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?
+      .ok_or(ClientError(MutAccountServiceError::NonExistentAccount))?;
+    self.mut_account_repository.set_role(user_id, role).await?;
+    Ok(Account { role, ..account })
+  }
+
+  async fn set_verified(&self, user_id: u32) -> Result<Account, ServiceError<MutAccountServiceError>> {
+    let account = self
+      .account_service
+      .get_by_user_id(user_id)
+      .await
+      .map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?
+      .ok_or(ClientError(MutAccountServiceError::NonExistentAccount))?;
+    self.mut_account_repository.set_verified(user_id).await?;
+    Ok(Account {
+      verified: true,
+      ..account
+    })
+  }
+
+  async fn delete(
+    &self,
+    user_id: u32,
+    password: &Password,
+  ) -> Result<Vec<String>, ServiceError<MutAccountServiceError>> {
+    let account = self
+      .account_service
+      .get_by_user_id(user_id)
+      .await
+      .map_err(map_account_error)?
+      .ok_or(ClientError(MutAccountServiceError::NonExistentAccount))?;
+    self
+      .account_service
+      .login(&account.email, password, None)
+      .await
+      .map_err(map_account_error)?;
+
+    self.mut_account_repository.delete(user_id).await?;
+    self.mut_user_service.delete(user_id).await.map_err(|x| match x {
+      ClientError(x) => ClientError(OtherError(Box::new(x))),
+      ServerError(x) => ServerError(x),
+    })
+  }
+}
+
+fn map_account_error(error: ServiceError<AccountServiceError>) -> ServiceError<MutAccountServiceError> {
+  match error {
+    ClientError(AccountServiceError::InvalidCredentials) => ClientError(MutAccountServiceError::WrongPassword),
+    ClientError(x) => ClientError(OtherError(Box::new(x))),
+    ServerError(x) => ServerError(x),
   }
 }
 
@@ -84,13 +158,3 @@ impl<'a> DefaultMutAccountService<'a> {
     Ok(())
   }
 }
-
-fn hash_password(password: &str) -> Result<Password, ServiceError<MutAccountServiceError>> {
-  let salt = SaltString::generate(&mut OsRng);
-  let argon2 = Argon2::default();
-  let password_hash = argon2
-    .hash_password(password.as_bytes(), &salt)
-    .map_err(|y| map_server_error(Box::new(y)))?
-    .to_string();
-  Ok(Password(password_hash))
-}
@@ -0,0 +1,407 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use domain::entities::account::create_password_reset::CreatePasswordReset;
+use domain::entities::account::{Email, Password};
+use domain::entities::mail::Mail;
+use repositories::account_repository::mut_account_repository::MutAccountRepository;
+use repositories::account_repository::password_reset_repository::mut_password_reset_repository::MutPasswordResetRepository;
+use repositories::account_repository::password_reset_repository::PasswordResetRepository;
+use repositories::mail_sender::MailSender;
+use services::account_service::mut_password_service::MutPasswordServiceError::OtherError;
+use services::account_service::mut_password_service::{MutPasswordService, MutPasswordServiceError};
+use services::account_service::mut_refresh_token_service::MutRefreshTokenService;
+use services::account_service::{AccountService, AccountServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+use crate::services::account_service::{hash_password, secret_token};
+
+const RESET_LIFETIME_MINUTES: i64 = 60;
+
+pub struct DefaultMutPasswordService<'a> {
+  account_service: Arc<dyn AccountService + 'a>,
+  mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+  password_reset_repository: Arc<dyn PasswordResetRepository + 'a>,
+  mut_password_reset_repository: Arc<dyn MutPasswordResetRepository + 'a>,
+  mut_refresh_token_service: Arc<dyn MutRefreshTokenService + 'a>,
+  mail_sender: Arc<dyn MailSender + 'a>,
+  frontend_url: &'a str,
+}
+
+impl<'a> DefaultMutPasswordService<'a> {
+  pub fn new(
+    account_service: Arc<dyn AccountService + 'a>,
+    mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+    password_reset_repository: Arc<dyn PasswordResetRepository + 'a>,
+    mut_password_reset_repository: Arc<dyn MutPasswordResetRepository + 'a>,
+    mut_refresh_token_service: Arc<dyn MutRefreshTokenService + 'a>,
+    mail_sender: Arc<dyn MailSender + 'a>,
+    frontend_url: &'a str,
+  ) -> DefaultMutPasswordService<'a> {
+    DefaultMutPasswordService {
+      account_service,
+      mut_account_repository,
+      password_reset_repository,
+      mut_password_reset_repository,
+      mut_refresh_token_service,
+      mail_sender,
+      frontend_url,
+    }
+  }
+}
+
+#[async_trait]
+impl MutPasswordService for DefaultMutPasswordService<'_> {
+  async fn change(
+    &self,
+    user_id: u32,
+    current_password: &Password,
+    new_password: &Password,
+  ) -> Result<(), ServiceError<MutPasswordServiceError>> {
+    validate_password(new_password)?;
+    let account = self
+      .account_service
+      .get_by_user_id(user_id)
+      .await
+      .map_err(map_account_error)?
+      .ok_or(ClientError(MutPasswordServiceError::NonExistentAccount))?;
+    self
+      .account_service
+      .login(&account.email, current_password, None)
+      .await
+      .map_err(map_account_error)?;
+
+    let password = hash_password(&new_password.0)?;
+    Ok(self.mut_account_repository.update_password(user_id, &password).await?)
+  }
+
+  async fn request_reset(&self, email: &Email) -> Result<(), ServiceError<MutPasswordServiceError>> {
+    let account = self
+      .account_service
+      .get_by_email(email)
+      .await
+      .map_err(map_account_error)?;
+    let Some(account) = account else {
+      return Ok(());
+    };
+    let user_id = account.user.id;
+
+    self.mut_password_reset_repository.delete_by_user(user_id).await?;
+    let token = secret_token::generate();
+    self
+      .mut_password_reset_repository
+      .create(CreatePasswordReset {
+        user_id,
+        hash: secret_token::hash(&token),
+        expires: Utc::now().naive_utc() + Duration::minutes(RESET_LIFETIME_MINUTES),
+      })
+      .await?;
+
+    let mail = Mail {
+      to: account.email,
+      subject: "Reset your password".to_string(),
+      body: format!(
+        "Hello {},\n\nuse the following link within the next hour to choose a new password:\n{}reset-password?token={token}\n\nIf you did not request this, you can ignore this mail.",
+        account.user.name, self.frontend_url
+      ),
+    };
+    Ok(self.mail_sender.send(&mail).await?)
+  }
+
+  async fn reset(&self, token: &str, new_password: &Password) -> Result<(), ServiceError<MutPasswordServiceError>> {
+    validate_password(new_password)?;
+    let reset = self
+      .password_reset_repository
+      .get_by_hash(&secret_token::hash(token))
+      .await?
+      .ok_or(ClientError(MutPasswordServiceError::InvalidToken))?;
+    self.mut_password_reset_repository.delete_by_user(reset.user_id).await?;
+    if reset.expires <= Utc::now().naive_utc() {
+      return Err(ClientError(MutPasswordServiceError::ExpiredToken));
+    }
+
+    let password = hash_password(&new_password.0)?;
+    self
+      .mut_account_repository
+      .update_password(reset.user_id, &password)
+      .await?;
+    self
+      .mut_refresh_token_service
+      .revoke_all(reset.user_id)
+      .await
+      .map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })
+  }
+}
+
+const fn validate_password(password: &Password) -> Result<(), ServiceError<MutPasswordServiceError>> {
+  match password.0.is_empty() {
+    true => Err(ClientError(MutPasswordServiceError::InvalidPassword)),
+    false => Ok(()),
+  }
+}
+
+fn map_account_error(error: ServiceError<AccountServiceError>) -> ServiceError<MutPasswordServiceError> {
+  match error {
+    ClientError(AccountServiceError::InvalidCredentials) => ClientError(MutPasswordServiceError::WrongPassword),
+    ClientError(x) => ClientError(OtherError(Box::new(x))),
+    ServerError(x) => ServerError(x),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::error::Error;
+  use std::net::IpAddr;
+  use std::sync::{Arc, Mutex};
+
+  use async_trait::async_trait;
+  use chrono::{Duration, NaiveDate};
+
+  use domain::entities::account::create_partial_account::CreatePartialAccount;
+  use domain::entities::account::create_password_reset::CreatePasswordReset;
+  use domain::entities::account::password_reset::PasswordReset;
+  use domain::entities::account::{Account, Email, Password};
+  use domain::entities::mail::Mail;
+  use domain::entities::user::user_average::UserAverage;
+  use domain::entities::user::User;
+  use domain::enums::account_role::AccountRole;
+  use domain::items_total::ItemsTotal;
+  use domain::pagination::Pagination;
+  use repositories::account_repository::mut_account_repository::MutAccountRepository;
+  use repositories::account_repository::password_reset_repository::mut_password_reset_repository::MutPasswordResetRepository;
+  use repositories::account_repository::password_reset_repository::PasswordResetRepository;
+  use repositories::mail_sender::MailSender;
+  use services::account_service::mut_password_service::{MutPasswordService, MutPasswordServiceError};
+  use services::account_service::mut_refresh_token_service::{MutRefreshTokenService, MutRefreshTokenServiceError};
+  use services::account_service::{AccountService, AccountServiceError};
+  use services::traits::service_error::ServiceError;
+
+  use super::DefaultMutPasswordService;
+
+  const USER_ID: u32 = 1;
+  const EMAIL: &str = "user@example.com";
+
+  fn account() -> Account {
+    Account {
+      user: User {
+        id: USER_ID,
+        name: "User".to_string(),
+        joined: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        description: None,
+        deleted: false,
+        profile_picture: None,
+        averages: UserAverage::default(),
+      },
+      email: Email(EMAIL.to_string()),
+      password: Password(String::new()),
+      role: AccountRole::Member,
+      verified: true,
+    }
+  }
+
+  /// Knows a single account, logging in is not needed for resets
+  struct FakeAccounts;
+
+  #[async_trait]
+  impl AccountService for FakeAccounts {
+    async fn get(&self, _: Pagination) -> Result<ItemsTotal<Account>, ServiceError<AccountServiceError>> {
+      Ok(ItemsTotal {
+        items: vec![account()],
+        total: 1,
+      })
+    }
+    async fn get_by_user_id(&self, id: u32) -> Result<Option<Account>, ServiceError<AccountServiceError>> {
+      Ok(Some(account()).filter(|x| x.user.id == id))
+    }
+    async fn get_by_email(&self, email: &Email) -> Result<Option<Account>, ServiceError<AccountServiceError>> {
+      Ok(Some(account()).filter(|x| x.email.0 == email.0))
+    }
+    async fn login(
+      &self,
+      _: &Email,
+      _: &Password,
+      _: Option<IpAddr>,
+    ) -> Result<Account, ServiceError<AccountServiceError>> {
+      Err(ServiceError::ClientError(AccountServiceError::InvalidCredentials))
+    }
+  }
+
+  /// Records the changed passwords, the resets, the revoked sessions and the sent mails
+  #[derive(Default)]
+  struct Fakes {
+    passwords: Mutex<Vec<(u32, Password)>>,
+    resets: Mutex<Vec<PasswordReset>>,
+    revoked: Mutex<Vec<u32>>,
+    mails: Mutex<Vec<Mail>>,
+  }
+
+  impl Fakes {
+    /// The token of the last mail
+    fn token(&self) -> String {
+      let mails = self.mails.lock().unwrap();
+      let body = &mails.last().expect("Mail should be sent").body;
+      let start = body.find("token=").expect("Mail should contain the token") + "token=".len();
+      body[start..].split_whitespace().next().unwrap().to_string()
+    }
+
+    fn expire(&self) {
+      for reset in self.resets.lock().unwrap().iter_mut() {
+        reset.expires -= Duration::hours(2);
+      }
+    }
+  }
+
+  #[async_trait]
+  impl MutAccountRepository for Fakes {
+    async fn create(&self, _: CreatePartialAccount) -> Result<Account, Box<dyn Error>> {
+      unreachable!("Resets do not create accounts")
+    }
+    async fn update_password(&self, user_id: u32, password: &Password) -> Result<(), Box<dyn Error>> {
+      self.passwords.lock().unwrap().push((user_id, password.clone()));
+      Ok(())
+    }
+    async fn set_verified(&self, _: u32) -> Result<(), Box<dyn Error>> {
+      unreachable!("Resets do not verify accounts")
+    }
+    async fn set_role(&self, _: u32, _: AccountRole) -> Result<(), Box<dyn Error>> {
+      unreachable!("Resets do not change roles")
+    }
+    async fn delete(&self, _: u32) -> Result<(), Box<dyn Error>> {
+      unreachable!("Resets do not delete accounts")
+    }
+  }
+
+  #[async_trait]
+  impl PasswordResetRepository for Fakes {
+    async fn get_by_hash(&self, hash: &str) -> Result<Option<PasswordReset>, Box<dyn Error>> {
+      let resets = self.resets.lock().unwrap();
+      Ok(resets.iter().find(|x| x.hash == hash).cloned())
+    }
+  }
+
+  #[async_trait]
+  impl MutPasswordResetRepository for Fakes {
+    async fn create(&self, reset: CreatePasswordReset) -> Result<(), Box<dyn Error>> {
+      let mut resets = self.resets.lock().unwrap();
+      let id = resets.iter().map(|x| x.id).max().unwrap_or_default() + 1;
+      resets.push(PasswordReset {
+        id,
+        user_id: reset.user_id,
+        hash: reset.hash,
+        expires: reset.expires,
+      });
+      Ok(())
+    }
+    async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+      self.resets.lock().unwrap().retain(|x| x.user_id != user_id);
+      Ok(())
+    }
+  }
+
+  #[async_trait]
+  impl MutRefreshTokenService for Fakes {
+    async fn create(&self, _: u32) -> Result<String, ServiceError<MutRefreshTokenServiceError>> {
+      unreachable!("Resets do not log in")
+    }
+    async fn rotate(&self, _: &str) -> Result<(u32, String), ServiceError<MutRefreshTokenServiceError>> {
+      unreachable!("Resets do not log in")
+    }
+    async fn revoke(&self, _: &str) -> Result<(), ServiceError<MutRefreshTokenServiceError>> {
+      unreachable!("Resets revoke every session")
+    }
+    async fn revoke_all(&self, user_id: u32) -> Result<(), ServiceError<MutRefreshTokenServiceError>> {
+      self.revoked.lock().unwrap().push(user_id);
+      Ok(())
+    }
+    async fn revoke_others(&self, _: u32, _: Option<&str>) -> Result<(), ServiceError<MutRefreshTokenServiceError>> {
+      unreachable!("Resets revoke every session")
+    }
+  }
+
+  #[async_trait]
+  impl MailSender for Fakes {
+    async fn send(&self, mail: &Mail) -> Result<(), Box<dyn Error>> {
+      self.mails.lock().unwrap().push(mail.clone());
+      Ok(())
+    }
+  }
+
+  fn service() -> (DefaultMutPasswordService<'static>, Arc<Fakes>) {
+    let fakes = Arc::new(Fakes::default());
+    (
+      DefaultMutPasswordService::new(
+        Arc::new(FakeAccounts),
+        fakes.clone(),
+        fakes.clone(),
+        fakes.clone(),
+        fakes.clone(),
+        fakes.clone(),
+        "http://localhost/",
+      ),
+      fakes,
+    )
+  }
+
+  fn new_password() -> Password {
+    Password("new password".to_string())
+  }
+
+  #[tokio::test]
+  async fn reset_token_is_single_use() {
+    let (service, fakes) = service();
+    service.request_reset(&Email(EMAIL.to_string())).await.ok().unwrap();
+    let token = fakes.token();
+
+    assert!(service.reset(&token, &new_password()).await.is_ok());
+    assert_eq!(fakes.passwords.lock().unwrap().len(), 1);
+    assert_eq!(*fakes.revoked.lock().unwrap(), vec![USER_ID]);
+    assert!(matches!(
+      service.reset(&token, &new_password()).await,
+      Err(ServiceError::ClientError(MutPasswordServiceError::InvalidToken))
+    ));
+    assert_eq!(fakes.passwords.lock().unwrap().len(), 1);
+  }
+
+  #[tokio::test]
+  async fn expired_reset_token_is_rejected() {
+    let (service, fakes) = service();
+    service.request_reset(&Email(EMAIL.to_string())).await.ok().unwrap();
+    let token = fakes.token();
+    fakes.expire();
+
+    assert!(matches!(
+      service.reset(&token, &new_password()).await,
+      Err(ServiceError::ClientError(MutPasswordServiceError::ExpiredToken))
+    ));
+    assert!(fakes.passwords.lock().unwrap().is_empty());
+    assert!(fakes.resets.lock().unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn new_request_replaces_previous_token() {
+    let (service, fakes) = service();
+    service.request_reset(&Email(EMAIL.to_string())).await.ok().unwrap();
+    let previous = fakes.token();
+    service.request_reset(&Email(EMAIL.to_string())).await.ok().unwrap();
+
+    assert!(service.reset(&previous, &new_password()).await.is_err());
+    assert!(service.reset(&fakes.token(), &new_password()).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn unknown_email_sends_no_mail() {
+    let (service, fakes) = service();
+    assert!(service
+      .request_reset(&Email("other@example.com".to_string()))
+      .await
+      .is_ok());
+    assert!(fakes.mails.lock().unwrap().is_empty());
+    assert!(fakes.resets.lock().unwrap().is_empty());
+  }
+}
@@ -0,0 +1,313 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use domain::entities::account::create_refresh_token::CreateRefreshToken;
+use domain::entities::account::refresh_token::RefreshToken;
+use repositories::account_repository::refresh_token_repository::mut_refresh_token_repository::MutRefreshTokenRepository;
+use repositories::account_repository::refresh_token_repository::RefreshTokenRepository;
+use services::account_service::mut_refresh_token_service::{MutRefreshTokenService, MutRefreshTokenServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+use crate::services::account_service::secret_token;
+
+const FAMILY_LIFETIME_DAYS: i64 = 30;
+
+pub struct DefaultMutRefreshTokenService<'a> {
+  refresh_token_repository: Arc<dyn RefreshTokenRepository + 'a>,
+  mut_refresh_token_repository: Arc<dyn MutRefreshTokenRepository + 'a>,
+}
+
+impl<'a> DefaultMutRefreshTokenService<'a> {
+  pub fn new(
+    refresh_token_repository: Arc<dyn RefreshTokenRepository + 'a>,
+    mut_refresh_token_repository: Arc<dyn MutRefreshTokenRepository + 'a>,
+  ) -> DefaultMutRefreshTokenService<'a> {
+    DefaultMutRefreshTokenService {
+      refresh_token_repository,
+      mut_refresh_token_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutRefreshTokenService for DefaultMutRefreshTokenService<'_> {
+  async fn create(&self, user_id: u32) -> Result<String, ServiceError<MutRefreshTokenServiceError>> {
+    let now = Utc::now().naive_utc();
+    let expired_families: Vec<u32> = self
+      .refresh_token_repository
+      .get_families(user_id)
+      .await?
+      .into_iter()
+      .filter(|x| x.expires <= now)
+      .map(|x| x.id)
+      .collect();
+    if !expired_families.is_empty() {
+      self
+        .mut_refresh_token_repository
+        .delete_families(&expired_families)
+        .await?;
+    }
+
+    let expires = now + Duration::days(FAMILY_LIFETIME_DAYS);
+    self.insert(user_id, None, expires).await
+  }
+
+  async fn rotate(&self, token: &str) -> Result<(u32, String), ServiceError<MutRefreshTokenServiceError>> {
+    let token = self.get_token(token).await?;
+    // marking the token is atomic, so of two concurrent rotations only one succeeds and the other revokes the family
+    if !self.mut_refresh_token_repository.mark_used(token.id).await? {
+      self
+        .mut_refresh_token_repository
+        .delete_families(&[token.family_id()])
+        .await?;
+      return Err(ClientError(MutRefreshTokenServiceError::ReusedToken));
+    }
+    if token.expires <= Utc::now().naive_utc() {
+      self
+        .mut_refresh_token_repository
+        .delete_families(&[token.family_id()])
+        .await?;
+      return Err(ClientError(MutRefreshTokenServiceError::ExpiredToken));
+    }
+
+    let new_token = self.insert(token.user_id, Some(&token), token.expires).await?;
+    Ok((token.user_id, new_token))
+  }
+
+  async fn revoke(&self, token: &str) -> Result<(), ServiceError<MutRefreshTokenServiceError>> {
+    let token = self.get_token(token).await?;
+    self
+      .mut_refresh_token_repository
+      .delete_families(&[token.family_id()])
+      .await?;
+    Ok(())
+  }
+
+  async fn revoke_all(&self, user_id: u32) -> Result<(), ServiceError<MutRefreshTokenServiceError>> {
+    Ok(self.mut_refresh_token_repository.delete_by_user(user_id).await?)
+  }
+
+  async fn revoke_others(
+    &self,
+    user_id: u32,
+    token: Option<&str>,
+  ) -> Result<(), ServiceError<MutRefreshTokenServiceError>> {
+    let kept_family = match token {
+      Some(token) => {
+        let token = self.get_token(token).await?;
+        if token.user_id != user_id {
+          return Err(ClientError(MutRefreshTokenServiceError::InvalidToken));
+        }
+        Some(token.family_id())
+      }
+      None => None,
+    };
+    let families: Vec<u32> = self
+      .refresh_token_repository
+      .get_families(user_id)
+      .await?
+      .into_iter()
+      .map(|x| x.id)
+      .filter(|x| Some(*x) != kept_family)
+      .collect();
+    if !families.is_empty() {
+      self.mut_refresh_token_repository.delete_families(&families).await?;
+    }
+    Ok(())
+  }
+}
+
+impl DefaultMutRefreshTokenService<'_> {
+  async fn get_token(&self, token: &str) -> Result<RefreshToken, ServiceError<MutRefreshTokenServiceError>> {
+    self
+      .refresh_token_repository
+      .get_by_hash(&secret_token::hash(token))
+      .await?
+      .ok_or(ClientError(MutRefreshTokenServiceError::InvalidToken))
+  }
+
+  async fn insert(
+    &self,
+    user_id: u32,
+    parent: Option<&RefreshToken>,
+    expires: NaiveDateTime,
+  ) -> Result<String, ServiceError<MutRefreshTokenServiceError>> {
+    let token = secret_token::generate();
+
+    self
+      .mut_refresh_token_repository
+      .create(CreateRefreshToken {
+        user_id,
+        family: parent.map(RefreshToken::family_id),
+        parent: parent.map(|x| x.id),
+        hash: secret_token::hash(&token),
+        expires,
+      })
+      .await?;
+    Ok(token)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::error::Error;
+  use std::sync::{Arc, Mutex};
+
+  use async_trait::async_trait;
+
+  use domain::entities::account::create_refresh_token::CreateRefreshToken;
+  use domain::entities::account::refresh_token::RefreshToken;
+  use repositories::account_repository::refresh_token_repository::mut_refresh_token_repository::MutRefreshTokenRepository;
+  use repositories::account_repository::refresh_token_repository::RefreshTokenRepository;
+  use services::account_service::mut_refresh_token_service::{MutRefreshTokenService, MutRefreshTokenServiceError};
+  use services::traits::service_error::ServiceError;
+
+  use super::DefaultMutRefreshTokenService;
+
+  /// Keeps the tokens in memory together with whether they were used
+  #[derive(Default)]
+  struct FakeRefreshTokens(Mutex<Vec<(RefreshToken, bool)>>);
+
+  impl FakeRefreshTokens {
+    fn count(&self) -> usize {
+      self.0.lock().unwrap().len()
+    }
+  }
+
+  #[async_trait]
+  impl RefreshTokenRepository for FakeRefreshTokens {
+    async fn get_by_hash(&self, hash: &str) -> Result<Option<RefreshToken>, Box<dyn Error>> {
+      let tokens = self.0.lock().unwrap();
+      Ok(tokens.iter().find(|(x, _)| x.hash == hash).map(|(x, _)| x.clone()))
+    }
+
+    async fn get_families(&self, user_id: u32) -> Result<Vec<RefreshToken>, Box<dyn Error>> {
+      let tokens = self.0.lock().unwrap();
+      Ok(
+        tokens
+          .iter()
+          .filter(|(x, _)| x.user_id == user_id && x.family.is_none())
+          .map(|(x, _)| x.clone())
+          .collect(),
+      )
+    }
+  }
+
+  #[async_trait]
+  impl MutRefreshTokenRepository for FakeRefreshTokens {
+    async fn create(&self, token: CreateRefreshToken) -> Result<RefreshToken, Box<dyn Error>> {
+      let mut tokens = self.0.lock().unwrap();
+      let token = RefreshToken {
+        id: tokens.iter().map(|(x, _)| x.id).max().unwrap_or_default() + 1,
+        user_id: token.user_id,
+        family: token.family,
+        parent: token.parent,
+        hash: token.hash,
+        expires: token.expires,
+      };
+      tokens.push((token.clone(), false));
+      Ok(token)
+    }
+
+    async fn mark_used(&self, id: u32) -> Result<bool, Box<dyn Error>> {
+      let mut tokens = self.0.lock().unwrap();
+      let (_, used) = tokens.iter_mut().find(|(x, _)| x.id == id).expect("Token should exist");
+      Ok(!std::mem::replace(used, true))
+    }
+
+    async fn delete_families(&self, families: &[u32]) -> Result<(), Box<dyn Error>> {
+      let mut tokens = self.0.lock().unwrap();
+      tokens.retain(|(x, _)| !families.contains(&x.family_id()));
+      Ok(())
+    }
+
+    async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>> {
+      let mut tokens = self.0.lock().unwrap();
+      tokens.retain(|(x, _)| x.user_id != user_id);
+      Ok(())
+    }
+  }
+
+  fn service() -> (DefaultMutRefreshTokenService<'static>, Arc<FakeRefreshTokens>) {
+    let tokens = Arc::new(FakeRefreshTokens::default());
+    (
+      DefaultMutRefreshTokenService::new(tokens.clone(), tokens.clone()),
+      tokens,
+    )
+  }
+
+  fn is_reused(result: Result<(u32, String), ServiceError<MutRefreshTokenServiceError>>) -> bool {
+    matches!(
+      result,
+      Err(ServiceError::ClientError(MutRefreshTokenServiceError::ReusedToken))
+    )
+  }
+
+  #[tokio::test]
+  async fn rotate_returns_new_token_of_the_family() {
+    let (service, tokens) = service();
+    let token = service.create(1).await.ok().unwrap();
+    let (user_id, rotated) = service.rotate(&token).await.ok().unwrap();
+    assert_eq!(user_id, 1);
+    assert_ne!(token, rotated);
+    assert_eq!(tokens.count(), 2);
+    assert!(service.rotate(&rotated).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn reused_token_revokes_family() {
+    let (service, tokens) = service();
+    let token = service.create(1).await.ok().unwrap();
+    let (_, rotated) = service.rotate(&token).await.ok().unwrap();
+
+    assert!(is_reused(service.rotate(&token).await));
+    assert_eq!(tokens.count(), 0);
+    assert!(matches!(
+      service.rotate(&rotated).await,
+      Err(ServiceError::ClientError(MutRefreshTokenServiceError::InvalidToken))
+    ));
+  }
+
+  #[tokio::test]
+  async fn reuse_keeps_other_families() {
+    let (service, tokens) = service();
+    let token = service.create(1).await.ok().unwrap();
+    let other = service.create(1).await.ok().unwrap();
+    service.rotate(&token).await.ok().unwrap();
+
+    assert!(is_reused(service.rotate(&token).await));
+    assert_eq!(tokens.count(), 1);
+    assert!(service.rotate(&other).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn revoke_others_keeps_family_of_token() {
+    let (service, tokens) = service();
+    let token = service.create(1).await.ok().unwrap();
+    let (_, current) = service.rotate(&token).await.ok().unwrap();
+    let other = service.create(1).await.ok().unwrap();
+    let other_user = service.create(2).await.ok().unwrap();
+
+    assert!(service.revoke_others(1, Some(&current)).await.is_ok());
+    assert_eq!(tokens.count(), 3);
+    assert!(service.rotate(&current).await.is_ok());
+    assert!(service.rotate(&other).await.is_err());
+    assert!(service.rotate(&other_user).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn revoke_others_rejects_token_of_other_user() {
+    let (service, tokens) = service();
+    service.create(1).await.ok().unwrap();
+    let other_user = service.create(2).await.ok().unwrap();
+
+    assert!(matches!(
+      service.revoke_others(1, Some(&other_user)).await,
+      Err(ServiceError::ClientError(MutRefreshTokenServiceError::InvalidToken))
+    ));
+    assert_eq!(tokens.count(), 2);
+  }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use domain::entities::account::create_email_verification::CreateEmailVerification;
+use domain::entities::mail::Mail;
+use repositories::account_repository::email_verification_repository::mut_email_verification_repository::MutEmailVerificationRepository;
+use repositories::account_repository::email_verification_repository::EmailVerificationRepository;
+use repositories::account_repository::mut_account_repository::MutAccountRepository;
+use repositories::account_repository::AccountRepository;
+use repositories::mail_sender::MailSender;
+use services::account_service::mut_verification_service::{MutVerificationService, MutVerificationServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+use crate::services::account_service::secret_token;
+
+const VERIFICATION_LIFETIME_HOURS: i64 = 24;
+
+pub struct DefaultMutVerificationService<'a> {
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+  email_verification_repository: Arc<dyn EmailVerificationRepository + 'a>,
+  mut_email_verification_repository: Arc<dyn MutEmailVerificationRepository + 'a>,
+  mail_sender: Arc<dyn MailSender + 'a>,
+  frontend_url: &'a str,
+}
+
+impl<'a> DefaultMutVerificationService<'a> {
+  pub fn new(
+    account_repository: Arc<dyn AccountRepository + 'a>,
+    mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+    email_verification_repository: Arc<dyn EmailVerificationRepository + 'a>,
+    mut_email_verification_repository: Arc<dyn MutEmailVerificationRepository + 'a>,
+    mail_sender: Arc<dyn MailSender + 'a>,
+    frontend_url: &'a str,
+  ) -> DefaultMutVerificationService<'a> {
+    DefaultMutVerificationService {
+      account_repository,
+      mut_account_repository,
+      email_verification_repository,
+      mut_email_verification_repository,
+      mail_sender,
+      frontend_url,
+    }
+  }
+}
+
+#[async_trait]
+impl MutVerificationService for DefaultMutVerificationService<'_> {
+  async fn send(&self, user_id: u32) -> Result<(), ServiceError<MutVerificationServiceError>> {
+    let account = self
+      .account_repository
+      .get_by_user_id(user_id)
+      .await?
+      .ok_or(ClientError(MutVerificationServiceError::NonExistentAccount))?;
+    if account.verified {
+      return Err(ClientError(MutVerificationServiceError::AlreadyVerified));
+    }
+
+    self.mut_email_verification_repository.delete_by_user(user_id).await?;
+    let token = secret_token::generate();
+    self
+      .mut_email_verification_repository
+      .create(CreateEmailVerification {
+        user_id,
+        hash: secret_token::hash(&token),
+        expires: Utc::now().naive_utc() + Duration::hours(VERIFICATION_LIFETIME_HOURS),
+      })
+      .await?;
+
+    let mail = Mail {
+      to: account.email,
+      subject: "Verify your email".to_string(),
+      body: format!(
+        "Hello {},\n\nplease confirm your email by opening the following link within the next day:\n{}verify-email?token={token}",
+        account.user.name, self.frontend_url
+      ),
+    };
+    Ok(self.mail_sender.send(&mail).await?)
+  }
+
+  async fn verify(&self, token: &str) -> Result<u32, ServiceError<MutVerificationServiceError>> {
+    let verification = self
+      .email_verification_repository
+      .get_by_hash(&secret_token::hash(token))
+      .await?
+      .ok_or(ClientError(MutVerificationServiceError::InvalidToken))?;
+    self
+      .mut_email_verification_repository
+      .delete_by_user(verification.user_id)
+      .await?;
+    if verification.expires <= Utc::now().naive_utc() {
+      return Err(ClientError(MutVerificationServiceError::ExpiredToken));
+    }
+
+    self.mut_account_repository.set_verified(verification.user_id).await?;
+    Ok(verification.user_id)
+  }
+}
@@ -1,2 +1,29 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHasher};
+
+use domain::entities::account::Password;
+use services::traits::service_error::ServiceError;
+
+use crate::services::map_server_error;
+
+pub mod default_account_export_service;
 pub mod default_account_service;
+pub mod default_login_throttle_service;
 pub mod default_mut_account_service;
+pub mod default_mut_password_service;
+pub mod default_mut_refresh_token_service;
+pub mod default_mut_verification_service;
+pub mod personal_access_token_service;
+mod secret_token;
+pub mod two_factor_service;
+
+fn hash_password<T>(password: &str) -> Result<Password, ServiceError<T>> {
+  let salt = SaltString::generate(&mut OsRng);
+  let argon2 = Argon2::default();
+  let password_hash = argon2
+    .hash_password(password.as_bytes(), &salt)
+    .map_err(|y| map_server_error(Box::new(y)))?
+    .to_string();
+  Ok(Password(password_hash))
+}
@@ -0,0 +1,310 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use domain::entities::account::create_partial_personal_access_token::CreatePartialPersonalAccessToken;
+use domain::entities::account::create_personal_access_token::CreatePersonalAccessToken;
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use domain::entities::account::Account;
+use repositories::account_repository::personal_access_token_repository::mut_personal_access_token_repository::MutPersonalAccessTokenRepository;
+use repositories::account_repository::personal_access_token_repository::PersonalAccessTokenRepository;
+use repositories::account_repository::AccountRepository;
+use services::account_service::personal_access_token_service::mut_personal_access_token_service::{
+  MutPersonalAccessTokenService, MutPersonalAccessTokenServiceError, PERSONAL_ACCESS_TOKEN_PREFIX,
+};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+use crate::services::account_service::secret_token;
+
+const MAX_NAME_LENGTH: usize = 100;
+/// Keeps scripts from writing the token row on every request
+const LAST_USED_PRECISION_SECONDS: i64 = 60;
+
+pub struct DefaultMutPersonalAccessTokenService<'a> {
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository + 'a>,
+  mut_personal_access_token_repository: Arc<dyn MutPersonalAccessTokenRepository + 'a>,
+}
+
+impl<'a> DefaultMutPersonalAccessTokenService<'a> {
+  pub fn new(
+    account_repository: Arc<dyn AccountRepository + 'a>,
+    personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository + 'a>,
+    mut_personal_access_token_repository: Arc<dyn MutPersonalAccessTokenRepository + 'a>,
+  ) -> DefaultMutPersonalAccessTokenService<'a> {
+    DefaultMutPersonalAccessTokenService {
+      account_repository,
+      personal_access_token_repository,
+      mut_personal_access_token_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutPersonalAccessTokenService for DefaultMutPersonalAccessTokenService<'_> {
+  async fn create(
+    &self,
+    user_id: u32,
+    item: CreatePersonalAccessToken,
+  ) -> Result<(PersonalAccessToken, String), ServiceError<MutPersonalAccessTokenServiceError>> {
+    let name = item.name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+      return Err(ClientError(MutPersonalAccessTokenServiceError::InvalidName));
+    }
+    let mut scopes = item.scopes;
+    scopes.sort_by_key(ToString::to_string);
+    scopes.dedup();
+    if scopes.is_empty() {
+      return Err(ClientError(MutPersonalAccessTokenServiceError::NoScopes));
+    }
+
+    let token = format!("{PERSONAL_ACCESS_TOKEN_PREFIX}{}", secret_token::generate());
+    let created = self
+      .mut_personal_access_token_repository
+      .create(CreatePartialPersonalAccessToken {
+        user_id,
+        name,
+        scopes,
+        hash: secret_token::hash(&token),
+        created: Utc::now().naive_utc(),
+      })
+      .await?;
+    Ok((created, token))
+  }
+
+  async fn revoke(&self, user_id: u32, id: u32) -> Result<(), ServiceError<MutPersonalAccessTokenServiceError>> {
+    match self.mut_personal_access_token_repository.delete(user_id, id).await? {
+      true => Ok(()),
+      false => Err(ClientError(MutPersonalAccessTokenServiceError::NonExistentToken)),
+    }
+  }
+
+  async fn authenticate(
+    &self,
+    token: &str,
+  ) -> Result<(PersonalAccessToken, Account), ServiceError<MutPersonalAccessTokenServiceError>> {
+    let mut personal_access_token = self
+      .personal_access_token_repository
+      .get_by_hash(&secret_token::hash(token))
+      .await?
+      .ok_or(ClientError(MutPersonalAccessTokenServiceError::InvalidToken))?;
+    let account = self
+      .account_repository
+      .get_by_user_id(personal_access_token.user_id)
+      .await?
+      .ok_or(ClientError(MutPersonalAccessTokenServiceError::InvalidToken))?;
+
+    let now = Utc::now().naive_utc();
+    let outdated = personal_access_token
+      .last_used
+      .is_none_or(|x| now - x >= Duration::seconds(LAST_USED_PRECISION_SECONDS));
+    if outdated {
+      self
+        .mut_personal_access_token_repository
+        .set_last_used(personal_access_token.id)
+        .await?;
+      personal_access_token.last_used = Some(now);
+    }
+    Ok((personal_access_token, account))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::error::Error;
+  use std::sync::{Arc, Mutex};
+
+  use async_trait::async_trait;
+  use chrono::NaiveDate;
+
+  use domain::entities::account::create_partial_personal_access_token::CreatePartialPersonalAccessToken;
+  use domain::entities::account::create_personal_access_token::CreatePersonalAccessToken;
+  use domain::entities::account::personal_access_token::PersonalAccessToken;
+  use domain::entities::account::{Account, Email, Password};
+  use domain::entities::user::user_average::UserAverage;
+  use domain::entities::user::User;
+  use domain::enums::account_role::AccountRole;
+  use domain::enums::token_scope::TokenScope;
+  use domain::items_total::ItemsTotal;
+  use domain::pagination::Pagination;
+  use repositories::account_repository::personal_access_token_repository::mut_personal_access_token_repository::MutPersonalAccessTokenRepository;
+  use repositories::account_repository::personal_access_token_repository::PersonalAccessTokenRepository;
+  use repositories::account_repository::AccountRepository;
+  use services::account_service::personal_access_token_service::mut_personal_access_token_service::{
+    MutPersonalAccessTokenService, MutPersonalAccessTokenServiceError,
+  };
+  use services::traits::service_error::ServiceError;
+
+  use super::DefaultMutPersonalAccessTokenService;
+
+  /// Every user id has an account
+  struct FakeAccounts;
+
+  fn account(user_id: u32) -> Account {
+    Account {
+      user: User {
+        id: user_id,
+        name: format!("User {user_id}"),
+        joined: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        description: None,
+        deleted: false,
+        profile_picture: None,
+        averages: UserAverage::default(),
+      },
+      email: Email(format!("user{user_id}@example.com")),
+      password: Password(String::new()),
+      role: AccountRole::Member,
+      verified: true,
+    }
+  }
+
+  #[async_trait]
+  impl AccountRepository for FakeAccounts {
+    async fn get(&self, _: Pagination) -> Result<ItemsTotal<Account>, Box<dyn Error>> {
+      Ok(ItemsTotal {
+        items: vec![],
+        total: 0,
+      })
+    }
+    async fn get_by_user_id(&self, id: u32) -> Result<Option<Account>, Box<dyn Error>> {
+      Ok(Some(account(id)))
+    }
+    async fn get_by_user_ids(&self, ids: &[u32]) -> Result<Vec<Account>, Box<dyn Error>> {
+      Ok(ids.iter().map(|x| account(*x)).collect())
+    }
+    async fn get_by_email(&self, _: &Email) -> Result<Option<Account>, Box<dyn Error>> {
+      Ok(None)
+    }
+    async fn filter_existing(&self, users: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+      Ok(users.to_vec())
+    }
+  }
+
+  /// Keeps the tokens in memory together with their hash
+  #[derive(Default)]
+  struct FakePersonalAccessTokens(Mutex<Vec<(PersonalAccessToken, String)>>);
+
+  #[async_trait]
+  impl PersonalAccessTokenRepository for FakePersonalAccessTokens {
+    async fn get_by_user(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, Box<dyn Error>> {
+      let tokens = self.0.lock().unwrap();
+      Ok(
+        tokens
+          .iter()
+          .filter(|(x, _)| x.user_id == user_id)
+          .map(|(x, _)| x.clone())
+          .collect(),
+      )
+    }
+
+    async fn get_by_hash(&self, hash: &str) -> Result<Option<PersonalAccessToken>, Box<dyn Error>> {
+      let tokens = self.0.lock().unwrap();
+      Ok(tokens.iter().find(|(_, x)| x == hash).map(|(x, _)| x.clone()))
+    }
+  }
+
+  #[async_trait]
+  impl MutPersonalAccessTokenRepository for FakePersonalAccessTokens {
+    async fn create(&self, token: CreatePartialPersonalAccessToken) -> Result<PersonalAccessToken, Box<dyn Error>> {
+      let mut tokens = self.0.lock().unwrap();
+      let created = PersonalAccessToken {
+        id: tokens.iter().map(|(x, _)| x.id).max().unwrap_or_default() + 1,
+        user_id: token.user_id,
+        name: token.name,
+        scopes: token.scopes,
+        created: token.created,
+        last_used: None,
+      };
+      tokens.push((created.clone(), token.hash));
+      Ok(created)
+    }
+
+    async fn delete(&self, user_id: u32, id: u32) -> Result<bool, Box<dyn Error>> {
+      let mut tokens = self.0.lock().unwrap();
+      let count = tokens.len();
+      tokens.retain(|(x, _)| x.user_id != user_id || x.id != id);
+      Ok(tokens.len() != count)
+    }
+
+    async fn set_last_used(&self, _: u32) -> Result<(), Box<dyn Error>> {
+      Ok(())
+    }
+  }
+
+  fn service() -> DefaultMutPersonalAccessTokenService<'static> {
+    let tokens = Arc::new(FakePersonalAccessTokens::default());
+    DefaultMutPersonalAccessTokenService::new(Arc::new(FakeAccounts), tokens.clone(), tokens)
+  }
+
+  fn create_item(scopes: Vec<TokenScope>) -> CreatePersonalAccessToken {
+    CreatePersonalAccessToken {
+      name: "Script".to_string(),
+      scopes,
+    }
+  }
+
+  fn is_invalid<T>(result: Result<T, ServiceError<MutPersonalAccessTokenServiceError>>) -> bool {
+    matches!(
+      result,
+      Err(ServiceError::ClientError(
+        MutPersonalAccessTokenServiceError::InvalidToken
+      ))
+    )
+  }
+
+  #[tokio::test]
+  async fn authenticate_returns_only_the_granted_scopes() {
+    let service = service();
+    let (_, token) = service
+      .create(1, create_item(vec![TokenScope::LibraryWrite]))
+      .await
+      .ok()
+      .unwrap();
+
+    let (authenticated, account) = service.authenticate(&token).await.ok().unwrap();
+    assert_eq!(account.user.id, 1);
+    assert_eq!(authenticated.scopes, vec![TokenScope::LibraryWrite]);
+    assert!(!authenticated.scopes.contains(&TokenScope::CatalogueWrite));
+    assert!(authenticated.last_used.is_some());
+  }
+
+  #[tokio::test]
+  async fn revoked_token_is_rejected() {
+    let service = service();
+    let (created, token) = service
+      .create(1, create_item(vec![TokenScope::LibraryWrite]))
+      .await
+      .ok()
+      .unwrap();
+
+    assert!(service.revoke(1, created.id).await.is_ok());
+    assert!(is_invalid(service.authenticate(&token).await));
+    assert!(matches!(
+      service.revoke(1, created.id).await,
+      Err(ServiceError::ClientError(
+        MutPersonalAccessTokenServiceError::NonExistentToken
+      ))
+    ));
+  }
+
+  #[tokio::test]
+  async fn revoke_rejects_token_of_other_user() {
+    let service = service();
+    let (created, token) = service
+      .create(2, create_item(vec![TokenScope::FriendsRead]))
+      .await
+      .ok()
+      .unwrap();
+
+    assert!(service.revoke(1, created.id).await.is_err());
+    assert!(service.authenticate(&token).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn unknown_token_is_rejected() {
+    let service = service();
+    assert!(is_invalid(service.authenticate("pat_unknown").await));
+  }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use repositories::account_repository::personal_access_token_repository::PersonalAccessTokenRepository;
+use services::account_service::personal_access_token_service::{
+  PersonalAccessTokenService, PersonalAccessTokenServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultPersonalAccessTokenService<'a> {
+  personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository + 'a>,
+}
+
+impl<'a> DefaultPersonalAccessTokenService<'a> {
+  pub fn new(
+    personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository + 'a>,
+  ) -> DefaultPersonalAccessTokenService<'a> {
+    DefaultPersonalAccessTokenService {
+      personal_access_token_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl PersonalAccessTokenService for DefaultPersonalAccessTokenService<'_> {
+  async fn get(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, ServiceError<PersonalAccessTokenServiceError>> {
+    Ok(self.personal_access_token_repository.get_by_user(user_id).await?)
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_mut_personal_access_token_service;
+pub mod default_personal_access_token_service;
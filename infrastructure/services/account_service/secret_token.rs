@@ -0,0 +1,25 @@
+use std::fmt::Write;
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// Creates a random token that is only handed out once and stored as its hash.
+pub fn generate() -> String {
+  let mut bytes = [0u8; 32];
+  OsRng.fill_bytes(&mut bytes);
+  to_hex(&bytes)
+}
+
+/// The tokens are random, so a fast hash is enough to keep them unusable if the table leaks.
+pub fn hash(token: &str) -> String {
+  to_hex(&Sha256::digest(token.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+  bytes
+    .iter()
+    .fold(String::with_capacity(bytes.len() * 2), |mut output, x| {
+      let _ = write!(output, "{x:02x}");
+      output
+    })
+}
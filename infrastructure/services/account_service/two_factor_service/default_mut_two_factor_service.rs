@@ -0,0 +1,496 @@
+use std::sync::Arc;
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use domain::entities::account::create_two_factor::CreateTwoFactor;
+use domain::entities::account::two_factor::TwoFactor;
+use domain::entities::account::two_factor_enrollment::TwoFactorEnrollment;
+use repositories::account_repository::two_factor_repository::mut_two_factor_repository::MutTwoFactorRepository;
+use repositories::account_repository::two_factor_repository::TwoFactorRepository;
+use repositories::account_repository::AccountRepository;
+use services::account_service::login_throttle_service::{LoginKey, LoginThrottleService, LoginThrottleServiceError};
+use services::account_service::two_factor_service::mut_two_factor_service::{
+  MutTwoFactorService, MutTwoFactorServiceError,
+};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+use crate::clock::Clock;
+use crate::services::account_service::secret_token;
+use crate::services::map_server_error;
+
+const ISSUER: &str = "MyCollection";
+const SECRET_BYTES: usize = 20;
+const STEP_SECONDS: u64 = 30;
+const RECOVERY_CODE_COUNT: usize = 10;
+/// Hex characters of a recovery code, 20 of them are 80 bits
+const RECOVERY_CODE_LENGTH: usize = 20;
+const RECOVERY_CODE_GROUP: usize = 5;
+
+pub struct DefaultMutTwoFactorService<'a> {
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  two_factor_repository: Arc<dyn TwoFactorRepository + 'a>,
+  mut_two_factor_repository: Arc<dyn MutTwoFactorRepository + 'a>,
+  login_throttle_service: Arc<dyn LoginThrottleService + 'a>,
+  clock: Arc<dyn Clock + 'a>,
+}
+
+impl<'a> DefaultMutTwoFactorService<'a> {
+  pub fn new(
+    account_repository: Arc<dyn AccountRepository + 'a>,
+    two_factor_repository: Arc<dyn TwoFactorRepository + 'a>,
+    mut_two_factor_repository: Arc<dyn MutTwoFactorRepository + 'a>,
+    login_throttle_service: Arc<dyn LoginThrottleService + 'a>,
+    clock: Arc<dyn Clock + 'a>,
+  ) -> DefaultMutTwoFactorService<'a> {
+    DefaultMutTwoFactorService {
+      account_repository,
+      two_factor_repository,
+      mut_two_factor_repository,
+      login_throttle_service,
+      clock,
+    }
+  }
+}
+
+#[async_trait]
+impl MutTwoFactorService for DefaultMutTwoFactorService<'_> {
+  async fn enroll(&self, user_id: u32) -> Result<TwoFactorEnrollment, ServiceError<MutTwoFactorServiceError>> {
+    let account = self
+      .account_repository
+      .get_by_user_id(user_id)
+      .await?
+      .ok_or(ClientError(MutTwoFactorServiceError::NonExistentAccount))?;
+    let existing = self.two_factor_repository.get(user_id).await?;
+    if let Some(two_factor) = existing {
+      if two_factor.confirmed {
+        return Err(ClientError(MutTwoFactorServiceError::AlreadyEnabled));
+      }
+      self.mut_two_factor_repository.delete(user_id).await?;
+    }
+
+    let mut secret = [0u8; SECRET_BYTES];
+    OsRng.fill_bytes(&mut secret);
+    let totp = TOTP::new(
+      Algorithm::SHA1,
+      6,
+      0,
+      STEP_SECONDS,
+      secret.to_vec(),
+      Some(ISSUER.to_string()),
+      account.email.0,
+    )
+    .map_err(|x| map_server_error(Box::new(x)))?;
+    let secret = totp.get_secret_base32();
+    self
+      .mut_two_factor_repository
+      .create(CreateTwoFactor {
+        user_id,
+        secret: secret.clone(),
+      })
+      .await?;
+    Ok(TwoFactorEnrollment {
+      secret,
+      uri: totp.get_url(),
+    })
+  }
+
+  async fn confirm(&self, user_id: u32, code: &str) -> Result<Vec<String>, ServiceError<MutTwoFactorServiceError>> {
+    let two_factor = self
+      .two_factor_repository
+      .get(user_id)
+      .await?
+      .ok_or(ClientError(MutTwoFactorServiceError::NotEnrolled))?;
+    if two_factor.confirmed {
+      return Err(ClientError(MutTwoFactorServiceError::AlreadyEnabled));
+    }
+    let step =
+      matching_step(&two_factor, code, self.clock.now())?.ok_or(ClientError(MutTwoFactorServiceError::InvalidCode))?;
+    self.mut_two_factor_repository.confirm(user_id).await?;
+    self.mut_two_factor_repository.set_last_step(user_id, step).await?;
+
+    let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+      .map(|_| {
+        let code = &secret_token::generate()[..RECOVERY_CODE_LENGTH];
+        code
+          .as_bytes()
+          .chunks(RECOVERY_CODE_GROUP)
+          .map(|x| String::from_utf8_lossy(x))
+          .collect::<Vec<_>>()
+          .join("-")
+      })
+      .collect();
+    let hashes: Vec<String> = codes.iter().map(|x| hash_recovery_code(x)).collect();
+    self
+      .mut_two_factor_repository
+      .set_recovery_codes(user_id, &hashes)
+      .await?;
+    Ok(codes)
+  }
+
+  async fn disable(&self, user_id: u32, code: &str) -> Result<(), ServiceError<MutTwoFactorServiceError>> {
+    self.verify(user_id, code).await?;
+    self.mut_two_factor_repository.delete(user_id).await?;
+    Ok(())
+  }
+
+  async fn verify(&self, user_id: u32, code: &str) -> Result<(), ServiceError<MutTwoFactorServiceError>> {
+    let keys = [LoginKey::SecondFactor(user_id)];
+    self
+      .login_throttle_service
+      .check(&keys)
+      .await
+      .map_err(map_throttle_error)?;
+    let two_factor = self
+      .two_factor_repository
+      .get(user_id)
+      .await?
+      .filter(|x| x.confirmed)
+      .ok_or(ClientError(MutTwoFactorServiceError::NotEnabled))?;
+
+    let step = matching_step(&two_factor, code, self.clock.now())?;
+    let valid = match step {
+      Some(step) => {
+        self.mut_two_factor_repository.set_last_step(user_id, step).await?;
+        true
+      }
+      None => {
+        self
+          .mut_two_factor_repository
+          .delete_recovery_code(user_id, &hash_recovery_code(code))
+          .await?
+      }
+    };
+    if !valid {
+      self
+        .login_throttle_service
+        .failed(&keys)
+        .await
+        .map_err(map_throttle_error)?;
+      return Err(ClientError(MutTwoFactorServiceError::InvalidCode));
+    }
+    self
+      .login_throttle_service
+      .succeeded(&keys)
+      .await
+      .map_err(map_throttle_error)
+  }
+}
+
+/// Returns the time step the code belongs to, one step of clock drift is accepted in both directions.
+/// Steps up to the last accepted one are rejected, so a code can not be used twice
+fn matching_step(
+  two_factor: &TwoFactor,
+  code: &str,
+  now: NaiveDateTime,
+) -> Result<Option<u64>, ServiceError<MutTwoFactorServiceError>> {
+  let secret = Secret::Encoded(two_factor.secret.clone())
+    .to_bytes()
+    .map_err(|x| map_server_error(Box::new(x)))?;
+  let totp = TOTP::new(Algorithm::SHA1, 6, 0, STEP_SECONDS, secret, None, String::new())
+    .map_err(|x| map_server_error(Box::new(x)))?;
+  let code = code.trim();
+  let current = now.and_utc().timestamp() as u64 / STEP_SECONDS;
+  let step = (current.saturating_sub(1)..=current + 1)
+    .filter(|x| two_factor.last_step.is_none_or(|y| *x > y))
+    .find(|x| totp.check(code, x * STEP_SECONDS));
+  Ok(step)
+}
+
+/// Dashes and case are ignored, so codes can be entered as they were shown or typed
+fn hash_recovery_code(code: &str) -> String {
+  let code: String = code
+    .chars()
+    .filter(char::is_ascii_alphanumeric)
+    .map(|x| x.to_ascii_lowercase())
+    .collect();
+  secret_token::hash(&code)
+}
+
+fn map_throttle_error(error: ServiceError<LoginThrottleServiceError>) -> ServiceError<MutTwoFactorServiceError> {
+  match error {
+    ClientError(LoginThrottleServiceError::Locked(x)) => ClientError(MutTwoFactorServiceError::TooManyAttempts(x)),
+    ServerError(x) => ServerError(x),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::error::Error;
+  use std::sync::{Arc, Mutex};
+
+  use async_trait::async_trait;
+  use chrono::Duration;
+  use totp_rs::{Algorithm, Secret, TOTP};
+
+  use domain::entities::account::create_two_factor::CreateTwoFactor;
+  use domain::entities::account::two_factor::TwoFactor;
+  use domain::entities::account::{Account, Email};
+  use domain::items_total::ItemsTotal;
+  use domain::pagination::Pagination;
+  use repositories::account_repository::two_factor_repository::mut_two_factor_repository::MutTwoFactorRepository;
+  use repositories::account_repository::two_factor_repository::TwoFactorRepository;
+  use repositories::account_repository::AccountRepository;
+  use services::account_service::two_factor_service::mut_two_factor_service::{
+    MutTwoFactorService, MutTwoFactorServiceError,
+  };
+  use services::traits::service_error::ServiceError;
+
+  use crate::clock::{Clock, FakeClock};
+  use crate::services::account_service::default_login_throttle_service::{
+    DefaultLoginThrottleService, LoginThrottleConfig,
+  };
+
+  use super::{DefaultMutTwoFactorService, STEP_SECONDS};
+
+  const USER_ID: u32 = 1;
+  const SECRET: [u8; 20] = [7; 20];
+
+  /// Only the second factor is tested, enrolling needs an account and is left out
+  struct NoAccounts;
+
+  #[async_trait]
+  impl AccountRepository for NoAccounts {
+    async fn get(&self, _: Pagination) -> Result<ItemsTotal<Account>, Box<dyn Error>> {
+      Ok(ItemsTotal {
+        items: vec![],
+        total: 0,
+      })
+    }
+    async fn get_by_user_id(&self, _: u32) -> Result<Option<Account>, Box<dyn Error>> {
+      Ok(None)
+    }
+    async fn get_by_user_ids(&self, _: &[u32]) -> Result<Vec<Account>, Box<dyn Error>> {
+      Ok(vec![])
+    }
+    async fn get_by_email(&self, _: &Email) -> Result<Option<Account>, Box<dyn Error>> {
+      Ok(None)
+    }
+    async fn filter_existing(&self, _: &[u32]) -> Result<Vec<u32>, Box<dyn Error>> {
+      Ok(vec![])
+    }
+  }
+
+  /// Keeps the second factor of a single user and the hashes of its recovery codes in memory
+  #[derive(Default)]
+  struct FakeTwoFactors {
+    two_factor: Mutex<Option<TwoFactor>>,
+    recovery_codes: Mutex<Vec<String>>,
+  }
+
+  impl FakeTwoFactors {
+    fn update(&self, update: impl FnOnce(&mut TwoFactor)) {
+      update(
+        self
+          .two_factor
+          .lock()
+          .unwrap()
+          .as_mut()
+          .expect("Second factor should exist"),
+      );
+    }
+  }
+
+  #[async_trait]
+  impl TwoFactorRepository for FakeTwoFactors {
+    async fn get(&self, _: u32) -> Result<Option<TwoFactor>, Box<dyn Error>> {
+      Ok(self.two_factor.lock().unwrap().clone())
+    }
+  }
+
+  #[async_trait]
+  impl MutTwoFactorRepository for FakeTwoFactors {
+    async fn create(&self, two_factor: CreateTwoFactor) -> Result<(), Box<dyn Error>> {
+      *self.two_factor.lock().unwrap() = Some(TwoFactor {
+        user_id: two_factor.user_id,
+        secret: two_factor.secret,
+        confirmed: false,
+        last_step: None,
+      });
+      Ok(())
+    }
+    async fn confirm(&self, _: u32) -> Result<(), Box<dyn Error>> {
+      self.update(|x| x.confirmed = true);
+      Ok(())
+    }
+    async fn set_last_step(&self, _: u32, step: u64) -> Result<(), Box<dyn Error>> {
+      self.update(|x| x.last_step = Some(step));
+      Ok(())
+    }
+    async fn delete(&self, _: u32) -> Result<(), Box<dyn Error>> {
+      *self.two_factor.lock().unwrap() = None;
+      self.recovery_codes.lock().unwrap().clear();
+      Ok(())
+    }
+    async fn set_recovery_codes(&self, _: u32, hashes: &[String]) -> Result<(), Box<dyn Error>> {
+      *self.recovery_codes.lock().unwrap() = hashes.to_vec();
+      Ok(())
+    }
+    async fn delete_recovery_code(&self, _: u32, hash: &str) -> Result<bool, Box<dyn Error>> {
+      let mut codes = self.recovery_codes.lock().unwrap();
+      let count = codes.len();
+      codes.retain(|x| x != hash);
+      Ok(codes.len() < count)
+    }
+  }
+
+  struct Setup {
+    service: DefaultMutTwoFactorService<'static>,
+    two_factors: Arc<FakeTwoFactors>,
+    clock: Arc<FakeClock>,
+  }
+
+  impl Setup {
+    /// Code of the step that is `offset` steps away from the current one
+    fn code(&self, offset: i64) -> String {
+      let totp = TOTP::new(
+        Algorithm::SHA1,
+        6,
+        0,
+        STEP_SECONDS,
+        SECRET.to_vec(),
+        None,
+        String::new(),
+      )
+      .unwrap();
+      let time = self.clock.now().and_utc().timestamp() + offset * STEP_SECONDS as i64;
+      totp.generate(time as u64)
+    }
+  }
+
+  /// Starts with an enrolled but unconfirmed second factor
+  async fn setup() -> Setup {
+    let clock = Arc::new(FakeClock::at(1_700_000_010));
+    let two_factors = Arc::new(FakeTwoFactors::default());
+    two_factors
+      .create(CreateTwoFactor {
+        user_id: USER_ID,
+        secret: Secret::Raw(SECRET.to_vec()).to_encoded().to_string(),
+      })
+      .await
+      .unwrap();
+    let login_throttle_service = Arc::new(DefaultLoginThrottleService::new(
+      LoginThrottleConfig::default(),
+      clock.clone(),
+    ));
+    let service = DefaultMutTwoFactorService::new(
+      Arc::new(NoAccounts),
+      two_factors.clone(),
+      two_factors.clone(),
+      login_throttle_service,
+      clock.clone(),
+    );
+    Setup {
+      service,
+      two_factors,
+      clock,
+    }
+  }
+
+  /// Confirms the second factor and moves on to the next step, so the confirming code is not reused
+  async fn confirmed() -> (Setup, Vec<String>) {
+    let setup = setup().await;
+    let codes = setup.service.confirm(USER_ID, &setup.code(0)).await.ok().unwrap();
+    setup.clock.advance(Duration::seconds(STEP_SECONDS as i64));
+    (setup, codes)
+  }
+
+  fn is_invalid<T>(result: Result<T, ServiceError<MutTwoFactorServiceError>>) -> bool {
+    matches!(
+      result,
+      Err(ServiceError::ClientError(MutTwoFactorServiceError::InvalidCode))
+    )
+  }
+
+  #[tokio::test]
+  async fn confirm_enables_second_factor() {
+    let setup = setup().await;
+    let codes = setup.service.confirm(USER_ID, &setup.code(0)).await.ok().unwrap();
+
+    let two_factor = setup.two_factors.get(USER_ID).await.unwrap().unwrap();
+    assert!(two_factor.confirmed);
+    assert!(two_factor.last_step.is_some());
+    assert_eq!(codes.len(), 10);
+    assert_eq!(setup.two_factors.recovery_codes.lock().unwrap().len(), 10);
+    assert!(matches!(
+      setup.service.confirm(USER_ID, &setup.code(0)).await,
+      Err(ServiceError::ClientError(MutTwoFactorServiceError::AlreadyEnabled))
+    ));
+  }
+
+  #[tokio::test]
+  async fn confirm_rejects_wrong_code() {
+    let setup = setup().await;
+    assert!(is_invalid(setup.service.confirm(USER_ID, &setup.code(5)).await));
+    assert!(!setup.two_factors.get(USER_ID).await.unwrap().unwrap().confirmed);
+  }
+
+  #[tokio::test]
+  async fn verify_requires_confirmation() {
+    let setup = setup().await;
+    assert!(matches!(
+      setup.service.verify(USER_ID, &setup.code(0)).await,
+      Err(ServiceError::ClientError(MutTwoFactorServiceError::NotEnabled))
+    ));
+  }
+
+  #[tokio::test]
+  async fn verify_accepts_one_step_of_drift() {
+    let (setup, _) = confirmed().await;
+    assert!(setup.service.verify(USER_ID, &setup.code(-1)).await.is_err());
+
+    setup.clock.advance(Duration::seconds(STEP_SECONDS as i64 * 2));
+    assert!(setup.service.verify(USER_ID, &setup.code(-1)).await.is_ok());
+    assert!(setup.service.verify(USER_ID, &setup.code(1)).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn verify_rejects_codes_past_drift() {
+    let (setup, _) = confirmed().await;
+    setup.clock.advance(Duration::seconds(STEP_SECONDS as i64 * 2));
+    assert!(is_invalid(setup.service.verify(USER_ID, &setup.code(-2)).await));
+    assert!(is_invalid(setup.service.verify(USER_ID, &setup.code(2)).await));
+  }
+
+  #[tokio::test]
+  async fn verify_rejects_replayed_code() {
+    let (setup, _) = confirmed().await;
+    let code = setup.code(0);
+    assert!(setup.service.verify(USER_ID, &code).await.is_ok());
+    assert!(is_invalid(setup.service.verify(USER_ID, &code).await));
+
+    setup.clock.advance(Duration::seconds(STEP_SECONDS as i64));
+    assert!(is_invalid(setup.service.verify(USER_ID, &code).await));
+    assert!(setup.service.verify(USER_ID, &setup.code(0)).await.is_ok());
+  }
+
+  #[tokio::test]
+  async fn recovery_code_is_single_use() {
+    let (setup, codes) = confirmed().await;
+    assert!(setup.service.verify(USER_ID, &codes[0]).await.is_ok());
+    assert!(is_invalid(setup.service.verify(USER_ID, &codes[0]).await));
+    assert!(setup
+      .service
+      .verify(USER_ID, &codes[1].replace('-', "").to_uppercase())
+      .await
+      .is_ok());
+    assert_eq!(setup.two_factors.recovery_codes.lock().unwrap().len(), 8);
+  }
+
+  #[tokio::test]
+  async fn recovery_codes_have_80_bits() {
+    let (_, codes) = confirmed().await;
+    for code in &codes {
+      let digits: Vec<char> = code.chars().filter(|x| *x != '-').collect();
+      assert_eq!(digits.len(), 20);
+      assert!(digits.iter().all(char::is_ascii_hexdigit));
+    }
+    let mut unique = codes.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), codes.len());
+  }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::account_repository::two_factor_repository::TwoFactorRepository;
+use services::account_service::two_factor_service::{TwoFactorService, TwoFactorServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultTwoFactorService<'a> {
+  two_factor_repository: Arc<dyn TwoFactorRepository + 'a>,
+}
+
+impl<'a> DefaultTwoFactorService<'a> {
+  pub fn new(two_factor_repository: Arc<dyn TwoFactorRepository + 'a>) -> DefaultTwoFactorService<'a> {
+    DefaultTwoFactorService { two_factor_repository }
+  }
+}
+
+#[async_trait]
+impl TwoFactorService for DefaultTwoFactorService<'_> {
+  async fn is_enabled(&self, user_id: u32) -> Result<bool, ServiceError<TwoFactorServiceError>> {
+    let two_factor = self.two_factor_repository.get(user_id).await?;
+    Ok(two_factor.is_some_and(|x| x.confirmed))
+  }
+}
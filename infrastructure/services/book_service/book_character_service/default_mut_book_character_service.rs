@@ -54,14 +54,6 @@ impl DefaultMutBookCharacterService<'_> {
     characters: &[u32],
   ) -> Result<(), ServiceError<MutBookCharacterServiceError>> {
     self.validate(book_id, characters).await?;
-    let associated = self
-      .book_character_repository
-      .filter_existing(book_id, characters)
-      .await?;
-    if !associated.is_empty() {
-      let error = MutBookCharacterServiceError::AlreadyAssociated(associated);
-      return Err(ServiceError::ClientError(error));
-    };
     let existing_characters = self.character_repository.filter_existing(characters).await?;
     if existing_characters.len() != characters.len() {
       let non_existent_characters = filter_non_existent(characters, &existing_characters);
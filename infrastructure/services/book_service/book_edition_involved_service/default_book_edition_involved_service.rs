@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition_involved::BookEditionInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::book_repository::book_edition_involved_repository::BookEditionInvolvedRepository;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use services::book_service::book_edition_involved_service::{
+  BookEditionInvolvedService, BookEditionInvolvedServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultBookEditionInvolvedService<'a> {
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  book_edition_involved_repository: Arc<dyn BookEditionInvolvedRepository + 'a>,
+}
+
+impl<'a> DefaultBookEditionInvolvedService<'a> {
+  pub fn new(
+    book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+    book_edition_involved_repository: Arc<dyn BookEditionInvolvedRepository + 'a>,
+  ) -> DefaultBookEditionInvolvedService<'a> {
+    DefaultBookEditionInvolvedService {
+      book_edition_repository,
+      book_edition_involved_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl BookEditionInvolvedService for DefaultBookEditionInvolvedService<'_> {
+  async fn get(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEditionInvolved>, ServiceError<BookEditionInvolvedServiceError>> {
+    let ids = self
+      .book_edition_repository
+      .filter_existing(book_id, &[edition_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        BookEditionInvolvedServiceError::NonExistentEdition(edition_id),
+      ));
+    }
+    Ok(
+      self
+        .book_edition_involved_repository
+        .get(edition_id, language, pagination)
+        .await?,
+    )
+  }
+}
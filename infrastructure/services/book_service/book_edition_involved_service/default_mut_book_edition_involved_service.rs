@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+use repositories::book_repository::book_edition_involved_repository::mut_book_edition_involved_repository::MutBookEditionInvolvedRepository;
+use repositories::book_repository::book_edition_involved_repository::BookEditionInvolvedRepository;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use repositories::person_repository::PersonRepository;
+use repositories::role_repository::RoleRepository;
+use services::book_service::book_edition_involved_service::mut_book_edition_involved_service::{
+  MutBookEditionInvolvedService, MutBookEditionInvolvedServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutBookEditionInvolvedService<'a> {
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  book_edition_involved_repository: Arc<dyn BookEditionInvolvedRepository + 'a>,
+  mut_book_edition_involved_repository: Arc<dyn MutBookEditionInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+}
+
+impl<'a> DefaultMutBookEditionInvolvedService<'a> {
+  pub fn new(
+    book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+    book_edition_involved_repository: Arc<dyn BookEditionInvolvedRepository + 'a>,
+    mut_book_edition_involved_repository: Arc<dyn MutBookEditionInvolvedRepository + 'a>,
+    person_repository: Arc<dyn PersonRepository + 'a>,
+    role_repository: Arc<dyn RoleRepository + 'a>,
+  ) -> DefaultMutBookEditionInvolvedService<'a> {
+    DefaultMutBookEditionInvolvedService {
+      book_edition_repository,
+      book_edition_involved_repository,
+      mut_book_edition_involved_repository,
+      person_repository,
+      role_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutBookEditionInvolvedService for DefaultMutBookEditionInvolvedService<'_> {
+  async fn add(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>> {
+    self.validate_add(book_id, edition_id, involved).await?;
+    Ok(
+      self
+        .mut_book_edition_involved_repository
+        .add(edition_id, involved)
+        .await?,
+    )
+  }
+
+  async fn remove(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>> {
+    self.validate_remove(book_id, edition_id, involved).await?;
+    Ok(
+      self
+        .mut_book_edition_involved_repository
+        .remove(edition_id, involved)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutBookEditionInvolvedService<'_> {
+  async fn validate_add(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>> {
+    self.validate(book_id, edition_id, involved).await?;
+    let people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+    let existing_people = self.person_repository.filter_existing(&people).await?;
+
+    if existing_people.len() != involved.len() {
+      let non_existent_people = filter_non_existent(&people, &existing_people);
+      return Err(ServiceError::ClientError(
+        MutBookEditionInvolvedServiceError::NonExistentPeople(non_existent_people),
+      ));
+    };
+    let roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+    let existing_roles = self.role_repository.filter_existing(&roles).await?;
+    if existing_roles.len() != involved.len() {
+      let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+      return Err(ServiceError::ClientError(
+        MutBookEditionInvolvedServiceError::NonExistentRoles(non_existent_roles),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>> {
+    self.validate(book_id, edition_id, involved).await?;
+    let existing = self
+      .book_edition_involved_repository
+      .filter_existing(edition_id, involved)
+      .await?;
+    if existing.len() != involved.len() {
+      let non_existent_involved = filter_involved_non_existent(involved, &existing);
+      return Err(ServiceError::ClientError(
+        MutBookEditionInvolvedServiceError::NonExistentAssociation(non_existent_involved),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>> {
+    let ids = self
+      .book_edition_repository
+      .filter_existing(book_id, &[edition_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutBookEditionInvolvedServiceError::NonExistentEdition(edition_id),
+      ));
+    }
+    if involved.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutBookEditionInvolvedServiceError::NoInvolvedProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn filter_involved_non_existent(items: &[InvolvedId], existing: &[InvolvedId]) -> Vec<InvolvedId> {
+  items
+    .iter()
+    .filter_map(|x| {
+      existing
+        .iter()
+        .find(|y| y.role_id == x.role_id && y.person_id == x.person_id)
+        .map_or_else(|| Some(x.clone()), |_| None)
+    })
+    .collect()
+}
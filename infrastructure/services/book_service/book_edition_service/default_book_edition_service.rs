@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::enums::language::Language;
+use domain::isbn13::Isbn13;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use services::book_service::book_edition_service::{BookEditionService, BookEditionServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultBookEditionService<'a> {
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+}
+
+impl<'a> DefaultBookEditionService<'a> {
+  pub fn new(book_edition_repository: Arc<dyn BookEditionRepository + 'a>) -> DefaultBookEditionService<'a> {
+    DefaultBookEditionService {
+      book_edition_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl BookEditionService for DefaultBookEditionService<'_> {
+  async fn get(
+    &self,
+    book_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEdition>, ServiceError<BookEditionServiceError>> {
+    Ok(self.book_edition_repository.get(book_id, language, pagination).await?)
+  }
+
+  async fn get_by_id(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    language: Language,
+  ) -> Result<Option<BookEdition>, ServiceError<BookEditionServiceError>> {
+    Ok(
+      self
+        .book_edition_repository
+        .get_by_id(book_id, edition_id, language)
+        .await?,
+    )
+  }
+
+  async fn get_by_isbn13(
+    &self,
+    isbn13: &Isbn13,
+    language: Language,
+  ) -> Result<Option<BookEdition>, ServiceError<BookEditionServiceError>> {
+    Ok(self.book_edition_repository.get_by_isbn13(isbn13, language).await?)
+  }
+}
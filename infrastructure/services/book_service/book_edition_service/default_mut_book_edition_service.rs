@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::entities::book::create_book_edition::{CreateBookEdition, CreateBookEditionData};
+use domain::entities::book::create_partial_book_edition::{
+  CreatePartialBookEdition, CreatePartialBookEditionTranslation,
+};
+use domain::entities::book::patch_book_edition::PatchBookEdition;
+use domain::entities::image::create_image::CreateImage;
+use domain::enums::language::Language;
+use domain::isbn13::Isbn13;
+use repositories::book_repository::book_edition_repository::mut_book_edition_repository::MutBookEditionRepository;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use repositories::book_repository::BookRepository;
+use repositories::person_repository::PersonRepository;
+use repositories::role_repository::RoleRepository;
+use services::book_service::book_edition_service::mut_book_edition_service::MutBookEditionServiceError::OtherError;
+use services::book_service::book_edition_service::mut_book_edition_service::{
+  MutBookEditionService, MutBookEditionServiceError,
+};
+use services::image_service::mut_image_service::MutImageService;
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+pub struct DefaultMutBookEditionService<'a> {
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  mut_book_edition_repository: Arc<dyn MutBookEditionRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+}
+
+impl<'a> DefaultMutBookEditionService<'a> {
+  pub fn new(
+    book_repository: Arc<dyn BookRepository + 'a>,
+    book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+    mut_book_edition_repository: Arc<dyn MutBookEditionRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+    person_repository: Arc<dyn PersonRepository + 'a>,
+    role_repository: Arc<dyn RoleRepository + 'a>,
+  ) -> DefaultMutBookEditionService<'a> {
+    DefaultMutBookEditionService {
+      book_repository,
+      book_edition_repository,
+      mut_book_edition_repository,
+      mut_image_service,
+      person_repository,
+      role_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutBookEditionService for DefaultMutBookEditionService<'_> {
+  async fn create(
+    &self,
+    book_id: u32,
+    item: CreateBookEdition,
+  ) -> Result<BookEdition, ServiceError<MutBookEditionServiceError>> {
+    self.validate_create(book_id, &item).await?;
+    let data = item.edition;
+    let mut covers = item.covers;
+    let cover = self
+      .mut_image_service
+      .create(covers.remove(data.cover))
+      .await
+      .map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?;
+    let translations: HashMap<Language, CreatePartialBookEditionTranslation> = data
+      .translations
+      .into_iter()
+      .map(|(language, translation)| {
+        (
+          language,
+          CreatePartialBookEditionTranslation {
+            description: translation.description,
+          },
+        )
+      })
+      .collect();
+
+    let partial_edition = CreatePartialBookEdition {
+      chapters: data.chapters,
+      pages: data.pages,
+      words: data.words,
+      published: data.published,
+      isbn13: data.isbn13,
+      language: data.language,
+      translations,
+      involved: data.involved.unwrap_or_default(),
+      cover,
+    };
+    Ok(
+      self
+        .mut_book_edition_repository
+        .create(book_id, partial_edition)
+        .await?,
+    )
+  }
+
+  async fn update(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    item: PatchBookEdition,
+  ) -> Result<BookEdition, ServiceError<MutBookEditionServiceError>> {
+    let existing = self
+      .book_edition_repository
+      .filter_existing(book_id, &[edition_id])
+      .await?;
+    if existing.is_empty() {
+      return Err(ClientError(MutBookEditionServiceError::NonExistentEdition(edition_id)));
+    }
+    if let Some(translations) = &item.translations {
+      validate_descriptions(translations.values().map(|x| &x.description))?;
+    }
+    if let Some(isbn13) = &item.isbn13 {
+      self.validate_isbn13(isbn13, Some(edition_id)).await?;
+    }
+    Ok(
+      self
+        .mut_book_edition_repository
+        .update(book_id, edition_id, &item)
+        .await?,
+    )
+  }
+
+  async fn delete(&self, book_id: u32, edition_id: u32) -> Result<(), ServiceError<MutBookEditionServiceError>> {
+    let existing = self
+      .book_edition_repository
+      .filter_existing(book_id, &[edition_id])
+      .await?;
+    if existing.is_empty() {
+      return Err(ClientError(MutBookEditionServiceError::NonExistentEdition(edition_id)));
+    }
+    Ok(self.mut_book_edition_repository.delete(&[edition_id]).await?)
+  }
+}
+
+impl DefaultMutBookEditionService<'_> {
+  async fn validate_create(
+    &self,
+    book_id: u32,
+    item: &CreateBookEdition,
+  ) -> Result<(), ServiceError<MutBookEditionServiceError>> {
+    let ids = self.book_repository.filter_existing(&[book_id]).await?;
+    if ids.is_empty() {
+      return Err(ClientError(MutBookEditionServiceError::NonExistentBook(book_id)));
+    }
+    self.validate_edition(&item.edition, &item.covers).await
+  }
+
+  async fn validate_edition(
+    &self,
+    edition: &CreateBookEditionData,
+    covers: &[CreateImage],
+  ) -> Result<(), ServiceError<MutBookEditionServiceError>> {
+    if edition.cover >= covers.len() {
+      return Err(ClientError(MutBookEditionServiceError::NonExistentCover(edition.cover)));
+    }
+    validate_descriptions(edition.translations.values().map(|x| &x.description))?;
+    if let Some(isbn13) = &edition.isbn13 {
+      self.validate_isbn13(isbn13, None).await?;
+    }
+    if let Some(involved) = &edition.involved {
+      let mut people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+      people.sort_unstable();
+      people.dedup();
+      if !people.is_empty() {
+        let existing_people = self.person_repository.filter_existing(&people).await?;
+        if people.len() != existing_people.len() {
+          let non_existent_people = filter_non_existent(&people, &existing_people);
+          return Err(ClientError(MutBookEditionServiceError::NonExistentPeople(
+            non_existent_people,
+          )));
+        }
+      }
+
+      let mut roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+      roles.sort_unstable();
+      roles.dedup();
+      if !roles.is_empty() {
+        let existing_roles = self.role_repository.filter_existing(&roles).await?;
+        if roles.len() != existing_roles.len() {
+          let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+          return Err(ClientError(MutBookEditionServiceError::NonExistentRoles(
+            non_existent_roles,
+          )));
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// The edition with the id `own_edition` may already use the ISBN-13
+  async fn validate_isbn13(
+    &self,
+    isbn13: &Isbn13,
+    own_edition: Option<u32>,
+  ) -> Result<(), ServiceError<MutBookEditionServiceError>> {
+    let existing = self
+      .book_edition_repository
+      .filter_existing_isbn13(std::slice::from_ref(isbn13))
+      .await?;
+    if existing.is_empty() {
+      return Ok(());
+    }
+    if let Some(own_edition) = own_edition {
+      // Only the id is compared, the language of the description does not matter
+      let edition = self.book_edition_repository.get_by_isbn13(isbn13, Language::EN).await?;
+      if edition.is_some_and(|x| x.id == own_edition) {
+        return Ok(());
+      }
+    }
+    Err(ClientError(MutBookEditionServiceError::AlreadyExistingIsbn13(
+      isbn13.to_string(),
+    )))
+  }
+}
+
+fn validate_descriptions<'a>(
+  descriptions: impl Iterator<Item = &'a String>,
+) -> Result<(), ServiceError<MutBookEditionServiceError>> {
+  for description in descriptions {
+    if description.is_empty() {
+      return Err(ClientError(MutBookEditionServiceError::InvalidDescription(
+        description.clone(),
+      )));
+    }
+  }
+  Ok(())
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -50,12 +50,6 @@ impl MutBookGenreService for DefaultMutBookGenreService<'_> {
 impl DefaultMutBookGenreService<'_> {
   async fn validate_add(&self, book_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutBookGenreServiceError>> {
     self.validate(book_id, genres).await?;
-    let existing = self.book_genre_repository.filter_existing(book_id, genres).await?;
-    if !existing.is_empty() {
-      return Err(ServiceError::ClientError(MutBookGenreServiceError::AlreadyAssociated(
-        existing,
-      )));
-    };
     let existing_genres = self.genre_repository.filter_existing(genres).await?;
     if existing_genres.len() != genres.len() {
       let non_existent_genres = filter_non_existent(genres, &existing_genres);
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_image::BookImage;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::book_repository::book_image_repository::BookImageRepository;
+use services::book_service::book_image_service::{BookImageService, BookImageServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultBookImageService<'a> {
+  book_image_repository: Arc<dyn BookImageRepository + 'a>,
+}
+
+impl<'a> DefaultBookImageService<'a> {
+  pub fn new(book_image_repository: Arc<dyn BookImageRepository + 'a>) -> DefaultBookImageService<'a> {
+    DefaultBookImageService { book_image_repository }
+  }
+}
+
+#[async_trait]
+impl BookImageService for DefaultBookImageService<'_> {
+  async fn get(
+    &self,
+    book_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookImage>, ServiceError<BookImageServiceError>> {
+    Ok(self.book_image_repository.get(book_id, pagination).await?)
+  }
+}
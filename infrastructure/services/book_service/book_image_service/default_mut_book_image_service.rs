@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_image::BookImage;
+use domain::entities::image::create_image::CreateImage;
+use repositories::book_repository::book_image_repository::mut_book_image_repository::MutBookImageRepository;
+use repositories::book_repository::book_image_repository::BookImageRepository;
+use repositories::book_repository::BookRepository;
+use services::book_service::book_image_service::mut_book_image_service::MutBookImageServiceError::OtherError;
+use services::book_service::book_image_service::mut_book_image_service::{
+  MutBookImageService, MutBookImageServiceError,
+};
+use services::image_service::mut_image_service::MutImageService;
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+pub struct DefaultMutBookImageService<'a> {
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_image_repository: Arc<dyn BookImageRepository + 'a>,
+  mut_book_image_repository: Arc<dyn MutBookImageRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+}
+
+impl<'a> DefaultMutBookImageService<'a> {
+  pub fn new(
+    book_repository: Arc<dyn BookRepository + 'a>,
+    book_image_repository: Arc<dyn BookImageRepository + 'a>,
+    mut_book_image_repository: Arc<dyn MutBookImageRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+  ) -> DefaultMutBookImageService<'a> {
+    DefaultMutBookImageService {
+      book_repository,
+      book_image_repository,
+      mut_book_image_repository,
+      mut_image_service,
+    }
+  }
+}
+
+#[async_trait]
+impl MutBookImageService for DefaultMutBookImageService<'_> {
+  async fn add(
+    &self,
+    book_id: u32,
+    images: Vec<CreateImage>,
+  ) -> Result<Vec<BookImage>, ServiceError<MutBookImageServiceError>> {
+    self.validate_book(book_id).await?;
+    if images.is_empty() {
+      return Err(ClientError(MutBookImageServiceError::NoImagesProvided));
+    }
+    let offset = self.book_image_repository.get_ids(book_id).await?.len();
+    let mut created = vec![];
+    for image in images {
+      let image = self.mut_image_service.create(image).await.map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?;
+      created.push(image);
+    }
+    let image_ids: Vec<u32> = created.iter().map(|x| x.id).collect();
+    self.mut_book_image_repository.add(book_id, &image_ids).await?;
+    Ok(
+      created
+        .into_iter()
+        .enumerate()
+        .map(|(index, image)| BookImage {
+          image,
+          position: (offset + index) as u16,
+        })
+        .collect(),
+    )
+  }
+
+  async fn remove(&self, book_id: u32, images: &[u32]) -> Result<(), ServiceError<MutBookImageServiceError>> {
+    self.validate_book(book_id).await?;
+    if images.is_empty() {
+      return Err(ClientError(MutBookImageServiceError::NoImagesProvided));
+    }
+    let existing = self.book_image_repository.filter_existing(book_id, images).await?;
+    if existing.len() != images.len() {
+      let non_existent = images.iter().filter(|x| !existing.contains(x)).copied().collect();
+      return Err(ClientError(MutBookImageServiceError::NonExistentImages(non_existent)));
+    }
+    Ok(self.mut_book_image_repository.remove(book_id, images).await?)
+  }
+
+  async fn reorder(&self, book_id: u32, images: &[u32]) -> Result<(), ServiceError<MutBookImageServiceError>> {
+    self.validate_book(book_id).await?;
+    let current = self.book_image_repository.get_ids(book_id).await?;
+    let mut sorted_current = current.clone();
+    sorted_current.sort_unstable();
+    let mut sorted_images = images.to_vec();
+    sorted_images.sort_unstable();
+    if sorted_current != sorted_images {
+      return Err(ClientError(MutBookImageServiceError::InvalidOrder(current)));
+    }
+    Ok(self.mut_book_image_repository.reorder(book_id, images).await?)
+  }
+}
+
+impl DefaultMutBookImageService<'_> {
+  async fn validate_book(&self, book_id: u32) -> Result<(), ServiceError<MutBookImageServiceError>> {
+    let ids = self.book_repository.filter_existing(&[book_id]).await?;
+    if ids.is_empty() {
+      return Err(ClientError(MutBookImageServiceError::NonExistentBook(book_id)));
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_book_image_service;
+pub mod default_mut_book_image_service;
@@ -63,12 +63,6 @@ impl DefaultMutBookInvolvedService<'_> {
     involved: &[InvolvedId],
   ) -> Result<(), ServiceError<MutBookInvolvedServiceError>> {
     self.validate(book_id, involved).await?;
-    let existing = self.book_involved_repository.filter_existing(book_id, involved).await?;
-    if !existing.is_empty() {
-      return Err(ServiceError::ClientError(
-        MutBookInvolvedServiceError::AlreadyAssociated(existing),
-      ));
-    };
     let people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
     let existing_people = self.person_repository.filter_existing(&people).await?;
 
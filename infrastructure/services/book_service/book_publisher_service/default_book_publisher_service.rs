@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::book_repository::book_publisher_repository::BookPublisherRepository;
+use services::book_service::book_publisher_service::{BookPublisherService, BookPublisherServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultBookPublisherService<'a> {
+  book_publisher_repository: Arc<dyn BookPublisherRepository + 'a>,
+}
+
+impl<'a> DefaultBookPublisherService<'a> {
+  pub fn new(book_publisher_repository: Arc<dyn BookPublisherRepository + 'a>) -> DefaultBookPublisherService<'a> {
+    DefaultBookPublisherService {
+      book_publisher_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl BookPublisherService for DefaultBookPublisherService<'_> {
+  async fn get(
+    &self,
+    book_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Company>, ServiceError<BookPublisherServiceError>> {
+    Ok(self.book_publisher_repository.get(book_id, pagination).await?)
+  }
+}
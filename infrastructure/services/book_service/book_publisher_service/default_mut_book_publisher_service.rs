@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::book_repository::book_publisher_repository::mut_book_publisher_repository::MutBookPublisherRepository;
+use repositories::book_repository::book_publisher_repository::BookPublisherRepository;
+use repositories::book_repository::BookRepository;
+use repositories::company_repository::CompanyRepository;
+use services::book_service::book_publisher_service::mut_book_publisher_service::{
+  MutBookPublisherService, MutBookPublisherServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutBookPublisherService<'a> {
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_publisher_repository: Arc<dyn BookPublisherRepository + 'a>,
+  mut_book_publisher_repository: Arc<dyn MutBookPublisherRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultMutBookPublisherService<'a> {
+  pub fn new(
+    book_repository: Arc<dyn BookRepository + 'a>,
+    book_publisher_repository: Arc<dyn BookPublisherRepository + 'a>,
+    mut_book_publisher_repository: Arc<dyn MutBookPublisherRepository + 'a>,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+  ) -> DefaultMutBookPublisherService<'a> {
+    DefaultMutBookPublisherService {
+      book_repository,
+      book_publisher_repository,
+      mut_book_publisher_repository,
+      company_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutBookPublisherService for DefaultMutBookPublisherService<'_> {
+  async fn add(&self, book_id: u32, publishers: &[u32]) -> Result<(), ServiceError<MutBookPublisherServiceError>> {
+    self.validate_add(book_id, publishers).await?;
+    Ok(self.mut_book_publisher_repository.add(book_id, publishers).await?)
+  }
+
+  async fn remove(&self, book_id: u32, publishers: &[u32]) -> Result<(), ServiceError<MutBookPublisherServiceError>> {
+    self.validate_remove(book_id, publishers).await?;
+    Ok(self.mut_book_publisher_repository.remove(book_id, publishers).await?)
+  }
+}
+
+impl DefaultMutBookPublisherService<'_> {
+  async fn validate_add(
+    &self,
+    book_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutBookPublisherServiceError>> {
+    self.validate(book_id, publishers).await?;
+    let existing_publishers = self.company_repository.filter_existing(publishers).await?;
+    if existing_publishers.len() != publishers.len() {
+      let non_existent_publishers = filter_non_existent(publishers, &existing_publishers);
+      return Err(ServiceError::ClientError(MutBookPublisherServiceError::NonExistent(
+        non_existent_publishers,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    book_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutBookPublisherServiceError>> {
+    self.validate(book_id, publishers).await?;
+    let existing = self
+      .book_publisher_repository
+      .filter_existing(book_id, publishers)
+      .await?;
+    if existing.len() != publishers.len() {
+      let not_associated = filter_non_existent(publishers, &existing);
+      return Err(ServiceError::ClientError(MutBookPublisherServiceError::NotAssociated(
+        not_associated,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, book_id: u32, publishers: &[u32]) -> Result<(), ServiceError<MutBookPublisherServiceError>> {
+    let ids = self.book_repository.filter_existing(&[book_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutBookPublisherServiceError::NonExistentBook(book_id),
+      ));
+    }
+    if publishers.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutBookPublisherServiceError::NoPublishersProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
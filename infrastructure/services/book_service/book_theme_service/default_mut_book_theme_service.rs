@@ -50,12 +50,6 @@ impl MutBookThemeService for DefaultMutBookThemeService<'_> {
 impl DefaultMutBookThemeService<'_> {
   async fn validate_add(&self, book_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutBookThemeServiceError>> {
     self.validate(book_id, themes).await?;
-    let existing = self.book_theme_repository.filter_existing(book_id, themes).await?;
-    if !existing.is_empty() {
-      return Err(ServiceError::ClientError(MutBookThemeServiceError::AlreadyAssociated(
-        existing,
-      )));
-    };
     let existing_themes = self.theme_repository.filter_existing(themes).await?;
     if existing_themes.len() != themes.len() {
       let non_existent_themes = filter_non_existent(themes, &existing_themes);
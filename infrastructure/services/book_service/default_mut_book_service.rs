@@ -5,10 +5,17 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use domain::entities::book::create_book::{CreateBook, CreateBookTranslation, CreateCover};
+use domain::entities::book::create_book_edition::CreateBookEditionData;
 use domain::entities::book::create_partial_book::{CreatePartialBook, CreatePartialBookTranslation};
+use domain::entities::book::create_partial_book_edition::{
+  CreatePartialBookEdition, CreatePartialBookEditionTranslation,
+};
 use domain::entities::book::Book;
 use domain::entities::image::create_image::CreateImage;
+use domain::entities::image::Image;
+use domain::entities::involved::InvolvedId;
 use domain::enums::language::Language;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
 use repositories::book_repository::mut_book_repository::MutBookRepository;
 use repositories::book_repository::BookRepository;
 use repositories::character_repository::CharacterRepository;
@@ -27,6 +34,7 @@ pub struct DefaultMutBookService<'a> {
   default_language: Language,
   book_repository: Arc<dyn BookRepository + 'a>,
   mut_book_repository: Arc<dyn MutBookRepository + 'a>,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
   mut_image_service: Arc<dyn MutImageService + 'a>,
   franchise_repository: Arc<dyn FranchiseRepository + 'a>,
   theme_repository: Arc<dyn ThemeRepository + 'a>,
@@ -42,6 +50,7 @@ impl<'a> DefaultMutBookService<'a> {
     default_language: Language,
     book_repository: Arc<dyn BookRepository + 'a>,
     mut_book_repository: Arc<dyn MutBookRepository + 'a>,
+    book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
     mut_image_service: Arc<dyn MutImageService + 'a>,
     franchise_repository: Arc<dyn FranchiseRepository + 'a>,
     theme_repository: Arc<dyn ThemeRepository + 'a>,
@@ -54,6 +63,7 @@ impl<'a> DefaultMutBookService<'a> {
       default_language,
       book_repository,
       mut_book_repository,
+      book_edition_repository,
       mut_image_service,
       franchise_repository,
       theme_repository,
@@ -72,6 +82,8 @@ impl MutBookService for DefaultMutBookService<'_> {
     let data = item.book;
     let covers = item.covers;
     let translations = self.transform_translations(data.translations, covers).await?;
+    let editions = self.transform_editions(item.editions, item.edition_covers).await?;
+    let images = self.transform_images(item.images.unwrap_or_default()).await?;
 
     let partial_book = CreatePartialBook {
       slug: data.slug,
@@ -82,7 +94,8 @@ impl MutBookService for DefaultMutBookService<'_> {
       themes: data.themes.unwrap_or_default(),
       characters: data.characters.unwrap_or_default(),
       involved: data.involved.unwrap_or_default(),
-      // images: vec![], // TODO,
+      editions,
+      images,
     };
     Ok(self.mut_book_repository.create(partial_book).await?)
   }
@@ -104,7 +117,6 @@ impl<'a> DefaultMutBookService<'a> {
       let non_existent_books = filter_non_existent(ids, &existing);
       return Err(ClientError(MutBookServiceError::NonExistentBooks(non_existent_books)));
     };
-    //TODO: Validate UserBook
     Ok(())
   }
   async fn validate_translations(
@@ -196,6 +208,119 @@ impl<'a> DefaultMutBookService<'a> {
     }
     Ok(hash_map)
   }
+  async fn transform_editions(
+    &self,
+    editions: Vec<CreateBookEditionData>,
+    covers: Vec<CreateImage>,
+  ) -> Result<Vec<CreatePartialBookEdition>, ServiceError<MutBookServiceError>> {
+    let mut result = vec![];
+    for edition in editions {
+      let cover = covers[edition.cover].clone();
+      let cover = self.mut_image_service.create(cover).await.map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?;
+      let translations = edition
+        .translations
+        .into_iter()
+        .map(|(language, translation)| {
+          (
+            language,
+            CreatePartialBookEditionTranslation {
+              description: translation.description,
+            },
+          )
+        })
+        .collect();
+      result.push(CreatePartialBookEdition {
+        chapters: edition.chapters,
+        pages: edition.pages,
+        words: edition.words,
+        published: edition.published,
+        isbn13: edition.isbn13,
+        language: edition.language,
+        translations,
+        involved: edition.involved.unwrap_or_default(),
+        cover,
+      });
+    }
+    Ok(result)
+  }
+  async fn transform_images(&self, images: Vec<CreateImage>) -> Result<Vec<Image>, ServiceError<MutBookServiceError>> {
+    let mut result = vec![];
+    for image in images {
+      let image = self.mut_image_service.create(image).await.map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServerError(x) => ServerError(x),
+      })?;
+      result.push(image);
+    }
+    Ok(result)
+  }
+  async fn validate_editions(
+    &self,
+    editions: &[CreateBookEditionData],
+    covers: &[CreateImage],
+  ) -> Result<(), ServiceError<MutBookServiceError>> {
+    let mut isbn13: Vec<String> = vec![];
+    for edition in editions {
+      if edition.cover >= covers.len() {
+        return Err(ClientError(MutBookServiceError::NonExistentEditionCover(edition.cover)));
+      }
+      for translation in edition.translations.values() {
+        if translation.description.is_empty() {
+          return Err(ClientError(MutBookServiceError::InvalidDescription(
+            translation.description.clone(),
+          )));
+        }
+      }
+      if let Some(value) = &edition.isbn13 {
+        let value = value.to_string();
+        if isbn13.contains(&value) {
+          return Err(ClientError(MutBookServiceError::AlreadyExistingIsbn13(value)));
+        }
+        isbn13.push(value);
+      }
+    }
+    let requested_isbn13: Vec<_> = editions.iter().filter_map(|x| x.isbn13.clone()).collect();
+    if !requested_isbn13.is_empty() {
+      let existing = self
+        .book_edition_repository
+        .filter_existing_isbn13(&requested_isbn13)
+        .await?;
+      if let Some(value) = existing.first() {
+        return Err(ClientError(MutBookServiceError::AlreadyExistingIsbn13(
+          value.to_string(),
+        )));
+      }
+    }
+    let involved: Vec<&InvolvedId> = editions.iter().filter_map(|x| x.involved.as_ref()).flatten().collect();
+    self.validate_involved(&involved).await
+  }
+  async fn validate_involved(&self, involved: &[&InvolvedId]) -> Result<(), ServiceError<MutBookServiceError>> {
+    let mut people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+    people.sort_unstable();
+    people.dedup();
+    if !people.is_empty() {
+      let existing_people = self.person_repository.filter_existing(&people).await?;
+      if people.len() != existing_people.len() {
+        let non_existent_people = filter_non_existent(&people, &existing_people);
+        return Err(ClientError(MutBookServiceError::NonExistentPeople(non_existent_people)));
+      }
+    }
+
+    let mut roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+    roles.sort_unstable();
+    roles.dedup();
+    if !roles.is_empty() {
+      let existing_roles = self.role_repository.filter_existing(&roles).await?;
+      if roles.len() != existing_roles.len() {
+        let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+        return Err(ClientError(MutBookServiceError::NonExistentRoles(non_existent_roles)));
+      }
+    }
+    Ok(())
+  }
   async fn validate_create(&self, item: &CreateBook) -> Result<(), ServiceError<MutBookServiceError>> {
     //TODO: Slug
     let data = &item.book;
@@ -256,6 +381,7 @@ impl<'a> DefaultMutBookService<'a> {
     self
       .validate_translations(&data.translations, &self.default_language, &item.covers)
       .await?;
+    self.validate_editions(&item.editions, &item.edition_covers).await?;
     Ok(())
   }
 }
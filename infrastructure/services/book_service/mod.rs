@@ -1,6 +1,10 @@
 pub mod book_character_service;
+pub mod book_edition_involved_service;
+pub mod book_edition_service;
 pub mod book_genre_service;
+pub mod book_image_service;
 pub mod book_involved_service;
+pub mod book_publisher_service;
 pub mod book_theme_service;
 pub mod default_book_service;
 pub mod default_mut_book_service;
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::company_repository::CompanyRepository;
+use services::company_service::{CompanyService, CompanyServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultCompanyService<'a> {
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultCompanyService<'a> {
+  pub fn new(company_repository: Arc<dyn CompanyRepository + 'a>) -> DefaultCompanyService<'a> {
+    DefaultCompanyService { company_repository }
+  }
+}
+
+#[async_trait]
+impl CompanyService for DefaultCompanyService<'_> {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Company>, ServiceError<CompanyServiceError>> {
+    Ok(self.company_repository.get(pagination).await?)
+  }
+
+  async fn get_by_id(&self, id: u32) -> Result<Option<Company>, ServiceError<CompanyServiceError>> {
+    Ok(self.company_repository.get_by_id(id).await?)
+  }
+
+  async fn get_by_name(
+    &self,
+    name: &str,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Company>, ServiceError<CompanyServiceError>> {
+    Ok(self.company_repository.get_by_name(name, pagination).await?)
+  }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::company::create_company::CreateCompany;
+use domain::entities::company::create_partial_company::CreatePartialCompany;
+use domain::entities::company::Company;
+use repositories::company_repository::mut_company_repository::MutCompanyRepository;
+use repositories::company_repository::CompanyRepository;
+use repositories::platform_repository::PlatformRepository;
+use services::company_service::mut_company_service::MutCompanyServiceError::OtherError;
+use services::company_service::mut_company_service::{MutCompanyService, MutCompanyServiceError};
+use services::image_service::mut_image_service::MutImageService;
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+pub struct DefaultMutCompanyService<'a> {
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+  mut_company_repository: Arc<dyn MutCompanyRepository + 'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+}
+
+impl<'a> DefaultMutCompanyService<'a> {
+  pub fn new(
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+    mut_company_repository: Arc<dyn MutCompanyRepository + 'a>,
+    platform_repository: Arc<dyn PlatformRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+  ) -> DefaultMutCompanyService<'a> {
+    DefaultMutCompanyService {
+      company_repository,
+      mut_company_repository,
+      platform_repository,
+      mut_image_service,
+    }
+  }
+}
+
+#[async_trait]
+impl MutCompanyService for DefaultMutCompanyService<'_> {
+  async fn create(&self, item: CreateCompany) -> Result<Company, ServiceError<MutCompanyServiceError>> {
+    let data = item.company;
+    if data.name.is_empty() {
+      return Err(ClientError(MutCompanyServiceError::InvalidName(data.name)));
+    }
+    let logo = self.mut_image_service.create(item.logo).await.map_err(|x| match x {
+      ClientError(x) => ClientError(OtherError(Box::new(x))),
+      ServerError(x) => ServerError(x),
+    })?;
+    let partial_company = CreatePartialCompany { name: data.name, logo };
+    Ok(self.mut_company_repository.create(partial_company).await?)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<Vec<String>, ServiceError<MutCompanyServiceError>> {
+    if ids.is_empty() {
+      return Err(ClientError(MutCompanyServiceError::NoIdsProvided));
+    }
+    let companies = self.company_repository.get_by_ids(ids).await?;
+    if companies.len() != ids.len() {
+      let existing: Vec<u32> = companies.iter().map(|x| x.id).collect();
+      let non_existent_companies = filter_non_existent(ids, &existing);
+      return Err(ClientError(MutCompanyServiceError::NonExistentCompanies(
+        non_existent_companies,
+      )));
+    };
+    let with_platforms = self.platform_repository.filter_companies_with_platforms(ids).await?;
+    if !with_platforms.is_empty() {
+      return Err(ClientError(MutCompanyServiceError::CompaniesWithPlatforms(
+        with_platforms,
+      )));
+    }
+    self.mut_company_repository.delete(ids).await?;
+    let mut files = vec![];
+    for company in companies {
+      let logo_files = self
+        .mut_image_service
+        .delete_record(&company.logo)
+        .await
+        .map_err(|x| match x {
+          ClientError(x) => ClientError(OtherError(Box::new(x))),
+          ServerError(x) => ServerError(x),
+        })?;
+      files.extend(logo_files);
+    }
+    Ok(files)
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
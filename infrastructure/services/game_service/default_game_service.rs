@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_statistic::GameStatistic;
+use domain::entities::game::Game;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::game_repository::GameRepository;
+use services::game_service::{GameService, GameServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+pub struct DefaultGameService<'a> {
+  game_repository: Arc<dyn GameRepository + 'a>,
+}
+
+impl<'a> DefaultGameService<'a> {
+  pub fn new(game_repository: Arc<dyn GameRepository + 'a>) -> DefaultGameService<'a> {
+    DefaultGameService { game_repository }
+  }
+}
+
+#[async_trait]
+impl GameService for DefaultGameService<'_> {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Game>, ServiceError<GameServiceError>> {
+    Ok(self.game_repository.get(language, pagination).await?)
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Game>, ServiceError<GameServiceError>> {
+    Ok(self.game_repository.get_by_id(id, language).await?)
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Game>, ServiceError<GameServiceError>> {
+    Ok(self.game_repository.get_by_title(title, language, pagination).await?)
+  }
+
+  async fn get_statistics(&self, game_ids: &[u32]) -> Result<Vec<GameStatistic>, ServiceError<GameServiceError>> {
+    let existing = self.game_repository.filter_existing(game_ids).await?;
+    if existing.len() != game_ids.len() {
+      let non_existent_games = filter_non_existent(game_ids, &existing);
+      return Err(ClientError(GameServiceError::NonExistentGames(non_existent_games)));
+    };
+    Ok(self.game_repository.get_statistics(game_ids).await?)
+  }
+}
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_character::GameCharacter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::game_repository::game_character_repository::GameCharacterRepository;
+use services::game_service::game_character_service::{GameCharacterService, GameCharacterServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGameCharacterService<'a> {
+  game_character_repository: Arc<dyn GameCharacterRepository + 'a>,
+}
+
+impl<'a> DefaultGameCharacterService<'a> {
+  pub fn new(game_character_repository: Arc<dyn GameCharacterRepository + 'a>) -> DefaultGameCharacterService<'a> {
+    DefaultGameCharacterService {
+      game_character_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GameCharacterService for DefaultGameCharacterService<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameCharacter>, ServiceError<GameCharacterServiceError>> {
+    Ok(
+      self
+        .game_character_repository
+        .get(game_id, language, pagination)
+        .await?,
+    )
+  }
+}
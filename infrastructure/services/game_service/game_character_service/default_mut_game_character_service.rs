@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::character_repository::CharacterRepository;
+use repositories::game_repository::game_character_repository::mut_game_character_repository::MutGameCharacterRepository;
+use repositories::game_repository::game_character_repository::GameCharacterRepository;
+use repositories::game_repository::GameRepository;
+use services::game_service::game_character_service::mut_game_character_service::{
+  MutGameCharacterService, MutGameCharacterServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGameCharacterService<'a> {
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_character_repository: Arc<dyn GameCharacterRepository + 'a>,
+  mut_game_character_repository: Arc<dyn MutGameCharacterRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+}
+
+impl<'a> DefaultMutGameCharacterService<'a> {
+  pub fn new(
+    game_repository: Arc<dyn GameRepository + 'a>,
+    game_character_repository: Arc<dyn GameCharacterRepository + 'a>,
+    mut_game_character_repository: Arc<dyn MutGameCharacterRepository + 'a>,
+    character_repository: Arc<dyn CharacterRepository + 'a>,
+  ) -> DefaultMutGameCharacterService<'a> {
+    DefaultMutGameCharacterService {
+      game_repository,
+      game_character_repository,
+      mut_game_character_repository,
+      character_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGameCharacterService for DefaultMutGameCharacterService<'_> {
+  async fn add(&self, game_id: u32, characters: &[u32]) -> Result<(), ServiceError<MutGameCharacterServiceError>> {
+    self.validate_add(game_id, characters).await?;
+    Ok(self.mut_game_character_repository.add(game_id, characters).await?)
+  }
+
+  async fn remove(&self, game_id: u32, characters: &[u32]) -> Result<(), ServiceError<MutGameCharacterServiceError>> {
+    self.validate_remove(game_id, characters).await?;
+    Ok(self.mut_game_character_repository.remove(game_id, characters).await?)
+  }
+}
+
+impl DefaultMutGameCharacterService<'_> {
+  async fn validate_add(
+    &self,
+    game_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGameCharacterServiceError>> {
+    self.validate(game_id, characters).await?;
+    let existing_characters = self.character_repository.filter_existing(characters).await?;
+    if existing_characters.len() != characters.len() {
+      let non_existent_characters = filter_non_existent(characters, &existing_characters);
+      let error = MutGameCharacterServiceError::NonExistent(non_existent_characters);
+      return Err(ServiceError::ClientError(error));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    game_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGameCharacterServiceError>> {
+    self.validate(game_id, characters).await?;
+    let existing = self
+      .game_character_repository
+      .filter_existing(game_id, characters)
+      .await?;
+    if existing.len() != characters.len() {
+      let non_existent_characters = filter_non_existent(characters, &existing);
+      return Err(ServiceError::ClientError(MutGameCharacterServiceError::NotAssociated(
+        non_existent_characters,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, game_id: u32, characters: &[u32]) -> Result<(), ServiceError<MutGameCharacterServiceError>> {
+    let ids = self.game_repository.filter_existing(&[game_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGameCharacterServiceError::NonExistentGame(game_id),
+      ));
+    }
+    if characters.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGameCharacterServiceError::NoCharactersProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
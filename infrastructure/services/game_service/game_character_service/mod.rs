@@ -0,0 +1,2 @@
+pub mod default_game_character_service;
+pub mod default_mut_game_character_service;
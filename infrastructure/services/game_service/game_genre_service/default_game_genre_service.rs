@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::genre::Genre;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::game_repository::game_genre_repository::GameGenreRepository;
+use services::game_service::game_genre_service::{GameGenreService, GameGenreServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGameGenreService<'a> {
+  game_genre_repository: Arc<dyn GameGenreRepository + 'a>,
+}
+
+impl<'a> DefaultGameGenreService<'a> {
+  pub fn new(game_genre_repository: Arc<dyn GameGenreRepository + 'a>) -> DefaultGameGenreService<'a> {
+    DefaultGameGenreService { game_genre_repository }
+  }
+}
+
+#[async_trait]
+impl GameGenreService for DefaultGameGenreService<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Genre>, ServiceError<GameGenreServiceError>> {
+    Ok(self.game_genre_repository.get(game_id, language, pagination).await?)
+  }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::game_repository::game_genre_repository::mut_game_genre_repository::MutGameGenreRepository;
+use repositories::game_repository::game_genre_repository::GameGenreRepository;
+use repositories::game_repository::GameRepository;
+use repositories::genre_repository::GenreRepository;
+use services::game_service::game_genre_service::mut_game_genre_service::{
+  MutGameGenreService, MutGameGenreServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGameGenreService<'a> {
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_genre_repository: Arc<dyn GameGenreRepository + 'a>,
+  mut_game_genre_repository: Arc<dyn MutGameGenreRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+}
+
+impl<'a> DefaultMutGameGenreService<'a> {
+  pub fn new(
+    game_repository: Arc<dyn GameRepository + 'a>,
+    game_genre_repository: Arc<dyn GameGenreRepository + 'a>,
+    mut_game_genre_repository: Arc<dyn MutGameGenreRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+  ) -> DefaultMutGameGenreService<'a> {
+    DefaultMutGameGenreService {
+      game_repository,
+      game_genre_repository,
+      mut_game_genre_repository,
+      genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGameGenreService for DefaultMutGameGenreService<'_> {
+  async fn add(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>> {
+    self.validate_add(game_id, genres).await?;
+    Ok(self.mut_game_genre_repository.add(game_id, genres).await?)
+  }
+
+  async fn remove(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>> {
+    self.validate_remove(game_id, genres).await?;
+    Ok(self.mut_game_genre_repository.remove(game_id, genres).await?)
+  }
+}
+
+impl DefaultMutGameGenreService<'_> {
+  async fn validate_add(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>> {
+    self.validate(game_id, genres).await?;
+    let existing_genres = self.genre_repository.filter_existing(genres).await?;
+    if existing_genres.len() != genres.len() {
+      let non_existent_genres = filter_non_existent(genres, &existing_genres);
+      return Err(ServiceError::ClientError(MutGameGenreServiceError::NonExistent(
+        non_existent_genres,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>> {
+    self.validate(game_id, genres).await?;
+    let existing = self.game_genre_repository.filter_existing(game_id, genres).await?;
+    if existing.len() != genres.len() {
+      let not_associated = filter_non_existent(genres, &existing);
+      return Err(ServiceError::ClientError(MutGameGenreServiceError::NotAssociated(
+        not_associated,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>> {
+    let ids = self.game_repository.filter_existing(&[game_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(MutGameGenreServiceError::NonExistentGame(
+        game_id,
+      )));
+    }
+    if genres.is_empty() {
+      return Err(ServiceError::ClientError(MutGameGenreServiceError::NoGenresProvided));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
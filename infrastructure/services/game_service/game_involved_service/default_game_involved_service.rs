@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_involved::GameInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::game_repository::game_involved_repository::GameInvolvedRepository;
+use services::game_service::game_involved_service::{GameInvolvedService, GameInvolvedServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGameInvolvedService<'a> {
+  game_involved_repository: Arc<dyn GameInvolvedRepository + 'a>,
+}
+
+impl<'a> DefaultGameInvolvedService<'a> {
+  pub fn new(game_involved_repository: Arc<dyn GameInvolvedRepository + 'a>) -> DefaultGameInvolvedService<'a> {
+    DefaultGameInvolvedService {
+      game_involved_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GameInvolvedService for DefaultGameInvolvedService<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameInvolved>, ServiceError<GameInvolvedServiceError>> {
+    Ok(self.game_involved_repository.get(game_id, language, pagination).await?)
+  }
+}
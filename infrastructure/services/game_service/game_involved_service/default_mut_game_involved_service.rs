@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+use repositories::game_repository::game_involved_repository::mut_game_involved_repository::MutGameInvolvedRepository;
+use repositories::game_repository::game_involved_repository::GameInvolvedRepository;
+use repositories::game_repository::GameRepository;
+use repositories::person_repository::PersonRepository;
+use repositories::role_repository::RoleRepository;
+use services::game_service::game_involved_service::mut_game_involved_service::{
+  MutGameInvolvedService, MutGameInvolvedServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGameInvolvedService<'a> {
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_involved_repository: Arc<dyn GameInvolvedRepository + 'a>,
+  mut_game_involved_repository: Arc<dyn MutGameInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+}
+
+impl<'a> DefaultMutGameInvolvedService<'a> {
+  pub fn new(
+    game_repository: Arc<dyn GameRepository + 'a>,
+    game_involved_repository: Arc<dyn GameInvolvedRepository + 'a>,
+    mut_game_involved_repository: Arc<dyn MutGameInvolvedRepository + 'a>,
+    person_repository: Arc<dyn PersonRepository + 'a>,
+    role_repository: Arc<dyn RoleRepository + 'a>,
+  ) -> DefaultMutGameInvolvedService<'a> {
+    DefaultMutGameInvolvedService {
+      game_repository,
+      game_involved_repository,
+      mut_game_involved_repository,
+      person_repository,
+      role_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGameInvolvedService for DefaultMutGameInvolvedService<'_> {
+  async fn add(&self, game_id: u32, involved: &[InvolvedId]) -> Result<(), ServiceError<MutGameInvolvedServiceError>> {
+    self.validate_add(game_id, involved).await?;
+    Ok(self.mut_game_involved_repository.add(game_id, involved).await?)
+  }
+
+  async fn remove(
+    &self,
+    game_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGameInvolvedServiceError>> {
+    self.validate_remove(game_id, involved).await?;
+    Ok(self.mut_game_involved_repository.remove(game_id, involved).await?)
+  }
+}
+
+impl DefaultMutGameInvolvedService<'_> {
+  async fn validate_add(
+    &self,
+    game_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGameInvolvedServiceError>> {
+    self.validate(game_id, involved).await?;
+    let people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+    let existing_people = self.person_repository.filter_existing(&people).await?;
+
+    if existing_people.len() != involved.len() {
+      let non_existent_people = filter_non_existent(&people, &existing_people);
+      return Err(ServiceError::ClientError(
+        MutGameInvolvedServiceError::NonExistentPeople(non_existent_people),
+      ));
+    };
+    let roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+    let existing_roles = self.role_repository.filter_existing(&roles).await?;
+    if existing_roles.len() != involved.len() {
+      let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+      return Err(ServiceError::ClientError(
+        MutGameInvolvedServiceError::NonExistentRoles(non_existent_roles),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    game_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGameInvolvedServiceError>> {
+    self.validate(game_id, involved).await?;
+    let existing = self.game_involved_repository.filter_existing(game_id, involved).await?;
+    if existing.len() != involved.len() {
+      let non_existent_involved = filter_involved_non_existent(involved, &existing);
+      return Err(ServiceError::ClientError(
+        MutGameInvolvedServiceError::NonExistentAssociation(non_existent_involved),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    game_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGameInvolvedServiceError>> {
+    let ids = self.game_repository.filter_existing(&[game_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(MutGameInvolvedServiceError::NonExistentGame(
+        game_id,
+      )));
+    }
+    if involved.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGameInvolvedServiceError::NoInvolvedProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn filter_involved_non_existent(items: &[InvolvedId], existing: &[InvolvedId]) -> Vec<InvolvedId> {
+  items
+    .iter()
+    .filter_map(|x| {
+      existing
+        .iter()
+        .find(|y| y.role_id == x.role_id && y.person_id == x.person_id)
+        .map_or_else(|| Some(x.clone()), |_| None)
+    })
+    .collect()
+}
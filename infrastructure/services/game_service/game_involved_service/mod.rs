@@ -0,0 +1,2 @@
+pub mod default_game_involved_service;
+pub mod default_mut_game_involved_service;
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::game_repository::game_platform_repository::GamePlatformRepository;
+use services::game_service::game_platform_service::{GamePlatformService, GamePlatformServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGamePlatformService<'a> {
+  game_platform_repository: Arc<dyn GamePlatformRepository + 'a>,
+}
+
+impl<'a> DefaultGamePlatformService<'a> {
+  pub fn new(game_platform_repository: Arc<dyn GamePlatformRepository + 'a>) -> DefaultGamePlatformService<'a> {
+    DefaultGamePlatformService {
+      game_platform_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GamePlatformService for DefaultGamePlatformService<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Platform>, ServiceError<GamePlatformServiceError>> {
+    Ok(self.game_platform_repository.get(game_id, pagination).await?)
+  }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::game_repository::game_platform_repository::mut_game_platform_repository::MutGamePlatformRepository;
+use repositories::game_repository::game_platform_repository::GamePlatformRepository;
+use repositories::game_repository::GameRepository;
+use repositories::platform_repository::PlatformRepository;
+use services::game_service::game_platform_service::mut_game_platform_service::{
+  MutGamePlatformService, MutGamePlatformServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGamePlatformService<'a> {
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_platform_repository: Arc<dyn GamePlatformRepository + 'a>,
+  mut_game_platform_repository: Arc<dyn MutGamePlatformRepository + 'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+}
+
+impl<'a> DefaultMutGamePlatformService<'a> {
+  pub fn new(
+    game_repository: Arc<dyn GameRepository + 'a>,
+    game_platform_repository: Arc<dyn GamePlatformRepository + 'a>,
+    mut_game_platform_repository: Arc<dyn MutGamePlatformRepository + 'a>,
+    platform_repository: Arc<dyn PlatformRepository + 'a>,
+  ) -> DefaultMutGamePlatformService<'a> {
+    DefaultMutGamePlatformService {
+      game_repository,
+      game_platform_repository,
+      mut_game_platform_repository,
+      platform_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGamePlatformService for DefaultMutGamePlatformService<'_> {
+  async fn add(&self, game_id: u32, platforms: &[u32]) -> Result<(), ServiceError<MutGamePlatformServiceError>> {
+    self.validate_add(game_id, platforms).await?;
+    Ok(self.mut_game_platform_repository.add(game_id, platforms).await?)
+  }
+
+  async fn remove(&self, game_id: u32, platforms: &[u32]) -> Result<(), ServiceError<MutGamePlatformServiceError>> {
+    self.validate_remove(game_id, platforms).await?;
+    Ok(self.mut_game_platform_repository.remove(game_id, platforms).await?)
+  }
+}
+
+impl DefaultMutGamePlatformService<'_> {
+  async fn validate_add(
+    &self,
+    game_id: u32,
+    platforms: &[u32],
+  ) -> Result<(), ServiceError<MutGamePlatformServiceError>> {
+    self.validate(game_id, platforms).await?;
+    let existing_platforms = self.platform_repository.filter_existing(platforms).await?;
+    if existing_platforms.len() != platforms.len() {
+      let non_existent_platforms = filter_non_existent(platforms, &existing_platforms);
+      return Err(ServiceError::ClientError(MutGamePlatformServiceError::NonExistent(
+        non_existent_platforms,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    game_id: u32,
+    platforms: &[u32],
+  ) -> Result<(), ServiceError<MutGamePlatformServiceError>> {
+    self.validate(game_id, platforms).await?;
+    let existing = self
+      .game_platform_repository
+      .filter_existing(game_id, platforms)
+      .await?;
+    if existing.len() != platforms.len() {
+      let not_associated = filter_non_existent(platforms, &existing);
+      return Err(ServiceError::ClientError(MutGamePlatformServiceError::NotAssociated(
+        not_associated,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, game_id: u32, platforms: &[u32]) -> Result<(), ServiceError<MutGamePlatformServiceError>> {
+    let ids = self.game_repository.filter_existing(&[game_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(MutGamePlatformServiceError::NonExistentGame(
+        game_id,
+      )));
+    }
+    if platforms.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGamePlatformServiceError::NoPlatformsProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
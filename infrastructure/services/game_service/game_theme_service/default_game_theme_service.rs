@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::theme::Theme;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::game_repository::game_theme_repository::GameThemeRepository;
+use services::game_service::game_theme_service::{GameThemeService, GameThemeServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGameThemeService<'a> {
+  game_theme_repository: Arc<dyn GameThemeRepository + 'a>,
+}
+
+impl<'a> DefaultGameThemeService<'a> {
+  pub fn new(game_theme_repository: Arc<dyn GameThemeRepository + 'a>) -> DefaultGameThemeService<'a> {
+    DefaultGameThemeService { game_theme_repository }
+  }
+}
+
+#[async_trait]
+impl GameThemeService for DefaultGameThemeService<'_> {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Theme>, ServiceError<GameThemeServiceError>> {
+    Ok(self.game_theme_repository.get(game_id, language, pagination).await?)
+  }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::game_repository::game_theme_repository::mut_game_theme_repository::MutGameThemeRepository;
+use repositories::game_repository::game_theme_repository::GameThemeRepository;
+use repositories::game_repository::GameRepository;
+use repositories::theme_repository::ThemeRepository;
+use services::game_service::game_theme_service::mut_game_theme_service::{
+  MutGameThemeService, MutGameThemeServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGameThemeService<'a> {
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_theme_repository: Arc<dyn GameThemeRepository + 'a>,
+  mut_game_theme_repository: Arc<dyn MutGameThemeRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+}
+
+impl<'a> DefaultMutGameThemeService<'a> {
+  pub fn new(
+    game_repository: Arc<dyn GameRepository + 'a>,
+    game_theme_repository: Arc<dyn GameThemeRepository + 'a>,
+    mut_game_theme_repository: Arc<dyn MutGameThemeRepository + 'a>,
+    theme_repository: Arc<dyn ThemeRepository + 'a>,
+  ) -> DefaultMutGameThemeService<'a> {
+    DefaultMutGameThemeService {
+      game_repository,
+      game_theme_repository,
+      mut_game_theme_repository,
+      theme_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGameThemeService for DefaultMutGameThemeService<'_> {
+  async fn add(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>> {
+    self.validate_add(game_id, themes).await?;
+    Ok(self.mut_game_theme_repository.add(game_id, themes).await?)
+  }
+
+  async fn remove(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>> {
+    self.validate_remove(game_id, themes).await?;
+    Ok(self.mut_game_theme_repository.remove(game_id, themes).await?)
+  }
+}
+
+impl DefaultMutGameThemeService<'_> {
+  async fn validate_add(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>> {
+    self.validate(game_id, themes).await?;
+    let existing_themes = self.theme_repository.filter_existing(themes).await?;
+    if existing_themes.len() != themes.len() {
+      let non_existent_themes = filter_non_existent(themes, &existing_themes);
+      return Err(ServiceError::ClientError(MutGameThemeServiceError::NonExistent(
+        non_existent_themes,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>> {
+    self.validate(game_id, themes).await?;
+    let existing = self.game_theme_repository.filter_existing(game_id, themes).await?;
+    if existing.len() != themes.len() {
+      let not_associated = filter_non_existent(themes, &existing);
+      return Err(ServiceError::ClientError(MutGameThemeServiceError::NotAssociated(
+        not_associated,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>> {
+    let ids = self.game_repository.filter_existing(&[game_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(MutGameThemeServiceError::NonExistentGame(
+        game_id,
+      )));
+    }
+    if themes.is_empty() {
+      return Err(ServiceError::ClientError(MutGameThemeServiceError::NoThemesProvided));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -0,0 +1,2 @@
+pub mod default_game_theme_service;
+pub mod default_mut_game_theme_service;
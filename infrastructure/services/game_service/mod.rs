@@ -0,0 +1,7 @@
+pub mod default_game_service;
+pub mod default_mut_game_service;
+pub mod game_character_service;
+pub mod game_genre_service;
+pub mod game_involved_service;
+pub mod game_platform_service;
+pub mod game_theme_service;
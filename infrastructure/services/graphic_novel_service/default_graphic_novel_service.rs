@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use services::graphic_novel_service::{GraphicNovelService, GraphicNovelServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelService<'a> {
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelService<'a> {
+  pub fn new(graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>) -> DefaultGraphicNovelService<'a> {
+    DefaultGraphicNovelService {
+      graphic_novel_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelService for DefaultGraphicNovelService<'_> {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovel>, ServiceError<GraphicNovelServiceError>> {
+    Ok(self.graphic_novel_repository.get(language, pagination).await?)
+  }
+
+  async fn get_by_id(
+    &self,
+    id: u32,
+    language: Language,
+  ) -> Result<Option<GraphicNovel>, ServiceError<GraphicNovelServiceError>> {
+    Ok(self.graphic_novel_repository.get_by_id(id, language).await?)
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovel>, ServiceError<GraphicNovelServiceError>> {
+    Ok(
+      self
+        .graphic_novel_repository
+        .get_by_title(title, language, pagination)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::create_book::CreateCover;
+use domain::entities::graphic_novel::create_graphic_novel::{CreateGraphicNovel, CreateGraphicNovelTranslation};
+use domain::entities::graphic_novel::create_partial_graphic_novel::{
+  CreatePartialGraphicNovel, CreatePartialGraphicNovelTranslation,
+};
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::entities::image::create_image::CreateImage;
+use domain::enums::language::Language;
+use repositories::graphic_novel_repository::mut_graphic_novel_repository::MutGraphicNovelRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use services::graphic_novel_service::mut_graphic_novel_service::MutGraphicNovelServiceError::OtherError;
+use services::graphic_novel_service::mut_graphic_novel_service::{MutGraphicNovelService, MutGraphicNovelServiceError};
+use services::image_service::mut_image_service::MutImageService;
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+pub struct DefaultMutGraphicNovelService<'a> {
+  default_language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  mut_graphic_novel_repository: Arc<dyn MutGraphicNovelRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelService<'a> {
+  pub fn new(
+    default_language: Language,
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    mut_graphic_novel_repository: Arc<dyn MutGraphicNovelRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+  ) -> DefaultMutGraphicNovelService<'a> {
+    DefaultMutGraphicNovelService {
+      default_language,
+      graphic_novel_repository,
+      mut_graphic_novel_repository,
+      mut_image_service,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelService for DefaultMutGraphicNovelService<'_> {
+  async fn create(&self, item: CreateGraphicNovel) -> Result<GraphicNovel, ServiceError<MutGraphicNovelServiceError>> {
+    self.validate_create(&item).await?;
+    let data = item.graphic_novel;
+    let covers = item.covers;
+    let translations = self.transform_translations(data.translations, covers).await?;
+
+    let partial_graphic_novel = CreatePartialGraphicNovel {
+      publish_start: data.publish_start,
+      publish_end: data.publish_end,
+      volumes: data.volumes,
+      chapters: data.chapters,
+      status: data.status,
+      translations,
+    };
+    Ok(self.mut_graphic_novel_repository.create(partial_graphic_novel).await?)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutGraphicNovelServiceError>> {
+    self.validate_delete(ids).await?;
+    Ok(self.mut_graphic_novel_repository.delete(ids).await?)
+  }
+}
+
+impl DefaultMutGraphicNovelService<'_> {
+  async fn validate_delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutGraphicNovelServiceError>> {
+    if ids.is_empty() {
+      return Err(ClientError(MutGraphicNovelServiceError::NoIdsProvided));
+    }
+
+    let existing = self.graphic_novel_repository.filter_existing(ids).await?;
+    if existing.len() != ids.len() {
+      let non_existent_graphic_novels = filter_non_existent(ids, &existing);
+      return Err(ClientError(MutGraphicNovelServiceError::NonExistentGraphicNovels(
+        non_existent_graphic_novels,
+      )));
+    };
+    Ok(())
+  }
+  async fn validate_translations(
+    &self,
+    translations: &HashMap<Language, CreateGraphicNovelTranslation>,
+    default_language: &Language,
+    covers: &[CreateImage],
+  ) -> Result<(), ServiceError<MutGraphicNovelServiceError>> {
+    if translations.is_empty() {
+      return Err(ClientError(MutGraphicNovelServiceError::NoTranslationsProvided));
+    }
+    if !translations.contains_key(default_language) {
+      return Err(ClientError(
+        MutGraphicNovelServiceError::NoTranslationInLanguageProvided(*default_language),
+      ));
+    }
+    for (current_language, item) in translations {
+      if item.title.is_empty() {
+        return Err(ClientError(MutGraphicNovelServiceError::InvalidTitle(
+          item.title.clone(),
+        )));
+      }
+      if let Some(description) = &item.description {
+        if description.is_empty() {
+          return Err(ClientError(MutGraphicNovelServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+      match item.cover {
+        CreateCover::ImageIndex(index) => {
+          if index >= covers.len() {
+            return Err(ClientError(MutGraphicNovelServiceError::NonExistentTranslationCover(
+              *current_language,
+            )));
+          }
+        }
+        CreateCover::ReuseFromLanguage(language) => {
+          let valid_reuse = match translations.get(&language) {
+            None => false,
+            Some(value) => match value.cover {
+              CreateCover::ImageIndex(_) => true,
+              CreateCover::ReuseFromLanguage(_) => false,
+            },
+          };
+          if *current_language == language || !valid_reuse {
+            return Err(ClientError(MutGraphicNovelServiceError::NonExistentTranslationCover(
+              language,
+            )));
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+  async fn transform_translations(
+    &self,
+    translations: HashMap<Language, CreateGraphicNovelTranslation>,
+    mut covers: Vec<CreateImage>,
+  ) -> Result<HashMap<Language, CreatePartialGraphicNovelTranslation>, ServiceError<MutGraphicNovelServiceError>> {
+    let mut hash_map: HashMap<Language, CreatePartialGraphicNovelTranslation> = HashMap::new();
+    let mut translations: Vec<(Language, CreateGraphicNovelTranslation)> = translations.into_iter().collect();
+    sort_translations(&mut translations);
+    for (language, translation) in translations {
+      let cover = match translation.cover {
+        CreateCover::ImageIndex(index) => {
+          self
+            .mut_image_service
+            .create(covers.remove(index))
+            .await
+            .map_err(|x| match x {
+              ClientError(x) => ClientError(OtherError(Box::new(x))),
+              ServerError(x) => ServerError(x),
+            })?
+        }
+        CreateCover::ReuseFromLanguage(lang) => {
+          hash_map
+            .get(&lang)
+            .expect("Translations are sorted. reuse_from_language should appear last")
+            .clone()
+            .cover
+        }
+      };
+
+      hash_map.insert(
+        language,
+        CreatePartialGraphicNovelTranslation {
+          title: translation.title,
+          description: translation.description,
+          cover,
+        },
+      );
+    }
+    Ok(hash_map)
+  }
+  async fn validate_create(&self, item: &CreateGraphicNovel) -> Result<(), ServiceError<MutGraphicNovelServiceError>> {
+    let data = &item.graphic_novel;
+    if let (Some(start), Some(end)) = (data.publish_start, data.publish_end) {
+      if end < start {
+        return Err(ClientError(MutGraphicNovelServiceError::InvalidPublish));
+      }
+    }
+    if let Some(volumes) = data.volumes {
+      if volumes == 0 {
+        return Err(ClientError(MutGraphicNovelServiceError::InvalidVolumes(volumes)));
+      }
+    }
+    if let Some(chapters) = data.chapters {
+      if chapters == 0 {
+        return Err(ClientError(MutGraphicNovelServiceError::InvalidChapters(chapters)));
+      }
+    }
+    self
+      .validate_translations(&data.translations, &self.default_language, &item.covers)
+      .await?;
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn sort_translations(translations: &mut [(Language, CreateGraphicNovelTranslation)]) {
+  translations.sort_by(|(_, x), (_, y)| {
+    let x_reuse = match x.cover {
+      CreateCover::ImageIndex(_) => false,
+      CreateCover::ReuseFromLanguage(_) => true,
+    };
+    let y_reuse = match y.cover {
+      CreateCover::ImageIndex(_) => false,
+      CreateCover::ReuseFromLanguage(_) => true,
+    };
+    if x_reuse && !y_reuse {
+      return Ordering::Greater;
+    }
+    if !x_reuse && y_reuse {
+      return Ordering::Less;
+    }
+    Ordering::Equal
+  });
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::GraphicNovelChapterRepository;
+use services::graphic_novel_service::graphic_novel_chapter_service::{
+  GraphicNovelChapterService, GraphicNovelChapterServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelChapterService<'a> {
+  graphic_novel_chapter_repository: Arc<dyn GraphicNovelChapterRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelChapterService<'a> {
+  pub fn new(
+    graphic_novel_chapter_repository: Arc<dyn GraphicNovelChapterRepository + 'a>,
+  ) -> DefaultGraphicNovelChapterService<'a> {
+    DefaultGraphicNovelChapterService {
+      graphic_novel_chapter_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelChapterService for DefaultGraphicNovelChapterService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelChapter>, ServiceError<GraphicNovelChapterServiceError>> {
+    Ok(
+      self
+        .graphic_novel_chapter_repository
+        .get(graphic_novel_id, volume, language, pagination)
+        .await?,
+    )
+  }
+
+  async fn get_by_chapter(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    chapter: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelChapter>, ServiceError<GraphicNovelChapterServiceError>> {
+    Ok(
+      self
+        .graphic_novel_chapter_repository
+        .get_by_chapter(graphic_novel_id, volume, chapter, language)
+        .await?,
+    )
+  }
+}
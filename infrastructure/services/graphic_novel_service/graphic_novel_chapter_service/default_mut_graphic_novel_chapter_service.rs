@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapter;
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::enums::language::Language;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::mut_graphic_novel_chapter_repository::MutGraphicNovelChapterRepository;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::GraphicNovelChapterRepository;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::GraphicNovelVolumeRepository;
+use services::graphic_novel_service::graphic_novel_chapter_service::mut_graphic_novel_chapter_service::{
+  MutGraphicNovelChapterService, MutGraphicNovelChapterServiceError,
+};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+pub struct DefaultMutGraphicNovelChapterService<'a> {
+  default_language: Language,
+  graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+  graphic_novel_chapter_repository: Arc<dyn GraphicNovelChapterRepository + 'a>,
+  mut_graphic_novel_chapter_repository: Arc<dyn MutGraphicNovelChapterRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelChapterService<'a> {
+  pub fn new(
+    default_language: Language,
+    graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+    graphic_novel_chapter_repository: Arc<dyn GraphicNovelChapterRepository + 'a>,
+    mut_graphic_novel_chapter_repository: Arc<dyn MutGraphicNovelChapterRepository + 'a>,
+  ) -> DefaultMutGraphicNovelChapterService<'a> {
+    DefaultMutGraphicNovelChapterService {
+      default_language,
+      graphic_novel_volume_repository,
+      graphic_novel_chapter_repository,
+      mut_graphic_novel_chapter_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelChapterService for DefaultMutGraphicNovelChapterService<'_> {
+  async fn create(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    item: CreateGraphicNovelChapter,
+  ) -> Result<GraphicNovelChapter, ServiceError<MutGraphicNovelChapterServiceError>> {
+    self.validate_create(graphic_novel_id, volume, &item).await?;
+    let chapter = item.chapter;
+    self
+      .mut_graphic_novel_chapter_repository
+      .create(graphic_novel_id, volume, item)
+      .await?;
+    let chapter = self
+      .graphic_novel_chapter_repository
+      .get_by_chapter(graphic_novel_id, volume, chapter, self.default_language)
+      .await?
+      .expect("Chapter was just created");
+    Ok(chapter)
+  }
+
+  async fn delete(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    chapter: u16,
+  ) -> Result<(), ServiceError<MutGraphicNovelChapterServiceError>> {
+    let existing = self
+      .graphic_novel_chapter_repository
+      .get_by_chapter(graphic_novel_id, volume, chapter, self.default_language)
+      .await?;
+    if existing.is_none() {
+      return Err(ClientError(MutGraphicNovelChapterServiceError::NonExistentChapter(
+        chapter,
+      )));
+    }
+    Ok(
+      self
+        .mut_graphic_novel_chapter_repository
+        .delete(graphic_novel_id, chapter)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelChapterService<'_> {
+  async fn validate_create(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    item: &CreateGraphicNovelChapter,
+  ) -> Result<(), ServiceError<MutGraphicNovelChapterServiceError>> {
+    if !self
+      .graphic_novel_volume_repository
+      .exists(graphic_novel_id, volume)
+      .await?
+    {
+      return Err(ClientError(MutGraphicNovelChapterServiceError::NonExistentVolume(
+        volume,
+      )));
+    }
+    if self
+      .graphic_novel_chapter_repository
+      .exists(graphic_novel_id, item.chapter)
+      .await?
+    {
+      return Err(ClientError(MutGraphicNovelChapterServiceError::ChapterAlreadyExists(
+        item.chapter,
+      )));
+    }
+    if item.pages == 0 {
+      return Err(ClientError(MutGraphicNovelChapterServiceError::InvalidPages(
+        item.pages,
+      )));
+    }
+    if item.translations.is_empty() {
+      return Err(ClientError(MutGraphicNovelChapterServiceError::NoTranslationsProvided));
+    }
+    if !item.translations.contains_key(&self.default_language) {
+      return Err(ClientError(
+        MutGraphicNovelChapterServiceError::NoTranslationInLanguageProvided(self.default_language),
+      ));
+    }
+    for translation in item.translations.values() {
+      if translation.title.is_empty() {
+        return Err(ClientError(MutGraphicNovelChapterServiceError::InvalidTitle(
+          translation.title.clone(),
+        )));
+      }
+      if let Some(description) = &translation.description {
+        if description.is_empty() {
+          return Err(ClientError(MutGraphicNovelChapterServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_character::GraphicNovelCharacter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::GraphicNovelCharacterRepository;
+use services::graphic_novel_service::graphic_novel_character_service::{
+  GraphicNovelCharacterService, GraphicNovelCharacterServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelCharacterService<'a> {
+  graphic_novel_character_repository: Arc<dyn GraphicNovelCharacterRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelCharacterService<'a> {
+  pub fn new(
+    graphic_novel_character_repository: Arc<dyn GraphicNovelCharacterRepository + 'a>,
+  ) -> DefaultGraphicNovelCharacterService<'a> {
+    DefaultGraphicNovelCharacterService {
+      graphic_novel_character_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelCharacterService for DefaultGraphicNovelCharacterService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelCharacter>, ServiceError<GraphicNovelCharacterServiceError>> {
+    Ok(
+      self
+        .graphic_novel_character_repository
+        .get(graphic_novel_id, language, pagination)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::character_repository::CharacterRepository;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::mut_graphic_novel_character_repository::MutGraphicNovelCharacterRepository;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::GraphicNovelCharacterRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use services::graphic_novel_service::graphic_novel_character_service::mut_graphic_novel_character_service::{
+  MutGraphicNovelCharacterService, MutGraphicNovelCharacterServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGraphicNovelCharacterService<'a> {
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_character_repository: Arc<dyn GraphicNovelCharacterRepository + 'a>,
+  mut_graphic_novel_character_repository: Arc<dyn MutGraphicNovelCharacterRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelCharacterService<'a> {
+  pub fn new(
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    graphic_novel_character_repository: Arc<dyn GraphicNovelCharacterRepository + 'a>,
+    mut_graphic_novel_character_repository: Arc<dyn MutGraphicNovelCharacterRepository + 'a>,
+    character_repository: Arc<dyn CharacterRepository + 'a>,
+  ) -> DefaultMutGraphicNovelCharacterService<'a> {
+    DefaultMutGraphicNovelCharacterService {
+      graphic_novel_repository,
+      graphic_novel_character_repository,
+      mut_graphic_novel_character_repository,
+      character_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelCharacterService for DefaultMutGraphicNovelCharacterService<'_> {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>> {
+    self.validate_add(graphic_novel_id, characters).await?;
+    Ok(
+      self
+        .mut_graphic_novel_character_repository
+        .add(graphic_novel_id, characters)
+        .await?,
+    )
+  }
+
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>> {
+    self.validate_remove(graphic_novel_id, characters).await?;
+    Ok(
+      self
+        .mut_graphic_novel_character_repository
+        .remove(graphic_novel_id, characters)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelCharacterService<'_> {
+  async fn validate_add(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>> {
+    self.validate(graphic_novel_id, characters).await?;
+    let existing_characters = self.character_repository.filter_existing(characters).await?;
+    if existing_characters.len() != characters.len() {
+      let non_existent_characters = filter_non_existent(characters, &existing_characters);
+      let error = MutGraphicNovelCharacterServiceError::NonExistent(non_existent_characters);
+      return Err(ServiceError::ClientError(error));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>> {
+    self.validate(graphic_novel_id, characters).await?;
+    let existing = self
+      .graphic_novel_character_repository
+      .filter_existing(graphic_novel_id, characters)
+      .await?;
+    if existing.len() != characters.len() {
+      let non_existent_characters = filter_non_existent(characters, &existing);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelCharacterServiceError::NotAssociated(non_existent_characters),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>> {
+    let ids = self
+      .graphic_novel_repository
+      .filter_existing(&[graphic_novel_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelCharacterServiceError::NonExistentGraphicNovel(graphic_novel_id),
+      ));
+    }
+    if characters.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelCharacterServiceError::NoCharactersProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::genre::Genre;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::GraphicNovelGenreRepository;
+use services::graphic_novel_service::graphic_novel_genre_service::{
+  GraphicNovelGenreService, GraphicNovelGenreServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelGenreService<'a> {
+  graphic_novel_genre_repository: Arc<dyn GraphicNovelGenreRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelGenreService<'a> {
+  pub fn new(
+    graphic_novel_genre_repository: Arc<dyn GraphicNovelGenreRepository + 'a>,
+  ) -> DefaultGraphicNovelGenreService<'a> {
+    DefaultGraphicNovelGenreService {
+      graphic_novel_genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelGenreService for DefaultGraphicNovelGenreService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Genre>, ServiceError<GraphicNovelGenreServiceError>> {
+    Ok(
+      self
+        .graphic_novel_genre_repository
+        .get(graphic_novel_id, language, pagination)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::genre_repository::GenreRepository;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::mut_graphic_novel_genre_repository::MutGraphicNovelGenreRepository;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::GraphicNovelGenreRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use services::graphic_novel_service::graphic_novel_genre_service::mut_graphic_novel_genre_service::{
+  MutGraphicNovelGenreService, MutGraphicNovelGenreServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGraphicNovelGenreService<'a> {
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_genre_repository: Arc<dyn GraphicNovelGenreRepository + 'a>,
+  mut_graphic_novel_genre_repository: Arc<dyn MutGraphicNovelGenreRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelGenreService<'a> {
+  pub fn new(
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    graphic_novel_genre_repository: Arc<dyn GraphicNovelGenreRepository + 'a>,
+    mut_graphic_novel_genre_repository: Arc<dyn MutGraphicNovelGenreRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+  ) -> DefaultMutGraphicNovelGenreService<'a> {
+    DefaultMutGraphicNovelGenreService {
+      graphic_novel_repository,
+      graphic_novel_genre_repository,
+      mut_graphic_novel_genre_repository,
+      genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelGenreService for DefaultMutGraphicNovelGenreService<'_> {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>> {
+    self.validate_add(graphic_novel_id, genres).await?;
+    Ok(
+      self
+        .mut_graphic_novel_genre_repository
+        .add(graphic_novel_id, genres)
+        .await?,
+    )
+  }
+
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>> {
+    self.validate_remove(graphic_novel_id, genres).await?;
+    Ok(
+      self
+        .mut_graphic_novel_genre_repository
+        .remove(graphic_novel_id, genres)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelGenreService<'_> {
+  async fn validate_add(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>> {
+    self.validate(graphic_novel_id, genres).await?;
+    let existing_genres = self.genre_repository.filter_existing(genres).await?;
+    if existing_genres.len() != genres.len() {
+      let non_existent_genres = filter_non_existent(genres, &existing_genres);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelGenreServiceError::NonExistent(non_existent_genres),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>> {
+    self.validate(graphic_novel_id, genres).await?;
+    let existing = self
+      .graphic_novel_genre_repository
+      .filter_existing(graphic_novel_id, genres)
+      .await?;
+    if existing.len() != genres.len() {
+      let not_associated = filter_non_existent(genres, &existing);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelGenreServiceError::NotAssociated(not_associated),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>> {
+    let ids = self
+      .graphic_novel_repository
+      .filter_existing(&[graphic_novel_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelGenreServiceError::NonExistentGraphicNovel(graphic_novel_id),
+      ));
+    }
+    if genres.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelGenreServiceError::NoGenresProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_involved::GraphicNovelInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::GraphicNovelInvolvedRepository;
+use services::graphic_novel_service::graphic_novel_involved_service::{
+  GraphicNovelInvolvedService, GraphicNovelInvolvedServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelInvolvedService<'a> {
+  graphic_novel_involved_repository: Arc<dyn GraphicNovelInvolvedRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelInvolvedService<'a> {
+  pub fn new(
+    graphic_novel_involved_repository: Arc<dyn GraphicNovelInvolvedRepository + 'a>,
+  ) -> DefaultGraphicNovelInvolvedService<'a> {
+    DefaultGraphicNovelInvolvedService {
+      graphic_novel_involved_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelInvolvedService for DefaultGraphicNovelInvolvedService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelInvolved>, ServiceError<GraphicNovelInvolvedServiceError>> {
+    Ok(
+      self
+        .graphic_novel_involved_repository
+        .get(graphic_novel_id, language, pagination)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::mut_graphic_novel_involved_repository::MutGraphicNovelInvolvedRepository;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::GraphicNovelInvolvedRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use repositories::person_repository::PersonRepository;
+use repositories::role_repository::RoleRepository;
+use services::graphic_novel_service::graphic_novel_involved_service::mut_graphic_novel_involved_service::{
+  MutGraphicNovelInvolvedService, MutGraphicNovelInvolvedServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGraphicNovelInvolvedService<'a> {
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_involved_repository: Arc<dyn GraphicNovelInvolvedRepository + 'a>,
+  mut_graphic_novel_involved_repository: Arc<dyn MutGraphicNovelInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelInvolvedService<'a> {
+  pub fn new(
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    graphic_novel_involved_repository: Arc<dyn GraphicNovelInvolvedRepository + 'a>,
+    mut_graphic_novel_involved_repository: Arc<dyn MutGraphicNovelInvolvedRepository + 'a>,
+    person_repository: Arc<dyn PersonRepository + 'a>,
+    role_repository: Arc<dyn RoleRepository + 'a>,
+  ) -> DefaultMutGraphicNovelInvolvedService<'a> {
+    DefaultMutGraphicNovelInvolvedService {
+      graphic_novel_repository,
+      graphic_novel_involved_repository,
+      mut_graphic_novel_involved_repository,
+      person_repository,
+      role_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelInvolvedService for DefaultMutGraphicNovelInvolvedService<'_> {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>> {
+    self.validate_add(graphic_novel_id, involved).await?;
+    Ok(
+      self
+        .mut_graphic_novel_involved_repository
+        .add(graphic_novel_id, involved)
+        .await?,
+    )
+  }
+
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>> {
+    self.validate_remove(graphic_novel_id, involved).await?;
+    Ok(
+      self
+        .mut_graphic_novel_involved_repository
+        .remove(graphic_novel_id, involved)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelInvolvedService<'_> {
+  async fn validate_add(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>> {
+    self.validate(graphic_novel_id, involved).await?;
+    let people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+    let existing_people = self.person_repository.filter_existing(&people).await?;
+
+    if existing_people.len() != involved.len() {
+      let non_existent_people = filter_non_existent(&people, &existing_people);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelInvolvedServiceError::NonExistentPeople(non_existent_people),
+      ));
+    };
+    let roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+    let existing_roles = self.role_repository.filter_existing(&roles).await?;
+    if existing_roles.len() != involved.len() {
+      let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelInvolvedServiceError::NonExistentRoles(non_existent_roles),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>> {
+    self.validate(graphic_novel_id, involved).await?;
+    let existing = self
+      .graphic_novel_involved_repository
+      .filter_existing(graphic_novel_id, involved)
+      .await?;
+    if existing.len() != involved.len() {
+      let non_existent_involved = filter_involved_non_existent(involved, &existing);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelInvolvedServiceError::NonExistentAssociation(non_existent_involved),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>> {
+    let ids = self
+      .graphic_novel_repository
+      .filter_existing(&[graphic_novel_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelInvolvedServiceError::NonExistentGraphicNovel(graphic_novel_id),
+      ));
+    }
+    if involved.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelInvolvedServiceError::NoInvolvedProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn filter_involved_non_existent(items: &[InvolvedId], existing: &[InvolvedId]) -> Vec<InvolvedId> {
+  items
+    .iter()
+    .filter_map(|x| {
+      existing
+        .iter()
+        .find(|y| y.role_id == x.role_id && y.person_id == x.person_id)
+        .map_or_else(|| Some(x.clone()), |_| None)
+    })
+    .collect()
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::GraphicNovelPublisherRepository;
+use services::graphic_novel_service::graphic_novel_publisher_service::{
+  GraphicNovelPublisherService, GraphicNovelPublisherServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelPublisherService<'a> {
+  graphic_novel_publisher_repository: Arc<dyn GraphicNovelPublisherRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelPublisherService<'a> {
+  pub fn new(
+    graphic_novel_publisher_repository: Arc<dyn GraphicNovelPublisherRepository + 'a>,
+  ) -> DefaultGraphicNovelPublisherService<'a> {
+    DefaultGraphicNovelPublisherService {
+      graphic_novel_publisher_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelPublisherService for DefaultGraphicNovelPublisherService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Company>, ServiceError<GraphicNovelPublisherServiceError>> {
+    Ok(
+      self
+        .graphic_novel_publisher_repository
+        .get(graphic_novel_id, pagination)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::mut_graphic_novel_publisher_repository::MutGraphicNovelPublisherRepository;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::GraphicNovelPublisherRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use repositories::company_repository::CompanyRepository;
+use services::graphic_novel_service::graphic_novel_publisher_service::mut_graphic_novel_publisher_service::{
+  MutGraphicNovelPublisherService, MutGraphicNovelPublisherServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGraphicNovelPublisherService<'a> {
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_publisher_repository: Arc<dyn GraphicNovelPublisherRepository + 'a>,
+  mut_graphic_novel_publisher_repository: Arc<dyn MutGraphicNovelPublisherRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelPublisherService<'a> {
+  pub fn new(
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    graphic_novel_publisher_repository: Arc<dyn GraphicNovelPublisherRepository + 'a>,
+    mut_graphic_novel_publisher_repository: Arc<dyn MutGraphicNovelPublisherRepository + 'a>,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+  ) -> DefaultMutGraphicNovelPublisherService<'a> {
+    DefaultMutGraphicNovelPublisherService {
+      graphic_novel_repository,
+      graphic_novel_publisher_repository,
+      mut_graphic_novel_publisher_repository,
+      company_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelPublisherService for DefaultMutGraphicNovelPublisherService<'_> {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>> {
+    self.validate_add(graphic_novel_id, publishers).await?;
+    Ok(
+      self
+        .mut_graphic_novel_publisher_repository
+        .add(graphic_novel_id, publishers)
+        .await?,
+    )
+  }
+
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>> {
+    self.validate_remove(graphic_novel_id, publishers).await?;
+    Ok(
+      self
+        .mut_graphic_novel_publisher_repository
+        .remove(graphic_novel_id, publishers)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelPublisherService<'_> {
+  async fn validate_add(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>> {
+    self.validate(graphic_novel_id, publishers).await?;
+    let existing_publishers = self.company_repository.filter_existing(publishers).await?;
+    if existing_publishers.len() != publishers.len() {
+      let non_existent_publishers = filter_non_existent(publishers, &existing_publishers);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelPublisherServiceError::NonExistent(non_existent_publishers),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>> {
+    self.validate(graphic_novel_id, publishers).await?;
+    let existing = self
+      .graphic_novel_publisher_repository
+      .filter_existing(graphic_novel_id, publishers)
+      .await?;
+    if existing.len() != publishers.len() {
+      let not_associated = filter_non_existent(publishers, &existing);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelPublisherServiceError::NotAssociated(not_associated),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>> {
+    let ids = self
+      .graphic_novel_repository
+      .filter_existing(&[graphic_novel_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelPublisherServiceError::NonExistentGraphicNovel(graphic_novel_id),
+      ));
+    }
+    if publishers.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelPublisherServiceError::NoPublishersProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
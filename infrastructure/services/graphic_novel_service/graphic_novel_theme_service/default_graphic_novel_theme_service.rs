@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::theme::Theme;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::GraphicNovelThemeRepository;
+use services::graphic_novel_service::graphic_novel_theme_service::{
+  GraphicNovelThemeService, GraphicNovelThemeServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelThemeService<'a> {
+  graphic_novel_theme_repository: Arc<dyn GraphicNovelThemeRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelThemeService<'a> {
+  pub fn new(
+    graphic_novel_theme_repository: Arc<dyn GraphicNovelThemeRepository + 'a>,
+  ) -> DefaultGraphicNovelThemeService<'a> {
+    DefaultGraphicNovelThemeService {
+      graphic_novel_theme_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelThemeService for DefaultGraphicNovelThemeService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Theme>, ServiceError<GraphicNovelThemeServiceError>> {
+    Ok(
+      self
+        .graphic_novel_theme_repository
+        .get(graphic_novel_id, language, pagination)
+        .await?,
+    )
+  }
+}
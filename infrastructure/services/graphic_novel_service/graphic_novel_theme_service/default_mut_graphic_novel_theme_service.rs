@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::mut_graphic_novel_theme_repository::MutGraphicNovelThemeRepository;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::GraphicNovelThemeRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use repositories::theme_repository::ThemeRepository;
+use services::graphic_novel_service::graphic_novel_theme_service::mut_graphic_novel_theme_service::{
+  MutGraphicNovelThemeService, MutGraphicNovelThemeServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutGraphicNovelThemeService<'a> {
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_theme_repository: Arc<dyn GraphicNovelThemeRepository + 'a>,
+  mut_graphic_novel_theme_repository: Arc<dyn MutGraphicNovelThemeRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelThemeService<'a> {
+  pub fn new(
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    graphic_novel_theme_repository: Arc<dyn GraphicNovelThemeRepository + 'a>,
+    mut_graphic_novel_theme_repository: Arc<dyn MutGraphicNovelThemeRepository + 'a>,
+    theme_repository: Arc<dyn ThemeRepository + 'a>,
+  ) -> DefaultMutGraphicNovelThemeService<'a> {
+    DefaultMutGraphicNovelThemeService {
+      graphic_novel_repository,
+      graphic_novel_theme_repository,
+      mut_graphic_novel_theme_repository,
+      theme_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelThemeService for DefaultMutGraphicNovelThemeService<'_> {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>> {
+    self.validate_add(graphic_novel_id, themes).await?;
+    Ok(
+      self
+        .mut_graphic_novel_theme_repository
+        .add(graphic_novel_id, themes)
+        .await?,
+    )
+  }
+
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>> {
+    self.validate_remove(graphic_novel_id, themes).await?;
+    Ok(
+      self
+        .mut_graphic_novel_theme_repository
+        .remove(graphic_novel_id, themes)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelThemeService<'_> {
+  async fn validate_add(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>> {
+    self.validate(graphic_novel_id, themes).await?;
+    let existing_themes = self.theme_repository.filter_existing(themes).await?;
+    if existing_themes.len() != themes.len() {
+      let non_existent_themes = filter_non_existent(themes, &existing_themes);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelThemeServiceError::NonExistent(non_existent_themes),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>> {
+    self.validate(graphic_novel_id, themes).await?;
+    let existing = self
+      .graphic_novel_theme_repository
+      .filter_existing(graphic_novel_id, themes)
+      .await?;
+    if existing.len() != themes.len() {
+      let not_associated = filter_non_existent(themes, &existing);
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelThemeServiceError::NotAssociated(not_associated),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>> {
+    let ids = self
+      .graphic_novel_repository
+      .filter_existing(&[graphic_novel_id])
+      .await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelThemeServiceError::NonExistentGraphicNovel(graphic_novel_id),
+      ));
+    }
+    if themes.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutGraphicNovelThemeServiceError::NoThemesProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::GraphicNovelVolumeRepository;
+use services::graphic_novel_service::graphic_novel_volume_service::{
+  GraphicNovelVolumeService, GraphicNovelVolumeServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultGraphicNovelVolumeService<'a> {
+  graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+}
+
+impl<'a> DefaultGraphicNovelVolumeService<'a> {
+  pub fn new(
+    graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+  ) -> DefaultGraphicNovelVolumeService<'a> {
+    DefaultGraphicNovelVolumeService {
+      graphic_novel_volume_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl GraphicNovelVolumeService for DefaultGraphicNovelVolumeService<'_> {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelVolume>, ServiceError<GraphicNovelVolumeServiceError>> {
+    Ok(
+      self
+        .graphic_novel_volume_repository
+        .get(graphic_novel_id, language, pagination)
+        .await?,
+    )
+  }
+
+  async fn get_by_volume(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelVolume>, ServiceError<GraphicNovelVolumeServiceError>> {
+    Ok(
+      self
+        .graphic_novel_volume_repository
+        .get_by_volume(graphic_novel_id, volume, language)
+        .await?,
+    )
+  }
+}
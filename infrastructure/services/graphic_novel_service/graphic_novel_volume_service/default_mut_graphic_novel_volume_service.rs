@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolume;
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::enums::language::Language;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::mut_graphic_novel_volume_repository::MutGraphicNovelVolumeRepository;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::GraphicNovelVolumeRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use services::graphic_novel_service::graphic_novel_volume_service::mut_graphic_novel_volume_service::{
+  MutGraphicNovelVolumeService, MutGraphicNovelVolumeServiceError,
+};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+pub struct DefaultMutGraphicNovelVolumeService<'a> {
+  default_language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+  mut_graphic_novel_volume_repository: Arc<dyn MutGraphicNovelVolumeRepository + 'a>,
+}
+
+impl<'a> DefaultMutGraphicNovelVolumeService<'a> {
+  pub fn new(
+    default_language: Language,
+    graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+    graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+    mut_graphic_novel_volume_repository: Arc<dyn MutGraphicNovelVolumeRepository + 'a>,
+  ) -> DefaultMutGraphicNovelVolumeService<'a> {
+    DefaultMutGraphicNovelVolumeService {
+      default_language,
+      graphic_novel_repository,
+      graphic_novel_volume_repository,
+      mut_graphic_novel_volume_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutGraphicNovelVolumeService for DefaultMutGraphicNovelVolumeService<'_> {
+  async fn create(
+    &self,
+    graphic_novel_id: u32,
+    item: CreateGraphicNovelVolume,
+  ) -> Result<GraphicNovelVolume, ServiceError<MutGraphicNovelVolumeServiceError>> {
+    self.validate_create(graphic_novel_id, &item).await?;
+    let volume = item.volume;
+    self
+      .mut_graphic_novel_volume_repository
+      .create(graphic_novel_id, item)
+      .await?;
+    let volume = self
+      .graphic_novel_volume_repository
+      .get_by_volume(graphic_novel_id, volume, self.default_language)
+      .await?
+      .expect("Volume was just created");
+    Ok(volume)
+  }
+
+  async fn delete(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+  ) -> Result<(), ServiceError<MutGraphicNovelVolumeServiceError>> {
+    if !self
+      .graphic_novel_volume_repository
+      .exists(graphic_novel_id, volume)
+      .await?
+    {
+      return Err(ClientError(MutGraphicNovelVolumeServiceError::NonExistentVolume(
+        volume,
+      )));
+    }
+    Ok(
+      self
+        .mut_graphic_novel_volume_repository
+        .delete(graphic_novel_id, volume)
+        .await?,
+    )
+  }
+}
+
+impl DefaultMutGraphicNovelVolumeService<'_> {
+  async fn validate_create(
+    &self,
+    graphic_novel_id: u32,
+    item: &CreateGraphicNovelVolume,
+  ) -> Result<(), ServiceError<MutGraphicNovelVolumeServiceError>> {
+    if self
+      .graphic_novel_repository
+      .filter_existing(&[graphic_novel_id])
+      .await?
+      .is_empty()
+    {
+      return Err(ClientError(MutGraphicNovelVolumeServiceError::NonExistentGraphicNovel(
+        graphic_novel_id,
+      )));
+    }
+    if self
+      .graphic_novel_volume_repository
+      .exists(graphic_novel_id, item.volume)
+      .await?
+    {
+      return Err(ClientError(MutGraphicNovelVolumeServiceError::VolumeAlreadyExists(
+        item.volume,
+      )));
+    }
+    if let Some(pages) = item.pages {
+      if pages == 0 {
+        return Err(ClientError(MutGraphicNovelVolumeServiceError::InvalidPages(pages)));
+      }
+    }
+    if item.translations.is_empty() {
+      return Err(ClientError(MutGraphicNovelVolumeServiceError::NoTranslationsProvided));
+    }
+    if !item.translations.contains_key(&self.default_language) {
+      return Err(ClientError(
+        MutGraphicNovelVolumeServiceError::NoTranslationInLanguageProvided(self.default_language),
+      ));
+    }
+    for translation in item.translations.values() {
+      if translation.title.is_empty() {
+        return Err(ClientError(MutGraphicNovelVolumeServiceError::InvalidTitle(
+          translation.title.clone(),
+        )));
+      }
+      if let Some(description) = &translation.description {
+        if description.is_empty() {
+          return Err(ClientError(MutGraphicNovelVolumeServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+    }
+    Ok(())
+  }
+}
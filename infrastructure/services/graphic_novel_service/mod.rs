@@ -0,0 +1,9 @@
+pub mod default_graphic_novel_service;
+pub mod default_mut_graphic_novel_service;
+pub mod graphic_novel_chapter_service;
+pub mod graphic_novel_character_service;
+pub mod graphic_novel_genre_service;
+pub mod graphic_novel_involved_service;
+pub mod graphic_novel_publisher_service;
+pub mod graphic_novel_theme_service;
+pub mod graphic_novel_volume_service;
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -12,6 +13,8 @@ use services::image_service::mut_image_service::{MutImageService, MutImageServic
 use services::traits::service_error::ServiceError;
 use services::traits::service_error::ServiceError::ClientError;
 
+use crate::services::map_server_error;
+
 pub struct DefaultMutImageService<'a> {
   mut_image_repository: Arc<dyn MutImageRepository + 'a>,
   mut_file_service: Arc<dyn MutFileService + 'a>,
@@ -55,4 +58,31 @@ impl MutImageService for DefaultMutImageService<'_> {
     };
     Ok(self.mut_image_repository.create(image).await?)
   }
+
+  async fn delete(&self, image: &Image) -> Result<(), ServiceError<MutImageServiceError>> {
+    let files = self.delete_record(image).await?;
+    for file in files {
+      self.mut_file_service.delete(&file).await.map_err(|x| match x {
+        ClientError(x) => ClientError(OtherError(Box::new(x))),
+        ServiceError::ServerError(x) => ServiceError::ServerError(x),
+      })?;
+    }
+    Ok(())
+  }
+
+  async fn delete_record(&self, image: &Image) -> Result<Vec<String>, ServiceError<MutImageServiceError>> {
+    self.mut_image_repository.delete(&[image.id]).await?;
+    image
+      .versions
+      .iter()
+      .filter_map(|version| version.uri.strip_prefix(self.display_path))
+      .map(|file_name| {
+        Path::new(self.path)
+          .join(file_name)
+          .to_str()
+          .map(str::to_string)
+          .ok_or_else(|| map_server_error(Box::from("Invalid image path")))
+      })
+      .collect()
+  }
 }
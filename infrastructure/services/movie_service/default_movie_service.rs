@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::movie::movie_statistic::MovieStatistic;
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::movie_repository::MovieRepository;
+use services::movie_service::{MovieService, MovieServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+pub struct DefaultMovieService<'a> {
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+}
+
+impl<'a> DefaultMovieService<'a> {
+  pub fn new(movie_repository: Arc<dyn MovieRepository + 'a>) -> DefaultMovieService<'a> {
+    DefaultMovieService { movie_repository }
+  }
+}
+
+#[async_trait]
+impl MovieService for DefaultMovieService<'_> {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Movie>, ServiceError<MovieServiceError>> {
+    Ok(self.movie_repository.get(language, pagination).await?)
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Movie>, ServiceError<MovieServiceError>> {
+    Ok(self.movie_repository.get_by_id(id, language).await?)
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Movie>, ServiceError<MovieServiceError>> {
+    Ok(self.movie_repository.get_by_title(title, language, pagination).await?)
+  }
+
+  async fn get_statistics(&self, movie_ids: &[u32]) -> Result<Vec<MovieStatistic>, ServiceError<MovieServiceError>> {
+    let existing = self.movie_repository.filter_existing(movie_ids).await?;
+    if existing.len() != movie_ids.len() {
+      let non_existent_movies = filter_non_existent(movie_ids, &existing);
+      return Err(ClientError(MovieServiceError::NonExistentMovies(non_existent_movies)));
+    };
+    Ok(self.movie_repository.get_statistics(movie_ids).await?)
+  }
+}
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
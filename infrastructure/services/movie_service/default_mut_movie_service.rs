@@ -0,0 +1,271 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::create_book::CreateCover;
+use domain::entities::image::create_image::CreateImage;
+use domain::entities::movie::create_movie::{CreateMovie, CreateMovieTranslation};
+use domain::entities::movie::create_partial_movie::{CreatePartialMovie, CreatePartialMovieTranslation};
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use repositories::genre_repository::GenreRepository;
+use repositories::movie_repository::mut_movie_repository::MutMovieRepository;
+use repositories::movie_repository::MovieRepository;
+use repositories::person_repository::PersonRepository;
+use repositories::role_repository::RoleRepository;
+use repositories::theme_repository::ThemeRepository;
+use services::image_service::mut_image_service::MutImageService;
+use services::movie_service::mut_movie_service::MutMovieServiceError::OtherError;
+use services::movie_service::mut_movie_service::{MutMovieService, MutMovieServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+pub struct DefaultMutMovieService<'a> {
+  default_language: Language,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  mut_movie_repository: Arc<dyn MutMovieRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+}
+
+impl<'a> DefaultMutMovieService<'a> {
+  pub fn new(
+    default_language: Language,
+    movie_repository: Arc<dyn MovieRepository + 'a>,
+    mut_movie_repository: Arc<dyn MutMovieRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+    theme_repository: Arc<dyn ThemeRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+    person_repository: Arc<dyn PersonRepository + 'a>,
+    role_repository: Arc<dyn RoleRepository + 'a>,
+  ) -> DefaultMutMovieService<'a> {
+    DefaultMutMovieService {
+      default_language,
+      movie_repository,
+      mut_movie_repository,
+      mut_image_service,
+      theme_repository,
+      genre_repository,
+      person_repository,
+      role_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutMovieService for DefaultMutMovieService<'_> {
+  async fn create(&self, item: CreateMovie) -> Result<Movie, ServiceError<MutMovieServiceError>> {
+    self.validate_create(&item).await?;
+    let data = item.movie;
+    let covers = item.covers;
+    let translations = self.transform_translations(data.translations, covers).await?;
+
+    let partial_movie = CreatePartialMovie {
+      airing: data.airing,
+      length: data.length,
+      translations,
+      genres: data.genres.unwrap_or_default(),
+      themes: data.themes.unwrap_or_default(),
+      involved: data.involved.unwrap_or_default(),
+    };
+    Ok(self.mut_movie_repository.create(partial_movie).await?)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutMovieServiceError>> {
+    self.validate_delete(ids).await?;
+    Ok(self.mut_movie_repository.delete(ids).await?)
+  }
+}
+
+impl<'a> DefaultMutMovieService<'a> {
+  async fn validate_delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutMovieServiceError>> {
+    if ids.is_empty() {
+      return Err(ClientError(MutMovieServiceError::NoIdsProvided));
+    }
+
+    let existing = self.movie_repository.filter_existing(ids).await?;
+    if existing.len() != ids.len() {
+      let non_existent_movies = filter_non_existent(ids, &existing);
+      return Err(ClientError(MutMovieServiceError::NonExistentMovies(
+        non_existent_movies,
+      )));
+    };
+    Ok(())
+  }
+  async fn validate_translations(
+    &self,
+    translations: &HashMap<Language, CreateMovieTranslation>,
+    default_language: &Language,
+    covers: &[CreateImage],
+  ) -> Result<(), ServiceError<MutMovieServiceError>> {
+    if translations.is_empty() {
+      return Err(ClientError(MutMovieServiceError::NoTranslationsProvided));
+    }
+    if !translations.contains_key(default_language) {
+      return Err(ClientError(MutMovieServiceError::NoTranslationInLanguageProvided(
+        *default_language,
+      )));
+    }
+    for (current_language, item) in translations {
+      if item.title.is_empty() {
+        return Err(ClientError(MutMovieServiceError::InvalidTitle(item.title.clone())));
+      }
+      if let Some(description) = &item.description {
+        if description.is_empty() {
+          return Err(ClientError(MutMovieServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+      match item.cover {
+        CreateCover::ImageIndex(index) => {
+          if index >= covers.len() {
+            return Err(ClientError(MutMovieServiceError::NonExistentTranslationCover(
+              *current_language,
+            )));
+          }
+        }
+        CreateCover::ReuseFromLanguage(language) => {
+          let valid_reuse = match translations.get(&language) {
+            None => false,
+            Some(value) => match value.cover {
+              CreateCover::ImageIndex(_) => true,
+              CreateCover::ReuseFromLanguage(_) => false,
+            },
+          };
+          if *current_language == language || !valid_reuse {
+            return Err(ClientError(MutMovieServiceError::NonExistentTranslationCover(language)));
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+  async fn transform_translations(
+    &self,
+    translations: HashMap<Language, CreateMovieTranslation>,
+    mut covers: Vec<CreateImage>,
+  ) -> Result<HashMap<Language, CreatePartialMovieTranslation>, ServiceError<MutMovieServiceError>> {
+    let mut hash_map: HashMap<Language, CreatePartialMovieTranslation> = HashMap::new();
+    let mut translations: Vec<(Language, CreateMovieTranslation)> = translations.into_iter().collect();
+    sort_translations(&mut translations);
+    for (language, translation) in translations {
+      let cover = match translation.cover {
+        CreateCover::ImageIndex(index) => {
+          self
+            .mut_image_service
+            .create(covers.remove(index))
+            .await
+            .map_err(|x| match x {
+              ClientError(x) => ClientError(OtherError(Box::new(x))),
+              ServerError(x) => ServerError(x),
+            })?
+        }
+        CreateCover::ReuseFromLanguage(lang) => {
+          hash_map
+            .get(&lang)
+            .expect("Translations are sorted. reuse_from_language should appear last")
+            .clone()
+            .cover
+        }
+      };
+
+      hash_map.insert(
+        language,
+        CreatePartialMovieTranslation {
+          title: translation.title,
+          description: translation.description,
+          cover,
+        },
+      );
+    }
+    Ok(hash_map)
+  }
+  async fn validate_create(&self, item: &CreateMovie) -> Result<(), ServiceError<MutMovieServiceError>> {
+    let data = &item.movie;
+    if let Some(length) = data.length {
+      if length == 0 {
+        return Err(ClientError(MutMovieServiceError::InvalidLength(length)));
+      }
+    }
+    if let Some(themes) = &data.themes {
+      if !themes.is_empty() {
+        let existing_themes = self.theme_repository.filter_existing(themes).await?;
+        if themes.len() != existing_themes.len() {
+          let non_existent_themes = filter_non_existent(themes, &existing_themes);
+          return Err(ClientError(MutMovieServiceError::NonExistentThemes(
+            non_existent_themes,
+          )));
+        }
+      }
+    }
+    if let Some(genres) = &data.genres {
+      if !genres.is_empty() {
+        let existing_genres = self.genre_repository.filter_existing(genres).await?;
+        if genres.len() != existing_genres.len() {
+          let non_existent_genres = filter_non_existent(genres, &existing_genres);
+          return Err(ClientError(MutMovieServiceError::NonExistentGenres(
+            non_existent_genres,
+          )));
+        }
+      }
+    }
+    if let Some(involved) = &data.involved {
+      let people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+      if !people.is_empty() {
+        let existing_people = self.person_repository.filter_existing(&people).await?;
+        if people.len() != existing_people.len() {
+          let non_existent_people = filter_non_existent(&people, &existing_people);
+          return Err(ClientError(MutMovieServiceError::NonExistentPeople(
+            non_existent_people,
+          )));
+        }
+      }
+
+      let roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+      if !roles.is_empty() {
+        let existing_roles = self.role_repository.filter_existing(&roles).await?;
+        if roles.len() != existing_roles.len() {
+          let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+          return Err(ClientError(MutMovieServiceError::NonExistentRoles(non_existent_roles)));
+        }
+      }
+    }
+    self
+      .validate_translations(&data.translations, &self.default_language, &item.covers)
+      .await?;
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn sort_translations(translations: &mut [(Language, CreateMovieTranslation)]) {
+  translations.sort_by(|(_, x), (_, y)| {
+    let x_reuse = match x.cover {
+      CreateCover::ImageIndex(_) => false,
+      CreateCover::ReuseFromLanguage(_) => true,
+    };
+    let y_reuse = match y.cover {
+      CreateCover::ImageIndex(_) => false,
+      CreateCover::ReuseFromLanguage(_) => true,
+    };
+    if x_reuse && !y_reuse {
+      return Ordering::Greater;
+    }
+    if !x_reuse && y_reuse {
+      return Ordering::Less;
+    }
+    Ordering::Equal
+  });
+}
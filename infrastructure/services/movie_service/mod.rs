@@ -0,0 +1,5 @@
+pub mod default_movie_service;
+pub mod default_mut_movie_service;
+pub mod movie_genre_service;
+pub mod movie_involved_service;
+pub mod movie_theme_service;
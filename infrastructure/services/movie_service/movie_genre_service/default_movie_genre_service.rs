@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::genre::Genre;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::movie_repository::movie_genre_repository::MovieGenreRepository;
+use services::movie_service::movie_genre_service::{MovieGenreService, MovieGenreServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMovieGenreService<'a> {
+  movie_genre_repository: Arc<dyn MovieGenreRepository + 'a>,
+}
+
+impl<'a> DefaultMovieGenreService<'a> {
+  pub fn new(movie_genre_repository: Arc<dyn MovieGenreRepository + 'a>) -> DefaultMovieGenreService<'a> {
+    DefaultMovieGenreService { movie_genre_repository }
+  }
+}
+
+#[async_trait]
+impl MovieGenreService for DefaultMovieGenreService<'_> {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Genre>, ServiceError<MovieGenreServiceError>> {
+    Ok(self.movie_genre_repository.get(movie_id, language, pagination).await?)
+  }
+}
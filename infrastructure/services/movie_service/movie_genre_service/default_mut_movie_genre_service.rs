@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::genre_repository::GenreRepository;
+use repositories::movie_repository::movie_genre_repository::mut_movie_genre_repository::MutMovieGenreRepository;
+use repositories::movie_repository::movie_genre_repository::MovieGenreRepository;
+use repositories::movie_repository::MovieRepository;
+use services::movie_service::movie_genre_service::mut_movie_genre_service::{
+  MutMovieGenreService, MutMovieGenreServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutMovieGenreService<'a> {
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  movie_genre_repository: Arc<dyn MovieGenreRepository + 'a>,
+  mut_movie_genre_repository: Arc<dyn MutMovieGenreRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+}
+
+impl<'a> DefaultMutMovieGenreService<'a> {
+  pub fn new(
+    movie_repository: Arc<dyn MovieRepository + 'a>,
+    movie_genre_repository: Arc<dyn MovieGenreRepository + 'a>,
+    mut_movie_genre_repository: Arc<dyn MutMovieGenreRepository + 'a>,
+    genre_repository: Arc<dyn GenreRepository + 'a>,
+  ) -> DefaultMutMovieGenreService<'a> {
+    DefaultMutMovieGenreService {
+      movie_repository,
+      movie_genre_repository,
+      mut_movie_genre_repository,
+      genre_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutMovieGenreService for DefaultMutMovieGenreService<'_> {
+  async fn add(&self, movie_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutMovieGenreServiceError>> {
+    self.validate_add(movie_id, genres).await?;
+    Ok(self.mut_movie_genre_repository.add(movie_id, genres).await?)
+  }
+
+  async fn remove(&self, movie_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutMovieGenreServiceError>> {
+    self.validate_remove(movie_id, genres).await?;
+    Ok(self.mut_movie_genre_repository.remove(movie_id, genres).await?)
+  }
+}
+
+impl DefaultMutMovieGenreService<'_> {
+  async fn validate_add(&self, movie_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutMovieGenreServiceError>> {
+    self.validate(movie_id, genres).await?;
+    let existing_genres = self.genre_repository.filter_existing(genres).await?;
+    if existing_genres.len() != genres.len() {
+      let non_existent_genres = filter_non_existent(genres, &existing_genres);
+      return Err(ServiceError::ClientError(MutMovieGenreServiceError::NonExistent(
+        non_existent_genres,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    movie_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutMovieGenreServiceError>> {
+    self.validate(movie_id, genres).await?;
+    let existing = self.movie_genre_repository.filter_existing(movie_id, genres).await?;
+    if existing.len() != genres.len() {
+      let not_associated = filter_non_existent(genres, &existing);
+      return Err(ServiceError::ClientError(MutMovieGenreServiceError::NotAssociated(
+        not_associated,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, movie_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutMovieGenreServiceError>> {
+    let ids = self.movie_repository.filter_existing(&[movie_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(MutMovieGenreServiceError::NonExistentMovie(
+        movie_id,
+      )));
+    }
+    if genres.is_empty() {
+      return Err(ServiceError::ClientError(MutMovieGenreServiceError::NoGenresProvided));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
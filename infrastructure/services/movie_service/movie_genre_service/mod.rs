@@ -0,0 +1,2 @@
+pub mod default_movie_genre_service;
+pub mod default_mut_movie_genre_service;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::movie::movie_involved::MovieInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::movie_repository::movie_involved_repository::MovieInvolvedRepository;
+use services::movie_service::movie_involved_service::{MovieInvolvedService, MovieInvolvedServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMovieInvolvedService<'a> {
+  movie_involved_repository: Arc<dyn MovieInvolvedRepository + 'a>,
+}
+
+impl<'a> DefaultMovieInvolvedService<'a> {
+  pub fn new(movie_involved_repository: Arc<dyn MovieInvolvedRepository + 'a>) -> DefaultMovieInvolvedService<'a> {
+    DefaultMovieInvolvedService {
+      movie_involved_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MovieInvolvedService for DefaultMovieInvolvedService<'_> {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<MovieInvolved>, ServiceError<MovieInvolvedServiceError>> {
+    Ok(
+      self
+        .movie_involved_repository
+        .get(movie_id, language, pagination)
+        .await?,
+    )
+  }
+}
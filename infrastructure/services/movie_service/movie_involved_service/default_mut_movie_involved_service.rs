@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+use repositories::movie_repository::movie_involved_repository::mut_movie_involved_repository::MutMovieInvolvedRepository;
+use repositories::movie_repository::movie_involved_repository::MovieInvolvedRepository;
+use repositories::movie_repository::MovieRepository;
+use repositories::person_repository::PersonRepository;
+use repositories::role_repository::RoleRepository;
+use services::movie_service::movie_involved_service::mut_movie_involved_service::{
+  MutMovieInvolvedService, MutMovieInvolvedServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutMovieInvolvedService<'a> {
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  movie_involved_repository: Arc<dyn MovieInvolvedRepository + 'a>,
+  mut_movie_involved_repository: Arc<dyn MutMovieInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+}
+
+impl<'a> DefaultMutMovieInvolvedService<'a> {
+  pub fn new(
+    movie_repository: Arc<dyn MovieRepository + 'a>,
+    movie_involved_repository: Arc<dyn MovieInvolvedRepository + 'a>,
+    mut_movie_involved_repository: Arc<dyn MutMovieInvolvedRepository + 'a>,
+    person_repository: Arc<dyn PersonRepository + 'a>,
+    role_repository: Arc<dyn RoleRepository + 'a>,
+  ) -> DefaultMutMovieInvolvedService<'a> {
+    DefaultMutMovieInvolvedService {
+      movie_repository,
+      movie_involved_repository,
+      mut_movie_involved_repository,
+      person_repository,
+      role_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutMovieInvolvedService for DefaultMutMovieInvolvedService<'_> {
+  async fn add(
+    &self,
+    movie_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutMovieInvolvedServiceError>> {
+    self.validate_add(movie_id, involved).await?;
+    Ok(self.mut_movie_involved_repository.add(movie_id, involved).await?)
+  }
+
+  async fn remove(
+    &self,
+    movie_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutMovieInvolvedServiceError>> {
+    self.validate_remove(movie_id, involved).await?;
+    Ok(self.mut_movie_involved_repository.remove(movie_id, involved).await?)
+  }
+}
+
+impl DefaultMutMovieInvolvedService<'_> {
+  async fn validate_add(
+    &self,
+    movie_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutMovieInvolvedServiceError>> {
+    self.validate(movie_id, involved).await?;
+    let people: Vec<u32> = involved.iter().map(|x| x.person_id).collect();
+    let existing_people = self.person_repository.filter_existing(&people).await?;
+
+    if existing_people.len() != involved.len() {
+      let non_existent_people = filter_non_existent(&people, &existing_people);
+      return Err(ServiceError::ClientError(
+        MutMovieInvolvedServiceError::NonExistentPeople(non_existent_people),
+      ));
+    };
+    let roles: Vec<u32> = involved.iter().map(|x| x.role_id).collect();
+    let existing_roles = self.role_repository.filter_existing(&roles).await?;
+    if existing_roles.len() != involved.len() {
+      let non_existent_roles = filter_non_existent(&roles, &existing_roles);
+      return Err(ServiceError::ClientError(
+        MutMovieInvolvedServiceError::NonExistentRoles(non_existent_roles),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    movie_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutMovieInvolvedServiceError>> {
+    self.validate(movie_id, involved).await?;
+    let existing = self
+      .movie_involved_repository
+      .filter_existing(movie_id, involved)
+      .await?;
+    if existing.len() != involved.len() {
+      let non_existent_involved = filter_involved_non_existent(involved, &existing);
+      return Err(ServiceError::ClientError(
+        MutMovieInvolvedServiceError::NonExistentAssociation(non_existent_involved),
+      ));
+    };
+
+    Ok(())
+  }
+  async fn validate(
+    &self,
+    movie_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutMovieInvolvedServiceError>> {
+    let ids = self.movie_repository.filter_existing(&[movie_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutMovieInvolvedServiceError::NonExistentMovie(movie_id),
+      ));
+    }
+    if involved.is_empty() {
+      return Err(ServiceError::ClientError(
+        MutMovieInvolvedServiceError::NoInvolvedProvided,
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn filter_involved_non_existent(items: &[InvolvedId], existing: &[InvolvedId]) -> Vec<InvolvedId> {
+  items
+    .iter()
+    .filter_map(|x| {
+      existing
+        .iter()
+        .find(|y| y.role_id == x.role_id && y.person_id == x.person_id)
+        .map_or_else(|| Some(x.clone()), |_| None)
+    })
+    .collect()
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::theme::Theme;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::movie_repository::movie_theme_repository::MovieThemeRepository;
+use services::movie_service::movie_theme_service::{MovieThemeService, MovieThemeServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMovieThemeService<'a> {
+  movie_theme_repository: Arc<dyn MovieThemeRepository + 'a>,
+}
+
+impl<'a> DefaultMovieThemeService<'a> {
+  pub fn new(movie_theme_repository: Arc<dyn MovieThemeRepository + 'a>) -> DefaultMovieThemeService<'a> {
+    DefaultMovieThemeService { movie_theme_repository }
+  }
+}
+
+#[async_trait]
+impl MovieThemeService for DefaultMovieThemeService<'_> {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Theme>, ServiceError<MovieThemeServiceError>> {
+    Ok(self.movie_theme_repository.get(movie_id, language, pagination).await?)
+  }
+}
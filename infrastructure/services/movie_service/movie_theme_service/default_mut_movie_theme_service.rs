@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::movie_repository::movie_theme_repository::mut_movie_theme_repository::MutMovieThemeRepository;
+use repositories::movie_repository::movie_theme_repository::MovieThemeRepository;
+use repositories::movie_repository::MovieRepository;
+use repositories::theme_repository::ThemeRepository;
+use services::movie_service::movie_theme_service::mut_movie_theme_service::{
+  MutMovieThemeService, MutMovieThemeServiceError,
+};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultMutMovieThemeService<'a> {
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  movie_theme_repository: Arc<dyn MovieThemeRepository + 'a>,
+  mut_movie_theme_repository: Arc<dyn MutMovieThemeRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+}
+
+impl<'a> DefaultMutMovieThemeService<'a> {
+  pub fn new(
+    movie_repository: Arc<dyn MovieRepository + 'a>,
+    movie_theme_repository: Arc<dyn MovieThemeRepository + 'a>,
+    mut_movie_theme_repository: Arc<dyn MutMovieThemeRepository + 'a>,
+    theme_repository: Arc<dyn ThemeRepository + 'a>,
+  ) -> DefaultMutMovieThemeService<'a> {
+    DefaultMutMovieThemeService {
+      movie_repository,
+      movie_theme_repository,
+      mut_movie_theme_repository,
+      theme_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutMovieThemeService for DefaultMutMovieThemeService<'_> {
+  async fn add(&self, movie_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutMovieThemeServiceError>> {
+    self.validate_add(movie_id, themes).await?;
+    Ok(self.mut_movie_theme_repository.add(movie_id, themes).await?)
+  }
+
+  async fn remove(&self, movie_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutMovieThemeServiceError>> {
+    self.validate_remove(movie_id, themes).await?;
+    Ok(self.mut_movie_theme_repository.remove(movie_id, themes).await?)
+  }
+}
+
+impl DefaultMutMovieThemeService<'_> {
+  async fn validate_add(&self, movie_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutMovieThemeServiceError>> {
+    self.validate(movie_id, themes).await?;
+    let existing_themes = self.theme_repository.filter_existing(themes).await?;
+    if existing_themes.len() != themes.len() {
+      let non_existent_themes = filter_non_existent(themes, &existing_themes);
+      return Err(ServiceError::ClientError(MutMovieThemeServiceError::NonExistent(
+        non_existent_themes,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate_remove(
+    &self,
+    movie_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutMovieThemeServiceError>> {
+    self.validate(movie_id, themes).await?;
+    let existing = self.movie_theme_repository.filter_existing(movie_id, themes).await?;
+    if existing.len() != themes.len() {
+      let not_associated = filter_non_existent(themes, &existing);
+      return Err(ServiceError::ClientError(MutMovieThemeServiceError::NotAssociated(
+        not_associated,
+      )));
+    };
+
+    Ok(())
+  }
+  async fn validate(&self, movie_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutMovieThemeServiceError>> {
+    let ids = self.movie_repository.filter_existing(&[movie_id]).await?;
+    if ids.is_empty() {
+      return Err(ServiceError::ClientError(MutMovieThemeServiceError::NonExistentMovie(
+        movie_id,
+      )));
+    }
+    if themes.is_empty() {
+      return Err(ServiceError::ClientError(MutMovieThemeServiceError::NoThemesProvided));
+    }
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::create_partial_platform::CreatePartialPlatform;
+use domain::entities::platform::create_platform::CreatePlatform;
+use domain::entities::platform::patch_platform::PatchPlatform;
+use domain::entities::platform::Platform;
+use repositories::company_repository::CompanyRepository;
+use repositories::platform_repository::mut_platform_repository::MutPlatformRepository;
+use repositories::platform_repository::PlatformRepository;
+use services::image_service::mut_image_service::MutImageService;
+use services::platform_service::mut_platform_service::MutPlatformServiceError::OtherError;
+use services::platform_service::mut_platform_service::{MutPlatformService, MutPlatformServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_SHORT_NAME_LENGTH: usize = 10;
+
+pub struct DefaultMutPlatformService<'a> {
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+  mut_platform_repository: Arc<dyn MutPlatformRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+}
+
+impl<'a> DefaultMutPlatformService<'a> {
+  pub fn new(
+    platform_repository: Arc<dyn PlatformRepository + 'a>,
+    mut_platform_repository: Arc<dyn MutPlatformRepository + 'a>,
+    company_repository: Arc<dyn CompanyRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+  ) -> DefaultMutPlatformService<'a> {
+    DefaultMutPlatformService {
+      platform_repository,
+      mut_platform_repository,
+      company_repository,
+      mut_image_service,
+    }
+  }
+}
+
+#[async_trait]
+impl MutPlatformService for DefaultMutPlatformService<'_> {
+  async fn create(&self, item: CreatePlatform) -> Result<Platform, ServiceError<MutPlatformServiceError>> {
+    let data = item.platform;
+    let name = validate_name(data.name)?;
+    let short_name = validate_short_name(data.short_name)?;
+    self.validate_company(data.company).await?;
+    let logo = self.mut_image_service.create(item.logo).await.map_err(|x| match x {
+      ClientError(x) => ClientError(OtherError(Box::new(x))),
+      ServerError(x) => ServerError(x),
+    })?;
+    let partial_platform = CreatePartialPlatform {
+      name,
+      short_name,
+      company: data.company,
+      logo,
+    };
+    Ok(self.mut_platform_repository.create(partial_platform).await?)
+  }
+
+  async fn update(&self, id: u32, item: PatchPlatform) -> Result<Platform, ServiceError<MutPlatformServiceError>> {
+    let current = self
+      .platform_repository
+      .get_by_id(id)
+      .await?
+      .ok_or(ClientError(MutPlatformServiceError::NonExistentPlatform))?;
+    let name = match item.name {
+      None => current.name,
+      Some(name) => validate_name(name)?,
+    };
+    let short_name = match item.short_name {
+      None => current.short_name,
+      Some(short_name) => validate_short_name(Some(short_name))?,
+    };
+    let company = match item.company {
+      None => current.company.id,
+      Some(company) => {
+        self.validate_company(company).await?;
+        company
+      }
+    };
+    let partial_platform = CreatePartialPlatform {
+      name,
+      short_name,
+      company,
+      logo: current.logo,
+    };
+    Ok(self.mut_platform_repository.update(id, partial_platform).await?)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<Vec<String>, ServiceError<MutPlatformServiceError>> {
+    if ids.is_empty() {
+      return Err(ClientError(MutPlatformServiceError::NoIdsProvided));
+    }
+    let platforms = self.platform_repository.get_by_ids(ids).await?;
+    if platforms.len() != ids.len() {
+      let existing: Vec<u32> = platforms.iter().map(|x| x.id).collect();
+      let non_existent_platforms = filter_non_existent(ids, &existing);
+      return Err(ClientError(MutPlatformServiceError::NonExistentPlatforms(
+        non_existent_platforms,
+      )));
+    };
+    self.mut_platform_repository.delete(ids).await?;
+    let mut files = vec![];
+    for platform in platforms {
+      let logo_files = self
+        .mut_image_service
+        .delete_record(&platform.logo)
+        .await
+        .map_err(|x| match x {
+          ClientError(x) => ClientError(OtherError(Box::new(x))),
+          ServerError(x) => ServerError(x),
+        })?;
+      files.extend(logo_files);
+    }
+    Ok(files)
+  }
+}
+
+impl DefaultMutPlatformService<'_> {
+  async fn validate_company(&self, company: u32) -> Result<(), ServiceError<MutPlatformServiceError>> {
+    let existing = self.company_repository.filter_existing(&[company]).await?;
+    if existing.is_empty() {
+      return Err(ClientError(MutPlatformServiceError::NonExistentCompany(company)));
+    }
+    Ok(())
+  }
+}
+
+/// Returns the trimmed name
+fn validate_name(name: String) -> Result<String, ServiceError<MutPlatformServiceError>> {
+  let name = name.trim().to_string();
+  if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+    return Err(ClientError(MutPlatformServiceError::InvalidName(name)));
+  }
+  Ok(name)
+}
+
+/// Blank short names are stored as no short name
+fn validate_short_name(short_name: Option<String>) -> Result<Option<String>, ServiceError<MutPlatformServiceError>> {
+  let short_name = short_name.map(|x| x.trim().to_string()).filter(|x| !x.is_empty());
+  match short_name {
+    Some(x) if x.chars().count() > MAX_SHORT_NAME_LENGTH => {
+      Err(ClientError(MutPlatformServiceError::InvalidShortName(x)))
+    }
+    short_name => Ok(short_name),
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
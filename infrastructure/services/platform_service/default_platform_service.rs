@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::platform_repository::PlatformRepository;
+use services::platform_service::{PlatformService, PlatformServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultPlatformService<'a> {
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+}
+
+impl<'a> DefaultPlatformService<'a> {
+  pub fn new(platform_repository: Arc<dyn PlatformRepository + 'a>) -> DefaultPlatformService<'a> {
+    DefaultPlatformService { platform_repository }
+  }
+}
+
+#[async_trait]
+impl PlatformService for DefaultPlatformService<'_> {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Platform>, ServiceError<PlatformServiceError>> {
+    Ok(self.platform_repository.get(pagination).await?)
+  }
+
+  async fn get_by_id(&self, id: u32) -> Result<Option<Platform>, ServiceError<PlatformServiceError>> {
+    Ok(self.platform_repository.get_by_id(id).await?)
+  }
+
+  async fn get_by_name(
+    &self,
+    name: &str,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Platform>, ServiceError<PlatformServiceError>> {
+    Ok(self.platform_repository.get_by_name(name, pagination).await?)
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_mut_platform_service;
+pub mod default_platform_service;
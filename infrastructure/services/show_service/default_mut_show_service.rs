@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::book::create_book::CreateCover;
+use domain::entities::image::create_image::CreateImage;
+use domain::entities::show::create_partial_show::{CreatePartialShow, CreatePartialShowTranslation};
+use domain::entities::show::create_show::{CreateShow, CreateShowTranslation};
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use repositories::franchise_repository::FranchiseRepository;
+use repositories::show_repository::mut_show_repository::MutShowRepository;
+use repositories::show_repository::ShowRepository;
+use services::image_service::mut_image_service::MutImageService;
+use services::show_service::mut_show_service::MutShowServiceError::OtherError;
+use services::show_service::mut_show_service::{MutShowService, MutShowServiceError};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::{ClientError, ServerError};
+
+pub struct DefaultMutShowService<'a> {
+  default_language: Language,
+  show_repository: Arc<dyn ShowRepository + 'a>,
+  mut_show_repository: Arc<dyn MutShowRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+}
+
+impl<'a> DefaultMutShowService<'a> {
+  pub fn new(
+    default_language: Language,
+    show_repository: Arc<dyn ShowRepository + 'a>,
+    mut_show_repository: Arc<dyn MutShowRepository + 'a>,
+    mut_image_service: Arc<dyn MutImageService + 'a>,
+    franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+  ) -> DefaultMutShowService<'a> {
+    DefaultMutShowService {
+      default_language,
+      show_repository,
+      mut_show_repository,
+      mut_image_service,
+      franchise_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutShowService for DefaultMutShowService<'_> {
+  async fn create(&self, item: CreateShow) -> Result<Show, ServiceError<MutShowServiceError>> {
+    self.validate_create(&item).await?;
+    let data = item.show;
+    let covers = item.covers;
+    let translations = self.transform_translations(data.translations, covers).await?;
+
+    let partial_show = CreatePartialShow {
+      airing_start: data.airing_start,
+      airing_end: data.airing_end,
+      status: data.status,
+      franchise: data.franchise,
+      translations,
+    };
+    Ok(self.mut_show_repository.create(partial_show).await?)
+  }
+
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutShowServiceError>> {
+    self.validate_delete(ids).await?;
+    Ok(self.mut_show_repository.delete(ids).await?)
+  }
+}
+
+impl DefaultMutShowService<'_> {
+  async fn validate_delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutShowServiceError>> {
+    if ids.is_empty() {
+      return Err(ClientError(MutShowServiceError::NoIdsProvided));
+    }
+
+    let existing = self.show_repository.filter_existing(ids).await?;
+    if existing.len() != ids.len() {
+      let non_existent_shows = filter_non_existent(ids, &existing);
+      return Err(ClientError(MutShowServiceError::NonExistentShows(non_existent_shows)));
+    };
+    Ok(())
+  }
+  async fn validate_translations(
+    &self,
+    translations: &HashMap<Language, CreateShowTranslation>,
+    default_language: &Language,
+    covers: &[CreateImage],
+  ) -> Result<(), ServiceError<MutShowServiceError>> {
+    if translations.is_empty() {
+      return Err(ClientError(MutShowServiceError::NoTranslationsProvided));
+    }
+    if !translations.contains_key(default_language) {
+      return Err(ClientError(MutShowServiceError::NoTranslationInLanguageProvided(
+        *default_language,
+      )));
+    }
+    for (current_language, item) in translations {
+      if item.title.is_empty() {
+        return Err(ClientError(MutShowServiceError::InvalidTitle(item.title.clone())));
+      }
+      if let Some(description) = &item.description {
+        if description.is_empty() {
+          return Err(ClientError(MutShowServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+      match item.cover {
+        CreateCover::ImageIndex(index) => {
+          if index >= covers.len() {
+            return Err(ClientError(MutShowServiceError::NonExistentTranslationCover(
+              *current_language,
+            )));
+          }
+        }
+        CreateCover::ReuseFromLanguage(language) => {
+          let valid_reuse = match translations.get(&language) {
+            None => false,
+            Some(value) => match value.cover {
+              CreateCover::ImageIndex(_) => true,
+              CreateCover::ReuseFromLanguage(_) => false,
+            },
+          };
+          if *current_language == language || !valid_reuse {
+            return Err(ClientError(MutShowServiceError::NonExistentTranslationCover(language)));
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+  async fn transform_translations(
+    &self,
+    translations: HashMap<Language, CreateShowTranslation>,
+    mut covers: Vec<CreateImage>,
+  ) -> Result<HashMap<Language, CreatePartialShowTranslation>, ServiceError<MutShowServiceError>> {
+    let mut hash_map: HashMap<Language, CreatePartialShowTranslation> = HashMap::new();
+    let mut translations: Vec<(Language, CreateShowTranslation)> = translations.into_iter().collect();
+    sort_translations(&mut translations);
+    for (language, translation) in translations {
+      let cover = match translation.cover {
+        CreateCover::ImageIndex(index) => {
+          self
+            .mut_image_service
+            .create(covers.remove(index))
+            .await
+            .map_err(|x| match x {
+              ClientError(x) => ClientError(OtherError(Box::new(x))),
+              ServerError(x) => ServerError(x),
+            })?
+        }
+        CreateCover::ReuseFromLanguage(lang) => {
+          hash_map
+            .get(&lang)
+            .expect("Translations are sorted. reuse_from_language should appear last")
+            .clone()
+            .cover
+        }
+      };
+
+      hash_map.insert(
+        language,
+        CreatePartialShowTranslation {
+          title: translation.title,
+          description: translation.description,
+          cover,
+        },
+      );
+    }
+    Ok(hash_map)
+  }
+  async fn validate_create(&self, item: &CreateShow) -> Result<(), ServiceError<MutShowServiceError>> {
+    let data = &item.show;
+    if let (Some(start), Some(end)) = (data.airing_start, data.airing_end) {
+      if end < start {
+        return Err(ClientError(MutShowServiceError::InvalidAiring));
+      }
+    }
+    if let Some(franchise) = data.franchise {
+      let existing = self.franchise_repository.filter_existing(&[franchise]).await?;
+      if existing.is_empty() {
+        return Err(ClientError(MutShowServiceError::NonExistentFranchise(franchise)));
+      }
+    }
+    self
+      .validate_translations(&data.translations, &self.default_language, &item.covers)
+      .await?;
+    Ok(())
+  }
+}
+
+fn filter_non_existent(items: &[u32], existing: &[u32]) -> Vec<u32> {
+  items
+    .iter()
+    .filter_map(|x| existing.iter().find(|y| **y == *x).map_or(Some(*x), |_| None))
+    .collect()
+}
+
+fn sort_translations(translations: &mut [(Language, CreateShowTranslation)]) {
+  translations.sort_by(|(_, x), (_, y)| {
+    let x_reuse = match x.cover {
+      CreateCover::ImageIndex(_) => false,
+      CreateCover::ReuseFromLanguage(_) => true,
+    };
+    let y_reuse = match y.cover {
+      CreateCover::ImageIndex(_) => false,
+      CreateCover::ReuseFromLanguage(_) => true,
+    };
+    if x_reuse && !y_reuse {
+      return Ordering::Greater;
+    }
+    if !x_reuse && y_reuse {
+      return Ordering::Less;
+    }
+    Ordering::Equal
+  });
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::show_repository::ShowRepository;
+use services::show_service::{ShowService, ShowServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultShowService<'a> {
+  show_repository: Arc<dyn ShowRepository + 'a>,
+}
+
+impl<'a> DefaultShowService<'a> {
+  pub fn new(show_repository: Arc<dyn ShowRepository + 'a>) -> DefaultShowService<'a> {
+    DefaultShowService { show_repository }
+  }
+}
+
+#[async_trait]
+impl ShowService for DefaultShowService<'_> {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Show>, ServiceError<ShowServiceError>> {
+    Ok(self.show_repository.get(language, pagination).await?)
+  }
+
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Show>, ServiceError<ShowServiceError>> {
+    Ok(self.show_repository.get_by_id(id, language).await?)
+  }
+
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Show>, ServiceError<ShowServiceError>> {
+    Ok(self.show_repository.get_by_title(title, language, pagination).await?)
+  }
+}
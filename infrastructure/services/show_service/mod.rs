@@ -0,0 +1,4 @@
+pub mod default_mut_show_service;
+pub mod default_show_service;
+pub mod show_episode_service;
+pub mod show_season_service;
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_episode::CreateShowEpisode;
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::enums::language::Language;
+use repositories::show_repository::show_episode_repository::mut_show_episode_repository::MutShowEpisodeRepository;
+use repositories::show_repository::show_episode_repository::ShowEpisodeRepository;
+use repositories::show_repository::show_season_repository::ShowSeasonRepository;
+use services::show_service::show_episode_service::mut_show_episode_service::{
+  MutShowEpisodeService, MutShowEpisodeServiceError,
+};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+pub struct DefaultMutShowEpisodeService<'a> {
+  default_language: Language,
+  show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+  show_episode_repository: Arc<dyn ShowEpisodeRepository + 'a>,
+  mut_show_episode_repository: Arc<dyn MutShowEpisodeRepository + 'a>,
+}
+
+impl<'a> DefaultMutShowEpisodeService<'a> {
+  pub fn new(
+    default_language: Language,
+    show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+    show_episode_repository: Arc<dyn ShowEpisodeRepository + 'a>,
+    mut_show_episode_repository: Arc<dyn MutShowEpisodeRepository + 'a>,
+  ) -> DefaultMutShowEpisodeService<'a> {
+    DefaultMutShowEpisodeService {
+      default_language,
+      show_season_repository,
+      show_episode_repository,
+      mut_show_episode_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutShowEpisodeService for DefaultMutShowEpisodeService<'_> {
+  async fn create(
+    &self,
+    show_id: u32,
+    season: u16,
+    item: CreateShowEpisode,
+  ) -> Result<ShowEpisode, ServiceError<MutShowEpisodeServiceError>> {
+    self.validate_create(show_id, season, &item).await?;
+    let episode = item.episode;
+    self.mut_show_episode_repository.create(show_id, season, item).await?;
+    let episode = self
+      .show_episode_repository
+      .get_by_episode(show_id, season, episode, self.default_language)
+      .await?
+      .expect("Episode was just created");
+    Ok(episode)
+  }
+
+  async fn delete(
+    &self,
+    show_id: u32,
+    season: u16,
+    episode: u16,
+  ) -> Result<(), ServiceError<MutShowEpisodeServiceError>> {
+    let existing = self
+      .show_episode_repository
+      .get_by_episode(show_id, season, episode, self.default_language)
+      .await?;
+    if existing.is_none() {
+      return Err(ClientError(MutShowEpisodeServiceError::NonExistentEpisode(episode)));
+    }
+    Ok(self.mut_show_episode_repository.delete(show_id, episode).await?)
+  }
+}
+
+impl DefaultMutShowEpisodeService<'_> {
+  async fn validate_create(
+    &self,
+    show_id: u32,
+    season: u16,
+    item: &CreateShowEpisode,
+  ) -> Result<(), ServiceError<MutShowEpisodeServiceError>> {
+    if !self.show_season_repository.exists(show_id, season).await? {
+      return Err(ClientError(MutShowEpisodeServiceError::NonExistentSeason(season)));
+    }
+    if self.show_episode_repository.exists(show_id, item.episode).await? {
+      return Err(ClientError(MutShowEpisodeServiceError::EpisodeAlreadyExists(
+        item.episode,
+      )));
+    }
+    if let Some(length) = item.length {
+      if length == 0 {
+        return Err(ClientError(MutShowEpisodeServiceError::InvalidLength(length)));
+      }
+    }
+    if item.translations.is_empty() {
+      return Err(ClientError(MutShowEpisodeServiceError::NoTranslationsProvided));
+    }
+    if !item.translations.contains_key(&self.default_language) {
+      return Err(ClientError(
+        MutShowEpisodeServiceError::NoTranslationInLanguageProvided(self.default_language),
+      ));
+    }
+    for translation in item.translations.values() {
+      if translation.title.is_empty() {
+        return Err(ClientError(MutShowEpisodeServiceError::InvalidTitle(
+          translation.title.clone(),
+        )));
+      }
+      if let Some(description) = &translation.description {
+        if description.is_empty() {
+          return Err(ClientError(MutShowEpisodeServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::show_repository::show_episode_repository::ShowEpisodeRepository;
+use services::show_service::show_episode_service::{ShowEpisodeService, ShowEpisodeServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultShowEpisodeService<'a> {
+  show_episode_repository: Arc<dyn ShowEpisodeRepository + 'a>,
+}
+
+impl<'a> DefaultShowEpisodeService<'a> {
+  pub fn new(show_episode_repository: Arc<dyn ShowEpisodeRepository + 'a>) -> DefaultShowEpisodeService<'a> {
+    DefaultShowEpisodeService {
+      show_episode_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl ShowEpisodeService for DefaultShowEpisodeService<'_> {
+  async fn get(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowEpisode>, ServiceError<ShowEpisodeServiceError>> {
+    Ok(
+      self
+        .show_episode_repository
+        .get(show_id, season, language, pagination)
+        .await?,
+    )
+  }
+
+  async fn get_by_episode(
+    &self,
+    show_id: u32,
+    season: u16,
+    episode: u16,
+    language: Language,
+  ) -> Result<Option<ShowEpisode>, ServiceError<ShowEpisodeServiceError>> {
+    Ok(
+      self
+        .show_episode_repository
+        .get_by_episode(show_id, season, episode, language)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,2 @@
+pub mod default_mut_show_episode_service;
+pub mod default_show_episode_service;
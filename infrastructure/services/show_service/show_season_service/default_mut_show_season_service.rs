@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_season::CreateShowSeason;
+use domain::entities::show::show_season::ShowSeason;
+use domain::enums::language::Language;
+use repositories::show_repository::show_season_repository::mut_show_season_repository::MutShowSeasonRepository;
+use repositories::show_repository::show_season_repository::ShowSeasonRepository;
+use repositories::show_repository::ShowRepository;
+use services::show_service::show_season_service::mut_show_season_service::{
+  MutShowSeasonService, MutShowSeasonServiceError,
+};
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+
+pub struct DefaultMutShowSeasonService<'a> {
+  default_language: Language,
+  show_repository: Arc<dyn ShowRepository + 'a>,
+  show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+  mut_show_season_repository: Arc<dyn MutShowSeasonRepository + 'a>,
+}
+
+impl<'a> DefaultMutShowSeasonService<'a> {
+  pub fn new(
+    default_language: Language,
+    show_repository: Arc<dyn ShowRepository + 'a>,
+    show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+    mut_show_season_repository: Arc<dyn MutShowSeasonRepository + 'a>,
+  ) -> DefaultMutShowSeasonService<'a> {
+    DefaultMutShowSeasonService {
+      default_language,
+      show_repository,
+      show_season_repository,
+      mut_show_season_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutShowSeasonService for DefaultMutShowSeasonService<'_> {
+  async fn create(
+    &self,
+    show_id: u32,
+    item: CreateShowSeason,
+  ) -> Result<ShowSeason, ServiceError<MutShowSeasonServiceError>> {
+    self.validate_create(show_id, &item).await?;
+    let season = item.season;
+    self.mut_show_season_repository.create(show_id, item).await?;
+    let season = self
+      .show_season_repository
+      .get_by_season(show_id, season, self.default_language)
+      .await?
+      .expect("Season was just created");
+    Ok(season)
+  }
+
+  async fn delete(&self, show_id: u32, season: u16) -> Result<(), ServiceError<MutShowSeasonServiceError>> {
+    if !self.show_season_repository.exists(show_id, season).await? {
+      return Err(ClientError(MutShowSeasonServiceError::NonExistentSeason(season)));
+    }
+    Ok(self.mut_show_season_repository.delete(show_id, season).await?)
+  }
+}
+
+impl DefaultMutShowSeasonService<'_> {
+  async fn validate_create(
+    &self,
+    show_id: u32,
+    item: &CreateShowSeason,
+  ) -> Result<(), ServiceError<MutShowSeasonServiceError>> {
+    if self.show_repository.filter_existing(&[show_id]).await?.is_empty() {
+      return Err(ClientError(MutShowSeasonServiceError::NonExistentShow(show_id)));
+    }
+    if self.show_season_repository.exists(show_id, item.season).await? {
+      return Err(ClientError(MutShowSeasonServiceError::SeasonAlreadyExists(item.season)));
+    }
+    if let Some(episodes) = item.episodes {
+      if episodes == 0 {
+        return Err(ClientError(MutShowSeasonServiceError::InvalidEpisodes(episodes)));
+      }
+    }
+    if let (Some(start), Some(end)) = (item.airing_start, item.airing_end) {
+      if end < start {
+        return Err(ClientError(MutShowSeasonServiceError::InvalidAiring));
+      }
+    }
+    if item.translations.is_empty() {
+      return Err(ClientError(MutShowSeasonServiceError::NoTranslationsProvided));
+    }
+    if !item.translations.contains_key(&self.default_language) {
+      return Err(ClientError(MutShowSeasonServiceError::NoTranslationInLanguageProvided(
+        self.default_language,
+      )));
+    }
+    for translation in item.translations.values() {
+      if translation.title.is_empty() {
+        return Err(ClientError(MutShowSeasonServiceError::InvalidTitle(
+          translation.title.clone(),
+        )));
+      }
+      if let Some(description) = &translation.description {
+        if description.is_empty() {
+          return Err(ClientError(MutShowSeasonServiceError::InvalidDescription(
+            description.clone(),
+          )));
+        }
+      }
+    }
+    Ok(())
+  }
+}
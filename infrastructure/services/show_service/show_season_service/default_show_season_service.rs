@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_season::ShowSeason;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::show_repository::show_season_repository::ShowSeasonRepository;
+use services::show_service::show_season_service::{ShowSeasonService, ShowSeasonServiceError};
+use services::traits::service_error::ServiceError;
+
+pub struct DefaultShowSeasonService<'a> {
+  show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+}
+
+impl<'a> DefaultShowSeasonService<'a> {
+  pub fn new(show_season_repository: Arc<dyn ShowSeasonRepository + 'a>) -> DefaultShowSeasonService<'a> {
+    DefaultShowSeasonService { show_season_repository }
+  }
+}
+
+#[async_trait]
+impl ShowSeasonService for DefaultShowSeasonService<'_> {
+  async fn get(
+    &self,
+    show_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowSeason>, ServiceError<ShowSeasonServiceError>> {
+    Ok(self.show_season_repository.get(show_id, language, pagination).await?)
+  }
+
+  async fn get_by_season(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+  ) -> Result<Option<ShowSeason>, ServiceError<ShowSeasonServiceError>> {
+    Ok(
+      self
+        .show_season_repository
+        .get_by_season(show_id, season, language)
+        .await?,
+    )
+  }
+}
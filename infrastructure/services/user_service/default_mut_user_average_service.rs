@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use repositories::user_repository::mut_user_average_repository::MutUserAverageRepository;
+use services::traits::service_error::ServiceError;
+use services::user_service::mut_user_average_service::{MutUserAverageService, MutUserAverageServiceError};
+
+pub struct DefaultMutUserAverageService<'a> {
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+}
+
+impl<'a> DefaultMutUserAverageService<'a> {
+  pub fn new(mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>) -> DefaultMutUserAverageService<'a> {
+    DefaultMutUserAverageService {
+      mut_user_average_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutUserAverageService for DefaultMutUserAverageService<'_> {
+  async fn recompute_all(&self) -> Result<usize, ServiceError<MutUserAverageServiceError>> {
+    Ok(self.mut_user_average_repository.recompute_all().await?)
+  }
+}
@@ -2,27 +2,39 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use domain::entities::image::create_image::CreateImage;
+use domain::entities::image::Image;
 use domain::entities::user::create_partial_user::CreatePartialUser;
 use domain::entities::user::create_user::CreateUser;
+use domain::entities::user::patch_user::PatchUser;
 use domain::entities::user::User;
 use repositories::user_repository::mut_user_repository::MutUserRepository;
+use repositories::user_repository::UserRepository;
 use services::image_service::mut_image_service::MutImageService;
 use services::traits::service_error::ServiceError;
 use services::traits::service_error::ServiceError::ClientError;
 use services::user_service::mut_user_service::MutUserServiceError::OtherError;
 use services::user_service::mut_user_service::{MutUserService, MutUserServiceError};
 
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_DESCRIPTION_LENGTH: usize = 500;
+/// Names of deleted users, see `MutUserRepository::anonymize`
+const RESERVED_NAME_PREFIX: &str = "deleted-user-";
+
 pub struct DefaultMutUserService<'a> {
+  user_repository: Arc<dyn UserRepository + 'a>,
   mut_user_repository: Arc<dyn MutUserRepository + 'a>,
   mut_image_service: Arc<dyn MutImageService + 'a>,
 }
 
 impl<'a> DefaultMutUserService<'a> {
   pub fn new(
+    user_repository: Arc<dyn UserRepository + 'a>,
     mut_user_repository: Arc<dyn MutUserRepository + 'a>,
     mut_image_service: Arc<dyn MutImageService + 'a>,
   ) -> DefaultMutUserService<'a> {
     DefaultMutUserService {
+      user_repository,
       mut_user_repository,
       mut_image_service,
     }
@@ -33,18 +45,126 @@ impl<'a> DefaultMutUserService<'a> {
 impl MutUserService for DefaultMutUserService<'_> {
   async fn create(&self, user: CreateUser) -> Result<User, ServiceError<MutUserServiceError>> {
     let data = user.user;
+    let name = self.validate_name(data.name, None).await?;
+    let description = validate_description(data.description)?;
     let image = match user.profile_picture {
       None => None,
-      Some(value) => Some(self.mut_image_service.create(value).await.map_err(|x| match x {
-        ClientError(x) => ClientError(OtherError(Box::new(x))),
-        ServiceError::ServerError(x) => ServiceError::ServerError(x),
-      })?),
+      Some(value) => Some(self.create_image(value).await?),
     };
     let user = CreatePartialUser {
-      name: data.name,
-      description: data.description,
+      name,
+      description,
       profile_picture: image,
     };
     Ok(self.mut_user_repository.create(user).await?)
   }
+
+  async fn update(
+    &self,
+    user_id: u32,
+    user: PatchUser,
+  ) -> Result<(User, Vec<String>), ServiceError<MutUserServiceError>> {
+    let current = self.get_existing(user_id).await?;
+    let name = match user.user.name {
+      None => current.name,
+      Some(name) => self.validate_name(name, Some(user_id)).await?,
+    };
+    let description = match user.user.description {
+      None => current.description,
+      Some(description) => validate_description(Some(description))?,
+    };
+    let (profile_picture, replaced) = match user.profile_picture {
+      None => (current.profile_picture, None),
+      Some(value) => (Some(self.create_image(value).await?), current.profile_picture),
+    };
+
+    let user = CreatePartialUser {
+      name,
+      description,
+      profile_picture,
+    };
+    let user = self.mut_user_repository.update(user_id, user).await?;
+    let files = match replaced {
+      None => vec![],
+      Some(image) => self.delete_image(&image).await?,
+    };
+    Ok((user, files))
+  }
+
+  async fn delete_profile_picture(
+    &self,
+    user_id: u32,
+  ) -> Result<(User, Vec<String>), ServiceError<MutUserServiceError>> {
+    let current = self.get_existing(user_id).await?;
+    let image = current
+      .profile_picture
+      .ok_or(ClientError(MutUserServiceError::NoProfilePicture))?;
+    let user = CreatePartialUser {
+      name: current.name,
+      description: current.description,
+      profile_picture: None,
+    };
+    let user = self.mut_user_repository.update(user_id, user).await?;
+    let files = self.delete_image(&image).await?;
+    Ok((user, files))
+  }
+
+  async fn delete(&self, user_id: u32) -> Result<Vec<String>, ServiceError<MutUserServiceError>> {
+    let user = self.get_existing(user_id).await?;
+    self.mut_user_repository.anonymize(user_id).await?;
+    let Some(image) = user.profile_picture else {
+      return Ok(vec![]);
+    };
+    self.delete_image(&image).await
+  }
+}
+
+impl DefaultMutUserService<'_> {
+  async fn get_existing(&self, user_id: u32) -> Result<User, ServiceError<MutUserServiceError>> {
+    let user = self.user_repository.get_by_id(user_id).await?;
+    user
+      .filter(|x| !x.deleted)
+      .ok_or(ClientError(MutUserServiceError::NonExistentUser))
+  }
+
+  /// Returns the trimmed name, it may only be taken by the given user
+  async fn validate_name(
+    &self,
+    name: String,
+    user_id: Option<u32>,
+  ) -> Result<String, ServiceError<MutUserServiceError>> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH || name.starts_with(RESERVED_NAME_PREFIX) {
+      return Err(ClientError(MutUserServiceError::InvalidName));
+    }
+    let owner = self.user_repository.get_id_by_exact_name(&name).await?;
+    match owner {
+      Some(owner) if Some(owner) != user_id => Err(ClientError(MutUserServiceError::NameTaken)),
+      _ => Ok(name),
+    }
+  }
+
+  async fn create_image(&self, image: CreateImage) -> Result<Image, ServiceError<MutUserServiceError>> {
+    self.mut_image_service.create(image).await.map_err(|x| match x {
+      ClientError(x) => ClientError(OtherError(Box::new(x))),
+      ServiceError::ServerError(x) => ServiceError::ServerError(x),
+    })
+  }
+
+  /// Removes only the image, the returned files are deleted once the transaction is committed
+  async fn delete_image(&self, image: &Image) -> Result<Vec<String>, ServiceError<MutUserServiceError>> {
+    self.mut_image_service.delete_record(image).await.map_err(|x| match x {
+      ClientError(x) => ClientError(OtherError(Box::new(x))),
+      ServiceError::ServerError(x) => ServiceError::ServerError(x),
+    })
+  }
+}
+
+/// Blank descriptions are stored as no description
+fn validate_description(description: Option<String>) -> Result<Option<String>, ServiceError<MutUserServiceError>> {
+  let description = description.map(|x| x.trim().to_string()).filter(|x| !x.is_empty());
+  match description {
+    Some(x) if x.chars().count() > MAX_DESCRIPTION_LENGTH => Err(ClientError(MutUserServiceError::InvalidDescription)),
+    description => Ok(description),
+  }
 }
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::user::friendship::Friendship;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::user_repository::friendship_repository::FriendshipRepository;
+use repositories::user_repository::UserRepository;
+use services::traits::service_error::ServiceError;
+use services::user_service::friendship_service::{FriendshipService, FriendshipServiceError};
+
+pub struct DefaultFriendshipService<'a> {
+  user_repository: Arc<dyn UserRepository + 'a>,
+  friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+}
+
+impl<'a> DefaultFriendshipService<'a> {
+  pub fn new(
+    user_repository: Arc<dyn UserRepository + 'a>,
+    friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+  ) -> DefaultFriendshipService<'a> {
+    DefaultFriendshipService {
+      user_repository,
+      friendship_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl FriendshipService for DefaultFriendshipService<'_> {
+  async fn get_friends(
+    &self,
+    user_id: u32,
+    pagination: Pagination,
+  ) -> Result<Option<ItemsTotal<Friendship>>, ServiceError<FriendshipServiceError>> {
+    let user = self.user_repository.get_by_id(user_id).await?;
+    if user.is_none_or(|x| x.deleted) {
+      return Ok(None);
+    }
+    Ok(Some(self.friendship_repository.get_friends(user_id, pagination).await?))
+  }
+
+  async fn get_incoming(
+    &self,
+    user_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Friendship>, ServiceError<FriendshipServiceError>> {
+    Ok(self.friendship_repository.get_incoming(user_id, pagination).await?)
+  }
+
+  async fn get_outgoing(
+    &self,
+    user_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Friendship>, ServiceError<FriendshipServiceError>> {
+    Ok(self.friendship_repository.get_outgoing(user_id, pagination).await?)
+  }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::user::friendship::Friendship;
+use domain::enums::friendship_status::FriendshipStatus;
+use repositories::user_repository::friendship_repository::mut_friendship_repository::MutFriendshipRepository;
+use repositories::user_repository::friendship_repository::FriendshipRepository;
+use repositories::user_repository::UserRepository;
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+use services::user_service::friendship_service::mut_friendship_service::{
+  MutFriendshipService, MutFriendshipServiceError,
+};
+
+pub struct DefaultMutFriendshipService<'a> {
+  user_repository: Arc<dyn UserRepository + 'a>,
+  friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+  mut_friendship_repository: Arc<dyn MutFriendshipRepository + 'a>,
+}
+
+impl<'a> DefaultMutFriendshipService<'a> {
+  pub fn new(
+    user_repository: Arc<dyn UserRepository + 'a>,
+    friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+    mut_friendship_repository: Arc<dyn MutFriendshipRepository + 'a>,
+  ) -> DefaultMutFriendshipService<'a> {
+    DefaultMutFriendshipService {
+      user_repository,
+      friendship_repository,
+      mut_friendship_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutFriendshipService for DefaultMutFriendshipService<'_> {
+  async fn send(
+    &self,
+    user_id: u32,
+    other_user_id: u32,
+  ) -> Result<Friendship, ServiceError<MutFriendshipServiceError>> {
+    if user_id == other_user_id {
+      return Err(ClientError(MutFriendshipServiceError::SelfFriendship));
+    }
+    let other_user = self.user_repository.get_by_id(other_user_id).await?;
+    if other_user.is_none_or(|x| x.deleted) {
+      return Err(ClientError(MutFriendshipServiceError::NonExistentUser(other_user_id)));
+    }
+
+    let existing = self.friendship_repository.get_by_users(user_id, other_user_id).await?;
+    match existing.map(|x| x.status) {
+      Some(FriendshipStatus::Accepted) => {
+        return Err(ClientError(MutFriendshipServiceError::AlreadyFriends(other_user_id)))
+      }
+      Some(FriendshipStatus::Pending) => {
+        return Err(ClientError(MutFriendshipServiceError::AlreadyRequested(other_user_id)))
+      }
+      Some(FriendshipStatus::Declined) => self.mut_friendship_repository.delete(user_id, other_user_id).await?,
+      None => {}
+    }
+    if !self.mut_friendship_repository.create(user_id, other_user_id).await? {
+      return Err(ClientError(MutFriendshipServiceError::AlreadyRequested(other_user_id)));
+    }
+    self.get_saved(user_id, other_user_id).await
+  }
+
+  async fn accept(
+    &self,
+    user_id: u32,
+    other_user_id: u32,
+  ) -> Result<Friendship, ServiceError<MutFriendshipServiceError>> {
+    self.validate_incoming(user_id, other_user_id).await?;
+    self
+      .mut_friendship_repository
+      .set_status(other_user_id, user_id, FriendshipStatus::Accepted)
+      .await?;
+    self.get_saved(user_id, other_user_id).await
+  }
+
+  async fn decline(&self, user_id: u32, other_user_id: u32) -> Result<(), ServiceError<MutFriendshipServiceError>> {
+    self.validate_incoming(user_id, other_user_id).await?;
+    Ok(
+      self
+        .mut_friendship_repository
+        .set_status(other_user_id, user_id, FriendshipStatus::Declined)
+        .await?,
+    )
+  }
+
+  async fn remove(&self, user_id: u32, other_user_id: u32) -> Result<(), ServiceError<MutFriendshipServiceError>> {
+    let existing = self.friendship_repository.get_by_users(user_id, other_user_id).await?;
+    if existing.is_none() {
+      return Err(ClientError(MutFriendshipServiceError::NotFriends(other_user_id)));
+    }
+    Ok(self.mut_friendship_repository.delete(user_id, other_user_id).await?)
+  }
+}
+
+impl DefaultMutFriendshipService<'_> {
+  async fn validate_incoming(
+    &self,
+    user_id: u32,
+    other_user_id: u32,
+  ) -> Result<(), ServiceError<MutFriendshipServiceError>> {
+    let existing = self.friendship_repository.get_by_users(user_id, other_user_id).await?;
+    match existing {
+      Some(x) if x.status == FriendshipStatus::Pending && !x.outgoing => Ok(()),
+      _ => Err(ClientError(MutFriendshipServiceError::NonExistentRequest(
+        other_user_id,
+      ))),
+    }
+  }
+
+  async fn get_saved(
+    &self,
+    user_id: u32,
+    other_user_id: u32,
+  ) -> Result<Friendship, ServiceError<MutFriendshipServiceError>> {
+    Ok(
+      self
+        .friendship_repository
+        .get_by_users(user_id, other_user_id)
+        .await?
+        .expect("Friendship was just saved, it should exist"),
+    )
+  }
+}
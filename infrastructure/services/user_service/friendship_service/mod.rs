@@ -0,0 +1,2 @@
+pub mod default_friendship_service;
+pub mod default_mut_friendship_service;
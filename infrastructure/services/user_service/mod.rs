@@ -1,2 +1,5 @@
+pub mod default_mut_user_average_service;
 pub mod default_mut_user_service;
 pub mod default_user_service;
+pub mod friendship_service;
+pub mod user_book_service;
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::user::create_user_book::CreateUserBook;
+use domain::entities::user::patch_user_book::PatchUserBook;
+use domain::entities::user::user_book::UserBook;
+use domain::enums::language::Language;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use repositories::book_repository::BookRepository;
+use repositories::user_repository::user_book_repository::mut_user_book_repository::MutUserBookRepository;
+use repositories::user_repository::user_book_repository::UserBookRepository;
+use services::traits::service_error::ServiceError;
+use services::traits::service_error::ServiceError::ClientError;
+use services::user_service::user_book_service::mut_user_book_service::{MutUserBookService, MutUserBookServiceError};
+
+pub struct DefaultMutUserBookService<'a> {
+  default_language: Language,
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  user_book_repository: Arc<dyn UserBookRepository + 'a>,
+  mut_user_book_repository: Arc<dyn MutUserBookRepository + 'a>,
+}
+
+impl<'a> DefaultMutUserBookService<'a> {
+  pub fn new(
+    default_language: Language,
+    book_repository: Arc<dyn BookRepository + 'a>,
+    book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+    user_book_repository: Arc<dyn UserBookRepository + 'a>,
+    mut_user_book_repository: Arc<dyn MutUserBookRepository + 'a>,
+  ) -> DefaultMutUserBookService<'a> {
+    DefaultMutUserBookService {
+      default_language,
+      book_repository,
+      book_edition_repository,
+      user_book_repository,
+      mut_user_book_repository,
+    }
+  }
+}
+
+#[async_trait]
+impl MutUserBookService for DefaultMutUserBookService<'_> {
+  async fn save(
+    &self,
+    user_id: u32,
+    book_id: u32,
+    item: CreateUserBook,
+  ) -> Result<UserBook, ServiceError<MutUserBookServiceError>> {
+    self.validate(book_id, &item).await?;
+    let existing = self
+      .user_book_repository
+      .get_by_id(user_id, book_id, self.default_language)
+      .await?;
+    match existing {
+      None => self.mut_user_book_repository.create(user_id, book_id, &item).await?,
+      Some(_) => self.mut_user_book_repository.update(user_id, book_id, &item).await?,
+    }
+    self.get_saved(user_id, book_id).await
+  }
+
+  async fn patch(
+    &self,
+    user_id: u32,
+    book_id: u32,
+    item: PatchUserBook,
+  ) -> Result<UserBook, ServiceError<MutUserBookServiceError>> {
+    let Some(current) = self
+      .user_book_repository
+      .get_by_id(user_id, book_id, self.default_language)
+      .await?
+    else {
+      return Err(ClientError(MutUserBookServiceError::NonExistentEntry(book_id)));
+    };
+    let item = item.apply(current);
+    self.validate(book_id, &item).await?;
+    self.mut_user_book_repository.update(user_id, book_id, &item).await?;
+    self.get_saved(user_id, book_id).await
+  }
+
+  async fn delete(&self, user_id: u32, book_id: u32) -> Result<(), ServiceError<MutUserBookServiceError>> {
+    let existing = self
+      .user_book_repository
+      .get_by_id(user_id, book_id, self.default_language)
+      .await?;
+    if existing.is_none() {
+      return Err(ClientError(MutUserBookServiceError::NonExistentEntry(book_id)));
+    }
+    Ok(self.mut_user_book_repository.delete(user_id, &[book_id]).await?)
+  }
+}
+
+impl DefaultMutUserBookService<'_> {
+  async fn validate(&self, book_id: u32, item: &CreateUserBook) -> Result<(), ServiceError<MutUserBookServiceError>> {
+    let ids = self.book_repository.filter_existing(&[book_id]).await?;
+    if ids.is_empty() {
+      return Err(ClientError(MutUserBookServiceError::NonExistentBook(book_id)));
+    }
+    if let Some(score) = item.score {
+      if !(1..=10).contains(&score) {
+        return Err(ClientError(MutUserBookServiceError::InvalidScore(score)));
+      }
+    }
+    if let Some(review) = &item.review {
+      if review.trim().is_empty() || review.chars().count() > 255 {
+        return Err(ClientError(MutUserBookServiceError::InvalidReview));
+      }
+    }
+    if let (Some(start), Some(finished)) = (item.start, item.finished) {
+      if finished < start {
+        return Err(ClientError(MutUserBookServiceError::InvalidDates));
+      }
+    }
+    if item.chapters.is_none() && item.pages.is_none() {
+      return Ok(());
+    }
+    let (max_chapters, max_pages) = self.book_edition_repository.get_max_progress(book_id).await?;
+    if let (Some(chapters), Some(max)) = (item.chapters, max_chapters) {
+      if chapters > max {
+        return Err(ClientError(MutUserBookServiceError::InvalidChapters(chapters, max)));
+      }
+    }
+    if let (Some(pages), Some(max)) = (item.pages, max_pages) {
+      if pages > max {
+        return Err(ClientError(MutUserBookServiceError::InvalidPages(pages, max)));
+      }
+    }
+    Ok(())
+  }
+
+  async fn get_saved(&self, user_id: u32, book_id: u32) -> Result<UserBook, ServiceError<MutUserBookServiceError>> {
+    Ok(
+      self
+        .user_book_repository
+        .get_by_id(user_id, book_id, self.default_language)
+        .await?
+        .expect("Library entry was just saved, it should exist"),
+    )
+  }
+}
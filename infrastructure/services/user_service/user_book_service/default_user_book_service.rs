@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use domain::entities::user::user_book::UserBook;
+use domain::enums::language::Language;
+use domain::enums::user_status::UserStatus;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use repositories::user_repository::user_book_repository::UserBookRepository;
+use services::traits::service_error::ServiceError;
+use services::user_service::user_book_service::{UserBookService, UserBookServiceError};
+
+pub struct DefaultUserBookService<'a> {
+  user_book_repository: Arc<dyn UserBookRepository + 'a>,
+}
+
+impl<'a> DefaultUserBookService<'a> {
+  pub fn new(user_book_repository: Arc<dyn UserBookRepository + 'a>) -> DefaultUserBookService<'a> {
+    DefaultUserBookService { user_book_repository }
+  }
+}
+
+#[async_trait]
+impl UserBookService for DefaultUserBookService<'_> {
+  async fn get(
+    &self,
+    user_id: u32,
+    status: Option<UserStatus>,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<UserBook>, ServiceError<UserBookServiceError>> {
+    Ok(
+      self
+        .user_book_repository
+        .get(user_id, status, language, pagination)
+        .await?,
+    )
+  }
+}
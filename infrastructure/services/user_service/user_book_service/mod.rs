@@ -0,0 +1,2 @@
+pub mod default_mut_user_book_service;
+pub mod default_user_book_service;
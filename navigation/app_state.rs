@@ -1,11 +1,18 @@
+use std::sync::Arc;
+
+use application::cached_connection::CachedConnectionManager;
 use bb8_postgres::bb8::Pool;
-use bb8_postgres::PostgresConnectionManager;
-use tokio_postgres::NoTls;
+use repositories::mail_sender::MailSender;
+use services::account_service::login_throttle_service::LoginThrottleService;
 
 #[derive(Clone)]
 pub struct AppState {
-  pub pool: Pool<PostgresConnectionManager<NoTls>>,
+  pub pool: Pool<CachedConnectionManager>,
   pub display_path: String,
   pub content_path: String,
   pub secret: String,
+  /// Base url of the site, mailed links open its verify-email and reset-password pages
+  pub frontend_url: String,
+  pub mail_sender: Arc<dyn MailSender>,
+  pub login_throttle_service: Arc<dyn LoginThrottleService>,
 }
@@ -1,37 +1,81 @@
-use crate::openapi::responses::forbidden::Forbidden;
-use axum::extract::State;
+use application::cached_connection::{CachedClient, CachedTransaction};
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::header::RETRY_AFTER;
 use axum::http::StatusCode;
-use axum::response::IntoResponse;
-use axum::routing::{get, post};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post, put};
 use axum::{debug_handler, Json, Router};
 use chrono::Utc;
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
 use multipart::MultiPartRequest;
 use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio_postgres::{Client, Transaction};
 use utoipa::ToSchema;
 
 use crate::app_state::AppState;
-use crate::controllers::{convert_error, convert_service_error};
-use crate::extractors::headers::authorization::JWTAuthorization;
+use crate::controllers::{
+  append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
+  remove_files, DEFAULT_LANGUAGE,
+};
+use crate::extractors::authenticated_user::{AuthenticatedUser, Claim};
+use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::Admin;
 use crate::implementations::{
-  get_account_repository, get_account_service, get_file_repository, get_image_repository, get_mut_account_repository,
-  get_mut_account_service, get_mut_file_repository, get_mut_file_service, get_mut_image_repository,
-  get_mut_image_service, get_mut_user_repository, get_mut_user_service, get_user_repository,
+  get_account_export_service, get_account_repository, get_account_service, get_book_repository,
+  get_email_verification_repository, get_file_repository, get_franchise_repository, get_friendship_repository,
+  get_image_repository, get_mut_account_repository, get_mut_account_service, get_mut_email_verification_repository,
+  get_mut_file_repository, get_mut_file_service, get_mut_image_repository, get_mut_image_service,
+  get_mut_password_reset_repository, get_mut_password_service, get_mut_personal_access_token_repository,
+  get_mut_personal_access_token_service, get_mut_refresh_token_repository, get_mut_refresh_token_service,
+  get_mut_two_factor_repository, get_mut_two_factor_service, get_mut_user_repository, get_mut_user_service,
+  get_mut_verification_service, get_password_reset_repository, get_personal_access_token_repository,
+  get_personal_access_token_service, get_refresh_token_repository, get_two_factor_repository, get_two_factor_service,
+  get_user_book_repository, get_user_repository,
 };
+use crate::openapi::params::header::accept_language::AcceptLanguageParam;
 use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
+use crate::openapi::params::path::id::IdParam;
 use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
 use crate::openapi::responses::not_authorized::NotAuthorized;
+use crate::openapi::responses::not_found::NotFound;
 use crate::openapi::responses::server_error::ServerError;
+use crate::openapi::responses::too_many_requests::TooManyRequests;
 use domain::entities::account::create_account::CreateAccount;
-use domain::entities::account::{Email, Password};
+use domain::entities::account::create_personal_access_token::CreatePersonalAccessToken;
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use domain::entities::account::two_factor_enrollment::TwoFactorEnrollment;
+use domain::entities::account::{Account, Email, Password};
 use domain::entities::user::User;
+use domain::enums::account_role::AccountRole;
+use domain::enums::token_scope::TokenScope;
+use repositories::account_repository::mut_account_repository::MutAccountRepository;
+use repositories::account_repository::AccountRepository;
+use services::account_service::account_export_service::AccountExportService;
 use services::account_service::mut_account_service::MutAccountService;
-use services::account_service::AccountService;
+use services::account_service::mut_password_service::MutPasswordService;
+use services::account_service::mut_refresh_token_service::MutRefreshTokenService;
+use services::account_service::mut_verification_service::MutVerificationService;
+use services::account_service::personal_access_token_service::mut_personal_access_token_service::{
+  MutPersonalAccessTokenService, MutPersonalAccessTokenServiceError,
+};
+use services::account_service::personal_access_token_service::PersonalAccessTokenService;
+use services::account_service::two_factor_service::mut_two_factor_service::{
+  MutTwoFactorService, MutTwoFactorServiceError,
+};
+use services::account_service::two_factor_service::TwoFactorService;
+use services::account_service::{AccountService, AccountServiceError};
+use services::traits::service_error::ServiceError;
 
 pub mod account_doc;
 
+const ACCESS_TOKEN_SECONDS: i64 = 900;
+const SECOND_FACTOR_TOKEN_SECONDS: i64 = 300;
+/// Differs from the issuer of access tokens, so second factor tokens are not accepted in their place
+const SECOND_FACTOR_ISSUER: &str = "MyCollection/SecondFactor";
+
 #[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct LoginData {
   email: String,
@@ -41,20 +85,122 @@ pub struct LoginData {
 #[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct LoginReturnData {
   token: String,
+  refresh_token: String,
   user: User,
 }
 
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct SecondFactorRequiredData {
+  /// Exchanged together with a code for the JWT, valid for 5 minutes
+  second_factor_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct SecondFactorLoginData {
+  second_factor_token: String,
+  /// Code of the authenticator app or one of the recovery codes
+  code: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct TwoFactorCodeData {
+  code: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct RecoveryCodesData {
+  /// Each one can be used once instead of a code, they are not shown again
+  recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SecondFactorClaim {
+  user_id: u32,
+  sub: String,
+  exp: usize,
+  iat: usize,
+  iss: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct RefreshData {
+  refresh_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct TokenData {
+  token: String,
+  refresh_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ChangePasswordData {
+  current_password: String,
+  new_password: String,
+  /// Refresh token of the session that stays logged in, all other sessions are logged out
+  #[serde(default)]
+  refresh_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct PasswordResetRequestData {
+  email: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct PasswordResetData {
+  token: String,
+  password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct VerificationData {
+  token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct DeleteAccountData {
+  password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CreatedPersonalAccessTokenData {
+  /// Only returned once, only its hash is stored
+  token: String,
+  personal_access_token: PersonalAccessToken,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct RoleData {
+  role: AccountRole,
+}
+
 pub fn routes(app_state: AppState) -> Router {
   Router::new()
     .route("/login", post(login))
+    .route("/login/second-factor", post(login_second_factor))
     .route("/register", post(register))
-    .route("/refresh", get(refresh_token))
+    .route("/refresh", post(refresh_token))
+    .route("/logout", post(logout))
+    .route("/logout-all", post(logout_all))
+    .route("/verify", post(verify_email))
+    .route("/verify/resend", post(resend_verification))
+    .route("/password", put(change_password))
+    .route("/password/reset", post(request_password_reset))
+    .route("/password/reset/confirm", post(reset_password))
+    .route("/me", delete(delete_account))
+    .route("/me/export", get(export_account))
+    .route("/me/tokens", get(get_access_tokens).post(create_access_token))
+    .route("/me/tokens/:id", delete(revoke_access_token))
+    .route("/me/two-factor", post(enroll_two_factor).delete(disable_two_factor))
+    .route("/me/two-factor/confirm", post(confirm_two_factor))
+    .route("/:user_id/role", put(grant_role).delete(revoke_role))
     .with_state(app_state)
 }
 
 #[utoipa::path(post, path = "/register",
   responses(
-    (status = 201, description = "Returned JWT and user. Valid for a week", body = LoginReturnData), ServerError, BadRequest
+    (status = 201, description = "Returned JWT, refresh token and user. The JWT is valid for 15 minutes, a verification token was mailed to the email", body = LoginReturnData), ServerError, BadRequest
   ),
   request_body(content_type = ["multipart/form-data"], content = CreateAccount),
   tag = "Accounts"
@@ -67,19 +213,31 @@ async fn register(
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
 
-  let account = {
-    let service = get_mut_service(&transaction, &app_state.display_path, &app_state.content_path);
-    service.create(account).await.map_err(convert_service_error)?
+  let (account, refresh_token) = {
+    let service = get_mut_service(&transaction, &app_state);
+    let account = service.create(account).await.map_err(convert_service_error)?;
+    let refresh_token_service = get_mut_token_service(&transaction);
+    let refresh_token = refresh_token_service
+      .create(account.user.id)
+      .await
+      .map_err(convert_service_error)?;
+    (account, refresh_token)
   };
   transaction.commit().await.map_err(convert_error)?;
 
-  let user_id = account.user.id;
-
-  let in_a_week = (Utc::now().timestamp() + 604_800) as usize;
-  let claim = create_claim("Register".to_string(), user_id, in_a_week);
-  let token = create_token(claim, app_state.secret.as_bytes())?;
+  let token = create_token(
+    create_claim("Register".to_string(), &account),
+    app_state.secret.as_bytes(),
+  )?;
   let user = account.user;
-  Ok((StatusCode::CREATED, Json(LoginReturnData { token, user })))
+  Ok((
+    StatusCode::CREATED,
+    Json(LoginReturnData {
+      token,
+      refresh_token,
+      user,
+    }),
+  ))
 }
 
 fn create_token(claim: Claim, secret: &[u8]) -> Result<String, (StatusCode, String)> {
@@ -89,82 +247,794 @@ fn create_token(claim: Claim, secret: &[u8]) -> Result<String, (StatusCode, Stri
   jsonwebtoken::encode(&header, &claim, &key).map_err(convert_error)
 }
 
-fn create_claim(subject: String, user_id: u32, exp: usize) -> Claim {
+fn create_claim(subject: String, account: &Account) -> Claim {
+  let now = Utc::now().timestamp();
   Claim {
-    user_id,
+    user_id: account.user.id,
+    role: account.role,
+    verified: account.verified,
     sub: subject,
     iss: "MyCollection".to_string(),
-    exp,
-    iat: Utc::now().timestamp() as usize,
+    exp: (now + ACCESS_TOKEN_SECONDS) as usize,
+    iat: now as usize,
   }
 }
 
 #[utoipa::path(post, path = "/login",
 responses(
-(status = 200, description = "Returned JWT. Valid for a week", body = LoginReturnData), ServerError, NotAuthorized
+(status = 200, description = "Returned JWT, refresh token and user. The JWT is valid for 15 minutes", body = LoginReturnData),
+(status = 202, description = "The account has a second factor, the returned token has to be sent with a code to /login/second-factor", body = SecondFactorRequiredData), ServerError, NotAuthorized, TooManyRequests
 ),
 request_body = LoginData,
 tag = "Accounts"
 )]
 async fn login(
+  ConnectInfo(address): ConnectInfo<SocketAddr>,
   State(app_state): State<AppState>,
   Json(login_data): Json<LoginData>,
-) -> Result<(StatusCode, Json<LoginReturnData>), (StatusCode, String)> {
-  let pooled = app_state.pool.get().await.unwrap();
+) -> Result<Response, Response> {
+  let mut connection = app_state
+    .pool
+    .get()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+  let transaction = connection
+    .transaction()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
 
   let password = login_data.password;
   let email = login_data.email;
 
-  let account = {
-    let service = get_service(&pooled);
-    service
-      .login(&Email(email), &Password(password))
+  let (account, refresh_token) = {
+    let service = get_service(transaction.client(), &app_state);
+    let account = service
+      .login(&Email(email), &Password(password), Some(address.ip()))
+      .await
+      .map_err(convert_login_error)?;
+    let two_factor_repository = Arc::new(get_two_factor_repository(transaction.client()));
+    let two_factor_enabled = get_two_factor_service(two_factor_repository)
+      .is_enabled(account.user.id)
       .await
-      .map_err(convert_service_error)?
+      .map_err(|x| convert_service_error(x).into_response())?;
+    let refresh_token = match two_factor_enabled {
+      true => None,
+      false => Some(
+        get_mut_token_service(&transaction)
+          .create(account.user.id)
+          .await
+          .map_err(|x| convert_service_error(x).into_response())?,
+      ),
+    };
+    (account, refresh_token)
+  };
+  transaction
+    .commit()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+
+  let Some(refresh_token) = refresh_token else {
+    let second_factor_token =
+      create_second_factor_token(account.user.id, app_state.secret.as_bytes()).map_err(IntoResponse::into_response)?;
+    return Ok(
+      (
+        StatusCode::ACCEPTED,
+        Json(SecondFactorRequiredData { second_factor_token }),
+      )
+        .into_response(),
+    );
   };
-  let in_a_week = (Utc::now().timestamp() + 604_800) as usize;
-  let claim = create_claim("Login".to_string(), account.user.id, in_a_week);
-  let token = create_token(claim, app_state.secret.as_bytes())?;
+  let token = create_token(create_claim("Login".to_string(), &account), app_state.secret.as_bytes())
+    .map_err(IntoResponse::into_response)?;
   let user = account.user;
-  Ok((StatusCode::OK, Json(LoginReturnData { token, user })))
+  Ok(
+    (
+      StatusCode::OK,
+      Json(LoginReturnData {
+        token,
+        refresh_token,
+        user,
+      }),
+    )
+      .into_response(),
+  )
 }
 
-#[utoipa::path(get, path = "/refresh",
+fn create_second_factor_token(user_id: u32, secret: &[u8]) -> Result<String, (StatusCode, String)> {
+  let now = Utc::now().timestamp();
+  let claim = SecondFactorClaim {
+    user_id,
+    sub: "SecondFactor".to_string(),
+    iss: SECOND_FACTOR_ISSUER.to_string(),
+    exp: (now + SECOND_FACTOR_TOKEN_SECONDS) as usize,
+    iat: now as usize,
+  };
+  jsonwebtoken::encode(&Header::default(), &claim, &EncodingKey::from_secret(secret)).map_err(convert_error)
+}
+
+#[utoipa::path(post, path = "/login/second-factor",
   responses(
-    (status = 200, description = "Returned JWT. Valid for an hour", body = String), ServerError, Forbidden
+    (status = 200, description = "Returned JWT, refresh token and user. The JWT is valid for 15 minutes", body = LoginReturnData), ServerError, BadRequest, NotAuthorized, TooManyRequests
   ),
-  params(JsonWebTokenParam),
+  request_body = SecondFactorLoginData,
   tag = "Accounts"
 )]
-async fn refresh_token(auth: JWTAuthorization, State(app_state): State<AppState>) -> impl IntoResponse {
-  let claim = jsonwebtoken::decode::<Claim>(
-    &auth.token,
+async fn login_second_factor(
+  State(app_state): State<AppState>,
+  Json(second_factor_data): Json<SecondFactorLoginData>,
+) -> Result<(StatusCode, Json<LoginReturnData>), Response> {
+  let mut validation = Validation::default();
+  validation.set_issuer(&[SECOND_FACTOR_ISSUER]);
+  let user_id = jsonwebtoken::decode::<SecondFactorClaim>(
+    &second_factor_data.second_factor_token,
     &DecodingKey::from_secret(app_state.secret.as_bytes()),
-    &Validation::default(),
+    &validation,
+  )
+  .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid or expired second factor token").into_response())?
+  .claims
+  .user_id;
+
+  let mut connection = app_state
+    .pool
+    .get()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+  let transaction = connection
+    .transaction()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+  let result = {
+    let service = get_second_factor_service(&transaction, &app_state);
+    let verified = service
+      .verify(user_id, &second_factor_data.code)
+      .await
+      .map_err(convert_two_factor_error);
+    match verified {
+      Ok(()) => get_account_with_refresh_token(&transaction, &app_state, user_id).await,
+      Err(error) => Err(error),
+    }
+  };
+  transaction
+    .commit()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+
+  let (account, refresh_token) = result?;
+  let token = create_token(create_claim("Login".to_string(), &account), app_state.secret.as_bytes())
+    .map_err(IntoResponse::into_response)?;
+  let user = account.user;
+  Ok((
+    StatusCode::OK,
+    Json(LoginReturnData {
+      token,
+      refresh_token,
+      user,
+    }),
+  ))
+}
+
+async fn get_account_with_refresh_token(
+  transaction: &CachedTransaction<'_>,
+  app_state: &AppState,
+  user_id: u32,
+) -> Result<(Account, String), Response> {
+  let account = get_service(transaction.client(), app_state)
+    .get_by_user_id(user_id)
+    .await
+    .map_err(|x| convert_service_error(x).into_response())?
+    .ok_or_else(|| (StatusCode::UNAUTHORIZED, "Account no longer exists").into_response())?;
+  let refresh_token = get_mut_token_service(transaction)
+    .create(user_id)
+    .await
+    .map_err(|x| convert_service_error(x).into_response())?;
+  Ok((account, refresh_token))
+}
+
+/// Wrong codes are reported as unauthorized, throttled attempts tell when to retry.
+fn convert_two_factor_error(error: ServiceError<MutTwoFactorServiceError>) -> Response {
+  match error {
+    ServiceError::ClientError(MutTwoFactorServiceError::InvalidCode) => (
+      StatusCode::UNAUTHORIZED,
+      MutTwoFactorServiceError::InvalidCode.to_string(),
+    )
+      .into_response(),
+    ServiceError::ClientError(MutTwoFactorServiceError::TooManyAttempts(seconds)) => (
+      StatusCode::TOO_MANY_REQUESTS,
+      [(RETRY_AFTER, seconds.to_string())],
+      MutTwoFactorServiceError::TooManyAttempts(seconds).to_string(),
+    )
+      .into_response(),
+    error => convert_service_error(error).into_response(),
+  }
+}
+
+/// Wrong credentials are reported as unauthorized, throttled attempts tell when to retry.
+fn convert_login_error(error: ServiceError<AccountServiceError>) -> Response {
+  match error {
+    ServiceError::ClientError(AccountServiceError::InvalidCredentials) => (
+      StatusCode::UNAUTHORIZED,
+      AccountServiceError::InvalidCredentials.to_string(),
+    )
+      .into_response(),
+    ServiceError::ClientError(AccountServiceError::TooManyAttempts(seconds)) => (
+      StatusCode::TOO_MANY_REQUESTS,
+      [(RETRY_AFTER, seconds.to_string())],
+      AccountServiceError::TooManyAttempts(seconds).to_string(),
+    )
+      .into_response(),
+    error => convert_service_error(error).into_response(),
+  }
+}
+
+#[utoipa::path(post, path = "/refresh",
+  responses(
+    (status = 200, description = "Returned a new JWT valid for 15 minutes and a new refresh token, the used refresh token becomes invalid", body = TokenData), ServerError, BadRequest, NotAuthorized
+  ),
+  request_body = RefreshData,
+  tag = "Accounts"
+)]
+async fn refresh_token(
+  State(app_state): State<AppState>,
+  Json(refresh_data): Json<RefreshData>,
+) -> Result<(StatusCode, Json<TokenData>), (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let refresh_token_service = get_mut_token_service(&transaction);
+    let rotated = refresh_token_service
+      .rotate(&refresh_data.refresh_token)
+      .await
+      .map_err(convert_service_error);
+    match rotated {
+      Ok((user_id, refresh_token)) => get_service(transaction.client(), &app_state)
+        .get_by_user_id(user_id)
+        .await
+        .map_err(convert_service_error)
+        .and_then(|x| x.ok_or_else(|| (StatusCode::UNAUTHORIZED, "Account no longer exists".to_string())))
+        .map(|account| (account, refresh_token)),
+      Err(error) => Err(error),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+
+  let (account, refresh_token) = result?;
+  let token = create_token(
+    create_claim("Refresh".to_string(), &account),
+    app_state.secret.as_bytes(),
+  )?;
+  Ok((StatusCode::OK, Json(TokenData { token, refresh_token })))
+}
+
+#[utoipa::path(post, path = "/logout",
+  responses(
+    (status = 200, description = "Revoked the refresh token and all tokens created from it"), ServerError, BadRequest
+  ),
+  request_body = RefreshData,
+  tag = "Accounts"
+)]
+async fn logout(
+  State(app_state): State<AppState>,
+  Json(refresh_data): Json<RefreshData>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let refresh_token_service = get_mut_token_service(&transaction);
+    match refresh_token_service.revoke(&refresh_data.refresh_token).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/logout-all",
+  responses(
+    (status = 200, description = "Revoked all refresh tokens of the account, already issued JWTs stay valid until they expire"), ServerError, NotAuthorized
+  ),
+  params(JsonWebTokenParam),
+  tag = "Accounts"
+)]
+async fn logout_all(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let refresh_token_service = get_mut_token_service(&transaction);
+    match refresh_token_service.revoke_all(user.id).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/verify",
+  responses(
+    (status = 200, description = "Email successfully verified, takes effect with the next JWT of the account. The mailed link opens FRONTEND_URL/verify-email?token=, which posts the token here"), ServerError, BadRequest
+  ),
+  request_body = VerificationData,
+  tag = "Accounts"
+)]
+async fn verify_email(
+  State(app_state): State<AppState>,
+  Json(verification_data): Json<VerificationData>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_verification_service(&transaction, &app_state);
+    match service.verify(&verification_data.token).await {
+      Ok(_) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/verify/resend",
+  responses(
+    (status = 200, description = "Mailed a new verification token, earlier tokens are no longer valid"), ServerError, BadRequest, NotAuthorized
+  ),
+  params(JsonWebTokenParam),
+  tag = "Accounts"
+)]
+async fn resend_verification(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_verification_service(&transaction, &app_state);
+    match service.send(user.id).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(put, path = "/password",
+  responses(
+    (status = 200, description = "Password successfully changed, revoked the refresh tokens of all sessions except the one of the given refresh token. Already issued JWTs stay valid until they expire"), ServerError, BadRequest, NotAuthorized
+  ),
+  params(JsonWebTokenParam),
+  request_body = ChangePasswordData,
+  tag = "Accounts"
+)]
+async fn change_password(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(password_data): Json<ChangePasswordData>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_password_service(&transaction, &app_state);
+    let current_password = Password(password_data.current_password);
+    let new_password = Password(password_data.new_password);
+    let changed = service
+      .change(user.id, &current_password, &new_password)
+      .await
+      .map_err(convert_service_error);
+    match changed {
+      Ok(()) => get_mut_token_service(&transaction)
+        .revoke_others(user.id, password_data.refresh_token.as_deref())
+        .await
+        .map(|()| StatusCode::OK)
+        .map_err(convert_service_error),
+      Err(error) => Err(error),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/me",
+  responses(
+    (status = 200, description = "Account successfully deleted, the user stays as an anonymous deleted user on library entries and friendships. Refresh and personal access tokens stop working immediately, already issued JWTs are not checked against the database and stay valid until they expire after at most 15 minutes"), ServerError, BadRequest, NotAuthorized
+  ),
+  params(JsonWebTokenParam),
+  request_body = DeleteAccountData,
+  tag = "Accounts"
+)]
+async fn delete_account(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(delete_data): Json<DeleteAccountData>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_service(&transaction, &app_state);
+    let password = Password(delete_data.password);
+
+    println!("Route for deleting the account of a user with the id {}", user.id);
+
+    service.delete(user.id, &password).await.map_err(convert_service_error)
+  };
+  transaction.commit().await.map_err(convert_error)?;
+
+  remove_files(result?).await?;
+  Ok(StatusCode::OK)
+}
+
+#[utoipa::path(get, path = "/me/export",
+  responses(
+    (status = 200, description = "Returned everything stored about the account and its user", body = AccountExport), ServerError, BadRequest, NotAuthorized, NotFound
+  ),
+  params(JsonWebTokenParam, AcceptLanguageParam),
+  tag = "Accounts"
+)]
+async fn export_account(
+  user: AuthenticatedUser,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::AccountRead)?;
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_export_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!(
+    "Route for exporting the account of a user with the id {} in {}",
+    user.id, language
   );
-  let Ok(claim) = claim else {
-    return Err((StatusCode::FORBIDDEN, "Invalid JWT".to_string()));
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.export(user.id, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/me/tokens",
+  responses(
+    (status = 200, description = "Returned the personal access tokens of the account", body = [PersonalAccessToken]), ServerError, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  tag = "Accounts"
+)]
+async fn get_access_tokens(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> Result<(StatusCode, Json<Vec<PersonalAccessToken>>), (StatusCode, String)> {
+  user.require_session()?;
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let personal_access_token_repository = Arc::new(get_personal_access_token_repository(&connection));
+  let service = get_personal_access_token_service(personal_access_token_repository);
+
+  println!("Route for personal access tokens of a user with the id {}", user.id);
+
+  match service.get(user.id).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/me/tokens",
+  responses(
+    (status = 201, description = "Personal access token successfully created, the token is not shown again", body = CreatedPersonalAccessTokenData), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  request_body = CreatePersonalAccessToken,
+  tag = "Accounts"
+)]
+async fn create_access_token(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(item): Json<CreatePersonalAccessToken>,
+) -> Result<(StatusCode, Json<CreatedPersonalAccessTokenData>), (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_access_token_service(&transaction);
+
+    println!(
+      "Route for creating a personal access token for a user with the id {}",
+      user.id
+    );
+
+    match service.create(user.id, item).await {
+      Ok((personal_access_token, token)) => Ok((
+        StatusCode::CREATED,
+        Json(CreatedPersonalAccessTokenData {
+          token,
+          personal_access_token,
+        }),
+      )),
+      Err(error) => Err(convert_service_error(error)),
+    }
   };
-  let in_one_hour = (Utc::now().timestamp() as usize) + 3600;
-  let claim = create_claim("Refresh".to_string(), claim.claims.user_id, in_one_hour);
-  let token = create_token(claim, app_state.secret.as_bytes())?;
-  Ok((StatusCode::OK, token))
+  transaction.commit().await.map_err(convert_error)?;
+  result
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Claim {
+#[utoipa::path(delete, path = "/me/tokens/{id}",
+  responses(
+    (status = 200, description = "Personal access token successfully revoked"), ServerError, NotAuthorized, Forbidden, NotFound
+  ),
+  params(IdParam, JsonWebTokenParam),
+  tag = "Accounts"
+)]
+async fn revoke_access_token(
+  Path(id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_access_token_service(&transaction);
+
+    println!(
+      "Route for revoking the personal access token with the id {id} of a user with the id {}",
+      user.id
+    );
+
+    match service.revoke(user.id, id).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(ServiceError::ClientError(MutPersonalAccessTokenServiceError::NonExistentToken)) => {
+        Err((StatusCode::NOT_FOUND, "".to_string()))
+      }
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/me/two-factor",
+  responses(
+    (status = 200, description = "Returned a new secret, it has to be confirmed with a code before it is used for logins", body = TwoFactorEnrollment), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  tag = "Accounts"
+)]
+async fn enroll_two_factor(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> Result<(StatusCode, Json<TwoFactorEnrollment>), (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_second_factor_service(&transaction, &app_state);
+
+    println!("Route for enrolling a second factor for a user with the id {}", user.id);
+
+    match service.enroll(user.id).await {
+      Ok(item) => Ok((StatusCode::OK, Json(item))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/me/two-factor/confirm",
+  responses(
+    (status = 200, description = "Second factor enabled, returned the recovery codes", body = RecoveryCodesData), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  request_body = TwoFactorCodeData,
+  tag = "Accounts"
+)]
+async fn confirm_two_factor(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(code_data): Json<TwoFactorCodeData>,
+) -> Result<(StatusCode, Json<RecoveryCodesData>), (StatusCode, String)> {
+  user.require_session()?;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_second_factor_service(&transaction, &app_state);
+
+    println!(
+      "Route for confirming the second factor of a user with the id {}",
+      user.id
+    );
+
+    match service.confirm(user.id, &code_data.code).await {
+      Ok(recovery_codes) => Ok((StatusCode::OK, Json(RecoveryCodesData { recovery_codes }))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/me/two-factor",
+  responses(
+    (status = 200, description = "Second factor and its recovery codes removed"), ServerError, BadRequest, NotAuthorized, Forbidden, TooManyRequests
+  ),
+  params(JsonWebTokenParam),
+  request_body = TwoFactorCodeData,
+  tag = "Accounts"
+)]
+async fn disable_two_factor(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(code_data): Json<TwoFactorCodeData>,
+) -> Result<StatusCode, Response> {
+  user.require_session().map_err(IntoResponse::into_response)?;
+  let mut connection = app_state
+    .pool
+    .get()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+  let transaction = connection
+    .transaction()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+  let result = {
+    let service = get_second_factor_service(&transaction, &app_state);
+
+    println!(
+      "Route for disabling the second factor of a user with the id {}",
+      user.id
+    );
+
+    match service.disable(user.id, &code_data.code).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_two_factor_error(error)),
+    }
+  };
+  transaction
+    .commit()
+    .await
+    .map_err(|x| convert_error(x).into_response())?;
+  result
+}
+
+#[utoipa::path(post, path = "/password/reset",
+  responses(
+    (status = 200, description = "If an account with the email exists, a mail with a reset link valid for an hour was sent to it. The link opens FRONTEND_URL/reset-password?token=, which posts the token with the new password to /password/reset/confirm"), ServerError
+  ),
+  request_body = PasswordResetRequestData,
+  tag = "Accounts"
+)]
+async fn request_password_reset(
+  State(app_state): State<AppState>,
+  Json(request_data): Json<PasswordResetRequestData>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_password_service(&transaction, &app_state);
+    match service.request_reset(&Email(request_data.email)).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/password/reset/confirm",
+  responses(
+    (status = 200, description = "Password successfully reset, all sessions of the account were logged out"), ServerError, BadRequest
+  ),
+  request_body = PasswordResetData,
+  tag = "Accounts"
+)]
+async fn reset_password(
+  State(app_state): State<AppState>,
+  Json(reset_data): Json<PasswordResetData>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_password_service(&transaction, &app_state);
+    match service.reset(&reset_data.token, &Password(reset_data.password)).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(put, path = "/{user_id}/role",
+  responses(
+    (status = 200, description = "Role successfully granted, takes effect with the next JWT of the account", body = AccountRole), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(("user_id" = u32, Path,), JsonWebTokenParam),
+  request_body = RoleData,
+  tag = "Accounts"
+)]
+async fn grant_role(
+  _user: Admin,
+  Path(user_id): Path<u32>,
+  State(app_state): State<AppState>,
+  Json(role_data): Json<RoleData>,
+) -> Result<(StatusCode, Json<AccountRole>), (StatusCode, String)> {
+  set_role(&app_state, user_id, role_data.role).await
+}
+
+#[utoipa::path(delete, path = "/{user_id}/role",
+  responses(
+    (status = 200, description = "Role successfully revoked, the account is a member again", body = AccountRole), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(("user_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Accounts"
+)]
+async fn revoke_role(
+  _user: Admin,
+  Path(user_id): Path<u32>,
+  State(app_state): State<AppState>,
+) -> Result<(StatusCode, Json<AccountRole>), (StatusCode, String)> {
+  set_role(&app_state, user_id, AccountRole::Member).await
+}
+
+async fn set_role(
+  app_state: &AppState,
   user_id: u32,
-  sub: String,
-  exp: usize,
-  iat: usize,
-  iss: String,
+  role: AccountRole,
+) -> Result<(StatusCode, Json<AccountRole>), (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_service(&transaction, app_state);
+    match service.set_role(user_id, role).await {
+      Ok(account) => Ok((StatusCode::OK, Json(account.role))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
 }
 
-fn get_mut_service<'a>(
-  transaction: &'a Transaction,
-  display_path: &'a str,
-  path: &'a str,
-) -> impl MutAccountService + 'a {
+/// Makes the account with the given email an admin, so a fresh installation has someone to grant the other roles.
+pub async fn seed_admin(app_state: &AppState, email: &str) -> Result<(), Box<dyn Error>> {
+  let mut connection = app_state.pool.get().await?;
+  let transaction = connection.transaction().await?;
+  let account = get_service(transaction.client(), app_state)
+    .get_by_email(&Email(email.to_string()))
+    .await
+    .map_err(|x| x.to_string())?;
+  let Some(account) = account else {
+    println!("No account with the admin email {email} exists yet, register it and restart the server");
+    return Ok(());
+  };
+  {
+    let service = get_mut_service(&transaction, app_state);
+    if account.role != AccountRole::Admin {
+      service
+        .set_role(account.user.id, AccountRole::Admin)
+        .await
+        .map_err(|x| x.to_string())?;
+    }
+    // the admin has to be able to act before any mail is configured
+    if !account.verified {
+      service.set_verified(account.user.id).await.map_err(|x| x.to_string())?;
+    }
+  }
+  transaction.commit().await?;
+  Ok(())
+}
+
+fn get_mut_service<'a>(transaction: &'a CachedTransaction<'a>, app_state: &'a AppState) -> impl MutAccountService + 'a {
   let image_repository = Arc::new(get_image_repository(transaction.client()));
   let user_repository = Arc::new(get_user_repository(transaction.client(), image_repository.clone()));
   let account_repository = Arc::new(get_account_repository(transaction.client(), user_repository.clone()));
@@ -175,7 +1045,7 @@ fn get_mut_service<'a>(
   ));
   let mut_user_repository = Arc::new(get_mut_user_repository(
     transaction,
-    user_repository,
+    user_repository.clone(),
     image_repository.clone(),
   ));
   let mut_file_repository = Arc::new(get_mut_file_repository());
@@ -191,19 +1061,155 @@ fn get_mut_service<'a>(
   let mut_image_service = Arc::new(get_mut_image_service(
     mut_image_repository,
     mut_file_service,
-    display_path,
-    path,
+    &app_state.display_path,
+    &app_state.content_path,
+  ));
+  let mut_user_service = Arc::new(get_mut_user_service(
+    user_repository.clone(),
+    mut_user_repository,
+    mut_image_service,
   ));
-  let mut_user_service = Arc::new(get_mut_user_service(mut_user_repository, mut_image_service));
-  let account_service = Arc::new(get_account_service(account_repository));
-  let mut_account_service = get_mut_account_service(mut_account_repository, account_service, mut_user_service);
+  let account_service = Arc::new(get_account_service(
+    account_repository.clone(),
+    app_state.login_throttle_service.clone(),
+  ));
+  let mut_verification_service = Arc::new(get_verification_service_with(
+    transaction,
+    app_state,
+    account_repository,
+    mut_account_repository.clone(),
+  ));
+  let mut_account_service = get_mut_account_service(
+    mut_account_repository,
+    account_service,
+    mut_user_service,
+    mut_verification_service,
+  );
   mut_account_service
 }
 
-fn get_service(client: &Client) -> impl AccountService + '_ {
+fn get_verification_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  app_state: &'a AppState,
+) -> impl MutVerificationService + 'a {
+  let image_repository = Arc::new(get_image_repository(transaction.client()));
+  let user_repository = Arc::new(get_user_repository(transaction.client(), image_repository));
+  let account_repository = Arc::new(get_account_repository(transaction.client(), user_repository.clone()));
+  let mut_account_repository = Arc::new(get_mut_account_repository(
+    transaction,
+    account_repository.clone(),
+    user_repository,
+  ));
+  get_verification_service_with(transaction, app_state, account_repository, mut_account_repository)
+}
+
+fn get_verification_service_with<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  app_state: &'a AppState,
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+) -> impl MutVerificationService + 'a {
+  let email_verification_repository = Arc::new(get_email_verification_repository(transaction.client()));
+  let mut_email_verification_repository = Arc::new(get_mut_email_verification_repository(transaction));
+  get_mut_verification_service(
+    account_repository,
+    mut_account_repository,
+    email_verification_repository,
+    mut_email_verification_repository,
+    app_state.mail_sender.clone(),
+    &app_state.frontend_url,
+  )
+}
+
+fn get_mut_token_service<'a>(transaction: &'a CachedTransaction<'a>) -> impl MutRefreshTokenService + 'a {
+  let refresh_token_repository = Arc::new(get_refresh_token_repository(transaction.client()));
+  let mut_refresh_token_repository = Arc::new(get_mut_refresh_token_repository(
+    transaction,
+    refresh_token_repository.clone(),
+  ));
+  get_mut_refresh_token_service(refresh_token_repository, mut_refresh_token_repository)
+}
+
+fn get_mut_access_token_service<'a>(transaction: &'a CachedTransaction<'a>) -> impl MutPersonalAccessTokenService + 'a {
+  let image_repository = Arc::new(get_image_repository(transaction.client()));
+  let user_repository = Arc::new(get_user_repository(transaction.client(), image_repository));
+  let account_repository = Arc::new(get_account_repository(transaction.client(), user_repository));
+  let personal_access_token_repository = Arc::new(get_personal_access_token_repository(transaction.client()));
+  let mut_personal_access_token_repository = Arc::new(get_mut_personal_access_token_repository(transaction));
+  get_mut_personal_access_token_service(
+    account_repository,
+    personal_access_token_repository,
+    mut_personal_access_token_repository,
+  )
+}
+
+fn get_second_factor_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  app_state: &'a AppState,
+) -> impl MutTwoFactorService + 'a {
+  let image_repository = Arc::new(get_image_repository(transaction.client()));
+  let user_repository = Arc::new(get_user_repository(transaction.client(), image_repository));
+  let account_repository = Arc::new(get_account_repository(transaction.client(), user_repository));
+  let two_factor_repository = Arc::new(get_two_factor_repository(transaction.client()));
+  let mut_two_factor_repository = Arc::new(get_mut_two_factor_repository(transaction));
+  get_mut_two_factor_service(
+    account_repository,
+    two_factor_repository,
+    mut_two_factor_repository,
+    app_state.login_throttle_service.clone(),
+  )
+}
+
+fn get_password_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  app_state: &'a AppState,
+) -> impl MutPasswordService + 'a {
+  let image_repository = Arc::new(get_image_repository(transaction.client()));
+  let user_repository = Arc::new(get_user_repository(transaction.client(), image_repository));
+  let account_repository = Arc::new(get_account_repository(transaction.client(), user_repository.clone()));
+  let mut_account_repository = Arc::new(get_mut_account_repository(
+    transaction,
+    account_repository.clone(),
+    user_repository,
+  ));
+  let account_service = Arc::new(get_account_service(
+    account_repository,
+    app_state.login_throttle_service.clone(),
+  ));
+  let password_reset_repository = Arc::new(get_password_reset_repository(transaction.client()));
+  let mut_password_reset_repository = Arc::new(get_mut_password_reset_repository(transaction));
+  let mut_refresh_token_service = Arc::new(get_mut_token_service(transaction));
+  get_mut_password_service(
+    account_service,
+    mut_account_repository,
+    password_reset_repository,
+    mut_password_reset_repository,
+    mut_refresh_token_service,
+    app_state.mail_sender.clone(),
+    &app_state.frontend_url,
+  )
+}
+
+fn get_export_service(client: &CachedClient) -> impl AccountExportService + '_ {
+  let image_repository = Arc::new(get_image_repository(client));
+  let user_repository = Arc::new(get_user_repository(client, image_repository.clone()));
+  let account_repository = Arc::new(get_account_repository(client, user_repository.clone()));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let book_repository = Arc::new(get_book_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository,
+    franchise_repository,
+  ));
+  let user_book_repository = Arc::new(get_user_book_repository(client, book_repository));
+  let friendship_repository = Arc::new(get_friendship_repository(client, user_repository));
+  get_account_export_service(account_repository, user_book_repository, friendship_repository)
+}
+
+fn get_service<'a>(client: &'a CachedClient, app_state: &'a AppState) -> impl AccountService + 'a {
   let image_repository = Arc::new(get_image_repository(client));
   let user_repository = Arc::new(get_user_repository(client, image_repository));
   let account_repository = Arc::new(get_account_repository(client, user_repository));
-  let account_service = get_account_service(account_repository);
+  let account_service = get_account_service(account_repository, app_state.login_throttle_service.clone());
   account_service
 }
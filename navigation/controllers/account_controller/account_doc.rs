@@ -2,6 +2,28 @@ use super::*;
 
 #[derive(utoipa::OpenApi)]
 #[openapi(tags((name = "Accounts", description = "Endpoints related to managing accounts")),
-  paths(login, register, refresh_token)
+  paths(
+    login,
+    login_second_factor,
+    register,
+    refresh_token,
+    logout,
+    logout_all,
+    verify_email,
+    resend_verification,
+    delete_account,
+    export_account,
+    get_access_tokens,
+    create_access_token,
+    revoke_access_token,
+    enroll_two_factor,
+    confirm_two_factor,
+    disable_two_factor,
+    change_password,
+    request_password_reset,
+    reset_password,
+    grant_role,
+    revoke_role
+  )
 )]
 pub(crate) struct AccountDoc;
@@ -1,38 +1,57 @@
 use crate::app_state::AppState;
 use crate::controllers::book_controller::book_implementations::{
-  get_character_service, get_genre_service, get_involved_service, get_mut_character_service, get_mut_genre_service,
-  get_mut_involved_service, get_mut_service, get_mut_theme_service, get_service, get_theme_service,
+  get_character_service, get_edition_involved_service, get_edition_service, get_genre_service, get_images_service,
+  get_involved_service, get_mut_character_service, get_mut_edition_involved_service, get_mut_edition_service,
+  get_mut_genre_service, get_mut_images_service, get_mut_involved_service, get_mut_publisher_service, get_mut_service,
+  get_mut_theme_service, get_publisher_service, get_service, get_theme_service,
 };
 use crate::controllers::{
   append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
   set_pagination_limit, DEFAULT_LANGUAGE,
 };
 use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
 use crate::extractors::query_pagination::QueryPagination;
 use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
 use crate::openapi::params::path::id::IdParam;
+use crate::openapi::params::path::isbn13::Isbn13Param;
 use crate::openapi::params::path::slug::SlugParam;
 use crate::openapi::params::path::title::TitleParam;
 use crate::openapi::params::query::count::CountParam;
 use crate::openapi::params::query::page::PageParam;
 use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
 use crate::openapi::responses::not_found::NotFound;
 use crate::openapi::responses::server_error::ServerError;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
-use axum::routing::{delete, get, post};
+use axum::routing::{delete, get, patch, post, put};
 use axum::{Json, Router};
 use domain::entities::book::create_book::CreateBook;
+use domain::entities::book::create_book_edition::CreateBookEdition;
+use domain::entities::book::create_book_image::CreateBookImages;
+use domain::entities::book::patch_book_edition::PatchBookEdition;
 use domain::entities::involved::InvolvedId;
+use domain::isbn13::Isbn13;
 use domain::slug::Slug;
 use multipart::MultiPartRequest;
 use services::book_service::book_character_service::mut_book_character_service::MutBookCharacterService;
 use services::book_service::book_character_service::BookCharacterService;
+use services::book_service::book_edition_involved_service::mut_book_edition_involved_service::MutBookEditionInvolvedService;
+use services::book_service::book_edition_involved_service::BookEditionInvolvedService;
+use services::book_service::book_edition_service::mut_book_edition_service::MutBookEditionService;
+use services::book_service::book_edition_service::BookEditionService;
 use services::book_service::book_genre_service::mut_book_genre_service::MutBookGenreService;
 use services::book_service::book_genre_service::BookGenreService;
+use services::book_service::book_image_service::mut_book_image_service::MutBookImageService;
+use services::book_service::book_image_service::BookImageService;
 use services::book_service::book_involved_service::mut_book_involved_service::MutBookInvolvedService;
 use services::book_service::book_involved_service::BookInvolvedService;
+use services::book_service::book_publisher_service::mut_book_publisher_service::MutBookPublisherService;
+use services::book_service::book_publisher_service::BookPublisherService;
 use services::book_service::book_theme_service::mut_book_theme_service::MutBookThemeService;
 use services::book_service::book_theme_service::BookThemeService;
 use services::book_service::mut_book_service::MutBookService;
@@ -51,6 +70,7 @@ pub fn routes(app_state: AppState) -> Router {
     .route("/:id/statistic", get(get_statistic))
     .route("/title/:title", get(get_by_title))
     .route("/slug/:slug", get(get_by_slug))
+    .route("/isbn/:isbn", get(get_edition_by_isbn13))
     .route("/:id/genres", get(get_genres))
     .route("/:id/genres/:genre_id", post(add_genre))
     .route("/:id/genres/:genre_id", delete(remove_genre))
@@ -63,6 +83,27 @@ pub fn routes(app_state: AppState) -> Router {
     .route("/:id/involved", get(get_involved))
     .route("/:id/involved/:person_id/:role_id", post(add_involved))
     .route("/:id/involved/:person_id/:role_id", delete(remove_involved))
+    .route("/:id/editions", get(get_editions))
+    .route("/:id/editions", post(create_edition))
+    .route("/:id/editions/:edition_id", get(get_edition))
+    .route("/:id/editions/:edition_id", patch(update_edition))
+    .route("/:id/editions/:edition_id", delete(delete_edition))
+    .route("/:id/editions/:edition_id/involved", get(get_edition_involved))
+    .route(
+      "/:id/editions/:edition_id/involved/:person_id/:role_id",
+      post(add_edition_involved),
+    )
+    .route(
+      "/:id/editions/:edition_id/involved/:person_id/:role_id",
+      delete(remove_edition_involved),
+    )
+    .route("/:id/images", get(get_images))
+    .route("/:id/images", post(add_images))
+    .route("/:id/images", put(reorder_images))
+    .route("/:id/images/:image_id", delete(remove_image))
+    .route("/:id/publishers", get(get_publishers))
+    .route("/:id/publishers/:publisher_id", post(add_publisher))
+    .route("/:id/publishers/:publisher_id", delete(remove_publisher))
     .with_state(app_state)
 }
 
@@ -333,12 +374,13 @@ async fn get_involved(
 
 #[utoipa::path(post, path = "/{id}/characters/{character_id}",
   responses(
-    (status = 200, description = "Character association successfully added"), ServerError, BadRequest
+    (status = 200, description = "Character association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("character_id" = u32, Path,)),
+  params(IdParam, ("character_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
 async fn add_character(
+  _user: Editor,
   Path((id, character_id)): Path<(u32, u32)>,
   State(app_state): State<AppState>,
 ) -> impl IntoResponse {
@@ -361,12 +403,13 @@ async fn add_character(
 
 #[utoipa::path(delete, path = "/{id}/characters/{character_id}",
   responses(
-    (status = 200, description = "Character association successfully removed"), ServerError, BadRequest
+    (status = 200, description = "Character association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("character_id" = u32, Path,)),
+  params(IdParam, ("character_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
 async fn remove_character(
+  _user: Editor,
   Path((id, character_id)): Path<(u32, u32)>,
   State(app_state): State<AppState>,
 ) -> impl IntoResponse {
@@ -389,12 +432,16 @@ async fn remove_character(
 
 #[utoipa::path(post, path = "/{id}/genres/{genre_id}",
   responses(
-    (status = 200, description = "Genre association successfully added"), ServerError, BadRequest
+    (status = 200, description = "Genre association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("genre_id" = u32, Path,)),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
-async fn add_genre(Path((id, genre_id)): Path<(u32, u32)>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn add_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -414,12 +461,16 @@ async fn add_genre(Path((id, genre_id)): Path<(u32, u32)>, State(app_state): Sta
 
 #[utoipa::path(delete, path = "/{id}/genres/{genre_id}",
   responses(
-    (status = 200, description = "Genre association successfully removed"), ServerError, BadRequest
+    (status = 200, description = "Genre association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("genre_id" = u32, Path,)),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
-async fn remove_genre(Path((id, genre_id)): Path<(u32, u32)>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn remove_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -439,12 +490,16 @@ async fn remove_genre(Path((id, genre_id)): Path<(u32, u32)>, State(app_state):
 
 #[utoipa::path(post, path = "/{id}/themes/{theme_id}",
   responses(
-    (status = 200, description = "Theme association successfully added"), ServerError, BadRequest
+    (status = 200, description = "Theme association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("theme_id" = u32, Path,)),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
-async fn add_theme(Path((id, theme_id)): Path<(u32, u32)>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn add_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -464,12 +519,16 @@ async fn add_theme(Path((id, theme_id)): Path<(u32, u32)>, State(app_state): Sta
 
 #[utoipa::path(delete, path = "/{id}/themes/{theme_id}",
   responses(
-    (status = 200, description = "Theme association successfully removed"), ServerError, BadRequest
+    (status = 200, description = "Theme association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("theme_id" = u32, Path,)),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
-async fn remove_theme(Path((id, theme_id)): Path<(u32, u32)>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn remove_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -489,12 +548,13 @@ async fn remove_theme(Path((id, theme_id)): Path<(u32, u32)>, State(app_state):
 
 #[utoipa::path(post, path = "/{id}/involved/{person_id}/{role_id}",
   responses(
-    (status = 200, description = "Involved association successfully added"), ServerError, BadRequest
+    (status = 200, description = "Involved association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,)),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
 async fn add_involved(
+  _user: Editor,
   Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
   State(app_state): State<AppState>,
 ) -> impl IntoResponse {
@@ -517,12 +577,13 @@ async fn add_involved(
 
 #[utoipa::path(delete, path = "/{id}/involved/{person_id}/{role_id}",
   responses(
-    (status = 200, description = "Involved association successfully removed"), ServerError, BadRequest
+    (status = 200, description = "Involved association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,)),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
   tag = "Books"
 )]
 async fn remove_involved(
+  _user: Editor,
   Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
   State(app_state): State<AppState>,
 ) -> impl IntoResponse {
@@ -545,12 +606,14 @@ async fn remove_involved(
 }
 #[utoipa::path(post, path = "",
   responses(
-    (status = 201, description = "Book successfully created", body = Book), ServerError, BadRequest
+    (status = 201, description = "Book successfully created", body = Book), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
   request_body(content_type = ["multipart/form-data"], content = CreateBook),
+  params(JsonWebTokenParam),
   tag = "Books"
 )]
 async fn create_book(
+  _user: Editor,
   State(app_state): State<AppState>,
   MultiPartRequest(create_book): MultiPartRequest<CreateBook>,
 ) -> impl IntoResponse {
@@ -573,12 +636,12 @@ async fn create_book(
 
 #[utoipa::path(delete, path = "/{id}",
   responses(
-    (status = 204, description = "Book successfully deleted"), ServerError, BadRequest
+    (status = 204, description = "Book successfully deleted, it is removed from the libraries of all users"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(("id" = u32, Path, description = "Id of the item to delete")),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
   tag = "Books"
 )]
-async fn delete_book(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn delete_book(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -595,3 +658,479 @@ async fn delete_book(Path(id): Path<u32>, State(app_state): State<AppState>) ->
   transaction.commit().await.map_err(convert_error)?;
   result
 }
+
+#[utoipa::path(get, path = "/{id}/editions",
+  responses(
+    (status = 200, description = "Returned editions based on the book id", body = BookEditionsTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Books"
+)]
+async fn get_editions(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_edition_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for editions from a book with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/editions/{edition_id}",
+  responses(
+    (status = 200, description = "Returned edition based on the book id and edition id", body = BookEdition), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, ("edition_id" = u32, Path,), AcceptLanguageParam),
+  tag = "Books"
+)]
+async fn get_edition(
+  Path((id, edition_id)): Path<(u32, u32)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_edition_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for an edition with the id {edition_id} from a book with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_id(id, edition_id, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/isbn/{isbn}",
+  responses(
+    (status = 200, description = "Returned edition based on the ISBN-13", body = BookEdition), ServerError, BadRequest, NotFound
+  ),
+  params(Isbn13Param, AcceptLanguageParam),
+  tag = "Books"
+)]
+async fn get_edition_by_isbn13(
+  Path(isbn13): Path<Isbn13>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_edition_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for an edition with the ISBN-13 {} in {}", isbn13, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_isbn13(&isbn13, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/{id}/editions",
+  responses(
+    (status = 201, description = "Edition successfully created", body = BookEdition), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, JsonWebTokenParam),
+  request_body(content_type = ["multipart/form-data"], content = CreateBookEdition),
+  tag = "Books"
+)]
+async fn create_edition(
+  _user: Editor,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_edition): MultiPartRequest<CreateBookEdition>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_edition_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for creating an edition for a book with the id {id}");
+
+    match service.create(id, create_edition).await {
+      Ok(edition) => Ok((StatusCode::CREATED, Json(edition))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(patch, path = "/{id}/editions/{edition_id}",
+  responses(
+    (status = 200, description = "Edition successfully updated", body = BookEdition), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("edition_id" = u32, Path,), JsonWebTokenParam),
+  request_body = PatchBookEdition,
+  tag = "Books"
+)]
+async fn update_edition(
+  _user: Editor,
+  Path((id, edition_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+  Json(patch_edition): Json<PatchBookEdition>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_edition_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for updating an edition with the id {edition_id} for a book with the id {id}");
+
+    match service.update(id, edition_id, patch_edition).await {
+      Ok(edition) => Ok((StatusCode::OK, Json(edition))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/editions/{edition_id}",
+  responses(
+    (status = 204, description = "Edition successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("edition_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Books"
+)]
+async fn delete_edition(
+  _user: Admin,
+  Path((id, edition_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_edition_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for deleting an edition with the id {edition_id} for a book with the id {id}");
+
+    match service.delete(id, edition_id).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(get, path = "/{id}/editions/{edition_id}/involved",
+  responses(
+    (status = 200, description = "Returned people involved based on the book id and edition id", body = BookEditionInvolvedTotal), ServerError, BadRequest
+  ),
+  params(IdParam, ("edition_id" = u32, Path,), AcceptLanguageParam, PageParam, CountParam),
+  tag = "Books"
+)]
+async fn get_edition_involved(
+  Path((id, edition_id)): Path<(u32, u32)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_edition_involved_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for people involved from an edition with the id {edition_id} of a book with the id {id} in {language}"
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, edition_id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/{id}/editions/{edition_id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("edition_id" = u32, Path,), ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Books"
+)]
+async fn add_edition_involved(
+  _user: Editor,
+  Path((id, edition_id, person_id, role_id)): Path<(u32, u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_edition_involved_service(&transaction, client);
+    let involved_id = InvolvedId { person_id, role_id };
+    println!(
+      "Route for adding an association with the ids {involved_id} for an edition with the id {edition_id} of a book with the id {id}"
+    );
+
+    match service.add(id, edition_id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/editions/{edition_id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("edition_id" = u32, Path,), ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Books"
+)]
+async fn remove_edition_involved(
+  _user: Editor,
+  Path((id, edition_id, person_id, role_id)): Path<(u32, u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_edition_involved_service(&transaction, client);
+    let involved_id = InvolvedId { person_id, role_id };
+    println!(
+      "Route for removing an association with the ids {involved_id} for an edition with the id {edition_id} of a book with the id {id}"
+    );
+
+    match service.remove(id, edition_id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(get, path = "/{id}/images",
+  responses(
+    (status = 200, description = "Returned images based on the book id", body = BookImagesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, PageParam, CountParam),
+  tag = "Books"
+)]
+async fn get_images(
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_images_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for images from a book with the id {id}");
+
+  match service.get(id, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/{id}/images",
+  responses(
+    (status = 201, description = "Images successfully added", body = [BookImage]), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, JsonWebTokenParam),
+  request_body(content_type = ["multipart/form-data"], content = CreateBookImages),
+  tag = "Books"
+)]
+async fn add_images(
+  _user: Editor,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_images): MultiPartRequest<CreateBookImages>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_images_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for adding images to a book with the id {id}");
+
+    match service.add(id, create_images.images).await {
+      Ok(images) => Ok((StatusCode::CREATED, Json(images))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(put, path = "/{id}/images",
+  responses(
+    (status = 200, description = "Images successfully reordered"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, JsonWebTokenParam),
+  request_body(description = "Every image id of the book in the new order", content = Vec<u32>),
+  tag = "Books"
+)]
+async fn reorder_images(
+  _user: Editor,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Json(order): Json<Vec<u32>>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_images_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for reordering the images of a book with the id {id}");
+
+    match service.reorder(id, &order).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/images/{image_id}",
+  responses(
+    (status = 200, description = "Image successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("image_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Books"
+)]
+async fn remove_image(
+  _user: Editor,
+  Path((id, image_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_images_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for removing an image with the id {image_id} from a book with the id {id}");
+
+    match service.remove(id, &[image_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(get, path = "/{id}/publishers",
+  responses(
+    (status = 200, description = "Returned publishers based on the book id", body = CompaniesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, PageParam, CountParam),
+  tag = "Books"
+)]
+async fn get_publishers(
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_publisher_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for publishers from a book with the id {}", id);
+
+  match service.get(id, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/{id}/publishers/{publisher_id}",
+  responses(
+    (status = 200, description = "Publisher association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("publisher_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Books"
+)]
+async fn add_publisher(
+  _user: Editor,
+  Path((id, publisher_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_publisher_service(&transaction, client);
+
+    println!("Route for adding a publisher with the id {publisher_id} for a book with the id {id}");
+
+    match service.add(id, &[publisher_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/publishers/{publisher_id}",
+  responses(
+    (status = 200, description = "Publisher association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("publisher_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Books"
+)]
+async fn remove_publisher(
+  _user: Editor,
+  Path((id, publisher_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_publisher_service(&transaction, client);
+
+    println!("Route for removing a publisher with the id {publisher_id} for a book with the id {id}");
+
+    match service.remove(id, &[publisher_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
@@ -21,7 +21,23 @@ use super::*;
     create_book,
     delete_book,
     get_statistic,
-    get_by_slug
+    get_by_slug,
+    get_editions,
+    get_edition,
+    get_edition_by_isbn13,
+    create_edition,
+    update_edition,
+    delete_edition,
+    get_edition_involved,
+    add_edition_involved,
+    remove_edition_involved,
+    get_images,
+    add_images,
+    reorder_images,
+    remove_image,
+    get_publishers,
+    add_publisher,
+    remove_publisher
   )
 )]
 pub(crate) struct BookDoc;
@@ -1,28 +1,42 @@
 use crate::controllers::DEFAULT_LANGUAGE;
 use crate::implementations::{
-  get_book_character_repository, get_book_character_service, get_book_genre_repository, get_book_genre_service,
-  get_book_involved_repository, get_book_involved_service, get_book_repository, get_book_service,
-  get_book_theme_repository, get_book_theme_service, get_character_repository, get_file_repository,
-  get_franchise_repository, get_genre_repository, get_image_repository, get_mut_book_character_repository,
-  get_mut_book_character_service, get_mut_book_genre_repository, get_mut_book_genre_service,
-  get_mut_book_involved_repository, get_mut_book_involved_service, get_mut_book_repository, get_mut_book_service,
-  get_mut_book_theme_repository, get_mut_book_theme_service, get_mut_file_repository, get_mut_file_service,
-  get_mut_image_repository, get_mut_image_service, get_person_repository, get_role_repository, get_theme_repository,
+  get_book_character_repository, get_book_character_service, get_book_edition_involved_repository,
+  get_book_edition_involved_service, get_book_edition_repository, get_book_edition_service, get_book_genre_repository,
+  get_book_genre_service, get_book_image_repository, get_book_image_service, get_book_involved_repository,
+  get_book_involved_service, get_book_publisher_repository, get_book_publisher_service, get_book_repository,
+  get_book_service, get_book_theme_repository, get_book_theme_service, get_character_repository,
+  get_company_repository, get_file_repository, get_franchise_repository, get_genre_repository, get_image_repository,
+  get_mut_book_character_repository, get_mut_book_character_service, get_mut_book_edition_involved_repository,
+  get_mut_book_edition_involved_service, get_mut_book_edition_repository, get_mut_book_edition_service,
+  get_mut_book_genre_repository, get_mut_book_genre_service, get_mut_book_image_repository, get_mut_book_image_service,
+  get_mut_book_involved_repository, get_mut_book_involved_service, get_mut_book_publisher_repository,
+  get_mut_book_publisher_service, get_mut_book_repository, get_mut_book_service, get_mut_book_theme_repository,
+  get_mut_book_theme_service, get_mut_file_repository, get_mut_file_service, get_mut_image_repository,
+  get_mut_image_service, get_mut_user_average_repository, get_mut_user_book_repository, get_person_repository,
+  get_role_repository, get_theme_repository,
 };
+use application::cached_connection::{CachedClient, CachedTransaction};
 use services::book_service::book_character_service::mut_book_character_service::MutBookCharacterService;
 use services::book_service::book_character_service::BookCharacterService;
+use services::book_service::book_edition_involved_service::mut_book_edition_involved_service::MutBookEditionInvolvedService;
+use services::book_service::book_edition_involved_service::BookEditionInvolvedService;
+use services::book_service::book_edition_service::mut_book_edition_service::MutBookEditionService;
+use services::book_service::book_edition_service::BookEditionService;
 use services::book_service::book_genre_service::mut_book_genre_service::MutBookGenreService;
 use services::book_service::book_genre_service::BookGenreService;
+use services::book_service::book_image_service::mut_book_image_service::MutBookImageService;
+use services::book_service::book_image_service::BookImageService;
 use services::book_service::book_involved_service::mut_book_involved_service::MutBookInvolvedService;
 use services::book_service::book_involved_service::BookInvolvedService;
+use services::book_service::book_publisher_service::mut_book_publisher_service::MutBookPublisherService;
+use services::book_service::book_publisher_service::BookPublisherService;
 use services::book_service::book_theme_service::mut_book_theme_service::MutBookThemeService;
 use services::book_service::book_theme_service::BookThemeService;
 use services::book_service::mut_book_service::MutBookService;
 use services::book_service::BookService;
 use std::sync::Arc;
-use tokio_postgres::{Client, Transaction};
 
-pub fn get_genre_service(connection: &Client) -> impl BookGenreService + '_ {
+pub fn get_genre_service(connection: &CachedClient) -> impl BookGenreService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
   let book_repository = Arc::new(get_book_repository(
@@ -42,8 +56,8 @@ pub fn get_genre_service(connection: &Client) -> impl BookGenreService + '_ {
 }
 
 pub fn get_mut_genre_service<'a>(
-  transaction: &'a Transaction<'a>,
-  client: &'a Client,
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
 ) -> impl MutBookGenreService + 'a {
   let image_repository = Arc::new(get_image_repository(client));
   let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
@@ -69,7 +83,7 @@ pub fn get_mut_genre_service<'a>(
   )
 }
 
-pub fn get_theme_service(connection: &Client) -> impl BookThemeService + '_ {
+pub fn get_theme_service(connection: &CachedClient) -> impl BookThemeService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
   let book_repository = Arc::new(get_book_repository(
@@ -89,8 +103,8 @@ pub fn get_theme_service(connection: &Client) -> impl BookThemeService + '_ {
 }
 
 pub fn get_mut_theme_service<'a>(
-  transaction: &'a Transaction<'a>,
-  client: &'a Client,
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
 ) -> impl MutBookThemeService + 'a {
   let image_repository = Arc::new(get_image_repository(client));
   let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
@@ -116,7 +130,7 @@ pub fn get_mut_theme_service<'a>(
   )
 }
 
-pub fn get_character_service(connection: &Client) -> impl BookCharacterService + '_ {
+pub fn get_character_service(connection: &CachedClient) -> impl BookCharacterService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
   let book_repository = Arc::new(get_book_repository(
@@ -136,8 +150,8 @@ pub fn get_character_service(connection: &Client) -> impl BookCharacterService +
 }
 
 pub fn get_mut_character_service<'a>(
-  transaction: &'a Transaction<'a>,
-  client: &'a Client,
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
 ) -> impl MutBookCharacterService + 'a {
   let image_repository = Arc::new(get_image_repository(client));
   let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
@@ -163,7 +177,7 @@ pub fn get_mut_character_service<'a>(
   )
 }
 
-pub fn get_involved_service(connection: &Client) -> impl BookInvolvedService + '_ {
+pub fn get_involved_service(connection: &CachedClient) -> impl BookInvolvedService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
   let book_repository = Arc::new(get_book_repository(
@@ -188,7 +202,7 @@ pub fn get_involved_service(connection: &Client) -> impl BookInvolvedService + '
   get_book_involved_service(repository)
 }
 
-pub fn get_service(connection: &Client) -> impl BookService + '_ {
+pub fn get_service(connection: &CachedClient) -> impl BookService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
   let repository = get_book_repository(connection, DEFAULT_LANGUAGE, image_repository, franchise_repository);
@@ -196,8 +210,8 @@ pub fn get_service(connection: &Client) -> impl BookService + '_ {
 }
 
 pub fn get_mut_involved_service<'a>(
-  transaction: &'a Transaction<'a>,
-  client: &'a Client,
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
 ) -> impl MutBookInvolvedService + 'a {
   let image_repository = Arc::new(get_image_repository(client));
   let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
@@ -232,8 +246,8 @@ pub fn get_mut_involved_service<'a>(
 
 //TODO: Refactor
 pub fn get_mut_service<'a>(
-  transaction: &'a Transaction<'a>,
-  client: &'a Client,
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
   display_path: &'a str,
   path: &'a str,
 ) -> impl MutBookService + 'a {
@@ -270,6 +284,23 @@ pub fn get_mut_service<'a>(
   ));
   let mut_book_involved_repository = Arc::new(get_mut_book_involved_repository(transaction));
 
+  let book_edition_repository = Arc::new(get_book_edition_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let mut_book_edition_involved_repository = Arc::new(get_mut_book_edition_involved_repository(transaction));
+  let mut_book_edition_repository = Arc::new(get_mut_book_edition_repository(
+    transaction,
+    DEFAULT_LANGUAGE,
+    book_edition_repository.clone(),
+    mut_book_edition_involved_repository,
+  ));
+  let mut_book_image_repository = Arc::new(get_mut_book_image_repository(transaction));
+  let mut_book_publisher_repository = Arc::new(get_mut_book_publisher_repository(transaction));
+  let mut_user_average_repository = Arc::new(get_mut_user_average_repository(transaction));
+  let mut_user_book_repository = Arc::new(get_mut_user_book_repository(transaction, mut_user_average_repository));
+
   let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
   let book_repository = Arc::new(get_book_repository(
     client,
@@ -286,12 +317,17 @@ pub fn get_mut_service<'a>(
     mut_book_character_repository,
     mut_book_theme_repository,
     mut_book_involved_repository,
+    mut_book_edition_repository,
+    mut_book_image_repository,
+    mut_book_publisher_repository,
+    mut_user_book_repository,
     book_repository.clone(),
   ));
   get_mut_book_service(
     DEFAULT_LANGUAGE,
     book_repository,
     mut_book_repository,
+    book_edition_repository,
     mut_image_service,
     franchise_repository,
     theme_repository,
@@ -301,3 +337,187 @@ pub fn get_mut_service<'a>(
     role_repository,
   )
 }
+
+pub fn get_edition_service(connection: &CachedClient) -> impl BookEditionService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let repository = get_book_edition_repository(connection, DEFAULT_LANGUAGE, image_repository);
+  get_book_edition_service(Arc::new(repository))
+}
+
+pub fn get_mut_edition_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutBookEditionService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let file_repository = Arc::new(get_file_repository());
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository.clone()));
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository.clone(),
+    mut_file_repository,
+    file_repository,
+  ));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let book_repository = Arc::new(get_book_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let book_edition_repository = Arc::new(get_book_edition_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let mut_book_edition_involved_repository = Arc::new(get_mut_book_edition_involved_repository(transaction));
+  let mut_book_edition_repository = Arc::new(get_mut_book_edition_repository(
+    transaction,
+    DEFAULT_LANGUAGE,
+    book_edition_repository.clone(),
+    mut_book_edition_involved_repository,
+  ));
+  get_mut_book_edition_service(
+    book_repository,
+    book_edition_repository,
+    mut_book_edition_repository,
+    mut_image_service,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_edition_involved_service(connection: &CachedClient) -> impl BookEditionInvolvedService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let person_repository = Arc::new(get_person_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let role_repository = Arc::new(get_role_repository(connection, DEFAULT_LANGUAGE));
+  let book_edition_repository = Arc::new(get_book_edition_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository,
+  ));
+  let repository = Arc::new(get_book_edition_involved_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    person_repository,
+    role_repository,
+  ));
+  get_book_edition_involved_service(book_edition_repository, repository)
+}
+
+pub fn get_mut_edition_involved_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutBookEditionInvolvedService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let book_edition_repository = Arc::new(get_book_edition_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let book_edition_involved_repository = get_book_edition_involved_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    person_repository.clone(),
+    role_repository.clone(),
+  );
+  let repository = get_mut_book_edition_involved_repository(transaction);
+  get_mut_book_edition_involved_service(
+    book_edition_repository,
+    Arc::new(book_edition_involved_repository),
+    Arc::new(repository),
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_images_service(connection: &CachedClient) -> impl BookImageService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let repository = get_book_image_repository(connection, image_repository);
+  get_book_image_service(Arc::new(repository))
+}
+
+pub fn get_mut_images_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutBookImageService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let file_repository = Arc::new(get_file_repository());
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository.clone()));
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository.clone(),
+    mut_file_repository,
+    file_repository,
+  ));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let book_repository = Arc::new(get_book_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let book_image_repository = get_book_image_repository(client, image_repository);
+  let repository = get_mut_book_image_repository(transaction);
+  get_mut_book_image_service(
+    book_repository,
+    Arc::new(book_image_repository),
+    Arc::new(repository),
+    mut_image_service,
+  )
+}
+
+pub fn get_publisher_service(connection: &CachedClient) -> impl BookPublisherService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let company_repository = Arc::new(get_company_repository(connection, image_repository));
+  let repository = Arc::new(get_book_publisher_repository(connection, company_repository));
+  get_book_publisher_service(repository)
+}
+
+pub fn get_mut_publisher_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutBookPublisherService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let book_repository = Arc::new(get_book_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let company_repository = Arc::new(get_company_repository(client, image_repository));
+  let book_publisher_repository = get_book_publisher_repository(client, company_repository.clone());
+  let repository = get_mut_book_publisher_repository(transaction);
+  get_mut_book_publisher_service(
+    book_repository,
+    Arc::new(book_publisher_repository),
+    Arc::new(repository),
+    company_repository,
+  )
+}
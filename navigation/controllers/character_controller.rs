@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
+use application::cached_connection::CachedClient;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::{Json, Router};
-use tokio_postgres::Client;
 
 use services::character_service::CharacterService;
 
@@ -126,7 +126,7 @@ async fn get_by_name(
   }
 }
 
-fn get_service(connection: &Client) -> impl CharacterService + '_ {
+fn get_service(connection: &CachedClient) -> impl CharacterService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let repository = get_character_repository(connection, DEFAULT_LANGUAGE, image_repository);
   get_character_service(Arc::new(repository))
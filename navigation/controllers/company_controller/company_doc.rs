@@ -0,0 +1,7 @@
+use super::*;
+
+#[derive(utoipa::OpenApi)]
+#[openapi(tags((name = "Companies", description = "Endpoints related to companies")),
+  paths(get_items, get_by_id, get_by_name, create_item, delete_item)
+)]
+pub(crate) struct CompanyDoc;
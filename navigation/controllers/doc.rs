@@ -1,63 +1,166 @@
+use domain::entities::account::account_export::AccountExport;
 use domain::entities::account::create_account::CreateAccount;
 use domain::entities::account::create_account::CreateAccountData;
+use domain::entities::account::create_personal_access_token::CreatePersonalAccessToken;
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use domain::entities::account::two_factor_enrollment::TwoFactorEnrollment;
 use domain::entities::account::Email;
 use domain::entities::account::Password;
 use domain::entities::book::book_character::BookCharacter;
+use domain::entities::book::book_edition::BookEdition;
+use domain::entities::book::book_image::BookImage;
 use domain::entities::book::book_involved::BookInvolved;
 use domain::entities::book::book_statistic::BookStatistic;
 use domain::entities::book::create_book::CreateBook;
 use domain::entities::book::create_book::CreateBookData;
 use domain::entities::book::create_book::CreateBookTranslation;
 use domain::entities::book::create_book::CreateCover;
+use domain::entities::book::create_book_edition::CreateBookEdition;
+use domain::entities::book::create_book_edition::CreateBookEditionData;
+use domain::entities::book::create_book_edition::CreateBookEditionTranslation;
+use domain::entities::book::create_book_image::CreateBookImages;
+use domain::entities::book::patch_book_edition::PatchBookEdition;
 use domain::entities::book::Book;
 use domain::entities::character::Character;
+use domain::entities::company::create_company::CreateCompany;
+use domain::entities::company::create_company::CreateCompanyData;
+use domain::entities::company::Company;
 use domain::entities::franchise::create_franchise::CreateFranchise;
 use domain::entities::franchise::create_franchise::CreateFranchiseTranslation;
 use domain::entities::franchise::Franchise;
+use domain::entities::game::create_game::CreateGame;
+use domain::entities::game::create_game::CreateGameData;
+use domain::entities::game::create_game::CreateGameTranslation;
+use domain::entities::game::game_character::GameCharacter;
+use domain::entities::game::game_involved::GameInvolved;
+use domain::entities::game::game_statistic::GameStatistic;
+use domain::entities::game::Game;
 use domain::entities::genre::create_genre::CreateGenre;
 use domain::entities::genre::create_genre::CreateGenreTranslation;
 use domain::entities::genre::Genre;
+use domain::entities::graphic_novel::create_graphic_novel::CreateGraphicNovel;
+use domain::entities::graphic_novel::create_graphic_novel::CreateGraphicNovelData;
+use domain::entities::graphic_novel::create_graphic_novel::CreateGraphicNovelTranslation;
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapter;
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapterTranslation;
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolume;
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolumeTranslation;
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::entities::graphic_novel::graphic_novel_character::GraphicNovelCharacter;
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::entities::graphic_novel::GraphicNovel;
 use domain::entities::image::create_image::CreateImage;
 use domain::entities::image::image_data::ImageData;
 use domain::entities::image::Image;
 use domain::entities::involved::InvolvedId;
+use domain::entities::movie::create_movie::CreateMovie;
+use domain::entities::movie::create_movie::CreateMovieData;
+use domain::entities::movie::create_movie::CreateMovieTranslation;
+use domain::entities::movie::movie_statistic::MovieStatistic;
+use domain::entities::movie::Movie;
 use domain::entities::person::create_person::CreatePerson;
 use domain::entities::person::create_person::CreatePersonData;
 use domain::entities::person::create_person::CreatePersonTranslation;
 use domain::entities::person::person_role::PersonRole;
 use domain::entities::person::Person;
+use domain::entities::platform::create_platform::CreatePlatform;
+use domain::entities::platform::create_platform::CreatePlatformData;
+use domain::entities::platform::patch_platform::PatchPlatform;
+use domain::entities::platform::Platform;
 use domain::entities::rating::Rating;
 use domain::entities::role::create_role::CreateRole;
 use domain::entities::role::create_role::CreateRoleTranslation;
 use domain::entities::role::Role;
+use domain::entities::show::create_show::CreateShow;
+use domain::entities::show::create_show::CreateShowData;
+use domain::entities::show::create_show::CreateShowTranslation;
+use domain::entities::show::create_show_episode::CreateShowEpisode;
+use domain::entities::show::create_show_episode::CreateShowEpisodeTranslation;
+use domain::entities::show::create_show_season::CreateShowSeason;
+use domain::entities::show::create_show_season::CreateShowSeasonTranslation;
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::entities::show::show_season::ShowSeason;
+use domain::entities::show::Show;
 use domain::entities::theme::create_theme::CreateTheme;
 use domain::entities::theme::create_theme::CreateThemeTranslation;
 use domain::entities::theme::Theme;
 use domain::entities::user::create_user::CreateUser;
 use domain::entities::user::create_user::CreateUserData;
+use domain::entities::user::create_user_book::CreateUserBook;
+use domain::entities::user::friendship::Friendship;
+use domain::entities::user::patch_user::PatchUser;
+use domain::entities::user::patch_user::PatchUserData;
+use domain::entities::user::patch_user_book::PatchUserBook;
+use domain::entities::user::user_average::UserAverage;
+use domain::entities::user::user_book::UserBook;
 use domain::entities::user::User;
+use domain::enums::account_role::AccountRole;
+use domain::enums::friendship_status::FriendshipStatus;
 use domain::enums::language::Language;
+use domain::enums::status::Status;
+use domain::enums::token_scope::TokenScope;
+use domain::enums::user_status::UserStatus;
+use domain::isbn13::Isbn13;
 use domain::items_total::BookCharactersTotal;
+use domain::items_total::BookEditionInvolvedTotal;
+use domain::items_total::BookEditionsTotal;
+use domain::items_total::BookImagesTotal;
 use domain::items_total::BookInvolvedTotal;
 use domain::items_total::BooksTotal;
 use domain::items_total::CharactersTotal;
+use domain::items_total::CompaniesTotal;
 use domain::items_total::FranchisesTotal;
+use domain::items_total::FriendshipsTotal;
+use domain::items_total::GameCharactersTotal;
+use domain::items_total::GameInvolvedTotal;
+use domain::items_total::GamesTotal;
 use domain::items_total::GenresTotal;
+use domain::items_total::GraphicNovelChaptersTotal;
+use domain::items_total::GraphicNovelCharactersTotal;
+use domain::items_total::GraphicNovelInvolvedTotal;
+use domain::items_total::GraphicNovelVolumesTotal;
+use domain::items_total::GraphicNovelsTotal;
+use domain::items_total::MovieInvolvedTotal;
+use domain::items_total::MoviesTotal;
 use domain::items_total::PeopleTotal;
+use domain::items_total::PlatformsTotal;
 use domain::items_total::RolesTotal;
+use domain::items_total::ShowEpisodesTotal;
+use domain::items_total::ShowSeasonsTotal;
+use domain::items_total::ShowsTotal;
 use domain::items_total::ThemesTotal;
+use domain::items_total::UserBooksTotal;
 use domain::items_total::UsersTotal;
 use domain::slug::Slug;
 
 use crate::controllers::account_controller::account_doc::AccountDoc;
+use crate::controllers::account_controller::ChangePasswordData;
+use crate::controllers::account_controller::CreatedPersonalAccessTokenData;
+use crate::controllers::account_controller::DeleteAccountData;
 use crate::controllers::account_controller::LoginData;
 use crate::controllers::account_controller::LoginReturnData;
+use crate::controllers::account_controller::PasswordResetData;
+use crate::controllers::account_controller::PasswordResetRequestData;
+use crate::controllers::account_controller::RecoveryCodesData;
+use crate::controllers::account_controller::RefreshData;
+use crate::controllers::account_controller::RoleData;
+use crate::controllers::account_controller::SecondFactorLoginData;
+use crate::controllers::account_controller::SecondFactorRequiredData;
+use crate::controllers::account_controller::TokenData;
+use crate::controllers::account_controller::TwoFactorCodeData;
+use crate::controllers::account_controller::VerificationData;
 use crate::controllers::book_controller::book_doc::BookDoc;
 use crate::controllers::character_controller::character_doc::CharacterDoc;
+use crate::controllers::company_controller::company_doc::CompanyDoc;
 use crate::controllers::franchise_controller::franchise_doc::FranchiseDoc;
+use crate::controllers::game_controller::game_doc::GameDoc;
 use crate::controllers::genre_controller::genre_doc::GenreDoc;
+use crate::controllers::graphic_novel_controller::graphic_novel_doc::GraphicNovelDoc;
+use crate::controllers::movie_controller::movie_doc::MovieDoc;
 use crate::controllers::person_controller::person_doc::PersonDoc;
+use crate::controllers::platform_controller::platform_doc::PlatformDoc;
 use crate::controllers::role_controller::role_doc::RoleDoc;
+use crate::controllers::show_controller::show_doc::ShowDoc;
 use crate::controllers::theme_controller::theme_doc::ThemeDoc;
 use crate::controllers::user_controller::user_doc::UserDoc;
 
@@ -72,6 +175,12 @@ use crate::controllers::user_controller::user_doc::UserDoc;
     ("/roles", RoleDoc),
     ("/users", UserDoc),
     ("/franchises", FranchiseDoc),
+    ("/movies", MovieDoc),
+    ("/shows", ShowDoc),
+    ("/games", GameDoc),
+    ("/platforms", PlatformDoc),
+    ("/companies", CompanyDoc),
+    ("/graphic-novels", GraphicNovelDoc),
     ("/accounts", AccountDoc),
   ),
   components(schemas(
@@ -126,6 +235,103 @@ use crate::controllers::user_controller::user_doc::UserDoc;
     CreateFranchiseTranslation,
     Slug,
     BookStatistic,
-    Rating
+    Rating,
+    Movie,
+    MoviesTotal,
+    MovieInvolvedTotal,
+    MovieStatistic,
+    CreateMovie,
+    CreateMovieData,
+    CreateMovieTranslation,
+    Show,
+    ShowsTotal,
+    ShowSeason,
+    ShowSeasonsTotal,
+    ShowEpisode,
+    ShowEpisodesTotal,
+    CreateShow,
+    CreateShowData,
+    CreateShowTranslation,
+    CreateShowSeason,
+    CreateShowSeasonTranslation,
+    CreateShowEpisode,
+    CreateShowEpisodeTranslation,
+    Status,
+    Game,
+    GamesTotal,
+    GameInvolved,
+    GameInvolvedTotal,
+    GameCharacter,
+    GameCharactersTotal,
+    GameStatistic,
+    CreateGame,
+    CreateGameData,
+    CreateGameTranslation,
+    Platform,
+    PlatformsTotal,
+    Company,
+    CompaniesTotal,
+    GraphicNovel,
+    GraphicNovelsTotal,
+    GraphicNovelVolume,
+    GraphicNovelVolumesTotal,
+    GraphicNovelChapter,
+    GraphicNovelChaptersTotal,
+    GraphicNovelCharacter,
+    GraphicNovelCharactersTotal,
+    GraphicNovelInvolvedTotal,
+    CreateGraphicNovel,
+    CreateGraphicNovelData,
+    CreateGraphicNovelTranslation,
+    CreateGraphicNovelVolume,
+    CreateGraphicNovelVolumeTranslation,
+    CreateGraphicNovelChapter,
+    CreateGraphicNovelChapterTranslation,
+    BookEdition,
+    BookEditionsTotal,
+    BookEditionInvolvedTotal,
+    CreateBookEdition,
+    CreateBookEditionData,
+    CreateBookEditionTranslation,
+    Isbn13,
+    BookImage,
+    BookImagesTotal,
+    CreateBookImages,
+    CreateCompany,
+    CreateCompanyData,
+    CreatePlatform,
+    CreatePlatformData,
+    PatchBookEdition,
+    PatchPlatform,
+    UserBook,
+    UserBooksTotal,
+    CreateUserBook,
+    PatchUserBook,
+    PatchUser,
+    PatchUserData,
+    UserStatus,
+    Friendship,
+    FriendshipsTotal,
+    FriendshipStatus,
+    UserAverage,
+    RoleData,
+    AccountRole,
+    RefreshData,
+    TokenData,
+    ChangePasswordData,
+    PasswordResetRequestData,
+    PasswordResetData,
+    VerificationData,
+    DeleteAccountData,
+    AccountExport,
+    TokenScope,
+    PersonalAccessToken,
+    CreatePersonalAccessToken,
+    CreatedPersonalAccessTokenData,
+    SecondFactorRequiredData,
+    SecondFactorLoginData,
+    TwoFactorEnrollment,
+    TwoFactorCodeData,
+    RecoveryCodesData
   )))]
 pub(crate) struct ApiDoc;
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
+use application::cached_connection::{CachedClient, CachedTransaction};
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{delete, get, post};
 use axum::{Json, Router};
 use domain::entities::franchise::create_franchise::CreateFranchise;
-use tokio_postgres::{Client, Transaction};
 
 use services::franchise_service::mut_franchise_service::MutFranchiseService;
 use services::franchise_service::FranchiseService;
@@ -17,16 +17,20 @@ use crate::controllers::{
   set_pagination_limit, DEFAULT_LANGUAGE,
 };
 use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
 use crate::extractors::query_pagination::QueryPagination;
 use crate::implementations::{
   get_franchise_repository, get_franchise_service, get_mut_franchise_repository, get_mut_franchise_service,
 };
 use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
 use crate::openapi::params::path::id::IdParam;
 use crate::openapi::params::path::name::NameParam;
 use crate::openapi::params::query::count::CountParam;
 use crate::openapi::params::query::page::PageParam;
 use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
 use crate::openapi::responses::not_found::NotFound;
 use crate::openapi::responses::server_error::ServerError;
 
@@ -132,19 +136,20 @@ async fn get_by_name(
   }
 }
 
-fn get_service(connection: &Client) -> impl FranchiseService + '_ {
+fn get_service(connection: &CachedClient) -> impl FranchiseService + '_ {
   let repository = get_franchise_repository(connection, DEFAULT_LANGUAGE);
   get_franchise_service(Arc::new(repository))
 }
 
 #[utoipa::path(post, path = "",
 responses(
-(status = 201, description = "Franchise successfully created", body = Franchise), ServerError, BadRequest
+(status = 201, description = "Franchise successfully created", body = Franchise), ServerError, NotAuthorized, Forbidden, BadRequest
 ),
 request_body = CreateFranchise,
 tag = "Franchises"
 )]
 async fn create_item(
+  _user: Editor,
   State(app_state): State<AppState>,
   Json(create_franchise): Json<CreateFranchise>,
 ) -> impl IntoResponse {
@@ -167,12 +172,12 @@ async fn create_item(
 
 #[utoipa::path(delete, path = "/{id}",
   responses(
-    (status = 204, description = "Franchise successfully deleted"), ServerError, BadRequest
+    (status = 204, description = "Franchise successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(("id" = u32, Path, description = "Id of the item to delete")),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
   tag = "Franchises"
 )]
-async fn delete_item(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn delete_item(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -190,7 +195,10 @@ async fn delete_item(Path(id): Path<u32>, State(app_state): State<AppState>) ->
   result
 }
 
-fn get_mut_service<'a>(transaction: &'a Transaction<'a>, client: &'a Client) -> impl MutFranchiseService + 'a {
+fn get_mut_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutFranchiseService + 'a {
   let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
   let mut_franchise_repository = Arc::new(get_mut_franchise_repository(
     transaction,
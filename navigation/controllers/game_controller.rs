@@ -0,0 +1,678 @@
+use crate::app_state::AppState;
+use crate::controllers::game_controller::game_implementations::{
+  get_character_service, get_genre_service, get_involved_service, get_mut_character_service, get_mut_genre_service,
+  get_mut_involved_service, get_mut_platform_service, get_mut_service, get_mut_theme_service, get_platform_service,
+  get_service, get_theme_service,
+};
+use crate::controllers::{
+  append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
+  set_pagination_limit, DEFAULT_LANGUAGE,
+};
+use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
+use crate::extractors::query_pagination::QueryPagination;
+use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
+use crate::openapi::params::path::id::IdParam;
+use crate::openapi::params::path::title::TitleParam;
+use crate::openapi::params::query::count::CountParam;
+use crate::openapi::params::query::page::PageParam;
+use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
+use crate::openapi::responses::not_found::NotFound;
+use crate::openapi::responses::server_error::ServerError;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use domain::entities::game::create_game::CreateGame;
+use domain::entities::involved::InvolvedId;
+use multipart::MultiPartRequest;
+use services::game_service::game_character_service::mut_game_character_service::MutGameCharacterService;
+use services::game_service::game_character_service::GameCharacterService;
+use services::game_service::game_genre_service::mut_game_genre_service::MutGameGenreService;
+use services::game_service::game_genre_service::GameGenreService;
+use services::game_service::game_involved_service::mut_game_involved_service::MutGameInvolvedService;
+use services::game_service::game_involved_service::GameInvolvedService;
+use services::game_service::game_platform_service::mut_game_platform_service::MutGamePlatformService;
+use services::game_service::game_platform_service::GamePlatformService;
+use services::game_service::game_theme_service::mut_game_theme_service::MutGameThemeService;
+use services::game_service::game_theme_service::GameThemeService;
+use services::game_service::mut_game_service::MutGameService;
+use services::game_service::{GameService, GameServiceError};
+use services::traits::service_error::ServiceError;
+
+pub mod game_doc;
+mod game_implementations;
+
+pub fn routes(app_state: AppState) -> Router {
+  Router::new()
+    .route("/", get(get_items))
+    .route("/", post(create_game))
+    .route("/:id", get(get_by_id))
+    .route("/:id", delete(delete_game))
+    .route("/:id/statistic", get(get_statistic))
+    .route("/title/:title", get(get_by_title))
+    .route("/:id/genres", get(get_genres))
+    .route("/:id/genres/:genre_id", post(add_genre))
+    .route("/:id/genres/:genre_id", delete(remove_genre))
+    .route("/:id/themes", get(get_themes))
+    .route("/:id/themes/:theme_id", post(add_theme))
+    .route("/:id/themes/:theme_id", delete(remove_theme))
+    .route("/:id/characters", get(get_characters))
+    .route("/:id/characters/:character_id", post(add_character))
+    .route("/:id/characters/:character_id", delete(remove_character))
+    .route("/:id/platforms", get(get_platforms))
+    .route("/:id/platforms/:platform_id", post(add_platform))
+    .route("/:id/platforms/:platform_id", delete(remove_platform))
+    .route("/:id/involved", get(get_involved))
+    .route("/:id/involved/:person_id/:role_id", post(add_involved))
+    .route("/:id/involved/:person_id/:role_id", delete(remove_involved))
+    .with_state(app_state)
+}
+
+#[utoipa::path(get, path = "",
+  responses(
+    (status = 200, description = "Returned games", body = GamesTotal), ServerError, BadRequest),
+  params(AcceptLanguageParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_items(
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for games in {}", language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(language, pagination.into()).await {
+    Ok(games) => Ok((StatusCode::OK, content_language, Json(games))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}",
+  responses(
+    (status = 200, description = "Returned game based on the id", body = Game), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, AcceptLanguageParam),
+  tag = "Games"
+)]
+async fn get_by_id(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for a game with id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_id(id, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+#[utoipa::path(get, path = "/{id}/statistic",
+  responses(
+    (status = 200, description = "Returned game statistic based on the id", body = GameStatistic), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam),
+  tag = "Games"
+)]
+async fn get_statistic(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  println!("Route for a game statistic with id {}", id);
+
+  match service.get_statistics(&[id]).await {
+    Ok(mut items) => Ok((StatusCode::OK, Json(items.swap_remove(0)))),
+    Err(error) => Err(match error {
+      ServiceError::ClientError(error) => match error {
+        GameServiceError::NonExistentGames(_) => (StatusCode::NOT_FOUND, error.to_string()),
+      },
+      ServiceError::ServerError(_) => convert_service_error(error),
+    }),
+  }
+}
+
+#[utoipa::path(get, path = "/title/{title}",
+  responses(
+    (status = 200, description = "Returned games based on the title", body = GamesTotal), ServerError, BadRequest
+  ),
+  params(TitleParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_by_title(
+  Path(title): Path<String>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for games with the title {} in {}", title, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_title(&title, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+#[utoipa::path(get, path = "/{id}/genres",
+  responses(
+    (status = 200, description = "Returned genres based on the game id", body = GenresTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_genres(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_genre_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for genres from a game with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/themes",
+  responses(
+    (status = 200, description = "Returned themes based on the game id", body = ThemesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_themes(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_theme_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for themes from a game with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/characters",
+  responses(
+    (status = 200, description = "Returned characters based on the game id", body = GameCharactersTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_characters(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_character_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for characters from a game with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/platforms",
+  responses(
+    (status = 200, description = "Returned platforms based on the game id", body = PlatformsTotal), ServerError, BadRequest
+  ),
+  params(IdParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_platforms(
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_platform_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for platforms from a game with the id {}", id);
+
+  match service.get(id, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/involved",
+  responses(
+    (status = 200, description = "Returned people involved based on the game id", body = GameInvolvedTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Games"
+)]
+async fn get_involved(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_involved_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for people involved from a game with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/{id}/characters/{character_id}",
+  responses(
+    (status = 200, description = "Character association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("character_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn add_character(
+  _user: Editor,
+  Path((id, character_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_character_service(&transaction, client);
+
+    println!("Route for adding a character with the id {character_id} for a game with the id {id}");
+
+    match service.add(id, &[character_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/characters/{character_id}",
+  responses(
+    (status = 200, description = "Character association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("character_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn remove_character(
+  _user: Editor,
+  Path((id, character_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_character_service(&transaction, client);
+
+    println!("Route for removing a character with the id {character_id} for a game with the id {id}");
+
+    match service.remove(id, &[character_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/genres/{genre_id}",
+  responses(
+    (status = 200, description = "Genre association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn add_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_genre_service(&transaction, client);
+
+    println!("Route for adding a genre with the id {genre_id} for a game with the id {id}");
+
+    match service.add(id, &[genre_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/genres/{genre_id}",
+  responses(
+    (status = 200, description = "Genre association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn remove_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_genre_service(&transaction, client);
+
+    println!("Route for removing a genre with the id {genre_id} for a game with the id {id}");
+
+    match service.remove(id, &[genre_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/platforms/{platform_id}",
+  responses(
+    (status = 200, description = "Platform association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("platform_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn add_platform(
+  _user: Editor,
+  Path((id, platform_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_platform_service(&transaction, client);
+
+    println!("Route for adding a platform with the id {platform_id} for a game with the id {id}");
+
+    match service.add(id, &[platform_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/platforms/{platform_id}",
+  responses(
+    (status = 200, description = "Platform association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("platform_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn remove_platform(
+  _user: Editor,
+  Path((id, platform_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_platform_service(&transaction, client);
+
+    println!("Route for removing a platform with the id {platform_id} for a game with the id {id}");
+
+    match service.remove(id, &[platform_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/themes/{theme_id}",
+  responses(
+    (status = 200, description = "Theme association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn add_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_theme_service(&transaction, client);
+
+    println!("Route for adding a theme with the id {theme_id} for a game with the id {id}");
+
+    match service.add(id, &[theme_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/themes/{theme_id}",
+  responses(
+    (status = 200, description = "Theme association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn remove_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_theme_service(&transaction, client);
+
+    println!("Route for removing a theme with the id {theme_id} for a game with the id {id}");
+
+    match service.remove(id, &[theme_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn add_involved(
+  _user: Editor,
+  Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_involved_service(&transaction, client);
+    let involved_id = InvolvedId { person_id, role_id };
+    println!("Route for adding an association with the ids {involved_id} for a game with the id {id}");
+
+    match service.add(id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn remove_involved(
+  _user: Editor,
+  Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_involved_service(&transaction, client);
+
+    let involved_id = InvolvedId { person_id, role_id };
+    println!("Route for removing an association with the ids {involved_id} for a game with the id {id}");
+
+    match service.remove(id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+#[utoipa::path(post, path = "",
+  responses(
+    (status = 201, description = "Game successfully created", body = Game), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body(content_type = ["multipart/form-data"], content = CreateGame),
+  params(JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn create_game(
+  _user: Editor,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_game): MultiPartRequest<CreateGame>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for creating a game");
+
+    match service.create(create_game).await {
+      Ok(game) => Ok((StatusCode::CREATED, Json(game))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}",
+  responses(
+    (status = 204, description = "Game successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
+  tag = "Games"
+)]
+async fn delete_game(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for deleting a game");
+
+    match service.delete(&[id]).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+#[derive(utoipa::OpenApi)]
+#[openapi(tags((name = "Games", description = "Endpoints related to games")),
+  paths(
+    get_items,
+    get_by_id,
+    get_by_title,
+    get_genres,
+    get_themes,
+    get_characters,
+    get_involved,
+    add_character,
+    remove_character,
+    add_genre,
+    remove_genre,
+    add_theme,
+    remove_theme,
+    add_involved,
+    remove_involved,
+    create_game,
+    delete_game,
+    get_statistic,
+    get_platforms,
+    add_platform,
+    remove_platform
+  )
+)]
+pub(crate) struct GameDoc;
@@ -0,0 +1,352 @@
+use crate::controllers::DEFAULT_LANGUAGE;
+use crate::implementations::{
+  get_character_repository, get_company_repository, get_file_repository, get_franchise_repository,
+  get_game_character_repository, get_game_character_service, get_game_genre_repository, get_game_genre_service,
+  get_game_involved_repository, get_game_involved_service, get_game_platform_repository, get_game_platform_service,
+  get_game_repository, get_game_service, get_game_theme_repository, get_game_theme_service, get_genre_repository,
+  get_image_repository, get_mut_file_repository, get_mut_file_service, get_mut_game_character_repository,
+  get_mut_game_character_service, get_mut_game_genre_repository, get_mut_game_genre_service,
+  get_mut_game_involved_repository, get_mut_game_involved_service, get_mut_game_platform_repository,
+  get_mut_game_platform_service, get_mut_game_repository, get_mut_game_service, get_mut_game_theme_repository,
+  get_mut_game_theme_service, get_mut_image_repository, get_mut_image_service, get_person_repository,
+  get_platform_repository, get_role_repository, get_theme_repository,
+};
+use application::cached_connection::{CachedClient, CachedTransaction};
+use services::game_service::game_character_service::mut_game_character_service::MutGameCharacterService;
+use services::game_service::game_character_service::GameCharacterService;
+use services::game_service::game_genre_service::mut_game_genre_service::MutGameGenreService;
+use services::game_service::game_genre_service::GameGenreService;
+use services::game_service::game_involved_service::mut_game_involved_service::MutGameInvolvedService;
+use services::game_service::game_involved_service::GameInvolvedService;
+use services::game_service::game_platform_service::mut_game_platform_service::MutGamePlatformService;
+use services::game_service::game_platform_service::GamePlatformService;
+use services::game_service::game_theme_service::mut_game_theme_service::MutGameThemeService;
+use services::game_service::game_theme_service::GameThemeService;
+use services::game_service::mut_game_service::MutGameService;
+use services::game_service::GameService;
+use std::sync::Arc;
+
+pub fn get_genre_service(connection: &CachedClient) -> impl GameGenreService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let genre_repository = Arc::new(get_genre_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_game_genre_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    game_repository,
+    genre_repository,
+  ));
+  get_game_genre_service(repository)
+}
+
+pub fn get_mut_genre_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGameGenreService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let genre_repository = Arc::new(get_genre_repository(client, DEFAULT_LANGUAGE));
+  let game_genre_repository = get_game_genre_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    game_repository.clone(),
+    genre_repository.clone(),
+  );
+  let repository = get_mut_game_genre_repository(transaction);
+  get_mut_game_genre_service(
+    game_repository,
+    Arc::new(game_genre_repository),
+    Arc::new(repository),
+    genre_repository,
+  )
+}
+
+pub fn get_theme_service(connection: &CachedClient) -> impl GameThemeService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let theme_repository = Arc::new(get_theme_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_game_theme_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    game_repository,
+    theme_repository,
+  ));
+  get_game_theme_service(repository)
+}
+
+pub fn get_mut_theme_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGameThemeService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let theme_repository = Arc::new(get_theme_repository(client, DEFAULT_LANGUAGE));
+  let game_theme_repository = get_game_theme_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    game_repository.clone(),
+    theme_repository.clone(),
+  );
+  let repository = get_mut_game_theme_repository(transaction);
+  get_mut_game_theme_service(
+    game_repository,
+    Arc::new(game_theme_repository),
+    Arc::new(repository),
+    theme_repository,
+  )
+}
+
+pub fn get_character_service(connection: &CachedClient) -> impl GameCharacterService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let character_repository = Arc::new(get_character_repository(connection, DEFAULT_LANGUAGE, image_repository));
+  let repository = Arc::new(get_game_character_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    game_repository,
+    character_repository,
+  ));
+  get_game_character_service(repository)
+}
+
+pub fn get_mut_character_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGameCharacterService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let character_repository = Arc::new(get_character_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let game_character_repository = get_game_character_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    game_repository.clone(),
+    character_repository.clone(),
+  );
+  let repository = get_mut_game_character_repository(transaction);
+  get_mut_game_character_service(
+    game_repository,
+    Arc::new(game_character_repository),
+    Arc::new(repository),
+    character_repository,
+  )
+}
+
+pub fn get_involved_service(connection: &CachedClient) -> impl GameInvolvedService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let person_repository = Arc::new(get_person_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let role_repository = Arc::new(get_role_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_game_involved_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    game_repository,
+    person_repository,
+    role_repository,
+  ));
+  get_game_involved_service(repository)
+}
+
+pub fn get_service(connection: &CachedClient) -> impl GameService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let repository = get_game_repository(connection, DEFAULT_LANGUAGE, image_repository, franchise_repository);
+  get_game_service(Arc::new(repository))
+}
+
+pub fn get_mut_involved_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGameInvolvedService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let game_involved_repository = get_game_involved_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    game_repository.clone(),
+    person_repository.clone(),
+    role_repository.clone(),
+  );
+  let repository = get_mut_game_involved_repository(transaction);
+  get_mut_game_involved_service(
+    game_repository,
+    Arc::new(game_involved_repository),
+    Arc::new(repository),
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_platform_service(connection: &CachedClient) -> impl GamePlatformService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let company_repository = Arc::new(get_company_repository(connection, image_repository.clone()));
+  let platform_repository = Arc::new(get_platform_repository(
+    connection,
+    image_repository,
+    company_repository,
+  ));
+  let repository = Arc::new(get_game_platform_repository(connection, platform_repository));
+  get_game_platform_service(repository)
+}
+
+pub fn get_mut_platform_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGamePlatformService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let company_repository = Arc::new(get_company_repository(client, image_repository.clone()));
+  let platform_repository = Arc::new(get_platform_repository(client, image_repository, company_repository));
+  let game_platform_repository = get_game_platform_repository(client, platform_repository.clone());
+  let repository = get_mut_game_platform_repository(transaction);
+  get_mut_game_platform_service(
+    game_repository,
+    Arc::new(game_platform_repository),
+    Arc::new(repository),
+    platform_repository,
+  )
+}
+
+//TODO: Refactor
+pub fn get_mut_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutGameService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let file_repository = Arc::new(get_file_repository());
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+
+  let genre_repository = Arc::new(get_genre_repository(client, DEFAULT_LANGUAGE));
+  let mut_game_genre_repository = Arc::new(get_mut_game_genre_repository(transaction));
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository.clone()));
+  let character_repository = Arc::new(get_character_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let mut_game_character_repository = Arc::new(get_mut_game_character_repository(transaction));
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository.clone(),
+    mut_file_repository,
+    file_repository,
+  ));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let mut_game_involved_repository = Arc::new(get_mut_game_involved_repository(transaction));
+
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let game_repository = Arc::new(get_game_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository.clone(),
+  ));
+  let theme_repository = Arc::new(get_theme_repository(client, DEFAULT_LANGUAGE));
+  let mut_game_theme_repository = Arc::new(get_mut_game_theme_repository(transaction));
+  let company_repository = Arc::new(get_company_repository(client, image_repository.clone()));
+  let platform_repository = Arc::new(get_platform_repository(
+    client,
+    image_repository.clone(),
+    company_repository,
+  ));
+  let mut_game_platform_repository = Arc::new(get_mut_game_platform_repository(transaction));
+  let mut_game_repository = Arc::new(get_mut_game_repository(
+    transaction,
+    DEFAULT_LANGUAGE,
+    mut_game_genre_repository,
+    mut_game_character_repository,
+    mut_game_theme_repository,
+    mut_game_involved_repository,
+    mut_game_platform_repository,
+    game_repository.clone(),
+  ));
+  get_mut_game_service(
+    DEFAULT_LANGUAGE,
+    game_repository,
+    mut_game_repository,
+    mut_image_service,
+    franchise_repository,
+    theme_repository,
+    genre_repository,
+    character_repository,
+    platform_repository,
+    person_repository,
+    role_repository,
+  )
+}
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
+use application::cached_connection::{CachedClient, CachedTransaction};
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{delete, get, post};
 use axum::{Json, Router};
-use tokio_postgres::{Client, Transaction};
 
 use domain::entities::genre::create_genre::CreateGenre;
 use services::genre_service::mut_genre_service::MutGenreService;
@@ -17,16 +17,20 @@ use crate::controllers::{
   set_pagination_limit, DEFAULT_LANGUAGE,
 };
 use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
 use crate::extractors::query_pagination::QueryPagination;
 use crate::implementations::{
   get_genre_repository, get_genre_service, get_mut_genre_repository, get_mut_genre_service,
 };
 use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
 use crate::openapi::params::path::id::IdParam;
 use crate::openapi::params::path::name::NameParam;
 use crate::openapi::params::query::count::CountParam;
 use crate::openapi::params::query::page::PageParam;
 use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
 use crate::openapi::responses::not_found::NotFound;
 use crate::openapi::responses::server_error::ServerError;
 
@@ -131,19 +135,23 @@ async fn get_by_name(
   }
 }
 
-fn get_service(connection: &Client) -> impl GenreService + '_ {
+fn get_service(connection: &CachedClient) -> impl GenreService + '_ {
   let repository = get_genre_repository(connection, DEFAULT_LANGUAGE);
   get_genre_service(Arc::new(repository))
 }
 
 #[utoipa::path(post, path = "",
 responses(
-(status = 201, description = "Genre successfully created", body = Genre), ServerError, BadRequest
+(status = 201, description = "Genre successfully created", body = Genre), ServerError, NotAuthorized, Forbidden, BadRequest
 ),
 request_body = CreateGenre,
 tag = "Genres"
 )]
-async fn create_item(State(app_state): State<AppState>, Json(create_genre): Json<CreateGenre>) -> impl IntoResponse {
+async fn create_item(
+  _user: Editor,
+  State(app_state): State<AppState>,
+  Json(create_genre): Json<CreateGenre>,
+) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -163,12 +171,12 @@ async fn create_item(State(app_state): State<AppState>, Json(create_genre): Json
 
 #[utoipa::path(delete, path = "/{id}",
   responses(
-    (status = 204, description = "Genre successfully deleted"), ServerError, BadRequest
+    (status = 204, description = "Genre successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
   ),
-  params(("id" = u32, Path, description = "Id of the item to delete")),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
   tag = "Genres"
 )]
-async fn delete_item(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn delete_item(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -186,7 +194,7 @@ async fn delete_item(Path(id): Path<u32>, State(app_state): State<AppState>) ->
   result
 }
 
-fn get_mut_service<'a>(transaction: &'a Transaction<'a>, client: &'a Client) -> impl MutGenreService + 'a {
+fn get_mut_service<'a>(transaction: &'a CachedTransaction<'a>, client: &'a CachedClient) -> impl MutGenreService + 'a {
   let genre_repository = Arc::new(get_genre_repository(client, DEFAULT_LANGUAGE));
   let mut_genre_repository = Arc::new(get_mut_genre_repository(
     transaction,
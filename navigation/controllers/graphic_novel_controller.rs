@@ -0,0 +1,928 @@
+use crate::app_state::AppState;
+use crate::controllers::graphic_novel_controller::graphic_novel_implementations::{
+  get_chapter_service, get_character_service, get_genre_service, get_involved_service, get_mut_chapter_service,
+  get_mut_character_service, get_mut_genre_service, get_mut_involved_service, get_mut_publisher_service,
+  get_mut_service, get_mut_theme_service, get_mut_volume_service, get_publisher_service, get_service,
+  get_theme_service, get_volume_service,
+};
+use crate::controllers::{
+  append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
+  set_pagination_limit, DEFAULT_LANGUAGE,
+};
+use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
+use crate::extractors::query_pagination::QueryPagination;
+use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
+use crate::openapi::params::path::id::IdParam;
+use crate::openapi::params::path::title::TitleParam;
+use crate::openapi::params::query::count::CountParam;
+use crate::openapi::params::query::page::PageParam;
+use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
+use crate::openapi::responses::not_found::NotFound;
+use crate::openapi::responses::server_error::ServerError;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use domain::entities::graphic_novel::create_graphic_novel::CreateGraphicNovel;
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapter;
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolume;
+use domain::entities::involved::InvolvedId;
+use multipart::MultiPartRequest;
+use services::graphic_novel_service::graphic_novel_chapter_service::mut_graphic_novel_chapter_service::MutGraphicNovelChapterService;
+use services::graphic_novel_service::graphic_novel_chapter_service::GraphicNovelChapterService;
+use services::graphic_novel_service::graphic_novel_character_service::mut_graphic_novel_character_service::MutGraphicNovelCharacterService;
+use services::graphic_novel_service::graphic_novel_character_service::GraphicNovelCharacterService;
+use services::graphic_novel_service::graphic_novel_genre_service::mut_graphic_novel_genre_service::MutGraphicNovelGenreService;
+use services::graphic_novel_service::graphic_novel_genre_service::GraphicNovelGenreService;
+use services::graphic_novel_service::graphic_novel_involved_service::mut_graphic_novel_involved_service::MutGraphicNovelInvolvedService;
+use services::graphic_novel_service::graphic_novel_involved_service::GraphicNovelInvolvedService;
+use services::graphic_novel_service::graphic_novel_publisher_service::mut_graphic_novel_publisher_service::MutGraphicNovelPublisherService;
+use services::graphic_novel_service::graphic_novel_publisher_service::GraphicNovelPublisherService;
+use services::graphic_novel_service::graphic_novel_theme_service::mut_graphic_novel_theme_service::MutGraphicNovelThemeService;
+use services::graphic_novel_service::graphic_novel_theme_service::GraphicNovelThemeService;
+use services::graphic_novel_service::graphic_novel_volume_service::mut_graphic_novel_volume_service::MutGraphicNovelVolumeService;
+use services::graphic_novel_service::graphic_novel_volume_service::GraphicNovelVolumeService;
+use services::graphic_novel_service::mut_graphic_novel_service::MutGraphicNovelService;
+use services::graphic_novel_service::GraphicNovelService;
+
+pub mod graphic_novel_doc;
+mod graphic_novel_implementations;
+
+pub fn routes(app_state: AppState) -> Router {
+  Router::new()
+    .route("/", get(get_items))
+    .route("/", post(create_graphic_novel))
+    .route("/:id", get(get_by_id))
+    .route("/:id", delete(delete_graphic_novel))
+    .route("/title/:title", get(get_by_title))
+    .route("/:id/volumes", get(get_volumes))
+    .route("/:id/volumes", post(create_volume))
+    .route("/:id/volumes/:volume", get(get_volume))
+    .route("/:id/volumes/:volume", delete(delete_volume))
+    .route("/:id/volumes/:volume/chapters", get(get_chapters))
+    .route("/:id/volumes/:volume/chapters", post(create_chapter))
+    .route("/:id/volumes/:volume/chapters/:chapter", get(get_chapter))
+    .route("/:id/volumes/:volume/chapters/:chapter", delete(delete_chapter))
+    .route("/:id/genres", get(get_genres))
+    .route("/:id/genres/:genre_id", post(add_genre))
+    .route("/:id/genres/:genre_id", delete(remove_genre))
+    .route("/:id/themes", get(get_themes))
+    .route("/:id/themes/:theme_id", post(add_theme))
+    .route("/:id/themes/:theme_id", delete(remove_theme))
+    .route("/:id/characters", get(get_characters))
+    .route("/:id/characters/:character_id", post(add_character))
+    .route("/:id/characters/:character_id", delete(remove_character))
+    .route("/:id/publishers", get(get_publishers))
+    .route("/:id/publishers/:publisher_id", post(add_publisher))
+    .route("/:id/publishers/:publisher_id", delete(remove_publisher))
+    .route("/:id/involved", get(get_involved))
+    .route("/:id/involved/:person_id/:role_id", post(add_involved))
+    .route("/:id/involved/:person_id/:role_id", delete(remove_involved))
+    .with_state(app_state)
+}
+
+#[utoipa::path(get, path = "",
+  responses(
+    (status = 200, description = "Returned graphic novels", body = GraphicNovelsTotal), ServerError, BadRequest),
+  params(AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_items(
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for graphic novels in {}", language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(language, pagination.into()).await {
+    Ok(graphic_novels) => Ok((StatusCode::OK, content_language, Json(graphic_novels))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}",
+  responses(
+    (status = 200, description = "Returned graphic novel based on the id", body = GraphicNovel), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, AcceptLanguageParam),
+  tag = "Graphic Novels"
+)]
+async fn get_by_id(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for a graphic novel with id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_id(id, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/title/{title}",
+  responses(
+    (status = 200, description = "Returned graphic novels based on the title", body = GraphicNovelsTotal), ServerError, BadRequest
+  ),
+  params(TitleParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_by_title(
+  Path(title): Path<String>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for graphic novels with the title {} in {}", title, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_title(&title, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/volumes",
+  responses(
+    (status = 200, description = "Returned volumes based on the graphic novel id", body = GraphicNovelVolumesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_volumes(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_volume_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for volumes from a graphic novel with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/volumes/{volume}",
+  responses(
+    (status = 200, description = "Returned volume based on the graphic novel id and volume number", body = GraphicNovelVolume), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, ("volume" = u16, Path,), AcceptLanguageParam),
+  tag = "Graphic Novels"
+)]
+async fn get_volume(
+  Path((id, volume)): Path<(u32, u16)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_volume_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for volume {volume} from a graphic novel with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_volume(id, volume, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/volumes/{volume}/chapters",
+  responses(
+    (status = 200, description = "Returned chapters based on the graphic novel id and volume number", body = GraphicNovelChaptersTotal), ServerError, BadRequest
+  ),
+  params(IdParam, ("volume" = u16, Path,), AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_chapters(
+  Path((id, volume)): Path<(u32, u16)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_chapter_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for chapters of volume {volume} from a graphic novel with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, volume, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/volumes/{volume}/chapters/{chapter}",
+  responses(
+    (status = 200, description = "Returned chapter based on the graphic novel id, volume and chapter number", body = GraphicNovelChapter), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, ("volume" = u16, Path,), ("chapter" = u16, Path,), AcceptLanguageParam),
+  tag = "Graphic Novels"
+)]
+async fn get_chapter(
+  Path((id, volume, chapter)): Path<(u32, u16, u16)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_chapter_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for chapter {chapter} of volume {volume} from a graphic novel with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_chapter(id, volume, chapter, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/genres",
+  responses(
+    (status = 200, description = "Returned genres based on the graphic novel id", body = GenresTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_genres(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_genre_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for genres from a graphic novel with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/themes",
+  responses(
+    (status = 200, description = "Returned themes based on the graphic novel id", body = ThemesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_themes(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_theme_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for themes from a graphic novel with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/characters",
+  responses(
+    (status = 200, description = "Returned characters based on the graphic novel id", body = GraphicNovelCharactersTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_characters(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_character_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for characters from a graphic novel with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/involved",
+  responses(
+    (status = 200, description = "Returned people involved based on the graphic novel id", body = GraphicNovelInvolvedTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_involved(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_involved_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for people involved from a graphic novel with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/publishers",
+  responses(
+    (status = 200, description = "Returned publishers based on the graphic novel id", body = CompaniesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, PageParam, CountParam),
+  tag = "Graphic Novels"
+)]
+async fn get_publishers(
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_publisher_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for publishers from a graphic novel with the id {}", id);
+
+  match service.get(id, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "",
+  responses(
+    (status = 201, description = "Graphic novel successfully created", body = GraphicNovel), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body(content_type = ["multipart/form-data"], content = CreateGraphicNovel),
+  params(JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn create_graphic_novel(
+  _user: Editor,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_graphic_novel): MultiPartRequest<CreateGraphicNovel>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for creating a graphic novel");
+
+    match service.create(create_graphic_novel).await {
+      Ok(graphic_novel) => Ok((StatusCode::CREATED, Json(graphic_novel))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}",
+  responses(
+    (status = 204, description = "Graphic novel with its volumes and chapters successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn delete_graphic_novel(
+  _user: Admin,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for deleting a graphic novel");
+
+    match service.delete(&[id]).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/volumes",
+  responses(
+    (status = 201, description = "Volume successfully created", body = GraphicNovelVolume), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body = CreateGraphicNovelVolume,
+  params(IdParam, JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn create_volume(
+  _user: Editor,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Json(create_volume): Json<CreateGraphicNovelVolume>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_volume_service(&transaction, client);
+
+    println!("Route for creating a volume for the graphic novel with the id {id}");
+
+    match service.create(id, create_volume).await {
+      Ok(volume) => Ok((StatusCode::CREATED, Json(volume))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/volumes/{volume}",
+  responses(
+    (status = 204, description = "Volume with its chapters successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("volume" = u16, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn delete_volume(
+  _user: Admin,
+  Path((id, volume)): Path<(u32, u16)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_volume_service(&transaction, client);
+
+    println!("Route for deleting volume {volume} from the graphic novel with the id {id}");
+
+    match service.delete(id, volume).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/volumes/{volume}/chapters",
+  responses(
+    (status = 201, description = "Chapter successfully created", body = GraphicNovelChapter), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body = CreateGraphicNovelChapter,
+  params(IdParam, ("volume" = u16, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn create_chapter(
+  _user: Editor,
+  Path((id, volume)): Path<(u32, u16)>,
+  State(app_state): State<AppState>,
+  Json(create_chapter): Json<CreateGraphicNovelChapter>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_chapter_service(&transaction, client);
+
+    println!("Route for creating a chapter in volume {volume} of the graphic novel with the id {id}");
+
+    match service.create(id, volume, create_chapter).await {
+      Ok(chapter) => Ok((StatusCode::CREATED, Json(chapter))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/volumes/{volume}/chapters/{chapter}",
+  responses(
+    (status = 204, description = "Chapter successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("volume" = u16, Path,), ("chapter" = u16, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn delete_chapter(
+  _user: Admin,
+  Path((id, volume, chapter)): Path<(u32, u16, u16)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_chapter_service(&transaction, client);
+
+    println!("Route for deleting chapter {chapter} of volume {volume} from the graphic novel with the id {id}");
+
+    match service.delete(id, volume, chapter).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/characters/{character_id}",
+  responses(
+    (status = 200, description = "Character association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("character_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn add_character(
+  _user: Editor,
+  Path((id, character_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_character_service(&transaction, client);
+
+    println!("Route for adding a character with the id {character_id} for a graphic novel with the id {id}");
+
+    match service.add(id, &[character_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/characters/{character_id}",
+  responses(
+    (status = 200, description = "Character association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("character_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn remove_character(
+  _user: Editor,
+  Path((id, character_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_character_service(&transaction, client);
+
+    println!("Route for removing a character with the id {character_id} for a graphic novel with the id {id}");
+
+    match service.remove(id, &[character_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/genres/{genre_id}",
+  responses(
+    (status = 200, description = "Genre association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn add_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_genre_service(&transaction, client);
+
+    println!("Route for adding a genre with the id {genre_id} for a graphic novel with the id {id}");
+
+    match service.add(id, &[genre_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/genres/{genre_id}",
+  responses(
+    (status = 200, description = "Genre association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn remove_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_genre_service(&transaction, client);
+
+    println!("Route for removing a genre with the id {genre_id} for a graphic novel with the id {id}");
+
+    match service.remove(id, &[genre_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/themes/{theme_id}",
+  responses(
+    (status = 200, description = "Theme association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn add_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_theme_service(&transaction, client);
+
+    println!("Route for adding a theme with the id {theme_id} for a graphic novel with the id {id}");
+
+    match service.add(id, &[theme_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/themes/{theme_id}",
+  responses(
+    (status = 200, description = "Theme association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn remove_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_theme_service(&transaction, client);
+
+    println!("Route for removing a theme with the id {theme_id} for a graphic novel with the id {id}");
+
+    match service.remove(id, &[theme_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn add_involved(
+  _user: Editor,
+  Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_involved_service(&transaction, client);
+    let involved_id = InvolvedId { person_id, role_id };
+    println!("Route for adding an association with the ids {involved_id} for a graphic novel with the id {id}");
+
+    match service.add(id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn remove_involved(
+  _user: Editor,
+  Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_involved_service(&transaction, client);
+
+    let involved_id = InvolvedId { person_id, role_id };
+    println!("Route for removing an association with the ids {involved_id} for a graphic novel with the id {id}");
+
+    match service.remove(id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/publishers/{publisher_id}",
+  responses(
+    (status = 200, description = "Publisher association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("publisher_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn add_publisher(
+  _user: Editor,
+  Path((id, publisher_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_publisher_service(&transaction, client);
+
+    println!("Route for adding a publisher with the id {publisher_id} for a graphic novel with the id {id}");
+
+    match service.add(id, &[publisher_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/publishers/{publisher_id}",
+  responses(
+    (status = 200, description = "Publisher association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("publisher_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Graphic Novels"
+)]
+async fn remove_publisher(
+  _user: Editor,
+  Path((id, publisher_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_publisher_service(&transaction, client);
+
+    println!("Route for removing a publisher with the id {publisher_id} for a graphic novel with the id {id}");
+
+    match service.remove(id, &[publisher_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
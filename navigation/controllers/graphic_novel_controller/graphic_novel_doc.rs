@@ -0,0 +1,36 @@
+use super::*;
+
+#[derive(utoipa::OpenApi)]
+#[openapi(tags((name = "Graphic Novels", description = "Endpoints related to graphic novels")),
+  paths(
+    get_items,
+    get_by_id,
+    get_by_title,
+    get_volumes,
+    get_volume,
+    get_chapters,
+    get_chapter,
+    create_graphic_novel,
+    delete_graphic_novel,
+    create_volume,
+    delete_volume,
+    create_chapter,
+    delete_chapter,
+    get_genres,
+    get_themes,
+    get_characters,
+    get_publishers,
+    get_involved,
+    add_character,
+    remove_character,
+    add_genre,
+    remove_genre,
+    add_theme,
+    remove_theme,
+    add_publisher,
+    remove_publisher,
+    add_involved,
+    remove_involved
+  )
+)]
+pub(crate) struct GraphicNovelDoc;
@@ -0,0 +1,347 @@
+use crate::controllers::DEFAULT_LANGUAGE;
+use crate::implementations::{
+  get_character_repository, get_company_repository, get_file_repository, get_genre_repository,
+  get_graphic_novel_chapter_repository, get_graphic_novel_chapter_service, get_graphic_novel_character_repository,
+  get_graphic_novel_character_service, get_graphic_novel_genre_repository, get_graphic_novel_genre_service,
+  get_graphic_novel_involved_repository, get_graphic_novel_involved_service, get_graphic_novel_publisher_repository,
+  get_graphic_novel_publisher_service, get_graphic_novel_repository, get_graphic_novel_service,
+  get_graphic_novel_theme_repository, get_graphic_novel_theme_service, get_graphic_novel_volume_repository,
+  get_graphic_novel_volume_service, get_image_repository, get_mut_file_repository, get_mut_file_service,
+  get_mut_graphic_novel_chapter_repository, get_mut_graphic_novel_chapter_service,
+  get_mut_graphic_novel_character_repository, get_mut_graphic_novel_character_service,
+  get_mut_graphic_novel_genre_repository, get_mut_graphic_novel_genre_service,
+  get_mut_graphic_novel_involved_repository, get_mut_graphic_novel_involved_service,
+  get_mut_graphic_novel_publisher_repository, get_mut_graphic_novel_publisher_service,
+  get_mut_graphic_novel_repository, get_mut_graphic_novel_service, get_mut_graphic_novel_theme_repository,
+  get_mut_graphic_novel_theme_service, get_mut_graphic_novel_volume_repository, get_mut_graphic_novel_volume_service,
+  get_mut_image_repository, get_mut_image_service, get_mut_user_average_repository, get_person_repository,
+  get_role_repository, get_theme_repository,
+};
+use application::cached_connection::{CachedClient, CachedTransaction};
+use services::graphic_novel_service::graphic_novel_chapter_service::mut_graphic_novel_chapter_service::MutGraphicNovelChapterService;
+use services::graphic_novel_service::graphic_novel_chapter_service::GraphicNovelChapterService;
+use services::graphic_novel_service::graphic_novel_character_service::mut_graphic_novel_character_service::MutGraphicNovelCharacterService;
+use services::graphic_novel_service::graphic_novel_character_service::GraphicNovelCharacterService;
+use services::graphic_novel_service::graphic_novel_genre_service::mut_graphic_novel_genre_service::MutGraphicNovelGenreService;
+use services::graphic_novel_service::graphic_novel_genre_service::GraphicNovelGenreService;
+use services::graphic_novel_service::graphic_novel_involved_service::mut_graphic_novel_involved_service::MutGraphicNovelInvolvedService;
+use services::graphic_novel_service::graphic_novel_involved_service::GraphicNovelInvolvedService;
+use services::graphic_novel_service::graphic_novel_publisher_service::mut_graphic_novel_publisher_service::MutGraphicNovelPublisherService;
+use services::graphic_novel_service::graphic_novel_publisher_service::GraphicNovelPublisherService;
+use services::graphic_novel_service::graphic_novel_theme_service::mut_graphic_novel_theme_service::MutGraphicNovelThemeService;
+use services::graphic_novel_service::graphic_novel_theme_service::GraphicNovelThemeService;
+use services::graphic_novel_service::graphic_novel_volume_service::mut_graphic_novel_volume_service::MutGraphicNovelVolumeService;
+use services::graphic_novel_service::graphic_novel_volume_service::GraphicNovelVolumeService;
+use services::graphic_novel_service::mut_graphic_novel_service::MutGraphicNovelService;
+use services::graphic_novel_service::GraphicNovelService;
+use std::sync::Arc;
+
+pub fn get_service(connection: &CachedClient) -> impl GraphicNovelService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let repository = get_graphic_novel_repository(connection, DEFAULT_LANGUAGE, image_repository);
+  get_graphic_novel_service(Arc::new(repository))
+}
+
+pub fn get_mut_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutGraphicNovelService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let file_repository = Arc::new(get_file_repository());
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository.clone()));
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository.clone(),
+    mut_file_repository,
+    file_repository,
+  ));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let mut_graphic_novel_volume_repository = Arc::new(get_mut_graphic_novel_volume_repository(
+    transaction,
+    Arc::new(get_mut_graphic_novel_chapter_repository(transaction)),
+  ));
+  let mut_graphic_novel_repository = Arc::new(get_mut_graphic_novel_repository(
+    transaction,
+    DEFAULT_LANGUAGE,
+    Arc::new(get_mut_graphic_novel_genre_repository(transaction)),
+    Arc::new(get_mut_graphic_novel_character_repository(transaction)),
+    Arc::new(get_mut_graphic_novel_theme_repository(transaction)),
+    Arc::new(get_mut_graphic_novel_involved_repository(transaction)),
+    Arc::new(get_mut_graphic_novel_publisher_repository(transaction)),
+    mut_graphic_novel_volume_repository,
+    Arc::new(get_mut_user_average_repository(transaction)),
+    graphic_novel_repository.clone(),
+  ));
+  get_mut_graphic_novel_service(
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository,
+    mut_graphic_novel_repository,
+    mut_image_service,
+  )
+}
+
+pub fn get_volume_service(connection: &CachedClient) -> impl GraphicNovelVolumeService + '_ {
+  let repository = get_graphic_novel_volume_repository(connection, DEFAULT_LANGUAGE);
+  get_graphic_novel_volume_service(Arc::new(repository))
+}
+
+pub fn get_mut_volume_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelVolumeService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let graphic_novel_repository = get_graphic_novel_repository(client, DEFAULT_LANGUAGE, image_repository);
+  let graphic_novel_volume_repository = get_graphic_novel_volume_repository(client, DEFAULT_LANGUAGE);
+  let mut_graphic_novel_volume_repository = get_mut_graphic_novel_volume_repository(
+    transaction,
+    Arc::new(get_mut_graphic_novel_chapter_repository(transaction)),
+  );
+  get_mut_graphic_novel_volume_service(
+    DEFAULT_LANGUAGE,
+    Arc::new(graphic_novel_repository),
+    Arc::new(graphic_novel_volume_repository),
+    Arc::new(mut_graphic_novel_volume_repository),
+  )
+}
+
+pub fn get_chapter_service(connection: &CachedClient) -> impl GraphicNovelChapterService + '_ {
+  let repository = get_graphic_novel_chapter_repository(connection, DEFAULT_LANGUAGE);
+  get_graphic_novel_chapter_service(Arc::new(repository))
+}
+
+pub fn get_mut_chapter_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelChapterService + 'a {
+  let graphic_novel_volume_repository = get_graphic_novel_volume_repository(client, DEFAULT_LANGUAGE);
+  let graphic_novel_chapter_repository = get_graphic_novel_chapter_repository(client, DEFAULT_LANGUAGE);
+  get_mut_graphic_novel_chapter_service(
+    DEFAULT_LANGUAGE,
+    Arc::new(graphic_novel_volume_repository),
+    Arc::new(graphic_novel_chapter_repository),
+    Arc::new(get_mut_graphic_novel_chapter_repository(transaction)),
+  )
+}
+
+pub fn get_genre_service(connection: &CachedClient) -> impl GraphicNovelGenreService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let genre_repository = Arc::new(get_genre_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_graphic_novel_genre_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository,
+    genre_repository,
+  ));
+  get_graphic_novel_genre_service(repository)
+}
+
+pub fn get_mut_genre_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelGenreService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let genre_repository = Arc::new(get_genre_repository(client, DEFAULT_LANGUAGE));
+  let graphic_novel_genre_repository = get_graphic_novel_genre_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository.clone(),
+    genre_repository.clone(),
+  );
+  let repository = get_mut_graphic_novel_genre_repository(transaction);
+  get_mut_graphic_novel_genre_service(
+    graphic_novel_repository,
+    Arc::new(graphic_novel_genre_repository),
+    Arc::new(repository),
+    genre_repository,
+  )
+}
+
+pub fn get_theme_service(connection: &CachedClient) -> impl GraphicNovelThemeService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let theme_repository = Arc::new(get_theme_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_graphic_novel_theme_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository,
+    theme_repository,
+  ));
+  get_graphic_novel_theme_service(repository)
+}
+
+pub fn get_mut_theme_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelThemeService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let theme_repository = Arc::new(get_theme_repository(client, DEFAULT_LANGUAGE));
+  let graphic_novel_theme_repository = get_graphic_novel_theme_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository.clone(),
+    theme_repository.clone(),
+  );
+  let repository = get_mut_graphic_novel_theme_repository(transaction);
+  get_mut_graphic_novel_theme_service(
+    graphic_novel_repository,
+    Arc::new(graphic_novel_theme_repository),
+    Arc::new(repository),
+    theme_repository,
+  )
+}
+
+pub fn get_character_service(connection: &CachedClient) -> impl GraphicNovelCharacterService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let character_repository = Arc::new(get_character_repository(connection, DEFAULT_LANGUAGE, image_repository));
+  let repository = Arc::new(get_graphic_novel_character_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository,
+    character_repository,
+  ));
+  get_graphic_novel_character_service(repository)
+}
+
+pub fn get_mut_character_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelCharacterService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let character_repository = Arc::new(get_character_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let graphic_novel_character_repository = get_graphic_novel_character_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository.clone(),
+    character_repository.clone(),
+  );
+  let repository = get_mut_graphic_novel_character_repository(transaction);
+  get_mut_graphic_novel_character_service(
+    graphic_novel_repository,
+    Arc::new(graphic_novel_character_repository),
+    Arc::new(repository),
+    character_repository,
+  )
+}
+
+pub fn get_involved_service(connection: &CachedClient) -> impl GraphicNovelInvolvedService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let person_repository = Arc::new(get_person_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let role_repository = Arc::new(get_role_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_graphic_novel_involved_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository,
+    person_repository,
+    role_repository,
+  ));
+  get_graphic_novel_involved_service(repository)
+}
+
+pub fn get_mut_involved_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelInvolvedService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let graphic_novel_involved_repository = get_graphic_novel_involved_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    graphic_novel_repository.clone(),
+    person_repository.clone(),
+    role_repository.clone(),
+  );
+  let repository = get_mut_graphic_novel_involved_repository(transaction);
+  get_mut_graphic_novel_involved_service(
+    graphic_novel_repository,
+    Arc::new(graphic_novel_involved_repository),
+    Arc::new(repository),
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_publisher_service(connection: &CachedClient) -> impl GraphicNovelPublisherService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let company_repository = Arc::new(get_company_repository(connection, image_repository));
+  let repository = Arc::new(get_graphic_novel_publisher_repository(connection, company_repository));
+  get_graphic_novel_publisher_service(repository)
+}
+
+pub fn get_mut_publisher_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutGraphicNovelPublisherService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let graphic_novel_repository = Arc::new(get_graphic_novel_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let company_repository = Arc::new(get_company_repository(client, image_repository));
+  let graphic_novel_publisher_repository = get_graphic_novel_publisher_repository(client, company_repository.clone());
+  let repository = get_mut_graphic_novel_publisher_repository(transaction);
+  get_mut_graphic_novel_publisher_service(
+    graphic_novel_repository,
+    Arc::new(graphic_novel_publisher_repository),
+    Arc::new(repository),
+    company_repository,
+  )
+}
@@ -1,6 +1,7 @@
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use axum::http::{HeaderMap, StatusCode};
 use axum::Router;
@@ -9,22 +10,32 @@ use utoipa_swagger_ui::SwaggerUi;
 
 use domain::enums::language::Language;
 use domain::enums::language::Language::EN;
+use services::file_service::mut_file_service::MutFileService;
 use services::traits::service_error::ServiceError;
 
 use crate::app_state::AppState;
 use crate::extractors::headers::accept_language::AcceptLanguage;
 use crate::extractors::query_pagination::QueryPagination;
+use crate::implementations::{get_mut_file_repository, get_mut_file_service};
 
 mod account_controller;
 mod book_controller;
 mod character_controller;
+mod company_controller;
 mod doc;
 mod franchise_controller;
+mod game_controller;
 mod genre_controller;
+mod graphic_novel_controller;
+mod movie_controller;
 mod person_controller;
+mod platform_controller;
 mod role_controller;
+mod show_controller;
 mod theme_controller;
 mod user_controller;
+
+pub(crate) use account_controller::seed_admin;
 pub fn generate_openapi_spec() -> Result<String, impl Error> {
   doc::ApiDoc::openapi().to_pretty_json()
 }
@@ -39,6 +50,12 @@ pub fn route_controllers(app_state: AppState) -> Router {
     .nest("/roles", role_controller::routes(app_state.clone()))
     .nest("/users", user_controller::routes(app_state.clone()))
     .nest("/franchises", franchise_controller::routes(app_state.clone()))
+    .nest("/movies", movie_controller::routes(app_state.clone()))
+    .nest("/shows", show_controller::routes(app_state.clone()))
+    .nest("/games", game_controller::routes(app_state.clone()))
+    .nest("/platforms", platform_controller::routes(app_state.clone()))
+    .nest("/companies", company_controller::routes(app_state.clone()))
+    .nest("/graphic-novels", graphic_novel_controller::routes(app_state.clone()))
     .nest("/accounts", account_controller::routes(app_state))
     .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", doc))
 }
@@ -89,6 +106,15 @@ pub fn convert_error(error: impl Error) -> (StatusCode, String) {
   (StatusCode::INTERNAL_SERVER_ERROR, "".to_string())
 }
 
+/// Only call once the transaction which deleted the images is committed, a rollback would otherwise keep an image without files
+pub async fn remove_files(files: Vec<String>) -> Result<(), (StatusCode, String)> {
+  let mut_file_service = get_mut_file_service(Arc::new(get_mut_file_repository()));
+  for file in files {
+    mut_file_service.delete(&file).await.map_err(convert_service_error)?;
+  }
+  Ok(())
+}
+
 fn set_pagination_limit(pagination: &mut QueryPagination) {
   if pagination.count > 50 {
     pagination.count = 50;
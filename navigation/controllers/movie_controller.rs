@@ -0,0 +1,496 @@
+use crate::app_state::AppState;
+use crate::controllers::movie_controller::movie_implementations::{
+  get_genre_service, get_involved_service, get_mut_genre_service, get_mut_involved_service, get_mut_service,
+  get_mut_theme_service, get_service, get_theme_service,
+};
+use crate::controllers::{
+  append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
+  set_pagination_limit, DEFAULT_LANGUAGE,
+};
+use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
+use crate::extractors::query_pagination::QueryPagination;
+use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
+use crate::openapi::params::path::id::IdParam;
+use crate::openapi::params::path::title::TitleParam;
+use crate::openapi::params::query::count::CountParam;
+use crate::openapi::params::query::page::PageParam;
+use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
+use crate::openapi::responses::not_found::NotFound;
+use crate::openapi::responses::server_error::ServerError;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use domain::entities::involved::InvolvedId;
+use domain::entities::movie::create_movie::CreateMovie;
+use multipart::MultiPartRequest;
+use services::movie_service::movie_genre_service::mut_movie_genre_service::MutMovieGenreService;
+use services::movie_service::movie_genre_service::MovieGenreService;
+use services::movie_service::movie_involved_service::mut_movie_involved_service::MutMovieInvolvedService;
+use services::movie_service::movie_involved_service::MovieInvolvedService;
+use services::movie_service::movie_theme_service::mut_movie_theme_service::MutMovieThemeService;
+use services::movie_service::movie_theme_service::MovieThemeService;
+use services::movie_service::mut_movie_service::MutMovieService;
+use services::movie_service::{MovieService, MovieServiceError};
+use services::traits::service_error::ServiceError;
+
+pub mod movie_doc;
+mod movie_implementations;
+
+pub fn routes(app_state: AppState) -> Router {
+  Router::new()
+    .route("/", get(get_items))
+    .route("/", post(create_movie))
+    .route("/:id", get(get_by_id))
+    .route("/:id", delete(delete_movie))
+    .route("/:id/statistic", get(get_statistic))
+    .route("/title/:title", get(get_by_title))
+    .route("/:id/genres", get(get_genres))
+    .route("/:id/genres/:genre_id", post(add_genre))
+    .route("/:id/genres/:genre_id", delete(remove_genre))
+    .route("/:id/themes", get(get_themes))
+    .route("/:id/themes/:theme_id", post(add_theme))
+    .route("/:id/themes/:theme_id", delete(remove_theme))
+    .route("/:id/involved", get(get_involved))
+    .route("/:id/involved/:person_id/:role_id", post(add_involved))
+    .route("/:id/involved/:person_id/:role_id", delete(remove_involved))
+    .with_state(app_state)
+}
+
+#[utoipa::path(get, path = "",
+  responses(
+    (status = 200, description = "Returned movies", body = MoviesTotal), ServerError, BadRequest),
+  params(AcceptLanguageParam, PageParam, CountParam),
+  tag = "Movies"
+)]
+async fn get_items(
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for movies in {}", language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(language, pagination.into()).await {
+    Ok(movies) => Ok((StatusCode::OK, content_language, Json(movies))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}",
+  responses(
+    (status = 200, description = "Returned movie based on the id", body = Movie), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, AcceptLanguageParam),
+  tag = "Movies"
+)]
+async fn get_by_id(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for a movie with id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_id(id, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+#[utoipa::path(get, path = "/{id}/statistic",
+  responses(
+    (status = 200, description = "Returned movie statistic based on the id", body = MovieStatistic), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam),
+  tag = "Movies"
+)]
+async fn get_statistic(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  println!("Route for a movie statistic with id {}", id);
+
+  match service.get_statistics(&[id]).await {
+    Ok(mut items) => Ok((StatusCode::OK, Json(items.swap_remove(0)))),
+    Err(error) => Err(match error {
+      ServiceError::ClientError(error) => match error {
+        MovieServiceError::NonExistentMovies(_) => (StatusCode::NOT_FOUND, error.to_string()),
+      },
+      ServiceError::ServerError(_) => convert_service_error(error),
+    }),
+  }
+}
+
+#[utoipa::path(get, path = "/title/{title}",
+  responses(
+    (status = 200, description = "Returned movies based on the title", body = MoviesTotal), ServerError, BadRequest
+  ),
+  params(TitleParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Movies"
+)]
+async fn get_by_title(
+  Path(title): Path<String>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for movies with the title {} in {}", title, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_title(&title, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+#[utoipa::path(get, path = "/{id}/genres",
+  responses(
+    (status = 200, description = "Returned genres based on the movie id", body = GenresTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Movies"
+)]
+async fn get_genres(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_genre_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for genres from a movie with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/themes",
+  responses(
+    (status = 200, description = "Returned themes based on the movie id", body = ThemesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Movies"
+)]
+async fn get_themes(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_theme_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for themes from a movie with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/involved",
+  responses(
+    (status = 200, description = "Returned people involved based on the movie id", body = MovieInvolvedTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Movies"
+)]
+async fn get_involved(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_involved_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for people involved from a movie with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/{id}/genres/{genre_id}",
+  responses(
+    (status = 200, description = "Genre association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn add_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_genre_service(&transaction, client);
+
+    println!("Route for adding a genre with the id {genre_id} for a movie with the id {id}");
+
+    match service.add(id, &[genre_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/genres/{genre_id}",
+  responses(
+    (status = 200, description = "Genre association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("genre_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn remove_genre(
+  _user: Editor,
+  Path((id, genre_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_genre_service(&transaction, client);
+
+    println!("Route for removing a genre with the id {genre_id} for a movie with the id {id}");
+
+    match service.remove(id, &[genre_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/themes/{theme_id}",
+  responses(
+    (status = 200, description = "Theme association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn add_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_theme_service(&transaction, client);
+
+    println!("Route for adding a theme with the id {theme_id} for a movie with the id {id}");
+
+    match service.add(id, &[theme_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/themes/{theme_id}",
+  responses(
+    (status = 200, description = "Theme association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("theme_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn remove_theme(
+  _user: Editor,
+  Path((id, theme_id)): Path<(u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_theme_service(&transaction, client);
+
+    println!("Route for removing a theme with the id {theme_id} for a movie with the id {id}");
+
+    match service.remove(id, &[theme_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully added"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn add_involved(
+  _user: Editor,
+  Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_involved_service(&transaction, client);
+    let involved_id = InvolvedId { person_id, role_id };
+    println!("Route for adding an association with the ids {involved_id} for a movie with the id {id}");
+
+    match service.add(id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/involved/{person_id}/{role_id}",
+  responses(
+    (status = 200, description = "Involved association successfully removed"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("person_id" = u32, Path,), ("role_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn remove_involved(
+  _user: Editor,
+  Path((id, person_id, role_id)): Path<(u32, u32, u32)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_involved_service(&transaction, client);
+
+    let involved_id = InvolvedId { person_id, role_id };
+    println!("Route for removing an association with the ids {involved_id} for a movie with the id {id}");
+
+    match service.remove(id, &[involved_id]).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+#[utoipa::path(post, path = "",
+  responses(
+    (status = 201, description = "Movie successfully created", body = Movie), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body(content_type = ["multipart/form-data"], content = CreateMovie),
+  params(JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn create_movie(
+  _user: Editor,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_movie): MultiPartRequest<CreateMovie>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for creating a movie");
+
+    match service.create(create_movie).await {
+      Ok(movie) => Ok((StatusCode::CREATED, Json(movie))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}",
+  responses(
+    (status = 204, description = "Movie successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
+  tag = "Movies"
+)]
+async fn delete_movie(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for deleting a movie");
+
+    match service.delete(&[id]).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
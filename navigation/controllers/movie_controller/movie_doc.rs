@@ -0,0 +1,23 @@
+use super::*;
+
+#[derive(utoipa::OpenApi)]
+#[openapi(tags((name = "Movies", description = "Endpoints related to movies")),
+  paths(
+    get_items,
+    get_by_id,
+    get_by_title,
+    get_genres,
+    get_themes,
+    get_involved,
+    add_genre,
+    remove_genre,
+    add_theme,
+    remove_theme,
+    add_involved,
+    remove_involved,
+    create_movie,
+    delete_movie,
+    get_statistic
+  )
+)]
+pub(crate) struct MovieDoc;
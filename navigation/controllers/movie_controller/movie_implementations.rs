@@ -0,0 +1,192 @@
+use crate::controllers::DEFAULT_LANGUAGE;
+use crate::implementations::{
+  get_file_repository, get_genre_repository, get_image_repository, get_movie_genre_repository, get_movie_genre_service,
+  get_movie_involved_repository, get_movie_involved_service, get_movie_repository, get_movie_service,
+  get_movie_theme_repository, get_movie_theme_service, get_mut_file_repository, get_mut_file_service,
+  get_mut_image_repository, get_mut_image_service, get_mut_movie_genre_repository, get_mut_movie_genre_service,
+  get_mut_movie_involved_repository, get_mut_movie_involved_service, get_mut_movie_repository, get_mut_movie_service,
+  get_mut_movie_theme_repository, get_mut_movie_theme_service, get_person_repository, get_role_repository,
+  get_theme_repository,
+};
+use application::cached_connection::{CachedClient, CachedTransaction};
+use services::movie_service::movie_genre_service::mut_movie_genre_service::MutMovieGenreService;
+use services::movie_service::movie_genre_service::MovieGenreService;
+use services::movie_service::movie_involved_service::mut_movie_involved_service::MutMovieInvolvedService;
+use services::movie_service::movie_involved_service::MovieInvolvedService;
+use services::movie_service::movie_theme_service::mut_movie_theme_service::MutMovieThemeService;
+use services::movie_service::movie_theme_service::MovieThemeService;
+use services::movie_service::mut_movie_service::MutMovieService;
+use services::movie_service::MovieService;
+use std::sync::Arc;
+
+pub fn get_genre_service(connection: &CachedClient) -> impl MovieGenreService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let movie_repository = Arc::new(get_movie_repository(connection, DEFAULT_LANGUAGE, image_repository));
+  let genre_repository = Arc::new(get_genre_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_movie_genre_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    movie_repository,
+    genre_repository,
+  ));
+  get_movie_genre_service(repository)
+}
+
+pub fn get_mut_genre_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutMovieGenreService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let movie_repository = Arc::new(get_movie_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let genre_repository = Arc::new(get_genre_repository(client, DEFAULT_LANGUAGE));
+  let movie_genre_repository = get_movie_genre_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    movie_repository.clone(),
+    genre_repository.clone(),
+  );
+  let repository = get_mut_movie_genre_repository(transaction);
+  get_mut_movie_genre_service(
+    movie_repository,
+    Arc::new(movie_genre_repository),
+    Arc::new(repository),
+    genre_repository,
+  )
+}
+
+pub fn get_theme_service(connection: &CachedClient) -> impl MovieThemeService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let movie_repository = Arc::new(get_movie_repository(connection, DEFAULT_LANGUAGE, image_repository));
+  let theme_repository = Arc::new(get_theme_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_movie_theme_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    movie_repository,
+    theme_repository,
+  ));
+  get_movie_theme_service(repository)
+}
+
+pub fn get_mut_theme_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutMovieThemeService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let movie_repository = Arc::new(get_movie_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let theme_repository = Arc::new(get_theme_repository(client, DEFAULT_LANGUAGE));
+  let movie_theme_repository = get_movie_theme_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    movie_repository.clone(),
+    theme_repository.clone(),
+  );
+  let repository = get_mut_movie_theme_repository(transaction);
+  get_mut_movie_theme_service(
+    movie_repository,
+    Arc::new(movie_theme_repository),
+    Arc::new(repository),
+    theme_repository,
+  )
+}
+
+pub fn get_involved_service(connection: &CachedClient) -> impl MovieInvolvedService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let movie_repository = Arc::new(get_movie_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let person_repository = Arc::new(get_person_repository(connection, DEFAULT_LANGUAGE, image_repository));
+  let role_repository = Arc::new(get_role_repository(connection, DEFAULT_LANGUAGE));
+  let repository = Arc::new(get_movie_involved_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    movie_repository,
+    person_repository,
+    role_repository,
+  ));
+  get_movie_involved_service(repository)
+}
+
+pub fn get_mut_involved_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutMovieInvolvedService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let movie_repository = Arc::new(get_movie_repository(client, DEFAULT_LANGUAGE, image_repository.clone()));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let movie_involved_repository = get_movie_involved_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    movie_repository.clone(),
+    person_repository.clone(),
+    role_repository.clone(),
+  );
+  let repository = get_mut_movie_involved_repository(transaction);
+  get_mut_movie_involved_service(
+    movie_repository,
+    Arc::new(movie_involved_repository),
+    Arc::new(repository),
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_service(connection: &CachedClient) -> impl MovieService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let repository = get_movie_repository(connection, DEFAULT_LANGUAGE, image_repository);
+  get_movie_service(Arc::new(repository))
+}
+
+pub fn get_mut_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutMovieService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let file_repository = Arc::new(get_file_repository());
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository.clone()));
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository.clone(),
+    mut_file_repository,
+    file_repository,
+  ));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+
+  let genre_repository = Arc::new(get_genre_repository(client, DEFAULT_LANGUAGE));
+  let theme_repository = Arc::new(get_theme_repository(client, DEFAULT_LANGUAGE));
+  let role_repository = Arc::new(get_role_repository(client, DEFAULT_LANGUAGE));
+  let person_repository = Arc::new(get_person_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+  ));
+  let movie_repository = Arc::new(get_movie_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let mut_movie_repository = Arc::new(get_mut_movie_repository(
+    transaction,
+    DEFAULT_LANGUAGE,
+    Arc::new(get_mut_movie_genre_repository(transaction)),
+    Arc::new(get_mut_movie_theme_repository(transaction)),
+    Arc::new(get_mut_movie_involved_repository(transaction)),
+    movie_repository.clone(),
+  ));
+  get_mut_movie_service(
+    DEFAULT_LANGUAGE,
+    movie_repository,
+    mut_movie_repository,
+    mut_image_service,
+    theme_repository,
+    genre_repository,
+    person_repository,
+    role_repository,
+  )
+}
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
+use application::cached_connection::{CachedClient, CachedTransaction};
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{delete, get, post};
 use axum::{Json, Router};
-use tokio_postgres::{Client, Transaction};
 
 use domain::entities::person::create_person::CreatePerson;
 use multipart::MultiPartRequest;
@@ -18,17 +18,21 @@ use crate::controllers::{
   set_pagination_limit, DEFAULT_LANGUAGE,
 };
 use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
 use crate::extractors::query_pagination::QueryPagination;
 use crate::implementations::{
   get_file_repository, get_image_repository, get_mut_file_repository, get_mut_file_service, get_mut_image_repository,
   get_mut_image_service, get_mut_person_repository, get_mut_person_service, get_person_repository, get_person_service,
 };
 use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
 use crate::openapi::params::path::id::IdParam;
 use crate::openapi::params::path::name::NameParam;
 use crate::openapi::params::query::count::CountParam;
 use crate::openapi::params::query::page::PageParam;
 use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
 use crate::openapi::responses::not_found::NotFound;
 use crate::openapi::responses::server_error::ServerError;
 
@@ -135,12 +139,13 @@ async fn get_by_name(
 }
 #[utoipa::path(post, path = "",
     responses(
-        (status = 201, description = "Person successfully created", body = Person), ServerError, BadRequest
+        (status = 201, description = "Person successfully created", body = Person), ServerError, NotAuthorized, Forbidden, BadRequest
     ),
     request_body(content_type = ["multipart/form-data"], content = CreatePerson),
     tag = "People"
 )]
 async fn create_item(
+  _user: Editor,
   State(app_state): State<AppState>,
   MultiPartRequest(create_person): MultiPartRequest<CreatePerson>,
 ) -> impl IntoResponse {
@@ -163,12 +168,12 @@ async fn create_item(
 
 #[utoipa::path(delete, path = "/{id}",
     responses(
-        (status = 204, description = "Person successfully deleted"), ServerError, BadRequest
+        (status = 204, description = "Person successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
     ),
     params(("id" = u32, Path, description = "Id of the item to delete")),
     tag = "People"
 )]
-async fn delete_item(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+async fn delete_item(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
   let mut connection = app_state.pool.get().await.map_err(convert_error)?;
   let transaction = connection.transaction().await.map_err(convert_error)?;
   let result = {
@@ -186,15 +191,15 @@ async fn delete_item(Path(id): Path<u32>, State(app_state): State<AppState>) ->
   result
 }
 
-fn get_service(connection: &Client) -> impl PersonService + '_ {
+fn get_service(connection: &CachedClient) -> impl PersonService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let repository = Arc::new(get_person_repository(connection, DEFAULT_LANGUAGE, image_repository));
   get_person_service(repository)
 }
 
 fn get_mut_service<'a>(
-  transaction: &'a Transaction<'a>,
-  client: &'a Client,
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
   display_path: &'a str,
   path: &'a str,
 ) -> impl MutPersonService + 'a {
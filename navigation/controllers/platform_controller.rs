@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use application::cached_connection::{CachedClient, CachedTransaction};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, patch, post};
+use axum::{Json, Router};
+
+use domain::entities::platform::create_platform::CreatePlatform;
+use domain::entities::platform::patch_platform::PatchPlatform;
+use multipart::MultiPartRequest;
+use services::platform_service::mut_platform_service::MutPlatformService;
+use services::platform_service::PlatformService;
+
+use crate::app_state::AppState;
+use crate::controllers::{convert_error, convert_service_error, remove_files, set_pagination_limit};
+use crate::extractors::permission::{Admin, Editor};
+use crate::extractors::query_pagination::QueryPagination;
+use crate::implementations::{
+  get_company_repository, get_file_repository, get_image_repository, get_mut_file_repository, get_mut_file_service,
+  get_mut_image_repository, get_mut_image_service, get_mut_platform_repository, get_mut_platform_service,
+  get_platform_repository, get_platform_service,
+};
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
+use crate::openapi::params::path::id::IdParam;
+use crate::openapi::params::path::name::NameParam;
+use crate::openapi::params::query::count::CountParam;
+use crate::openapi::params::query::page::PageParam;
+use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
+use crate::openapi::responses::not_found::NotFound;
+use crate::openapi::responses::server_error::ServerError;
+
+pub mod platform_doc;
+
+pub fn routes(app_state: AppState) -> Router {
+  Router::new()
+    .route("/", get(get_items))
+    .route("/", post(create_item))
+    .route("/:id", get(get_by_id))
+    .route("/:id", patch(update_item))
+    .route("/:id", delete(delete_item))
+    .route("/name/:name", get(get_by_name))
+    .with_state(app_state)
+}
+
+#[utoipa::path(get, path = "",
+  responses(
+    (status = 200, description = "Returned platforms", body = PlatformsTotal), ServerError, BadRequest),
+  params(PageParam, CountParam),
+  tag = "Platforms"
+)]
+async fn get_items(
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for platforms");
+
+  match service.get(pagination.into()).await {
+    Ok(platforms) => Ok((StatusCode::OK, Json(platforms))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}",
+  responses(
+    (status = 200, description = "Returned platform based on the id", body = Platform), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam),
+  tag = "Platforms"
+)]
+async fn get_by_id(Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  println!("Route for a platform with id {}", id);
+
+  match service.get_by_id(id).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/name/{name}",
+  responses(
+    (status = 200, description = "Returned platforms based on the name", body = PlatformsTotal), ServerError, BadRequest
+  ),
+  params(NameParam, PageParam, CountParam),
+  tag = "Platforms"
+)]
+async fn get_by_name(
+  Path(name): Path<String>,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for platforms with the name {}", name);
+
+  match service.get_by_name(&name, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "",
+  responses(
+    (status = 201, description = "Platform successfully created", body = Platform), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body(content_type = ["multipart/form-data"], content = CreatePlatform),
+  params(JsonWebTokenParam),
+  tag = "Platforms"
+)]
+async fn create_item(
+  _user: Editor,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_platform): MultiPartRequest<CreatePlatform>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for creating a platform");
+
+    match service.create(create_platform).await {
+      Ok(platform) => Ok((StatusCode::CREATED, Json(platform))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(patch, path = "/{id}",
+  responses(
+    (status = 200, description = "Platform successfully updated", body = Platform), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body = PatchPlatform,
+  params(("id" = u32, Path, description = "Id of the item to update"), JsonWebTokenParam),
+  tag = "Platforms"
+)]
+async fn update_item(
+  _user: Editor,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Json(patch_platform): Json<PatchPlatform>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for updating a platform with id {}", id);
+
+    match service.update(id, patch_platform).await {
+      Ok(platform) => Ok((StatusCode::OK, Json(platform))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}",
+  responses(
+    (status = 204, description = "Platform successfully deleted, it is removed from all games"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
+  tag = "Platforms"
+)]
+async fn delete_item(
+  _user: Admin,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+) -> Result<StatusCode, (StatusCode, String)> {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for deleting a platform");
+
+    service.delete(&[id]).await.map_err(convert_service_error)
+  };
+  transaction.commit().await.map_err(convert_error)?;
+
+  remove_files(result?).await?;
+  Ok(StatusCode::NO_CONTENT)
+}
+
+fn get_service(connection: &CachedClient) -> impl PlatformService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let company_repository = Arc::new(get_company_repository(connection, image_repository.clone()));
+  let repository = get_platform_repository(connection, image_repository, company_repository);
+  get_platform_service(Arc::new(repository))
+}
+
+fn get_mut_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutPlatformService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let company_repository = Arc::new(get_company_repository(client, image_repository.clone()));
+  let platform_repository = Arc::new(get_platform_repository(
+    client,
+    image_repository.clone(),
+    company_repository.clone(),
+  ));
+  let mut_platform_repository = Arc::new(get_mut_platform_repository(transaction, platform_repository.clone()));
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let file_repository = Arc::new(get_file_repository());
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository,
+    mut_file_repository.clone(),
+    file_repository,
+  ));
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+  get_mut_platform_service(
+    platform_repository,
+    mut_platform_repository,
+    company_repository,
+    mut_image_service,
+  )
+}
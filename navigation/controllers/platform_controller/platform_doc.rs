@@ -0,0 +1,7 @@
+use super::*;
+
+#[derive(utoipa::OpenApi)]
+#[openapi(tags((name = "Platforms", description = "Endpoints related to platforms")),
+  paths(get_items, get_by_id, get_by_name, create_item, update_item, delete_item)
+)]
+pub(crate) struct PlatformDoc;
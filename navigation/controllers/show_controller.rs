@@ -0,0 +1,445 @@
+use crate::app_state::AppState;
+use crate::controllers::show_controller::show_implementations::{
+  get_episode_service, get_mut_episode_service, get_mut_season_service, get_mut_service, get_season_service,
+  get_service,
+};
+use crate::controllers::{
+  append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
+  set_pagination_limit, DEFAULT_LANGUAGE,
+};
+use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::{Admin, Editor};
+use crate::extractors::query_pagination::QueryPagination;
+use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
+use crate::openapi::params::path::id::IdParam;
+use crate::openapi::params::path::title::TitleParam;
+use crate::openapi::params::query::count::CountParam;
+use crate::openapi::params::query::page::PageParam;
+use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
+use crate::openapi::responses::not_found::NotFound;
+use crate::openapi::responses::server_error::ServerError;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use domain::entities::show::create_show::CreateShow;
+use domain::entities::show::create_show_episode::CreateShowEpisode;
+use domain::entities::show::create_show_season::CreateShowSeason;
+use multipart::MultiPartRequest;
+use services::show_service::mut_show_service::MutShowService;
+use services::show_service::show_episode_service::mut_show_episode_service::MutShowEpisodeService;
+use services::show_service::show_episode_service::ShowEpisodeService;
+use services::show_service::show_season_service::mut_show_season_service::MutShowSeasonService;
+use services::show_service::show_season_service::ShowSeasonService;
+use services::show_service::ShowService;
+
+pub mod show_doc;
+mod show_implementations;
+
+pub fn routes(app_state: AppState) -> Router {
+  Router::new()
+    .route("/", get(get_items))
+    .route("/", post(create_show))
+    .route("/:id", get(get_by_id))
+    .route("/:id", delete(delete_show))
+    .route("/title/:title", get(get_by_title))
+    .route("/:id/seasons", get(get_seasons))
+    .route("/:id/seasons", post(create_season))
+    .route("/:id/seasons/:season", get(get_season))
+    .route("/:id/seasons/:season", delete(delete_season))
+    .route("/:id/seasons/:season/episodes", get(get_episodes))
+    .route("/:id/seasons/:season/episodes", post(create_episode))
+    .route("/:id/seasons/:season/episodes/:episode", get(get_episode))
+    .route("/:id/seasons/:season/episodes/:episode", delete(delete_episode))
+    .with_state(app_state)
+}
+
+#[utoipa::path(get, path = "",
+  responses(
+    (status = 200, description = "Returned shows", body = ShowsTotal), ServerError, BadRequest),
+  params(AcceptLanguageParam, PageParam, CountParam),
+  tag = "Shows"
+)]
+async fn get_items(
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for shows in {}", language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(language, pagination.into()).await {
+    Ok(shows) => Ok((StatusCode::OK, content_language, Json(shows))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}",
+  responses(
+    (status = 200, description = "Returned show based on the id", body = Show), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, AcceptLanguageParam),
+  tag = "Shows"
+)]
+async fn get_by_id(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for a show with id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_id(id, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/title/{title}",
+  responses(
+    (status = 200, description = "Returned shows based on the title", body = ShowsTotal), ServerError, BadRequest
+  ),
+  params(TitleParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Shows"
+)]
+async fn get_by_title(
+  Path(title): Path<String>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for shows with the title {} in {}", title, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_title(&title, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/seasons",
+  responses(
+    (status = 200, description = "Returned seasons based on the show id", body = ShowSeasonsTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, PageParam, CountParam),
+  tag = "Shows"
+)]
+async fn get_seasons(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_season_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for seasons from a show with the id {} in {}", id, language);
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/seasons/{season}",
+  responses(
+    (status = 200, description = "Returned season based on the show id and season number", body = ShowSeason), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, ("season" = u16, Path,), AcceptLanguageParam),
+  tag = "Shows"
+)]
+async fn get_season(
+  Path((id, season)): Path<(u32, u16)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_season_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for season {season} from a show with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_season(id, season, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/seasons/{season}/episodes",
+  responses(
+    (status = 200, description = "Returned episodes based on the show id and season number", body = ShowEpisodesTotal), ServerError, BadRequest
+  ),
+  params(IdParam, ("season" = u16, Path,), AcceptLanguageParam, PageParam, CountParam),
+  tag = "Shows"
+)]
+async fn get_episodes(
+  Path((id, season)): Path<(u32, u16)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_episode_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for episodes of season {season} from a show with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, season, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/{id}/seasons/{season}/episodes/{episode}",
+  responses(
+    (status = 200, description = "Returned episode based on the show id, season and episode number", body = ShowEpisode), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, ("season" = u16, Path,), ("episode" = u16, Path,), AcceptLanguageParam),
+  tag = "Shows"
+)]
+async fn get_episode(
+  Path((id, season, episode)): Path<(u32, u16, u16)>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_episode_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+
+  println!("Route for episode {episode} of season {season} from a show with the id {id} in {language}");
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get_by_episode(id, season, episode, language).await {
+    Ok(item) => match item {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(item) => Ok((StatusCode::OK, content_language, Json(item))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "",
+  responses(
+    (status = 201, description = "Show successfully created", body = Show), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body(content_type = ["multipart/form-data"], content = CreateShow),
+  params(JsonWebTokenParam),
+  tag = "Shows"
+)]
+async fn create_show(
+  _user: Editor,
+  State(app_state): State<AppState>,
+  MultiPartRequest(create_show): MultiPartRequest<CreateShow>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for creating a show");
+
+    match service.create(create_show).await {
+      Ok(show) => Ok((StatusCode::CREATED, Json(show))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}",
+  responses(
+    (status = 204, description = "Show with its seasons and episodes successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(("id" = u32, Path, description = "Id of the item to delete"), JsonWebTokenParam),
+  tag = "Shows"
+)]
+async fn delete_show(_user: Admin, Path(id): Path<u32>, State(app_state): State<AppState>) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_service(&transaction, client, &app_state.display_path, &app_state.content_path);
+
+    println!("Route for deleting a show");
+
+    match service.delete(&[id]).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/seasons",
+  responses(
+    (status = 201, description = "Season successfully created", body = ShowSeason), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body = CreateShowSeason,
+  params(IdParam, JsonWebTokenParam),
+  tag = "Shows"
+)]
+async fn create_season(
+  _user: Editor,
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Json(create_season): Json<CreateShowSeason>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_season_service(&transaction, client);
+
+    println!("Route for creating a season for the show with the id {id}");
+
+    match service.create(id, create_season).await {
+      Ok(season) => Ok((StatusCode::CREATED, Json(season))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/seasons/{season}",
+  responses(
+    (status = 204, description = "Season with its episodes successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("season" = u16, Path,), JsonWebTokenParam),
+  tag = "Shows"
+)]
+async fn delete_season(
+  _user: Admin,
+  Path((id, season)): Path<(u32, u16)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_season_service(&transaction, client);
+
+    println!("Route for deleting season {season} from the show with the id {id}");
+
+    match service.delete(id, season).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/{id}/seasons/{season}/episodes",
+  responses(
+    (status = 201, description = "Episode successfully created", body = ShowEpisode), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  request_body = CreateShowEpisode,
+  params(IdParam, ("season" = u16, Path,), JsonWebTokenParam),
+  tag = "Shows"
+)]
+async fn create_episode(
+  _user: Editor,
+  Path((id, season)): Path<(u32, u16)>,
+  State(app_state): State<AppState>,
+  Json(create_episode): Json<CreateShowEpisode>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_episode_service(&transaction, client);
+
+    println!("Route for creating an episode in season {season} of the show with the id {id}");
+
+    match service.create(id, season, create_episode).await {
+      Ok(episode) => Ok((StatusCode::CREATED, Json(episode))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/{id}/seasons/{season}/episodes/{episode}",
+  responses(
+    (status = 204, description = "Episode successfully deleted"), ServerError, NotAuthorized, Forbidden, BadRequest
+  ),
+  params(IdParam, ("season" = u16, Path,), ("episode" = u16, Path,), JsonWebTokenParam),
+  tag = "Shows"
+)]
+async fn delete_episode(
+  _user: Admin,
+  Path((id, season, episode)): Path<(u32, u16, u16)>,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_episode_service(&transaction, client);
+
+    println!("Route for deleting episode {episode} of season {season} from the show with the id {id}");
+
+    match service.delete(id, season, episode).await {
+      Ok(()) => Ok(StatusCode::NO_CONTENT),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+#[derive(utoipa::OpenApi)]
+#[openapi(tags((name = "Shows", description = "Endpoints related to shows")),
+  paths(
+    get_items,
+    get_by_id,
+    get_by_title,
+    get_seasons,
+    get_season,
+    get_episodes,
+    get_episode,
+    create_show,
+    delete_show,
+    create_season,
+    delete_season,
+    create_episode,
+    delete_episode
+  )
+)]
+pub(crate) struct ShowDoc;
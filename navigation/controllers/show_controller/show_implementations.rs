@@ -0,0 +1,117 @@
+use crate::controllers::DEFAULT_LANGUAGE;
+use crate::implementations::{
+  get_file_repository, get_franchise_repository, get_image_repository, get_mut_file_repository, get_mut_file_service,
+  get_mut_image_repository, get_mut_image_service, get_mut_show_episode_repository, get_mut_show_episode_service,
+  get_mut_show_repository, get_mut_show_season_repository, get_mut_show_season_service, get_mut_show_service,
+  get_mut_user_average_repository, get_show_episode_repository, get_show_episode_service, get_show_repository,
+  get_show_season_repository, get_show_season_service, get_show_service,
+};
+use application::cached_connection::{CachedClient, CachedTransaction};
+use services::show_service::mut_show_service::MutShowService;
+use services::show_service::show_episode_service::mut_show_episode_service::MutShowEpisodeService;
+use services::show_service::show_episode_service::ShowEpisodeService;
+use services::show_service::show_season_service::mut_show_season_service::MutShowSeasonService;
+use services::show_service::show_season_service::ShowSeasonService;
+use services::show_service::ShowService;
+use std::sync::Arc;
+
+pub fn get_service(connection: &CachedClient) -> impl ShowService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let repository = get_show_repository(connection, DEFAULT_LANGUAGE, image_repository, franchise_repository);
+  get_show_service(Arc::new(repository))
+}
+
+pub fn get_mut_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+  display_path: &'a str,
+  path: &'a str,
+) -> impl MutShowService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let file_repository = Arc::new(get_file_repository());
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository.clone()));
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository.clone(),
+    mut_file_repository,
+    file_repository,
+  ));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    display_path,
+    path,
+  ));
+
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let show_repository = Arc::new(get_show_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository,
+    franchise_repository.clone(),
+  ));
+  let mut_show_season_repository = Arc::new(get_mut_show_season_repository(
+    transaction,
+    Arc::new(get_mut_show_episode_repository(transaction)),
+  ));
+  let mut_show_repository = Arc::new(get_mut_show_repository(
+    transaction,
+    DEFAULT_LANGUAGE,
+    mut_show_season_repository,
+    Arc::new(get_mut_user_average_repository(transaction)),
+    show_repository.clone(),
+  ));
+  get_mut_show_service(
+    DEFAULT_LANGUAGE,
+    show_repository,
+    mut_show_repository,
+    mut_image_service,
+    franchise_repository,
+  )
+}
+
+pub fn get_season_service(connection: &CachedClient) -> impl ShowSeasonService + '_ {
+  let repository = get_show_season_repository(connection, DEFAULT_LANGUAGE);
+  get_show_season_service(Arc::new(repository))
+}
+
+pub fn get_mut_season_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutShowSeasonService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let show_repository = get_show_repository(client, DEFAULT_LANGUAGE, image_repository, franchise_repository);
+  let show_season_repository = get_show_season_repository(client, DEFAULT_LANGUAGE);
+  let mut_show_season_repository =
+    get_mut_show_season_repository(transaction, Arc::new(get_mut_show_episode_repository(transaction)));
+  get_mut_show_season_service(
+    DEFAULT_LANGUAGE,
+    Arc::new(show_repository),
+    Arc::new(show_season_repository),
+    Arc::new(mut_show_season_repository),
+  )
+}
+
+pub fn get_episode_service(connection: &CachedClient) -> impl ShowEpisodeService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let repository = get_show_episode_repository(connection, DEFAULT_LANGUAGE, image_repository);
+  get_show_episode_service(Arc::new(repository))
+}
+
+pub fn get_mut_episode_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutShowEpisodeService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let show_season_repository = get_show_season_repository(client, DEFAULT_LANGUAGE);
+  let show_episode_repository = get_show_episode_repository(client, DEFAULT_LANGUAGE, image_repository);
+  get_mut_show_episode_service(
+    DEFAULT_LANGUAGE,
+    Arc::new(show_season_repository),
+    Arc::new(show_episode_repository),
+    Arc::new(get_mut_show_episode_repository(transaction)),
+  )
+}
@@ -1,33 +1,80 @@
 use std::sync::Arc;
 
+use application::cached_connection::{CachedClient, CachedTransaction};
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
-use axum::routing::get;
+use axum::routing::{delete, get, patch, post, put};
 use axum::{Json, Router};
-use tokio_postgres::Client;
+use multipart::MultiPartRequest;
 
+use domain::entities::user::create_user_book::CreateUserBook;
+use domain::entities::user::patch_user::PatchUser;
+use domain::entities::user::patch_user_book::PatchUserBook;
+use domain::entities::user::User;
+use domain::enums::token_scope::TokenScope;
+use services::user_service::friendship_service::mut_friendship_service::MutFriendshipService;
+use services::user_service::friendship_service::FriendshipService;
+use services::user_service::mut_user_average_service::MutUserAverageService;
+use services::user_service::mut_user_service::MutUserService;
+use services::user_service::user_book_service::mut_user_book_service::MutUserBookService;
+use services::user_service::user_book_service::UserBookService;
 use services::user_service::UserService;
 
 use crate::app_state::AppState;
-use crate::controllers::{convert_error, convert_service_error, set_pagination_limit};
+use crate::controllers::user_controller::user_implementations::{
+  get_book_service, get_friends_service, get_mut_average_service, get_mut_book_service, get_mut_friends_service,
+};
+use crate::controllers::{
+  append_content_language_header, content_language_header, convert_error, convert_service_error, get_language,
+  remove_files, set_pagination_limit, DEFAULT_LANGUAGE,
+};
+use crate::extractors::authenticated_user::AuthenticatedUser;
+use crate::extractors::headers::accept_language::AcceptLanguageHeader;
+use crate::extractors::permission::Admin;
 use crate::extractors::query_pagination::QueryPagination;
-use crate::implementations::{get_image_repository, get_user_repository, get_user_service};
+use crate::extractors::query_user_status::QueryUserStatus;
+use crate::implementations::{
+  get_file_repository, get_image_repository, get_mut_file_repository, get_mut_file_service, get_mut_image_repository,
+  get_mut_image_service, get_mut_user_repository, get_mut_user_service, get_user_repository, get_user_service,
+};
+use crate::openapi::params::header::accept_language::AcceptLanguageParam;
+use crate::openapi::params::header::json_web_token::JsonWebTokenParam;
 use crate::openapi::params::path::id::IdParam;
 use crate::openapi::params::path::name::NameParam;
 use crate::openapi::params::query::count::CountParam;
 use crate::openapi::params::query::page::PageParam;
+use crate::openapi::params::query::user_status::UserStatusParam;
 use crate::openapi::responses::bad_request::BadRequest;
+use crate::openapi::responses::forbidden::Forbidden;
+use crate::openapi::responses::not_authorized::NotAuthorized;
 use crate::openapi::responses::not_found::NotFound;
 use crate::openapi::responses::server_error::ServerError;
 
 pub mod user_doc;
+mod user_implementations;
 
 pub fn routes(app_state: AppState) -> Router {
   Router::new()
     .route("/", get(get_items))
     .route("/:id", get(get_by_id))
     .route("/name/:name", get(get_by_name))
+    .route("/:id/books", get(get_books))
+    .route("/me", patch(patch_me))
+    .route("/me/profile-picture", delete(delete_profile_picture))
+    .route(
+      "/me/books/:book_id",
+      put(save_book).patch(patch_book).delete(remove_book),
+    )
+    .route("/:id/friends", get(get_friends))
+    .route("/averages/recompute", post(recompute_averages))
+    .route("/me/friends", get(get_my_friends))
+    .route("/me/friends/incoming", get(get_incoming_requests))
+    .route("/me/friends/outgoing", get(get_outgoing_requests))
+    .route("/me/friends/:user_id", post(send_request))
+    .route("/me/friends/:user_id", delete(remove_friend))
+    .route("/me/friends/:user_id/accept", post(accept_request))
+    .route("/me/friends/:user_id/decline", post(decline_request))
     .with_state(app_state)
 }
 
@@ -101,8 +148,496 @@ async fn get_by_name(
   }
 }
 
-fn get_service(connection: &Client) -> impl UserService + '_ {
+#[utoipa::path(patch, path = "/me",
+  responses(
+    (status = 200, description = "Profile successfully updated, a new profile picture replaces the old one", body = User), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  request_body(content_type = ["multipart/form-data"], content = PatchUser),
+  tag = "Users"
+)]
+async fn patch_me(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  MultiPartRequest(item): MultiPartRequest<PatchUser>,
+) -> Result<(StatusCode, Json<User>), (StatusCode, String)> {
+  user.require_session()?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_service(&transaction, &app_state);
+
+    println!("Route for updating the profile of a user with the id {user_id}");
+
+    service.update(user_id, item).await.map_err(convert_service_error)
+  };
+  transaction.commit().await.map_err(convert_error)?;
+
+  let (item, files) = result?;
+  remove_files(files).await?;
+  Ok((StatusCode::OK, Json(item)))
+}
+
+#[utoipa::path(delete, path = "/me/profile-picture",
+  responses(
+    (status = 200, description = "Profile picture successfully deleted", body = User), ServerError, BadRequest, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn delete_profile_picture(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> Result<(StatusCode, Json<User>), (StatusCode, String)> {
+  user.require_session()?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_service(&transaction, &app_state);
+
+    println!("Route for deleting the profile picture of a user with the id {user_id}");
+
+    service
+      .delete_profile_picture(user_id)
+      .await
+      .map_err(convert_service_error)
+  };
+  transaction.commit().await.map_err(convert_error)?;
+
+  let (item, files) = result?;
+  remove_files(files).await?;
+  Ok((StatusCode::OK, Json(item)))
+}
+
+#[utoipa::path(get, path = "/{id}/books",
+  responses(
+    (status = 200, description = "Returned books from the library of the user", body = UserBooksTotal), ServerError, BadRequest
+  ),
+  params(IdParam, AcceptLanguageParam, UserStatusParam, PageParam, CountParam),
+  tag = "Users"
+)]
+async fn get_books(
+  Path(id): Path<u32>,
+  AcceptLanguageHeader(languages): AcceptLanguageHeader,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+  Query(status): Query<QueryUserStatus>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_book_service(&connection);
+
+  let language = get_language(languages, DEFAULT_LANGUAGE);
+  set_pagination_limit(&mut pagination);
+
+  println!(
+    "Route for books from the library of a user with the id {} in {}",
+    id, language
+  );
+
+  let mut content_language = content_language_header(language);
+  append_content_language_header(&mut content_language, DEFAULT_LANGUAGE);
+
+  match service.get(id, status.status, language, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, content_language, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(put, path = "/me/books/{book_id}",
+  responses(
+    (status = 200, description = "Book successfully saved in the library", body = UserBook), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("book_id" = u32, Path,), JsonWebTokenParam),
+  request_body = CreateUserBook,
+  tag = "Users"
+)]
+async fn save_book(
+  Path(book_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(item): Json<CreateUserBook>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::LibraryWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_book_service(&transaction, client);
+
+    println!("Route for saving a book with the id {book_id} in the library of a user with the id {user_id}");
+
+    match service.save(user_id, book_id, item).await {
+      Ok(item) => Ok((StatusCode::OK, Json(item))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(patch, path = "/me/books/{book_id}",
+  responses(
+    (status = 200, description = "Library entry successfully updated", body = UserBook), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("book_id" = u32, Path,), JsonWebTokenParam),
+  request_body = PatchUserBook,
+  tag = "Users"
+)]
+async fn patch_book(
+  Path(book_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Json(item): Json<PatchUserBook>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::LibraryWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_book_service(&transaction, client);
+
+    println!("Route for updating a book with the id {book_id} in the library of a user with the id {user_id}");
+
+    match service.patch(user_id, book_id, item).await {
+      Ok(item) => Ok((StatusCode::OK, Json(item))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/me/books/{book_id}",
+  responses(
+    (status = 200, description = "Book successfully removed from the library"), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("book_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn remove_book(
+  Path(book_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::LibraryWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_book_service(&transaction, client);
+
+    println!("Route for removing a book with the id {book_id} from the library of a user with the id {user_id}");
+
+    match service.delete(user_id, book_id).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(get, path = "/{id}/friends",
+  responses(
+    (status = 200, description = "Returned friends of the user", body = FriendshipsTotal), ServerError, BadRequest, NotFound
+  ),
+  params(IdParam, PageParam, CountParam),
+  tag = "Users"
+)]
+async fn get_friends(
+  Path(id): Path<u32>,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_friends_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for friends of a user with the id {}", id);
+
+  match service.get_friends(id, pagination.into()).await {
+    Ok(items) => match items {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(items) => Ok((StatusCode::OK, Json(items))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/me/friends",
+  responses(
+    (status = 200, description = "Returned friends of the logged in user", body = FriendshipsTotal), ServerError, BadRequest, NotAuthorized
+  ),
+  params(JsonWebTokenParam, PageParam, CountParam),
+  tag = "Users"
+)]
+async fn get_my_friends(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsRead)?;
+  let user_id = user.id;
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_friends_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for friends of a user with the id {}", user_id);
+
+  match service.get_friends(user_id, pagination.into()).await {
+    Ok(items) => match items {
+      None => Err((StatusCode::NOT_FOUND, "".to_string())),
+      Some(items) => Ok((StatusCode::OK, Json(items))),
+    },
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/me/friends/incoming",
+  responses(
+    (status = 200, description = "Returned pending friend requests sent to the logged in user", body = FriendshipsTotal), ServerError, BadRequest, NotAuthorized
+  ),
+  params(JsonWebTokenParam, PageParam, CountParam),
+  tag = "Users"
+)]
+async fn get_incoming_requests(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsRead)?;
+  let user_id = user.id;
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_friends_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for incoming friend requests of a user with the id {}", user_id);
+
+  match service.get_incoming(user_id, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(get, path = "/me/friends/outgoing",
+  responses(
+    (status = 200, description = "Returned pending friend requests sent by the logged in user", body = FriendshipsTotal), ServerError, BadRequest, NotAuthorized
+  ),
+  params(JsonWebTokenParam, PageParam, CountParam),
+  tag = "Users"
+)]
+async fn get_outgoing_requests(
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+  Query(mut pagination): Query<QueryPagination>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsRead)?;
+  let user_id = user.id;
+  let connection = app_state.pool.get().await.map_err(convert_error)?;
+  let service = get_friends_service(&connection);
+
+  set_pagination_limit(&mut pagination);
+
+  println!("Route for outgoing friend requests of a user with the id {}", user_id);
+
+  match service.get_outgoing(user_id, pagination.into()).await {
+    Ok(items) => Ok((StatusCode::OK, Json(items))),
+    Err(error) => Err(convert_service_error(error)),
+  }
+}
+
+#[utoipa::path(post, path = "/me/friends/{user_id}",
+  responses(
+    (status = 201, description = "Friend request successfully sent", body = Friendship), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("user_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn send_request(
+  Path(other_user_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_friends_service(&transaction, client);
+
+    println!(
+      "Route for sending a friend request from a user with the id {user_id} to a user with the id {other_user_id}"
+    );
+
+    match service.send(user_id, other_user_id).await {
+      Ok(item) => Ok((StatusCode::CREATED, Json(item))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/me/friends/{user_id}/accept",
+  responses(
+    (status = 200, description = "Friend request successfully accepted", body = Friendship), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("user_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn accept_request(
+  Path(other_user_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_friends_service(&transaction, client);
+
+    println!(
+      "Route for accepting a friend request from a user with the id {other_user_id} for a user with the id {user_id}"
+    );
+
+    match service.accept(user_id, other_user_id).await {
+      Ok(item) => Ok((StatusCode::OK, Json(item))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/me/friends/{user_id}/decline",
+  responses(
+    (status = 200, description = "Friend request successfully declined"), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("user_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn decline_request(
+  Path(other_user_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_friends_service(&transaction, client);
+
+    println!(
+      "Route for declining a friend request from a user with the id {other_user_id} for a user with the id {user_id}"
+    );
+
+    match service.decline(user_id, other_user_id).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(delete, path = "/me/friends/{user_id}",
+  responses(
+    (status = 200, description = "Friendship or friend request successfully removed"), ServerError, BadRequest, NotAuthorized
+  ),
+  params(("user_id" = u32, Path,), JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn remove_friend(
+  Path(other_user_id): Path<u32>,
+  user: AuthenticatedUser,
+  State(app_state): State<AppState>,
+) -> impl IntoResponse {
+  user.require_scope(TokenScope::FriendsWrite)?;
+  let user_id = user.id;
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let client = transaction.client();
+    let service = get_mut_friends_service(&transaction, client);
+
+    println!(
+      "Route for removing a friendship between a user with the id {user_id} and a user with the id {other_user_id}"
+    );
+
+    match service.remove(user_id, other_user_id).await {
+      Ok(()) => Ok(StatusCode::OK),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+#[utoipa::path(post, path = "/averages/recompute",
+  responses(
+    (status = 200, description = "Recomputed the averages of all users, returns the amount of users", body = usize), ServerError, NotAuthorized, Forbidden
+  ),
+  params(JsonWebTokenParam),
+  tag = "Users"
+)]
+async fn recompute_averages(_user: Admin, State(app_state): State<AppState>) -> impl IntoResponse {
+  let mut connection = app_state.pool.get().await.map_err(convert_error)?;
+  let transaction = connection.transaction().await.map_err(convert_error)?;
+  let result = {
+    let service = get_mut_average_service(&transaction);
+
+    println!("Route for recomputing the averages of all users");
+
+    match service.recompute_all().await {
+      Ok(amount) => Ok((StatusCode::OK, Json(amount))),
+      Err(error) => Err(convert_service_error(error)),
+    }
+  };
+  transaction.commit().await.map_err(convert_error)?;
+  result
+}
+
+fn get_service(connection: &CachedClient) -> impl UserService + '_ {
   let image_repository = Arc::new(get_image_repository(connection));
   let repository = get_user_repository(connection, image_repository);
   get_user_service(Arc::new(repository))
 }
+
+fn get_mut_service<'a>(transaction: &'a CachedTransaction<'a>, app_state: &'a AppState) -> impl MutUserService + 'a {
+  let image_repository = Arc::new(get_image_repository(transaction.client()));
+  let user_repository = Arc::new(get_user_repository(transaction.client(), image_repository.clone()));
+  let mut_user_repository = Arc::new(get_mut_user_repository(
+    transaction,
+    user_repository.clone(),
+    image_repository.clone(),
+  ));
+  let mut_file_repository = Arc::new(get_mut_file_repository());
+  let file_repository = Arc::new(get_file_repository());
+  let mut_image_repository = Arc::new(get_mut_image_repository(
+    transaction,
+    image_repository,
+    mut_file_repository.clone(),
+    file_repository,
+  ));
+  let mut_file_service = Arc::new(get_mut_file_service(mut_file_repository));
+  let mut_image_service = Arc::new(get_mut_image_service(
+    mut_image_repository,
+    mut_file_service,
+    &app_state.display_path,
+    &app_state.content_path,
+  ));
+  get_mut_user_service(user_repository, mut_user_repository, mut_image_service)
+}
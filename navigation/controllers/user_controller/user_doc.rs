@@ -2,6 +2,8 @@ use super::*;
 
 #[derive(utoipa::OpenApi)]
 #[openapi(tags((name = "Users", description = "Endpoints related to users")),
-paths(get_items, get_by_id,get_by_name)
+paths(get_items, get_by_id,get_by_name, patch_me, delete_profile_picture, get_books, save_book, patch_book, remove_book, get_friends, get_my_friends,
+  get_incoming_requests, get_outgoing_requests, send_request, accept_request, decline_request, remove_friend,
+  recompute_averages)
 )]
 pub(crate) struct UserDoc;
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use application::cached_connection::{CachedClient, CachedTransaction};
+
+use services::user_service::friendship_service::mut_friendship_service::MutFriendshipService;
+use services::user_service::friendship_service::FriendshipService;
+use services::user_service::mut_user_average_service::MutUserAverageService;
+use services::user_service::user_book_service::mut_user_book_service::MutUserBookService;
+use services::user_service::user_book_service::UserBookService;
+
+use crate::controllers::DEFAULT_LANGUAGE;
+use crate::implementations::{
+  get_book_edition_repository, get_book_repository, get_franchise_repository, get_friendship_repository,
+  get_friendship_service, get_image_repository, get_mut_friendship_repository, get_mut_friendship_service,
+  get_mut_user_average_repository, get_mut_user_average_service, get_mut_user_book_repository,
+  get_mut_user_book_service, get_user_book_repository, get_user_book_service, get_user_repository,
+};
+
+pub fn get_book_service(connection: &CachedClient) -> impl UserBookService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let franchise_repository = Arc::new(get_franchise_repository(connection, DEFAULT_LANGUAGE));
+  let book_repository = Arc::new(get_book_repository(
+    connection,
+    DEFAULT_LANGUAGE,
+    image_repository,
+    franchise_repository,
+  ));
+  let repository = get_user_book_repository(connection, book_repository);
+  get_user_book_service(Arc::new(repository))
+}
+
+pub fn get_mut_book_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutUserBookService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let franchise_repository = Arc::new(get_franchise_repository(client, DEFAULT_LANGUAGE));
+  let book_repository = Arc::new(get_book_repository(
+    client,
+    DEFAULT_LANGUAGE,
+    image_repository.clone(),
+    franchise_repository,
+  ));
+  let book_edition_repository = Arc::new(get_book_edition_repository(client, DEFAULT_LANGUAGE, image_repository));
+  let user_book_repository = Arc::new(get_user_book_repository(client, book_repository.clone()));
+  let mut_user_average_repository = Arc::new(get_mut_user_average_repository(transaction));
+  let mut_user_book_repository = Arc::new(get_mut_user_book_repository(transaction, mut_user_average_repository));
+  get_mut_user_book_service(
+    DEFAULT_LANGUAGE,
+    book_repository,
+    book_edition_repository,
+    user_book_repository,
+    mut_user_book_repository,
+  )
+}
+
+pub fn get_friends_service(connection: &CachedClient) -> impl FriendshipService + '_ {
+  let image_repository = Arc::new(get_image_repository(connection));
+  let user_repository = Arc::new(get_user_repository(connection, image_repository));
+  let repository = get_friendship_repository(connection, user_repository.clone());
+  get_friendship_service(user_repository, Arc::new(repository))
+}
+
+pub fn get_mut_friends_service<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  client: &'a CachedClient,
+) -> impl MutFriendshipService + 'a {
+  let image_repository = Arc::new(get_image_repository(client));
+  let user_repository = Arc::new(get_user_repository(client, image_repository));
+  let friendship_repository = Arc::new(get_friendship_repository(client, user_repository.clone()));
+  let repository = get_mut_friendship_repository(transaction);
+  get_mut_friendship_service(user_repository, friendship_repository, Arc::new(repository))
+}
+
+pub fn get_mut_average_service<'a>(transaction: &'a CachedTransaction<'a>) -> impl MutUserAverageService + 'a {
+  let repository = get_mut_user_average_repository(transaction);
+  get_mut_user_average_service(Arc::new(repository))
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use jsonwebtoken::{DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::app_state::AppState;
+use crate::controllers::{convert_error, convert_service_error};
+use crate::extractors::headers::authorization::{JWTAuthorization, JWTError};
+use crate::implementations::{
+  get_account_repository, get_image_repository, get_mut_personal_access_token_repository,
+  get_mut_personal_access_token_service, get_personal_access_token_repository, get_user_repository,
+};
+use domain::enums::account_role::AccountRole;
+use domain::enums::token_scope::TokenScope;
+use services::account_service::personal_access_token_service::mut_personal_access_token_service::{
+  MutPersonalAccessTokenService, PERSONAL_ACCESS_TOKEN_PREFIX,
+};
+use services::traits::service_error::ServiceError;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claim {
+  pub user_id: u32,
+  #[serde(default)]
+  pub role: AccountRole,
+  #[serde(default)]
+  pub verified: bool,
+  pub sub: String,
+  pub exp: usize,
+  pub iat: usize,
+  pub iss: String,
+}
+
+/// The user of a valid, unexpired JWT signed with the secret of the server or of a personal access token.
+#[derive(Debug)]
+pub struct AuthenticatedUser {
+  pub id: u32,
+  pub role: AccountRole,
+  pub verified: bool,
+  /// Scopes of the personal access token, None for a JWT
+  pub scopes: Option<Vec<TokenScope>>,
+}
+
+impl AuthenticatedUser {
+  /// Personal access tokens need the scope, JWTs are allowed everything
+  pub fn require_scope(&self, scope: TokenScope) -> Result<(), (StatusCode, String)> {
+    match &self.scopes {
+      Some(scopes) if !scopes.contains(&scope) => Err((
+        StatusCode::FORBIDDEN,
+        format!("Personal access token is missing the scope {scope}"),
+      )),
+      _ => Ok(()),
+    }
+  }
+
+  /// Only allows JWTs, personal access tokens can not manage the account
+  pub fn require_session(&self) -> Result<(), (StatusCode, String)> {
+    match self.scopes {
+      Some(_) => Err((
+        StatusCode::FORBIDDEN,
+        "Requires a login instead of a personal access token".to_string(),
+      )),
+      None => Ok(()),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum AuthenticationError {
+  Authorization(JWTError),
+  InvalidJWT,
+  InvalidPersonalAccessToken,
+  ServerError,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthenticatedUser
+where
+  AppState: FromRef<S>,
+  S: Send + Sync,
+{
+  type Rejection = AuthenticationError;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    let auth = JWTAuthorization::from_request_parts(parts, state)
+      .await
+      .map_err(AuthenticationError::Authorization)?;
+    let app_state = AppState::from_ref(state);
+    if auth.token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX) {
+      return authenticate_personal_access_token(&app_state, &auth.token).await;
+    }
+
+    authenticate_jwt(&auth.token, &app_state.secret)
+  }
+}
+
+/// Only accepts access tokens, second factor tokens are signed with the same secret but have another issuer
+fn authenticate_jwt(token: &str, secret: &str) -> Result<AuthenticatedUser, AuthenticationError> {
+  let mut validation = Validation::default();
+  validation.set_issuer(&["MyCollection"]);
+  let claim = jsonwebtoken::decode::<Claim>(token, &DecodingKey::from_secret(secret.as_bytes()), &validation)
+    .map_err(|_| AuthenticationError::InvalidJWT)?;
+
+  Ok(AuthenticatedUser {
+    id: claim.claims.user_id,
+    role: claim.claims.role,
+    verified: claim.claims.verified,
+    scopes: None,
+  })
+}
+
+async fn authenticate_personal_access_token(
+  app_state: &AppState,
+  token: &str,
+) -> Result<AuthenticatedUser, AuthenticationError> {
+  // the error is already logged by the conversion
+  let server_error = |_: (StatusCode, String)| AuthenticationError::ServerError;
+  let mut connection = app_state
+    .pool
+    .get()
+    .await
+    .map_err(convert_error)
+    .map_err(server_error)?;
+  let transaction = connection
+    .transaction()
+    .await
+    .map_err(convert_error)
+    .map_err(server_error)?;
+  let result = {
+    let client = transaction.client();
+    let image_repository = Arc::new(get_image_repository(client));
+    let user_repository = Arc::new(get_user_repository(client, image_repository));
+    let account_repository = Arc::new(get_account_repository(client, user_repository));
+    let personal_access_token_repository = Arc::new(get_personal_access_token_repository(client));
+    let mut_personal_access_token_repository = Arc::new(get_mut_personal_access_token_repository(&transaction));
+    let service = get_mut_personal_access_token_service(
+      account_repository,
+      personal_access_token_repository,
+      mut_personal_access_token_repository,
+    );
+
+    match service.authenticate(token).await {
+      Ok((token, account)) => Ok(AuthenticatedUser {
+        id: account.user.id,
+        role: account.role,
+        verified: account.verified,
+        scopes: Some(token.scopes),
+      }),
+      Err(ServiceError::ClientError(_)) => Err(AuthenticationError::InvalidPersonalAccessToken),
+      Err(error) => Err(server_error(convert_service_error(error))),
+    }
+  };
+  transaction
+    .commit()
+    .await
+    .map_err(convert_error)
+    .map_err(server_error)?;
+  result
+}
+
+impl IntoResponse for AuthenticationError {
+  fn into_response(self) -> Response {
+    match self {
+      AuthenticationError::Authorization(error) => error.into_response(),
+      AuthenticationError::InvalidJWT => (StatusCode::UNAUTHORIZED, "Invalid or expired JWT").into_response(),
+      AuthenticationError::InvalidPersonalAccessToken => {
+        (StatusCode::UNAUTHORIZED, "Invalid personal access token").into_response()
+      }
+      AuthenticationError::ServerError => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use axum::http::StatusCode;
+  use axum::response::IntoResponse;
+  use chrono::Utc;
+  use jsonwebtoken::{EncodingKey, Header};
+  use serde::Serialize;
+
+  use domain::enums::account_role::AccountRole;
+  use domain::enums::token_scope::TokenScope;
+
+  use super::{authenticate_jwt, AuthenticatedUser, AuthenticationError, Claim};
+
+  const SECRET: &str = "secret";
+
+  #[derive(Serialize)]
+  struct SecondFactorClaim {
+    user_id: u32,
+    sub: String,
+    exp: usize,
+    iat: usize,
+    iss: String,
+  }
+
+  fn claim(expires_in: i64) -> Claim {
+    let now = Utc::now().timestamp();
+    Claim {
+      user_id: 1,
+      role: AccountRole::Editor,
+      verified: true,
+      sub: "User".to_string(),
+      exp: (now + expires_in) as usize,
+      iat: now as usize,
+      iss: "MyCollection".to_string(),
+    }
+  }
+
+  fn encode(claim: &impl Serialize, secret: &str) -> String {
+    jsonwebtoken::encode(&Header::default(), claim, &EncodingKey::from_secret(secret.as_bytes())).unwrap()
+  }
+
+  fn status(result: Result<AuthenticatedUser, AuthenticationError>) -> StatusCode {
+    result.unwrap_err().into_response().status()
+  }
+
+  #[test]
+  fn valid_token_is_accepted() {
+    let user = authenticate_jwt(&encode(&claim(60), SECRET), SECRET).unwrap();
+    assert_eq!(user.id, 1);
+    assert_eq!(user.role, AccountRole::Editor);
+    assert!(user.verified);
+    assert!(user.scopes.is_none());
+  }
+
+  #[test]
+  fn expired_token_is_unauthorized() {
+    // more than the default leeway of 60 seconds in the past
+    let token = encode(&claim(-120), SECRET);
+    assert_eq!(status(authenticate_jwt(&token, SECRET)), StatusCode::UNAUTHORIZED);
+  }
+
+  #[test]
+  fn forged_signature_is_unauthorized() {
+    let token = encode(&claim(60), "other secret");
+    assert_eq!(status(authenticate_jwt(&token, SECRET)), StatusCode::UNAUTHORIZED);
+  }
+
+  #[test]
+  fn second_factor_token_is_unauthorized() {
+    let now = Utc::now().timestamp();
+    let second_factor_claim = SecondFactorClaim {
+      user_id: 1,
+      sub: "SecondFactor".to_string(),
+      exp: (now + 60) as usize,
+      iat: now as usize,
+      iss: "MyCollection/SecondFactor".to_string(),
+    };
+    let token = encode(&second_factor_claim, SECRET);
+    assert_eq!(status(authenticate_jwt(&token, SECRET)), StatusCode::UNAUTHORIZED);
+  }
+
+  #[test]
+  fn personal_access_token_without_scope_is_forbidden() {
+    let user = AuthenticatedUser {
+      id: 1,
+      role: AccountRole::Admin,
+      verified: true,
+      scopes: Some(vec![TokenScope::LibraryWrite]),
+    };
+    assert!(user.require_scope(TokenScope::LibraryWrite).is_ok());
+    assert_eq!(
+      user.require_scope(TokenScope::CatalogueWrite).unwrap_err().0,
+      StatusCode::FORBIDDEN
+    );
+    assert_eq!(user.require_session().unwrap_err().0, StatusCode::FORBIDDEN);
+  }
+
+  #[test]
+  fn jwt_is_not_limited_by_scopes() {
+    let user = authenticate_jwt(&encode(&claim(60), SECRET), SECRET).unwrap();
+    assert!(user.require_scope(TokenScope::Admin).is_ok());
+    assert!(user.require_session().is_ok());
+  }
+}
@@ -2,7 +2,10 @@ use std::str::FromStr;
 
 use axum::async_trait;
 use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
 use axum::http::request::Parts;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 
 #[derive(Debug)]
@@ -19,10 +22,14 @@ pub struct JWTAuthorization {
 impl FromStr for JWTAuthorization {
   type Err = JWTError;
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let mut split_value = s.trim().split("bearer: ");
-    let (_, token) = (split_value.next(), split_value.next());
-    let token = token.ok_or(JWTError::MissingJWT)?.to_string();
-    Ok(JWTAuthorization { token })
+    let (scheme, token) = s.trim().split_once(' ').ok_or(JWTError::MissingJWT)?;
+    let token = token.trim();
+    if !scheme.eq_ignore_ascii_case("bearer") || token.is_empty() {
+      return Err(JWTError::MissingJWT);
+    }
+    Ok(JWTAuthorization {
+      token: token.to_string(),
+    })
   }
 }
 
@@ -34,12 +41,17 @@ where
   type Rejection = JWTError;
 
   async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-    let x = parts
-      .headers
-      .get("authorization")
+    JWTAuthorization::from_headers(&parts.headers)
+  }
+}
+
+impl JWTAuthorization {
+  fn from_headers(headers: &HeaderMap) -> Result<Self, JWTError> {
+    let x = headers
+      .get(AUTHORIZATION)
       .ok_or(JWTError::AuthorizationMissing)?
       .to_str()
-      .unwrap();
+      .map_err(|_| JWTError::MissingJWT)?;
 
     JWTAuthorization::from_str(x)
   }
@@ -49,8 +61,57 @@ impl IntoResponse for JWTError {
   fn into_response(self) -> Response {
     let message = match self {
       JWTError::AuthorizationMissing => "Authorization header missing",
-      JWTError::MissingJWT => "Invalid bearer prefix or jwt missing",
+      JWTError::MissingJWT => "Invalid bearer scheme or jwt missing",
     };
-    Response::new(message.into())
+    (StatusCode::UNAUTHORIZED, message).into_response()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use axum::http::header::AUTHORIZATION;
+  use axum::http::{HeaderMap, HeaderValue, StatusCode};
+  use axum::response::IntoResponse;
+
+  use super::JWTAuthorization;
+
+  fn status(value: Option<HeaderValue>) -> StatusCode {
+    let mut headers = HeaderMap::new();
+    if let Some(value) = value {
+      headers.insert(AUTHORIZATION, value);
+    }
+    JWTAuthorization::from_headers(&headers)
+      .unwrap_err()
+      .into_response()
+      .status()
+  }
+
+  #[test]
+  fn bearer_token_is_extracted() {
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("bearer  abc "));
+    assert_eq!(JWTAuthorization::from_headers(&headers).unwrap().token, "abc");
+  }
+
+  #[test]
+  fn missing_header_is_unauthorized() {
+    assert_eq!(status(None), StatusCode::UNAUTHORIZED);
+  }
+
+  #[test]
+  fn non_bearer_scheme_is_unauthorized() {
+    assert_eq!(
+      status(Some(HeaderValue::from_static("Basic dXNlcjpwYXNz"))),
+      StatusCode::UNAUTHORIZED
+    );
+  }
+
+  #[test]
+  fn malformed_header_is_unauthorized() {
+    for value in ["Bearer", "Bearer ", "abc", ""] {
+      assert_eq!(status(Some(HeaderValue::from_static(value))), StatusCode::UNAUTHORIZED);
+    }
+    let non_ascii = HeaderValue::from_bytes("Bearer ä".as_bytes()).unwrap();
+    assert_eq!(status(Some(non_ascii)), StatusCode::UNAUTHORIZED);
   }
 }
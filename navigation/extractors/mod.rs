@@ -1,2 +1,5 @@
+pub mod authenticated_user;
 pub mod headers;
+pub mod permission;
 pub mod query_pagination;
+pub mod query_user_status;
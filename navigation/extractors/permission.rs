@@ -0,0 +1,98 @@
+use axum::async_trait;
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::app_state::AppState;
+use crate::extractors::authenticated_user::{AuthenticatedUser, AuthenticationError};
+use domain::enums::account_role::AccountRole;
+use domain::enums::token_scope::TokenScope;
+
+/// Only lets through verified users that are allowed to create and modify catalogue entities,
+/// personal access tokens additionally need the scope catalogue:write.
+#[derive(Debug)]
+pub struct Editor;
+
+/// Only lets through verified users that are allowed to delete catalogue entities and manage accounts,
+/// personal access tokens additionally need the scope admin.
+#[derive(Debug)]
+pub struct Admin;
+
+#[derive(Debug)]
+pub enum PermissionError {
+  Authentication(AuthenticationError),
+  MissingRole(AccountRole),
+  UnverifiedEmail,
+  MissingScope(TokenScope),
+}
+
+async fn require_role<S>(
+  parts: &mut Parts,
+  state: &S,
+  role: AccountRole,
+  scope: TokenScope,
+) -> Result<(), PermissionError>
+where
+  AppState: FromRef<S>,
+  S: Send + Sync,
+{
+  let user = AuthenticatedUser::from_request_parts(parts, state)
+    .await
+    .map_err(PermissionError::Authentication)?;
+  if user.role < role {
+    return Err(PermissionError::MissingRole(role));
+  }
+  if user.scopes.as_ref().is_some_and(|x| !x.contains(&scope)) {
+    return Err(PermissionError::MissingScope(scope));
+  }
+  match user.verified {
+    true => Ok(()),
+    false => Err(PermissionError::UnverifiedEmail),
+  }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Editor
+where
+  AppState: FromRef<S>,
+  S: Send + Sync,
+{
+  type Rejection = PermissionError;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    require_role(parts, state, AccountRole::Editor, TokenScope::CatalogueWrite).await?;
+    Ok(Editor)
+  }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Admin
+where
+  AppState: FromRef<S>,
+  S: Send + Sync,
+{
+  type Rejection = PermissionError;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    require_role(parts, state, AccountRole::Admin, TokenScope::Admin).await?;
+    Ok(Admin)
+  }
+}
+
+impl IntoResponse for PermissionError {
+  fn into_response(self) -> Response {
+    match self {
+      PermissionError::Authentication(error) => error.into_response(),
+      PermissionError::MissingRole(role) => {
+        (StatusCode::FORBIDDEN, format!("Requires the role {role} or higher")).into_response()
+      }
+      PermissionError::UnverifiedEmail => (StatusCode::FORBIDDEN, "Email is not verified").into_response(),
+      PermissionError::MissingScope(scope) => (
+        StatusCode::FORBIDDEN,
+        format!("Personal access token is missing the scope {scope}"),
+      )
+        .into_response(),
+    }
+  }
+}
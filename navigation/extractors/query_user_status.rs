@@ -0,0 +1,8 @@
+use domain::enums::user_status::UserStatus;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct QueryUserStatus {
+  #[serde(default)]
+  pub status: Option<UserStatus>,
+}
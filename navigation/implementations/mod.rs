@@ -1,11 +1,93 @@
+use application::cached_connection::{CachedClient, CachedTransaction};
+use application::repositories::graphic_novel_repository::graphic_novel_character_repository::default_graphic_novel_character_repository::DefaultGraphicNovelCharacterRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_character_repository::default_mut_graphic_novel_character_repository::DefaultMutGraphicNovelCharacterRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_genre_repository::default_graphic_novel_genre_repository::DefaultGraphicNovelGenreRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_genre_repository::default_mut_graphic_novel_genre_repository::DefaultMutGraphicNovelGenreRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_involved_repository::default_graphic_novel_involved_repository::DefaultGraphicNovelInvolvedRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_involved_repository::default_mut_graphic_novel_involved_repository::DefaultMutGraphicNovelInvolvedRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_publisher_repository::default_graphic_novel_publisher_repository::DefaultGraphicNovelPublisherRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_publisher_repository::default_mut_graphic_novel_publisher_repository::DefaultMutGraphicNovelPublisherRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_theme_repository::default_graphic_novel_theme_repository::DefaultGraphicNovelThemeRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_theme_repository::default_mut_graphic_novel_theme_repository::DefaultMutGraphicNovelThemeRepository;
+use infrastructure::services::graphic_novel_service::graphic_novel_character_service::default_graphic_novel_character_service::DefaultGraphicNovelCharacterService;
+use infrastructure::services::graphic_novel_service::graphic_novel_character_service::default_mut_graphic_novel_character_service::DefaultMutGraphicNovelCharacterService;
+use infrastructure::services::graphic_novel_service::graphic_novel_genre_service::default_graphic_novel_genre_service::DefaultGraphicNovelGenreService;
+use infrastructure::services::graphic_novel_service::graphic_novel_genre_service::default_mut_graphic_novel_genre_service::DefaultMutGraphicNovelGenreService;
+use infrastructure::services::graphic_novel_service::graphic_novel_involved_service::default_graphic_novel_involved_service::DefaultGraphicNovelInvolvedService;
+use infrastructure::services::graphic_novel_service::graphic_novel_involved_service::default_mut_graphic_novel_involved_service::DefaultMutGraphicNovelInvolvedService;
+use infrastructure::services::graphic_novel_service::graphic_novel_publisher_service::default_graphic_novel_publisher_service::DefaultGraphicNovelPublisherService;
+use infrastructure::services::graphic_novel_service::graphic_novel_publisher_service::default_mut_graphic_novel_publisher_service::DefaultMutGraphicNovelPublisherService;
+use infrastructure::services::graphic_novel_service::graphic_novel_theme_service::default_graphic_novel_theme_service::DefaultGraphicNovelThemeService;
+use infrastructure::services::graphic_novel_service::graphic_novel_theme_service::default_mut_graphic_novel_theme_service::DefaultMutGraphicNovelThemeService;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::mut_graphic_novel_character_repository::MutGraphicNovelCharacterRepository;
+use repositories::graphic_novel_repository::graphic_novel_character_repository::GraphicNovelCharacterRepository;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::mut_graphic_novel_genre_repository::MutGraphicNovelGenreRepository;
+use repositories::graphic_novel_repository::graphic_novel_genre_repository::GraphicNovelGenreRepository;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::mut_graphic_novel_involved_repository::MutGraphicNovelInvolvedRepository;
+use repositories::graphic_novel_repository::graphic_novel_involved_repository::GraphicNovelInvolvedRepository;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::mut_graphic_novel_publisher_repository::MutGraphicNovelPublisherRepository;
+use repositories::graphic_novel_repository::graphic_novel_publisher_repository::GraphicNovelPublisherRepository;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::mut_graphic_novel_theme_repository::MutGraphicNovelThemeRepository;
+use repositories::graphic_novel_repository::graphic_novel_theme_repository::GraphicNovelThemeRepository;
+use services::graphic_novel_service::graphic_novel_character_service::mut_graphic_novel_character_service::MutGraphicNovelCharacterService;
+use services::graphic_novel_service::graphic_novel_character_service::GraphicNovelCharacterService;
+use services::graphic_novel_service::graphic_novel_genre_service::mut_graphic_novel_genre_service::MutGraphicNovelGenreService;
+use services::graphic_novel_service::graphic_novel_genre_service::GraphicNovelGenreService;
+use services::graphic_novel_service::graphic_novel_involved_service::mut_graphic_novel_involved_service::MutGraphicNovelInvolvedService;
+use services::graphic_novel_service::graphic_novel_involved_service::GraphicNovelInvolvedService;
+use services::graphic_novel_service::graphic_novel_publisher_service::mut_graphic_novel_publisher_service::MutGraphicNovelPublisherService;
+use services::graphic_novel_service::graphic_novel_publisher_service::GraphicNovelPublisherService;
+use services::graphic_novel_service::graphic_novel_theme_service::mut_graphic_novel_theme_service::MutGraphicNovelThemeService;
+use services::graphic_novel_service::graphic_novel_theme_service::GraphicNovelThemeService;
+use application::repositories::graphic_novel_repository::default_graphic_novel_repository::DefaultGraphicNovelRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_chapter_repository::default_graphic_novel_chapter_repository::DefaultGraphicNovelChapterRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_volume_repository::default_graphic_novel_volume_repository::DefaultGraphicNovelVolumeRepository;
+use infrastructure::services::graphic_novel_service::default_graphic_novel_service::DefaultGraphicNovelService;
+use infrastructure::services::graphic_novel_service::graphic_novel_chapter_service::default_graphic_novel_chapter_service::DefaultGraphicNovelChapterService;
+use infrastructure::services::graphic_novel_service::graphic_novel_volume_service::default_graphic_novel_volume_service::DefaultGraphicNovelVolumeService;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::GraphicNovelChapterRepository;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::GraphicNovelVolumeRepository;
+use repositories::graphic_novel_repository::GraphicNovelRepository;
+use services::graphic_novel_service::graphic_novel_chapter_service::GraphicNovelChapterService;
+use services::graphic_novel_service::graphic_novel_volume_service::GraphicNovelVolumeService;
+use services::graphic_novel_service::GraphicNovelService;
+use application::repositories::graphic_novel_repository::default_mut_graphic_novel_repository::DefaultMutGraphicNovelRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_chapter_repository::default_mut_graphic_novel_chapter_repository::DefaultMutGraphicNovelChapterRepository;
+use application::repositories::graphic_novel_repository::graphic_novel_volume_repository::default_mut_graphic_novel_volume_repository::DefaultMutGraphicNovelVolumeRepository;
+use infrastructure::services::graphic_novel_service::default_mut_graphic_novel_service::DefaultMutGraphicNovelService;
+use infrastructure::services::graphic_novel_service::graphic_novel_chapter_service::default_mut_graphic_novel_chapter_service::DefaultMutGraphicNovelChapterService;
+use infrastructure::services::graphic_novel_service::graphic_novel_volume_service::default_mut_graphic_novel_volume_service::DefaultMutGraphicNovelVolumeService;
+use repositories::graphic_novel_repository::graphic_novel_chapter_repository::mut_graphic_novel_chapter_repository::MutGraphicNovelChapterRepository;
+use repositories::graphic_novel_repository::graphic_novel_volume_repository::mut_graphic_novel_volume_repository::MutGraphicNovelVolumeRepository;
+use repositories::graphic_novel_repository::mut_graphic_novel_repository::MutGraphicNovelRepository;
+use services::graphic_novel_service::graphic_novel_chapter_service::mut_graphic_novel_chapter_service::MutGraphicNovelChapterService;
+use services::graphic_novel_service::graphic_novel_volume_service::mut_graphic_novel_volume_service::MutGraphicNovelVolumeService;
+use services::graphic_novel_service::mut_graphic_novel_service::MutGraphicNovelService;
+use std::error::Error;
 use std::sync::Arc;
 
-use tokio_postgres::{Client, Transaction};
-
 use application::repositories::account_repository::default_account_repository::DefaultAccountRepository;
 use application::repositories::account_repository::default_mut_account_repository::DefaultMutAccountRepository;
+use application::repositories::account_repository::email_verification_repository::default_email_verification_repository::DefaultEmailVerificationRepository;
+use application::repositories::account_repository::email_verification_repository::default_mut_email_verification_repository::DefaultMutEmailVerificationRepository;
+use application::repositories::account_repository::personal_access_token_repository::default_mut_personal_access_token_repository::DefaultMutPersonalAccessTokenRepository;
+use application::repositories::account_repository::personal_access_token_repository::default_personal_access_token_repository::DefaultPersonalAccessTokenRepository;
+use application::repositories::account_repository::two_factor_repository::default_mut_two_factor_repository::DefaultMutTwoFactorRepository;
+use application::repositories::account_repository::two_factor_repository::default_two_factor_repository::DefaultTwoFactorRepository;
+use application::repositories::account_repository::password_reset_repository::default_mut_password_reset_repository::DefaultMutPasswordResetRepository;
+use application::repositories::account_repository::password_reset_repository::default_password_reset_repository::DefaultPasswordResetRepository;
+use application::repositories::account_repository::refresh_token_repository::default_mut_refresh_token_repository::DefaultMutRefreshTokenRepository;
+use application::repositories::account_repository::refresh_token_repository::default_refresh_token_repository::DefaultRefreshTokenRepository;
 use application::repositories::book_repository::book_character_repository::default_book_character_repository::DefaultBookCharacterRepository;
 use application::repositories::book_repository::book_character_repository::default_mut_book_character_repository::DefaultMutBookCharacterRepository;
+use application::repositories::book_repository::book_edition_involved_repository::default_book_edition_involved_repository::DefaultBookEditionInvolvedRepository;
+use application::repositories::book_repository::book_edition_involved_repository::default_mut_book_edition_involved_repository::DefaultMutBookEditionInvolvedRepository;
+use application::repositories::book_repository::book_edition_repository::default_book_edition_repository::DefaultBookEditionRepository;
+use application::repositories::book_repository::book_edition_repository::default_mut_book_edition_repository::DefaultMutBookEditionRepository;
+use application::repositories::book_repository::book_image_repository::default_book_image_repository::DefaultBookImageRepository;
+use application::repositories::book_repository::book_image_repository::default_mut_book_image_repository::DefaultMutBookImageRepository;
+use application::repositories::book_repository::book_publisher_repository::default_book_publisher_repository::DefaultBookPublisherRepository;
+use application::repositories::book_repository::book_publisher_repository::default_mut_book_publisher_repository::DefaultMutBookPublisherRepository;
 use application::repositories::book_repository::book_genre_repository::default_book_genre_repository::DefaultBookGenreRepository;
 use application::repositories::book_repository::book_genre_repository::default_mut_book_genre_repository::DefaultMutBookGenreRepository;
 use application::repositories::book_repository::book_involved_repository::default_book_involved_repository::DefaultBookInvolvedRepository;
@@ -14,28 +96,87 @@ use application::repositories::book_repository::book_theme_repository::default_b
 use application::repositories::book_repository::book_theme_repository::default_mut_book_theme_repository::DefaultMutBookThemeRepository;
 use application::repositories::book_repository::default_book_repository::DefaultBookRepository;
 use application::repositories::book_repository::default_mut_book_repository::DefaultMutBookRepository;
+use application::repositories::company_repository::default_company_repository::DefaultCompanyRepository;
+use application::repositories::company_repository::default_mut_company_repository::DefaultMutCompanyRepository;
 use application::repositories::default_character_repository::DefaultCharacterRepository;
 use application::repositories::file_repository::default_file_repository::DefaultFileRepository;
 use application::repositories::file_repository::default_mut_file_repository::DefaultMutFileRepository;
 use application::repositories::franchise_repository::default_franchise_repository::DefaultFranchiseRepository;
 use application::repositories::franchise_repository::default_mut_franchise_repository::DefaultMutFranchiseRepository;
+use application::repositories::game_repository::default_game_repository::DefaultGameRepository;
+use application::repositories::game_repository::default_mut_game_repository::DefaultMutGameRepository;
+use application::repositories::game_repository::game_character_repository::default_game_character_repository::DefaultGameCharacterRepository;
+use application::repositories::game_repository::game_character_repository::default_mut_game_character_repository::DefaultMutGameCharacterRepository;
+use application::repositories::game_repository::game_genre_repository::default_game_genre_repository::DefaultGameGenreRepository;
+use application::repositories::game_repository::game_genre_repository::default_mut_game_genre_repository::DefaultMutGameGenreRepository;
+use application::repositories::game_repository::game_involved_repository::default_game_involved_repository::DefaultGameInvolvedRepository;
+use application::repositories::game_repository::game_involved_repository::default_mut_game_involved_repository::DefaultMutGameInvolvedRepository;
+use application::repositories::game_repository::game_platform_repository::default_game_platform_repository::DefaultGamePlatformRepository;
+use application::repositories::game_repository::game_platform_repository::default_mut_game_platform_repository::DefaultMutGamePlatformRepository;
+use application::repositories::game_repository::game_theme_repository::default_game_theme_repository::DefaultGameThemeRepository;
+use application::repositories::game_repository::game_theme_repository::default_mut_game_theme_repository::DefaultMutGameThemeRepository;
 use application::repositories::genre_repository::default_genre_repository::DefaultGenreRepository;
 use application::repositories::genre_repository::default_mut_genre_repository::DefaultMutGenreRepository;
 use application::repositories::image_repository::default_image_repository::DefaultImageRepository;
 use application::repositories::image_repository::default_mut_image_repository::DefaultMutImageRepository;
+use application::repositories::mail_sender::file_mail_sender::FileMailSender;
+use application::repositories::mail_sender::smtp_mail_sender::SmtpMailSender;
+use application::repositories::movie_repository::default_movie_repository::DefaultMovieRepository;
+use application::repositories::movie_repository::default_mut_movie_repository::DefaultMutMovieRepository;
+use application::repositories::movie_repository::movie_genre_repository::default_movie_genre_repository::DefaultMovieGenreRepository;
+use application::repositories::movie_repository::movie_genre_repository::default_mut_movie_genre_repository::DefaultMutMovieGenreRepository;
+use application::repositories::movie_repository::movie_involved_repository::default_movie_involved_repository::DefaultMovieInvolvedRepository;
+use application::repositories::movie_repository::movie_involved_repository::default_mut_movie_involved_repository::DefaultMutMovieInvolvedRepository;
+use application::repositories::movie_repository::movie_theme_repository::default_movie_theme_repository::DefaultMovieThemeRepository;
+use application::repositories::movie_repository::movie_theme_repository::default_mut_movie_theme_repository::DefaultMutMovieThemeRepository;
 use application::repositories::person_repository::default_mut_person_repository::DefaultMutPersonRepository;
 use application::repositories::person_repository::default_person_repository::DefaultPersonRepository;
+use application::repositories::platform_repository::default_platform_repository::DefaultPlatformRepository;
+use application::repositories::platform_repository::default_mut_platform_repository::DefaultMutPlatformRepository;
 use application::repositories::role_repository::default_mut_role_repository::DefaultMutRoleRepository;
 use application::repositories::role_repository::default_role_repository::DefaultRoleRepository;
+use application::repositories::show_repository::default_mut_show_repository::DefaultMutShowRepository;
+use application::repositories::show_repository::default_show_repository::DefaultShowRepository;
+use application::repositories::show_repository::show_episode_repository::default_mut_show_episode_repository::DefaultMutShowEpisodeRepository;
+use application::repositories::show_repository::show_episode_repository::default_show_episode_repository::DefaultShowEpisodeRepository;
+use application::repositories::show_repository::show_season_repository::default_mut_show_season_repository::DefaultMutShowSeasonRepository;
+use application::repositories::show_repository::show_season_repository::default_show_season_repository::DefaultShowSeasonRepository;
 use application::repositories::theme_repository::default_mut_theme_repository::DefaultMutThemeRepository;
 use application::repositories::theme_repository::default_theme_repository::DefaultThemeRepository;
+use application::repositories::user_repository::default_mut_user_average_repository::DefaultMutUserAverageRepository;
 use application::repositories::user_repository::default_mut_user_repository::DefaultMutUserRepository;
 use application::repositories::user_repository::default_user_repository::DefaultUserRepository;
+use application::repositories::user_repository::friendship_repository::default_friendship_repository::DefaultFriendshipRepository;
+use application::repositories::user_repository::friendship_repository::default_mut_friendship_repository::DefaultMutFriendshipRepository;
+use application::repositories::user_repository::user_book_repository::default_mut_user_book_repository::DefaultMutUserBookRepository;
+use application::repositories::user_repository::user_book_repository::default_user_book_repository::DefaultUserBookRepository;
 use domain::enums::language::Language;
+use infrastructure::services::account_service::default_account_export_service::DefaultAccountExportService;
+use infrastructure::clock::SystemClock;
 use infrastructure::services::account_service::default_account_service::DefaultAccountService;
+use infrastructure::services::account_service::default_login_throttle_service::{
+  DefaultLoginThrottleService, LoginThrottleConfig,
+};
 use infrastructure::services::account_service::default_mut_account_service::DefaultMutAccountService;
+use infrastructure::services::account_service::default_mut_password_service::DefaultMutPasswordService;
+use infrastructure::services::account_service::default_mut_refresh_token_service::DefaultMutRefreshTokenService;
+use infrastructure::services::account_service::default_mut_verification_service::DefaultMutVerificationService;
+use infrastructure::services::account_service::personal_access_token_service::default_mut_personal_access_token_service::DefaultMutPersonalAccessTokenService;
+use infrastructure::services::account_service::personal_access_token_service::default_personal_access_token_service::DefaultPersonalAccessTokenService;
+use infrastructure::services::account_service::two_factor_service::default_mut_two_factor_service::DefaultMutTwoFactorService;
+use infrastructure::services::account_service::two_factor_service::default_two_factor_service::DefaultTwoFactorService;
 use infrastructure::services::book_service::book_character_service::default_book_character_service::DefaultBookCharacterService;
 use infrastructure::services::book_service::book_character_service::default_mut_book_character_service::DefaultMutBookCharacterService;
+use infrastructure::services::book_service::book_edition_involved_service::default_book_edition_involved_service::DefaultBookEditionInvolvedService;
+use infrastructure::services::book_service::book_edition_involved_service::default_mut_book_edition_involved_service::DefaultMutBookEditionInvolvedService;
+use infrastructure::services::book_service::book_edition_service::default_book_edition_service::DefaultBookEditionService;
+use infrastructure::services::book_service::book_edition_service::default_mut_book_edition_service::DefaultMutBookEditionService;
+use infrastructure::services::book_service::book_image_service::default_book_image_service::DefaultBookImageService;
+use infrastructure::services::book_service::book_image_service::default_mut_book_image_service::DefaultMutBookImageService;
+use infrastructure::services::book_service::book_publisher_service::default_book_publisher_service::DefaultBookPublisherService;
+use infrastructure::services::book_service::book_publisher_service::default_mut_book_publisher_service::DefaultMutBookPublisherService;
+use infrastructure::services::company_service::default_company_service::DefaultCompanyService;
+use infrastructure::services::company_service::default_mut_company_service::DefaultMutCompanyService;
 use infrastructure::services::book_service::book_genre_service::default_book_genre_service::DefaultBookGenreService;
 use infrastructure::services::book_service::book_genre_service::default_mut_book_genre_service::DefaultMutBookGenreService;
 use infrastructure::services::book_service::book_involved_service::default_book_involved_service::DefaultBookInvolvedService;
@@ -49,22 +190,73 @@ use infrastructure::services::file_service::default_file_service::DefaultFileSer
 use infrastructure::services::file_service::default_mut_file_service::DefaultMutFileService;
 use infrastructure::services::franchise_service::default_franchise_service::DefaultFranchiseService;
 use infrastructure::services::franchise_service::default_mut_franchise_service::DefaultMutFranchiseService;
+use infrastructure::services::game_service::default_game_service::DefaultGameService;
+use infrastructure::services::game_service::default_mut_game_service::DefaultMutGameService;
+use infrastructure::services::game_service::game_character_service::default_game_character_service::DefaultGameCharacterService;
+use infrastructure::services::game_service::game_character_service::default_mut_game_character_service::DefaultMutGameCharacterService;
+use infrastructure::services::game_service::game_genre_service::default_game_genre_service::DefaultGameGenreService;
+use infrastructure::services::game_service::game_genre_service::default_mut_game_genre_service::DefaultMutGameGenreService;
+use infrastructure::services::game_service::game_involved_service::default_game_involved_service::DefaultGameInvolvedService;
+use infrastructure::services::game_service::game_involved_service::default_mut_game_involved_service::DefaultMutGameInvolvedService;
+use infrastructure::services::game_service::game_platform_service::default_game_platform_service::DefaultGamePlatformService;
+use infrastructure::services::game_service::game_platform_service::default_mut_game_platform_service::DefaultMutGamePlatformService;
+use infrastructure::services::game_service::game_theme_service::default_game_theme_service::DefaultGameThemeService;
+use infrastructure::services::game_service::game_theme_service::default_mut_game_theme_service::DefaultMutGameThemeService;
 use infrastructure::services::genre_service::default_genre_service::DefaultGenreService;
 use infrastructure::services::genre_service::default_mut_genre_service::DefaultMutGenreService;
 use infrastructure::services::image_service::default_image_service::DefaultImageService;
 use infrastructure::services::image_service::default_mut_image_service::DefaultMutImageService;
+use infrastructure::services::movie_service::default_movie_service::DefaultMovieService;
+use infrastructure::services::movie_service::default_mut_movie_service::DefaultMutMovieService;
+use infrastructure::services::movie_service::movie_genre_service::default_movie_genre_service::DefaultMovieGenreService;
+use infrastructure::services::movie_service::movie_genre_service::default_mut_movie_genre_service::DefaultMutMovieGenreService;
+use infrastructure::services::movie_service::movie_involved_service::default_movie_involved_service::DefaultMovieInvolvedService;
+use infrastructure::services::movie_service::movie_involved_service::default_mut_movie_involved_service::DefaultMutMovieInvolvedService;
+use infrastructure::services::movie_service::movie_theme_service::default_movie_theme_service::DefaultMovieThemeService;
+use infrastructure::services::movie_service::movie_theme_service::default_mut_movie_theme_service::DefaultMutMovieThemeService;
 use infrastructure::services::person_service::default_mut_person_service::DefaultMutPersonService;
 use infrastructure::services::person_service::default_person_service::DefaultPersonService;
+use infrastructure::services::platform_service::default_platform_service::DefaultPlatformService;
+use infrastructure::services::platform_service::default_mut_platform_service::DefaultMutPlatformService;
 use infrastructure::services::role_service::default_mut_role_service::DefaultMutRoleService;
 use infrastructure::services::role_service::default_role_service::DefaultRoleService;
+use infrastructure::services::show_service::default_mut_show_service::DefaultMutShowService;
+use infrastructure::services::show_service::default_show_service::DefaultShowService;
+use infrastructure::services::show_service::show_episode_service::default_mut_show_episode_service::DefaultMutShowEpisodeService;
+use infrastructure::services::show_service::show_episode_service::default_show_episode_service::DefaultShowEpisodeService;
+use infrastructure::services::show_service::show_season_service::default_mut_show_season_service::DefaultMutShowSeasonService;
+use infrastructure::services::show_service::show_season_service::default_show_season_service::DefaultShowSeasonService;
 use infrastructure::services::theme_service::default_mut_theme_service::DefaultMutThemeService;
 use infrastructure::services::theme_service::default_theme_service::DefaultThemeService;
+use infrastructure::services::user_service::default_mut_user_average_service::DefaultMutUserAverageService;
 use infrastructure::services::user_service::default_mut_user_service::DefaultMutUserService;
 use infrastructure::services::user_service::default_user_service::DefaultUserService;
+use infrastructure::services::user_service::friendship_service::default_friendship_service::DefaultFriendshipService;
+use infrastructure::services::user_service::friendship_service::default_mut_friendship_service::DefaultMutFriendshipService;
+use infrastructure::services::user_service::user_book_service::default_mut_user_book_service::DefaultMutUserBookService;
+use infrastructure::services::user_service::user_book_service::default_user_book_service::DefaultUserBookService;
+use repositories::account_repository::email_verification_repository::mut_email_verification_repository::MutEmailVerificationRepository;
+use repositories::account_repository::email_verification_repository::EmailVerificationRepository;
+use repositories::account_repository::personal_access_token_repository::mut_personal_access_token_repository::MutPersonalAccessTokenRepository;
+use repositories::account_repository::personal_access_token_repository::PersonalAccessTokenRepository;
+use repositories::account_repository::two_factor_repository::mut_two_factor_repository::MutTwoFactorRepository;
+use repositories::account_repository::two_factor_repository::TwoFactorRepository;
 use repositories::account_repository::mut_account_repository::MutAccountRepository;
+use repositories::account_repository::password_reset_repository::mut_password_reset_repository::MutPasswordResetRepository;
+use repositories::account_repository::password_reset_repository::PasswordResetRepository;
+use repositories::account_repository::refresh_token_repository::mut_refresh_token_repository::MutRefreshTokenRepository;
+use repositories::account_repository::refresh_token_repository::RefreshTokenRepository;
 use repositories::account_repository::AccountRepository;
 use repositories::book_repository::book_character_repository::mut_book_character_repository::MutBookCharacterRepository;
 use repositories::book_repository::book_character_repository::BookCharacterRepository;
+use repositories::book_repository::book_edition_involved_repository::mut_book_edition_involved_repository::MutBookEditionInvolvedRepository;
+use repositories::book_repository::book_edition_involved_repository::BookEditionInvolvedRepository;
+use repositories::book_repository::book_edition_repository::mut_book_edition_repository::MutBookEditionRepository;
+use repositories::book_repository::book_edition_repository::BookEditionRepository;
+use repositories::book_repository::book_image_repository::mut_book_image_repository::MutBookImageRepository;
+use repositories::book_repository::book_image_repository::BookImageRepository;
+use repositories::book_repository::book_publisher_repository::mut_book_publisher_repository::MutBookPublisherRepository;
+use repositories::book_repository::book_publisher_repository::BookPublisherRepository;
 use repositories::book_repository::book_genre_repository::mut_book_genre_repository::MutBookGenreRepository;
 use repositories::book_repository::book_genre_repository::BookGenreRepository;
 use repositories::book_repository::book_involved_repository::mut_book_involved_repository::MutBookInvolvedRepository;
@@ -74,26 +266,81 @@ use repositories::book_repository::book_theme_repository::BookThemeRepository;
 use repositories::book_repository::mut_book_repository::MutBookRepository;
 use repositories::book_repository::BookRepository;
 use repositories::character_repository::CharacterRepository;
+use repositories::company_repository::mut_company_repository::MutCompanyRepository;
+use repositories::company_repository::CompanyRepository;
 use repositories::file_repository::mut_file_repository::MutFileRepository;
 use repositories::file_repository::FileRepository;
 use repositories::franchise_repository::mut_franchise_repository::MutFranchiseRepository;
 use repositories::franchise_repository::FranchiseRepository;
+use repositories::game_repository::game_character_repository::mut_game_character_repository::MutGameCharacterRepository;
+use repositories::game_repository::game_character_repository::GameCharacterRepository;
+use repositories::game_repository::game_genre_repository::mut_game_genre_repository::MutGameGenreRepository;
+use repositories::game_repository::game_genre_repository::GameGenreRepository;
+use repositories::game_repository::game_involved_repository::mut_game_involved_repository::MutGameInvolvedRepository;
+use repositories::game_repository::game_involved_repository::GameInvolvedRepository;
+use repositories::game_repository::game_platform_repository::mut_game_platform_repository::MutGamePlatformRepository;
+use repositories::game_repository::game_platform_repository::GamePlatformRepository;
+use repositories::game_repository::game_theme_repository::mut_game_theme_repository::MutGameThemeRepository;
+use repositories::game_repository::game_theme_repository::GameThemeRepository;
+use repositories::game_repository::mut_game_repository::MutGameRepository;
+use repositories::game_repository::GameRepository;
 use repositories::genre_repository::mut_genre_repository::MutGenreRepository;
 use repositories::genre_repository::GenreRepository;
 use repositories::image_repository::mut_image_repository::MutImageRepository;
 use repositories::image_repository::ImageRepository;
+use repositories::mail_sender::MailSender;
+use repositories::movie_repository::movie_genre_repository::mut_movie_genre_repository::MutMovieGenreRepository;
+use repositories::movie_repository::movie_genre_repository::MovieGenreRepository;
+use repositories::movie_repository::movie_involved_repository::mut_movie_involved_repository::MutMovieInvolvedRepository;
+use repositories::movie_repository::movie_involved_repository::MovieInvolvedRepository;
+use repositories::movie_repository::movie_theme_repository::mut_movie_theme_repository::MutMovieThemeRepository;
+use repositories::movie_repository::movie_theme_repository::MovieThemeRepository;
+use repositories::movie_repository::mut_movie_repository::MutMovieRepository;
+use repositories::movie_repository::MovieRepository;
 use repositories::person_repository::mut_person_repository::MutPersonRepository;
 use repositories::person_repository::PersonRepository;
+use repositories::platform_repository::mut_platform_repository::MutPlatformRepository;
+use repositories::platform_repository::PlatformRepository;
 use repositories::role_repository::mut_role_repository::MutRoleRepository;
 use repositories::role_repository::RoleRepository;
+use repositories::show_repository::mut_show_repository::MutShowRepository;
+use repositories::show_repository::show_episode_repository::mut_show_episode_repository::MutShowEpisodeRepository;
+use repositories::show_repository::show_episode_repository::ShowEpisodeRepository;
+use repositories::show_repository::show_season_repository::mut_show_season_repository::MutShowSeasonRepository;
+use repositories::show_repository::show_season_repository::ShowSeasonRepository;
+use repositories::show_repository::ShowRepository;
 use repositories::theme_repository::mut_theme_repository::MutThemeRepository;
 use repositories::theme_repository::ThemeRepository;
+use repositories::user_repository::friendship_repository::mut_friendship_repository::MutFriendshipRepository;
+use repositories::user_repository::friendship_repository::FriendshipRepository;
+use repositories::user_repository::mut_user_average_repository::MutUserAverageRepository;
 use repositories::user_repository::mut_user_repository::MutUserRepository;
+use repositories::user_repository::user_book_repository::mut_user_book_repository::MutUserBookRepository;
+use repositories::user_repository::user_book_repository::UserBookRepository;
 use repositories::user_repository::UserRepository;
+use services::account_service::account_export_service::AccountExportService;
 use services::account_service::mut_account_service::MutAccountService;
+use services::account_service::mut_password_service::MutPasswordService;
+use services::account_service::mut_refresh_token_service::MutRefreshTokenService;
+use services::account_service::mut_verification_service::MutVerificationService;
+use services::account_service::personal_access_token_service::mut_personal_access_token_service::MutPersonalAccessTokenService;
+use services::account_service::personal_access_token_service::PersonalAccessTokenService;
+use services::account_service::two_factor_service::mut_two_factor_service::MutTwoFactorService;
+use services::account_service::two_factor_service::TwoFactorService;
+use services::account_service::login_throttle_service::LoginThrottleService;
 use services::account_service::AccountService;
 use services::book_service::book_character_service::mut_book_character_service::MutBookCharacterService;
 use services::book_service::book_character_service::BookCharacterService;
+use services::book_service::book_edition_involved_service::mut_book_edition_involved_service::MutBookEditionInvolvedService;
+use services::book_service::book_edition_involved_service::BookEditionInvolvedService;
+use services::book_service::book_edition_service::mut_book_edition_service::MutBookEditionService;
+use services::book_service::book_edition_service::BookEditionService;
+use services::book_service::book_image_service::mut_book_image_service::MutBookImageService;
+use services::book_service::book_image_service::BookImageService;
+use services::book_service::book_publisher_service::mut_book_publisher_service::MutBookPublisherService;
+use services::book_service::book_publisher_service::BookPublisherService;
+use services::company_service::mut_company_service::MutCompanyService;
+use services::company_service::CompanyService;
 use services::book_service::book_genre_service::mut_book_genre_service::MutBookGenreService;
 use services::book_service::book_genre_service::BookGenreService;
 use services::book_service::book_involved_service::mut_book_involved_service::MutBookInvolvedService;
@@ -107,17 +354,50 @@ use services::file_service::mut_file_service::MutFileService;
 use services::file_service::FileService;
 use services::franchise_service::mut_franchise_service::MutFranchiseService;
 use services::franchise_service::FranchiseService;
+use services::game_service::game_character_service::mut_game_character_service::MutGameCharacterService;
+use services::game_service::game_character_service::GameCharacterService;
+use services::game_service::game_genre_service::mut_game_genre_service::MutGameGenreService;
+use services::game_service::game_genre_service::GameGenreService;
+use services::game_service::game_involved_service::mut_game_involved_service::MutGameInvolvedService;
+use services::game_service::game_involved_service::GameInvolvedService;
+use services::game_service::game_platform_service::mut_game_platform_service::MutGamePlatformService;
+use services::game_service::game_platform_service::GamePlatformService;
+use services::game_service::game_theme_service::mut_game_theme_service::MutGameThemeService;
+use services::game_service::game_theme_service::GameThemeService;
+use services::game_service::mut_game_service::MutGameService;
+use services::game_service::GameService;
 use services::genre_service::mut_genre_service::MutGenreService;
 use services::genre_service::GenreService;
 use services::image_service::mut_image_service::MutImageService;
 use services::image_service::ImageService;
+use services::movie_service::movie_genre_service::mut_movie_genre_service::MutMovieGenreService;
+use services::movie_service::movie_genre_service::MovieGenreService;
+use services::movie_service::movie_involved_service::mut_movie_involved_service::MutMovieInvolvedService;
+use services::movie_service::movie_involved_service::MovieInvolvedService;
+use services::movie_service::movie_theme_service::mut_movie_theme_service::MutMovieThemeService;
+use services::movie_service::movie_theme_service::MovieThemeService;
+use services::movie_service::mut_movie_service::MutMovieService;
+use services::movie_service::MovieService;
 use services::person_service::mut_person_service::MutPersonService;
 use services::person_service::PersonService;
+use services::platform_service::mut_platform_service::MutPlatformService;
+use services::platform_service::PlatformService;
 use services::role_service::mut_role_service::MutRoleService;
 use services::role_service::RoleService;
+use services::show_service::mut_show_service::MutShowService;
+use services::show_service::show_episode_service::mut_show_episode_service::MutShowEpisodeService;
+use services::show_service::show_episode_service::ShowEpisodeService;
+use services::show_service::show_season_service::mut_show_season_service::MutShowSeasonService;
+use services::show_service::show_season_service::ShowSeasonService;
+use services::show_service::ShowService;
 use services::theme_service::mut_theme_service::MutThemeService;
 use services::theme_service::ThemeService;
+use services::user_service::friendship_service::mut_friendship_service::MutFriendshipService;
+use services::user_service::friendship_service::FriendshipService;
+use services::user_service::mut_user_average_service::MutUserAverageService;
 use services::user_service::mut_user_service::MutUserService;
+use services::user_service::user_book_service::mut_user_book_service::MutUserBookService;
+use services::user_service::user_book_service::UserBookService;
 use services::user_service::UserService;
 
 pub fn get_book_service<'a>(book_repository: Arc<dyn BookRepository + 'a>) -> impl BookService + 'a {
@@ -125,7 +405,7 @@ pub fn get_book_service<'a>(book_repository: Arc<dyn BookRepository + 'a>) -> im
 }
 
 pub fn get_book_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   image_repository: Arc<dyn ImageRepository + 'a>,
   franchise_repository: Arc<dyn FranchiseRepository + 'a>,
@@ -133,11 +413,11 @@ pub fn get_book_repository<'a>(
   DefaultBookRepository::new(client, language, image_repository, franchise_repository)
 }
 
-pub fn get_image_repository(client: &Client) -> impl ImageRepository + '_ {
+pub fn get_image_repository(client: &CachedClient) -> impl ImageRepository + '_ {
   DefaultImageRepository::new(client)
 }
 
-pub fn get_franchise_repository(client: &Client, language: Language) -> impl FranchiseRepository + '_ {
+pub fn get_franchise_repository(client: &CachedClient, language: Language) -> impl FranchiseRepository + '_ {
   DefaultFranchiseRepository::new(client, language)
 }
 
@@ -155,7 +435,7 @@ pub fn get_genre_service<'a>(genre_repository: Arc<dyn GenreRepository + 'a>) ->
   DefaultGenreService::new(genre_repository)
 }
 
-pub fn get_genre_repository(client: &Client, language: Language) -> impl GenreRepository + '_ {
+pub fn get_genre_repository(client: &CachedClient, language: Language) -> impl GenreRepository + '_ {
   DefaultGenreRepository::new(client, language)
 }
 
@@ -163,7 +443,7 @@ pub fn get_theme_service<'a>(theme_repository: Arc<dyn ThemeRepository + 'a>) ->
   DefaultThemeService::new(theme_repository)
 }
 
-pub fn get_theme_repository(client: &Client, language: Language) -> impl ThemeRepository + '_ {
+pub fn get_theme_repository(client: &CachedClient, language: Language) -> impl ThemeRepository + '_ {
   DefaultThemeRepository::new(client, language)
 }
 
@@ -172,7 +452,7 @@ pub fn get_person_service<'a>(person_repository: Arc<dyn PersonRepository + 'a>)
 }
 
 pub fn get_person_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   image_repository: Arc<dyn ImageRepository + 'a>,
 ) -> impl PersonRepository + 'a {
@@ -186,7 +466,7 @@ pub fn get_character_service<'a>(
 }
 
 pub fn get_character_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   image_repository: Arc<dyn ImageRepository + 'a>,
 ) -> impl CharacterRepository + 'a {
@@ -200,7 +480,7 @@ pub fn get_book_genre_service<'a>(
 }
 
 pub fn get_book_genre_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   book_repository: Arc<dyn BookRepository + 'a>,
   genre_repository: Arc<dyn GenreRepository + 'a>,
@@ -215,7 +495,7 @@ pub fn get_book_theme_service<'a>(
 }
 
 pub fn get_book_theme_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   book_repository: Arc<dyn BookRepository + 'a>,
   theme_repository: Arc<dyn ThemeRepository + 'a>,
@@ -230,7 +510,7 @@ pub fn get_book_character_service<'a>(
 }
 
 pub fn get_book_character_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   book_repository: Arc<dyn BookRepository + 'a>,
   character_repository: Arc<dyn CharacterRepository + 'a>,
@@ -252,7 +532,7 @@ pub fn get_mut_book_theme_service<'a>(
   )
 }
 
-pub fn get_mut_book_theme_repository<'a>(transaction: &'a Transaction) -> impl MutBookThemeRepository + 'a {
+pub fn get_mut_book_theme_repository<'a>(transaction: &'a CachedTransaction) -> impl MutBookThemeRepository + 'a {
   DefaultMutBookThemeRepository::new(transaction)
 }
 
@@ -270,7 +550,7 @@ pub fn get_mut_book_genre_service<'a>(
   )
 }
 
-pub fn get_mut_book_genre_repository<'a>(transaction: &'a Transaction) -> impl MutBookGenreRepository + 'a {
+pub fn get_mut_book_genre_repository<'a>(transaction: &'a CachedTransaction) -> impl MutBookGenreRepository + 'a {
   DefaultMutBookGenreRepository::new(transaction)
 }
 
@@ -288,7 +568,9 @@ pub fn get_mut_book_character_service<'a>(
   )
 }
 
-pub fn get_mut_book_character_repository<'a>(transaction: &'a Transaction) -> impl MutBookCharacterRepository + 'a {
+pub fn get_mut_book_character_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutBookCharacterRepository + 'a {
   DefaultMutBookCharacterRepository::new(transaction)
 }
 
@@ -299,7 +581,7 @@ pub fn get_book_involved_service<'a>(
 }
 
 pub fn get_book_involved_repository<'a>(
-  client: &'a Client,
+  client: &'a CachedClient,
   language: Language,
   book_repository: Arc<dyn BookRepository + 'a>,
   person_repository: Arc<dyn PersonRepository + 'a>,
@@ -312,7 +594,7 @@ pub fn get_role_service<'a>(role_repository: Arc<dyn RoleRepository + 'a>) -> im
   DefaultRoleService::new(role_repository)
 }
 
-pub fn get_role_repository(client: &Client, language: Language) -> impl RoleRepository + '_ {
+pub fn get_role_repository(client: &CachedClient, language: Language) -> impl RoleRepository + '_ {
   DefaultRoleRepository::new(client, language)
 }
 
@@ -342,7 +624,7 @@ pub fn get_mut_image_service<'a>(
 }
 
 pub fn get_mut_image_repository<'a>(
-  transaction: &'a Transaction,
+  transaction: &'a CachedTransaction,
   image_repository: Arc<dyn ImageRepository + 'a>,
   mut_file_repository: Arc<dyn MutFileRepository + 'a>,
   file_repository: Arc<dyn FileRepository + 'a>,
@@ -355,29 +637,102 @@ pub fn get_user_service<'a>(user_repository: Arc<dyn UserRepository + 'a>) -> im
 }
 
 pub fn get_user_repository<'a>(
-  connection: &'a Client,
+  connection: &'a CachedClient,
   image_repository: Arc<dyn ImageRepository + 'a>,
 ) -> impl UserRepository + 'a {
   DefaultUserRepository::new(connection, image_repository)
 }
 
 pub fn get_mut_user_service<'a>(
+  user_repository: Arc<dyn UserRepository + 'a>,
   mut_user_repository: Arc<dyn MutUserRepository + 'a>,
   mut_image_service: Arc<dyn MutImageService + 'a>,
 ) -> impl MutUserService + 'a {
-  DefaultMutUserService::new(mut_user_repository, mut_image_service)
+  DefaultMutUserService::new(user_repository, mut_user_repository, mut_image_service)
 }
 
 pub fn get_mut_user_repository<'a>(
-  transaction: &'a Transaction,
+  transaction: &'a CachedTransaction,
   user_repository: Arc<dyn UserRepository + 'a>,
   image_repository: Arc<dyn ImageRepository + 'a>,
 ) -> impl MutUserRepository + 'a {
   DefaultMutUserRepository::new(transaction, user_repository, image_repository)
 }
 
+pub fn get_user_book_service<'a>(user_book_repository: Arc<dyn UserBookRepository + 'a>) -> impl UserBookService + 'a {
+  DefaultUserBookService::new(user_book_repository)
+}
+
+pub fn get_user_book_repository<'a>(
+  client: &'a CachedClient,
+  book_repository: Arc<dyn BookRepository + 'a>,
+) -> impl UserBookRepository + 'a {
+  DefaultUserBookRepository::new(client, book_repository)
+}
+
+pub fn get_mut_user_book_service<'a>(
+  default_language: Language,
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  user_book_repository: Arc<dyn UserBookRepository + 'a>,
+  mut_user_book_repository: Arc<dyn MutUserBookRepository + 'a>,
+) -> impl MutUserBookService + 'a {
+  DefaultMutUserBookService::new(
+    default_language,
+    book_repository,
+    book_edition_repository,
+    user_book_repository,
+    mut_user_book_repository,
+  )
+}
+
+pub fn get_mut_user_book_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+) -> impl MutUserBookRepository + 'a {
+  DefaultMutUserBookRepository::new(transaction, mut_user_average_repository)
+}
+
+pub fn get_mut_user_average_service<'a>(
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+) -> impl MutUserAverageService + 'a {
+  DefaultMutUserAverageService::new(mut_user_average_repository)
+}
+
+pub fn get_mut_user_average_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+) -> impl MutUserAverageRepository + 'a {
+  DefaultMutUserAverageRepository::new(transaction)
+}
+
+pub fn get_friendship_service<'a>(
+  user_repository: Arc<dyn UserRepository + 'a>,
+  friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+) -> impl FriendshipService + 'a {
+  DefaultFriendshipService::new(user_repository, friendship_repository)
+}
+
+pub fn get_friendship_repository<'a>(
+  client: &'a CachedClient,
+  user_repository: Arc<dyn UserRepository + 'a>,
+) -> impl FriendshipRepository + 'a {
+  DefaultFriendshipRepository::new(client, user_repository)
+}
+
+pub fn get_mut_friendship_service<'a>(
+  user_repository: Arc<dyn UserRepository + 'a>,
+  friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+  mut_friendship_repository: Arc<dyn MutFriendshipRepository + 'a>,
+) -> impl MutFriendshipService + 'a {
+  DefaultMutFriendshipService::new(user_repository, friendship_repository, mut_friendship_repository)
+}
+
+pub fn get_mut_friendship_repository<'a>(transaction: &'a CachedTransaction<'a>) -> impl MutFriendshipRepository + 'a {
+  DefaultMutFriendshipRepository::new(transaction)
+}
+
 pub fn get_mut_account_repository<'a>(
-  transaction: &'a Transaction,
+  transaction: &'a CachedTransaction,
   account_repository: Arc<dyn AccountRepository + 'a>,
   user_repository: Arc<dyn UserRepository + 'a>,
 ) -> impl MutAccountRepository + 'a {
@@ -388,21 +743,188 @@ pub fn get_mut_account_service<'a>(
   mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
   account_service: Arc<dyn AccountService + 'a>,
   mut_user_service: Arc<dyn MutUserService + 'a>,
+  mut_verification_service: Arc<dyn MutVerificationService + 'a>,
 ) -> impl MutAccountService + 'a {
-  DefaultMutAccountService::new(mut_account_repository, account_service, mut_user_service)
+  DefaultMutAccountService::new(
+    mut_account_repository,
+    account_service,
+    mut_user_service,
+    mut_verification_service,
+  )
+}
+
+pub fn get_account_service<'a>(
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  login_throttle_service: Arc<dyn LoginThrottleService + 'a>,
+) -> impl AccountService + 'a {
+  DefaultAccountService::new(account_repository, login_throttle_service)
 }
 
-pub fn get_account_service<'a>(account_repository: Arc<dyn AccountRepository + 'a>) -> impl AccountService + 'a {
-  DefaultAccountService::new(account_repository)
+pub fn get_login_throttle_service(config: LoginThrottleConfig) -> impl LoginThrottleService {
+  DefaultLoginThrottleService::new(config, Arc::new(SystemClock))
+}
+
+pub fn get_account_export_service<'a>(
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  user_book_repository: Arc<dyn UserBookRepository + 'a>,
+  friendship_repository: Arc<dyn FriendshipRepository + 'a>,
+) -> impl AccountExportService + 'a {
+  DefaultAccountExportService::new(account_repository, user_book_repository, friendship_repository)
 }
 
 pub fn get_account_repository<'a>(
-  connection: &'a Client,
+  connection: &'a CachedClient,
   user_repository: Arc<dyn UserRepository + 'a>,
 ) -> impl AccountRepository + 'a {
   DefaultAccountRepository::new(connection, user_repository)
 }
 
+pub fn get_email_verification_repository(client: &CachedClient) -> impl EmailVerificationRepository + '_ {
+  DefaultEmailVerificationRepository::new(client)
+}
+
+pub fn get_mut_email_verification_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+) -> impl MutEmailVerificationRepository + 'a {
+  DefaultMutEmailVerificationRepository::new(transaction)
+}
+
+pub fn get_mut_verification_service<'a>(
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+  email_verification_repository: Arc<dyn EmailVerificationRepository + 'a>,
+  mut_email_verification_repository: Arc<dyn MutEmailVerificationRepository + 'a>,
+  mail_sender: Arc<dyn MailSender + 'a>,
+  frontend_url: &'a str,
+) -> impl MutVerificationService + 'a {
+  DefaultMutVerificationService::new(
+    account_repository,
+    mut_account_repository,
+    email_verification_repository,
+    mut_email_verification_repository,
+    mail_sender,
+    frontend_url,
+  )
+}
+
+pub fn get_personal_access_token_repository(client: &CachedClient) -> impl PersonalAccessTokenRepository + '_ {
+  DefaultPersonalAccessTokenRepository::new(client)
+}
+
+pub fn get_mut_personal_access_token_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+) -> impl MutPersonalAccessTokenRepository + 'a {
+  DefaultMutPersonalAccessTokenRepository::new(transaction)
+}
+
+pub fn get_personal_access_token_service<'a>(
+  personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository + 'a>,
+) -> impl PersonalAccessTokenService + 'a {
+  DefaultPersonalAccessTokenService::new(personal_access_token_repository)
+}
+
+pub fn get_mut_personal_access_token_service<'a>(
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository + 'a>,
+  mut_personal_access_token_repository: Arc<dyn MutPersonalAccessTokenRepository + 'a>,
+) -> impl MutPersonalAccessTokenService + 'a {
+  DefaultMutPersonalAccessTokenService::new(
+    account_repository,
+    personal_access_token_repository,
+    mut_personal_access_token_repository,
+  )
+}
+
+pub fn get_two_factor_repository(client: &CachedClient) -> impl TwoFactorRepository + '_ {
+  DefaultTwoFactorRepository::new(client)
+}
+
+pub fn get_mut_two_factor_repository<'a>(transaction: &'a CachedTransaction<'a>) -> impl MutTwoFactorRepository + 'a {
+  DefaultMutTwoFactorRepository::new(transaction)
+}
+
+pub fn get_two_factor_service<'a>(
+  two_factor_repository: Arc<dyn TwoFactorRepository + 'a>,
+) -> impl TwoFactorService + 'a {
+  DefaultTwoFactorService::new(two_factor_repository)
+}
+
+pub fn get_mut_two_factor_service<'a>(
+  account_repository: Arc<dyn AccountRepository + 'a>,
+  two_factor_repository: Arc<dyn TwoFactorRepository + 'a>,
+  mut_two_factor_repository: Arc<dyn MutTwoFactorRepository + 'a>,
+  login_throttle_service: Arc<dyn LoginThrottleService + 'a>,
+) -> impl MutTwoFactorService + 'a {
+  DefaultMutTwoFactorService::new(
+    account_repository,
+    two_factor_repository,
+    mut_two_factor_repository,
+    login_throttle_service,
+    Arc::new(SystemClock),
+  )
+}
+
+pub fn get_password_reset_repository(client: &CachedClient) -> impl PasswordResetRepository + '_ {
+  DefaultPasswordResetRepository::new(client)
+}
+
+pub fn get_mut_password_reset_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+) -> impl MutPasswordResetRepository + 'a {
+  DefaultMutPasswordResetRepository::new(transaction)
+}
+
+pub fn get_mut_password_service<'a>(
+  account_service: Arc<dyn AccountService + 'a>,
+  mut_account_repository: Arc<dyn MutAccountRepository + 'a>,
+  password_reset_repository: Arc<dyn PasswordResetRepository + 'a>,
+  mut_password_reset_repository: Arc<dyn MutPasswordResetRepository + 'a>,
+  mut_refresh_token_service: Arc<dyn MutRefreshTokenService + 'a>,
+  mail_sender: Arc<dyn MailSender + 'a>,
+  frontend_url: &'a str,
+) -> impl MutPasswordService + 'a {
+  DefaultMutPasswordService::new(
+    account_service,
+    mut_account_repository,
+    password_reset_repository,
+    mut_password_reset_repository,
+    mut_refresh_token_service,
+    mail_sender,
+    frontend_url,
+  )
+}
+
+pub fn get_smtp_mail_sender(
+  host: &str,
+  username: String,
+  password: String,
+  from: &str,
+) -> Result<impl MailSender, Box<dyn Error>> {
+  SmtpMailSender::new(host, username, password, from)
+}
+
+pub fn get_file_mail_sender(directory: String) -> impl MailSender {
+  FileMailSender::new(directory)
+}
+
+pub fn get_refresh_token_repository(client: &CachedClient) -> impl RefreshTokenRepository + '_ {
+  DefaultRefreshTokenRepository::new(client)
+}
+
+pub fn get_mut_refresh_token_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  refresh_token_repository: Arc<dyn RefreshTokenRepository + 'a>,
+) -> impl MutRefreshTokenRepository + 'a {
+  DefaultMutRefreshTokenRepository::new(transaction, refresh_token_repository)
+}
+
+pub fn get_mut_refresh_token_service<'a>(
+  refresh_token_repository: Arc<dyn RefreshTokenRepository + 'a>,
+  mut_refresh_token_repository: Arc<dyn MutRefreshTokenRepository + 'a>,
+) -> impl MutRefreshTokenService + 'a {
+  DefaultMutRefreshTokenService::new(refresh_token_repository, mut_refresh_token_repository)
+}
+
 pub fn get_mut_book_involved_service<'a>(
   book_repository: Arc<dyn BookRepository + 'a>,
   book_involved_repository: Arc<dyn BookInvolvedRepository + 'a>,
@@ -419,7 +941,7 @@ pub fn get_mut_book_involved_service<'a>(
   )
 }
 
-pub fn get_mut_book_involved_repository<'a>(transaction: &'a Transaction) -> impl MutBookInvolvedRepository + 'a {
+pub fn get_mut_book_involved_repository<'a>(transaction: &'a CachedTransaction) -> impl MutBookInvolvedRepository + 'a {
   DefaultMutBookInvolvedRepository::new(transaction)
 }
 
@@ -427,6 +949,7 @@ pub fn get_mut_book_service<'a>(
   language: Language,
   book_repository: Arc<dyn BookRepository + 'a>,
   mut_book_repository: Arc<dyn MutBookRepository + 'a>,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
   mut_image_service: Arc<dyn MutImageService + 'a>,
   franchise_repository: Arc<dyn FranchiseRepository + 'a>,
   theme_repository: Arc<dyn ThemeRepository + 'a>,
@@ -439,6 +962,7 @@ pub fn get_mut_book_service<'a>(
     language,
     book_repository,
     mut_book_repository,
+    book_edition_repository,
     mut_image_service,
     franchise_repository,
     theme_repository,
@@ -450,12 +974,16 @@ pub fn get_mut_book_service<'a>(
 }
 
 pub fn get_mut_book_repository<'a>(
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   mut_book_genre_repository: Arc<dyn MutBookGenreRepository + 'a>,
   mut_book_character_repository: Arc<dyn MutBookCharacterRepository + 'a>,
   mut_book_theme_repository: Arc<dyn MutBookThemeRepository + 'a>,
   mut_book_involved_repository: Arc<dyn MutBookInvolvedRepository + 'a>,
+  mut_book_edition_repository: Arc<dyn MutBookEditionRepository + 'a>,
+  mut_book_image_repository: Arc<dyn MutBookImageRepository + 'a>,
+  mut_book_publisher_repository: Arc<dyn MutBookPublisherRepository + 'a>,
+  mut_user_book_repository: Arc<dyn MutUserBookRepository + 'a>,
   book_repository: Arc<dyn BookRepository + 'a>,
 ) -> impl MutBookRepository + 'a {
   DefaultMutBookRepository::new(
@@ -465,10 +993,162 @@ pub fn get_mut_book_repository<'a>(
     mut_book_character_repository,
     mut_book_theme_repository,
     mut_book_involved_repository,
+    mut_book_edition_repository,
+    mut_book_image_repository,
+    mut_book_publisher_repository,
+    mut_user_book_repository,
+    book_repository,
+  )
+}
+
+pub fn get_book_edition_service<'a>(
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+) -> impl BookEditionService + 'a {
+  DefaultBookEditionService::new(book_edition_repository)
+}
+
+pub fn get_book_edition_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+) -> impl BookEditionRepository + 'a {
+  DefaultBookEditionRepository::new(client, language, image_repository)
+}
+
+pub fn get_mut_book_edition_service<'a>(
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  mut_book_edition_repository: Arc<dyn MutBookEditionRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutBookEditionService + 'a {
+  DefaultMutBookEditionService::new(
+    book_repository,
+    book_edition_repository,
+    mut_book_edition_repository,
+    mut_image_service,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_book_edition_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  mut_book_edition_involved_repository: Arc<dyn MutBookEditionInvolvedRepository + 'a>,
+) -> impl MutBookEditionRepository + 'a {
+  DefaultMutBookEditionRepository::new(
+    transaction,
+    default_language,
+    book_edition_repository,
+    mut_book_edition_involved_repository,
+  )
+}
+
+pub fn get_book_edition_involved_service<'a>(
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  book_edition_involved_repository: Arc<dyn BookEditionInvolvedRepository + 'a>,
+) -> impl BookEditionInvolvedService + 'a {
+  DefaultBookEditionInvolvedService::new(book_edition_repository, book_edition_involved_repository)
+}
+
+pub fn get_book_edition_involved_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl BookEditionInvolvedRepository + 'a {
+  DefaultBookEditionInvolvedRepository::new(client, language, person_repository, role_repository)
+}
+
+pub fn get_mut_book_edition_involved_service<'a>(
+  book_edition_repository: Arc<dyn BookEditionRepository + 'a>,
+  book_edition_involved_repository: Arc<dyn BookEditionInvolvedRepository + 'a>,
+  mut_book_edition_involved_repository: Arc<dyn MutBookEditionInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutBookEditionInvolvedService + 'a {
+  DefaultMutBookEditionInvolvedService::new(
+    book_edition_repository,
+    book_edition_involved_repository,
+    mut_book_edition_involved_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_book_edition_involved_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutBookEditionInvolvedRepository + 'a {
+  DefaultMutBookEditionInvolvedRepository::new(transaction)
+}
+
+pub fn get_book_image_service<'a>(
+  book_image_repository: Arc<dyn BookImageRepository + 'a>,
+) -> impl BookImageService + 'a {
+  DefaultBookImageService::new(book_image_repository)
+}
+
+pub fn get_book_image_repository<'a>(
+  client: &'a CachedClient,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+) -> impl BookImageRepository + 'a {
+  DefaultBookImageRepository::new(client, image_repository)
+}
+
+pub fn get_mut_book_image_service<'a>(
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_image_repository: Arc<dyn BookImageRepository + 'a>,
+  mut_book_image_repository: Arc<dyn MutBookImageRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+) -> impl MutBookImageService + 'a {
+  DefaultMutBookImageService::new(
     book_repository,
+    book_image_repository,
+    mut_book_image_repository,
+    mut_image_service,
   )
 }
 
+pub fn get_mut_book_image_repository<'a>(transaction: &'a CachedTransaction) -> impl MutBookImageRepository + 'a {
+  DefaultMutBookImageRepository::new(transaction)
+}
+
+pub fn get_book_publisher_service<'a>(
+  book_publisher_repository: Arc<dyn BookPublisherRepository + 'a>,
+) -> impl BookPublisherService + 'a {
+  DefaultBookPublisherService::new(book_publisher_repository)
+}
+
+pub fn get_book_publisher_repository<'a>(
+  client: &'a CachedClient,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+) -> impl BookPublisherRepository + 'a {
+  DefaultBookPublisherRepository::new(client, company_repository)
+}
+
+pub fn get_mut_book_publisher_service<'a>(
+  book_repository: Arc<dyn BookRepository + 'a>,
+  book_publisher_repository: Arc<dyn BookPublisherRepository + 'a>,
+  mut_book_publisher_repository: Arc<dyn MutBookPublisherRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+) -> impl MutBookPublisherService + 'a {
+  DefaultMutBookPublisherService::new(
+    book_repository,
+    book_publisher_repository,
+    mut_book_publisher_repository,
+    company_repository,
+  )
+}
+
+pub fn get_mut_book_publisher_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutBookPublisherRepository + 'a {
+  DefaultMutBookPublisherRepository::new(transaction)
+}
+
 pub fn get_mut_person_service<'a>(
   default_language: Language,
   person_repository: Arc<dyn PersonRepository + 'a>,
@@ -484,7 +1164,7 @@ pub fn get_mut_person_service<'a>(
 }
 
 pub fn get_mut_person_repository<'a>(
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   person_repository: Arc<dyn PersonRepository + 'a>,
 ) -> impl MutPersonRepository + 'a {
@@ -500,7 +1180,7 @@ pub fn get_mut_genre_service<'a>(
 }
 
 pub fn get_mut_genre_repository<'a>(
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   genre_repository: Arc<dyn GenreRepository + 'a>,
 ) -> impl MutGenreRepository + 'a {
@@ -516,7 +1196,7 @@ pub fn get_mut_theme_service<'a>(
 }
 
 pub fn get_mut_theme_repository<'a>(
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   theme_repository: Arc<dyn ThemeRepository + 'a>,
 ) -> impl MutThemeRepository + 'a {
@@ -532,7 +1212,7 @@ pub fn get_mut_role_service<'a>(
 }
 
 pub fn get_mut_role_repository<'a>(
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   role_repository: Arc<dyn RoleRepository + 'a>,
 ) -> impl MutRoleRepository + 'a {
@@ -547,9 +1227,874 @@ pub fn get_mut_franchise_service<'a>(
 }
 
 pub fn get_mut_franchise_repository<'a>(
-  transaction: &'a Transaction<'a>,
+  transaction: &'a CachedTransaction<'a>,
   default_language: Language,
   franchise_repository: Arc<dyn FranchiseRepository + 'a>,
 ) -> impl MutFranchiseRepository + 'a {
   DefaultMutFranchiseRepository::new(transaction, default_language, franchise_repository)
 }
+
+pub fn get_movie_service<'a>(movie_repository: Arc<dyn MovieRepository + 'a>) -> impl MovieService + 'a {
+  DefaultMovieService::new(movie_repository)
+}
+
+pub fn get_movie_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+) -> impl MovieRepository + 'a {
+  DefaultMovieRepository::new(client, language, image_repository)
+}
+
+pub fn get_mut_movie_service<'a>(
+  language: Language,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  mut_movie_repository: Arc<dyn MutMovieRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutMovieService + 'a {
+  DefaultMutMovieService::new(
+    language,
+    movie_repository,
+    mut_movie_repository,
+    mut_image_service,
+    theme_repository,
+    genre_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_movie_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_movie_genre_repository: Arc<dyn MutMovieGenreRepository + 'a>,
+  mut_movie_theme_repository: Arc<dyn MutMovieThemeRepository + 'a>,
+  mut_movie_involved_repository: Arc<dyn MutMovieInvolvedRepository + 'a>,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+) -> impl MutMovieRepository + 'a {
+  DefaultMutMovieRepository::new(
+    transaction,
+    default_language,
+    mut_movie_genre_repository,
+    mut_movie_theme_repository,
+    mut_movie_involved_repository,
+    movie_repository,
+  )
+}
+
+pub fn get_movie_genre_service<'a>(
+  movie_genre_repository: Arc<dyn MovieGenreRepository + 'a>,
+) -> impl MovieGenreService + 'a {
+  DefaultMovieGenreService::new(movie_genre_repository)
+}
+
+pub fn get_movie_genre_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+) -> impl MovieGenreRepository + 'a {
+  DefaultMovieGenreRepository::new(client, language, movie_repository, genre_repository)
+}
+
+pub fn get_mut_movie_genre_service<'a>(
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  movie_genre_repository: Arc<dyn MovieGenreRepository + 'a>,
+  mut_movie_genre_repository: Arc<dyn MutMovieGenreRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+) -> impl MutMovieGenreService + 'a {
+  DefaultMutMovieGenreService::new(
+    movie_repository,
+    movie_genre_repository,
+    mut_movie_genre_repository,
+    genre_repository,
+  )
+}
+
+pub fn get_mut_movie_genre_repository<'a>(transaction: &'a CachedTransaction) -> impl MutMovieGenreRepository + 'a {
+  DefaultMutMovieGenreRepository::new(transaction)
+}
+
+pub fn get_movie_theme_service<'a>(
+  movie_theme_repository: Arc<dyn MovieThemeRepository + 'a>,
+) -> impl MovieThemeService + 'a {
+  DefaultMovieThemeService::new(movie_theme_repository)
+}
+
+pub fn get_movie_theme_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+) -> impl MovieThemeRepository + 'a {
+  DefaultMovieThemeRepository::new(client, language, movie_repository, theme_repository)
+}
+
+pub fn get_mut_movie_theme_service<'a>(
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  movie_theme_repository: Arc<dyn MovieThemeRepository + 'a>,
+  mut_movie_theme_repository: Arc<dyn MutMovieThemeRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+) -> impl MutMovieThemeService + 'a {
+  DefaultMutMovieThemeService::new(
+    movie_repository,
+    movie_theme_repository,
+    mut_movie_theme_repository,
+    theme_repository,
+  )
+}
+
+pub fn get_mut_movie_theme_repository<'a>(transaction: &'a CachedTransaction) -> impl MutMovieThemeRepository + 'a {
+  DefaultMutMovieThemeRepository::new(transaction)
+}
+
+pub fn get_movie_involved_service<'a>(
+  movie_involved_repository: Arc<dyn MovieInvolvedRepository + 'a>,
+) -> impl MovieInvolvedService + 'a {
+  DefaultMovieInvolvedService::new(movie_involved_repository)
+}
+
+pub fn get_movie_involved_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MovieInvolvedRepository + 'a {
+  DefaultMovieInvolvedRepository::new(client, language, movie_repository, person_repository, role_repository)
+}
+
+pub fn get_mut_movie_involved_service<'a>(
+  movie_repository: Arc<dyn MovieRepository + 'a>,
+  movie_involved_repository: Arc<dyn MovieInvolvedRepository + 'a>,
+  mut_movie_involved_repository: Arc<dyn MutMovieInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutMovieInvolvedService + 'a {
+  DefaultMutMovieInvolvedService::new(
+    movie_repository,
+    movie_involved_repository,
+    mut_movie_involved_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_movie_involved_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutMovieInvolvedRepository + 'a {
+  DefaultMutMovieInvolvedRepository::new(transaction)
+}
+
+pub fn get_show_service<'a>(show_repository: Arc<dyn ShowRepository + 'a>) -> impl ShowService + 'a {
+  DefaultShowService::new(show_repository)
+}
+
+pub fn get_show_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+) -> impl ShowRepository + 'a {
+  DefaultShowRepository::new(client, language, image_repository, franchise_repository)
+}
+
+pub fn get_mut_show_service<'a>(
+  language: Language,
+  show_repository: Arc<dyn ShowRepository + 'a>,
+  mut_show_repository: Arc<dyn MutShowRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+) -> impl MutShowService + 'a {
+  DefaultMutShowService::new(
+    language,
+    show_repository,
+    mut_show_repository,
+    mut_image_service,
+    franchise_repository,
+  )
+}
+
+pub fn get_mut_show_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_show_season_repository: Arc<dyn MutShowSeasonRepository + 'a>,
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+  show_repository: Arc<dyn ShowRepository + 'a>,
+) -> impl MutShowRepository + 'a {
+  DefaultMutShowRepository::new(
+    transaction,
+    default_language,
+    mut_show_season_repository,
+    mut_user_average_repository,
+    show_repository,
+  )
+}
+
+pub fn get_show_season_service<'a>(
+  show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+) -> impl ShowSeasonService + 'a {
+  DefaultShowSeasonService::new(show_season_repository)
+}
+
+pub fn get_show_season_repository(client: &CachedClient, language: Language) -> impl ShowSeasonRepository + '_ {
+  DefaultShowSeasonRepository::new(client, language)
+}
+
+pub fn get_mut_show_season_service<'a>(
+  language: Language,
+  show_repository: Arc<dyn ShowRepository + 'a>,
+  show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+  mut_show_season_repository: Arc<dyn MutShowSeasonRepository + 'a>,
+) -> impl MutShowSeasonService + 'a {
+  DefaultMutShowSeasonService::new(
+    language,
+    show_repository,
+    show_season_repository,
+    mut_show_season_repository,
+  )
+}
+
+pub fn get_mut_show_season_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  mut_show_episode_repository: Arc<dyn MutShowEpisodeRepository + 'a>,
+) -> impl MutShowSeasonRepository + 'a {
+  DefaultMutShowSeasonRepository::new(transaction, mut_show_episode_repository)
+}
+
+pub fn get_show_episode_service<'a>(
+  show_episode_repository: Arc<dyn ShowEpisodeRepository + 'a>,
+) -> impl ShowEpisodeService + 'a {
+  DefaultShowEpisodeService::new(show_episode_repository)
+}
+
+pub fn get_show_episode_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+) -> impl ShowEpisodeRepository + 'a {
+  DefaultShowEpisodeRepository::new(client, language, image_repository)
+}
+
+pub fn get_mut_show_episode_service<'a>(
+  language: Language,
+  show_season_repository: Arc<dyn ShowSeasonRepository + 'a>,
+  show_episode_repository: Arc<dyn ShowEpisodeRepository + 'a>,
+  mut_show_episode_repository: Arc<dyn MutShowEpisodeRepository + 'a>,
+) -> impl MutShowEpisodeService + 'a {
+  DefaultMutShowEpisodeService::new(
+    language,
+    show_season_repository,
+    show_episode_repository,
+    mut_show_episode_repository,
+  )
+}
+
+pub fn get_mut_show_episode_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+) -> impl MutShowEpisodeRepository + 'a {
+  DefaultMutShowEpisodeRepository::new(transaction)
+}
+
+pub fn get_game_service<'a>(game_repository: Arc<dyn GameRepository + 'a>) -> impl GameService + 'a {
+  DefaultGameService::new(game_repository)
+}
+
+pub fn get_game_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+) -> impl GameRepository + 'a {
+  DefaultGameRepository::new(client, language, image_repository, franchise_repository)
+}
+
+pub fn get_game_genre_service<'a>(
+  game_genre_repository: Arc<dyn GameGenreRepository + 'a>,
+) -> impl GameGenreService + 'a {
+  DefaultGameGenreService::new(game_genre_repository)
+}
+
+pub fn get_game_genre_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+) -> impl GameGenreRepository + 'a {
+  DefaultGameGenreRepository::new(client, language, game_repository, genre_repository)
+}
+
+pub fn get_game_theme_service<'a>(
+  game_theme_repository: Arc<dyn GameThemeRepository + 'a>,
+) -> impl GameThemeService + 'a {
+  DefaultGameThemeService::new(game_theme_repository)
+}
+
+pub fn get_game_theme_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+) -> impl GameThemeRepository + 'a {
+  DefaultGameThemeRepository::new(client, language, game_repository, theme_repository)
+}
+
+pub fn get_game_character_service<'a>(
+  game_character_repository: Arc<dyn GameCharacterRepository + 'a>,
+) -> impl GameCharacterService + 'a {
+  DefaultGameCharacterService::new(game_character_repository)
+}
+
+pub fn get_game_character_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+) -> impl GameCharacterRepository + 'a {
+  DefaultGameCharacterRepository::new(client, language, game_repository, character_repository)
+}
+
+pub fn get_mut_game_theme_service<'a>(
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_theme_repository: Arc<dyn GameThemeRepository + 'a>,
+  mut_game_theme_repository: Arc<dyn MutGameThemeRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+) -> impl MutGameThemeService + 'a {
+  DefaultMutGameThemeService::new(
+    game_repository,
+    game_theme_repository,
+    mut_game_theme_repository,
+    theme_repository,
+  )
+}
+
+pub fn get_mut_game_theme_repository<'a>(transaction: &'a CachedTransaction) -> impl MutGameThemeRepository + 'a {
+  DefaultMutGameThemeRepository::new(transaction)
+}
+
+pub fn get_mut_game_genre_service<'a>(
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_genre_repository: Arc<dyn GameGenreRepository + 'a>,
+  mut_game_genre_repository: Arc<dyn MutGameGenreRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+) -> impl MutGameGenreService + 'a {
+  DefaultMutGameGenreService::new(
+    game_repository,
+    game_genre_repository,
+    mut_game_genre_repository,
+    genre_repository,
+  )
+}
+
+pub fn get_mut_game_genre_repository<'a>(transaction: &'a CachedTransaction) -> impl MutGameGenreRepository + 'a {
+  DefaultMutGameGenreRepository::new(transaction)
+}
+
+pub fn get_mut_game_character_service<'a>(
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_character_repository: Arc<dyn GameCharacterRepository + 'a>,
+  mut_game_character_repository: Arc<dyn MutGameCharacterRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+) -> impl MutGameCharacterService + 'a {
+  DefaultMutGameCharacterService::new(
+    game_repository,
+    game_character_repository,
+    mut_game_character_repository,
+    character_repository,
+  )
+}
+
+pub fn get_mut_game_character_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutGameCharacterRepository + 'a {
+  DefaultMutGameCharacterRepository::new(transaction)
+}
+
+pub fn get_game_involved_service<'a>(
+  game_involved_repository: Arc<dyn GameInvolvedRepository + 'a>,
+) -> impl GameInvolvedService + 'a {
+  DefaultGameInvolvedService::new(game_involved_repository)
+}
+
+pub fn get_game_involved_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl GameInvolvedRepository + 'a {
+  DefaultGameInvolvedRepository::new(client, language, game_repository, person_repository, role_repository)
+}
+
+pub fn get_mut_game_involved_service<'a>(
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_involved_repository: Arc<dyn GameInvolvedRepository + 'a>,
+  mut_game_involved_repository: Arc<dyn MutGameInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutGameInvolvedService + 'a {
+  DefaultMutGameInvolvedService::new(
+    game_repository,
+    game_involved_repository,
+    mut_game_involved_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_game_involved_repository<'a>(transaction: &'a CachedTransaction) -> impl MutGameInvolvedRepository + 'a {
+  DefaultMutGameInvolvedRepository::new(transaction)
+}
+
+pub fn get_mut_game_service<'a>(
+  language: Language,
+  game_repository: Arc<dyn GameRepository + 'a>,
+  mut_game_repository: Arc<dyn MutGameRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+  franchise_repository: Arc<dyn FranchiseRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutGameService + 'a {
+  DefaultMutGameService::new(
+    language,
+    game_repository,
+    mut_game_repository,
+    mut_image_service,
+    franchise_repository,
+    theme_repository,
+    genre_repository,
+    character_repository,
+    platform_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_game_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_game_genre_repository: Arc<dyn MutGameGenreRepository + 'a>,
+  mut_game_character_repository: Arc<dyn MutGameCharacterRepository + 'a>,
+  mut_game_theme_repository: Arc<dyn MutGameThemeRepository + 'a>,
+  mut_game_involved_repository: Arc<dyn MutGameInvolvedRepository + 'a>,
+  mut_game_platform_repository: Arc<dyn MutGamePlatformRepository + 'a>,
+  game_repository: Arc<dyn GameRepository + 'a>,
+) -> impl MutGameRepository + 'a {
+  DefaultMutGameRepository::new(
+    transaction,
+    default_language,
+    mut_game_genre_repository,
+    mut_game_character_repository,
+    mut_game_theme_repository,
+    mut_game_involved_repository,
+    mut_game_platform_repository,
+    game_repository,
+  )
+}
+
+pub fn get_game_platform_service<'a>(
+  game_platform_repository: Arc<dyn GamePlatformRepository + 'a>,
+) -> impl GamePlatformService + 'a {
+  DefaultGamePlatformService::new(game_platform_repository)
+}
+
+pub fn get_game_platform_repository<'a>(
+  client: &'a CachedClient,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+) -> impl GamePlatformRepository + 'a {
+  DefaultGamePlatformRepository::new(client, platform_repository)
+}
+
+pub fn get_mut_game_platform_service<'a>(
+  game_repository: Arc<dyn GameRepository + 'a>,
+  game_platform_repository: Arc<dyn GamePlatformRepository + 'a>,
+  mut_game_platform_repository: Arc<dyn MutGamePlatformRepository + 'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+) -> impl MutGamePlatformService + 'a {
+  DefaultMutGamePlatformService::new(
+    game_repository,
+    game_platform_repository,
+    mut_game_platform_repository,
+    platform_repository,
+  )
+}
+
+pub fn get_mut_game_platform_repository<'a>(transaction: &'a CachedTransaction) -> impl MutGamePlatformRepository + 'a {
+  DefaultMutGamePlatformRepository::new(transaction)
+}
+
+pub fn get_platform_service<'a>(platform_repository: Arc<dyn PlatformRepository + 'a>) -> impl PlatformService + 'a {
+  DefaultPlatformService::new(platform_repository)
+}
+
+pub fn get_platform_repository<'a>(
+  client: &'a CachedClient,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+) -> impl PlatformRepository + 'a {
+  DefaultPlatformRepository::new(client, image_repository, company_repository)
+}
+
+pub fn get_mut_platform_service<'a>(
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+  mut_platform_repository: Arc<dyn MutPlatformRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+) -> impl MutPlatformService + 'a {
+  DefaultMutPlatformService::new(
+    platform_repository,
+    mut_platform_repository,
+    company_repository,
+    mut_image_service,
+  )
+}
+
+pub fn get_mut_platform_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+) -> impl MutPlatformRepository + 'a {
+  DefaultMutPlatformRepository::new(transaction, platform_repository)
+}
+
+pub fn get_company_repository<'a>(
+  client: &'a CachedClient,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+) -> impl CompanyRepository + 'a {
+  DefaultCompanyRepository::new(client, image_repository)
+}
+
+pub fn get_company_service<'a>(company_repository: Arc<dyn CompanyRepository + 'a>) -> impl CompanyService + 'a {
+  DefaultCompanyService::new(company_repository)
+}
+
+pub fn get_mut_company_service<'a>(
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+  mut_company_repository: Arc<dyn MutCompanyRepository + 'a>,
+  platform_repository: Arc<dyn PlatformRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+) -> impl MutCompanyService + 'a {
+  DefaultMutCompanyService::new(
+    company_repository,
+    mut_company_repository,
+    platform_repository,
+    mut_image_service,
+  )
+}
+
+pub fn get_mut_company_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+) -> impl MutCompanyRepository + 'a {
+  DefaultMutCompanyRepository::new(transaction, company_repository)
+}
+
+pub fn get_graphic_novel_service<'a>(
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+) -> impl GraphicNovelService + 'a {
+  DefaultGraphicNovelService::new(graphic_novel_repository)
+}
+
+pub fn get_graphic_novel_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  image_repository: Arc<dyn ImageRepository + 'a>,
+) -> impl GraphicNovelRepository + 'a {
+  DefaultGraphicNovelRepository::new(client, language, image_repository)
+}
+
+pub fn get_mut_graphic_novel_service<'a>(
+  language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  mut_graphic_novel_repository: Arc<dyn MutGraphicNovelRepository + 'a>,
+  mut_image_service: Arc<dyn MutImageService + 'a>,
+) -> impl MutGraphicNovelService + 'a {
+  DefaultMutGraphicNovelService::new(
+    language,
+    graphic_novel_repository,
+    mut_graphic_novel_repository,
+    mut_image_service,
+  )
+}
+
+pub fn get_mut_graphic_novel_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  default_language: Language,
+  mut_graphic_novel_genre_repository: Arc<dyn MutGraphicNovelGenreRepository + 'a>,
+  mut_graphic_novel_character_repository: Arc<dyn MutGraphicNovelCharacterRepository + 'a>,
+  mut_graphic_novel_theme_repository: Arc<dyn MutGraphicNovelThemeRepository + 'a>,
+  mut_graphic_novel_involved_repository: Arc<dyn MutGraphicNovelInvolvedRepository + 'a>,
+  mut_graphic_novel_publisher_repository: Arc<dyn MutGraphicNovelPublisherRepository + 'a>,
+  mut_graphic_novel_volume_repository: Arc<dyn MutGraphicNovelVolumeRepository + 'a>,
+  mut_user_average_repository: Arc<dyn MutUserAverageRepository + 'a>,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+) -> impl MutGraphicNovelRepository + 'a {
+  DefaultMutGraphicNovelRepository::new(
+    transaction,
+    default_language,
+    mut_graphic_novel_genre_repository,
+    mut_graphic_novel_character_repository,
+    mut_graphic_novel_theme_repository,
+    mut_graphic_novel_involved_repository,
+    mut_graphic_novel_publisher_repository,
+    mut_graphic_novel_volume_repository,
+    mut_user_average_repository,
+    graphic_novel_repository,
+  )
+}
+
+pub fn get_graphic_novel_volume_service<'a>(
+  graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+) -> impl GraphicNovelVolumeService + 'a {
+  DefaultGraphicNovelVolumeService::new(graphic_novel_volume_repository)
+}
+
+pub fn get_graphic_novel_volume_repository(
+  client: &CachedClient,
+  language: Language,
+) -> impl GraphicNovelVolumeRepository + '_ {
+  DefaultGraphicNovelVolumeRepository::new(client, language)
+}
+
+pub fn get_mut_graphic_novel_volume_service<'a>(
+  language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+  mut_graphic_novel_volume_repository: Arc<dyn MutGraphicNovelVolumeRepository + 'a>,
+) -> impl MutGraphicNovelVolumeService + 'a {
+  DefaultMutGraphicNovelVolumeService::new(
+    language,
+    graphic_novel_repository,
+    graphic_novel_volume_repository,
+    mut_graphic_novel_volume_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_volume_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+  mut_graphic_novel_chapter_repository: Arc<dyn MutGraphicNovelChapterRepository + 'a>,
+) -> impl MutGraphicNovelVolumeRepository + 'a {
+  DefaultMutGraphicNovelVolumeRepository::new(transaction, mut_graphic_novel_chapter_repository)
+}
+
+pub fn get_graphic_novel_chapter_service<'a>(
+  graphic_novel_chapter_repository: Arc<dyn GraphicNovelChapterRepository + 'a>,
+) -> impl GraphicNovelChapterService + 'a {
+  DefaultGraphicNovelChapterService::new(graphic_novel_chapter_repository)
+}
+
+pub fn get_graphic_novel_chapter_repository(
+  client: &CachedClient,
+  language: Language,
+) -> impl GraphicNovelChapterRepository + '_ {
+  DefaultGraphicNovelChapterRepository::new(client, language)
+}
+
+pub fn get_mut_graphic_novel_chapter_service<'a>(
+  language: Language,
+  graphic_novel_volume_repository: Arc<dyn GraphicNovelVolumeRepository + 'a>,
+  graphic_novel_chapter_repository: Arc<dyn GraphicNovelChapterRepository + 'a>,
+  mut_graphic_novel_chapter_repository: Arc<dyn MutGraphicNovelChapterRepository + 'a>,
+) -> impl MutGraphicNovelChapterService + 'a {
+  DefaultMutGraphicNovelChapterService::new(
+    language,
+    graphic_novel_volume_repository,
+    graphic_novel_chapter_repository,
+    mut_graphic_novel_chapter_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_chapter_repository<'a>(
+  transaction: &'a CachedTransaction<'a>,
+) -> impl MutGraphicNovelChapterRepository + 'a {
+  DefaultMutGraphicNovelChapterRepository::new(transaction)
+}
+
+pub fn get_graphic_novel_genre_service<'a>(
+  graphic_novel_genre_repository: Arc<dyn GraphicNovelGenreRepository + 'a>,
+) -> impl GraphicNovelGenreService + 'a {
+  DefaultGraphicNovelGenreService::new(graphic_novel_genre_repository)
+}
+
+pub fn get_graphic_novel_genre_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+) -> impl GraphicNovelGenreRepository + 'a {
+  DefaultGraphicNovelGenreRepository::new(client, language, graphic_novel_repository, genre_repository)
+}
+
+pub fn get_graphic_novel_theme_service<'a>(
+  graphic_novel_theme_repository: Arc<dyn GraphicNovelThemeRepository + 'a>,
+) -> impl GraphicNovelThemeService + 'a {
+  DefaultGraphicNovelThemeService::new(graphic_novel_theme_repository)
+}
+
+pub fn get_graphic_novel_theme_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+) -> impl GraphicNovelThemeRepository + 'a {
+  DefaultGraphicNovelThemeRepository::new(client, language, graphic_novel_repository, theme_repository)
+}
+
+pub fn get_graphic_novel_character_service<'a>(
+  graphic_novel_character_repository: Arc<dyn GraphicNovelCharacterRepository + 'a>,
+) -> impl GraphicNovelCharacterService + 'a {
+  DefaultGraphicNovelCharacterService::new(graphic_novel_character_repository)
+}
+
+pub fn get_graphic_novel_character_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+) -> impl GraphicNovelCharacterRepository + 'a {
+  DefaultGraphicNovelCharacterRepository::new(client, language, graphic_novel_repository, character_repository)
+}
+
+pub fn get_mut_graphic_novel_theme_service<'a>(
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_theme_repository: Arc<dyn GraphicNovelThemeRepository + 'a>,
+  mut_graphic_novel_theme_repository: Arc<dyn MutGraphicNovelThemeRepository + 'a>,
+  theme_repository: Arc<dyn ThemeRepository + 'a>,
+) -> impl MutGraphicNovelThemeService + 'a {
+  DefaultMutGraphicNovelThemeService::new(
+    graphic_novel_repository,
+    graphic_novel_theme_repository,
+    mut_graphic_novel_theme_repository,
+    theme_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_theme_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutGraphicNovelThemeRepository + 'a {
+  DefaultMutGraphicNovelThemeRepository::new(transaction)
+}
+
+pub fn get_mut_graphic_novel_genre_service<'a>(
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_genre_repository: Arc<dyn GraphicNovelGenreRepository + 'a>,
+  mut_graphic_novel_genre_repository: Arc<dyn MutGraphicNovelGenreRepository + 'a>,
+  genre_repository: Arc<dyn GenreRepository + 'a>,
+) -> impl MutGraphicNovelGenreService + 'a {
+  DefaultMutGraphicNovelGenreService::new(
+    graphic_novel_repository,
+    graphic_novel_genre_repository,
+    mut_graphic_novel_genre_repository,
+    genre_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_genre_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutGraphicNovelGenreRepository + 'a {
+  DefaultMutGraphicNovelGenreRepository::new(transaction)
+}
+
+pub fn get_mut_graphic_novel_character_service<'a>(
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_character_repository: Arc<dyn GraphicNovelCharacterRepository + 'a>,
+  mut_graphic_novel_character_repository: Arc<dyn MutGraphicNovelCharacterRepository + 'a>,
+  character_repository: Arc<dyn CharacterRepository + 'a>,
+) -> impl MutGraphicNovelCharacterService + 'a {
+  DefaultMutGraphicNovelCharacterService::new(
+    graphic_novel_repository,
+    graphic_novel_character_repository,
+    mut_graphic_novel_character_repository,
+    character_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_character_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutGraphicNovelCharacterRepository + 'a {
+  DefaultMutGraphicNovelCharacterRepository::new(transaction)
+}
+
+pub fn get_graphic_novel_involved_service<'a>(
+  graphic_novel_involved_repository: Arc<dyn GraphicNovelInvolvedRepository + 'a>,
+) -> impl GraphicNovelInvolvedService + 'a {
+  DefaultGraphicNovelInvolvedService::new(graphic_novel_involved_repository)
+}
+
+pub fn get_graphic_novel_involved_repository<'a>(
+  client: &'a CachedClient,
+  language: Language,
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl GraphicNovelInvolvedRepository + 'a {
+  DefaultGraphicNovelInvolvedRepository::new(
+    client,
+    language,
+    graphic_novel_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_involved_service<'a>(
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_involved_repository: Arc<dyn GraphicNovelInvolvedRepository + 'a>,
+  mut_graphic_novel_involved_repository: Arc<dyn MutGraphicNovelInvolvedRepository + 'a>,
+  person_repository: Arc<dyn PersonRepository + 'a>,
+  role_repository: Arc<dyn RoleRepository + 'a>,
+) -> impl MutGraphicNovelInvolvedService + 'a {
+  DefaultMutGraphicNovelInvolvedService::new(
+    graphic_novel_repository,
+    graphic_novel_involved_repository,
+    mut_graphic_novel_involved_repository,
+    person_repository,
+    role_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_involved_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutGraphicNovelInvolvedRepository + 'a {
+  DefaultMutGraphicNovelInvolvedRepository::new(transaction)
+}
+
+pub fn get_graphic_novel_publisher_service<'a>(
+  graphic_novel_publisher_repository: Arc<dyn GraphicNovelPublisherRepository + 'a>,
+) -> impl GraphicNovelPublisherService + 'a {
+  DefaultGraphicNovelPublisherService::new(graphic_novel_publisher_repository)
+}
+
+pub fn get_graphic_novel_publisher_repository<'a>(
+  client: &'a CachedClient,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+) -> impl GraphicNovelPublisherRepository + 'a {
+  DefaultGraphicNovelPublisherRepository::new(client, company_repository)
+}
+
+pub fn get_mut_graphic_novel_publisher_service<'a>(
+  graphic_novel_repository: Arc<dyn GraphicNovelRepository + 'a>,
+  graphic_novel_publisher_repository: Arc<dyn GraphicNovelPublisherRepository + 'a>,
+  mut_graphic_novel_publisher_repository: Arc<dyn MutGraphicNovelPublisherRepository + 'a>,
+  company_repository: Arc<dyn CompanyRepository + 'a>,
+) -> impl MutGraphicNovelPublisherService + 'a {
+  DefaultMutGraphicNovelPublisherService::new(
+    graphic_novel_repository,
+    graphic_novel_publisher_repository,
+    mut_graphic_novel_publisher_repository,
+    company_repository,
+  )
+}
+
+pub fn get_mut_graphic_novel_publisher_repository<'a>(
+  transaction: &'a CachedTransaction,
+) -> impl MutGraphicNovelPublisherRepository + 'a {
+  DefaultMutGraphicNovelPublisherRepository::new(transaction)
+}
@@ -1,16 +1,22 @@
-use axum::http::header::CONTENT_TYPE;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
 use std::env;
 use std::error::Error;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
 
+use application::cached_connection::CachedConnectionManager;
 use axum::http::Method;
-use bb8_postgres::bb8::{ManageConnection, Pool};
-use bb8_postgres::PostgresConnectionManager;
+use bb8_postgres::bb8::Pool;
+use chrono::Duration;
 use dotenvy::dotenv;
-use tokio_postgres::NoTls;
+use infrastructure::services::account_service::default_login_throttle_service::{LoginThrottleConfig, ThrottlePolicy};
+use repositories::mail_sender::MailSender;
 use tower_http::cors::{Any, CorsLayer};
 
 use crate::app_state::AppState;
-use crate::controllers::route_controllers;
+use crate::controllers::{route_controllers, seed_admin};
+use crate::implementations::{get_file_mail_sender, get_login_throttle_service, get_smtp_mail_sender};
 
 mod app_state;
 pub mod controllers;
@@ -21,16 +27,19 @@ pub async fn main() -> Result<(), Box<dyn Error>> {
   dotenv().ok();
   dotenvy::from_path_override(".local/.env").ok();
   let database_url = env::var("DATABASE_URL").map_err(|_| "DATABASE_URL must be set")?;
-  //let server_url = env::var("SERVER_URL").expect("SERVER_URL must be set");
+  let frontend_url = env::var("FRONTEND_URL").map_err(|_| "FRONTEND_URL must be set")?;
   let content_path = env::var("CONTENT_PATH").map_err(|_| "CONTENT_PATH must be set")?;
   let content_display_path = env::var("CONTENT_DISPLAY_PATH").map_err(|_| "CONTENT_DISPLAY_PATH must be set")?;
   let secret = env::var("SECRET").map_err(|_| "SECRET must be set")?;
+  let admin_email = env::var("ADMIN_EMAIL").ok();
+  let mail_sender = mail_sender()?;
+  let login_throttle_service = Arc::new(get_login_throttle_service(login_throttle_config()?));
 
   let pool = connection_pool(&database_url).await?;
 
   let cors = CorsLayer::new()
-    .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE])
-    .allow_headers([CONTENT_TYPE])
+    .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+    .allow_headers([AUTHORIZATION, CONTENT_TYPE])
     .allow_origin(Any);
 
   let app_state = AppState {
@@ -38,19 +47,61 @@ pub async fn main() -> Result<(), Box<dyn Error>> {
     display_path: content_display_path,
     content_path,
     secret,
+    frontend_url,
+    mail_sender,
+    login_throttle_service,
   };
 
+  if let Some(admin_email) = admin_email {
+    seed_admin(&app_state, &admin_email).await?;
+  }
+
   let app = route_controllers(app_state).layer(cors);
 
   let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
   println!("Server listening on port 3000!");
-  axum::serve(listener, app).await?;
+  axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
   Ok(())
 }
 
-async fn connection_pool(
-  database_url: &str,
-) -> Result<Pool<PostgresConnectionManager<NoTls>>, <PostgresConnectionManager<NoTls> as ManageConnection>::Error> {
-  let manager = PostgresConnectionManager::new_from_stringlike(database_url, NoTls)?;
+async fn connection_pool(database_url: &str) -> Result<Pool<CachedConnectionManager>, tokio_postgres::Error> {
+  let manager = CachedConnectionManager::new_from_stringlike(database_url)?;
   Pool::builder().build(manager).await
 }
+
+/// Sends mails over SMTP if a host is configured, otherwise writes them into `MAIL_PATH` for local development.
+fn mail_sender() -> Result<Arc<dyn MailSender>, Box<dyn Error>> {
+  let Ok(host) = env::var("SMTP_HOST") else {
+    let directory = env::var("MAIL_PATH").unwrap_or_else(|_| "mail".to_string());
+    return Ok(Arc::new(get_file_mail_sender(directory)));
+  };
+  let username = env::var("SMTP_USERNAME").map_err(|_| "SMTP_USERNAME must be set if SMTP_HOST is")?;
+  let password = env::var("SMTP_PASSWORD").map_err(|_| "SMTP_PASSWORD must be set if SMTP_HOST is")?;
+  let from = env::var("MAIL_FROM").map_err(|_| "MAIL_FROM must be set if SMTP_HOST is")?;
+  Ok(Arc::new(get_smtp_mail_sender(&host, username, password, &from)?))
+}
+
+/// Starts from the default thresholds and overrides the ones set through `LOGIN_EMAIL_*` and `LOGIN_IP_*`.
+fn login_throttle_config() -> Result<LoginThrottleConfig, Box<dyn Error>> {
+  let default = LoginThrottleConfig::default();
+  Ok(LoginThrottleConfig {
+    email: throttle_policy("LOGIN_EMAIL", default.email)?,
+    ip: throttle_policy("LOGIN_IP", default.ip)?,
+  })
+}
+
+fn throttle_policy(prefix: &str, default: ThrottlePolicy) -> Result<ThrottlePolicy, Box<dyn Error>> {
+  Ok(ThrottlePolicy {
+    free_attempts: env_number(&format!("{prefix}_FREE_ATTEMPTS"))?.unwrap_or(default.free_attempts),
+    base_delay: env_number(&format!("{prefix}_BASE_DELAY_SECONDS"))?.map_or(default.base_delay, Duration::seconds),
+    lockout_attempts: env_number(&format!("{prefix}_LOCKOUT_ATTEMPTS"))?.unwrap_or(default.lockout_attempts),
+    lockout: env_number(&format!("{prefix}_LOCKOUT_SECONDS"))?.map_or(default.lockout, Duration::seconds),
+  })
+}
+
+fn env_number<T: FromStr>(name: &str) -> Result<Option<T>, Box<dyn Error>> {
+  let Ok(value) = env::var(name) else {
+    return Ok(None);
+  };
+  Ok(Some(value.parse().map_err(|_| format!("{name} must be a number"))?))
+}
@@ -2,6 +2,6 @@
 #[into_params(names("Authorization"), parameter_in = Header)]
 //TODO: Fix
 pub struct JsonWebTokenParam(
-  ///JWT
+  ///JWT or personal access token in the form `Bearer <token>`
   String,
 );
@@ -0,0 +1,8 @@
+use domain::isbn13::Isbn13;
+
+#[derive(utoipa::IntoParams)]
+#[into_params(names("isbn"))]
+pub struct Isbn13Param(
+  ///ISBN-13 of the edition to search for
+  Isbn13,
+);
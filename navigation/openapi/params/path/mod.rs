@@ -1,4 +1,5 @@
 pub mod id;
+pub mod isbn13;
 pub mod name;
 pub mod slug;
 pub mod title;
@@ -1,2 +1,3 @@
 pub mod count;
 pub mod page;
+pub mod user_status;
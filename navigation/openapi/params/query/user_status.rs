@@ -0,0 +1,9 @@
+use domain::enums::user_status::UserStatus;
+
+#[derive(utoipa::IntoParams, serde::Deserialize)]
+#[into_params(names("status"), parameter_in = Query)]
+pub struct UserStatusParam(
+  ///Only return entries with this status
+  #[serde(default)]
+  Option<UserStatus>,
+);
@@ -3,3 +3,4 @@ pub mod forbidden;
 pub mod not_authorized;
 pub mod not_found;
 pub mod server_error;
+pub mod too_many_requests;
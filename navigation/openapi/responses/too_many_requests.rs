@@ -0,0 +1,3 @@
+#[derive(utoipa::IntoResponses)]
+#[response(status = 429, headers(("Retry-After" = u64, description = "Seconds until the next attempt is allowed")))]
+pub struct TooManyRequests(String);
@@ -6,7 +6,12 @@ use domain::entities::account::{Account, Email};
 use domain::items_total::ItemsTotal;
 use domain::pagination::Pagination;
 
+pub mod email_verification_repository;
 pub mod mut_account_repository;
+pub mod password_reset_repository;
+pub mod personal_access_token_repository;
+pub mod refresh_token_repository;
+pub mod two_factor_repository;
 
 #[async_trait]
 pub trait AccountRepository: Send + Sync {
@@ -0,0 +1,12 @@
+pub mod mut_email_verification_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::email_verification::EmailVerification;
+
+#[async_trait]
+pub trait EmailVerificationRepository: Send + Sync {
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<EmailVerification>, Box<dyn Error>>;
+}
@@ -3,9 +3,16 @@ use std::error::Error;
 use async_trait::async_trait;
 
 use domain::entities::account::create_partial_account::CreatePartialAccount;
-use domain::entities::account::Account;
+use domain::entities::account::{Account, Password};
+use domain::enums::account_role::AccountRole;
 
 #[async_trait]
 pub trait MutAccountRepository: Send + Sync {
   async fn create(&self, account: CreatePartialAccount) -> Result<Account, Box<dyn Error>>;
+  /// Expects the already hashed password
+  async fn update_password(&self, user_id: u32, password: &Password) -> Result<(), Box<dyn Error>>;
+  async fn set_verified(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
+  async fn set_role(&self, user_id: u32, role: AccountRole) -> Result<(), Box<dyn Error>>;
+  /// Removes the credentials, role and all tokens of the account, the user itself is kept
+  async fn delete(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
 }
@@ -0,0 +1,12 @@
+pub mod mut_password_reset_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::password_reset::PasswordReset;
+
+#[async_trait]
+pub trait PasswordResetRepository: Send + Sync {
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<PasswordReset>, Box<dyn Error>>;
+}
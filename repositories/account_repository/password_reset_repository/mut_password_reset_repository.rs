@@ -0,0 +1,11 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_password_reset::CreatePasswordReset;
+
+#[async_trait]
+pub trait MutPasswordResetRepository: Send + Sync {
+  async fn create(&self, reset: CreatePasswordReset) -> Result<(), Box<dyn Error>>;
+  async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,13 @@
+pub mod mut_personal_access_token_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+
+#[async_trait]
+pub trait PersonalAccessTokenRepository: Send + Sync {
+  async fn get_by_user(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, Box<dyn Error>>;
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<PersonalAccessToken>, Box<dyn Error>>;
+}
@@ -0,0 +1,15 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_partial_personal_access_token::CreatePartialPersonalAccessToken;
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+
+#[async_trait]
+pub trait MutPersonalAccessTokenRepository: Send + Sync {
+  async fn create(&self, token: CreatePartialPersonalAccessToken) -> Result<PersonalAccessToken, Box<dyn Error>>;
+  /// Returns false if the user has no token with the id
+  async fn delete(&self, user_id: u32, id: u32) -> Result<bool, Box<dyn Error>>;
+  /// Sets the time the token was last used to now
+  async fn set_last_used(&self, id: u32) -> Result<(), Box<dyn Error>>;
+}
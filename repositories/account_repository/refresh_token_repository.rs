@@ -0,0 +1,14 @@
+pub mod mut_refresh_token_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::refresh_token::RefreshToken;
+
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+  async fn get_by_hash(&self, hash: &str) -> Result<Option<RefreshToken>, Box<dyn Error>>;
+  /// Returns the tokens that started a family, the expiry of a family is the one of its first token
+  async fn get_families(&self, user_id: u32) -> Result<Vec<RefreshToken>, Box<dyn Error>>;
+}
@@ -0,0 +1,15 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_refresh_token::CreateRefreshToken;
+use domain::entities::account::refresh_token::RefreshToken;
+
+#[async_trait]
+pub trait MutRefreshTokenRepository: Send + Sync {
+  async fn create(&self, token: CreateRefreshToken) -> Result<RefreshToken, Box<dyn Error>>;
+  /// Returns false if the token was already used, a token can only be marked once
+  async fn mark_used(&self, id: u32) -> Result<bool, Box<dyn Error>>;
+  async fn delete_families(&self, families: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn delete_by_user(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
+}
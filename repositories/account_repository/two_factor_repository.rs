@@ -0,0 +1,12 @@
+pub mod mut_two_factor_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::two_factor::TwoFactor;
+
+#[async_trait]
+pub trait TwoFactorRepository: Send + Sync {
+  async fn get(&self, user_id: u32) -> Result<Option<TwoFactor>, Box<dyn Error>>;
+}
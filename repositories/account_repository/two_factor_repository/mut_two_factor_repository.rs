@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_two_factor::CreateTwoFactor;
+
+#[async_trait]
+pub trait MutTwoFactorRepository: Send + Sync {
+  async fn create(&self, two_factor: CreateTwoFactor) -> Result<(), Box<dyn Error>>;
+  async fn confirm(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
+  async fn set_last_step(&self, user_id: u32, step: u64) -> Result<(), Box<dyn Error>>;
+  /// Deletes the second factor together with its recovery codes
+  async fn delete(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
+  /// Replaces all recovery codes of the user
+  async fn set_recovery_codes(&self, user_id: u32, hashes: &[String]) -> Result<(), Box<dyn Error>>;
+  /// Returns false if the user has no recovery code with the hash
+  async fn delete_recovery_code(&self, user_id: u32, hash: &str) -> Result<bool, Box<dyn Error>>;
+}
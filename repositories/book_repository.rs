@@ -8,8 +8,12 @@ use domain::slug::Slug;
 use std::error::Error;
 
 pub mod book_character_repository;
+pub mod book_edition_involved_repository;
+pub mod book_edition_repository;
 pub mod book_genre_repository;
+pub mod book_image_repository;
 pub mod book_involved_repository;
+pub mod book_publisher_repository;
 pub mod book_theme_repository;
 pub mod mut_book_repository;
 
@@ -0,0 +1,22 @@
+pub mod mut_book_edition_involved_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition_involved::BookEditionInvolved;
+use domain::entities::involved::InvolvedId;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait BookEditionInvolvedRepository: Send + Sync {
+  async fn get(
+    &self,
+    edition_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEditionInvolved>, Box<dyn Error>>;
+  async fn filter_existing(&self, edition_id: u32, involved: &[InvolvedId]) -> Result<Vec<InvolvedId>, Box<dyn Error>>;
+}
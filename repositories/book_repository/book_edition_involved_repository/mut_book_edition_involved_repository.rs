@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use domain::entities::involved::InvolvedId;
+
+use std::error::Error;
+
+#[async_trait]
+pub trait MutBookEditionInvolvedRepository: Send + Sync {
+  async fn add(&self, edition_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>>;
+  async fn remove(&self, edition_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>>;
+  async fn remove_all(&self, edition_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
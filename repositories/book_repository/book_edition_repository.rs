@@ -0,0 +1,31 @@
+pub mod mut_book_edition_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::enums::language::Language;
+use domain::isbn13::Isbn13;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait BookEditionRepository: Send + Sync {
+  async fn get(
+    &self,
+    book_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEdition>, Box<dyn Error>>;
+  async fn get_by_id(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    language: Language,
+  ) -> Result<Option<BookEdition>, Box<dyn Error>>;
+  async fn get_by_isbn13(&self, isbn13: &Isbn13, language: Language) -> Result<Option<BookEdition>, Box<dyn Error>>;
+  async fn filter_existing(&self, book_id: u32, edition_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+  async fn filter_existing_isbn13(&self, isbn13: &[Isbn13]) -> Result<Vec<Isbn13>, Box<dyn Error>>;
+  async fn get_max_progress(&self, book_id: u32) -> Result<(Option<u16>, Option<u16>), Box<dyn Error>>;
+}
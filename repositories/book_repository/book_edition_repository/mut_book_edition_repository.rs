@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use domain::entities::book::book_edition::BookEdition;
+use domain::entities::book::create_partial_book_edition::CreatePartialBookEdition;
+use domain::entities::book::patch_book_edition::PatchBookEdition;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutBookEditionRepository: Send + Sync {
+  async fn create(&self, book_id: u32, item: CreatePartialBookEdition) -> Result<BookEdition, Box<dyn Error>>;
+  async fn update(&self, book_id: u32, id: u32, item: &PatchBookEdition) -> Result<BookEdition, Box<dyn Error>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove_all(&self, book_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
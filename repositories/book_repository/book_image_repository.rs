@@ -0,0 +1,16 @@
+pub mod mut_book_image_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_image::BookImage;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait BookImageRepository: Send + Sync {
+  async fn get(&self, book_id: u32, pagination: Pagination) -> Result<ItemsTotal<BookImage>, Box<dyn Error>>;
+  async fn get_ids(&self, book_id: u32) -> Result<Vec<u32>, Box<dyn Error>>;
+  async fn filter_existing(&self, book_id: u32, images: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
@@ -6,5 +6,6 @@ use std::error::Error;
 #[async_trait]
 pub trait MutBookRepository: Send + Sync {
   async fn create(&self, item: CreatePartialBook) -> Result<Book, Box<dyn Error>>;
+  /// Also removes the books from the libraries of all users and recomputes their averages
   async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
 }
@@ -0,0 +1,18 @@
+pub mod mut_company_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait CompanyRepository: Send + Sync {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>>;
+  async fn get_by_id(&self, id: u32) -> Result<Option<Company>, Box<dyn Error>>;
+  async fn get_by_ids(&self, ids: &[u32]) -> Result<Vec<Company>, Box<dyn Error>>;
+  async fn get_by_name(&self, name: &str, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>>;
+  async fn filter_existing(&self, companies: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
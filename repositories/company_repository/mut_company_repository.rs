@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::company::create_partial_company::CreatePartialCompany;
+use domain::entities::company::Company;
+
+#[async_trait]
+pub trait MutCompanyRepository: Send + Sync {
+  async fn create(&self, item: CreatePartialCompany) -> Result<Company, Box<dyn Error>>;
+  /// Also removes the companies as publishers, the logos are kept
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
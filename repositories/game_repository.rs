@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use domain::entities::game::game_statistic::GameStatistic;
+use domain::entities::game::Game;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use std::error::Error;
+
+pub mod game_character_repository;
+pub mod game_genre_repository;
+pub mod game_involved_repository;
+pub mod game_platform_repository;
+pub mod game_theme_repository;
+pub mod mut_game_repository;
+
+#[async_trait]
+pub trait GameRepository: Send + Sync {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<Game>, Box<dyn Error>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Game>, Box<dyn Error>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Game>, Box<dyn Error>>;
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<Game>, Box<dyn Error>>;
+
+  async fn filter_existing(&self, game_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+
+  async fn get_statistics(&self, game_ids: &[u32]) -> Result<Vec<GameStatistic>, Box<dyn Error>>;
+}
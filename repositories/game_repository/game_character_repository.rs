@@ -0,0 +1,21 @@
+pub mod mut_game_character_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_character::GameCharacter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait GameCharacterRepository: Send + Sync {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameCharacter>, Box<dyn Error>>;
+  async fn filter_existing(&self, game_id: u32, characters: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
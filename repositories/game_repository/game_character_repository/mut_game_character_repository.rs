@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutGameCharacterRepository: Send + Sync {
+  async fn add(&self, game_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove(&self, game_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>>;
+
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
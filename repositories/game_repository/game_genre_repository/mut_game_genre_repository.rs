@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutGameGenreRepository: Send + Sync {
+  async fn add(&self, game_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove(&self, game_id: u32, genres: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,22 @@
+pub mod mut_game_involved_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_involved::GameInvolved;
+use domain::entities::involved::InvolvedId;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait GameInvolvedRepository: Send + Sync {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameInvolved>, Box<dyn Error>>;
+  async fn filter_existing(&self, game_id: u32, involved: &[InvolvedId]) -> Result<Vec<InvolvedId>, Box<dyn Error>>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use domain::entities::involved::InvolvedId;
+
+use std::error::Error;
+
+#[async_trait]
+pub trait MutGameInvolvedRepository: Send + Sync {
+  async fn add(&self, game_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>>;
+  async fn remove(&self, game_id: u32, involved: &[InvolvedId]) -> Result<(), Box<dyn Error>>;
+  async fn remove_all(&self, game_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,15 @@
+pub mod mut_game_platform_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait GamePlatformRepository: Send + Sync {
+  async fn get(&self, game_id: u32, pagination: Pagination) -> Result<ItemsTotal<Platform>, Box<dyn Error>>;
+  async fn filter_existing(&self, game_id: u32, platforms: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use domain::entities::game::create_partial_game::CreatePartialGame;
+use domain::entities::game::Game;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutGameRepository: Send + Sync {
+  async fn create(&self, item: CreatePartialGame) -> Result<Game, Box<dyn Error>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use std::error::Error;
+
+pub mod graphic_novel_chapter_repository;
+pub mod graphic_novel_character_repository;
+pub mod graphic_novel_genre_repository;
+pub mod graphic_novel_involved_repository;
+pub mod graphic_novel_publisher_repository;
+pub mod graphic_novel_theme_repository;
+pub mod graphic_novel_volume_repository;
+pub mod mut_graphic_novel_repository;
+
+#[async_trait]
+pub trait GraphicNovelRepository: Send + Sync {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<GraphicNovel>, Box<dyn Error>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<GraphicNovel>, Box<dyn Error>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovel>, Box<dyn Error>>;
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<GraphicNovel>, Box<dyn Error>>;
+
+  async fn filter_existing(&self, graphic_novel_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
@@ -0,0 +1,30 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod mut_graphic_novel_chapter_repository;
+
+#[async_trait]
+pub trait GraphicNovelChapterRepository: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelChapter>, Box<dyn Error>>;
+  async fn get_by_chapter(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    chapter: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelChapter>, Box<dyn Error>>;
+  /// Chapters are numbered across the whole graphic novel, so the volume is not part of the check
+  async fn exists(&self, graphic_novel_id: u32, chapter: u16) -> Result<bool, Box<dyn Error>>;
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapter;
+
+#[async_trait]
+pub trait MutGraphicNovelChapterRepository: Send + Sync {
+  async fn create(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    item: CreateGraphicNovelChapter,
+  ) -> Result<(), Box<dyn Error>>;
+  async fn delete(&self, graphic_novel_id: u32, chapter: u16) -> Result<(), Box<dyn Error>>;
+  async fn delete_by_volume(&self, graphic_novel_id: u32, volume: u16) -> Result<(), Box<dyn Error>>;
+  async fn delete_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutGraphicNovelCharacterRepository: Send + Sync {
+  async fn add(&self, graphic_novel_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove(&self, graphic_novel_id: u32, characters: &[u32]) -> Result<(), Box<dyn Error>>;
+
+  async fn remove_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,26 @@
+pub mod mut_graphic_novel_involved_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_involved::GraphicNovelInvolved;
+use domain::entities::involved::InvolvedId;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait GraphicNovelInvolvedRepository: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelInvolved>, Box<dyn Error>>;
+  async fn filter_existing(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<Vec<InvolvedId>, Box<dyn Error>>;
+}
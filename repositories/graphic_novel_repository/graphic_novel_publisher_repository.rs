@@ -0,0 +1,15 @@
+pub mod mut_graphic_novel_publisher_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait GraphicNovelPublisherRepository: Send + Sync {
+  async fn get(&self, graphic_novel_id: u32, pagination: Pagination) -> Result<ItemsTotal<Company>, Box<dyn Error>>;
+  async fn filter_existing(&self, graphic_novel_id: u32, publishers: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
@@ -0,0 +1,27 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod mut_graphic_novel_volume_repository;
+
+#[async_trait]
+pub trait GraphicNovelVolumeRepository: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelVolume>, Box<dyn Error>>;
+  async fn get_by_volume(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelVolume>, Box<dyn Error>>;
+  async fn exists(&self, graphic_novel_id: u32, volume: u16) -> Result<bool, Box<dyn Error>>;
+}
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolume;
+
+#[async_trait]
+pub trait MutGraphicNovelVolumeRepository: Send + Sync {
+  async fn create(&self, graphic_novel_id: u32, item: CreateGraphicNovelVolume) -> Result<(), Box<dyn Error>>;
+  /// Also deletes the chapters of the volume
+  async fn delete(&self, graphic_novel_id: u32, volume: u16) -> Result<(), Box<dyn Error>>;
+  async fn delete_all(&self, graphic_novel_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
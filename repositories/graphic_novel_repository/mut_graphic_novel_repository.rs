@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use domain::entities::graphic_novel::create_partial_graphic_novel::CreatePartialGraphicNovel;
+use domain::entities::graphic_novel::GraphicNovel;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutGraphicNovelRepository: Send + Sync {
+  async fn create(&self, item: CreatePartialGraphicNovel) -> Result<GraphicNovel, Box<dyn Error>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
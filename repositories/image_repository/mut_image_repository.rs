@@ -8,4 +8,6 @@ use domain::entities::image::Image;
 #[async_trait]
 pub trait MutImageRepository: Send + Sync {
   async fn create(&self, image: CreatePartialImage<'_>) -> Result<Image, Box<dyn Error>>;
+  /// Only removes the rows, the files of the versions are left untouched
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
 }
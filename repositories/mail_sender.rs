@@ -0,0 +1,10 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::mail::Mail;
+
+#[async_trait]
+pub trait MailSender: Send + Sync {
+  async fn send(&self, mail: &Mail) -> Result<(), Box<dyn Error>>;
+}
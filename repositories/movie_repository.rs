@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use domain::entities::movie::movie_statistic::MovieStatistic;
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use std::error::Error;
+
+pub mod movie_genre_repository;
+pub mod movie_involved_repository;
+pub mod movie_theme_repository;
+pub mod mut_movie_repository;
+
+#[async_trait]
+pub trait MovieRepository: Send + Sync {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<Movie>, Box<dyn Error>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Movie>, Box<dyn Error>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Movie>, Box<dyn Error>>;
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<Movie>, Box<dyn Error>>;
+
+  async fn filter_existing(&self, movie_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+
+  async fn get_statistics(&self, movie_ids: &[u32]) -> Result<Vec<MovieStatistic>, Box<dyn Error>>;
+}
@@ -0,0 +1,22 @@
+pub mod mut_movie_involved_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+use domain::entities::movie::movie_involved::MovieInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait MovieInvolvedRepository: Send + Sync {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<MovieInvolved>, Box<dyn Error>>;
+  async fn filter_existing(&self, movie_id: u32, involved: &[InvolvedId]) -> Result<Vec<InvolvedId>, Box<dyn Error>>;
+}
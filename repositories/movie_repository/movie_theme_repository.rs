@@ -0,0 +1,21 @@
+pub mod mut_movie_theme_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::theme::Theme;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait MovieThemeRepository: Send + Sync {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Theme>, Box<dyn Error>>;
+  async fn filter_existing(&self, movie_id: u32, themes: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutMovieThemeRepository: Send + Sync {
+  async fn add(&self, movie_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove(&self, movie_id: u32, themes: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove_all(&self, movie_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
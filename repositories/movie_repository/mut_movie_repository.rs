@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use domain::entities::movie::create_partial_movie::CreatePartialMovie;
+use domain::entities::movie::Movie;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutMovieRepository: Send + Sync {
+  async fn create(&self, item: CreatePartialMovie) -> Result<Movie, Box<dyn Error>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,20 @@
+pub mod mut_platform_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait PlatformRepository: Send + Sync {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Platform>, Box<dyn Error>>;
+  async fn get_by_id(&self, id: u32) -> Result<Option<Platform>, Box<dyn Error>>;
+  async fn get_by_ids(&self, ids: &[u32]) -> Result<Vec<Platform>, Box<dyn Error>>;
+  async fn get_by_name(&self, name: &str, pagination: Pagination) -> Result<ItemsTotal<Platform>, Box<dyn Error>>;
+  async fn filter_existing(&self, platforms: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+  /// Returns the given companies which still have platforms
+  async fn filter_companies_with_platforms(&self, companies: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
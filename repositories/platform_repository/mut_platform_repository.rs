@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::platform::create_partial_platform::CreatePartialPlatform;
+use domain::entities::platform::Platform;
+
+#[async_trait]
+pub trait MutPlatformRepository: Send + Sync {
+  async fn create(&self, item: CreatePartialPlatform) -> Result<Platform, Box<dyn Error>>;
+  async fn update(&self, id: u32, item: CreatePartialPlatform) -> Result<Platform, Box<dyn Error>>;
+  /// Also removes the platforms from all games, the logos are kept
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+use std::error::Error;
+
+pub mod mut_show_repository;
+pub mod show_episode_repository;
+pub mod show_season_repository;
+
+#[async_trait]
+pub trait ShowRepository: Send + Sync {
+  async fn get(&self, language: Language, pagination: Pagination) -> Result<ItemsTotal<Show>, Box<dyn Error>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Show>, Box<dyn Error>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Show>, Box<dyn Error>>;
+  async fn get_by_ids(&self, ids: &[u32], language: Language) -> Result<Vec<Show>, Box<dyn Error>>;
+
+  async fn filter_existing(&self, show_ids: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
+}
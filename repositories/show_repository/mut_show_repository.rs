@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use domain::entities::show::create_partial_show::CreatePartialShow;
+use domain::entities::show::Show;
+use std::error::Error;
+
+#[async_trait]
+pub trait MutShowRepository: Send + Sync {
+  async fn create(&self, item: CreatePartialShow) -> Result<Show, Box<dyn Error>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,30 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod mut_show_episode_repository;
+
+#[async_trait]
+pub trait ShowEpisodeRepository: Send + Sync {
+  async fn get(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowEpisode>, Box<dyn Error>>;
+  async fn get_by_episode(
+    &self,
+    show_id: u32,
+    season: u16,
+    episode: u16,
+    language: Language,
+  ) -> Result<Option<ShowEpisode>, Box<dyn Error>>;
+  /// Episodes are numbered across the whole show, so the season is not part of the check
+  async fn exists(&self, show_id: u32, episode: u16) -> Result<bool, Box<dyn Error>>;
+}
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_episode::CreateShowEpisode;
+
+#[async_trait]
+pub trait MutShowEpisodeRepository: Send + Sync {
+  async fn create(&self, show_id: u32, season: u16, item: CreateShowEpisode) -> Result<(), Box<dyn Error>>;
+  async fn delete(&self, show_id: u32, episode: u16) -> Result<(), Box<dyn Error>>;
+  async fn delete_by_season(&self, show_id: u32, season: u16) -> Result<(), Box<dyn Error>>;
+  async fn delete_all(&self, show_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,27 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_season::ShowSeason;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod mut_show_season_repository;
+
+#[async_trait]
+pub trait ShowSeasonRepository: Send + Sync {
+  async fn get(
+    &self,
+    show_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowSeason>, Box<dyn Error>>;
+  async fn get_by_season(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+  ) -> Result<Option<ShowSeason>, Box<dyn Error>>;
+  async fn exists(&self, show_id: u32, season: u16) -> Result<bool, Box<dyn Error>>;
+}
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_season::CreateShowSeason;
+
+#[async_trait]
+pub trait MutShowSeasonRepository: Send + Sync {
+  async fn create(&self, show_id: u32, item: CreateShowSeason) -> Result<(), Box<dyn Error>>;
+  /// Also deletes the episodes of the season
+  async fn delete(&self, show_id: u32, season: u16) -> Result<(), Box<dyn Error>>;
+  async fn delete_all(&self, show_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -6,7 +6,10 @@ use domain::entities::user::User;
 use domain::items_total::ItemsTotal;
 use domain::pagination::Pagination;
 
+pub mod friendship_repository;
+pub mod mut_user_average_repository;
 pub mod mut_user_repository;
+pub mod user_book_repository;
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -14,6 +17,8 @@ pub trait UserRepository: Send + Sync {
   async fn get_by_id(&self, id: u32) -> Result<Option<User>, Box<dyn Error>>;
   async fn get_by_ids(&self, ids: &[u32]) -> Result<Vec<User>, Box<dyn Error>>;
   async fn get_by_name(&self, name: &str, pagination: Pagination) -> Result<ItemsTotal<User>, Box<dyn Error>>;
+  /// Returns the id of the user with exactly the name
+  async fn get_id_by_exact_name(&self, name: &str) -> Result<Option<u32>, Box<dyn Error>>;
 
   async fn filter_existing(&self, users: &[u32]) -> Result<Vec<u32>, Box<dyn Error>>;
 }
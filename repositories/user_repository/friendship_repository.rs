@@ -0,0 +1,17 @@
+pub mod mut_friendship_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::user::friendship::Friendship;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait FriendshipRepository: Send + Sync {
+  async fn get_friends(&self, user_id: u32, pagination: Pagination) -> Result<ItemsTotal<Friendship>, Box<dyn Error>>;
+  async fn get_incoming(&self, user_id: u32, pagination: Pagination) -> Result<ItemsTotal<Friendship>, Box<dyn Error>>;
+  async fn get_outgoing(&self, user_id: u32, pagination: Pagination) -> Result<ItemsTotal<Friendship>, Box<dyn Error>>;
+  async fn get_by_users(&self, user_id: u32, other_user_id: u32) -> Result<Option<Friendship>, Box<dyn Error>>;
+}
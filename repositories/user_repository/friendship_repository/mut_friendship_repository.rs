@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::enums::friendship_status::FriendshipStatus;
+
+#[async_trait]
+pub trait MutFriendshipRepository: Send + Sync {
+  /// Returns false if a friendship between the users already exists in either direction
+  async fn create(&self, user_id: u32, other_user_id: u32) -> Result<bool, Box<dyn Error>>;
+  /// `user_id` is the user who sent the request.
+  async fn set_status(&self, user_id: u32, other_user_id: u32, status: FriendshipStatus) -> Result<(), Box<dyn Error>>;
+  async fn delete(&self, user_id: u32, other_user_id: u32) -> Result<(), Box<dyn Error>>;
+}
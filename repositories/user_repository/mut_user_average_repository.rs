@@ -0,0 +1,10 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MutUserAverageRepository: Send + Sync {
+  async fn recompute(&self, user_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+  /// Returns the amount of users whose averages were recomputed
+  async fn recompute_all(&self) -> Result<usize, Box<dyn Error>>;
+}
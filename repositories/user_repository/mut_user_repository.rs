@@ -8,4 +8,8 @@ use domain::entities::user::User;
 #[async_trait]
 pub trait MutUserRepository: Send + Sync {
   async fn create(&self, user: CreatePartialUser) -> Result<User, Box<dyn Error>>;
+  /// Overwrites the name, description and profile picture of the user
+  async fn update(&self, user_id: u32, user: CreatePartialUser) -> Result<User, Box<dyn Error>>;
+  /// Marks the user as deleted and removes the name, description and profile picture from the row
+  async fn anonymize(&self, user_id: u32) -> Result<(), Box<dyn Error>>;
 }
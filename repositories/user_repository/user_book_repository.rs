@@ -0,0 +1,24 @@
+pub mod mut_user_book_repository;
+
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::user::user_book::UserBook;
+use domain::enums::language::Language;
+use domain::enums::user_status::UserStatus;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+#[async_trait]
+pub trait UserBookRepository: Send + Sync {
+  async fn get(
+    &self,
+    user_id: u32,
+    status: Option<UserStatus>,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<UserBook>, Box<dyn Error>>;
+  async fn get_by_id(&self, user_id: u32, book_id: u32, language: Language)
+    -> Result<Option<UserBook>, Box<dyn Error>>;
+}
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use domain::entities::user::create_user_book::CreateUserBook;
+
+#[async_trait]
+pub trait MutUserBookRepository: Send + Sync {
+  async fn create(&self, user_id: u32, book_id: u32, item: &CreateUserBook) -> Result<(), Box<dyn Error>>;
+  async fn update(&self, user_id: u32, book_id: u32, item: &CreateUserBook) -> Result<(), Box<dyn Error>>;
+  async fn delete(&self, user_id: u32, book_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+  async fn remove_all(&self, book_ids: &[u32]) -> Result<(), Box<dyn Error>>;
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
 
 use domain::entities::account::{Account, Email, Password};
 use domain::items_total::ItemsTotal;
@@ -7,20 +8,34 @@ use domain::pagination::Pagination;
 
 use crate::traits::service_error::ServiceError;
 
+pub mod account_export_service;
+pub mod login_throttle_service;
 pub mod mut_account_service;
+pub mod mut_password_service;
+pub mod mut_refresh_token_service;
+pub mod mut_verification_service;
+pub mod personal_access_token_service;
+pub mod two_factor_service;
 
 #[async_trait]
 pub trait AccountService: Send + Sync {
   async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Account>, ServiceError<AccountServiceError>>;
   async fn get_by_user_id(&self, id: u32) -> Result<Option<Account>, ServiceError<AccountServiceError>>;
   async fn get_by_email(&self, email: &Email) -> Result<Option<Account>, ServiceError<AccountServiceError>>;
-  async fn login(&self, email: &Email, password: &Password) -> Result<Account, ServiceError<AccountServiceError>>;
+  /// Failed attempts are throttled per email and, if given, per IP address
+  async fn login(
+    &self,
+    email: &Email,
+    password: &Password,
+    ip: Option<IpAddr>,
+  ) -> Result<Account, ServiceError<AccountServiceError>>;
 }
 
 pub enum AccountServiceError {
-  UnknownEmail,
+  InvalidCredentials,
   InvalidEmail,
-  WrongPassword,
+  /// Seconds until the next attempt is allowed
+  TooManyAttempts(u64),
   OtherError(Box<dyn Display>),
 }
 
@@ -30,9 +45,9 @@ impl Display for AccountServiceError {
       f,
       "{}",
       match self {
-        AccountServiceError::UnknownEmail => "Unknown email".to_string(),
+        AccountServiceError::InvalidCredentials => "Invalid email or password".to_string(),
         AccountServiceError::InvalidEmail => "Invalid email".to_string(),
-        AccountServiceError::WrongPassword => "Wrong password".to_string(),
+        AccountServiceError::TooManyAttempts(x) => format!("Too many failed login attempts, try again in {x} seconds"),
         AccountServiceError::OtherError(x) => x.to_string(),
       }
     )
@@ -0,0 +1,26 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::account::account_export::AccountExport;
+use domain::enums::language::Language;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait AccountExportService: Send + Sync {
+  /// Returns `None` if the account does not exist
+  async fn export(
+    &self,
+    user_id: u32,
+    language: Language,
+  ) -> Result<Option<AccountExport>, ServiceError<AccountExportServiceError>>;
+}
+
+pub enum AccountExportServiceError {}
+
+impl Display for AccountExportServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
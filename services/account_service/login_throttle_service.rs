@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+
+/// What failed login attempts are counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoginKey {
+  Email(String),
+  Ip(IpAddr),
+  /// Codes of the second factor of a user, throttled like emails
+  SecondFactor(u32),
+}
+
+#[async_trait]
+pub trait LoginThrottleService: Send + Sync {
+  /// Fails with `Locked` while one of the keys has to wait before the next attempt
+  async fn check(&self, keys: &[LoginKey]) -> Result<(), ServiceError<LoginThrottleServiceError>>;
+  async fn failed(&self, keys: &[LoginKey]) -> Result<(), ServiceError<LoginThrottleServiceError>>;
+  /// Forgets the failed attempts of the keys
+  async fn succeeded(&self, keys: &[LoginKey]) -> Result<(), ServiceError<LoginThrottleServiceError>>;
+}
+
+pub enum LoginThrottleServiceError {
+  /// Seconds until the next attempt is allowed
+  Locked(u64),
+}
+
+impl Display for LoginThrottleServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        LoginThrottleServiceError::Locked(x) => format!("Too many failed login attempts, try again in {x} seconds"),
+      }
+    )
+  }
+}
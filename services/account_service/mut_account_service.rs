@@ -3,19 +3,32 @@ use std::fmt::{Display, Formatter};
 use async_trait::async_trait;
 
 use domain::entities::account::create_account::CreateAccount;
-use domain::entities::account::Account;
+use domain::entities::account::{Account, Password};
+use domain::enums::account_role::AccountRole;
 
 use crate::traits::service_error::ServiceError;
 
 #[async_trait]
 pub trait MutAccountService: Send + Sync {
   async fn create(&self, account: CreateAccount) -> Result<Account, ServiceError<MutAccountServiceError>>;
+  async fn set_role(&self, user_id: u32, role: AccountRole) -> Result<Account, ServiceError<MutAccountServiceError>>;
+  /// Marks the email as verified without a verification token
+  async fn set_verified(&self, user_id: u32) -> Result<Account, ServiceError<MutAccountServiceError>>;
+  /// Removes the credentials after confirming the password and anonymizes the user.
+  /// Returns the files of the profile picture to delete once the transaction is committed
+  async fn delete(
+    &self,
+    user_id: u32,
+    password: &Password,
+  ) -> Result<Vec<String>, ServiceError<MutAccountServiceError>>;
 }
 
 pub enum MutAccountServiceError {
   EmailAlreadyExists,
   InvalidEmail,
   InvalidPassword,
+  NonExistentAccount,
+  WrongPassword,
   OtherError(Box<dyn Display>),
 }
 
@@ -28,6 +41,8 @@ impl Display for MutAccountServiceError {
         MutAccountServiceError::EmailAlreadyExists => "Account with the given email already exists".to_string(),
         MutAccountServiceError::InvalidEmail => "Invalid email".to_string(),
         MutAccountServiceError::InvalidPassword => "Invalid password".to_string(),
+        MutAccountServiceError::NonExistentAccount => "Account does not exist".to_string(),
+        MutAccountServiceError::WrongPassword => "Wrong password".to_string(),
         MutAccountServiceError::OtherError(x) => x.to_string(),
       }
     )
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::account::{Email, Password};
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutPasswordService: Send + Sync {
+  async fn change(
+    &self,
+    user_id: u32,
+    current_password: &Password,
+    new_password: &Password,
+  ) -> Result<(), ServiceError<MutPasswordServiceError>>;
+  /// Mails a reset token if an account with the email exists, succeeds either way so emails can not be probed
+  async fn request_reset(&self, email: &Email) -> Result<(), ServiceError<MutPasswordServiceError>>;
+  /// Sets the new password and revokes all refresh tokens of the account
+  async fn reset(&self, token: &str, new_password: &Password) -> Result<(), ServiceError<MutPasswordServiceError>>;
+}
+
+pub enum MutPasswordServiceError {
+  NonExistentAccount,
+  WrongPassword,
+  InvalidPassword,
+  InvalidToken,
+  ExpiredToken,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutPasswordServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutPasswordServiceError::NonExistentAccount => "Account does not exist".to_string(),
+        MutPasswordServiceError::WrongPassword => "Wrong password".to_string(),
+        MutPasswordServiceError::InvalidPassword => "Invalid password".to_string(),
+        MutPasswordServiceError::InvalidToken => "Invalid password reset token".to_string(),
+        MutPasswordServiceError::ExpiredToken => "Password reset token expired".to_string(),
+        MutPasswordServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutRefreshTokenService: Send + Sync {
+  /// Starts a new token family for the user, returns the opaque token
+  async fn create(&self, user_id: u32) -> Result<String, ServiceError<MutRefreshTokenServiceError>>;
+  /// Exchanges the token for a new one of the same family, returns the user and the new token.
+  /// Using a token twice revokes its whole family
+  async fn rotate(&self, token: &str) -> Result<(u32, String), ServiceError<MutRefreshTokenServiceError>>;
+  async fn revoke(&self, token: &str) -> Result<(), ServiceError<MutRefreshTokenServiceError>>;
+  async fn revoke_all(&self, user_id: u32) -> Result<(), ServiceError<MutRefreshTokenServiceError>>;
+  /// Revokes every family of the user except the one of the given token, all of them without a token
+  async fn revoke_others(
+    &self,
+    user_id: u32,
+    token: Option<&str>,
+  ) -> Result<(), ServiceError<MutRefreshTokenServiceError>>;
+}
+
+pub enum MutRefreshTokenServiceError {
+  InvalidToken,
+  ExpiredToken,
+  ReusedToken,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutRefreshTokenServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutRefreshTokenServiceError::InvalidToken => "Invalid refresh token".to_string(),
+        MutRefreshTokenServiceError::ExpiredToken => "Refresh token expired".to_string(),
+        MutRefreshTokenServiceError::ReusedToken =>
+          "Refresh token was already used, all tokens of its session were revoked".to_string(),
+        MutRefreshTokenServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutVerificationService: Send + Sync {
+  /// Mails a new verification token to the email of the account, earlier tokens become invalid
+  async fn send(&self, user_id: u32) -> Result<(), ServiceError<MutVerificationServiceError>>;
+  /// Marks the email of the account the token was sent to as verified, returns the id of its user
+  async fn verify(&self, token: &str) -> Result<u32, ServiceError<MutVerificationServiceError>>;
+}
+
+pub enum MutVerificationServiceError {
+  NonExistentAccount,
+  AlreadyVerified,
+  InvalidToken,
+  ExpiredToken,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutVerificationServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutVerificationServiceError::NonExistentAccount => "Account does not exist".to_string(),
+        MutVerificationServiceError::AlreadyVerified => "Email is already verified".to_string(),
+        MutVerificationServiceError::InvalidToken => "Invalid verification token".to_string(),
+        MutVerificationServiceError::ExpiredToken => "Verification token expired".to_string(),
+        MutVerificationServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
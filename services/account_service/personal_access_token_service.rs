@@ -0,0 +1,30 @@
+pub mod mut_personal_access_token_service;
+
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait PersonalAccessTokenService: Send + Sync {
+  async fn get(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, ServiceError<PersonalAccessTokenServiceError>>;
+}
+
+pub enum PersonalAccessTokenServiceError {
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for PersonalAccessTokenServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        PersonalAccessTokenServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,53 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::account::create_personal_access_token::CreatePersonalAccessToken;
+use domain::entities::account::personal_access_token::PersonalAccessToken;
+use domain::entities::account::Account;
+
+use crate::traits::service_error::ServiceError;
+
+/// Every personal access token starts with it, which tells them apart from JWTs
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "pat_";
+
+#[async_trait]
+pub trait MutPersonalAccessTokenService: Send + Sync {
+  /// Returns the created token and its secret, which can not be retrieved again
+  async fn create(
+    &self,
+    user_id: u32,
+    item: CreatePersonalAccessToken,
+  ) -> Result<(PersonalAccessToken, String), ServiceError<MutPersonalAccessTokenServiceError>>;
+  async fn revoke(&self, user_id: u32, id: u32) -> Result<(), ServiceError<MutPersonalAccessTokenServiceError>>;
+  /// Returns the token with the secret and the account it belongs to, and marks the token as used
+  async fn authenticate(
+    &self,
+    token: &str,
+  ) -> Result<(PersonalAccessToken, Account), ServiceError<MutPersonalAccessTokenServiceError>>;
+}
+
+pub enum MutPersonalAccessTokenServiceError {
+  InvalidName,
+  NoScopes,
+  NonExistentToken,
+  InvalidToken,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutPersonalAccessTokenServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutPersonalAccessTokenServiceError::InvalidName =>
+          "Token name has to be between 1 and 100 characters long".to_string(),
+        MutPersonalAccessTokenServiceError::NoScopes => "Token needs at least one scope".to_string(),
+        MutPersonalAccessTokenServiceError::NonExistentToken => "Personal access token does not exist".to_string(),
+        MutPersonalAccessTokenServiceError::InvalidToken => "Invalid personal access token".to_string(),
+        MutPersonalAccessTokenServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
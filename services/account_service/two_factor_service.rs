@@ -0,0 +1,29 @@
+pub mod mut_two_factor_service;
+
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait TwoFactorService: Send + Sync {
+  /// Whether logins of the user need a second factor
+  async fn is_enabled(&self, user_id: u32) -> Result<bool, ServiceError<TwoFactorServiceError>>;
+}
+
+pub enum TwoFactorServiceError {
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for TwoFactorServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        TwoFactorServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
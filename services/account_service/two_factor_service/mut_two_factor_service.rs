@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::account::two_factor_enrollment::TwoFactorEnrollment;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutTwoFactorService: Send + Sync {
+  /// Creates a new secret, an earlier unconfirmed one is replaced
+  async fn enroll(&self, user_id: u32) -> Result<TwoFactorEnrollment, ServiceError<MutTwoFactorServiceError>>;
+  /// Enables the second factor with a first code, returns the recovery codes
+  async fn confirm(&self, user_id: u32, code: &str) -> Result<Vec<String>, ServiceError<MutTwoFactorServiceError>>;
+  /// Requires a code or recovery code, since the session alone should not be enough to remove the second factor
+  async fn disable(&self, user_id: u32, code: &str) -> Result<(), ServiceError<MutTwoFactorServiceError>>;
+  /// Accepts a current code or a recovery code, which can not be used again.
+  /// Failed attempts are throttled per user
+  async fn verify(&self, user_id: u32, code: &str) -> Result<(), ServiceError<MutTwoFactorServiceError>>;
+}
+
+pub enum MutTwoFactorServiceError {
+  NonExistentAccount,
+  AlreadyEnabled,
+  NotEnrolled,
+  NotEnabled,
+  InvalidCode,
+  /// Seconds until the next attempt is allowed
+  TooManyAttempts(u64),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutTwoFactorServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutTwoFactorServiceError::NonExistentAccount => "Account does not exist".to_string(),
+        MutTwoFactorServiceError::AlreadyEnabled => "Second factor is already enabled".to_string(),
+        MutTwoFactorServiceError::NotEnrolled => "No second factor to confirm, enroll first".to_string(),
+        MutTwoFactorServiceError::NotEnabled => "Second factor is not enabled".to_string(),
+        MutTwoFactorServiceError::InvalidCode => "Invalid code".to_string(),
+        MutTwoFactorServiceError::TooManyAttempts(x) => format!("Too many invalid codes, try again in {x} seconds"),
+        MutTwoFactorServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
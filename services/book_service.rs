@@ -12,8 +12,12 @@ use domain::pagination::Pagination;
 use domain::slug::Slug;
 
 pub mod book_character_service;
+pub mod book_edition_involved_service;
+pub mod book_edition_service;
 pub mod book_genre_service;
+pub mod book_image_service;
 pub mod book_involved_service;
+pub mod book_publisher_service;
 pub mod book_theme_service;
 pub mod mut_book_service;
 
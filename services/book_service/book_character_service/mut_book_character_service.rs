@@ -13,7 +13,6 @@ pub trait MutBookCharacterService: Send + Sync {
 
 pub enum MutBookCharacterServiceError {
   NonExistentBook(u32),
-  AlreadyAssociated(Vec<u32>),
   NotAssociated(Vec<u32>),
   NonExistent(Vec<u32>),
   NoCharactersProvided,
@@ -27,10 +26,6 @@ impl Display for MutBookCharacterServiceError {
       "{}",
       match self {
         MutBookCharacterServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
-        MutBookCharacterServiceError::AlreadyAssociated(x) => format!(
-          "The following characters already have an association: [{}]",
-          x.join_comma()
-        ),
         MutBookCharacterServiceError::NotAssociated(x) => format!(
           "The following characters do not have an association: [{}]",
           x.join_comma()
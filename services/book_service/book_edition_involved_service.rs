@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition_involved::BookEditionInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_book_edition_involved_service;
+
+#[async_trait]
+pub trait BookEditionInvolvedService: Send + Sync {
+  async fn get(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEditionInvolved>, ServiceError<BookEditionInvolvedServiceError>>;
+}
+
+pub enum BookEditionInvolvedServiceError {
+  NonExistentEdition(u32),
+}
+
+impl Display for BookEditionInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        BookEditionInvolvedServiceError::NonExistentEdition(x) => format!("Edition with the id {x} does not exist"),
+      }
+    )
+  }
+}
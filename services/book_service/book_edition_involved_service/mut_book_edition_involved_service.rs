@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutBookEditionInvolvedService: Send + Sync {
+  async fn add(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>>;
+  async fn remove(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutBookEditionInvolvedServiceError>>;
+}
+
+pub enum MutBookEditionInvolvedServiceError {
+  NonExistentEdition(u32),
+  NonExistentAssociation(Vec<InvolvedId>),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  NoInvolvedProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutBookEditionInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutBookEditionInvolvedServiceError::NonExistentEdition(x) => format!("Edition with the id {x} does not exist"),
+        MutBookEditionInvolvedServiceError::NonExistentAssociation(x) => format!(
+          "The following people with roles do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutBookEditionInvolvedServiceError::NonExistentPeople(x) =>
+          format!("The following people do not exist: [{}]", x.join_comma()),
+        MutBookEditionInvolvedServiceError::NonExistentRoles(x) =>
+          format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutBookEditionInvolvedServiceError::NoInvolvedProvided => "No involved provided".to_string(),
+        MutBookEditionInvolvedServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
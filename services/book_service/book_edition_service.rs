@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::enums::language::Language;
+use domain::isbn13::Isbn13;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_book_edition_service;
+
+#[async_trait]
+pub trait BookEditionService: Send + Sync {
+  async fn get(
+    &self,
+    book_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookEdition>, ServiceError<BookEditionServiceError>>;
+  async fn get_by_id(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    language: Language,
+  ) -> Result<Option<BookEdition>, ServiceError<BookEditionServiceError>>;
+  async fn get_by_isbn13(
+    &self,
+    isbn13: &Isbn13,
+    language: Language,
+  ) -> Result<Option<BookEdition>, ServiceError<BookEditionServiceError>>;
+}
+
+pub enum BookEditionServiceError {}
+
+impl Display for BookEditionServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
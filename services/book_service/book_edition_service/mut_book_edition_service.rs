@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_edition::BookEdition;
+use domain::entities::book::create_book_edition::CreateBookEdition;
+use domain::entities::book::patch_book_edition::PatchBookEdition;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutBookEditionService: Send + Sync {
+  async fn create(
+    &self,
+    book_id: u32,
+    item: CreateBookEdition,
+  ) -> Result<BookEdition, ServiceError<MutBookEditionServiceError>>;
+  async fn update(
+    &self,
+    book_id: u32,
+    edition_id: u32,
+    item: PatchBookEdition,
+  ) -> Result<BookEdition, ServiceError<MutBookEditionServiceError>>;
+  async fn delete(&self, book_id: u32, edition_id: u32) -> Result<(), ServiceError<MutBookEditionServiceError>>;
+}
+
+pub enum MutBookEditionServiceError {
+  NonExistentBook(u32),
+  NonExistentEdition(u32),
+  NonExistentCover(usize),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  InvalidDescription(String),
+  AlreadyExistingIsbn13(String),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutBookEditionServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutBookEditionServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
+        MutBookEditionServiceError::NonExistentEdition(x) => format!("Edition with the id {x} does not exist"),
+        MutBookEditionServiceError::NonExistentCover(x) => format!("A cover with the index {x} does not exist"),
+        MutBookEditionServiceError::NonExistentPeople(x) =>
+          format!("The following people do not exist: [{}]", x.join_comma()),
+        MutBookEditionServiceError::NonExistentRoles(x) =>
+          format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutBookEditionServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutBookEditionServiceError::AlreadyExistingIsbn13(x) =>
+          format!("An edition with the following ISBN-13 already exists: {x}"),
+        MutBookEditionServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
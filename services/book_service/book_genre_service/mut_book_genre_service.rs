@@ -13,7 +13,6 @@ pub trait MutBookGenreService: Send + Sync {
 
 pub enum MutBookGenreServiceError {
   NonExistentBook(u32),
-  AlreadyAssociated(Vec<u32>),
   NotAssociated(Vec<u32>),
   NonExistent(Vec<u32>),
   NoGenresProvided,
@@ -27,8 +26,6 @@ impl Display for MutBookGenreServiceError {
       "{}",
       match self {
         MutBookGenreServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
-        MutBookGenreServiceError::AlreadyAssociated(x) =>
-          format!("The following genres already have an association: [{}]", x.join_comma()),
         MutBookGenreServiceError::NotAssociated(x) =>
           format!("The following genres do not have an association: [{}]", x.join_comma()),
         MutBookGenreServiceError::NonExistent(x) => format!("The following genres do not exist: [{}]", x.join_comma()),
@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_image::BookImage;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_book_image_service;
+
+#[async_trait]
+pub trait BookImageService: Send + Sync {
+  async fn get(
+    &self,
+    book_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<BookImage>, ServiceError<BookImageServiceError>>;
+}
+
+pub enum BookImageServiceError {}
+
+impl Display for BookImageServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::book::book_image::BookImage;
+use domain::entities::image::create_image::CreateImage;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutBookImageService: Send + Sync {
+  async fn add(
+    &self,
+    book_id: u32,
+    images: Vec<CreateImage>,
+  ) -> Result<Vec<BookImage>, ServiceError<MutBookImageServiceError>>;
+  async fn remove(&self, book_id: u32, images: &[u32]) -> Result<(), ServiceError<MutBookImageServiceError>>;
+  async fn reorder(&self, book_id: u32, images: &[u32]) -> Result<(), ServiceError<MutBookImageServiceError>>;
+}
+
+pub enum MutBookImageServiceError {
+  NonExistentBook(u32),
+  NonExistentImages(Vec<u32>),
+  InvalidOrder(Vec<u32>),
+  NoImagesProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutBookImageServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutBookImageServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
+        MutBookImageServiceError::NonExistentImages(x) =>
+          format!("The following images do not belong to the book: [{}]", x.join_comma()),
+        MutBookImageServiceError::InvalidOrder(x) => format!(
+          "The order has to contain each of the following images exactly once: [{}]",
+          x.join_comma()
+        ),
+        MutBookImageServiceError::NoImagesProvided => "No images provided".to_string(),
+        MutBookImageServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -19,7 +19,6 @@ pub trait MutBookInvolvedService: Send + Sync {
 
 pub enum MutBookInvolvedServiceError {
   NonExistentBook(u32),
-  AlreadyAssociated(Vec<InvolvedId>),
   NonExistentAssociation(Vec<InvolvedId>),
   NonExistentPeople(Vec<u32>),
   NonExistentRoles(Vec<u32>),
@@ -34,10 +33,6 @@ impl Display for MutBookInvolvedServiceError {
       "{}",
       match self {
         MutBookInvolvedServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
-        MutBookInvolvedServiceError::AlreadyAssociated(x) => format!(
-          "The following people with roles already have an association: [{}]",
-          x.join_comma()
-        ),
         MutBookInvolvedServiceError::NonExistentAssociation(x) => format!(
           "The following people with roles do not have an association: [{}]",
           x.join_comma()
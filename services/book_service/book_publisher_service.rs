@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_book_publisher_service;
+
+#[async_trait]
+pub trait BookPublisherService: Send + Sync {
+  async fn get(
+    &self,
+    book_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Company>, ServiceError<BookPublisherServiceError>>;
+}
+
+pub enum BookPublisherServiceError {}
+
+impl Display for BookPublisherServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutBookPublisherService: Send + Sync {
+  async fn add(&self, book_id: u32, publishers: &[u32]) -> Result<(), ServiceError<MutBookPublisherServiceError>>;
+  async fn remove(&self, book_id: u32, publishers: &[u32]) -> Result<(), ServiceError<MutBookPublisherServiceError>>;
+}
+
+pub enum MutBookPublisherServiceError {
+  NonExistentBook(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoPublishersProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutBookPublisherServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutBookPublisherServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
+        MutBookPublisherServiceError::NotAssociated(x) => format!(
+          "The following publishers do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutBookPublisherServiceError::NonExistent(x) =>
+          format!("The following publishers do not exist: [{}]", x.join_comma()),
+        MutBookPublisherServiceError::NoPublishersProvided => "No publishers provided".to_string(),
+        MutBookPublisherServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -13,7 +13,6 @@ pub trait MutBookThemeService: Send + Sync {
 
 pub enum MutBookThemeServiceError {
   NonExistentBook(u32),
-  AlreadyAssociated(Vec<u32>),
   NotAssociated(Vec<u32>),
   NonExistent(Vec<u32>),
   NoThemesProvided,
@@ -27,8 +26,6 @@ impl Display for MutBookThemeServiceError {
       "{}",
       match self {
         MutBookThemeServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
-        MutBookThemeServiceError::AlreadyAssociated(x) =>
-          format!("The following themes already have an association: [{}]", x.join_comma()),
         MutBookThemeServiceError::NotAssociated(x) =>
           format!("The following themes do not have an association: [{}]", x.join_comma()),
         MutBookThemeServiceError::NonExistent(x) => format!("The following themes do not exist: [{}]", x.join_comma()),
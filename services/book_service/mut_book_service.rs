@@ -9,6 +9,7 @@ use std::fmt::{Display, Formatter};
 #[async_trait]
 pub trait MutBookService: Send + Sync {
   async fn create(&self, item: CreateBook) -> Result<Book, ServiceError<MutBookServiceError>>;
+  /// Books in the libraries of users are deleted as well, they are removed from the libraries
   async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutBookServiceError>>;
 }
 
@@ -27,6 +28,8 @@ pub enum MutBookServiceError {
   InvalidDescription(String),
   AlreadyExistingSlug(String),
   NonExistentTranslationCover(Language),
+  NonExistentEditionCover(usize),
+  AlreadyExistingIsbn13(String),
   OtherError(Box<dyn Display>),
 }
 
@@ -56,6 +59,10 @@ impl Display for MutBookServiceError {
           "A cover for the language '{language}' ({}) does not exist",
           language.language_code()
         ),
+        MutBookServiceError::NonExistentEditionCover(x) =>
+          format!("An edition cover with the index {x} does not exist"),
+        MutBookServiceError::AlreadyExistingIsbn13(x) =>
+          format!("An edition with the following ISBN-13 already exists: {x}"),
         MutBookServiceError::NonExistentBooks(x) =>
           format!("Books with the following ids do not exist: [{}]", x.join_comma()),
         MutBookServiceError::NoIdsProvided => "No ids provided".to_string(),
@@ -0,0 +1,30 @@
+pub mod mut_company_service;
+
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait CompanyService: Send + Sync {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Company>, ServiceError<CompanyServiceError>>;
+  async fn get_by_id(&self, id: u32) -> Result<Option<Company>, ServiceError<CompanyServiceError>>;
+  async fn get_by_name(
+    &self,
+    name: &str,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Company>, ServiceError<CompanyServiceError>>;
+}
+
+pub enum CompanyServiceError {}
+
+impl Display for CompanyServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
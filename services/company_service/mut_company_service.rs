@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::company::create_company::CreateCompany;
+use domain::entities::company::Company;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutCompanyService: Send + Sync {
+  async fn create(&self, item: CreateCompany) -> Result<Company, ServiceError<MutCompanyServiceError>>;
+  /// Companies which still have platforms can not be deleted, they are removed as publishers.
+  /// Returns the files of the logos to delete once the transaction is committed
+  async fn delete(&self, ids: &[u32]) -> Result<Vec<String>, ServiceError<MutCompanyServiceError>>;
+}
+
+pub enum MutCompanyServiceError {
+  InvalidName(String),
+  NoIdsProvided,
+  NonExistentCompanies(Vec<u32>),
+  CompaniesWithPlatforms(Vec<u32>),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutCompanyServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutCompanyServiceError::InvalidName(x) => format!("Name '{x}' is invalid"),
+        MutCompanyServiceError::NoIdsProvided => "No ids provided".to_string(),
+        MutCompanyServiceError::NonExistentCompanies(x) =>
+          format!("The following companies do not exist: [{}]", x.join_comma()),
+        MutCompanyServiceError::CompaniesWithPlatforms(x) => format!(
+          "The following companies still have platforms, delete or move them first: [{}]",
+          x.join_comma()
+        ),
+        MutCompanyServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
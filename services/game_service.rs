@@ -0,0 +1,53 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+use domain::entities::game::game_statistic::GameStatistic;
+use domain::entities::game::Game;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod game_character_service;
+pub mod game_genre_service;
+pub mod game_involved_service;
+pub mod game_platform_service;
+pub mod game_theme_service;
+pub mod mut_game_service;
+
+#[async_trait]
+pub trait GameService: Send + Sync {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Game>, ServiceError<GameServiceError>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Game>, ServiceError<GameServiceError>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Game>, ServiceError<GameServiceError>>;
+
+  async fn get_statistics(&self, game_ids: &[u32]) -> Result<Vec<GameStatistic>, ServiceError<GameServiceError>>;
+}
+
+pub enum GameServiceError {
+  NonExistentGames(Vec<u32>),
+}
+
+impl Display for GameServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        GameServiceError::NonExistentGames(x) =>
+          format!("Games with the following ids do not exist: [{}]", x.join_comma()),
+      }
+    )
+  }
+}
@@ -0,0 +1,30 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_character::GameCharacter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_game_character_service;
+
+#[async_trait]
+pub trait GameCharacterService: Send + Sync {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameCharacter>, ServiceError<GameCharacterServiceError>>;
+}
+
+pub enum GameCharacterServiceError {}
+
+impl Display for GameCharacterServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
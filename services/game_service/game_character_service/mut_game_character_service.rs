@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGameCharacterService: Send + Sync {
+  async fn add(&self, game_id: u32, characters: &[u32]) -> Result<(), ServiceError<MutGameCharacterServiceError>>;
+  async fn remove(&self, game_id: u32, characters: &[u32]) -> Result<(), ServiceError<MutGameCharacterServiceError>>;
+}
+
+pub enum MutGameCharacterServiceError {
+  NonExistentGame(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoCharactersProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGameCharacterServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGameCharacterServiceError::NonExistentGame(x) => format!("Game with the id {x} does not exist"),
+        MutGameCharacterServiceError::NotAssociated(x) => format!(
+          "The following characters do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutGameCharacterServiceError::NonExistent(x) =>
+          format!("The following characters do not exist: [{}]", x.join_comma()),
+        MutGameCharacterServiceError::NoCharactersProvided => "No characters provided".to_string(),
+        MutGameCharacterServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGameGenreService: Send + Sync {
+  async fn add(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>>;
+  async fn remove(&self, game_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutGameGenreServiceError>>;
+}
+
+pub enum MutGameGenreServiceError {
+  NonExistentGame(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoGenresProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGameGenreServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGameGenreServiceError::NonExistentGame(x) => format!("Game with the id {x} does not exist"),
+        MutGameGenreServiceError::NotAssociated(x) =>
+          format!("The following genres do not have an association: [{}]", x.join_comma()),
+        MutGameGenreServiceError::NonExistent(x) => format!("The following genres do not exist: [{}]", x.join_comma()),
+        MutGameGenreServiceError::NoGenresProvided => "No genres provided".to_string(),
+        MutGameGenreServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
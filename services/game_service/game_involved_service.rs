@@ -0,0 +1,30 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::game::game_involved::GameInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_game_involved_service;
+
+#[async_trait]
+pub trait GameInvolvedService: Send + Sync {
+  async fn get(
+    &self,
+    game_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GameInvolved>, ServiceError<GameInvolvedServiceError>>;
+}
+
+pub enum GameInvolvedServiceError {}
+
+impl Display for GameInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
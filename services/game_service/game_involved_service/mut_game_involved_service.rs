@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGameInvolvedService: Send + Sync {
+  async fn add(&self, game_id: u32, involved: &[InvolvedId]) -> Result<(), ServiceError<MutGameInvolvedServiceError>>;
+  async fn remove(
+    &self,
+    game_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGameInvolvedServiceError>>;
+}
+
+pub enum MutGameInvolvedServiceError {
+  NonExistentGame(u32),
+  NonExistentAssociation(Vec<InvolvedId>),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  NoInvolvedProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGameInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGameInvolvedServiceError::NonExistentGame(x) => format!("Game with the id {x} does not exist"),
+        MutGameInvolvedServiceError::NonExistentAssociation(x) => format!(
+          "The following people with roles do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutGameInvolvedServiceError::NonExistentPeople(x) =>
+          format!("The following people do not exist: [{}]", x.join_comma()),
+        MutGameInvolvedServiceError::NonExistentRoles(x) =>
+          format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutGameInvolvedServiceError::NoInvolvedProvided => "No involved provided".to_string(),
+        MutGameInvolvedServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
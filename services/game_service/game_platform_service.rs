@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_game_platform_service;
+
+#[async_trait]
+pub trait GamePlatformService: Send + Sync {
+  async fn get(
+    &self,
+    game_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Platform>, ServiceError<GamePlatformServiceError>>;
+}
+
+pub enum GamePlatformServiceError {}
+
+impl Display for GamePlatformServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
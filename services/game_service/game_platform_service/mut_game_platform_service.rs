@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGamePlatformService: Send + Sync {
+  async fn add(&self, game_id: u32, platforms: &[u32]) -> Result<(), ServiceError<MutGamePlatformServiceError>>;
+  async fn remove(&self, game_id: u32, platforms: &[u32]) -> Result<(), ServiceError<MutGamePlatformServiceError>>;
+}
+
+pub enum MutGamePlatformServiceError {
+  NonExistentGame(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoPlatformsProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGamePlatformServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGamePlatformServiceError::NonExistentGame(x) => format!("Game with the id {x} does not exist"),
+        MutGamePlatformServiceError::NotAssociated(x) => format!(
+          "The following platforms do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutGamePlatformServiceError::NonExistent(x) =>
+          format!("The following platforms do not exist: [{}]", x.join_comma()),
+        MutGamePlatformServiceError::NoPlatformsProvided => "No platforms provided".to_string(),
+        MutGamePlatformServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGameThemeService: Send + Sync {
+  async fn add(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>>;
+  async fn remove(&self, game_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutGameThemeServiceError>>;
+}
+
+pub enum MutGameThemeServiceError {
+  NonExistentGame(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoThemesProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGameThemeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGameThemeServiceError::NonExistentGame(x) => format!("Game with the id {x} does not exist"),
+        MutGameThemeServiceError::NotAssociated(x) =>
+          format!("The following themes do not have an association: [{}]", x.join_comma()),
+        MutGameThemeServiceError::NonExistent(x) => format!("The following themes do not exist: [{}]", x.join_comma()),
+        MutGameThemeServiceError::NoThemesProvided => "No themes provided".to_string(),
+        MutGameThemeServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
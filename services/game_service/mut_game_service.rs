@@ -0,0 +1,67 @@
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+use async_trait::async_trait;
+use domain::entities::game::create_game::CreateGame;
+use domain::entities::game::Game;
+use domain::enums::language::Language;
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait MutGameService: Send + Sync {
+  async fn create(&self, item: CreateGame) -> Result<Game, ServiceError<MutGameServiceError>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutGameServiceError>>;
+}
+
+pub enum MutGameServiceError {
+  NonExistentFranchise(u32),
+  NoIdsProvided,
+  NonExistentGames(Vec<u32>),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  NonExistentThemes(Vec<u32>),
+  NonExistentGenres(Vec<u32>),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  NonExistentCharacters(Vec<u32>),
+  NonExistentPlatforms(Vec<u32>),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  NonExistentTranslationCover(Language),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGameServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGameServiceError::NonExistentFranchise(x) => format!("Franchise with the id '{x}' does not exist"),
+        MutGameServiceError::OtherError(x) => x.to_string(),
+        MutGameServiceError::NonExistentThemes(x) => format!("The following themes do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NonExistentGenres(x) => format!("The following genres do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NonExistentCharacters(x) =>
+          format!("The following characters do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NonExistentPlatforms(x) =>
+          format!("The following platforms do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NonExistentPeople(x) => format!("The following people do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NonExistentRoles(x) => format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutGameServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutGameServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutGameServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutGameServiceError::NonExistentTranslationCover(language) => format!(
+          "A cover for the language '{language}' ({}) does not exist",
+          language.language_code()
+        ),
+        MutGameServiceError::NonExistentGames(x) =>
+          format!("Games with the following ids do not exist: [{}]", x.join_comma()),
+        MutGameServiceError::NoIdsProvided => "No ids provided".to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod graphic_novel_chapter_service;
+pub mod graphic_novel_character_service;
+pub mod graphic_novel_genre_service;
+pub mod graphic_novel_involved_service;
+pub mod graphic_novel_publisher_service;
+pub mod graphic_novel_theme_service;
+pub mod graphic_novel_volume_service;
+pub mod mut_graphic_novel_service;
+
+#[async_trait]
+pub trait GraphicNovelService: Send + Sync {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovel>, ServiceError<GraphicNovelServiceError>>;
+  async fn get_by_id(
+    &self,
+    id: u32,
+    language: Language,
+  ) -> Result<Option<GraphicNovel>, ServiceError<GraphicNovelServiceError>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovel>, ServiceError<GraphicNovelServiceError>>;
+}
+
+pub enum GraphicNovelServiceError {}
+
+impl Display for GraphicNovelServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
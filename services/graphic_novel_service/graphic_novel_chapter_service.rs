@@ -0,0 +1,38 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_graphic_novel_chapter_service;
+
+#[async_trait]
+pub trait GraphicNovelChapterService: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelChapter>, ServiceError<GraphicNovelChapterServiceError>>;
+  async fn get_by_chapter(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    chapter: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelChapter>, ServiceError<GraphicNovelChapterServiceError>>;
+}
+
+pub enum GraphicNovelChapterServiceError {}
+
+impl Display for GraphicNovelChapterServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,62 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_chapter::CreateGraphicNovelChapter;
+use domain::entities::graphic_novel::graphic_novel_chapter::GraphicNovelChapter;
+use domain::enums::language::Language;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelChapterService: Send + Sync {
+  async fn create(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    item: CreateGraphicNovelChapter,
+  ) -> Result<GraphicNovelChapter, ServiceError<MutGraphicNovelChapterServiceError>>;
+  async fn delete(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    chapter: u16,
+  ) -> Result<(), ServiceError<MutGraphicNovelChapterServiceError>>;
+}
+
+pub enum MutGraphicNovelChapterServiceError {
+  NonExistentVolume(u16),
+  NonExistentChapter(u16),
+  ChapterAlreadyExists(u16),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidPages(u16),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelChapterServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelChapterServiceError::OtherError(x) => x.to_string(),
+        MutGraphicNovelChapterServiceError::NonExistentVolume(x) => format!("Volume {x} does not exist"),
+        MutGraphicNovelChapterServiceError::NonExistentChapter(x) => format!("Chapter {x} does not exist"),
+        MutGraphicNovelChapterServiceError::ChapterAlreadyExists(x) => format!("Chapter {x} already exists"),
+        MutGraphicNovelChapterServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutGraphicNovelChapterServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutGraphicNovelChapterServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutGraphicNovelChapterServiceError::InvalidDescription(x) =>
+          format!("Description '{x}' in translation is invalid"),
+        MutGraphicNovelChapterServiceError::InvalidPages(x) => format!("Pages '{x}' is invalid"),
+      }
+    )
+  }
+}
@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelCharacterService: Send + Sync {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>>;
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    characters: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelCharacterServiceError>>;
+}
+
+pub enum MutGraphicNovelCharacterServiceError {
+  NonExistentGraphicNovel(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoCharactersProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelCharacterServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelCharacterServiceError::NonExistentGraphicNovel(x) =>
+          format!("GraphicNovel with the id {x} does not exist"),
+        MutGraphicNovelCharacterServiceError::NotAssociated(x) => format!(
+          "The following characters do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutGraphicNovelCharacterServiceError::NonExistent(x) =>
+          format!("The following characters do not exist: [{}]", x.join_comma()),
+        MutGraphicNovelCharacterServiceError::NoCharactersProvided => "No characters provided".to_string(),
+        MutGraphicNovelCharacterServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelGenreService: Send + Sync {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>>;
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    genres: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelGenreServiceError>>;
+}
+
+pub enum MutGraphicNovelGenreServiceError {
+  NonExistentGraphicNovel(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoGenresProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelGenreServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelGenreServiceError::NonExistentGraphicNovel(x) =>
+          format!("GraphicNovel with the id {x} does not exist"),
+        MutGraphicNovelGenreServiceError::NotAssociated(x) =>
+          format!("The following genres do not have an association: [{}]", x.join_comma()),
+        MutGraphicNovelGenreServiceError::NonExistent(x) =>
+          format!("The following genres do not exist: [{}]", x.join_comma()),
+        MutGraphicNovelGenreServiceError::NoGenresProvided => "No genres provided".to_string(),
+        MutGraphicNovelGenreServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
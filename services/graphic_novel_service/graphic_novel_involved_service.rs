@@ -0,0 +1,30 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_involved::GraphicNovelInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_graphic_novel_involved_service;
+
+#[async_trait]
+pub trait GraphicNovelInvolvedService: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelInvolved>, ServiceError<GraphicNovelInvolvedServiceError>>;
+}
+
+pub enum GraphicNovelInvolvedServiceError {}
+
+impl Display for GraphicNovelInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelInvolvedService: Send + Sync {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>>;
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutGraphicNovelInvolvedServiceError>>;
+}
+
+pub enum MutGraphicNovelInvolvedServiceError {
+  NonExistentGraphicNovel(u32),
+  NonExistentAssociation(Vec<InvolvedId>),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  NoInvolvedProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelInvolvedServiceError::NonExistentGraphicNovel(x) =>
+          format!("GraphicNovel with the id {x} does not exist"),
+        MutGraphicNovelInvolvedServiceError::NonExistentAssociation(x) => format!(
+          "The following people with roles do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutGraphicNovelInvolvedServiceError::NonExistentPeople(x) =>
+          format!("The following people do not exist: [{}]", x.join_comma()),
+        MutGraphicNovelInvolvedServiceError::NonExistentRoles(x) =>
+          format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutGraphicNovelInvolvedServiceError::NoInvolvedProvided => "No involved provided".to_string(),
+        MutGraphicNovelInvolvedServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
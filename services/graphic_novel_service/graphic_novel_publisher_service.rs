@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::company::Company;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_graphic_novel_publisher_service;
+
+#[async_trait]
+pub trait GraphicNovelPublisherService: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Company>, ServiceError<GraphicNovelPublisherServiceError>>;
+}
+
+pub enum GraphicNovelPublisherServiceError {}
+
+impl Display for GraphicNovelPublisherServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelPublisherService: Send + Sync {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>>;
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    publishers: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelPublisherServiceError>>;
+}
+
+pub enum MutGraphicNovelPublisherServiceError {
+  NonExistentGraphicNovel(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoPublishersProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelPublisherServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelPublisherServiceError::NonExistentGraphicNovel(x) =>
+          format!("GraphicNovel with the id {x} does not exist"),
+        MutGraphicNovelPublisherServiceError::NotAssociated(x) => format!(
+          "The following publishers do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutGraphicNovelPublisherServiceError::NonExistent(x) =>
+          format!("The following publishers do not exist: [{}]", x.join_comma()),
+        MutGraphicNovelPublisherServiceError::NoPublishersProvided => "No publishers provided".to_string(),
+        MutGraphicNovelPublisherServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelThemeService: Send + Sync {
+  async fn add(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>>;
+  async fn remove(
+    &self,
+    graphic_novel_id: u32,
+    themes: &[u32],
+  ) -> Result<(), ServiceError<MutGraphicNovelThemeServiceError>>;
+}
+
+pub enum MutGraphicNovelThemeServiceError {
+  NonExistentGraphicNovel(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoThemesProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelThemeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelThemeServiceError::NonExistentGraphicNovel(x) =>
+          format!("GraphicNovel with the id {x} does not exist"),
+        MutGraphicNovelThemeServiceError::NotAssociated(x) =>
+          format!("The following themes do not have an association: [{}]", x.join_comma()),
+        MutGraphicNovelThemeServiceError::NonExistent(x) =>
+          format!("The following themes do not exist: [{}]", x.join_comma()),
+        MutGraphicNovelThemeServiceError::NoThemesProvided => "No themes provided".to_string(),
+        MutGraphicNovelThemeServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_graphic_novel_volume_service;
+
+#[async_trait]
+pub trait GraphicNovelVolumeService: Send + Sync {
+  async fn get(
+    &self,
+    graphic_novel_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<GraphicNovelVolume>, ServiceError<GraphicNovelVolumeServiceError>>;
+  async fn get_by_volume(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+    language: Language,
+  ) -> Result<Option<GraphicNovelVolume>, ServiceError<GraphicNovelVolumeServiceError>>;
+}
+
+pub enum GraphicNovelVolumeServiceError {}
+
+impl Display for GraphicNovelVolumeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,62 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::graphic_novel::create_graphic_novel_volume::CreateGraphicNovelVolume;
+use domain::entities::graphic_novel::graphic_novel_volume::GraphicNovelVolume;
+use domain::enums::language::Language;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutGraphicNovelVolumeService: Send + Sync {
+  async fn create(
+    &self,
+    graphic_novel_id: u32,
+    item: CreateGraphicNovelVolume,
+  ) -> Result<GraphicNovelVolume, ServiceError<MutGraphicNovelVolumeServiceError>>;
+  /// Also deletes the chapters of the volume
+  async fn delete(
+    &self,
+    graphic_novel_id: u32,
+    volume: u16,
+  ) -> Result<(), ServiceError<MutGraphicNovelVolumeServiceError>>;
+}
+
+pub enum MutGraphicNovelVolumeServiceError {
+  NonExistentGraphicNovel(u32),
+  NonExistentVolume(u16),
+  VolumeAlreadyExists(u16),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidPages(u16),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelVolumeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelVolumeServiceError::OtherError(x) => x.to_string(),
+        MutGraphicNovelVolumeServiceError::NonExistentGraphicNovel(x) =>
+          format!("Graphic novel with the id {x} does not exist"),
+        MutGraphicNovelVolumeServiceError::NonExistentVolume(x) => format!("Volume {x} does not exist"),
+        MutGraphicNovelVolumeServiceError::VolumeAlreadyExists(x) => format!("Volume {x} already exists"),
+        MutGraphicNovelVolumeServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutGraphicNovelVolumeServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutGraphicNovelVolumeServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutGraphicNovelVolumeServiceError::InvalidDescription(x) =>
+          format!("Description '{x}' in translation is invalid"),
+        MutGraphicNovelVolumeServiceError::InvalidPages(x) => format!("Pages '{x}' is invalid"),
+      }
+    )
+  }
+}
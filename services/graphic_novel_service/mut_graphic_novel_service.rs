@@ -0,0 +1,59 @@
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+use async_trait::async_trait;
+use domain::entities::graphic_novel::create_graphic_novel::CreateGraphicNovel;
+use domain::entities::graphic_novel::GraphicNovel;
+use domain::enums::language::Language;
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait MutGraphicNovelService: Send + Sync {
+  async fn create(&self, item: CreateGraphicNovel) -> Result<GraphicNovel, ServiceError<MutGraphicNovelServiceError>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutGraphicNovelServiceError>>;
+}
+
+pub enum MutGraphicNovelServiceError {
+  NoIdsProvided,
+  NonExistentGraphicNovels(Vec<u32>),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidVolumes(u16),
+  InvalidChapters(u16),
+  InvalidPublish,
+  NonExistentTranslationCover(Language),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutGraphicNovelServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutGraphicNovelServiceError::OtherError(x) => x.to_string(),
+        MutGraphicNovelServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutGraphicNovelServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutGraphicNovelServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutGraphicNovelServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutGraphicNovelServiceError::InvalidVolumes(x) => format!("Volumes '{x}' is invalid"),
+        MutGraphicNovelServiceError::InvalidChapters(x) => format!("Chapters '{x}' is invalid"),
+        MutGraphicNovelServiceError::InvalidPublish => "The publish end is before the publish start".to_string(),
+        MutGraphicNovelServiceError::NonExistentTranslationCover(language) => format!(
+          "A cover for the language '{language}' ({}) does not exist",
+          language.language_code()
+        ),
+        MutGraphicNovelServiceError::NonExistentGraphicNovels(x) => format!(
+          "Graphic novels with the following ids do not exist: [{}]",
+          x.join_comma()
+        ),
+        MutGraphicNovelServiceError::NoIdsProvided => "No ids provided".to_string(),
+      }
+    )
+  }
+}
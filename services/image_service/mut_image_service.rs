@@ -10,6 +10,10 @@ use crate::traits::service_error::ServiceError;
 #[async_trait]
 pub trait MutImageService: Send + Sync {
   async fn create(&self, image: CreateImage) -> Result<Image, ServiceError<MutImageServiceError>>;
+  /// Removes the image together with the files of all its versions
+  async fn delete(&self, image: &Image) -> Result<(), ServiceError<MutImageServiceError>>;
+  /// Removes only the image, returns the files of its versions to delete once the transaction is committed
+  async fn delete_record(&self, image: &Image) -> Result<Vec<String>, ServiceError<MutImageServiceError>>;
 }
 
 pub enum MutImageServiceError {
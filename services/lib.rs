@@ -1,13 +1,19 @@
 pub mod account_service;
 pub mod book_service;
 pub mod character_service;
+pub mod company_service;
 pub mod file_service;
 pub mod franchise_service;
+pub mod game_service;
 pub mod genre_service;
+pub mod graphic_novel_service;
 pub mod image_service;
 pub mod join_comma;
+pub mod movie_service;
 pub mod person_service;
+pub mod platform_service;
 pub mod role_service;
+pub mod show_service;
 pub mod theme_service;
 pub mod traits;
 pub mod user_service;
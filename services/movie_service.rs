@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+use domain::entities::movie::movie_statistic::MovieStatistic;
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod movie_genre_service;
+pub mod movie_involved_service;
+pub mod movie_theme_service;
+pub mod mut_movie_service;
+
+#[async_trait]
+pub trait MovieService: Send + Sync {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Movie>, ServiceError<MovieServiceError>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Movie>, ServiceError<MovieServiceError>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Movie>, ServiceError<MovieServiceError>>;
+  async fn get_statistics(&self, movie_ids: &[u32]) -> Result<Vec<MovieStatistic>, ServiceError<MovieServiceError>>;
+}
+
+pub enum MovieServiceError {
+  NonExistentMovies(Vec<u32>),
+}
+
+impl Display for MovieServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MovieServiceError::NonExistentMovies(x) =>
+          format!("Movies with the following ids do not exist: [{}]", x.join_comma()),
+      }
+    )
+  }
+}
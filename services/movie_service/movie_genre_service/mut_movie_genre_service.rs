@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutMovieGenreService: Send + Sync {
+  async fn add(&self, movie_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutMovieGenreServiceError>>;
+  async fn remove(&self, movie_id: u32, genres: &[u32]) -> Result<(), ServiceError<MutMovieGenreServiceError>>;
+}
+
+pub enum MutMovieGenreServiceError {
+  NonExistentMovie(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoGenresProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutMovieGenreServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutMovieGenreServiceError::NonExistentMovie(x) => format!("Movie with the id {x} does not exist"),
+        MutMovieGenreServiceError::NotAssociated(x) =>
+          format!("The following genres do not have an association: [{}]", x.join_comma()),
+        MutMovieGenreServiceError::NonExistent(x) => format!("The following genres do not exist: [{}]", x.join_comma()),
+        MutMovieGenreServiceError::NoGenresProvided => "No genres provided".to_string(),
+        MutMovieGenreServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,30 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::movie::movie_involved::MovieInvolved;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_movie_involved_service;
+
+#[async_trait]
+pub trait MovieInvolvedService: Send + Sync {
+  async fn get(
+    &self,
+    movie_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<MovieInvolved>, ServiceError<MovieInvolvedServiceError>>;
+}
+
+pub enum MovieInvolvedServiceError {}
+
+impl Display for MovieInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::involved::InvolvedId;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutMovieInvolvedService: Send + Sync {
+  async fn add(&self, movie_id: u32, involved: &[InvolvedId])
+    -> Result<(), ServiceError<MutMovieInvolvedServiceError>>;
+  async fn remove(
+    &self,
+    movie_id: u32,
+    involved: &[InvolvedId],
+  ) -> Result<(), ServiceError<MutMovieInvolvedServiceError>>;
+}
+
+pub enum MutMovieInvolvedServiceError {
+  NonExistentMovie(u32),
+  NonExistentAssociation(Vec<InvolvedId>),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  NoInvolvedProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutMovieInvolvedServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutMovieInvolvedServiceError::NonExistentMovie(x) => format!("Movie with the id {x} does not exist"),
+        MutMovieInvolvedServiceError::NonExistentAssociation(x) => format!(
+          "The following people with roles do not have an association: [{}]",
+          x.join_comma()
+        ),
+        MutMovieInvolvedServiceError::NonExistentPeople(x) =>
+          format!("The following people do not exist: [{}]", x.join_comma()),
+        MutMovieInvolvedServiceError::NonExistentRoles(x) =>
+          format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutMovieInvolvedServiceError::NoInvolvedProvided => "No involved provided".to_string(),
+        MutMovieInvolvedServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutMovieThemeService: Send + Sync {
+  async fn add(&self, movie_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutMovieThemeServiceError>>;
+  async fn remove(&self, movie_id: u32, themes: &[u32]) -> Result<(), ServiceError<MutMovieThemeServiceError>>;
+}
+
+pub enum MutMovieThemeServiceError {
+  NonExistentMovie(u32),
+  NotAssociated(Vec<u32>),
+  NonExistent(Vec<u32>),
+  NoThemesProvided,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutMovieThemeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutMovieThemeServiceError::NonExistentMovie(x) => format!("Movie with the id {x} does not exist"),
+        MutMovieThemeServiceError::NotAssociated(x) =>
+          format!("The following themes do not have an association: [{}]", x.join_comma()),
+        MutMovieThemeServiceError::NonExistent(x) => format!("The following themes do not exist: [{}]", x.join_comma()),
+        MutMovieThemeServiceError::NoThemesProvided => "No themes provided".to_string(),
+        MutMovieThemeServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
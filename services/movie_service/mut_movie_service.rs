@@ -0,0 +1,64 @@
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+use async_trait::async_trait;
+use domain::entities::movie::create_movie::CreateMovie;
+use domain::entities::movie::Movie;
+use domain::enums::language::Language;
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait MutMovieService: Send + Sync {
+  async fn create(&self, item: CreateMovie) -> Result<Movie, ServiceError<MutMovieServiceError>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutMovieServiceError>>;
+}
+
+pub enum MutMovieServiceError {
+  NoIdsProvided,
+  NonExistentMovies(Vec<u32>),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  NonExistentThemes(Vec<u32>),
+  NonExistentGenres(Vec<u32>),
+  NonExistentPeople(Vec<u32>),
+  NonExistentRoles(Vec<u32>),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidLength(u32),
+  NonExistentTranslationCover(Language),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutMovieServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutMovieServiceError::OtherError(x) => x.to_string(),
+        MutMovieServiceError::NonExistentThemes(x) =>
+          format!("The following themes do not exist: [{}]", x.join_comma()),
+        MutMovieServiceError::NonExistentGenres(x) =>
+          format!("The following genres do not exist: [{}]", x.join_comma()),
+        MutMovieServiceError::NonExistentPeople(x) =>
+          format!("The following people do not exist: [{}]", x.join_comma()),
+        MutMovieServiceError::NonExistentRoles(x) => format!("The following roles do not exist: [{}]", x.join_comma()),
+        MutMovieServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutMovieServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutMovieServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutMovieServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutMovieServiceError::InvalidLength(x) => format!("Length '{x}' is invalid"),
+        MutMovieServiceError::NonExistentTranslationCover(language) => format!(
+          "A cover for the language '{language}' ({}) does not exist",
+          language.language_code()
+        ),
+        MutMovieServiceError::NonExistentMovies(x) =>
+          format!("Movies with the following ids do not exist: [{}]", x.join_comma()),
+        MutMovieServiceError::NoIdsProvided => "No ids provided".to_string(),
+      }
+    )
+  }
+}
@@ -0,0 +1,30 @@
+pub mod mut_platform_service;
+
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::platform::Platform;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait PlatformService: Send + Sync {
+  async fn get(&self, pagination: Pagination) -> Result<ItemsTotal<Platform>, ServiceError<PlatformServiceError>>;
+  async fn get_by_id(&self, id: u32) -> Result<Option<Platform>, ServiceError<PlatformServiceError>>;
+  async fn get_by_name(
+    &self,
+    name: &str,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Platform>, ServiceError<PlatformServiceError>>;
+}
+
+pub enum PlatformServiceError {}
+
+impl Display for PlatformServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::platform::create_platform::CreatePlatform;
+use domain::entities::platform::patch_platform::PatchPlatform;
+use domain::entities::platform::Platform;
+
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutPlatformService: Send + Sync {
+  async fn create(&self, item: CreatePlatform) -> Result<Platform, ServiceError<MutPlatformServiceError>>;
+  async fn update(&self, id: u32, item: PatchPlatform) -> Result<Platform, ServiceError<MutPlatformServiceError>>;
+  /// Also removes the platforms from all games.
+  /// Returns the files of the logos to delete once the transaction is committed
+  async fn delete(&self, ids: &[u32]) -> Result<Vec<String>, ServiceError<MutPlatformServiceError>>;
+}
+
+pub enum MutPlatformServiceError {
+  InvalidName(String),
+  InvalidShortName(String),
+  NonExistentCompany(u32),
+  NonExistentPlatform,
+  NoIdsProvided,
+  NonExistentPlatforms(Vec<u32>),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutPlatformServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutPlatformServiceError::InvalidName(x) =>
+          format!("Name '{x}' is invalid, it has to be between 1 and 50 characters long"),
+        MutPlatformServiceError::InvalidShortName(x) =>
+          format!("Short name '{x}' is invalid, it can not be longer than 10 characters"),
+        MutPlatformServiceError::NonExistentCompany(x) => format!("Company with the id {x} does not exist"),
+        MutPlatformServiceError::NonExistentPlatform => "Platform does not exist".to_string(),
+        MutPlatformServiceError::NoIdsProvided => "No ids provided".to_string(),
+        MutPlatformServiceError::NonExistentPlatforms(x) =>
+          format!("The following platforms do not exist: [{}]", x.join_comma()),
+        MutPlatformServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
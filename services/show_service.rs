@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+pub mod mut_show_service;
+pub mod show_episode_service;
+pub mod show_season_service;
+
+#[async_trait]
+pub trait ShowService: Send + Sync {
+  async fn get(
+    &self,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Show>, ServiceError<ShowServiceError>>;
+  async fn get_by_id(&self, id: u32, language: Language) -> Result<Option<Show>, ServiceError<ShowServiceError>>;
+  async fn get_by_title(
+    &self,
+    title: &str,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Show>, ServiceError<ShowServiceError>>;
+}
+
+pub enum ShowServiceError {}
+
+impl Display for ShowServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,55 @@
+use crate::join_comma::JoinComma;
+use crate::traits::service_error::ServiceError;
+use async_trait::async_trait;
+use domain::entities::show::create_show::CreateShow;
+use domain::entities::show::Show;
+use domain::enums::language::Language;
+use std::fmt::{Display, Formatter};
+
+#[async_trait]
+pub trait MutShowService: Send + Sync {
+  async fn create(&self, item: CreateShow) -> Result<Show, ServiceError<MutShowServiceError>>;
+  async fn delete(&self, ids: &[u32]) -> Result<(), ServiceError<MutShowServiceError>>;
+}
+
+pub enum MutShowServiceError {
+  NoIdsProvided,
+  NonExistentShows(Vec<u32>),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  NonExistentFranchise(u32),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidAiring,
+  NonExistentTranslationCover(Language),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutShowServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutShowServiceError::OtherError(x) => x.to_string(),
+        MutShowServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutShowServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutShowServiceError::NonExistentFranchise(x) => format!("Franchise with the id {x} does not exist"),
+        MutShowServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutShowServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutShowServiceError::InvalidAiring => "The airing end is before the airing start".to_string(),
+        MutShowServiceError::NonExistentTranslationCover(language) => format!(
+          "A cover for the language '{language}' ({}) does not exist",
+          language.language_code()
+        ),
+        MutShowServiceError::NonExistentShows(x) =>
+          format!("Shows with the following ids do not exist: [{}]", x.join_comma()),
+        MutShowServiceError::NoIdsProvided => "No ids provided".to_string(),
+      }
+    )
+  }
+}
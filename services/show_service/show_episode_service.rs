@@ -0,0 +1,38 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_show_episode_service;
+
+#[async_trait]
+pub trait ShowEpisodeService: Send + Sync {
+  async fn get(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowEpisode>, ServiceError<ShowEpisodeServiceError>>;
+  async fn get_by_episode(
+    &self,
+    show_id: u32,
+    season: u16,
+    episode: u16,
+    language: Language,
+  ) -> Result<Option<ShowEpisode>, ServiceError<ShowEpisodeServiceError>>;
+}
+
+pub enum ShowEpisodeServiceError {}
+
+impl Display for ShowEpisodeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
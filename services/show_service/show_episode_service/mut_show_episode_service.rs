@@ -0,0 +1,61 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_episode::CreateShowEpisode;
+use domain::entities::show::show_episode::ShowEpisode;
+use domain::enums::language::Language;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutShowEpisodeService: Send + Sync {
+  async fn create(
+    &self,
+    show_id: u32,
+    season: u16,
+    item: CreateShowEpisode,
+  ) -> Result<ShowEpisode, ServiceError<MutShowEpisodeServiceError>>;
+  async fn delete(
+    &self,
+    show_id: u32,
+    season: u16,
+    episode: u16,
+  ) -> Result<(), ServiceError<MutShowEpisodeServiceError>>;
+}
+
+pub enum MutShowEpisodeServiceError {
+  NonExistentSeason(u16),
+  NonExistentEpisode(u16),
+  EpisodeAlreadyExists(u16),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidLength(u16),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutShowEpisodeServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutShowEpisodeServiceError::OtherError(x) => x.to_string(),
+        MutShowEpisodeServiceError::NonExistentSeason(x) => format!("Season {x} does not exist"),
+        MutShowEpisodeServiceError::NonExistentEpisode(x) => format!("Episode {x} does not exist"),
+        MutShowEpisodeServiceError::EpisodeAlreadyExists(x) => format!("Episode {x} already exists"),
+        MutShowEpisodeServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutShowEpisodeServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutShowEpisodeServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutShowEpisodeServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutShowEpisodeServiceError::InvalidLength(x) => format!("Length '{x}' is invalid"),
+      }
+    )
+  }
+}
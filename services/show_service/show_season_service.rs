@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::show::show_season::ShowSeason;
+use domain::enums::language::Language;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_show_season_service;
+
+#[async_trait]
+pub trait ShowSeasonService: Send + Sync {
+  async fn get(
+    &self,
+    show_id: u32,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<ShowSeason>, ServiceError<ShowSeasonServiceError>>;
+  async fn get_by_season(
+    &self,
+    show_id: u32,
+    season: u16,
+    language: Language,
+  ) -> Result<Option<ShowSeason>, ServiceError<ShowSeasonServiceError>>;
+}
+
+pub enum ShowSeasonServiceError {}
+
+impl Display for ShowSeasonServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,58 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::show::create_show_season::CreateShowSeason;
+use domain::entities::show::show_season::ShowSeason;
+use domain::enums::language::Language;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutShowSeasonService: Send + Sync {
+  async fn create(
+    &self,
+    show_id: u32,
+    item: CreateShowSeason,
+  ) -> Result<ShowSeason, ServiceError<MutShowSeasonServiceError>>;
+  /// Also deletes the episodes of the season
+  async fn delete(&self, show_id: u32, season: u16) -> Result<(), ServiceError<MutShowSeasonServiceError>>;
+}
+
+pub enum MutShowSeasonServiceError {
+  NonExistentShow(u32),
+  NonExistentSeason(u16),
+  SeasonAlreadyExists(u16),
+  NoTranslationsProvided,
+  NoTranslationInLanguageProvided(Language),
+  InvalidTitle(String),
+  InvalidDescription(String),
+  InvalidEpisodes(u16),
+  InvalidAiring,
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutShowSeasonServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutShowSeasonServiceError::OtherError(x) => x.to_string(),
+        MutShowSeasonServiceError::NonExistentShow(x) => format!("Show with the id {x} does not exist"),
+        MutShowSeasonServiceError::NonExistentSeason(x) => format!("Season {x} does not exist"),
+        MutShowSeasonServiceError::SeasonAlreadyExists(x) => format!("Season {x} already exists"),
+        MutShowSeasonServiceError::NoTranslationsProvided => "No translations provided".to_string(),
+        MutShowSeasonServiceError::NoTranslationInLanguageProvided(language) => format!(
+          "No translation in '{}' ({}) provided",
+          language,
+          language.language_code()
+        ),
+        MutShowSeasonServiceError::InvalidTitle(x) => format!("Title '{x}' in translation is invalid"),
+        MutShowSeasonServiceError::InvalidDescription(x) => format!("Description '{x}' in translation is invalid"),
+        MutShowSeasonServiceError::InvalidEpisodes(x) => format!("Episodes '{x}' is invalid"),
+        MutShowSeasonServiceError::InvalidAiring => "The airing end is before the airing start".to_string(),
+      }
+    )
+  }
+}
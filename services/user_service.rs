@@ -8,7 +8,10 @@ use domain::pagination::Pagination;
 
 use crate::traits::service_error::ServiceError;
 
+pub mod friendship_service;
+pub mod mut_user_average_service;
 pub mod mut_user_service;
+pub mod user_book_service;
 
 #[async_trait]
 pub trait UserService: Send + Sync {
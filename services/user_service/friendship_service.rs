@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::user::friendship::Friendship;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_friendship_service;
+
+#[async_trait]
+pub trait FriendshipService: Send + Sync {
+  /// Returns `None` if the user does not exist or was deleted
+  async fn get_friends(
+    &self,
+    user_id: u32,
+    pagination: Pagination,
+  ) -> Result<Option<ItemsTotal<Friendship>>, ServiceError<FriendshipServiceError>>;
+  async fn get_incoming(
+    &self,
+    user_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Friendship>, ServiceError<FriendshipServiceError>>;
+  async fn get_outgoing(
+    &self,
+    user_id: u32,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<Friendship>, ServiceError<FriendshipServiceError>>;
+}
+
+pub enum FriendshipServiceError {}
+
+impl Display for FriendshipServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::user::friendship::Friendship;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutFriendshipService: Send + Sync {
+  async fn send(&self, user_id: u32, other_user_id: u32)
+    -> Result<Friendship, ServiceError<MutFriendshipServiceError>>;
+  async fn accept(
+    &self,
+    user_id: u32,
+    other_user_id: u32,
+  ) -> Result<Friendship, ServiceError<MutFriendshipServiceError>>;
+  async fn decline(&self, user_id: u32, other_user_id: u32) -> Result<(), ServiceError<MutFriendshipServiceError>>;
+  async fn remove(&self, user_id: u32, other_user_id: u32) -> Result<(), ServiceError<MutFriendshipServiceError>>;
+}
+
+pub enum MutFriendshipServiceError {
+  SelfFriendship,
+  NonExistentUser(u32),
+  AlreadyFriends(u32),
+  AlreadyRequested(u32),
+  NonExistentRequest(u32),
+  NotFriends(u32),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutFriendshipServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutFriendshipServiceError::SelfFriendship => "Users can not befriend themselves".to_string(),
+        MutFriendshipServiceError::NonExistentUser(x) => format!("User with the id {x} does not exist"),
+        MutFriendshipServiceError::AlreadyFriends(x) => format!("Already friends with the user with the id {x}"),
+        MutFriendshipServiceError::AlreadyRequested(x) =>
+          format!("A friend request with the user with the id {x} is already pending"),
+        MutFriendshipServiceError::NonExistentRequest(x) =>
+          format!("There is no pending friend request from the user with the id {x}"),
+        MutFriendshipServiceError::NotFriends(x) => format!("Not friends with the user with the id {x}"),
+        MutFriendshipServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
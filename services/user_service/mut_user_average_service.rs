@@ -0,0 +1,27 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutUserAverageService: Send + Sync {
+  /// Returns the amount of users whose averages were recomputed
+  async fn recompute_all(&self) -> Result<usize, ServiceError<MutUserAverageServiceError>>;
+}
+
+pub enum MutUserAverageServiceError {
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutUserAverageServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutUserAverageServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}
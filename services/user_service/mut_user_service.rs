@@ -3,6 +3,7 @@ use std::fmt::{Display, Formatter};
 use async_trait::async_trait;
 
 use domain::entities::user::create_user::CreateUser;
+use domain::entities::user::patch_user::PatchUser;
 use domain::entities::user::User;
 
 use crate::traits::service_error::ServiceError;
@@ -10,9 +11,29 @@ use crate::traits::service_error::ServiceError;
 #[async_trait]
 pub trait MutUserService: Send + Sync {
   async fn create(&self, user: CreateUser) -> Result<User, ServiceError<MutUserServiceError>>;
+  /// A new profile picture replaces the current one, whose image is deleted.
+  /// Returns the files of the replaced profile picture to delete once the transaction is committed
+  async fn update(
+    &self,
+    user_id: u32,
+    user: PatchUser,
+  ) -> Result<(User, Vec<String>), ServiceError<MutUserServiceError>>;
+  /// Returns the files of the profile picture to delete once the transaction is committed
+  async fn delete_profile_picture(
+    &self,
+    user_id: u32,
+  ) -> Result<(User, Vec<String>), ServiceError<MutUserServiceError>>;
+  /// Anonymizes the user and deletes the profile picture, library entries and friendships are kept.
+  /// Returns the files of the profile picture to delete once the transaction is committed
+  async fn delete(&self, user_id: u32) -> Result<Vec<String>, ServiceError<MutUserServiceError>>;
 }
 
 pub enum MutUserServiceError {
+  NonExistentUser,
+  InvalidName,
+  NameTaken,
+  InvalidDescription,
+  NoProfilePicture,
   OtherError(Box<dyn Display>),
 }
 
@@ -22,6 +43,12 @@ impl Display for MutUserServiceError {
       f,
       "{}",
       match self {
+        MutUserServiceError::NonExistentUser => "User does not exist".to_string(),
+        MutUserServiceError::InvalidName =>
+          "Name has to be between 1 and 50 characters long and can not start with deleted-user-".to_string(),
+        MutUserServiceError::NameTaken => "Name is already taken".to_string(),
+        MutUserServiceError::InvalidDescription => "Description can not be longer than 500 characters".to_string(),
+        MutUserServiceError::NoProfilePicture => "User has no profile picture".to_string(),
         MutUserServiceError::OtherError(x) => x.to_string(),
       }
     )
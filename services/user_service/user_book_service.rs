@@ -0,0 +1,32 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::user::user_book::UserBook;
+use domain::enums::language::Language;
+use domain::enums::user_status::UserStatus;
+use domain::items_total::ItemsTotal;
+use domain::pagination::Pagination;
+
+use crate::traits::service_error::ServiceError;
+
+pub mod mut_user_book_service;
+
+#[async_trait]
+pub trait UserBookService: Send + Sync {
+  async fn get(
+    &self,
+    user_id: u32,
+    status: Option<UserStatus>,
+    language: Language,
+    pagination: Pagination,
+  ) -> Result<ItemsTotal<UserBook>, ServiceError<UserBookServiceError>>;
+}
+
+pub enum UserBookServiceError {}
+
+impl Display for UserBookServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "")
+  }
+}
@@ -0,0 +1,60 @@
+use std::fmt::{Display, Formatter};
+
+use async_trait::async_trait;
+
+use domain::entities::user::create_user_book::CreateUserBook;
+use domain::entities::user::patch_user_book::PatchUserBook;
+use domain::entities::user::user_book::UserBook;
+
+use crate::traits::service_error::ServiceError;
+
+#[async_trait]
+pub trait MutUserBookService: Send + Sync {
+  async fn save(
+    &self,
+    user_id: u32,
+    book_id: u32,
+    item: CreateUserBook,
+  ) -> Result<UserBook, ServiceError<MutUserBookServiceError>>;
+  async fn patch(
+    &self,
+    user_id: u32,
+    book_id: u32,
+    item: PatchUserBook,
+  ) -> Result<UserBook, ServiceError<MutUserBookServiceError>>;
+  async fn delete(&self, user_id: u32, book_id: u32) -> Result<(), ServiceError<MutUserBookServiceError>>;
+}
+
+pub enum MutUserBookServiceError {
+  NonExistentBook(u32),
+  NonExistentEntry(u32),
+  InvalidScore(u8),
+  InvalidReview,
+  InvalidDates,
+  InvalidChapters(u16, u16),
+  InvalidPages(u16, u16),
+  OtherError(Box<dyn Display>),
+}
+
+impl Display for MutUserBookServiceError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        MutUserBookServiceError::NonExistentBook(x) => format!("Book with the id {x} does not exist"),
+        MutUserBookServiceError::NonExistentEntry(x) =>
+          format!("Book with the id {x} is not part of the users library"),
+        MutUserBookServiceError::InvalidScore(x) => format!("Score {x} has to be between 1 and 10"),
+        MutUserBookServiceError::InvalidReview =>
+          "Review must not be empty and can be at most 255 characters long".to_string(),
+        MutUserBookServiceError::InvalidDates => "Finished date can not be before the start date".to_string(),
+        MutUserBookServiceError::InvalidChapters(x, max) =>
+          format!("Read chapters {x} can not be more than the books {max} chapters"),
+        MutUserBookServiceError::InvalidPages(x, max) =>
+          format!("Read pages {x} can not be more than the books {max} pages"),
+        MutUserBookServiceError::OtherError(x) => x.to_string(),
+      }
+    )
+  }
+}